@@ -13,6 +13,9 @@ from typing import Any, Dict
 
 from google.cloud import aiplatform
 
+from app.common.artifact_crypto import load_artifact
+from app.explainer.residency import enforce_local_only
+
 logger = logging.getLogger(__name__)
 
 
@@ -68,6 +71,7 @@ class SecurityAdvisorAgent:
         """Initialize AI model."""
         try:
             if os.getenv("AI_PROVIDER") == "vertex":
+                enforce_local_only("vertex")
                 aiplatform.init(project=self.project_id, location=self.location)
                 self.model = aiplatform.GenerativeModel("gemini-1.5-flash")
         except Exception as e:
@@ -288,13 +292,11 @@ class MultiAgentCoordinator:
             )
 
             # Read collected data
-            import json
             from pathlib import Path
 
             collected_file = Path("data/collected.json")
             if collected_file.exists():
-                with open(collected_file, "r") as f:
-                    data = json.load(f)
+                data = load_artifact(collected_file)
 
                 iam_count = len(data.get("iam_policies", {}).get("bindings", []))
                 scc_count = len(data.get("scc_findings", []))
@@ -327,13 +329,11 @@ class MultiAgentCoordinator:
             )
 
             # Read analysis results
-            import json
             from pathlib import Path
 
             explained_file = Path("data/explained.json")
             if explained_file.exists():
-                with open(explained_file, "r") as f:
-                    findings = json.load(f)
+                findings = load_artifact(explained_file)
 
                 critical_count = sum(1 for f in findings if f.get("severity") == "CRITICAL")
                 high_count = sum(1 for f in findings if f.get("severity") == "HIGH")
@@ -18,6 +18,8 @@ from rich.console import Console
 from rich.progress import Progress, SpinnerColumn, TextColumn
 from rich.table import Table
 
+from app.cli.output import is_quiet
+
 logger = logging.getLogger(__name__)
 console = Console()
 
@@ -55,7 +57,10 @@ class RecursiveAuditor:
         # Phase 1: Initial Discovery
         console.print("[bold yellow]Phase 1: Resource Discovery[/bold yellow]")
         with Progress(
-            SpinnerColumn(), TextColumn("[progress.description]{task.description}"), console=console
+            SpinnerColumn(),
+            TextColumn("[progress.description]{task.description}"),
+            console=console,
+            disable=is_quiet(),
         ) as progress:
             task = progress.add_task("Discovering resources...", total=None)
             self._discover_initial_resources()
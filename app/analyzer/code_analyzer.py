@@ -8,7 +8,6 @@ and map them to detected security issues.
 
 import json
 import logging
-import os
 import re
 from pathlib import Path
 from typing import Any, Dict, List, Optional
@@ -16,6 +15,8 @@ from typing import Any, Dict, List, Optional
 import fire
 from github import Github, GithubException
 
+from app.config.secrets import resolve_secret
+
 logger = logging.getLogger(__name__)
 
 
@@ -38,7 +39,7 @@ class CodebaseAnalyzer:
             github_repo: GitHub repository name
             local_path: Path to local repository (alternative to GitHub)
         """
-        self.github_token = github_token or os.getenv("GITHUB_TOKEN")
+        self.github_token = github_token or resolve_secret("GITHUB_TOKEN")
         self.github_owner = github_owner
         self.github_repo = github_repo
         self.local_path = local_path
@@ -1,6 +1,5 @@
 """Agent Manager for orchestrating Paddi agents in web context."""
 
-import json
 import logging
 import uuid
 from datetime import datetime
@@ -8,6 +7,7 @@ from pathlib import Path
 from typing import Any, Dict, Optional
 
 from app.collector.agent_collector import main as collector_main
+from app.common.artifact_crypto import load_artifact
 from app.explainer.agent_explainer import main as explainer_main
 from app.reporter.agent_reporter import main as reporter_main
 
@@ -158,8 +158,7 @@ class AgentManager:
             return None
 
         try:
-            with open(explained_file, "r", encoding="utf-8") as f:
-                data = json.load(f)
+            data = load_artifact(explained_file)
 
             # Format findings for web API
             findings = []
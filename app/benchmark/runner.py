@@ -0,0 +1,231 @@
+"""Run the pipeline on synthetic datasets and measure each stage.
+
+Each size runs collect, explain, and report in a temporary workspace, so the
+project's ``data/`` and ``output/`` are not touched. For every stage, the wall
+time, the peak memory allocated by Python (traced with :mod:`tracemalloc`), and
+the LLM tokens are recorded.
+
+With ``use_mock``, no LLM is called. The explain stage builds the same prompts
+as the Gemini analyzer and counts their estimated tokens, and its findings come
+from the rule-only analyzer, so the number of findings (and the work of the
+report stage) grows with the dataset, as it would with a real backend.
+"""
+
+import json
+import logging
+import tempfile
+import time
+import tracemalloc
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import Any, Callable, Dict, List, Optional, Sequence, Tuple
+
+from app.common.artifact_crypto import save_artifact
+from app.common.models import SecurityFinding
+from app.explainer.agent_explainer import PromptTemplate, SecurityRiskExplainer
+from app.explainer.rule_analyzer import RuleBasedAnalyzer
+from app.explainer.token_usage import TokenUsage
+from app.reporter.agent_reporter import main as reporter_main
+
+from .synthetic import SYNTHETIC_PROJECT, synthetic_collected
+
+logger = logging.getLogger(__name__)
+
+STAGES = ("collect", "explain", "report")
+DEFAULT_SIZES = (100, 1000, 10000)
+DEFAULT_FORMATS = ("markdown", "html")
+
+
+@dataclass
+class StageResult:
+    """Measurements of one pipeline stage."""
+
+    stage: str
+    seconds: float
+    peak_memory: int
+    tokens: Optional[int] = None
+
+    def to_dict(self) -> Dict[str, Any]:
+        """Convert to dictionary for JSON serialization."""
+        return {
+            "stage": self.stage,
+            "seconds": round(self.seconds, 4),
+            "peak_memory": self.peak_memory,
+            "tokens": self.tokens,
+        }
+
+
+@dataclass
+class BenchmarkResult:
+    """Measurements of the pipeline on one dataset size."""
+
+    size: int
+    findings: int = 0
+    stages: List[StageResult] = field(default_factory=list)
+    tokens_estimated: bool = False
+
+    @property
+    def seconds(self) -> float:
+        """Return the wall time of all stages."""
+        return sum(stage.seconds for stage in self.stages)
+
+    def to_dict(self) -> Dict[str, Any]:
+        """Convert to dictionary for JSON serialization."""
+        return {
+            "size": self.size,
+            "findings": self.findings,
+            "seconds": round(self.seconds, 4),
+            "tokens_estimated": self.tokens_estimated,
+            "stages": [stage.to_dict() for stage in self.stages],
+        }
+
+
+class SyntheticAnalyzer:
+    """Stands in for the LLM: builds its prompts, answers with the rule-only findings."""
+
+    def __init__(self):
+        self.usage = TokenUsage()
+        self._rules = RuleBasedAnalyzer()
+
+    def analyze_security_risks(self, configuration: Dict[str, Any]) -> List[SecurityFinding]:
+        """Analyze the data as the Gemini analyzer would, without calling it."""
+        findings = []
+        policy = configuration.get("iam_policies") or {}
+        if policy:
+            prompt = PromptTemplate.IAM_ANALYSIS_PROMPT.format(
+                iam_policy=json.dumps(policy, indent=2)
+            )
+            findings.extend(self._answer(prompt, self._rules.iam_findings(policy)))
+        reported = configuration.get("scc_findings") or []
+        if reported:
+            prompt = PromptTemplate.SCC_ANALYSIS_PROMPT.format(
+                scc_findings=json.dumps(reported, indent=2)
+            )
+            findings.extend(self._answer(prompt, self._rules.reported_findings(reported)))
+        return findings
+
+    def _answer(self, prompt: str, findings: List[SecurityFinding]) -> List[SecurityFinding]:
+        """Count the tokens of a call that would have returned these findings."""
+        completion = json.dumps([finding.to_dict() for finding in findings], indent=2)
+        self.usage.add(PromptTemplate.SYSTEM_PROMPT + prompt, completion)
+        return findings
+
+
+def measure(stage: str, func: Callable[[], Any]) -> Tuple[StageResult, Any]:
+    """Run a stage, measuring its wall time and peak traced memory."""
+    tracing = tracemalloc.is_tracing()
+    if tracing:
+        tracemalloc.reset_peak()
+    else:
+        tracemalloc.start()
+    start = time.perf_counter()
+    try:
+        value = func()
+    finally:
+        seconds = time.perf_counter() - start
+        _, peak = tracemalloc.get_traced_memory()
+        if not tracing:
+            tracemalloc.stop()
+    return StageResult(stage=stage, seconds=seconds, peak_memory=peak), value
+
+
+class Benchmark:
+    """Runs the pipeline stages on synthetic datasets."""
+
+    def __init__(
+        self,
+        use_mock: bool = True,
+        project_id: Optional[str] = None,
+        location: str = "us-central1",
+        ai_provider: Optional[str] = None,
+        ollama_model: Optional[str] = None,
+        ollama_endpoint: Optional[str] = None,
+        formats: Sequence[str] = DEFAULT_FORMATS,
+    ):
+        """Initialize Benchmark.
+
+        Args:
+            use_mock: Stand in for the LLM instead of calling the AI provider
+            project_id: GCP project for Vertex AI when the LLM is called
+            formats: Report formats generated by the report stage
+        """
+        self.use_mock = use_mock
+        self.project_id = project_id or SYNTHETIC_PROJECT
+        self.location = location
+        self.ai_provider = ai_provider
+        self.ollama_model = ollama_model
+        self.ollama_endpoint = ollama_endpoint
+        self.formats = list(formats)
+
+    def run(self, sizes: Sequence[int] = DEFAULT_SIZES) -> List[BenchmarkResult]:
+        """Benchmark each dataset size in turn."""
+        return [self.run_size(size) for size in sizes]
+
+    def run_size(self, size: int) -> BenchmarkResult:
+        """Run collect, explain, and report on a dataset of the given size."""
+        logger.info("Benchmarking %d resources...", size)
+        result = BenchmarkResult(size=size)
+        with tempfile.TemporaryDirectory(prefix="paddi-benchmark-") as workspace:
+            data_dir = Path(workspace) / "data"
+            output_dir = Path(workspace) / "output"
+            data_dir.mkdir()
+
+            stage, _ = measure("collect", lambda: self._collect(size, data_dir))
+            result.stages.append(stage)
+
+            stage, (findings, usage) = measure("explain", lambda: self._explain(data_dir))
+            stage.tokens = usage.total_tokens
+            result.stages.append(stage)
+            result.findings = findings
+            result.tokens_estimated = usage.estimated
+
+            stage, _ = measure("report", lambda: self._report(data_dir, output_dir))
+            result.stages.append(stage)
+        return result
+
+    def _collect(self, size: int, data_dir: Path) -> Path:
+        """Generate the dataset and write it as collect would."""
+        return save_artifact(
+            data_dir / "collected.json", synthetic_collected(size), indent=2, ensure_ascii=False
+        )
+
+    def _explain(self, data_dir: Path) -> Tuple[int, TokenUsage]:
+        """Analyze the dataset, returning the number of findings and the tokens used."""
+        explainer = SecurityRiskExplainer(
+            project_id=self.project_id,
+            location=self.location,
+            use_mock=self.use_mock,
+            input_file=str(data_dir / "collected.json"),
+            output_dir=str(data_dir),
+            ai_provider=self.ai_provider,
+            ollama_model=self.ollama_model,
+            ollama_endpoint=self.ollama_endpoint,
+        )
+        if self.use_mock:
+            explainer.analyzer = SyntheticAnalyzer()
+        findings = explainer.analyze()
+        explainer.save_findings(findings)
+        return len(findings), getattr(explainer.analyzer, "usage", TokenUsage())
+
+    def _report(self, data_dir: Path, output_dir: Path) -> None:
+        """Generate the reports from the explained findings."""
+        reporter_main(input_dir=str(data_dir), output_dir=str(output_dir), formats=self.formats)
+
+
+def format_results(results: List[BenchmarkResult]) -> str:
+    """Format benchmark results as a table."""
+    lines = [f"{'Size':>8}  {'Stage':<8}  {'Time (s)':>9}  {'Peak MiB':>9}  {'Tokens':>10}"]
+    for result in results:
+        for stage in result.stages:
+            tokens = "-" if stage.tokens is None else f"{stage.tokens:,}"
+            if stage.tokens is not None and result.tokens_estimated:
+                tokens = "~" + tokens
+            lines.append(
+                f"{result.size:>8}  {stage.stage:<8}  {stage.seconds:>9.3f}  "
+                f"{stage.peak_memory / 1024 / 1024:>9.1f}  {tokens:>10}"
+            )
+        lines.append(
+            f"{result.size:>8}  {'total':<8}  {result.seconds:>9.3f}  "
+            f"{'':>9}  {result.findings:>4} findings"
+        )
+    return "\n".join(lines)
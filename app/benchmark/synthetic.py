@@ -0,0 +1,85 @@
+"""Synthetic collected data of a chosen size.
+
+A dataset of size N describes N resources in one project: each has an IAM
+binding and a Security Command Center finding, so every stage of the pipeline
+gets proportionally more work. The data is generated from a fixed seed, so a
+size always produces the same dataset and benchmark runs are comparable.
+"""
+
+import random
+from typing import Any, Dict
+
+from app.common.models import CollectedData, CollectionMetadata
+
+SYNTHETIC_PROJECT = "paddi-benchmark"
+SYNTHETIC_ORGANIZATION = "000000000000"
+SYNTHETIC_TIMESTAMP = "2024-01-01T00:00:00+00:00"
+
+ROLES = (
+    "roles/owner",
+    "roles/editor",
+    "roles/viewer",
+    "roles/storage.admin",
+    "roles/compute.instanceAdmin.v1",
+    "roles/iam.serviceAccountUser",
+    "roles/bigquery.dataViewer",
+)
+MEMBER_KINDS = ("user", "group", "serviceAccount")
+# (category, resource type, severity) of the SCC findings
+SCC_CATEGORIES = (
+    ("PUBLIC_BUCKET_ACL", "storage.googleapis.com/Bucket", "HIGH"),
+    ("OPEN_FIREWALL", "compute.googleapis.com/Firewall", "HIGH"),
+    ("OVERPRIVILEGED_SERVICE_ACCOUNT", "iam.googleapis.com/ServiceAccount", "MEDIUM"),
+    ("SQL_NO_ROOT_PASSWORD", "sqladmin.googleapis.com/Instance", "CRITICAL"),
+    ("MFA_NOT_ENFORCED", "cloudresourcemanager.googleapis.com/Organization", "MEDIUM"),
+    ("WEAK_SSL_POLICY", "compute.googleapis.com/TargetHttpsProxy", "LOW"),
+)
+
+
+def synthetic_collected(size: int, seed: int = 0) -> Dict[str, Any]:
+    """Generate ``collected.json`` data for ``size`` resources."""
+    rng = random.Random(seed)
+    bindings = []
+    findings = []
+    for index in range(size):
+        members = [
+            f"{rng.choice(MEMBER_KINDS)}:member-{index}-{n}@{SYNTHETIC_PROJECT}.example.com"
+            for n in range(rng.randint(1, 3))
+        ]
+        # A few resources are public, as in real projects
+        if rng.random() < 0.02:
+            members.append("allUsers")
+        bindings.append({"role": rng.choice(ROLES), "members": members})
+
+        category, resource_type, severity = rng.choice(SCC_CATEGORIES)
+        service = resource_type.split("/")[0]
+        findings.append(
+            {
+                "name": (
+                    f"organizations/{SYNTHETIC_ORGANIZATION}/sources/1/findings/finding-{index}"
+                ),
+                "category": category,
+                "resource_name": f"//{service}/projects/{SYNTHETIC_PROJECT}/resources/r{index}",
+                "resource_type": resource_type,
+                "state": "ACTIVE",
+                "severity": severity,
+                "finding_class": "MISCONFIGURATION",
+                "source_type": "SHA",
+                "create_time": SYNTHETIC_TIMESTAMP,
+                "event_time": SYNTHETIC_TIMESTAMP,
+                "description": f"Synthetic {category.lower().replace('_', ' ')} finding",
+            }
+        )
+
+    return CollectedData.from_dict(
+        {
+            "metadata": CollectionMetadata(
+                project_id=SYNTHETIC_PROJECT,
+                organization_id=SYNTHETIC_ORGANIZATION,
+                timestamp=SYNTHETIC_TIMESTAMP,
+                source="benchmark",
+            ).to_dict(),
+            "iam_policies": {"bindings": bindings, "resource": f"projects/{SYNTHETIC_PROJECT}"},
+            "scc_findings": findings,
+        }
+    ).to_dict()
@@ -2,7 +2,7 @@
 
 from abc import ABC, abstractmethod
 from dataclasses import dataclass
-from typing import Optional
+from typing import Any, Optional
 
 
 @dataclass
@@ -24,6 +24,7 @@ class CommandContext:
 
     # Init-specific parameters
     skip_run: bool = False
+    interactive: bool = False
 
     # Multi-cloud parameters
     collect_all: bool = True
@@ -34,6 +35,95 @@ class CommandContext:
     github_owner: Optional[str] = None
     github_repo: Optional[str] = None
 
+    # Infrastructure as code parameters
+    terraform_file: Optional[str] = None
+
+    # Collection cache parameters
+    no_cache: bool = False
+    incremental: bool = False
+
+    # Subcommand parameters
+    action: Optional[str] = None
+    target: Optional[str] = None
+
+    # Findings parameters
+    severity: Optional[str] = None
+    category: Optional[str] = None
+    resource: Optional[str] = None
+    page: int = 1
+    page_size: int = 20
+    output_format: str = "table"
+    sort_by: str = "severity"
+    min_score: Optional[float] = None
+    reason: Optional[str] = None
+    tag: Optional[str] = None
+    query: Optional[str] = None
+
+    # Report parameters
+    inputs: Optional[str] = None
+    formats: Optional[str] = None
+    framework: Optional[str] = None
+    continue_on_error: bool = False
+    # Two history run IDs to report the changes between (report --compare A B)
+    compare: Any = None
+
+    # Remediation parameters
+    dry_run: bool = False
+
+    # Clean parameters
+    apply_retention: bool = False
+
+    # Trends parameters
+    runs: Optional[int] = None
+
+    # Baseline parameters
+    fail_on_new: bool = False
+
+    # Benchmark parameters
+    sizes: Any = None
+
+    # Multi-project audit parameters
+    projects: Optional[str] = None
+    fail_fast: bool = False
+
+    # Audit gate parameters
+    fail_on_critical: bool = False
+    fail_on_severity: Optional[str] = None
+
+    # Export parameters
+    export_target: Optional[str] = None
+
+    # Config parameters
+    config_file: Optional[str] = None
+    config_value: Any = None
+
+    # Plugin parameters
+    plugin_action: str = "analyze"
+    input_file: str = "data/collected.json"
+
+    # Serve parameters
+    host: Optional[str] = None
+    port: Optional[int] = None
+    token_action: Optional[str] = None
+    scope: str = "read"
+    user: Optional[str] = None
+
+    # Worker parameters
+    max_jobs: Optional[int] = None
+    worker_timeout: float = 3600.0
+
+    # Deploy parameters
+    schedule: Optional[str] = None
+    image: Optional[str] = None
+    namespace: Optional[str] = None
+    service_account: Optional[str] = None
+    volume_claim: Optional[str] = None
+    job_project: Optional[str] = None
+    time_zone: Optional[str] = None
+
+    # Completion parameters: the kind of value to list candidates for
+    completion_kind: Optional[str] = None
+
 
 class Command(ABC):
     """Abstract base class for commands."""
@@ -1,18 +1,217 @@
 """Concrete command implementations for Paddi CLI."""
 
+import getpass
 import json
 import logging
+import os
+import sqlite3
+import sys
+import time
+from dataclasses import asdict, replace
+from functools import partial
 from pathlib import Path
+from typing import Any, Callable, Dict, List, Optional
 
+from app.benchmark.runner import DEFAULT_SIZES, Benchmark, format_results
 from app.collector.agent_collector import main as collector_main
-from app.common.exceptions import AuthenticationError, CollectionError, PaddiException
+from app.collector.cache import CollectionCache, file_digest
+from app.collector.terraform_collector import TerraformCollector
+from app.common.artifact_crypto import load_artifact
+from app.common.artifact_schemas import (
+    ARTIFACTS,
+    artifact_name,
+    artifact_schema,
+    export_schemas,
+    validate_file,
+)
+from app.common.auth import load_adc, run_adc_login, validate_project_access
+from app.common.diagnostics import create_debug_bundle, default_debug_bundle_path
+from app.common.exceptions import (
+    AgentError,
+    AuthenticationError,
+    CollectionError,
+    ConfigurationError,
+    DataValidationError,
+    FindingsThresholdError,
+    NewFindingsError,
+    PaddiException,
+    PartialResultsError,
+    PluginError,
+    ProjectsFailedError,
+    QueryError,
+)
+from app.common.exit_codes import ExitCode, exit_code_for
+from app.common.failures import FailureLog, StageFailure, format_failures, write_failures
+from app.common.i18n import tr
+from app.common.models import SEVERITY_ORDER, severity_rank
+from app.common.process_limits import ProcessLimits
+from app.common.resource_usage import ResourceUsageLog, format_usage
+from app.common.self_update import SelfUpdater
+from app.config.encryption import encrypt_file, sensitive_values
+from app.config.paddi_config import DEFAULT_CONFIG_PATH, PaddiConfig, tomllib
+from app.config.schema import Diagnostic
+from app.config.secrets import check_secret_name, delete_secret, set_secret
+from app.config.validation import diagnose_config, format_diagnostic, validate_config
+from app.config.writer import parse_value, set_config_value, toml_value, update_toml_text
+from app.deploy.cloudrun import (
+    DEFAULT_TIME_ZONE,
+    CloudRunJobOptions,
+    build_job,
+    build_setup_script,
+    render_job,
+    write_deployment,
+)
+from app.deploy.kubernetes import (
+    PLACEHOLDER,
+    CronJobOptions,
+    build_manifests,
+    render_manifests,
+    write_manifests,
+)
+from app.deploy.schedule import DEFAULT_NAME, secret_env_vars
+from app.distributed.broker import RedisBroker
+from app.distributed.worker import Coordinator, Worker
+from app.drift.detector import detect_drift, format_drift_table
 from app.explainer.agent_explainer import main as explainer_main
+from app.export.github_issues import GitHubIssuesExporter
+from app.export.ocsf import write_events as write_ocsf_events
+from app.export.pr_comment import PRCommentExporter, comment_body
+from app.export.scc import SCCExporter
+from app.export.splunk_hec import SplunkHECExporter
+from app.findings.dedup import deduplicate_findings
+from app.findings.delta import findings_delta
+from app.findings.fingerprint import compute_fingerprint
+from app.findings.listing import (
+    SUMMARY_TOP_FINDINGS,
+    filter_findings,
+    format_summary,
+    format_table,
+    iter_findings,
+    load_findings,
+    paginate,
+    severity_counts,
+    sort_findings,
+)
+from app.findings.ownership import OwnershipRules
+from app.findings.query import Query, describe_error
+from app.findings.risk import RiskModel
+from app.findings.severity_overrides import SeverityOverrides
+from app.findings.suppressions import Suppressions
+from app.findings.tags import TagRules, load_finding_tags, tag_findings
+from app.history.baseline import BaselineComparison, compare_to_baseline, format_comparison
+from app.history.environment import cli_version
+from app.history.estimates import StageEstimates, finish_time, format_seconds
+from app.history.manifest import (
+    RunManifest,
+    format_manifest,
+    format_manifests,
+    load_manifest,
+    load_manifests,
+)
+from app.history.retention import RetentionPolicy, RetentionResult, apply_retention
+from app.history.sql import describe_tables, format_result, run_query
+from app.history.store import HISTORY_DB_NAME, RunStore
+from app.history.trends import compute_trends, format_trends
+from app.integrations.github_app import (
+    DEFAULT_FAIL_ON_SEVERITY,
+    CheckRunPublisher,
+    check_conclusion,
+    check_output,
+)
+from app.notifications.datadog import DatadogNotifier
+from app.notifications.pubsub import PubSubPublisher
+from app.notifications.webhooks import WebhookNotifier
+from app.plugins.exec_plugins import discover_exec_plugins
+from app.plugins.wasm_host import WasmPluginHost
+from app.remediation.gcloud_script import build_script, write_script
 from app.reporter.agent_reporter import main as reporter_main
+from app.reporter.comparison import compare_runs, write_comparison_report
+from app.reporter.consolidated import build_consolidated_report, write_consolidated_report
+from app.reporter.site import build_site
+from app.server.browser import ReportBrowser
+from app.server.events import QUEUE_STATE_NAME, AuditJobs
+from app.server.access import AccessPolicy
+from app.server.http_server import ServerSettings, create_server
+from app.server.tokens import TOKENS_DB_NAME, ApiToken, TokenStore
+from app.storage.archive import (
+    ARCHIVE_SUFFIX,
+    archive_dir,
+    create_archive,
+    default_archive_path,
+    extract_archive,
+    verify_archive,
+)
+from app.storage.bundle import default_bundle_path, export_bundle, import_bundle
+from app.storage.factory import StorageFactory
 
+from . import theme
 from .base import Command, CommandContext
+from .completion import candidates as completion_candidates
+from .completion import completion_script
+from .init_wizard import InitWizard
+from .multi_project import (
+    FAILED,
+    PASSED,
+    SKIPPED,
+    ProjectResult,
+    aggregate_exit_code,
+    format_project_results,
+    split_projects,
+)
+from .output import echo, emit, is_structured
+from .progress import TaskProgress
 
 logger = logging.getLogger(__name__)
 
+# Schema errors logged before a stage stops; 'paddi validate-data' prints them all
+MAX_LOGGED_ERRORS = 10
+# Stages of 'paddi audit', in the order they run
+AUDIT_STAGES = ("collect", "explain", "report")
+
+
+def describe_retention(result: RetentionResult, dry_run: bool = False) -> str:
+    """Summarize what a retention pass deleted."""
+    verb = "would delete" if dry_run else "deleted"
+    return (
+        f"{verb} {len(result.deleted_runs)} run(s) and {len(result.deleted_archives)} "
+        f"archive(s), {result.freed_bytes} bytes"
+    )
+
+
+def reported_findings(findings: List[dict], data_dir: str = "data") -> List[dict]:
+    """Apply severity overrides, suppressions, owners, and tags, merging duplicates, as reports do.
+
+    Tags that users put on findings are read from the run store under data_dir.
+    """
+    result = Suppressions.from_config().apply(SeverityOverrides.from_config().apply(findings))
+    return tag_findings(
+        deduplicate_findings(OwnershipRules.from_config().assign(result.findings)), data_dir
+    )
+
+
+def parse_query(text: Optional[str]) -> Optional[Query]:
+    """Parse a --query expression, logging where it is invalid."""
+    if not text:
+        return None
+    try:
+        return Query(str(text))
+    except QueryError as e:
+        message, caret = describe_error(e)
+        logger.error(theme.message("error", "Invalid query: %s"), message)
+        logger.error("   %s", text)
+        logger.error("   %s", caret)
+        raise
+
+
+def baseline_comparison(
+    findings: List[dict], data_dir: str = "data"
+) -> Optional[BaselineComparison]:
+    """Compare findings with the baseline run in the history store, if one is set."""
+    history = Path(data_dir) / HISTORY_DB_NAME
+    if not history.exists():
+        return None
+    return compare_to_baseline(RunStore(history), findings)
+
 
 class InitCommand(Command):
     """Initialize Paddi with sample data."""
@@ -27,7 +226,10 @@ class InitCommand(Command):
 
     def execute(self, context: CommandContext) -> None:
         """Execute init command."""
-        logger.info("🚀 Welcome to Paddi!")
+        logger.info(theme.message("welcome", "Welcome to Paddi!"))
+
+        if context.interactive:
+            self._run_wizard(context)
 
         # Ensure directories exist
         Path("data").mkdir(exist_ok=True)
@@ -56,14 +258,25 @@ class InitCommand(Command):
                 ],
             }
             sample_data_path.write_text(json.dumps(sample_data, indent=2), encoding="utf-8")
-            logger.info("✅ Created sample data")
+            logger.info(theme.message("success", "Created sample data"))
 
         if not context.skip_run:
             logger.info("Running full audit pipeline with sample data...")
             audit_cmd = AuditCommand()
             audit_cmd.execute(context)
         else:
-            logger.info("✅ Paddi initialized. Run 'python main.py audit' to start.")
+            logger.info(
+                theme.message("success", "Paddi initialized. Run 'python main.py audit' to start.")
+            )
+
+    def _run_wizard(self, context: CommandContext) -> None:
+        """Ask for setup choices, write paddi.toml, and use them for the first audit."""
+        wizard = InitWizard()
+        answers = wizard.run()
+        path = Path(context.config_file or DEFAULT_CONFIG_PATH)
+        if wizard.write(answers, path):
+            logger.info(theme.message("success", "Wrote %s"), path)
+        answers.apply(context)
 
 
 class CollectCommand(Command):
@@ -79,33 +292,71 @@ class CollectCommand(Command):
 
     def execute(self, context: CommandContext) -> None:
         """Execute collect command."""
-        logger.info("📥 Collecting cloud configuration data...")
+        logger.info(theme.message("collect", "Collecting cloud configuration data..."))
+
+        options = {}
+        if context.terraform_file:
+            options["terraform"] = context.terraform_file
+        if context.incremental:
+            options["incremental"] = True
+
+        config = PaddiConfig.load()
+        cache = CollectionCache.from_config(config)
+        key = cache.key(
+            {
+                "project_id": context.project_id,
+                "organization_id": context.organization_id,
+                "use_mock": context.use_mock,
+                "collect_all": context.collect_all,
+                "terraform": file_digest(context.terraform_file),
+                "collector": config.section("collector"),
+                # A collection cached before encryption was set up is not reused
+                "encryption": config.section("secrets.encryption"),
+            }
+        )
+        output_path = Path("data") / "collected.json"
+        cached = None if context.no_cache else cache.lookup(key)
+        if cached is not None:
+            cache.restore(cached, output_path)
+            logger.warning(
+                theme.message(
+                    "warning",
+                    "Using the collection cached %d min ago (--no-cache to collect again)",
+                ),
+                cached.age_seconds // 60,
+            )
+            return
 
         try:
+            started = time.time()
             collector_main(
                 project_id=context.project_id,
                 organization_id=context.organization_id,
                 use_mock=context.use_mock,
                 collect_all=context.collect_all,
                 verbose=context.verbose,
+                **options,
             )
+            # Only a file written by this collection is cached
+            if output_path.exists() and output_path.stat().st_mtime >= started - 1:
+                cache.store(key, output_path)
         except AuthenticationError as e:
-            logger.error("\n❌ %s", e.message)
+            logger.error(theme.message("error", "\n%s"), e.message)
             if e.details.get("solution"):
-                logger.info("\n💡 解決方法: %s", e.details["solution"])
+                logger.info(theme.message("hint", "\nSolution: %s"), e.details["solution"])
             raise
         except CollectionError as e:
-            logger.error("\n❌ %s", e.message)
+            logger.error(theme.message("error", "\n%s"), e.message)
             if e.details.get("error_type"):
-                logger.debug("エラータイプ: %s", e.details["error_type"])
+                logger.debug(tr("Error type: %s"), e.details["error_type"])
             raise
         except PaddiException as e:
-            logger.error("\n❌ エラー: %s", e.message)
+            logger.error(theme.message("error", "\nError: %s"), e.message)
             raise
         except Exception as e:
-            logger.error("\n❌ 予期しないエラーが発生しました")
-            logger.debug("詳細: %s", str(e))
-            raise
+            logger.error(theme.message("error", "\nAn unexpected error occurred"))
+            logger.debug(tr("Details: %s"), str(e))
+            raise AgentError("collector", {"error": str(e)}) from e
 
 
 class ExplainCommand(Command):
@@ -121,16 +372,37 @@ class ExplainCommand(Command):
 
     def execute(self, context: CommandContext) -> None:
         """Execute explain command."""
-        logger.info("🔍 Analyzing security risks...")
+        logger.info(theme.message("analyze", "Analyzing security risks..."))
+        _check_artifact(Path("data/collected.json"), "collected")
 
-        explainer_main(
-            project_id=context.project_id,
-            location=context.location,
-            use_mock=context.use_mock,
-            ai_provider=context.ai_provider,
-            ollama_model=context.ollama_model,
-            ollama_endpoint=context.ollama_endpoint,
-        )
+        try:
+            explainer_main(
+                project_id=context.project_id,
+                location=context.location,
+                use_mock=context.use_mock,
+                ai_provider=context.ai_provider,
+                ollama_model=context.ollama_model,
+                ollama_endpoint=context.ollama_endpoint,
+            )
+        except PaddiException:
+            raise
+        except Exception as e:
+            raise AgentError("explainer", {"error": str(e)}) from e
+        self._publish_new_findings(context)
+
+    def _publish_new_findings(self, context: CommandContext, data_dir: str = "data") -> None:
+        """Publish findings new since the last run when ``[notifications.pubsub]`` is set."""
+        publisher = PubSubPublisher.from_config(project_id=context.project_id)
+        if publisher is None:
+            return
+
+        try:
+            findings = load_findings(str(Path(data_dir) / "explained.json"))
+            findings = reported_findings(findings, data_dir)
+            publisher.publish_new(findings, Path(data_dir), context.project_id)
+        except Exception as e:
+            # Analysis results are already written, so a failed publish does not fail the run
+            logger.error(theme.message("error", "Pub/Sub publishing failed: %s"), e)
 
 
 class ReportCommand(Command):
@@ -146,14 +418,119 @@ class ReportCommand(Command):
 
     def execute(self, context: CommandContext) -> None:
         """Execute report command."""
-        logger.info("📝 Generating audit report...")
+        if context.compare:
+            self._compare(context)
+            return
+
+        failures = self.generate(context)
+        if context.continue_on_error and failures:
+            _write_failure_summary(failures, context.output_dir)
+            raise PartialResultsError(len(failures))
+
+    def generate(self, context: CommandContext) -> List[StageFailure]:
+        """Generate the reports, returning the steps that failed."""
+        logger.info(theme.message("report", "Generating audit report..."))
+        # With --continue-on-error, the reporter skips extra inputs that do not load instead
+        checked = [] if context.continue_on_error else _paths(context.inputs)
+        for path in [Path("data/explained.json"), *checked]:
+            _check_artifact(path, "explained")
+
+        options = {}
+        if context.inputs:
+            options["inputs"] = context.inputs
+        if context.formats:
+            options["formats"] = context.formats
+        if context.framework:
+            options["framework"] = context.framework
+        if context.tag:
+            options["tag"] = context.tag
+        if parse_query(context.query):
+            options["query"] = str(context.query)
+        if context.continue_on_error:
+            options["continue_on_error"] = True
+        return reporter_main(output_dir=context.output_dir, **options) or []
+
+    def _compare(self, context: CommandContext, data_dir: str = "data") -> None:
+        """Report what changed between two recorded runs (--compare A B)."""
+        run_ids = split_projects(context.compare)
+        if len(run_ids) != 2 or not all(run_id.isdigit() for run_id in run_ids):
+            raise ValueError(f"--compare takes two run IDs, got {context.compare!r}")
+
+        history = Path(data_dir) / HISTORY_DB_NAME
+        if not history.exists():
+            raise FileNotFoundError(f"Run history not found: {history}")
+        comparison = compare_runs(RunStore(history), int(run_ids[0]), int(run_ids[1]))
+        formats = [fmt.strip() for fmt in str(context.formats or "").split(",") if fmt.strip()]
+        written = write_comparison_report(comparison, Path(context.output_dir), formats)
+
+        if is_structured(context.output_format):
+            emit(comparison.to_dict(), context.output_format)
+            return
+        echo(
+            theme.message(
+                "summary",
+                f"{len(comparison.new)} new, {len(comparison.resolved)} resolved, "
+                f"{len(comparison.regressed)} regressed since run #{run_ids[0]}",
+            )
+        )
+        for path in written:
+            logger.info(theme.message("report", "Comparison report: %s"), path)
+
+
+def _paths(value) -> List[Path]:
+    """Split a list or comma-separated string of paths."""
+    if isinstance(value, str):
+        value = value.split(",")
+    return [Path(str(path).strip()) for path in value or [] if str(path).strip()]
+
+
+def _manifest_inputs(context: CommandContext) -> Dict[str, Any]:
+    """Return the options of an audit that decide its results, for the run manifest."""
+    return {
+        "project_id": context.project_id,
+        "organization_id": context.organization_id,
+        "use_mock": context.use_mock,
+        "ai_provider": context.ai_provider,
+        "location": context.location,
+        "terraform_file": context.terraform_file,
+        "incremental": context.incremental,
+        "no_cache": context.no_cache,
+        "formats": context.formats,
+        "output_dir": context.output_dir,
+        "continue_on_error": context.continue_on_error,
+    }
 
-        reporter_main(output_dir=context.output_dir)
+
+def _write_failure_summary(failures: List[StageFailure], output_dir: str) -> None:
+    """Write failures.json and print which steps failed (--continue-on-error)."""
+    path = write_failures(failures, Path(output_dir))
+    logger.warning(
+        theme.message("warning", "%d step(s) failed, so the results are partial:"), len(failures)
+    )
+    print(format_failures(failures))
+    logger.info(theme.message("hint", "Failure summary written to %s"), path)
+
+
+def _check_artifact(path: Path, name: str) -> None:
+    """Stop before a stage whose input file does not match the artifact's schema."""
+    if not path.exists():
+        return
+    errors = validate_file(path, name)
+    if errors:
+        logger.error(theme.message("error", "%s does not match the %s schema:"), path, name)
+        for error in errors[:MAX_LOGGED_ERRORS]:
+            logger.error("   %s", error)
+        logger.info(theme.message("hint", "Run 'paddi validate-data %s' for every error."), path)
+        raise DataValidationError(str(path), {"errors": errors})
 
 
 class AuditCommand(Command):
     """Run complete audit pipeline."""
 
+    # Called with the name and expected duration of each stage as it starts, e.g. to
+    # advance a progress bar
+    on_stage: Optional[Callable[[str, Optional[float]], None]] = None
+
     @property
     def name(self) -> str:
         return "audit"
@@ -164,38 +541,1980 @@ class AuditCommand(Command):
 
     def execute(self, context: CommandContext) -> None:
         """Execute audit command."""
-        logger.info("🔐 Starting complete security audit...")
+        if context.projects:
+            self._audit_projects(context)
+            return
+
+        manifest = RunManifest.start("audit", AUDIT_STAGES, _manifest_inputs(context))
+        try:
+            self._run(context, manifest)
+        except BaseException as e:
+            manifest.finish(e, int(exit_code_for(e)))
+            raise
+        else:
+            manifest.finish()
+        finally:
+            self._save_manifest(manifest)
+
+    def _run(self, context: CommandContext, manifest: RunManifest) -> None:
+        """Run the pipeline for one project, recording its stages in the manifest."""
+        logger.info(theme.message("audit", "Starting complete security audit..."))
+        estimates = StageEstimates.load(context.project_id)
+        expected = estimates.remaining(list(AUDIT_STAGES))
+        if expected is not None:
+            logger.info(
+                theme.message("hint", "Estimated time: %s, done by about %s (from %d run(s))"),
+                format_seconds(expected),
+                finish_time(expected),
+                estimates.samples,
+            )
 
         try:
-            # Run all steps in sequence
+            threshold = "CRITICAL" if context.fail_on_critical else context.fail_on_severity
+            if threshold and str(threshold).upper() not in SEVERITY_ORDER:
+                raise ConfigurationError(
+                    "--fail-on-severity", {"error": f"use one of {', '.join(SEVERITY_ORDER)}"}
+                )
+
+            # Run all steps in sequence; with --continue-on-error, failures after
+            # collection and analysis are recorded and the remaining steps still run
+            failures = FailureLog(context.continue_on_error)
             collect_cmd = CollectCommand()
             explain_cmd = ExplainCommand()
             report_cmd = ReportCommand()
 
-            logger.info("📥 Collecting cloud configuration data...")
-            collect_cmd.execute(context)
+            usage = ResourceUsageLog()
+
+            logger.info(theme.message("collect", "Collecting cloud configuration data..."))
+            self._stage_started("collect", estimates)
+            with manifest.stage("collect", ["data/collected.json"]), usage.measure("collector"):
+                collect_cmd.execute(context)
 
-            logger.info("🔍 Analyzing security risks...")
-            explain_cmd.execute(context)
+            logger.info(theme.message("analyze", "Analyzing security risks..."))
+            self._stage_started("explain", estimates)
+            with manifest.stage("explain", ["data/explained.json"]), usage.measure("explainer"):
+                explain_cmd.execute(context)
 
-            logger.info("📝 Generating audit report...")
-            report_cmd.execute(context)
+            logger.info(theme.message("report", "Generating audit report..."))
+            self._stage_started("report", estimates)
+            newest_run = self._newest_run_id()
+            with manifest.stage("report", [context.output_dir]), usage.measure("reporter"):
+                failures.extend(report_cmd.generate(context))
+            recorded_run = self._newest_run_id()
 
-            logger.info("✅ Audit complete! Check %s/ for results.", context.output_dir)
+            manifest.history_run_id = self._record_resource_usage(usage)
+            comparison = self._log_risk_summary(usage=usage)
+            if context.verbose and not is_structured():
+                print()
+                echo(theme.message("summary", "Resource usage:"))
+                print(format_usage(usage.agents))
+            self._notify(failures, recorded_run if recorded_run > newest_run else None)
+            failures.run("notify", "github_app", self._publish_check_run, context)
+            self._upload_artifacts(context, failures=failures)
+            failures.run("retention", "data", self._apply_retention)
+            logger.info(
+                theme.message("success", "Audit complete! Check %s/ for results."),
+                context.output_dir,
+            )
+            manifest.failures = [failure.to_dict() for failure in failures.failures]
+            partial = context.continue_on_error and len(failures) > 0
+            if partial:
+                _write_failure_summary(failures.failures, context.output_dir)
+            if context.fail_on_new:
+                self._check_new_findings(comparison)
+            if threshold:
+                self._check_severity_threshold(str(threshold))
+            if partial:
+                raise PartialResultsError(len(failures))
         except AuthenticationError as e:
-            logger.error("\n❌ %s", e.message)
+            logger.error(theme.message("error", "\n%s"), e.message)
             if e.details.get("solution"):
-                logger.info("\n💡 解決方法: %s", e.details["solution"])
+                logger.info(theme.message("hint", "\nSolution: %s"), e.details["solution"])
             raise
         except CollectionError as e:
-            logger.error("\n❌ %s", e.message)
+            logger.error(theme.message("error", "\n%s"), e.message)
             if e.details.get("error_type"):
-                logger.debug("エラータイプ: %s", e.details["error_type"])
+                logger.debug(tr("Error type: %s"), e.details["error_type"])
             raise
         except PaddiException as e:
-            logger.error("\n❌ エラー: %s", e.message)
+            logger.error(theme.message("error", "\nError: %s"), e.message)
             raise
         except Exception as e:
-            logger.error("\n❌ 予期しないエラーが発生しました")
-            logger.debug("詳細: %s", str(e))
+            logger.error(theme.message("error", "\nAn unexpected error occurred"))
+            logger.debug(tr("Details: %s"), str(e))
+            raise
+
+    def _stage_started(self, stage: str, estimates: StageEstimates) -> None:
+        """Show when a stage is expected to finish, from the durations of earlier runs."""
+        expected = estimates.stage(stage)
+        if expected is not None:
+            logger.info(
+                theme.message("hint", "Usually takes %s; expected to finish at %s"),
+                format_seconds(expected),
+                finish_time(expected),
+            )
+        if self.on_stage is not None:
+            self.on_stage(stage, expected)
+
+    def _audit_projects(self, context: CommandContext) -> None:
+        """Audit each project of --projects, writing its reports to <output_dir>/<project>.
+
+        Later projects are still audited after one fails, unless --fail-fast is given.
+        On a terminal, each project has a progress bar with its stage and elapsed time.
+        When more than one project passes, a consolidated report of them all is
+        written to <output_dir>.
+        """
+        projects = split_projects(context.projects)
+        results: List[ProjectResult] = []
+        findings_by_project: Dict[str, List[dict]] = {}
+        with TaskProgress.from_config(AUDIT_STAGES) as progress:
+            for project_id in projects:
+                progress.add(project_id)
+            for index, project_id in enumerate(projects, 1):
+                logger.info(
+                    theme.message("audit", "[%d/%d] Auditing %s ..."),
+                    index,
+                    len(projects),
+                    project_id,
+                )
+                project_context = replace(
+                    context,
+                    project_id=project_id,
+                    projects=None,
+                    output_dir=str(Path(context.output_dir) / project_id),
+                )
+                self.on_stage = partial(progress.stage, project_id)
+                try:
+                    self.execute(project_context)
+                except Exception as e:
+                    error = e.message if isinstance(e, PaddiException) else str(e)
+                    results.append(ProjectResult(project_id, FAILED, int(exit_code_for(e)), error))
+                    progress.finish(project_id, FAILED)
+                    if context.fail_fast:
+                        for skipped in projects[index:]:
+                            results.append(ProjectResult(skipped, SKIPPED))
+                            progress.finish(skipped, SKIPPED)
+                        break
+                else:
+                    results.append(ProjectResult(project_id, PASSED, int(ExitCode.SUCCESS)))
+                    progress.finish(project_id, PASSED)
+                    findings_by_project[project_id] = self._project_findings()
+                finally:
+                    self.on_stage = None
+
+        self._report_projects(context, results, findings_by_project)
+
+    def _report_projects(
+        self,
+        context: CommandContext,
+        results: List[ProjectResult],
+        findings_by_project: Dict[str, List[dict]],
+    ) -> None:
+        """Show the per-project results and write the consolidated report.
+
+        Raises:
+            ProjectsFailedError: If any project failed
+        """
+        if len(findings_by_project) > 1:
+            self._write_consolidated_report(context, findings_by_project)
+        exit_code = aggregate_exit_code(results)
+        if is_structured():
+            emit({"projects": [r.to_dict() for r in results], "exit_code": int(exit_code)})
+        else:
+            print()
+            echo(theme.message("summary", "Projects:"))
+            print(format_project_results(results))
+
+        failed = [r for r in results if r.status == FAILED]
+        if failed:
+            raise ProjectsFailedError(
+                len(failed), len(results), exit_code, {"results": [r.to_dict() for r in results]}
+            )
+
+    def _project_findings(self, findings_file: str = "data/explained.json") -> List[dict]:
+        """Return the findings of the project just audited, as its report shows them."""
+        try:
+            return reported_findings(load_findings(findings_file))
+        except FileNotFoundError:
+            return []
+
+    def _write_consolidated_report(
+        self, context: CommandContext, findings_by_project: Dict[str, List[dict]]
+    ) -> None:
+        """Write the report that summarizes all audited projects to <output_dir>."""
+        formats = [fmt.strip() for fmt in str(context.formats or "").split(",") if fmt.strip()]
+        try:
+            written = write_consolidated_report(
+                build_consolidated_report(findings_by_project),
+                Path(context.output_dir),
+                formats,
+            )
+        except (PaddiException, OSError) as e:
+            logger.warning(
+                theme.message("warning", "Could not write the consolidated report: %s"), e
+            )
+            return
+        for path in written:
+            logger.info(theme.message("report", "Consolidated report: %s"), path)
+
+    def _newest_run_id(self, data_dir: str = "data") -> int:
+        """Return the ID of the newest run in the history database, or 0 if there is none."""
+        history = Path(data_dir) / HISTORY_DB_NAME
+        latest = RunStore(history).latest_run() if history.exists() else None
+        return latest.run_id if latest else 0
+
+    def _notify(
+        self, failures: FailureLog, run_id: Optional[int], data_dir: str = "data"
+    ) -> None:
+        """Send the run to the configured webhooks and Datadog, recording failed deliveries.
+
+        Args:
+            run_id: The run the report recorded; None if it recorded none, because
+                history is off or the findings did not change, so webhooks are not sent
+                the previous delta again
+        """
+        notifiers: Dict[str, Callable[[], Dict[str, bool]]] = {}
+        webhooks = WebhookNotifier.from_config()
+        if run_id is not None:
+            notifiers["webhooks"] = partial(webhooks.notify_run, Path(data_dir), run_id)
+        elif webhooks.webhooks:
+            logger.info("No new run was recorded, so webhooks are not notified")
+        datadog = DatadogNotifier.from_config()
+        if datadog is not None:
+            notifiers["datadog"] = partial(datadog.notify_run, Path(data_dir))
+        for name, notify in notifiers.items():
+            results = failures.run("notify", name, notify)
+            for target, delivered in (results or {}).items():
+                if not delivered:
+                    failures.record("notify", f"{name}/{target}", "delivery failed")
+
+    def _publish_check_run(self, context: CommandContext) -> None:
+        """Create a Check Run with the findings, if ``[integrations.github_app]`` is enabled."""
+        publisher = CheckRunPublisher.from_config()
+        if publisher is None:
+            return
+        check_run = publisher.publish(self._project_findings(), context.project_id)
+        logger.info(
+            theme.message("success", "Check run created: %s"),
+            check_run.get("html_url") or check_run["id"],
+        )
+
+    def _apply_retention(self) -> None:
+        """Delete runs and archives beyond the ``[retention]`` limits, if any are set."""
+        policy = RetentionPolicy.from_config()
+        if policy.enabled:
+            result = apply_retention(policy, Path("data"), archive_dir())
+            if result.deleted_runs or result.deleted_archives:
+                logger.info(theme.message("summary", "Retention: %s"), describe_retention(result))
+
+    def _check_new_findings(self, comparison: Optional[BaselineComparison]) -> None:
+        """Fail the audit if there are findings that are not in the baseline (--fail-on-new)."""
+        if comparison is None:
+            logger.warning(
+                theme.message("warning", "No baseline is set, so --fail-on-new has nothing to gate")
+            )
+            logger.info(theme.message("hint", "Run 'python main.py baseline set' to accept a run."))
+            return
+        if comparison.new:
+            raise NewFindingsError(
+                len(comparison.new), {"baseline_run_id": comparison.baseline.run_id}
+            )
+
+    def _check_severity_threshold(
+        self, severity: str, findings_file: str = "data/explained.json"
+    ) -> None:
+        """Fail the audit if findings are at or above the severity (--fail-on-severity)."""
+        severity = severity.upper()
+        try:
+            findings = reported_findings(load_findings(findings_file))
+        except FileNotFoundError:
+            return
+        failing = [
+            f for f in findings if severity_rank(f.get("severity", "")) <= severity_rank(severity)
+        ]
+        if failing:
+            raise FindingsThresholdError(len(failing), severity, {"severity": severity})
+
+    def _record_resource_usage(
+        self, usage: ResourceUsageLog, data_dir: str = "data"
+    ) -> Optional[int]:
+        """Record the resource usage of the agents with the run the report recorded.
+
+        Returns:
+            The ID of that run in the history database, if there is one
+        """
+        history = Path(data_dir) / HISTORY_DB_NAME
+        if not history.exists():
+            return None
+        store = RunStore(history)
+        latest = store.latest_run()
+        if latest is None:
+            return None
+        store.record_resource_usage(latest.run_id, usage.to_list())
+        return latest.run_id
+
+    def _save_manifest(self, manifest: RunManifest) -> None:
+        """Write the run manifest; failing to write it does not fail the audit."""
+        try:
+            path = manifest.save()
+        except OSError as e:
+            logger.warning("Could not write the run manifest: %s", e)
+            return
+        logger.info(theme.message("summary", "Run manifest: %s"), path)
+
+    def _log_risk_summary(
+        self, findings_file: str = "data/explained.json", usage: Optional[ResourceUsageLog] = None
+    ) -> Optional[BaselineComparison]:
+        """Print the findings summary, the project risk grade, and the riskiest resources.
+
+        When a baseline is set, only the deviations from it are listed. Returns the
+        comparison with the baseline, if one is set. The structured summary also
+        has the resource usage of the agents, when given.
+        """
+        try:
+            findings = load_findings(findings_file)
+        except FileNotFoundError:
+            return None
+
+        findings = reported_findings(findings)
+        risk = RiskModel.from_config().summarize(findings)
+        comparison = baseline_comparison(findings)
+        if is_structured():
+            emit(
+                {
+                    "total": len(findings),
+                    "severity_counts": severity_counts(findings),
+                    "risk_score": risk.score,
+                    "risk_grade": risk.grade,
+                    "top_resources": [
+                        {"resource": r.resource, "score": r.score} for r in risk.resources[:3]
+                    ],
+                    "top_findings": [
+                        {
+                            "severity": f.get("severity"),
+                            "title": f.get("title"),
+                            "category": f.get("category"),
+                            "resource": f.get("resource"),
+                        }
+                        for f in sort_findings(findings)[:SUMMARY_TOP_FINDINGS]
+                    ],
+                    "baseline": comparison.to_dict() if comparison else None,
+                    "resource_usage": usage.to_list() if usage else None,
+                }
+            )
+            return comparison
+
+        print()
+        if comparison:
+            print(format_comparison(comparison, severity_style=theme.severity))
+        else:
+            print(format_summary(findings, severity_style=theme.severity))
+        print()
+        logger.info(theme.message("summary", "Risk grade: %s (score %s)"), risk.grade, risk.score)
+        for resource in risk.resources[:3]:
+            logger.info("   %6.2f  %s", resource.score, resource.resource)
+        return comparison
+
+    def _upload_artifacts(
+        self, context: CommandContext, data_dir: str = "data", failures: Optional[FailureLog] = None
+    ) -> None:
+        """Upload the run's data files and reports to each configured ``[storage.*]`` backend."""
+        for storage in StorageFactory.from_config():
+            logger.info(
+                theme.message("upload", "Uploading artifacts to %s ..."),
+                storage.uri("").rstrip("/"),
+            )
+            try:
+                uploaded = storage.upload_run(
+                    Path(data_dir), Path(context.output_dir), context.project_id
+                )
+            except Exception as e:
+                # Local results are already written, so a failed upload does not fail the audit
+                logger.error(
+                    theme.message("error", "Artifact upload to %s failed: %s"),
+                    storage.get_name(),
+                    e,
+                )
+                if failures is not None:
+                    failures.record("upload", storage.get_name(), e)
+                continue
+
+            for artifact in uploaded:
+                print(f"  {artifact.uri}")
+                if artifact.signed_url:
+                    print(f"    {artifact.signed_url}")
+
+
+class ConfigCommand(Command):
+    """Show or validate paddi.toml."""
+
+    @property
+    def name(self) -> str:
+        return "config"
+
+    @property
+    def description(self) -> str:
+        return (
+            "Show or validate paddi.toml, or manage keyring secrets "
+            "(config show | get <key> | set <key> <value> | validate | encrypt | "
+            "set-secret <name> | delete-secret <name>)"
+        )
+
+    def execute(self, context: CommandContext) -> None:
+        """Execute config command."""
+        action = context.action or "show"
+        if action == "show":
+            self._show(context)
+        elif action == "get":
+            self._get(context)
+        elif action == "set":
+            self._set(context)
+        elif action == "validate":
+            self._validate(context)
+        elif action == "encrypt":
+            self._encrypt(context)
+        elif action == "set-secret":
+            self._set_secret(context)
+        elif action == "delete-secret":
+            delete_secret(context.target)
+            echo(theme.message("success", f"Removed {context.target} from the keyring"))
+        else:
+            raise ValueError(f"Unknown config action: {action}")
+
+    def _show(self, context: CommandContext) -> None:
+        """Print the loaded configuration."""
+        config = PaddiConfig.load(context.config_file, strict=False)
+        path = str(config.path) if config.path else None
+
+        if is_structured(context.output_format):
+            emit({"path": path, "config": config.data}, context.output_format)
+            return
+
+        if path is None:
+            print("No paddi.toml found; using built-in defaults")
+            return
+        print(f"# {path}")
+        for key, value in _flatten(config.data):
+            print(f"{key} = {json.dumps(value, ensure_ascii=False)}")
+
+    def _get(self, context: CommandContext) -> None:
+        """Print one value (or table) by dotted key, with secret references as written."""
+        key = context.target
+        value = (
+            PaddiConfig.load(context.config_file, strict=False).get(key, resolve=False)
+            if key
+            else None
+        )
+        if value is None:
+            raise ConfigurationError(key or "config get", {"error": "not set"})
+
+        if is_structured(context.output_format):
+            emit({"key": key, "value": value}, context.output_format)
+        elif isinstance(value, dict):
+            for dotted, item in _flatten(value, f"{key}."):
+                print(f"{dotted} = {json.dumps(item, ensure_ascii=False)}")
+        else:
+            print(value if isinstance(value, str) else toml_value(value))
+
+    def _set(self, context: CommandContext) -> None:
+        """Set one value by dotted key, keeping its type and the file's other contents."""
+        key = context.target
+        if not key or context.config_value is None:
+            raise ConfigurationError(
+                "config set", {"error": "usage: paddi config set <section.key> <value>"}
+            )
+        path = _config_path(context)
+        config = (
+            PaddiConfig.load(str(path), strict=False) if path.exists() else PaddiConfig(path=path)
+        )
+        text = path.read_text(encoding="utf-8") if path.exists() else ""
+
+        current = config.get(key, resolve=False)
+        if isinstance(current, dict):
+            raise ConfigurationError(key, {"error": "is a table; set one of its keys instead"})
+        value = parse_value(context.config_value, current)
+
+        # Refuse values the loaders would reject, without blaming pre-existing errors
+        candidate = PaddiConfig(tomllib.loads(update_toml_text(text, key, value)), path)
+        before = validate_config(config, context.project_id)
+        errors = [e for e in validate_config(candidate, context.project_id) if e not in before]
+        if errors:
+            raise ConfigurationError(key, {"error": "; ".join(errors)})
+
+        set_config_value(path, key, value)
+        echo(theme.message("success", f"Set {key} = {toml_value(value)} in {path}"))
+
+    def _validate(self, context: CommandContext) -> None:
+        """Check the configuration against the schema and with the loaders that read it."""
+        path = context.config_file
+        text = ""
+        try:
+            config = PaddiConfig.load(context.config_file, strict=False)
+            path = str(config.path) if config.path else None
+            text = config.path.read_text(encoding="utf-8") if config.path else ""
+            diagnostics = diagnose_config(config, context.project_id)
+        except ConfigurationError as e:
+            diagnostics = [Diagnostic(e.config_item, e.details.get("error", e.message))]
+        errors = [str(diagnostic) for diagnostic in diagnostics]
+
+        if is_structured(context.output_format):
+            emit(
+                {
+                    "path": path,
+                    "valid": not errors,
+                    "errors": errors,
+                    "diagnostics": [asdict(diagnostic) for diagnostic in diagnostics],
+                },
+                context.output_format,
+            )
+        elif errors:
+            summary = f"{path or 'paddi.toml'} has {len(errors)} error(s):"
+            echo(theme.message("error", summary, color=True))
+            for diagnostic in diagnostics:
+                print()
+                for line in format_diagnostic(diagnostic, path, text):
+                    print(f"  {line}")
+        else:
+            echo(theme.message("success", f"{path or 'Built-in defaults'} is valid", color=True))
+
+        if errors:
+            raise ConfigurationError(path or "paddi.toml", {"error": f"{len(errors)} error(s)"})
+
+    def _encrypt(self, context: CommandContext) -> None:
+        """Encrypt the plaintext secret fields of the config file in place."""
+        path = _config_path(context)
+        keys = encrypt_file(path)
+        if not keys:
+            echo(theme.message("success", f"{path} has no plaintext secrets"))
+            return
+        for key in keys:
+            print(f"  - {key}")
+        echo(theme.message("success", f"Encrypted {len(keys)} field(s) in {path}", color=True))
+
+    def _set_secret(self, context: CommandContext) -> None:
+        """Read a secret without echoing it and store it in the OS keyring."""
+        check_secret_name(context.target)
+        if sys.stdin.isatty():
+            value = getpass.getpass(f"Value for {context.target}: ")
+        else:
+            value = sys.stdin.readline().strip()
+        set_secret(context.target, value)
+        echo(theme.message("success", f"Stored {context.target} in the OS keyring"))
+
+
+def _config_path(context: CommandContext) -> Path:
+    """Return the config file a command should write to."""
+    return Path(context.config_file or os.getenv("PADDI_CONFIG") or DEFAULT_CONFIG_PATH)
+
+
+def _flatten(data: dict, prefix: str = "") -> list:
+    """Flatten nested tables into ``(dotted key, value)`` pairs."""
+    items = []
+    for key, value in data.items():
+        dotted = f"{prefix}{key}"
+        if isinstance(value, dict) and value:
+            items.extend(_flatten(value, f"{dotted}."))
+        else:
+            items.append((dotted, value))
+    return items
+
+
+class LoginCommand(Command):
+    """Set up GCP Application Default Credentials for Paddi."""
+
+    @property
+    def name(self) -> str:
+        return "login"
+
+    @property
+    def description(self) -> str:
+        return "Check GCP credentials, log in with gcloud if needed, and save the project"
+
+    def execute(self, context: CommandContext) -> None:
+        """Execute login command."""
+        try:
+            credentials, adc_project = load_adc()
+        except AuthenticationError:
+            logger.info("No Application Default Credentials found; launching gcloud login...")
+            run_adc_login()
+            credentials, adc_project = load_adc()
+
+        config = PaddiConfig.load(context.config_file)
+        project_id = context.project_id or config.get("general.project_id") or adc_project
+        if not project_id:
+            raise ConfigurationError(
+                "general.project_id", {"error": "no project found; pass --project-id"}
+            )
+
+        display_name = validate_project_access(credentials, project_id)
+        logger.info(
+            theme.message("success", "Credentials can access %s (%s)"), project_id, display_name
+        )
+
+        path = config.path or Path(context.config_file or DEFAULT_CONFIG_PATH)
+        set_config_value(Path(path), "general.project_id", project_id)
+        logger.info(theme.message("success", "Saved project %s to %s"), project_id, path)
+
+
+class PluginsCommand(Command):
+    """List or run external plugins."""
+
+    @property
+    def name(self) -> str:
+        return "plugins"
+
+    @property
+    def description(self) -> str:
+        return "List or run external plugins (plugins list | plugins run <name>)"
+
+    def execute(self, context: CommandContext) -> None:
+        """Execute plugins command."""
+        action = context.action or "list"
+        if action == "list":
+            self._list()
+        elif action == "run":
+            self._run(context)
+        else:
+            raise ValueError(f"Unknown plugins action: {action}")
+
+    def _list(self) -> None:
+        """Print discovered exec and WASM plugins."""
+        exec_plugins = discover_exec_plugins()
+        wasm_plugins = WasmPluginHost.from_config().discover()
+
+        if not exec_plugins and not wasm_plugins:
+            print("No plugins found")
+            return
+
+        echo(theme.message("plugins", "\nInstalled Plugins:"))
+        print("=" * 60)
+        for name, plugin in sorted(exec_plugins.items()):
+            print(f"  {name:<20} exec  {plugin.path}")
+        for path in wasm_plugins:
+            print(f"  {path.stem:<20} wasm  {path}")
+
+    def _run(self, context: CommandContext) -> None:
+        """Run a single exec plugin and print its JSON response."""
+        if not context.target:
+            raise ValueError("Plugin name is required: plugins run <name>")
+
+        plugins = discover_exec_plugins()
+        plugin = plugins.get(context.target)
+        if plugin is None:
+            logger.error(
+                theme.message("error", "\nPlugin not found on PATH: paddi-plugin-%s"),
+                context.target,
+            )
+            raise PluginError(context.target, {"error": "not found"})
+
+        payload = {}
+        input_path = Path(context.input_file)
+        if input_path.exists():
+            payload = json.loads(input_path.read_text(encoding="utf-8"))
+
+        config = PaddiConfig.load()
+        response = plugin.run(
+            context.plugin_action,
+            payload,
+            config.section(f"plugins.config.{context.target}"),
+            limits=ProcessLimits.from_config(config),
+        )
+        print(json.dumps(response.to_dict(), indent=2, ensure_ascii=False))
+
+
+class FindingsCommand(Command):
+    """Browse findings from explained.json."""
+
+    @property
+    def name(self) -> str:
+        return "findings"
+
+    actions = ("list", "mark-fp", "unmark-fp", "tag", "untag")
+
+    @property
+    def description(self) -> str:
+        return (
+            "List findings with severity/category/resource/tag filters, tag them, or give "
+            "false-positive feedback (findings list | tag <id> | untag <id> | mark-fp <id> | "
+            "unmark-fp <id>)"
+        )
+
+    def execute(self, context: CommandContext) -> None:
+        """Execute findings command."""
+        action = context.action or "list"
+        if action not in self.actions:
+            raise ValueError(
+                f"Unknown findings action: {action} (supported: {', '.join(self.actions)})"
+            )
+        if action in ("mark-fp", "unmark-fp"):
+            self._false_positive_feedback(action, context)
+            return
+        if action in ("tag", "untag"):
+            self._tag(action, context)
+            return
+
+        try:
+            batches = iter_findings(context.input_file)
+        except FileNotFoundError:
+            logger.error(
+                theme.message("error", "\nFindings file not found: %s"),
+                context.input_file,
+            )
+            logger.info(
+                theme.message("hint", "Run 'python main.py audit' first to generate findings.")
+            )
+            raise
+
+        query = parse_query(context.query)
+        rules = TagRules.from_config()
+        stored = load_finding_tags(Path(context.input_file).parent)
+        # Only the matching findings are kept, so large files are filtered in bounded memory
+        findings = []
+        for batch in batches:
+            batch = filter_findings(
+                rules.apply(batch, stored),
+                severity=context.severity,
+                category=context.category,
+                resource=context.resource,
+                min_score=context.min_score,
+                tag=context.tag,
+            )
+            findings.extend(query.filter(batch) if query else batch)
+        matched = sort_findings(findings, by=context.sort_by)
+        page_items, total_pages = paginate(matched, context.page, context.page_size)
+        page = min(max(1, context.page), total_pages)
+
+        if is_structured(context.output_format):
+            emit(
+                {
+                    "total": len(matched),
+                    "page": page,
+                    "pages": total_pages,
+                    "findings": page_items,
+                },
+                context.output_format,
+            )
+            return
+
+        if not matched:
+            print("No findings match the given filters")
+            return
+
+        start_index = (page - 1) * max(1, context.page_size) + 1
+        print(format_table(page_items, start_index, severity_style=theme.severity))
+        print(f"\nPage {page}/{total_pages} ({len(matched)} findings)")
+
+    @staticmethod
+    def _match_fingerprint(action: str, prefix, candidates) -> str:
+        """Return the one candidate fingerprint that starts with the given prefix."""
+        if not prefix:
+            raise ValueError(f"findings {action} requires the finding fingerprint")
+        prefix = str(prefix).lower()
+        matches = [fingerprint for fingerprint in candidates if fingerprint.startswith(prefix)]
+        if len(matches) != 1:
+            problem = "No finding" if not matches else f"{len(matches)} findings"
+            raise ValueError(f"{problem} with fingerprint {prefix}")
+        return matches[0]
+
+    def _false_positive_feedback(self, action: str, context: CommandContext) -> None:
+        """Mark or unmark a finding (by fingerprint or a unique prefix) as a false positive."""
+        store = RunStore(Path(context.input_file).parent / HISTORY_DB_NAME)
+
+        if action == "mark-fp":
+            candidates = {
+                f.get("fingerprint") or compute_fingerprint(f): f
+                for f in load_findings(context.input_file)
+            }
+        else:
+            candidates = {e.fingerprint: e for e in store.false_positives()}
+        fingerprint = self._match_fingerprint(action, context.target, candidates)
+
+        if action == "unmark-fp":
+            store.unmark_false_positive(fingerprint)
+            text = f"{fingerprint} is no longer a false positive"
+            echo(theme.message("success", text, color=True))
+            return
+
+        entry = store.mark_false_positive(
+            candidates[fingerprint], reason=context.reason, marked_by=getpass.getuser()
+        )
+        text = f"Marked {fingerprint} ({entry.title}) as a false positive"
+        echo(theme.message("success", text, color=True))
+        echo(theme.message("hint", "Analysis will leave it out from the next audit on."))
+
+    def _tag(self, action: str, context: CommandContext) -> None:
+        """Add tags to, or remove tags from, a finding (by fingerprint or a unique prefix)."""
+        store = RunStore(Path(context.input_file).parent / HISTORY_DB_NAME)
+        tags = [tag.strip() for tag in str(context.tag or "").split(",") if tag.strip()]
+
+        if action == "tag":
+            if not tags:
+                raise ValueError("findings tag requires --tag (e.g. --tag=pci,accepted-risk)")
+            candidates = [
+                f.get("fingerprint") or compute_fingerprint(f)
+                for f in load_findings(context.input_file)
+            ]
+            fingerprint = self._match_fingerprint(action, context.target, candidates)
+            stored = store.tag_finding(fingerprint, tags, tagged_by=getpass.getuser())
+            text = f"Tagged {fingerprint}: {', '.join(stored)}"
+            echo(theme.message("success", text, color=True))
+            return
+
+        fingerprint = self._match_fingerprint(action, context.target, store.finding_tags())
+        removed = store.untag_finding(fingerprint, tags or None)
+        text = f"Removed {removed} tag(s) from {fingerprint}"
+        echo(theme.message("success", text, color=True))
+
+
+class RemediateCommand(Command):
+    """Generate gcloud remediation commands from findings."""
+
+    @property
+    def name(self) -> str:
+        return "remediate"
+
+    @property
+    def description(self) -> str:
+        return "Generate a gcloud remediation script (output/remediation.sh)"
+
+    def execute(self, context: CommandContext) -> None:
+        """Execute remediate command."""
+        try:
+            findings = load_findings(context.input_file)
+        except FileNotFoundError:
+            logger.error(
+                theme.message("error", "\nFindings file not found: %s"),
+                context.input_file,
+            )
+            logger.info(
+                theme.message("hint", "Run 'python main.py audit' first to generate findings.")
+            )
+            raise
+
+        findings = reported_findings(findings)
+        if context.dry_run:
+            print(build_script(findings), end="")
+            return
+
+        path = write_script(findings, Path(context.output_dir))
+        logger.info(
+            theme.message("success", "Remediation script written to %s. Review it before running."),
+            path,
+        )
+
+
+class DriftCommand(Command):
+    """Compare Terraform with the live configuration."""
+
+    @property
+    def name(self) -> str:
+        return "drift"
+
+    @property
+    def description(self) -> str:
+        return "Report drift between Terraform state and live collected data"
+
+    def execute(self, context: CommandContext) -> None:
+        """Execute drift command."""
+        if not context.terraform_file:
+            raise ValueError("drift requires --terraform=<state or show -json file>")
+
+        try:
+            live = load_artifact(Path(context.input_file))
+        except FileNotFoundError:
+            logger.error(
+                theme.message("error", "\nCollected data not found: %s"),
+                context.input_file,
+            )
+            logger.info(
+                theme.message("hint", "Run 'python main.py collect' first to collect live data.")
+            )
             raise
+
+        iac = TerraformCollector(context.terraform_file).collect()
+        report = detect_drift(iac, live)
+
+        if is_structured(context.output_format):
+            emit(report.to_dict(), context.output_format)
+            return
+
+        if not report.items:
+            print("No drift between Terraform and the live configuration")
+            return
+
+        print(format_drift_table(report))
+        relevant = [i for i in report.unmanaged if i.severity in ("CRITICAL", "HIGH")]
+        print(
+            f"\n{len(report.unmanaged)} unmanaged, {len(report.missing)} missing "
+            f"({len(relevant)} security-relevant)"
+        )
+
+
+class ExportCommand(Command):
+    """Export findings to external trackers and services."""
+
+    targets = ("github-issues", "splunk-hec", "scc", "ocsf", "pr-comment", "check-run")
+
+    @property
+    def name(self) -> str:
+        return "export"
+
+    @property
+    def description(self) -> str:
+        return "Export findings to external services (export --to <target>)"
+
+    def execute(self, context: CommandContext) -> None:
+        """Execute export command."""
+        if context.export_target not in self.targets:
+            raise ValueError(
+                f"Unknown export target: {context.export_target} "
+                f"(supported: {', '.join(self.targets)})"
+            )
+
+        try:
+            findings = load_findings(context.input_file)
+        except FileNotFoundError:
+            logger.error(
+                theme.message("error", "\nFindings file not found: %s"),
+                context.input_file,
+            )
+            logger.info(
+                theme.message("hint", "Run 'python main.py audit' first to generate findings.")
+            )
+            raise
+
+        query = parse_query(context.query)
+        findings = reported_findings(findings, str(Path(context.input_file).parent))
+        if context.tag:
+            findings = filter_findings(findings, tag=context.tag)
+        if query:
+            findings = query.filter(findings)
+        if context.export_target == "github-issues":
+            self._export_github_issues(findings, context)
+        elif context.export_target == "splunk-hec":
+            self._export_splunk_hec(findings, context)
+        elif context.export_target == "scc":
+            self._export_scc(findings, context)
+        elif context.export_target == "ocsf":
+            self._export_ocsf(findings, context)
+        elif context.export_target == "pr-comment":
+            self._export_pr_comment(findings, context)
+        elif context.export_target == "check-run":
+            self._export_check_run(findings, context)
+
+    def _export_github_issues(self, findings, context: CommandContext) -> None:
+        """Open and close GitHub issues to match the findings."""
+        result = GitHubIssuesExporter.from_config().sync(findings, dry_run=context.dry_run)
+
+        prefix = "Would open" if context.dry_run else "Opened"
+        for title in result.opened:
+            print(f"{prefix}: {title}")
+        prefix = "Would close" if context.dry_run else "Closed"
+        for title in result.closed:
+            print(f"{prefix}: {title}")
+        print(
+            f"\n{len(result.opened)} opened, {len(result.closed)} closed, "
+            f"{result.unchanged} unchanged"
+        )
+
+    def _export_splunk_hec(self, findings, context: CommandContext) -> None:
+        """Send the findings as events to a Splunk HTTP Event Collector."""
+        exporter = SplunkHECExporter.from_config()
+        if context.dry_run:
+            print(f"Would send {len(findings)} event(s) to {exporter.url}")
+            return
+
+        result = exporter.send(findings, host=context.project_id)
+        print(f"{result['sent']} event(s) sent, {result['failed']} failed")
+        if result["failed"]:
+            raise RuntimeError(f"{result['failed']} event(s) could not be sent to Splunk HEC")
+
+    def _export_scc(self, findings, context: CommandContext) -> None:
+        """Write the findings to a custom Security Command Center source."""
+        result = SCCExporter.from_config().sync(
+            findings, project_id=context.project_id, dry_run=context.dry_run
+        )
+
+        prefix = "Would create" if context.dry_run else "Created"
+        for title in result.created:
+            print(f"{prefix}: {title}")
+        prefix = "Would deactivate" if context.dry_run else "Deactivated"
+        for scc_id in result.deactivated:
+            print(f"{prefix}: {scc_id}")
+        print(
+            f"\n{len(result.created)} created, {len(result.updated)} updated, "
+            f"{len(result.deactivated)} deactivated"
+        )
+        if result.retries:
+            print(f"{result.retries} transient API error(s) retried")
+
+    def _export_ocsf(self, findings, context: CommandContext) -> None:
+        """Write the findings as OCSF Detection Finding events (JSON Lines)."""
+        if context.dry_run:
+            print(f"Would write {len(findings)} OCSF event(s) to {context.output_dir}/")
+            return
+
+        path = write_ocsf_events(findings, Path(context.output_dir), context.project_id)
+        print(f"Wrote {len(findings)} OCSF event(s) to {path}")
+
+    def _export_pr_comment(self, findings, context: CommandContext) -> None:
+        """Post the findings that are new or resolved as a comment on the current PR."""
+        new, resolved, reference = self._delta(findings, Path(context.input_file).parent)
+        if context.dry_run:
+            print(comment_body(new, resolved, len(findings), reference))
+            return
+
+        exporter = PRCommentExporter.from_config()
+        body = comment_body(new, resolved, len(findings), reference, exporter.max_findings)
+        action = exporter.post(body)
+        print(f"{action.capitalize()} the Paddi comment on {exporter.pull_request}")
+
+    def _export_check_run(self, findings, context: CommandContext) -> None:
+        """Create a Check Run with the findings on the current commit, as a GitHub App."""
+        if context.dry_run:
+            section = PaddiConfig.load().section("integrations.github_app")
+            severity = str(section.get("fail_on_severity", DEFAULT_FAIL_ON_SEVERITY)).upper()
+            output = check_output(findings, context.project_id)
+            print(f"{output['title']} ({check_conclusion(findings, severity)})")
+            print(output["summary"])
+            return
+
+        publisher = CheckRunPublisher.from_config()
+        if publisher is None:
+            raise ConfigurationError(
+                "integrations.github_app", {"error": "not configured, or enabled = false"}
+            )
+        check_run = publisher.publish(findings, context.project_id)
+        print(
+            f"Created check run {publisher.check_name} ({check_run.get('conclusion')}) on "
+            f"{publisher.repository}@{publisher.head_sha[:7]}: {check_run.get('html_url', '')}"
+        )
+
+    @staticmethod
+    def _delta(findings, data_dir: Path):
+        """Compare findings with the baseline run, or else the run before the latest one.
+
+        Returns the new findings, the resolved findings, and a label for the run
+        compared with (None if there is no run to compare with).
+        """
+        history = data_dir / HISTORY_DB_NAME
+        if not history.exists():
+            return findings, [], None
+        store = RunStore(history)
+        reference, label = store.baseline(), "baseline run"
+        if reference is None:
+            latest = store.latest_run()
+            reference = store.previous_run(latest.run_id) if latest else None
+            label = "run"
+        if reference is None:
+            return findings, [], None
+
+        new, resolved = findings_delta(findings, store.findings_for(reference.run_id))
+        return new, resolved, f"{label} #{reference.run_id} ({reference.created_at[:10]})"
+
+
+class SchemaCommand(Command):
+    """Export JSON Schemas for the pipeline artifacts."""
+
+    @property
+    def name(self) -> str:
+        return "schema"
+
+    @property
+    def description(self) -> str:
+        return "Export JSON Schemas for collected.json and explained.json (schema export)"
+
+    def execute(self, context: CommandContext) -> None:
+        """Execute schema command."""
+        action = context.action or "export"
+        if action != "export":
+            raise ValueError(f"Unknown schema action: {action}")
+
+        names = [context.target] if context.target else None
+        if context.output_dir:
+            for path in export_schemas(Path(context.output_dir), names):
+                echo(theme.message("success", f"Wrote {path}"))
+        elif names:
+            print(json.dumps(artifact_schema(names[0]), indent=2, ensure_ascii=False))
+        else:
+            schemas = {name: build() for name, build in ARTIFACTS.items()}
+            print(json.dumps(schemas, indent=2, ensure_ascii=False))
+
+
+class ValidateDataCommand(Command):
+    """Validate pipeline artifacts against their JSON Schemas."""
+
+    @property
+    def name(self) -> str:
+        return "validate-data"
+
+    @property
+    def description(self) -> str:
+        return "Validate collected.json/explained.json against their schemas"
+
+    def execute(self, context: CommandContext) -> None:
+        """Execute validate-data command."""
+        paths = _paths(context.inputs) or [
+            path
+            for path in (Path("data/collected.json"), Path("data/explained.json"))
+            if path.exists()
+        ]
+        if not paths:
+            raise FileNotFoundError("No data/collected.json or data/explained.json to validate")
+
+        results = []
+        for path in paths:
+            name = context.target or artifact_name(path)
+            errors = validate_file(path, name) if path.exists() else ["$: file not found"]
+            results.append(
+                {"path": str(path), "schema": name, "valid": not errors, "errors": errors}
+            )
+
+        if is_structured(context.output_format):
+            emit(results, context.output_format)
+        else:
+            for result in results:
+                if result["valid"]:
+                    text = f"{result['path']} matches the {result['schema']} schema"
+                    echo(theme.message("success", text, color=True))
+                    continue
+                text = f"{result['path']} has {len(result['errors'])} error(s):"
+                echo(theme.message("error", text, color=True))
+                for error in result["errors"]:
+                    print(f"  - {error}")
+
+        invalid = [result for result in results if not result["valid"]]
+        if invalid:
+            raise DataValidationError(
+                ", ".join(result["path"] for result in invalid),
+                {"errors": [error for result in invalid for error in result["errors"]]},
+            )
+
+
+class ArchiveCommand(Command):
+    """Bundle a run's artifacts into a compressed, checksummed archive, or extract one."""
+
+    actions = ("create", "verify", "extract")
+
+    @property
+    def name(self) -> str:
+        return "archive"
+
+    @property
+    def description(self) -> str:
+        return "Archive run artifacts as .tar.zst (archive create|verify|extract)"
+
+    def execute(self, context: CommandContext) -> None:
+        """Execute archive command."""
+        action = context.action or "create"
+        if action not in self.actions:
+            raise ValueError(
+                f"Unknown archive action: {action} (supported: {', '.join(self.actions)})"
+            )
+
+        if action == "create":
+            archive = Path(context.target or default_archive_path(archive_dir()))
+            manifest = create_archive(archive, Path("data"), Path(context.output_dir or "output"))
+            size = archive.stat().st_size
+            text = f"Archived {len(manifest.files)} artifact(s) to {archive} ({size} bytes)"
+            echo(theme.message("success", text, color=True))
+            return
+
+        if not context.target:
+            raise ValueError(f"archive {action} requires the archive path")
+        archive = Path(context.target)
+        if action == "verify":
+            manifest = verify_archive(archive)
+            for entry in manifest.files:
+                print(f"  {entry.sha256[:12]}  {entry.size:>10}  {entry.name}")
+            text = f"{archive} is intact ({len(manifest.files)} artifact(s))"
+            echo(theme.message("success", text, color=True))
+            return
+
+        default_dest = archive.name.removesuffix(ARCHIVE_SUFFIX)
+        if default_dest == archive.name:
+            default_dest = "restored"
+        dest = Path(context.output_dir or default_dest)
+        manifest = extract_archive(archive, dest)
+        text = f"Extracted {len(manifest.files)} artifact(s) to {dest}"
+        echo(theme.message("success", text, color=True))
+
+
+class BundleCommand(Command):
+    """Move collected data between air-gapped networks."""
+
+    actions = ("export", "import")
+
+    @property
+    def name(self) -> str:
+        return "bundle"
+
+    @property
+    def description(self) -> str:
+        return "Package collected data for analysis on another network (bundle export|import)"
+
+    def execute(self, context: CommandContext) -> None:
+        """Execute bundle command."""
+        action = context.action or "export"
+        if action not in self.actions:
+            raise ValueError(
+                f"Unknown bundle action: {action} (supported: {', '.join(self.actions)})"
+            )
+
+        if action == "export":
+            bundle = Path(context.target or default_bundle_path())
+            export_bundle(bundle, Path("data"))
+            size = bundle.stat().st_size
+            text = f"Bundled collected data to {bundle} ({size} bytes)"
+            echo(theme.message("success", text, color=True))
+            hint = f"Copy {bundle} and {bundle.name}.sha256 to the analysis machine"
+            echo(theme.message("hint", hint))
+            return
+
+        if not context.target:
+            raise ValueError("bundle import requires the bundle path")
+        manifest = import_bundle(Path(context.target), Path("data"))
+        text = f"Imported {len(manifest.files)} file(s) from {context.target} into data/"
+        echo(theme.message("success", text, color=True))
+        if context.skip_run:
+            return
+
+        ExplainCommand().execute(context)
+        ReportCommand().execute(context)
+
+
+class DebugCommand(Command):
+    """Collect diagnostics for bug reports."""
+
+    actions = ("bundle",)
+
+    @property
+    def name(self) -> str:
+        return "debug"
+
+    @property
+    def description(self) -> str:
+        return "Collect sanitized logs, config, and versions into a ZIP (debug bundle)"
+
+    def execute(self, context: CommandContext) -> None:
+        """Execute debug command."""
+        action = context.action or "bundle"
+        if action not in self.actions:
+            raise ValueError(
+                f"Unknown debug action: {action} (supported: {', '.join(self.actions)})"
+            )
+
+        bundle = Path(context.target or default_debug_bundle_path())
+        names = create_debug_bundle(bundle)
+        echo(theme.message("success", f"Wrote diagnostics to {bundle}", color=True))
+        for name in names:
+            print(f"  {name}")
+        if "failure.json" not in names:
+            hint = "No failed run was recorded; the bundle has no failure.json"
+            echo(theme.message("hint", hint))
+        echo(theme.message("hint", "Review the files before attaching the ZIP to a bug report"))
+
+
+class BenchmarkCommand(Command):
+    """Measure the pipeline on synthetic datasets."""
+
+    @property
+    def name(self) -> str:
+        return "benchmark"
+
+    @property
+    def description(self) -> str:
+        return "Measure time, memory, and tokens per stage on synthetic datasets"
+
+    def execute(self, context: CommandContext) -> None:
+        """Execute benchmark command."""
+        sizes = _sizes(context.sizes)
+        options = {}
+        if context.formats:
+            formats = context.formats
+            if isinstance(formats, str):
+                formats = formats.split(",")
+            options["formats"] = [str(fmt).strip() for fmt in formats if str(fmt).strip()]
+        benchmark = Benchmark(
+            use_mock=context.use_mock,
+            project_id=context.project_id,
+            location=context.location,
+            ai_provider=context.ai_provider,
+            ollama_model=context.ollama_model,
+            ollama_endpoint=context.ollama_endpoint,
+            **options,
+        )
+        results = benchmark.run(sizes)
+        data = [result.to_dict() for result in results]
+
+        if context.target:
+            path = Path(context.target)
+            path.parent.mkdir(parents=True, exist_ok=True)
+            path.write_text(json.dumps(data, indent=2) + "\n", encoding="utf-8")
+        if is_structured(context.output_format):
+            emit(data, context.output_format)
+            return
+
+        print(format_results(results))
+        if any(result.tokens_estimated for result in results):
+            echo(theme.message("hint", "~ marks tokens estimated from the prompt length"))
+        if context.target:
+            echo(theme.message("success", f"Results written to {context.target}", color=True))
+
+
+def _sizes(value) -> List[int]:
+    """Parse --sizes: a number, or a list or comma-separated string of numbers."""
+    if value is None:
+        return list(DEFAULT_SIZES)
+    if isinstance(value, str):
+        value = value.split(",")
+    elif isinstance(value, int):
+        value = [value]
+    try:
+        sizes = [int(str(size).strip()) for size in value if str(size).strip()]
+    except ValueError as e:
+        raise ValueError(f"Invalid --sizes: {value} (use numbers such as 100,1000)") from e
+    if not sizes or any(size < 1 for size in sizes):
+        raise ValueError(f"Invalid --sizes: {value} (use numbers above 0)")
+    return sizes
+
+
+class CleanCommand(Command):
+    """Delete old runs and archives."""
+
+    @property
+    def name(self) -> str:
+        return "clean"
+
+    @property
+    def description(self) -> str:
+        return "Delete runs and archives beyond the [retention] limits (clean --apply-retention)"
+
+    def execute(self, context: CommandContext) -> None:
+        """Execute clean command."""
+        if not context.apply_retention:
+            raise ValueError("Nothing to clean; pass --apply-retention")
+
+        policy = RetentionPolicy.from_config()
+        if not policy.enabled:
+            echo(theme.message("hint", "No [retention] limits are configured"))
+            return
+
+        result = apply_retention(policy, Path("data"), archive_dir(), dry_run=context.dry_run)
+        if is_structured(context.output_format):
+            emit(
+                {
+                    "dry_run": context.dry_run,
+                    "deleted_runs": result.deleted_runs,
+                    "deleted_archives": [str(path) for path in result.deleted_archives],
+                    "freed_bytes": result.freed_bytes,
+                },
+                context.output_format,
+            )
+            return
+        for path in result.deleted_archives:
+            print(f"  {path}")
+        text = describe_retention(result, context.dry_run).capitalize()
+        echo(theme.message("success", text, color=True))
+
+
+class TrendsCommand(Command):
+    """Show how findings changed across recorded runs."""
+
+    @property
+    def name(self) -> str:
+        return "trends"
+
+    @property
+    def description(self) -> str:
+        return "Show findings over time, mean time to remediation, and recurring categories"
+
+    def execute(self, context: CommandContext) -> None:
+        """Execute trends command."""
+        history = Path("data") / HISTORY_DB_NAME
+        if not history.exists():
+            logger.error(theme.message("error", "\nRun history not found: %s"), history)
+            logger.info(theme.message("hint", "Run 'python main.py audit' to record runs."))
+            raise FileNotFoundError(f"Run history not found: {history}")
+
+        trends = compute_trends(RunStore(history), limit=context.runs)
+        if context.output_format == "csv":
+            sys.stdout.write(trends.to_csv())
+        elif is_structured(context.output_format):
+            emit(trends.to_dict(), context.output_format)
+        else:
+            print(format_trends(trends, severity_style=theme.severity))
+
+
+class BaselineCommand(Command):
+    """Accept a recorded run as the baseline and compare later findings with it."""
+
+    actions = ("show", "set", "compare", "clear")
+
+    @property
+    def name(self) -> str:
+        return "baseline"
+
+    @property
+    def description(self) -> str:
+        return "Manage the accepted baseline run (baseline show|set [run_id]|compare|clear)"
+
+    def execute(self, context: CommandContext) -> None:
+        """Execute baseline command."""
+        action = context.action or "show"
+        if action not in self.actions:
+            raise ValueError(
+                f"Unknown baseline action: {action} (supported: {', '.join(self.actions)})"
+            )
+
+        history = Path("data") / HISTORY_DB_NAME
+        if not history.exists():
+            logger.error(theme.message("error", "\nRun history not found: %s"), history)
+            logger.info(theme.message("hint", "Run 'python main.py audit' to record runs."))
+            raise FileNotFoundError(f"Run history not found: {history}")
+        store = RunStore(history)
+
+        if action == "set":
+            if context.target is not None:
+                run_id = int(context.target)
+            else:
+                latest = store.latest_run()
+                if latest is None:
+                    raise ValueError(f"No runs recorded in {history}")
+                run_id = latest.run_id
+            run = store.set_baseline(run_id)
+            text = f"Run #{run.run_id} ({run.created_at}, {run.total} finding(s)) is the baseline"
+            echo(theme.message("success", text, color=True))
+            return
+
+        if action == "clear":
+            store.clear_baseline()
+            echo(theme.message("success", "Baseline cleared", color=True))
+            return
+
+        if action == "show":
+            run = store.baseline()
+            if is_structured(context.output_format):
+                emit(asdict(run) if run else None, context.output_format)
+            elif run is None:
+                echo(theme.message("hint", "No baseline is set (baseline set [run_id])"))
+            else:
+                print(f"Baseline: run #{run.run_id} ({run.created_at}, {run.total} finding(s))")
+            return
+
+        findings_file = Path("data") / "explained.json"
+        findings = reported_findings(load_findings(str(findings_file)))
+        comparison = compare_to_baseline(store, findings)
+        if comparison is None:
+            raise ValueError("No baseline is set; run 'baseline set' first")
+        if is_structured(context.output_format):
+            emit(comparison.to_dict(), context.output_format)
+        else:
+            print(format_comparison(comparison, severity_style=theme.severity))
+        if context.fail_on_new and comparison.new:
+            error = NewFindingsError(
+                len(comparison.new), {"baseline_run_id": comparison.baseline.run_id}
+            )
+            logger.error(theme.message("error", "\n%s"), error.message)
+            raise error
+
+
+class QueryCommand(Command):
+    """Run read-only SQL over the run history."""
+
+    @property
+    def name(self) -> str:
+        return "query"
+
+    @property
+    def description(self) -> str:
+        return "Run a read-only SQL query over the run history (query \"SELECT ...\")"
+
+    def execute(self, context: CommandContext) -> None:
+        """Execute query command."""
+        history = Path("data") / HISTORY_DB_NAME
+        if not history.exists():
+            logger.error(theme.message("error", "\nRun history not found: %s"), history)
+            logger.info(theme.message("hint", "Run 'python main.py audit' to record runs."))
+            raise FileNotFoundError(f"Run history not found: {history}")
+
+        if not context.target:
+            tables = describe_tables(history)
+            if is_structured(context.output_format):
+                emit(tables, context.output_format)
+                return
+            for table, columns in tables.items():
+                print(f"{table}: {', '.join(columns)}")
+            return
+
+        try:
+            result = run_query(history, str(context.target))
+        except sqlite3.Error as e:
+            logger.error(theme.message("error", "Query failed: %s"), e)
+            raise
+
+        if context.output_format == "csv":
+            sys.stdout.write(result.to_csv())
+        elif is_structured(context.output_format):
+            emit(result.to_dict(), context.output_format)
+        else:
+            print(format_result(result))
+
+
+class RunsCommand(Command):
+    """List audit runs and show their manifests."""
+
+    actions = ("list", "show")
+
+    @property
+    def name(self) -> str:
+        return "runs"
+
+    @property
+    def description(self) -> str:
+        return "List audit runs and show their manifests (runs list|show [run_id])"
+
+    def execute(self, context: CommandContext) -> None:
+        """Execute runs command."""
+        action = context.action or "list"
+        if action not in self.actions:
+            raise ValueError(
+                f"Unknown runs action: {action} (supported: {', '.join(self.actions)})"
+            )
+
+        if action == "list":
+            manifests = load_manifests(limit=context.runs)
+            if is_structured(context.output_format):
+                emit(manifests, context.output_format)
+            elif not manifests:
+                echo(theme.message("hint", "No runs recorded; 'python main.py audit' records one"))
+            else:
+                print(format_manifests(manifests))
+            return
+
+        if context.target is not None:
+            manifest = load_manifest(str(context.target))
+        else:
+            latest = load_manifests(limit=1)
+            if not latest:
+                raise FileNotFoundError("No runs recorded in data/runs")
+            manifest = latest[0]
+        if is_structured(context.output_format):
+            emit(manifest, context.output_format)
+        else:
+            print(format_manifest(manifest))
+
+
+class ServeCommand(Command):
+    """Serve a browser UI over the run history and reports."""
+
+    @property
+    def name(self) -> str:
+        return "serve"
+
+    token_actions = ("list", "create", "revoke")
+
+    @property
+    def description(self) -> str:
+        return (
+            "Browse runs, findings, and reports in a web browser, or manage its API tokens "
+            "(serve [--port 8080] | serve token list|create <name> [--user]|revoke <id>)"
+        )
+
+    def execute(self, context: CommandContext) -> None:
+        """Execute serve command."""
+        if context.action == "token":
+            self._token(context)
+            return
+        if context.action is not None:
+            raise ValueError(f"Unknown serve action: {context.action} (supported: token)")
+
+        settings = ServerSettings.from_config()
+        if context.host:
+            settings.host = str(context.host)
+        if context.port is not None:
+            settings.port = int(context.port)
+
+        browser = ReportBrowser(Path("data"), Path(context.output_dir))
+        if settings.max_concurrent_audits > 1:
+            logger.warning(
+                "Up to %d audits run at once; they share the working files in data/",
+                settings.max_concurrent_audits,
+            )
+        jobs = AuditJobs(
+            partial(self._run_audit, context),
+            max_concurrency=settings.max_concurrent_audits,
+            max_queued=settings.max_queued_audits,
+            state_path=Path("data") / QUEUE_STATE_NAME,
+        )
+        tokens = TokenStore(Path("data") / TOKENS_DB_NAME)
+        policy = AccessPolicy.from_config()
+        server = create_server(settings, browser, jobs, tokens, policy)
+        host, port = server.server_address[:2]
+        echo(theme.message("success", f"Serving Paddi on http://{host}:{port}/", color=True))
+        if not (policy.enabled or tokens.required()):
+            hint = "No API token or users configured; the API is open to anyone who can connect"
+            echo(theme.message("hint", hint))
+        echo(theme.message("hint", "Press Ctrl+C to stop"))
+        try:
+            server.serve_forever()
+        except KeyboardInterrupt:
+            pass
+        finally:
+            server.server_close()
+
+    @staticmethod
+    def _run_audit(
+        context: CommandContext,
+        options: Dict[str, Any],
+        on_stage: Callable[[str, Optional[float]], None],
+    ) -> None:
+        """Run an audit started from the browser, reporting each stage as it starts."""
+        command = AuditCommand()
+        command.on_stage = on_stage
+        command.execute(replace(context, **options))
+
+    def _token(self, context: CommandContext) -> None:
+        """List, create, or revoke the API tokens of the server."""
+        action = context.token_action or "list"
+        if action not in self.token_actions:
+            raise ValueError(
+                f"Unknown serve token action: {action} "
+                f"(supported: {', '.join(self.token_actions)})"
+            )
+        store = TokenStore(Path("data") / TOKENS_DB_NAME)
+
+        if action == "create":
+            if context.user and context.user not in AccessPolicy.from_config().users:
+                raise ValueError(f"{context.user} is not a user of [server.users] in paddi.toml")
+            token, secret = store.create(str(context.target or ""), context.scope, context.user)
+            owner = f", user {token.user}" if token.user else ""
+            text = f"Created token {token.token_id} ({token.name}, scope {token.scope}{owner})"
+            echo(theme.message("success", text, color=True))
+            hint = "Copy the token now; it is stored hashed and cannot be shown again."
+            echo(theme.message("hint", hint))
+            print(secret)
+            return
+
+        if action == "revoke":
+            if not context.target:
+                raise ValueError("serve token revoke requires a token ID (see serve token list)")
+            token = store.revoke(str(context.target))
+            if token is None:
+                raise ValueError(f"No active token with ID {context.target}")
+            text = f"Revoked token {token.token_id} ({token.name})"
+            echo(theme.message("success", text, color=True))
+            return
+
+        tokens = store.tokens()
+        if is_structured(context.output_format):
+            emit([asdict(token) for token in tokens], context.output_format)
+        elif not tokens:
+            echo(theme.message("hint", "No API tokens; 'serve token create <name>' creates one"))
+        else:
+            print(self._format_tokens(tokens))
+
+    @staticmethod
+    def _format_tokens(tokens: List[ApiToken]) -> str:
+        """Format the tokens as a table."""
+        width = max(len("Name"), *(len(token.name) for token in tokens))
+        user_width = max(len("User"), *(len(token.user or "-") for token in tokens))
+        header = (
+            f"{'ID':<8}  {'Name':<{width}}  {'Scope':<5}  {'User':<{user_width}}  "
+            f"{'Created':<25}  Last used"
+        )
+        lines = [header]
+        for token in tokens:
+            state = f"revoked {token.revoked_at}" if token.revoked_at else token.last_used_at or "-"
+            lines.append(
+                f"{token.token_id:<8}  {token.name:<{width}}  {token.scope:<5}  "
+                f"{token.user or '-':<{user_width}}  {token.created_at:<25}  {state}"
+            )
+        return "\n".join(lines)
+
+
+class WorkerCommand(Command):
+    """Audit projects on several machines through a shared queue."""
+
+    actions = ("run", "coordinate")
+
+    @property
+    def name(self) -> str:
+        return "worker"
+
+    @property
+    def description(self) -> str:
+        return (
+            "Audit projects from a shared queue, or spread a multi-project audit over the "
+            "workers (worker run | worker coordinate --projects a,b)"
+        )
+
+    def execute(self, context: CommandContext) -> None:
+        """Execute worker command."""
+        action = context.action or "run"
+        if action not in self.actions:
+            raise ValueError(
+                f"Unknown worker action: {action} (supported: {', '.join(self.actions)})"
+            )
+        broker = RedisBroker.from_config()
+        if broker is None:
+            raise ConfigurationError(
+                "workers.broker", {"error": "set [workers] broker to a redis:// URL"}
+            )
+
+        if action == "run":
+            worker = Worker(broker, partial(self._audit_project, context))
+            echo(theme.message("success", f"Worker {worker.name} waiting for audits", color=True))
+            echo(theme.message("hint", "Press Ctrl+C to stop"))
+            try:
+                done = worker.run(max_jobs=context.max_jobs)
+            except KeyboardInterrupt:
+                return
+            echo(theme.message("success", f"Worker {worker.name} ran {done} audit(s)", color=True))
+            return
+
+        projects = split_projects(context.projects)
+        if not projects:
+            raise ValueError("worker coordinate requires --projects (e.g. --projects=a,b)")
+        options = {
+            "use_mock": context.use_mock,
+            "ai_provider": context.ai_provider,
+            "formats": context.formats,
+        }
+        coordinator = Coordinator(broker, RunStore(Path("data") / HISTORY_DB_NAME))
+        batch = coordinator.run(projects, options, context.worker_timeout)
+        AuditCommand()._report_projects(context, batch.results, batch.findings_by_project)
+
+    @staticmethod
+    def _audit_project(
+        context: CommandContext, project_id: str, options: Dict[str, Any]
+    ) -> List[dict]:
+        """Audit one project of a job, writing its reports to <output_dir>/<project>."""
+        command = AuditCommand()
+        command.execute(
+            replace(
+                context,
+                project_id=project_id,
+                projects=None,
+                output_dir=str(Path(context.output_dir) / project_id),
+                **options,
+            )
+        )
+        return command._project_findings()
+
+
+class DeployCommand(Command):
+    """Generate manifests that run audits on a schedule."""
+
+    actions = ("k8s", "cloudrun")
+
+    @property
+    def name(self) -> str:
+        return "deploy"
+
+    @property
+    def description(self) -> str:
+        return (
+            "Generate Kubernetes manifests or a Cloud Run job for scheduled audits "
+            "(deploy k8s|cloudrun --schedule '0 3 * * *')"
+        )
+
+    def execute(self, context: CommandContext) -> None:
+        """Execute deploy command."""
+        action = context.action or "k8s"
+        if action not in self.actions:
+            raise ValueError(
+                f"Unknown deploy action: {action} (supported: {', '.join(self.actions)})"
+            )
+        if not context.schedule:
+            raise ValueError(
+                f"deploy {action} requires --schedule (e.g. --schedule '0 3 * * *')"
+            )
+
+        config = PaddiConfig.load()
+        if action == "k8s":
+            self._kubernetes(context, config)
+        else:
+            self._cloud_run(context, config)
+
+    @staticmethod
+    def _kubernetes(context: CommandContext, config: PaddiConfig) -> None:
+        config_text = config.path.read_text(encoding="utf-8") if config.path else ""
+        options = CronJobOptions(
+            schedule=context.schedule,
+            name=context.target or DEFAULT_NAME,
+            image=context.image,
+            project_id=context.project_id,
+            organization_id=context.organization_id,
+            use_mock=context.use_mock,
+            ai_provider=context.ai_provider,
+            formats=context.formats,
+            namespace=context.namespace,
+            service_account=context.service_account,
+            volume_claim=context.volume_claim,
+        )
+        manifests = build_manifests(options, config_text, secret_env_vars(config))
+        extra_fields = tuple(config.section("secrets.encryption").get("fields", []))
+        plaintext = [key for key, _ in sensitive_values(config.data, extra_fields)]
+        if plaintext:
+            logger.warning(
+                theme.message(
+                    "warning",
+                    "paddi.toml has plaintext secrets (%s), copied into the Secret %s-config; "
+                    "run `paddi config encrypt` to keep them out of the manifests",
+                ),
+                ", ".join(plaintext),
+                options.name,
+            )
+        if not context.output_dir:
+            print(render_manifests(manifests), end="")
+            return
+
+        for path in write_manifests(manifests, Path(context.output_dir)):
+            echo(theme.message("success", f"Wrote {path}"))
+        if any(m["kind"] == "Secret" for m in manifests):
+            echo(
+                theme.message(
+                    "hint", f"Replace {PLACEHOLDER} in the Secrets before applying them"
+                )
+            )
+
+    @staticmethod
+    def _cloud_run(context: CommandContext, config: PaddiConfig) -> None:
+        options = CloudRunJobOptions(
+            schedule=context.schedule,
+            name=context.target or DEFAULT_NAME,
+            image=context.image,
+            project_id=context.project_id,
+            organization_id=context.organization_id,
+            use_mock=context.use_mock,
+            ai_provider=context.ai_provider,
+            formats=context.formats,
+            region=context.location,
+            job_project=context.job_project,
+            service_account=context.service_account,
+            time_zone=context.time_zone or DEFAULT_TIME_ZONE,
+        )
+        env_vars = secret_env_vars(config)
+        config_path = config.path.resolve() if config.path else None
+        job = build_job(options, env_vars, with_config=config_path is not None)
+        gcs_bucket = config.section("storage.gcs").get("bucket")
+        if not context.output_dir:
+            script = build_setup_script(options, env_vars, config_path, render_job(job), gcs_bucket)
+            print(script, end="")
+            return
+
+        script = build_setup_script(options, env_vars, config_path, gcs_bucket=gcs_bucket)
+        for path in write_deployment(job, script, Path(context.output_dir)):
+            echo(theme.message("success", f"Wrote {path}"))
+        if env_vars:
+            names = ", ".join(env_vars)
+            echo(theme.message("hint", f"Set {names} before running the script"))
+
+
+class SiteCommand(Command):
+    """Render the run history into a static HTML site."""
+
+    actions = ("build",)
+
+    @property
+    def name(self) -> str:
+        return "site"
+
+    @property
+    def description(self) -> str:
+        return "Build a static dashboard site of the run history (site build)"
+
+    def execute(self, context: CommandContext) -> None:
+        """Execute site command."""
+        action = context.action or "build"
+        if action not in self.actions:
+            raise ValueError(
+                f"Unknown site action: {action} (supported: {', '.join(self.actions)})"
+            )
+
+        history = Path("data") / HISTORY_DB_NAME
+        if not history.exists():
+            logger.error(theme.message("error", "\nRun history not found: %s"), history)
+            logger.info(theme.message("hint", "Run 'python main.py audit' to record runs."))
+            raise FileNotFoundError(f"Run history not found: {history}")
+
+        output_dir = Path(context.output_dir)
+        written = build_site(RunStore(history), output_dir, limit=context.runs)
+        echo(theme.message("success", f"Wrote {len(written)} page(s) to {output_dir}/", color=True))
+
+
+class SelfUpdateCommand(Command):
+    """Update Paddi to the newest release of a channel."""
+
+    @property
+    def name(self) -> str:
+        return "self-update"
+
+    @property
+    def description(self) -> str:
+        return "Install the newest verified release from GitHub (self-update [--check])"
+
+    def execute(self, context: CommandContext) -> None:
+        """Execute self-update command."""
+        current = cli_version()
+        updater = SelfUpdater.from_config(channel=context.target)
+        release = updater.check(current)
+        result = {
+            "current_version": current,
+            "channel": updater.channel,
+            "update_available": release is not None,
+            "release": release.to_dict() if release else None,
+        }
+
+        if release is None or context.dry_run:
+            if is_structured(context.output_format):
+                emit(result, context.output_format)
+            elif release is None:
+                text = f"Paddi {current} is the newest {updater.channel} release"
+                echo(theme.message("success", text, color=True))
+            else:
+                print(f"Paddi {release.version} is available (installed: {current})")
+                if release.url:
+                    print(f"  {release.url}")
+                echo(theme.message("hint", "Run 'paddi self-update' to install it."))
+            return
+
+        logger.info(
+            theme.message("welcome", "Updating Paddi %s to %s ..."), current, release.version
+        )
+        wheel = updater.install(release)
+        if is_structured(context.output_format):
+            emit({**result, "installed": wheel}, context.output_format)
+        else:
+            text = f"Updated Paddi from {current} to {release.version}"
+            echo(theme.message("success", text, color=True))
+
+
+class CompletionCommand(Command):
+    """Print shell completion scripts and the candidates they complete."""
+
+    @property
+    def name(self) -> str:
+        return "completion"
+
+    @property
+    def description(self) -> str:
+        return "Print a shell completion script (completion bash|zsh|fish)"
+
+    def execute(self, context: CommandContext) -> None:
+        """Execute completion command."""
+        if context.completion_kind:
+            for candidate in completion_candidates(context.completion_kind, context.target):
+                print(candidate)
+            return
+
+        # Imported here: the CLI class imports this module
+        from .paddi_cli import PaddiCLI
+
+        commands = [
+            name.replace("_", "-")
+            for name in dir(PaddiCLI)
+            if not name.startswith("_") and callable(getattr(PaddiCLI, name))
+        ]
+        sys.stdout.write(completion_script(context.action or "bash", commands))
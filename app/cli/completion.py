@@ -0,0 +1,183 @@
+"""Shell completion for the paddi command.
+
+``paddi completion bash|zsh|fish`` prints a script that completes command names
+and, dynamically, the values of options that name things on this machine:
+
+- ``--project-id``/``--projects``: the projects of the gcloud configurations,
+  ``[general] project_id`` in paddi.toml, and the projects in the run history
+- ``--run-id`` (and the run of ``runs show`` and ``baseline set``): the runs in
+  ``data/runs`` for ``paddi runs``, and the runs in ``data/history.db`` for
+  ``paddi baseline``
+
+For those values the script calls ``paddi completion --complete=<kind>``, which
+prints one candidate per line. It never fails: a missing gcloud or an empty
+history just yields fewer candidates.
+"""
+
+import shutil
+import sqlite3
+import subprocess  # nosec B404
+from pathlib import Path
+from typing import Iterable, List, Optional
+
+from app.common.exceptions import PaddiException
+from app.config.paddi_config import PaddiConfig
+from app.history.manifest import RUNS_DIR, load_manifests
+from app.history.store import HISTORY_DB_NAME, RunStore
+
+SHELLS = ("bash", "zsh", "fish")
+KINDS = ("project_id", "run_id")
+GCLOUD_TIMEOUT_SECONDS = 3
+# Candidates offered at most, newest first for runs
+MAX_CANDIDATES = 50
+
+_BASH = """# paddi completion for bash
+_paddi_complete() {
+    local cur="${COMP_WORDS[COMP_CWORD]}" prev="${COMP_WORDS[COMP_CWORD-1]}" kind=""
+    if [[ $COMP_CWORD -eq 1 ]]; then
+        COMPREPLY=($(compgen -W "%(commands)s" -- "$cur"))
+        return
+    fi
+    # Bash splits --option=value into three words at the "="
+    if [[ "$cur" == "=" ]]; then
+        cur=""
+    elif [[ "$prev" == "=" ]]; then
+        prev="${COMP_WORDS[COMP_CWORD-2]}"
+    fi
+    case "$prev" in
+        --project-id|--project_id|--projects) kind=project_id ;;
+        --run-id|--run_id) kind=run_id ;;
+        show|set) [[ $COMP_CWORD -eq 3 ]] && kind=run_id ;;
+    esac
+    if [[ -n "$kind" ]]; then
+        local IFS=$'\\n'
+        local values
+        values="$(paddi completion --complete=$kind --command="${COMP_WORDS[1]}" 2>/dev/null)"
+        COMPREPLY=($(compgen -W "$values" -- "$cur"))
+    fi
+}
+complete -o default -F _paddi_complete paddi
+"""
+
+_ZSH = """#compdef paddi
+# paddi completion for zsh
+_paddi() {
+    local kind
+    if (( CURRENT == 2 )); then
+        compadd -- %(commands)s
+        return
+    fi
+    case "${words[CURRENT-1]}" in
+        --project-id|--project_id|--projects) kind=project_id ;;
+        --run-id|--run_id) kind=run_id ;;
+        show|set) (( CURRENT == 4 )) && kind=run_id ;;
+    esac
+    if [[ -n "$kind" ]]; then
+        compadd -- ${(f)"$(paddi completion --complete=$kind --command=${words[2]} 2>/dev/null)"}
+    else
+        _files
+    fi
+}
+compdef _paddi paddi
+"""
+
+_FISH = """# paddi completion for fish
+function __paddi_candidates
+    set -l words (commandline -opc)
+    paddi completion --complete=$argv[1] --command=$words[2] 2>/dev/null
+end
+complete -c paddi -f -n '__fish_use_subcommand' -a '%(commands)s'
+for option in project-id project_id projects
+    complete -c paddi -l $option -x -a '(__paddi_candidates project_id)'
+end
+for option in run-id run_id
+    complete -c paddi -l $option -x -a '(__paddi_candidates run_id)'
+end
+complete -c paddi -f -a '(__paddi_candidates run_id)' \\
+    -n '__fish_seen_subcommand_from runs baseline; and __fish_seen_subcommand_from show set'
+"""
+
+_SCRIPTS = {"bash": _BASH, "zsh": _ZSH, "fish": _FISH}
+
+
+def completion_script(shell: str, commands: Iterable[str]) -> str:
+    """Return the completion script for a shell."""
+    if shell not in _SCRIPTS:
+        raise ValueError(f"Unsupported shell: {shell} (supported: {', '.join(SHELLS)})")
+    return _SCRIPTS[shell] % {"commands": " ".join(sorted(commands))}
+
+
+def _unique(values: Iterable[Optional[str]]) -> List[str]:
+    seen: List[str] = []
+    for value in values:
+        if value and value not in seen:
+            seen.append(value)
+    return seen[:MAX_CANDIDATES]
+
+
+def gcloud_projects() -> List[str]:
+    """Return the projects set in the gcloud configurations, without calling any API."""
+    gcloud = shutil.which("gcloud")
+    if gcloud is None:
+        return []
+    try:
+        result = subprocess.run(  # nosec B603
+            [gcloud, "config", "configurations", "list", "--format=value(properties.core.project)"],
+            capture_output=True,
+            text=True,
+            timeout=GCLOUD_TIMEOUT_SECONDS,
+            check=False,
+        )
+    except (OSError, subprocess.TimeoutExpired):
+        return []
+    if result.returncode != 0:
+        return []
+    return [line.strip() for line in result.stdout.splitlines() if line.strip()]
+
+
+def _history(data_dir: Path) -> Optional[RunStore]:
+    history = Path(data_dir) / HISTORY_DB_NAME
+    return RunStore(history) if history.exists() else None
+
+
+def project_ids(config: Optional[PaddiConfig] = None, data_dir: Path = Path("data")) -> List[str]:
+    """Return the project IDs to offer for ``--project-id``."""
+    candidates = gcloud_projects()
+    # A broken paddi.toml or history must not break completion
+    try:
+        candidates.append((config or PaddiConfig.load()).section("general").get("project_id"))
+    except (PaddiException, OSError, ValueError):
+        pass
+    try:
+        store = _history(data_dir)
+        if store is not None:
+            candidates.extend(run.project_id for run in store.runs(limit=MAX_CANDIDATES))
+    except sqlite3.Error:
+        pass
+    return _unique(candidates)
+
+
+def run_ids(command: Optional[str] = None, data_dir: Path = Path("data")) -> List[str]:
+    """Return the run IDs to offer for ``--run-id``, newest first.
+
+    ``paddi baseline`` takes runs of the history database; other commands take
+    the runs of ``data/runs``.
+    """
+    if command == "baseline":
+        try:
+            store = _history(data_dir)
+            runs = store.runs(limit=MAX_CANDIDATES) if store is not None else []
+        except sqlite3.Error:
+            return []
+        return _unique(str(run.run_id) for run in runs)
+    runs_dir = Path(data_dir) / RUNS_DIR.name
+    return _unique(m.get("run_id") for m in load_manifests(runs_dir, limit=MAX_CANDIDATES))
+
+
+def candidates(kind: str, command: Optional[str] = None) -> List[str]:
+    """Return the completion candidates of a kind of value."""
+    if kind == "project_id":
+        return project_ids()
+    if kind == "run_id":
+        return run_ids(command)
+    raise ValueError(f"Unknown completion kind: {kind} (supported: {', '.join(KINDS)})")
@@ -0,0 +1,146 @@
+"""Interactive ``paddi init`` wizard that writes ``paddi.toml``."""
+
+import logging
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import Callable, List, Optional, Sequence
+
+from app.common.i18n import tr
+from app.config.writer import toml_value
+
+from .base import CommandContext
+
+logger = logging.getLogger(__name__)
+
+PROVIDERS = ("gcp", "aws", "azure", "github")
+LLM_BACKENDS = ("gemini", "ollama")
+REPORT_FORMATS = ("markdown", "html", "honkit")
+DEFAULT_OLLAMA_MODEL = "gemma3:latest"
+
+# What the target identifier is called for each provider
+TARGET_LABELS = {
+    "gcp": "GCP project ID",
+    "aws": "AWS account ID",
+    "azure": "Azure subscription ID",
+    "github": "GitHub owner (user or organization)",
+}
+
+
+@dataclass
+class WizardAnswers:
+    """Choices made in the init wizard."""
+
+    provider: str = "gcp"
+    project_id: str = "example-project-123"
+    use_mock: bool = True
+    ai_provider: str = "gemini"
+    ollama_model: Optional[str] = None
+    formats: List[str] = field(default_factory=lambda: ["markdown", "html"])
+
+    def to_toml(self) -> str:
+        """Render the answers as ``paddi.toml`` content."""
+        lines = [
+            "# Generated by 'paddi init --interactive'",
+            "",
+            "[general]",
+            f"provider = {toml_value(self.provider)}",
+            f"project_id = {toml_value(self.project_id)}",
+            f"use_mock = {toml_value(self.use_mock)}",
+            f"ai_provider = {toml_value(self.ai_provider)}",
+        ]
+        if self.ollama_model:
+            lines.append(f"ollama_model = {toml_value(self.ollama_model)}")
+        lines.extend(["", "[reporter]", f"formats = {toml_value(self.formats)}", ""])
+        return "\n".join(lines)
+
+    def apply(self, context: CommandContext) -> None:
+        """Copy the answers onto a command context for the first audit."""
+        context.use_mock = self.use_mock
+        context.ai_provider = self.ai_provider
+        context.ollama_model = self.ollama_model
+        context.formats = ",".join(self.formats)
+        if self.provider == "gcp":
+            context.project_id = self.project_id
+        elif self.provider == "aws":
+            context.aws_account_id = self.project_id
+        elif self.provider == "azure":
+            context.azure_subscription_id = self.project_id
+        elif self.provider == "github":
+            context.github_owner = self.project_id
+
+
+class InitWizard:
+    """Ask the questions needed for a first ``paddi.toml``."""
+
+    def __init__(self, ask: Callable[[str], str] = input):
+        """Initialize the wizard.
+
+        Args:
+            ask: Function that shows a prompt and returns the typed line
+        """
+        self.ask = ask
+
+    def prompt(self, question: str, default: str, options: str = "") -> str:
+        """Ask a free-text question; an empty answer keeps the default."""
+        answer = self.ask(f"{tr(question)}{options} [{default}]: ").strip()
+        return answer or default
+
+    def choose(self, question: str, choices: Sequence[str], default: str) -> str:
+        """Ask until the answer is one of ``choices``."""
+        while True:
+            answer = self.prompt(question, default, f" ({'/'.join(choices)})").lower()
+            if answer in choices:
+                return answer
+            print(tr("Please choose one of: {choices}", choices=", ".join(choices)))
+
+    def confirm(self, question: str, default: bool) -> bool:
+        """Ask a yes/no question."""
+        while True:
+            answer = self.prompt(question, "y" if default else "n", " (y/n)").lower()
+            if answer in ("y", "yes"):
+                return True
+            if answer in ("n", "no"):
+                return False
+            print(tr("Please answer y or n"))
+
+    def choose_many(self, question: str, choices: Sequence[str], default: List[str]) -> List[str]:
+        """Ask for a comma-separated subset of ``choices``."""
+        while True:
+            answer = self.prompt(question, ",".join(default), f" ({', '.join(choices)})")
+            selected = [item.strip().lower() for item in answer.split(",") if item.strip()]
+            unknown = [item for item in selected if item not in choices]
+            if selected and not unknown:
+                return selected
+            print(tr("Please choose from: {choices}", choices=", ".join(choices)))
+
+    def run(self) -> WizardAnswers:
+        """Ask every question and return the answers."""
+        answers = WizardAnswers()
+        answers.provider = self.choose("Cloud provider", PROVIDERS, answers.provider)
+        default_target = answers.project_id if answers.provider == "gcp" else ""
+        while True:
+            answers.project_id = self.prompt(TARGET_LABELS[answers.provider], default_target)
+            if answers.project_id:
+                break
+            print(tr("A value is required"))
+        answers.use_mock = self.confirm(
+            "Use mock data instead of real cloud APIs?", answers.use_mock
+        )
+        answers.ai_provider = self.choose("LLM backend", LLM_BACKENDS, answers.ai_provider)
+        if answers.ai_provider == "ollama":
+            answers.ollama_model = self.prompt("Ollama model", DEFAULT_OLLAMA_MODEL)
+        answers.formats = self.choose_many("Report formats", REPORT_FORMATS, answers.formats)
+        return answers
+
+    def write(self, answers: WizardAnswers, path: Path) -> bool:
+        """Write ``paddi.toml``, asking before replacing an existing file.
+
+        Returns:
+            Whether the file was written
+        """
+        overwrite = tr("{path} already exists. Overwrite?", path=path)
+        if path.exists() and not self.confirm(overwrite, False):
+            logger.info(tr("Keeping existing %s"), path)
+            return False
+        path.write_text(answers.to_toml(), encoding="utf-8")
+        return True
@@ -0,0 +1,64 @@
+"""Results of an audit over several projects (``paddi audit --projects``).
+
+Every project is audited even when an earlier one fails, unless ``--fail-fast``
+is given. The audit then exits with an aggregated status: success if every
+project passed, the failed projects' exit code if they all failed the same way,
+and 1 (general error) if they failed in different ways.
+"""
+
+from dataclasses import asdict, dataclass
+from typing import Any, Dict, List, Optional
+
+from app.common.exit_codes import ExitCode
+
+PASSED = "passed"
+FAILED = "failed"
+SKIPPED = "skipped"
+
+
+@dataclass
+class ProjectResult:
+    """The outcome of one project's audit."""
+
+    project_id: str
+    status: str
+    exit_code: Optional[int] = None
+    error: Optional[str] = None
+
+    def to_dict(self) -> Dict[str, Any]:
+        """Convert to a dictionary for JSON output."""
+        return asdict(self)
+
+
+def split_projects(value: Any) -> List[str]:
+    """Split a list or comma-separated string of project IDs, dropping duplicates."""
+    if isinstance(value, str):
+        value = value.split(",")
+    projects: List[str] = []
+    for project in value or []:
+        project = str(project).strip()
+        if project and project not in projects:
+            projects.append(project)
+    return projects
+
+
+def aggregate_exit_code(results: List[ProjectResult]) -> ExitCode:
+    """Return the exit code of the whole audit."""
+    codes = {result.exit_code for result in results if result.status == FAILED}
+    if not codes:
+        return ExitCode.SUCCESS
+    if len(codes) == 1:
+        return ExitCode(codes.pop())
+    return ExitCode.GENERAL_ERROR
+
+
+def format_project_results(results: List[ProjectResult]) -> str:
+    """Format the per-project results as a table."""
+    width = max(len("Project"), *(len(result.project_id) for result in results))
+    lines = [f"{'Project':<{width}}  {'Status':<7}  Exit  Error"]
+    for result in results:
+        code = "-" if result.exit_code is None else str(result.exit_code)
+        lines.append(
+            f"{result.project_id:<{width}}  {result.status:<7}  {code:<4}  {result.error or ''}"
+        )
+    return "\n".join(lines)
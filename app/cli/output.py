@@ -0,0 +1,111 @@
+"""Command output settings: the global ``--output`` and ``--quiet`` flags.
+
+``--output json|yaml|table`` applies to every command. It is taken out of the
+arguments before Fire parses them, so it does not clash with command options
+that share the name (such as ``init --output=<dir>``). Commands that print
+results call :func:`is_structured` and :func:`emit` instead of formatting text.
+"""
+
+import json
+import os
+from typing import Any, List, Optional, Tuple
+
+from app.common.logging_config import strip_symbols
+
+OUTPUT_FORMATS = ("table", "json", "yaml")
+QUIET_FLAGS = ("--quiet", "-q")
+
+_output_format: Optional[str] = None
+_quiet = False
+
+
+def set_output_format(output_format: Optional[str]) -> None:
+    """Set the output format for this process (``None`` restores the default)."""
+    global _output_format
+    if output_format is not None and output_format not in OUTPUT_FORMATS:
+        raise ValueError(
+            f"Unknown output format: {output_format} (supported: {', '.join(OUTPUT_FORMATS)})"
+        )
+    _output_format = output_format
+
+
+def get_output_format() -> str:
+    """Return the selected output format."""
+    return _output_format or "table"
+
+
+def is_structured(output_format: Optional[str] = None) -> bool:
+    """Return whether results should be printed as JSON or YAML."""
+    return (output_format or get_output_format()) in ("json", "yaml")
+
+
+def extract_output_flag(argv: List[str]) -> Tuple[List[str], Optional[str]]:
+    """Remove ``--output json|yaml|table`` from command-line arguments.
+
+    Only values that name an output format are taken, so ``--output=<dir>``
+    still reaches commands that accept a directory.
+
+    Returns:
+        Remaining arguments and the selected format, if any
+    """
+    remaining: List[str] = []
+    selected = None
+    skip = False
+    for index, arg in enumerate(argv):
+        if skip:
+            skip = False
+            continue
+        if arg.startswith("--output="):
+            value = arg.split("=", 1)[1]
+            if value in OUTPUT_FORMATS:
+                selected = value
+                continue
+        elif arg == "--output" and index + 1 < len(argv) and argv[index + 1] in OUTPUT_FORMATS:
+            selected = argv[index + 1]
+            skip = True
+            continue
+        remaining.append(arg)
+    return remaining, selected
+
+
+def render(data: Any, output_format: Optional[str] = None) -> str:
+    """Serialize data as JSON or YAML."""
+    if (output_format or get_output_format()) == "yaml":
+        import yaml
+
+        return yaml.safe_dump(data, sort_keys=False, allow_unicode=True).rstrip("\n")
+    return json.dumps(data, indent=2, ensure_ascii=False, default=str)
+
+
+def emit(data: Any, output_format: Optional[str] = None) -> None:
+    """Print data as JSON or YAML."""
+    print(render(data, output_format))
+
+
+def set_quiet(quiet: bool) -> None:
+    """Enable or disable quiet mode for this process."""
+    global _quiet
+    _quiet = quiet
+
+
+def is_quiet() -> bool:
+    """Return whether only essential results and errors should be printed.
+
+    Quiet mode is enabled by ``--quiet`` or by setting ``PADDI_QUIET=1``.
+    """
+    return _quiet or os.getenv("PADDI_QUIET", "").lower() in ("1", "true", "yes")
+
+
+def extract_quiet_flag(argv: List[str]) -> Tuple[List[str], bool]:
+    """Remove ``--quiet``/``-q`` from command-line arguments.
+
+    Returns:
+        Remaining arguments and whether the flag was given
+    """
+    remaining = [arg for arg in argv if arg not in QUIET_FLAGS]
+    return remaining, len(remaining) != len(argv)
+
+
+def echo(message: str = "") -> None:
+    """Print a human-readable result line, without emoji in quiet mode."""
+    print(strip_symbols(message) if is_quiet() else message)
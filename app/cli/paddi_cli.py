@@ -3,15 +3,40 @@
 import logging
 import os
 import sys
-from typing import Optional
+from typing import List, Optional
 
 from app.cli.base import Command, CommandContext
+from app.cli import theme
+from app.cli.output import get_output_format, is_quiet
 from app.cli.registry import registry
+from app.common.diagnostics import record_failure
+from app.common.exit_codes import ExitCode, exit_code_for
 from app.safety.safety_check import SafetyCheck
 
 logger = logging.getLogger(__name__)
 
 
+def join_compare_runs(args: List[str]) -> List[str]:
+    """Rewrite ``--compare A B`` as ``--compare=A,B``, which Fire reads as one value."""
+    if "--compare" not in args:
+        return list(args)
+    index = args.index("--compare")
+    runs = args[index + 1 : index + 3]
+    if len(runs) < 2 or any(run.startswith("-") for run in runs):
+        return list(args)
+    return [*args[:index], f"--compare={runs[0]},{runs[1]}", *args[index + 3 :]]
+
+
+def offer_debug_bundle(error: BaseException, exit_code: int) -> None:
+    """Record a failed command and point to ``paddi debug bundle``."""
+    # A failed gate is the expected outcome of a run, not something to report
+    if exit_code == ExitCode.FINDINGS_OVER_THRESHOLD:
+        return
+    if record_failure(error, exit_code=int(exit_code)) and not is_quiet():
+        hint = "Run 'paddi debug bundle' to collect diagnostics for a bug report"
+        print(theme.message("hint", hint), file=sys.stderr)
+
+
 class PaddiCLI:
     """Refactored Paddi CLI with command pattern."""
 
@@ -24,22 +49,39 @@ class PaddiCLI:
         """Execute command with error handling based on verbose mode."""
         try:
             command.execute(context)
-        except Exception:
+        except Exception as e:
             if not verbose:
-                # In normal mode, exit cleanly without traceback
-                sys.exit(1)
+                # In normal mode, exit cleanly without traceback, with the failure class's code
+                exit_code = exit_code_for(e)
+                offer_debug_bundle(e, exit_code)
+                sys.exit(exit_code)
             else:
                 # In verbose mode, show full traceback
                 raise
 
     def _create_context(self, **kwargs) -> CommandContext:
         """Create command context from kwargs."""
+        if kwargs.get("output_format") is None:
+            kwargs["output_format"] = get_output_format()
         return CommandContext(**kwargs)
 
-    def init(self, skip_run: bool = False, output: str = "output", verbose: bool = False, **kwargs):
-        """Initialize Paddi with sample data."""
+    def init(
+        self,
+        skip_run: bool = False,
+        output: str = "output",
+        verbose: bool = False,
+        interactive: bool = False,
+        config: Optional[str] = None,
+        **kwargs,
+    ):
+        """Initialize Paddi with sample data, or interactively with --interactive."""
         context = self._create_context(
-            skip_run=skip_run, output_dir=output, verbose=verbose, **kwargs
+            skip_run=skip_run,
+            output_dir=output,
+            verbose=verbose,
+            interactive=interactive,
+            config_file=config,
+            **kwargs,
         )
         command = self.registry.get_command("init")()
         self._execute_command(command, context, verbose)
@@ -55,9 +97,20 @@ class PaddiCLI:
         ai_provider: str = None,
         ollama_model: str = None,
         ollama_endpoint: str = None,
+        format: Optional[str] = None,
+        no_cache: bool = False,
+        incremental: bool = False,
         **kwargs,
     ):
-        """Run complete audit pipeline."""
+        """Run complete audit pipeline.
+
+        Args:
+            format: Alias for --formats of the report (e.g. --format github-annotations)
+            no_cache: Collect again even if a cached collection is recent enough
+            incremental: Collect only the SCC findings changed since the previous collection
+        """
+        if format:
+            kwargs["formats"] = ",".join(filter(None, [kwargs.get("formats"), format]))
         context = self._create_context(
             project_id=project_id,
             organization_id=organization_id,
@@ -68,6 +121,8 @@ class PaddiCLI:
             ai_provider=ai_provider,
             ollama_model=ollama_model,
             ollama_endpoint=ollama_endpoint,
+            no_cache=no_cache,
+            incremental=incremental,
             **kwargs,
         )
         command = self.registry.get_command("audit")()
@@ -86,9 +141,19 @@ class PaddiCLI:
         azure_tenant_id: Optional[str] = None,
         github_owner: Optional[str] = None,
         github_repo: Optional[str] = None,
+        terraform: Optional[str] = None,
+        no_cache: bool = False,
+        incremental: bool = False,
         **kwargs,
     ):
-        """Collect GCP configuration."""
+        """Collect GCP configuration.
+
+        Args:
+            terraform: Terraform state file or `terraform show -json` output to
+                audit declared resources instead of the live project
+            no_cache: Collect again even if a cached collection is recent enough
+            incremental: Collect only the SCC findings changed since the previous collection
+        """
         context = self._create_context(
             project_id=project_id,
             organization_id=organization_id,
@@ -101,6 +166,9 @@ class PaddiCLI:
             azure_tenant_id=azure_tenant_id,
             github_owner=github_owner,
             github_repo=github_repo,
+            terraform_file=terraform,
+            no_cache=no_cache,
+            incremental=incremental,
             **kwargs,
         )
         command = self.registry.get_command("collect")()
@@ -154,12 +222,729 @@ class PaddiCLI:
         command = self.registry.get_command("explain")()
         self._execute_command(command, context, verbose)
 
-    def report(self, output_dir: str = "output", verbose: bool = False, **kwargs):
-        """Generate audit report."""
-        context = self._create_context(output_dir=output_dir, verbose=verbose, **kwargs)
+    def report(
+        self,
+        output_dir: str = "output",
+        verbose: bool = False,
+        format: Optional[str] = None,
+        compare: Optional[str] = None,
+        **kwargs,
+    ):
+        """Generate audit report.
+
+        Args:
+            output_dir: Directory for generated reports
+            verbose: Show full traceback on errors
+            format: Alias for --formats (e.g. --format terraform)
+            compare: Two recorded run IDs (--compare 3 7) to report only what changed between
+        """
+        if format:
+            kwargs["formats"] = ",".join(filter(None, [kwargs.get("formats"), format]))
+        context = self._create_context(
+            output_dir=output_dir, verbose=verbose, compare=compare, **kwargs
+        )
         command = self.registry.get_command("report")()
         self._execute_command(command, context, verbose)
 
+    def plugins(
+        self,
+        action: str = "list",
+        name: Optional[str] = None,
+        plugin_action: str = "analyze",
+        input_file: str = "data/collected.json",
+        verbose: bool = False,
+    ):
+        """List or run external plugins.
+
+        Args:
+            action: 'list' to show installed plugins, 'run' to invoke one
+            name: Plugin name for 'run' (binary paddi-plugin-<name> on PATH)
+            plugin_action: Action sent to the plugin in its JSON request
+            input_file: JSON file passed to the plugin as input
+            verbose: Show full traceback on errors
+        """
+        context = self._create_context(
+            action=action,
+            target=name,
+            plugin_action=plugin_action,
+            input_file=input_file,
+            verbose=verbose,
+        )
+        command = self.registry.get_command("plugins")()
+        self._execute_command(command, context, verbose)
+
+    def findings(
+        self,
+        action: str = "list",
+        finding_id: Optional[str] = None,
+        severity: Optional[str] = None,
+        category: Optional[str] = None,
+        resource: Optional[str] = None,
+        page: int = 1,
+        page_size: int = 20,
+        output_format: Optional[str] = None,
+        sort_by: str = "severity",
+        min_score: Optional[float] = None,
+        input_file: str = "data/explained.json",
+        reason: Optional[str] = None,
+        tag: Optional[str] = None,
+        query: Optional[str] = None,
+        verbose: bool = False,
+    ):
+        """List findings from explained.json, tag them, or mark one as a false positive.
+
+        Args:
+            action: 'list', 'tag', 'untag', 'mark-fp', or 'unmark-fp'
+            finding_id: Fingerprint (or a unique prefix) for 'tag', 'untag', 'mark-fp',
+                and 'unmark-fp'
+            severity: Comma-separated severities (e.g. CRITICAL,HIGH)
+            category: Comma-separated finding categories
+            resource: Substring of the affected resource name
+            page: Page number (1-based)
+            page_size: Findings per page
+            output_format: 'table', 'json', or 'yaml' (defaults to --output)
+            sort_by: 'severity' or 'score' (CVSS base score)
+            min_score: Only show findings with at least this CVSS base score
+            input_file: Path to explained.json
+            reason: Why the finding is a false positive (for 'mark-fp')
+            tag: Comma-separated tags to filter by (for 'list'), to add (for 'tag'), or to
+                remove (for 'untag'; all tags if omitted)
+            query: Filter expression, e.g. 'severity>=HIGH and resource contains "prod"'
+            verbose: Show full traceback on errors
+        """
+        context = self._create_context(
+            action=action,
+            target=finding_id,
+            severity=severity,
+            category=category,
+            resource=resource,
+            page=page,
+            page_size=page_size,
+            output_format=output_format,
+            sort_by=sort_by,
+            min_score=min_score,
+            input_file=input_file,
+            reason=reason,
+            tag=tag,
+            query=query,
+            verbose=verbose,
+        )
+        command = self.registry.get_command("findings")()
+        self._execute_command(command, context, verbose)
+
+    def remediate(
+        self,
+        dry_run: bool = False,
+        input_file: str = "data/explained.json",
+        output_dir: str = "output",
+        verbose: bool = False,
+    ):
+        """Generate gcloud remediation commands for findings.
+
+        Args:
+            dry_run: Print the script instead of writing output/remediation.sh
+            input_file: Path to explained.json
+            output_dir: Directory for remediation.sh
+            verbose: Show full traceback on errors
+        """
+        context = self._create_context(
+            dry_run=dry_run, input_file=input_file, output_dir=output_dir, verbose=verbose
+        )
+        command = self.registry.get_command("remediate")()
+        self._execute_command(command, context, verbose)
+
+    def drift(
+        self,
+        terraform: Optional[str] = None,
+        live: str = "data/collected.json",
+        output_format: Optional[str] = None,
+        verbose: bool = False,
+    ):
+        """Report drift between Terraform and the live configuration.
+
+        Args:
+            terraform: Terraform state file or `terraform show -json` output
+            live: Live collected data from `paddi collect`
+            output_format: 'table', 'json', or 'yaml' (defaults to --output)
+            verbose: Show full traceback on errors
+        """
+        context = self._create_context(
+            terraform_file=terraform,
+            input_file=live,
+            output_format=output_format,
+            verbose=verbose,
+        )
+        command = self.registry.get_command("drift")()
+        self._execute_command(command, context, verbose)
+
+    def export(
+        self,
+        to: Optional[str] = None,
+        dry_run: bool = False,
+        input_file: str = "data/explained.json",
+        project_id: str = "example-project-123",
+        output_dir: str = "output",
+        tag: Optional[str] = None,
+        query: Optional[str] = None,
+        verbose: bool = False,
+    ):
+        """Export findings to an external tracker or service.
+
+        Args:
+            to: Export target ('github-issues', 'splunk-hec', 'scc', 'ocsf', 'pr-comment',
+                or 'check-run')
+            dry_run: Show the changes without making them
+            input_file: Path to explained.json
+            project_id: Audited project, for resources without a full name
+            output_dir: Directory for file exports ('ocsf')
+            tag: Only export findings with any of these comma-separated tags
+            query: Only export findings matching this filter expression
+            verbose: Show full traceback on errors
+        """
+        context = self._create_context(
+            export_target=to,
+            dry_run=dry_run,
+            input_file=input_file,
+            project_id=project_id,
+            output_dir=output_dir,
+            tag=tag,
+            query=query,
+            verbose=verbose,
+        )
+        command = self.registry.get_command("export")()
+        self._execute_command(command, context, verbose)
+
+    def config(
+        self,
+        action: str = "show",
+        name: Optional[str] = None,
+        value=None,
+        config: Optional[str] = None,
+        project_id: str = "example-project-123",
+        verbose: bool = False,
+    ):
+        """Show, edit, or validate paddi.toml, or manage secrets in the OS keyring.
+
+        Args:
+            action: 'show', 'get', 'set', 'validate', 'encrypt', 'set-secret', or 'delete-secret'
+            name: Dotted key for get/set (e.g. general.project_id), or secret name for
+                set-secret/delete-secret (e.g. gemini_api_key)
+            value: New value for set
+            config: Path to the configuration file (defaults to PADDI_CONFIG or ./paddi.toml)
+            project_id: Audited project, for options that default to it
+            verbose: Show full traceback on errors
+        """
+        context = self._create_context(
+            action=action,
+            target=name,
+            config_value=value,
+            config_file=config,
+            project_id=project_id,
+            verbose=verbose,
+        )
+        command = self.registry.get_command("config")()
+        self._execute_command(command, context, verbose)
+
+    def login(
+        self, project_id: Optional[str] = None, config: Optional[str] = None, verbose: bool = False
+    ):
+        """Check GCP credentials, run gcloud login if needed, and save the project.
+
+        Args:
+            project_id: Project to validate and save (defaults to paddi.toml, then the ADC project)
+            config: Path to the configuration file (defaults to PADDI_CONFIG or ./paddi.toml)
+            verbose: Show full traceback on errors
+        """
+        context = self._create_context(project_id=project_id, config_file=config, verbose=verbose)
+        command = self.registry.get_command("login")()
+        self._execute_command(command, context, verbose)
+
+
+    def schema(
+        self,
+        action: str = "export",
+        name: Optional[str] = None,
+        output_dir: Optional[str] = None,
+        verbose: bool = False,
+    ):
+        """Print or write the JSON Schemas of collected.json and explained.json.
+
+        Args:
+            action: Only 'export' is supported
+            name: 'collected' or 'explained' (defaults to both)
+            output_dir: Write <name>.schema.json files here instead of printing
+            verbose: Show full traceback on errors
+        """
+        context = self._create_context(
+            action=action, target=name, output_dir=output_dir or "", verbose=verbose
+        )
+        command = self.registry.get_command("schema")()
+        self._execute_command(command, context, verbose)
+
+    def validate_data(
+        self,
+        inputs=None,
+        schema: Optional[str] = None,
+        output_format: Optional[str] = None,
+        verbose: bool = False,
+    ):
+        """Validate collected.json/explained.json against their JSON Schemas.
+
+        Args:
+            inputs: Files to check (list or comma-separated); defaults to
+                data/collected.json and data/explained.json
+            schema: 'collected' or 'explained' (guessed from each file name by default)
+            output_format: 'table', 'json', or 'yaml' (defaults to --output)
+            verbose: Show full traceback on errors
+        """
+        context = self._create_context(
+            inputs=inputs, target=schema, output_format=output_format, verbose=verbose
+        )
+        command = self.registry.get_command("validate-data")()
+        self._execute_command(command, context, verbose)
+
+    def archive(
+        self,
+        action: str = "create",
+        archive: Optional[str] = None,
+        output_dir: Optional[str] = None,
+        verbose: bool = False,
+    ):
+        """Bundle a run's artifacts into a .tar.zst archive, or verify or extract one.
+
+        Args:
+            action: 'create', 'verify', or 'extract'
+            archive: Archive path (create defaults to paddi-<UTC timestamp>.tar.zst)
+            output_dir: Reports to include for 'create' (default: output); destination
+                for 'extract' (default: the archive name without .tar.zst)
+            verbose: Show full traceback on errors
+        """
+        context = self._create_context(
+            action=action, target=archive, output_dir=output_dir or "", verbose=verbose
+        )
+        command = self.registry.get_command("archive")()
+        self._execute_command(command, context, verbose)
+
+    def bundle(
+        self,
+        action: str = "export",
+        bundle: Optional[str] = None,
+        skip_analysis: bool = False,
+        project_id: str = "example-project-123",
+        location: str = "us-central1",
+        use_mock: bool = True,
+        ai_provider: str = None,
+        ollama_model: str = None,
+        ollama_endpoint: str = None,
+        output_dir: str = "output",
+        verbose: bool = False,
+        **kwargs,
+    ):
+        """Package collected data for an air-gapped transfer, or import and analyze it.
+
+        Args:
+            action: 'export' (on the collecting machine) or 'import' (on the analysis machine)
+            bundle: Bundle path (export defaults to paddi-bundle-<UTC timestamp>.tar.zst)
+            skip_analysis: Only unpack the collected data on import, without explain and report
+            project_id: Project used for analysis on import
+            output_dir: Directory for the reports generated on import
+            verbose: Show full traceback on errors
+        """
+        context = self._create_context(
+            action=action,
+            target=bundle,
+            skip_run=skip_analysis,
+            project_id=project_id,
+            location=location,
+            use_mock=use_mock,
+            ai_provider=ai_provider,
+            ollama_model=ollama_model,
+            ollama_endpoint=ollama_endpoint,
+            output_dir=output_dir,
+            verbose=verbose,
+            **kwargs,
+        )
+        command = self.registry.get_command("bundle")()
+        self._execute_command(command, context, verbose)
+
+    def debug(self, action: str = "bundle", output: Optional[str] = None, verbose: bool = False):
+        """Collect sanitized logs, config, and versions into a ZIP for a bug report.
+
+        Args:
+            action: 'bundle'
+            output: ZIP path (default: paddi-debug-<UTC timestamp>.zip)
+            verbose: Show full traceback on errors
+        """
+        context = self._create_context(action=action, target=output, verbose=verbose)
+        command = self.registry.get_command("debug")()
+        self._execute_command(command, context, verbose)
+
+    def benchmark(
+        self,
+        sizes: Optional[str] = None,
+        use_mock: bool = True,
+        project_id: str = "example-project-123",
+        location: str = "us-central1",
+        ai_provider: str = None,
+        ollama_model: str = None,
+        ollama_endpoint: str = None,
+        formats: Optional[str] = None,
+        save: Optional[str] = None,
+        output_format: Optional[str] = None,
+        verbose: bool = False,
+    ):
+        """Run the pipeline on synthetic datasets, measuring time, memory, and tokens per stage.
+
+        Args:
+            sizes: Dataset sizes in resources, comma-separated (default: 100,1000,10000)
+            use_mock: Stand in for the LLM (set --no-use-mock to call the AI provider)
+            formats: Report formats for the report stage (default: markdown,html)
+            save: Also write the results as JSON to this file, for comparison with later runs
+            output_format: 'table', 'json', or 'yaml' (defaults to --output)
+            verbose: Show full traceback on errors
+        """
+        context = self._create_context(
+            sizes=sizes,
+            use_mock=use_mock,
+            project_id=project_id,
+            location=location,
+            ai_provider=ai_provider,
+            ollama_model=ollama_model,
+            ollama_endpoint=ollama_endpoint,
+            formats=formats,
+            target=save,
+            output_format=output_format,
+            verbose=verbose,
+        )
+        command = self.registry.get_command("benchmark")()
+        self._execute_command(command, context, verbose)
+
+    def clean(
+        self,
+        apply_retention: bool = False,
+        dry_run: bool = False,
+        output_format: Optional[str] = None,
+        verbose: bool = False,
+    ):
+        """Delete runs and archives beyond the [retention] limits in paddi.toml.
+
+        Args:
+            apply_retention: Enforce the [retention] limits (required)
+            dry_run: List what would be deleted without deleting it
+            output_format: 'table', 'json', or 'yaml' (defaults to --output)
+            verbose: Show full traceback on errors
+        """
+        context = self._create_context(
+            apply_retention=apply_retention,
+            dry_run=dry_run,
+            output_format=output_format,
+            verbose=verbose,
+        )
+        command = self.registry.get_command("clean")()
+        self._execute_command(command, context, verbose)
+
+    def trends(
+        self,
+        runs: Optional[int] = None,
+        output_format: Optional[str] = None,
+        verbose: bool = False,
+    ):
+        """Show findings over time, mean time to remediation, and recurring categories.
+
+        Args:
+            runs: Only the most recent N runs (default: all recorded runs)
+            output_format: 'table', 'json', 'yaml', or 'csv' (defaults to --output)
+            verbose: Show full traceback on errors
+        """
+        context = self._create_context(runs=runs, output_format=output_format, verbose=verbose)
+        command = self.registry.get_command("trends")()
+        self._execute_command(command, context, verbose)
+
+    def baseline(
+        self,
+        action: str = "show",
+        run_id: Optional[int] = None,
+        fail_on_new: bool = False,
+        output_format: Optional[str] = None,
+        verbose: bool = False,
+    ):
+        """Manage the accepted baseline run and compare the current findings with it.
+
+        Args:
+            action: 'show', 'set', 'compare', or 'clear'
+            run_id: Run to accept for 'set' (default: the latest recorded run)
+            fail_on_new: With 'compare', exit non-zero if there are findings not in the baseline
+            output_format: 'table', 'json', or 'yaml' (defaults to --output)
+            verbose: Show full traceback on errors
+        """
+        context = self._create_context(
+            action=action,
+            target=run_id,
+            fail_on_new=fail_on_new,
+            output_format=output_format,
+            verbose=verbose,
+        )
+        command = self.registry.get_command("baseline")()
+        self._execute_command(command, context, verbose)
+
+    def query(
+        self,
+        sql: Optional[str] = None,
+        output_format: Optional[str] = None,
+        verbose: bool = False,
+    ):
+        """Run a read-only SQL query over the run history in data/history.db.
+
+        Args:
+            sql: One SQL statement, e.g. "SELECT category, COUNT(*) FROM run_findings
+                GROUP BY category"; lists the tables and their columns if omitted
+            output_format: 'table', 'json', 'yaml', or 'csv' (defaults to --output)
+            verbose: Show full traceback on errors
+        """
+        context = self._create_context(target=sql, output_format=output_format, verbose=verbose)
+        command = self.registry.get_command("query")()
+        self._execute_command(command, context, verbose)
+
+    def runs(
+        self,
+        action: str = "list",
+        run_id: Optional[str] = None,
+        runs: Optional[int] = None,
+        output_format: Optional[str] = None,
+        verbose: bool = False,
+    ):
+        """List audit runs and show the manifest of one, from data/runs/<run_id>/run.json.
+
+        Args:
+            action: 'list' or 'show'
+            run_id: Run to show, or a unique prefix of its ID (default: the latest run)
+            runs: With 'list', only the most recent N runs
+            output_format: 'table', 'json', or 'yaml' (defaults to --output)
+            verbose: Show full traceback on errors
+        """
+        context = self._create_context(
+            action=action,
+            target=run_id,
+            runs=runs,
+            output_format=output_format,
+            verbose=verbose,
+        )
+        command = self.registry.get_command("runs")()
+        self._execute_command(command, context, verbose)
+
+    def site(
+        self,
+        action: str = "build",
+        output_dir: str = "site",
+        runs: Optional[int] = None,
+        verbose: bool = False,
+    ):
+        """Render the run history into a static HTML site ready to publish.
+
+        Args:
+            action: 'build'
+            output_dir: Directory for the site
+            runs: Only the most recent N runs (default: all recorded runs)
+            verbose: Show full traceback on errors
+        """
+        context = self._create_context(
+            action=action, output_dir=output_dir, runs=runs, verbose=verbose
+        )
+        command = self.registry.get_command("site")()
+        self._execute_command(command, context, verbose)
+
+    def serve(
+        self,
+        action: Optional[str] = None,
+        token_action: str = "list",
+        token: Optional[str] = None,
+        host: Optional[str] = None,
+        port: Optional[int] = None,
+        output_dir: str = "output",
+        scope: str = "read",
+        user: Optional[str] = None,
+        output_format: Optional[str] = None,
+        verbose: bool = False,
+    ):
+        """Serve a browser UI to browse runs, findings, and reports, or manage its API tokens.
+
+        Args:
+            action: None to serve, or 'token' to manage API tokens
+            token_action: 'list', 'create', or 'revoke' (for 'token')
+            token: Name of the token to create, or ID of the token to revoke
+            host: Address to listen on (default: [server] host, else 127.0.0.1)
+            port: Port to listen on (default: [server] port, else 8080)
+            output_dir: Directory of the reports to serve
+            scope: Scope of a new token: 'read', 'audit' to also start audits, or 'admin'
+            user: User of [server.users] a new token acts as
+            output_format: 'table', 'json', or 'yaml' for 'token list' (defaults to --output)
+            verbose: Show full traceback on errors
+        """
+        context = self._create_context(
+            action=action,
+            token_action=token_action,
+            target=token,
+            host=host,
+            port=port,
+            output_dir=output_dir,
+            scope=scope,
+            user=user,
+            output_format=output_format,
+            verbose=verbose,
+        )
+        command = self.registry.get_command("serve")()
+        self._execute_command(command, context, verbose)
+
+    def worker(
+        self,
+        action: str = "run",
+        projects: Optional[str] = None,
+        max_jobs: Optional[int] = None,
+        timeout: float = 3600,
+        use_mock: bool = True,
+        ai_provider: Optional[str] = None,
+        formats: Optional[str] = None,
+        output_dir: str = "output",
+        output_format: Optional[str] = None,
+        verbose: bool = False,
+    ):
+        """Audit projects on several machines through the [workers] broker queue.
+
+        Args:
+            action: 'run' to audit queued projects, or 'coordinate' to queue projects
+                and record the workers' results in this machine's history
+            projects: Comma-separated project IDs to queue (for 'coordinate')
+            max_jobs: Stop after this many audits (for 'run'; default: run until stopped)
+            timeout: Seconds to wait for all results (for 'coordinate')
+            use_mock: Use mock data in the workers' audits
+            ai_provider: AI provider of the workers' audits
+            formats: Report formats of the workers' audits
+            output_dir: Directory of the reports (one subdirectory per project)
+            output_format: 'table', 'json', or 'yaml' for 'coordinate' (defaults to --output)
+            verbose: Show full traceback on errors
+        """
+        context = self._create_context(
+            action=action,
+            projects=projects,
+            max_jobs=max_jobs,
+            worker_timeout=float(timeout),
+            use_mock=use_mock,
+            ai_provider=ai_provider,
+            formats=formats,
+            output_dir=output_dir,
+            output_format=output_format,
+            verbose=verbose,
+        )
+        command = self.registry.get_command("worker")()
+        self._execute_command(command, context, verbose)
+
+    def deploy(
+        self,
+        action: str = "k8s",
+        schedule: Optional[str] = None,
+        name: Optional[str] = None,
+        namespace: Optional[str] = None,
+        image: Optional[str] = None,
+        project_id: Optional[str] = None,
+        organization_id: Optional[str] = None,
+        use_mock: bool = False,
+        ai_provider: Optional[str] = None,
+        formats: Optional[str] = None,
+        service_account: Optional[str] = None,
+        volume_claim: Optional[str] = None,
+        region: str = "us-central1",
+        job_project: Optional[str] = None,
+        time_zone: Optional[str] = None,
+        output_dir: Optional[str] = None,
+        verbose: bool = False,
+    ):
+        """Print or write what runs audits on a schedule in Kubernetes or Cloud Run.
+
+        Args:
+            action: 'k8s' (CronJob, and Secrets for paddi.toml and its credentials) or 'cloudrun'
+                (Cloud Run job and the gcloud script that sets it up)
+            schedule: Cron schedule of the audits, e.g. '0 3 * * *'
+            name: Name of the CronJob or job, and prefix of the other objects (default: paddi)
+            namespace: Namespace of the objects (k8s; default: kubectl's current one)
+            image: Image built from Paddi's Dockerfile (default: paddi:latest for k8s,
+                gcr.io/<project>/paddi for cloudrun)
+            project_id: Project to audit (default: from paddi.toml)
+            organization_id: Organization to audit
+            use_mock: Audit mock data instead of the live project
+            ai_provider: AI provider of the audits
+            formats: Report formats of the audits
+            service_account: Service account to run as: for k8s, a Kubernetes service
+                account (Workload Identity) instead of a key Secret; for cloudrun, the ID
+                of one to create (default: <name>-auditor) or the email of an existing one
+            volume_claim: PersistentVolumeClaim keeping data/ and output/ between runs (k8s)
+            region: Region of the job and its schedule (cloudrun)
+            job_project: Project the job runs in (cloudrun; default: the audited project)
+            time_zone: Time zone of the schedule (cloudrun; default: Etc/UTC)
+            output_dir: Write the manifests (k8s) or job.yaml and deploy.sh (cloudrun)
+                here instead of printing
+            verbose: Show full traceback on errors
+        """
+        context = self._create_context(
+            action=action,
+            schedule=schedule,
+            target=name,
+            namespace=namespace,
+            image=image,
+            project_id=project_id,
+            organization_id=organization_id,
+            use_mock=use_mock,
+            ai_provider=ai_provider,
+            formats=formats,
+            service_account=service_account,
+            volume_claim=volume_claim,
+            location=region,
+            job_project=job_project,
+            time_zone=time_zone,
+            output_dir=output_dir or "",
+            verbose=verbose,
+        )
+        command = self.registry.get_command("deploy")()
+        self._execute_command(command, context, verbose)
+
+    def self_update(
+        self,
+        check: bool = False,
+        channel: Optional[str] = None,
+        output_format: Optional[str] = None,
+        verbose: bool = False,
+    ):
+        """Update Paddi to the newest release on GitHub, after verifying its checksum.
+
+        Args:
+            check: Only report whether a newer release is available
+            channel: 'stable' or 'beta' (defaults to [self_update] channel, then 'stable')
+            output_format: 'table', 'json', or 'yaml' (defaults to --output)
+            verbose: Show full traceback on errors
+        """
+        context = self._create_context(
+            dry_run=check, target=channel, output_format=output_format, verbose=verbose
+        )
+        command = self.registry.get_command("self-update")()
+        self._execute_command(command, context, verbose)
+
+    def completion(
+        self,
+        shell: str = "bash",
+        complete: Optional[str] = None,
+        command: Optional[str] = None,
+    ):
+        """Print a shell completion script: eval "$(paddi completion bash)".
+
+        Args:
+            shell: 'bash', 'zsh', or 'fish'
+            complete: Instead of the script, list the candidates for 'project_id' or
+                'run_id', one per line (used by the script)
+            command: With --complete, the command being completed, e.g. 'baseline'
+        """
+        context = self._create_context(action=shell, completion_kind=complete, target=command)
+        command_class = self.registry.get_command("completion")
+        self._execute_command(command_class(), context)
+
     def list_commands(self):
         """List available commands."""
         print("\n📋 Available Paddi Commands:")
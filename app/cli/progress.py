@@ -0,0 +1,130 @@
+"""Progress bars for runs made of several tasks, such as ``paddi audit --projects``.
+
+Each task gets its own bar on stderr, with its current stage, its status, the
+time since it started, and when the stage is expected to end, so a long run
+shows at a glance which projects are done, which failed, and which one is still
+collecting. Log messages are printed above the bars.
+
+The bars are shown only when stderr is a terminal, and not with ``--quiet``,
+structured output, or ``progress_bar = false`` in ``[cli]``. Otherwise every
+method does nothing. The bars may be updated from several threads.
+"""
+
+import logging
+import sys
+import threading
+from typing import Any, Dict, List, Optional, Tuple
+
+from app.config.paddi_config import PaddiConfig
+from app.history.estimates import finish_time
+
+from .multi_project import FAILED, SKIPPED
+from .output import is_quiet, is_structured
+
+PENDING = "pending"
+RUNNING = "running"
+
+
+def progress_enabled(config: Optional[PaddiConfig] = None) -> bool:
+    """Return whether progress bars should be shown for this process."""
+    if is_quiet() or is_structured() or not sys.stderr.isatty():
+        return False
+    return bool((config or PaddiConfig.load()).section("cli").get("progress_bar", True))
+
+
+class TaskProgress:
+    """One progress bar per task, each advancing through the same stages."""
+
+    def __init__(self, stages: Tuple[str, ...], enabled: bool = True):
+        """Initialize TaskProgress.
+
+        Args:
+            stages: Stages every task goes through, in order
+            enabled: Whether to draw the bars at all
+        """
+        self.stages = stages
+        self.enabled = enabled
+        self._lock = threading.Lock()
+        self._tasks: Dict[str, Any] = {}
+        self._progress: Any = None
+        self._redirected: List[Tuple[logging.StreamHandler, object]] = []
+
+    @classmethod
+    def from_config(
+        cls, stages: Tuple[str, ...], config: Optional[PaddiConfig] = None
+    ) -> "TaskProgress":
+        """Create the bars, enabled per ``[cli] progress_bar`` and the terminal."""
+        return cls(stages, enabled=progress_enabled(config))
+
+    def __enter__(self) -> "TaskProgress":
+        if not self.enabled:
+            return self
+        # Imported here, so commands that show no bars do not load rich
+        from rich.console import Console
+        from rich.progress import BarColumn, Progress, TextColumn, TimeElapsedColumn
+
+        self._progress = Progress(
+            TextColumn("{task.description}"),
+            BarColumn(),
+            TextColumn("{task.fields[stage]:<8} {task.fields[status]}"),
+            TimeElapsedColumn(),
+            TextColumn("{task.fields[eta]}"),
+            console=Console(stderr=True),
+        )
+        self._progress.start()
+        # Console log handlers keep the stream they were created with; point them at
+        # the redirected stderr so log messages are printed above the bars
+        for handler in logging.getLogger().handlers:
+            if isinstance(handler, logging.StreamHandler) and not isinstance(
+                handler, logging.FileHandler
+            ):
+                self._redirected.append((handler, handler.setStream(sys.stderr)))
+        return self
+
+    def __exit__(self, *exc_info) -> None:
+        if self._progress is None:
+            return
+        for handler, stream in self._redirected:
+            handler.setStream(stream)
+        self._redirected.clear()
+        self._progress.stop()
+        self._progress = None
+
+    def add(self, name: str) -> None:
+        """Add a bar for a task that has not started yet."""
+        if self._progress is None:
+            return
+        with self._lock:
+            self._tasks[name] = self._progress.add_task(
+                name, total=len(self.stages), start=False, stage="", status=PENDING, eta=""
+            )
+
+    def stage(self, name: str, stage: str, expected: Optional[float] = None) -> None:
+        """Mark a task as running ``stage``; the stages before it are done.
+
+        With the stage's expected duration in seconds, the bar shows when it should end.
+        """
+        if self._progress is None or name not in self._tasks:
+            return
+        with self._lock:
+            task = self._tasks[name]
+            self._progress.start_task(task)
+            completed = self.stages.index(stage) if stage in self.stages else 0
+            eta = f"ETA {finish_time(expected)}" if expected is not None else ""
+            self._progress.update(task, completed=completed, stage=stage, status=RUNNING, eta=eta)
+
+    def finish(self, name: str, status: str) -> None:
+        """Stop a task's clock and show its final status: passed, failed, or skipped.
+
+        A failed task's bar stays at the stage that failed.
+        """
+        if self._progress is None or name not in self._tasks:
+            return
+        with self._lock:
+            task = self._tasks[name]
+            if status == SKIPPED:
+                self._progress.update(task, status=status)
+                return
+            completed = None if status == FAILED else len(self.stages)
+            self._progress.update(task, completed=completed, status=status, eta="")
+            self._progress.stop_task(task)
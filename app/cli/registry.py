@@ -4,11 +4,35 @@ from typing import Dict, Type
 
 from .base import Command
 from .commands import (
+    ArchiveCommand,
     AuditCommand,
+    BaselineCommand,
+    BenchmarkCommand,
+    BundleCommand,
+    CleanCommand,
     CollectCommand,
+    CompletionCommand,
+    ConfigCommand,
+    DebugCommand,
+    DeployCommand,
+    DriftCommand,
     ExplainCommand,
+    ExportCommand,
+    FindingsCommand,
     InitCommand,
+    LoginCommand,
+    PluginsCommand,
+    QueryCommand,
+    RemediateCommand,
     ReportCommand,
+    RunsCommand,
+    SchemaCommand,
+    SelfUpdateCommand,
+    ServeCommand,
+    SiteCommand,
+    TrendsCommand,
+    ValidateDataCommand,
+    WorkerCommand,
 )
 
 
@@ -27,6 +51,30 @@ class CommandRegistry:
         self.register(CollectCommand)
         self.register(ExplainCommand)
         self.register(ReportCommand)
+        self.register(PluginsCommand)
+        self.register(FindingsCommand)
+        self.register(RemediateCommand)
+        self.register(DriftCommand)
+        self.register(ExportCommand)
+        self.register(ConfigCommand)
+        self.register(LoginCommand)
+        self.register(SchemaCommand)
+        self.register(ValidateDataCommand)
+        self.register(ArchiveCommand)
+        self.register(BundleCommand)
+        self.register(DebugCommand)
+        self.register(BenchmarkCommand)
+        self.register(CleanCommand)
+        self.register(TrendsCommand)
+        self.register(BaselineCommand)
+        self.register(QueryCommand)
+        self.register(RunsCommand)
+        self.register(SiteCommand)
+        self.register(ServeCommand)
+        self.register(WorkerCommand)
+        self.register(DeployCommand)
+        self.register(SelfUpdateCommand)
+        self.register(CompletionCommand)
 
     def register(self, command_class: Type[Command]) -> None:
         """Register a command class."""
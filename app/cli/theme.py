@@ -0,0 +1,117 @@
+"""Terminal theming shared by all commands: status symbols and severity colors.
+
+Commands build user-facing text with :func:`message` instead of embedding
+emoji, so every command uses the same symbol for the same kind of message.
+Colors are applied only to stdout, only when it is a terminal, and never when
+``NO_COLOR`` is set (https://no-color.org) or ``--no-color``/``--quiet`` is
+given. Quiet mode also drops the symbols. The text is translated into the
+selected language (see :mod:`app.common.i18n`).
+"""
+
+import os
+import sys
+from typing import List, Optional, TextIO, Tuple
+
+from app.common.i18n import tr
+
+from .output import is_quiet
+
+SYMBOLS = {
+    "success": "✅",
+    "error": "❌",
+    "warning": "⚠️ ",
+    "hint": "💡",
+    "welcome": "🚀",
+    "audit": "🔐",
+    "collect": "📥",
+    "analyze": "🔍",
+    "report": "📝",
+    "summary": "📊",
+    "upload": "☁️ ",
+    "plugins": "🔌",
+    "list": "📋",
+    "locked": "🔒",
+    "agent": "🤖",
+    "fix": "🔧",
+    "document": "📄",
+    "logs": "📜",
+    "safety": "🛡️ ",
+}
+
+# ANSI SGR codes
+STATUS_COLORS = {
+    "success": "32",
+    "error": "31",
+    "warning": "33",
+    "hint": "36",
+}
+
+SEVERITY_COLORS = {
+    "CRITICAL": "1;31",
+    "HIGH": "31",
+    "MEDIUM": "33",
+    "LOW": "36",
+    "INFO": "2",
+}
+
+NO_COLOR_FLAG = "--no-color"
+
+_color_override: Optional[bool] = None
+
+
+def set_color(enabled: Optional[bool]) -> None:
+    """Force colors on or off for this process (``None`` restores detection)."""
+    global _color_override
+    _color_override = enabled
+
+
+def extract_no_color_flag(argv: List[str]) -> Tuple[List[str], bool]:
+    """Remove ``--no-color`` from command-line arguments.
+
+    Returns:
+        Remaining arguments and whether the flag was given
+    """
+    remaining = [arg for arg in argv if arg != NO_COLOR_FLAG]
+    return remaining, len(remaining) != len(argv)
+
+
+def color_enabled(stream: Optional[TextIO] = None) -> bool:
+    """Return whether ANSI colors should be written to ``stream`` (stdout by default)."""
+    if _color_override is not None:
+        return _color_override
+    if os.getenv("NO_COLOR") or is_quiet() or os.getenv("TERM") == "dumb":
+        return False
+    stream = stream or sys.stdout
+    return hasattr(stream, "isatty") and stream.isatty()
+
+
+def colorize(text: str, code: Optional[str]) -> str:
+    """Wrap text in an ANSI color when colors are enabled."""
+    if not code or not color_enabled():
+        return text
+    return f"\033[{code}m{text}\033[0m"
+
+
+def symbol(name: str) -> str:
+    """Return the symbol for a kind of message, or an empty string in quiet mode."""
+    return "" if is_quiet() else SYMBOLS[name]
+
+
+def message(name: str, text: str, color: bool = False) -> str:
+    """Prefix text with the symbol for a kind of message.
+
+    Leading newlines stay in front of the symbol. Use ``color=True`` only for
+    text printed to stdout; log messages stay uncolored.
+    """
+    stripped = tr(text.lstrip("\n"))
+    prefix = symbol(name)
+    body = f"{prefix} {stripped}" if prefix else stripped
+    if color:
+        body = colorize(body, STATUS_COLORS.get(name))
+    return text[: len(text) - len(text.lstrip("\n"))] + body
+
+
+def severity(label: str, value: Optional[str] = None) -> str:
+    """Color a severity label (which may be padded) by its severity."""
+    key = str(value if value is not None else label).strip().upper()
+    return colorize(label, SEVERITY_COLORS.get(key))
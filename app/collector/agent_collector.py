@@ -9,6 +9,7 @@ It supports both real GCP environments and mocked data for testing.
 import json
 import logging
 from abc import ABC, abstractmethod
+from datetime import datetime
 from pathlib import Path
 from typing import Any, Dict, List, Optional
 
@@ -17,8 +18,20 @@ from google.auth.exceptions import RefreshError
 from grpc import StatusCode
 from grpc._channel import _InactiveRpcError
 
+from app.collector.asset_inventory import AssetInventoryCollector
+from app.collector.audit_logs import AuditLogCollector
+from app.collector.firewall_collector import FirewallCollector
+from app.collector.gke_collector import GkeCollector
+from app.collector.sql_collector import SqlCollector
+from app.collector.storage_collector import BucketCollector
+from app.collector.incremental import Snapshot, merge_scc_findings, previous_snapshot
+from app.common.artifact_crypto import save_artifact
 from app.common.auth import check_gcp_credentials
 from app.common.exceptions import AuthenticationError, CollectionError
+from app.common.i18n import tr
+from app.common.models import CollectedData, CollectionMetadata, IamPolicy, SccFinding
+from app.common.retry import RetryPolicy
+from app.plugins.wasm_host import WasmPluginHost
 
 # Configure logging
 logger = logging.getLogger(__name__)
@@ -35,9 +48,12 @@ class CollectorInterface(ABC):
 class IAMCollector(CollectorInterface):
     """Collector for IAM policies and roles."""
 
-    def __init__(self, project_id: str, use_mock: bool = False):
+    def __init__(
+        self, project_id: str, use_mock: bool = False, retry: Optional[RetryPolicy] = None
+    ):
         """Initialize IAMCollector with project configuration."""
         self.project_id = project_id
+        self.retry = retry or RetryPolicy()
         # Ensure use_mock is properly converted to boolean
         if isinstance(use_mock, str):
             self.use_mock = use_mock.lower() in ("true", "1", "yes", "on")
@@ -71,12 +87,13 @@ class IAMCollector(CollectorInterface):
 
     def _raise_auth_error(self, exception=None) -> None:
         """Raise authentication error with consistent message."""
-        error_msg = (
-            "認証エラー: Google Cloud の認証に問題があります。\n"
-            "以下のコマンドを実行して再認証してください:\n"
-            "  gcloud auth application-default login"
+        logger.error(
+            tr(
+                "Authentication error: Google Cloud credentials are not valid.\n"
+                "Run the following command to authenticate again:\n"
+                "  gcloud auth application-default login"
+            )
         )
-        logger.error(error_msg)
         raise AuthenticationError(
             "GCP", {"solution": "gcloud auth application-default login"}
         ) from exception
@@ -94,20 +111,20 @@ class IAMCollector(CollectorInterface):
 
         try:
             logger.info("Attempting to collect real IAM data for project: %s", self.project_id)
-            logger.info("🔑 Google Cloud API に接続中...")
+            logger.info(tr("🔑 Connecting to the Google Cloud API..."))
             from google.cloud import resourcemanager_v3
             from google.iam.v1 import iam_policy_pb2
 
             # Initialize Resource Manager client to get IAM policy
-            logger.info("Resource Manager クライアントを初期化中...")
+            logger.info(tr("Initializing the Resource Manager client..."))
             client = resourcemanager_v3.ProjectsClient()
 
             # Get IAM policy for the project
             resource = f"projects/{self.project_id}"
-            logger.info("📝 IAM ポリシーを取得中: %s", resource)
+            logger.info(tr("📝 Fetching the IAM policy of %s"), resource)
             request = iam_policy_pb2.GetIamPolicyRequest(resource=resource)
 
-            policy = client.get_iam_policy(request=request)
+            policy = self.retry.call("iam.get_iam_policy", client.get_iam_policy, request=request)
             logger.info("IAM policy retrieved successfully")
 
             # Convert protobuf to dict
@@ -124,21 +141,21 @@ class IAMCollector(CollectorInterface):
             return {"bindings": bindings, "etag": etag_str, "version": policy.version}
 
         except ImportError:
-            logger.error("google-cloud-resource-manager がインストールされていません")
-            logger.info("pip install google-cloud-resource-manager を実行してください")
+            logger.error(tr("google-cloud-resource-manager is not installed"))
+            logger.info(tr("Run pip install google-cloud-resource-manager"))
             return self._get_mock_iam_data()
         except RefreshError:
             self._raise_auth_error()
         except _InactiveRpcError as e:
             if e.code() == StatusCode.UNAUTHENTICATED:
                 self._raise_auth_error(e)
-            logger.error("GCP API エラー: %s", e.details())
+            logger.error(tr("GCP API error: %s"), e.details())
             raise CollectionError("IAM", {"error_type": "APIError", "error": e.details()}) from e
         except Exception as e:
             error_type = type(e).__name__
             if "RefreshError" in str(e) or "Reauthentication" in str(e):
                 self._raise_auth_error(e)
-            logger.error("IAMデータの収集中にエラーが発生しました: %s", error_type)
+            logger.error(tr("Collecting IAM data failed: %s"), error_type)
             raise CollectionError("IAM", {"error_type": error_type, "error": str(e)}) from e
 
     def _get_mock_iam_data(self) -> Dict[str, Any]:
@@ -166,7 +183,9 @@ class IAMCollector(CollectorInterface):
 class SCCCollectorAdapter(CollectorInterface):
     """Adapter for the dedicated SCCCollector to maintain backward compatibility."""
 
-    def __init__(self, organization_id: str, use_mock: bool = False):
+    def __init__(
+        self, organization_id: str, use_mock: bool = False, retry: Optional[RetryPolicy] = None
+    ):
         """Initialize SCCCollectorAdapter with organization configuration."""
         # Import the dedicated SCC collector
         from .scc_collector import SCCCollector
@@ -183,25 +202,26 @@ class SCCCollectorAdapter(CollectorInterface):
             self.use_mock,
             use_mock,
         )
-        self.scc_collector = SCCCollector(organization_id)
+        self.scc_collector = SCCCollector(organization_id, retry=retry)
 
-    def collect(self) -> List[Dict[str, Any]]:
-        """Collect SCC findings using the dedicated collector."""
+    def collect(self, since: Optional[datetime] = None) -> List[Dict[str, Any]]:
+        """Collect SCC findings using the dedicated collector (only changes, with ``since``)."""
         try:
-            return self.scc_collector.collect_findings(use_mock=self.use_mock)
+            return self.scc_collector.collect_findings(use_mock=self.use_mock, since=since)
         except RefreshError:
-            error_msg = (
-                "Google Cloud の認証が期限切れです。\n"
-                "以下のコマンドを実行して再認証してください:\n"
-                "  gcloud auth application-default login"
+            logger.error(
+                tr(
+                    "Google Cloud credentials have expired.\n"
+                    "Run the following command to authenticate again:\n"
+                    "  gcloud auth application-default login"
+                )
             )
-            logger.error(error_msg)
             raise AuthenticationError(
                 "GCP", {"solution": "gcloud auth application-default login"}
             ) from None
         except Exception as e:
             error_type = type(e).__name__
-            logger.error("SCCデータの収集中にエラーが発生しました: %s", error_type)
+            logger.error(tr("Collecting SCC data failed: %s"), error_type)
             raise CollectionError("SCC", {"error_type": error_type, "error": str(e)}) from e
 
 
@@ -221,26 +241,55 @@ class GCPConfigurationCollector:
         self.use_mock = use_mock
         self.output_dir = Path(output_dir)
         self.output_dir.mkdir(exist_ok=True)
+        # Shared by the collectors, so the run metadata counts every retry
+        self.retry = RetryPolicy.from_config()
 
         # Initialize collectors
         logger.info(
             "Initializing IAMCollector with project_id=%s, use_mock=%s", project_id, use_mock
         )
-        self.iam_collector = IAMCollector(project_id, use_mock)
+        self.iam_collector = IAMCollector(project_id, use_mock, retry=self.retry)
         logger.info(
             "Initializing SCCCollector with organization_id=%s, use_mock=%s",
             self.organization_id,
             use_mock,
         )
-        self.scc_collector = SCCCollectorAdapter(self.organization_id, use_mock)
+        self.scc_collector = SCCCollectorAdapter(self.organization_id, use_mock, retry=self.retry)
+        # Replaces the IAM collector when [collector.asset_inventory] is enabled
+        self.asset_collector = AssetInventoryCollector.from_config(
+            project_id, organization_id, use_mock, retry=self.retry
+        )
+        self.firewall_collector = FirewallCollector.from_config(
+            project_id, use_mock, retry=self.retry
+        )
+        self.gke_collector = GkeCollector.from_config(project_id, use_mock, retry=self.retry)
+        self.sql_collector = SqlCollector.from_config(project_id, use_mock, retry=self.retry)
+        self.bucket_collector = BucketCollector.from_config(project_id, use_mock, retry=self.retry)
+        # Reads recent activity when [collector.audit_logs] is enabled
+        self.audit_log_collector = AuditLogCollector.from_config(
+            project_id, use_mock, retry=self.retry
+        )
+
+    def collect_all(self, previous: Optional[Snapshot] = None) -> Dict[str, Any]:
+        """Collect all GCP configurations.
 
-    def collect_all(self) -> Dict[str, Any]:
-        """Collect all GCP configurations."""
+        Args:
+            previous: Snapshot to merge the SCC findings changed since into, instead
+                of listing them all (see app.collector.incremental)
+        """
         logger.info("Starting GCP configuration collection for project: %s", self.project_id)
+        # Taken before listing, so an incremental collection from it misses no change
+        timestamp = self._get_timestamp()
 
         # Collect IAM policies with debugging
-        logger.info("About to call IAM collector...")
-        iam_data = self.iam_collector.collect()
+        assets = None
+        if self.asset_collector is not None:
+            logger.info("About to call Cloud Asset Inventory collector...")
+            snapshot = self.asset_collector.collect()
+            iam_data, assets = snapshot["iam_policy"], snapshot["assets"]
+        else:
+            logger.info("About to call IAM collector...")
+            iam_data = self.iam_collector.collect()
         logger.info("IAM data collected, type: %s", type(iam_data))
         if isinstance(iam_data, dict) and "bindings" in iam_data:
             logger.info("IAM bindings count: %d", len(iam_data["bindings"]))
@@ -254,27 +303,67 @@ class GCPConfigurationCollector:
 
         # Collect SCC findings
         logger.info("About to call SCC collector...")
-        scc_data = self.scc_collector.collect()
+        incremental = None
+        if previous is None:
+            scc_data = self.scc_collector.collect()
+        else:
+            changed = self.scc_collector.collect(since=previous.collected_at)
+            scc_data, incremental = merge_scc_findings(
+                previous.data.get("scc_findings") or [], changed
+            )
+            incremental["since"] = previous.collected_at.isoformat()
+            logger.info(
+                "Merged %d changed SCC finding(s) into the previous collection "
+                "(%d added, %d removed, %d expired)",
+                incremental["changed"],
+                incremental["added"],
+                incremental["removed"],
+                incremental["expired"],
+            )
         logger.info("SCC data collected, type: %s", type(scc_data))
 
-        collected_data = {
-            "metadata": {
-                "project_id": self.project_id,
-                "organization_id": self.organization_id,
-                "timestamp": self._get_timestamp(),
-            },
-            "iam_policies": iam_data,
-            "scc_findings": scc_data,
-        }
+        collected_data = CollectedData(
+            metadata=CollectionMetadata(
+                project_id=self.project_id,
+                organization_id=self.organization_id,
+                timestamp=timestamp,
+                retries=self.retry.stats.to_dict(),
+                incremental=incremental,
+            ),
+            iam_policies=IamPolicy.from_dict(iam_data),
+            scc_findings=[SccFinding.from_dict(finding) for finding in scc_data],
+        ).to_dict()
+        if assets is not None:
+            collected_data["assets"] = assets
+        if self.firewall_collector is not None:
+            firewall_rules = self.firewall_collector.collect()
+            if firewall_rules is not None:
+                collected_data["firewall_rules"] = firewall_rules
+        if self.gke_collector is not None:
+            gke_clusters = self.gke_collector.collect()
+            if gke_clusters is not None:
+                collected_data["gke_clusters"] = gke_clusters
+        if self.sql_collector is not None:
+            sql_instances = self.sql_collector.collect()
+            if sql_instances is not None:
+                collected_data["sql_instances"] = sql_instances
+        if self.bucket_collector is not None:
+            collected_data.update(self.bucket_collector.collect() or {})
+        if self.audit_log_collector is not None:
+            logger.info("About to call audit log collector...")
+            collected_data["audit_logs"] = self.audit_log_collector.collect(iam_data)
+
+        # Collect data from WASM collector plugins, if any are installed
+        plugin_data = WasmPluginHost.from_config().run_collectors()
+        if plugin_data:
+            collected_data["plugins"] = plugin_data
 
         logger.info("Collection completed successfully")
         return collected_data
 
     def save_to_file(self, data: Dict[str, Any], filename: str = "collected.json") -> Path:
         """Save collected data to JSON file."""
-        output_path = self.output_dir / filename
-        with open(output_path, "w", encoding="utf-8") as f:
-            json.dump(data, f, indent=2, ensure_ascii=False)
+        output_path = save_artifact(self.output_dir / filename, data, indent=2, ensure_ascii=False)
         logger.info("Data saved to: %s", output_path)
         return output_path
 
@@ -297,6 +386,8 @@ def main(
     github_token: Optional[str] = None,
     github_owner: Optional[str] = None,
     github_repo: Optional[str] = None,
+    terraform: Optional[str] = None,
+    incremental: bool = False,
     **kwargs,
 ):
     """
@@ -314,12 +405,32 @@ def main(
         github_token: GitHub personal access token
         github_owner: GitHub repository owner
         github_repo: GitHub repository name
+        terraform: Terraform state file or `terraform show -json` output to
+            audit instead of live cloud data
+        incremental: Fetch only the SCC findings changed since the previous
+            collection in ``output_dir`` and merge them into it (GCP only)
         **kwargs: Additional provider-specific parameters
     """
     try:
         # Import multi-cloud collector
         from .multi_cloud_collector import MultiCloudCollector
 
+        if incremental and (terraform or providers or provider.lower() != "gcp"):
+            logger.warning(
+                "Incremental collection is supported for GCP only; collecting everything"
+            )
+
+        # Pre-deployment audit of resources declared in Terraform
+        if terraform:
+            from .terraform_collector import TerraformCollector
+
+            data = TerraformCollector(terraform, project_id).collect()
+            output_path = Path(output_dir) / "collected.json"
+            output_path.parent.mkdir(parents=True, exist_ok=True)
+            save_artifact(output_path, data, indent=2, ensure_ascii=False)
+            print(f"✅ Terraform collection successful! Data saved to: {output_path}")
+            return
+
         # Handle multi-cloud collection
         if providers:
             provider_configs = json.loads(providers)
@@ -367,8 +478,13 @@ def main(
             output_dir=output_dir,
         )
 
-        # Collect data
-        data = collector.collect_all()
+        # Collect data, merging changes into the previous collection if asked to
+        previous = None
+        if incremental:
+            previous = previous_snapshot(
+                Path(output_dir) / "collected.json", project_id, collector.organization_id
+            )
+        data = collector.collect_all(previous)
 
         # Save to file
         output_path = collector.save_to_file(data)
@@ -0,0 +1,255 @@
+"""
+Cloud Asset Inventory Collector
+
+Snapshots the resources of a project or organization, and the IAM policies set
+on them, through the Cloud Asset API: two paged listings of the whole scope
+instead of one call per resource API.
+
+Enabled by ``[collector.asset_inventory]`` in paddi.toml::
+
+    [collector.asset_inventory]
+    enabled = true
+    scope = "organization"   # or "project" (default)
+    asset_types = ["storage.googleapis.com/Bucket", "compute.googleapis.com/Firewall"]
+
+The project's own IAM policy becomes ``iam_policies`` of the collected data,
+so the audit sees the same bindings as with the Resource Manager API. Every
+asset, with its IAM policy if it has one, is kept in ``assets``.
+"""
+
+import logging
+from typing import Any, Dict, List, Optional
+
+from google.auth.exceptions import RefreshError
+
+from app.common.exceptions import AuthenticationError, CollectionError
+from app.common.i18n import tr
+from app.common.retry import RetryPolicy
+from app.config.paddi_config import PaddiConfig
+
+logger = logging.getLogger(__name__)
+
+PROJECT_ASSET_TYPE = "cloudresourcemanager.googleapis.com/Project"
+SCOPES = ("project", "organization")
+PAGE_SIZE = 1000
+# asset_v1.ContentType values, so requests can be built without the client library
+RESOURCE = 1
+IAM_POLICY = 2
+
+
+def _policy(policy: Dict[str, Any]) -> Dict[str, Any]:
+    """Convert an asset's IAM policy to the collected data schema."""
+    return {
+        "bindings": [
+            {"role": binding.get("role"), "members": list(binding.get("members") or [])}
+            for binding in policy.get("bindings") or []
+        ],
+        "etag": policy.get("etag") or "",
+        "version": policy.get("version") or 1,
+    }
+
+
+class AssetInventoryCollector:
+    """Collector for resources and IAM policies through the Cloud Asset API."""
+
+    def __init__(
+        self,
+        project_id: str,
+        organization_id: Optional[str] = None,
+        scope: str = "project",
+        asset_types: Optional[List[str]] = None,
+        use_mock: bool = False,
+        retry: Optional[RetryPolicy] = None,
+        client: Any = None,
+    ):
+        """Initialize the collector.
+
+        Raises:
+            ValueError: If the scope is unknown, or organization without an organization ID
+        """
+        if scope not in SCOPES:
+            raise ValueError(
+                f"Unknown asset inventory scope: {scope} (supported: {', '.join(SCOPES)})"
+            )
+        if scope == "organization" and not organization_id:
+            raise ValueError("The organization scope requires --organization_id")
+        self.project_id = project_id
+        self.organization_id = organization_id
+        self.scope = scope
+        self.asset_types = list(asset_types or [])
+        self.use_mock = use_mock
+        self.retry = retry or RetryPolicy()
+        self._client = client
+
+    @classmethod
+    def from_config(
+        cls,
+        project_id: str,
+        organization_id: Optional[str] = None,
+        use_mock: bool = False,
+        retry: Optional[RetryPolicy] = None,
+        config: Optional[PaddiConfig] = None,
+    ) -> Optional["AssetInventoryCollector"]:
+        """Create the collector from ``[collector.asset_inventory]``, or None if not enabled."""
+        section = (config or PaddiConfig.load()).section("collector.asset_inventory")
+        if not section or not section.get("enabled", True):
+            return None
+        return cls(
+            project_id,
+            organization_id,
+            scope=section.get("scope", "project"),
+            asset_types=section.get("asset_types"),
+            use_mock=use_mock,
+            retry=retry,
+        )
+
+    @property
+    def parent(self) -> str:
+        """Resource name of the scope that is listed."""
+        if self.scope == "organization":
+            return f"organizations/{self.organization_id}"
+        return f"projects/{self.project_id}"
+
+    def collect(self) -> Dict[str, Any]:
+        """Snapshot the assets of the scope.
+
+        Returns:
+            ``{"iam_policy": <project IAM policy>, "assets": [...]}``
+
+        Raises:
+            AuthenticationError: If the credentials are not valid
+            CollectionError: If the Cloud Asset API cannot be called
+        """
+        if self.use_mock:
+            return self._get_mock_data()
+
+        try:
+            logger.info(tr("📦 Listing the assets of %s with Cloud Asset Inventory"), self.parent)
+            resources = self._list(RESOURCE)
+            policies = self._list(IAM_POLICY)
+        except ImportError as e:
+            logger.error(tr("google-cloud-asset is not installed"))
+            logger.info(tr("Run pip install google-cloud-asset"))
+            raise CollectionError(
+                "Cloud Asset Inventory", {"error_type": "ImportError", "error": str(e)}
+            ) from e
+        except RefreshError as e:
+            raise AuthenticationError(
+                "GCP", {"solution": "gcloud auth application-default login"}
+            ) from e
+        except Exception as e:
+            error_type = type(e).__name__
+            logger.error(tr("Collecting Cloud Asset Inventory data failed: %s"), error_type)
+            raise CollectionError(
+                "Cloud Asset Inventory", {"error_type": error_type, "error": str(e)}
+            ) from e
+
+        snapshot = self._merge(resources, policies)
+        logger.info(
+            "Collected %d assets from Cloud Asset Inventory (%d with an IAM policy)",
+            len(snapshot["assets"]),
+            len(policies),
+        )
+        return snapshot
+
+    def _client_instance(self) -> Any:
+        if self._client is None:
+            from google.cloud import asset_v1
+
+            self._client = asset_v1.AssetServiceClient()
+        return self._client
+
+    def _list(self, content_type: int) -> List[Dict[str, Any]]:
+        """List every asset of the scope with one content type, following all pages."""
+        client = self._client_instance()
+        request = {"parent": self.parent, "content_type": content_type, "page_size": PAGE_SIZE}
+        if self.asset_types:
+            request["asset_types"] = self.asset_types
+
+        def list_all() -> List[Dict[str, Any]]:
+            return [type(asset).to_dict(asset) for asset in client.list_assets(request=request)]
+
+        return self.retry.call("asset.list_assets", list_all)
+
+    def _merge(
+        self, resources: List[Dict[str, Any]], policies: List[Dict[str, Any]]
+    ) -> Dict[str, Any]:
+        """Join resources and IAM policies by asset name."""
+        assets: Dict[str, Dict[str, Any]] = {}
+        project_name = None
+        for asset in resources:
+            resource = asset.get("resource") or {}
+            data = resource.get("data") or {}
+            assets[asset["name"]] = {
+                "name": asset["name"],
+                "asset_type": asset.get("asset_type"),
+                "location": resource.get("location"),
+                "parent": resource.get("parent"),
+                "data": data,
+            }
+            if asset.get("asset_type") == PROJECT_ASSET_TYPE and data.get("projectId") == (
+                self.project_id
+            ):
+                project_name = asset["name"]
+
+        iam_policy: Dict[str, Any] = {"bindings": [], "etag": "", "version": 1}
+        for asset in policies:
+            entry = assets.setdefault(
+                asset["name"], {"name": asset["name"], "asset_type": asset.get("asset_type")}
+            )
+            entry["iam_policy"] = _policy(asset.get("iam_policy") or {})
+            # Names hold the project number, so a project scope without the
+            # project's resource falls back to its only project policy
+            if asset["name"] == project_name or (
+                project_name is None
+                and self.scope == "project"
+                and asset.get("asset_type") == PROJECT_ASSET_TYPE
+            ):
+                iam_policy = entry["iam_policy"]
+
+        return {"iam_policy": iam_policy, "assets": list(assets.values())}
+
+    def _get_mock_data(self) -> Dict[str, Any]:
+        """Return mock assets for testing."""
+        from app.collector.agent_collector import IAMCollector
+
+        project_policy = IAMCollector(self.project_id, use_mock=True).collect()
+        project = f"//cloudresourcemanager.googleapis.com/projects/{self.project_id}"
+        return {
+            "iam_policy": project_policy,
+            "assets": [
+                {
+                    "name": project,
+                    "asset_type": PROJECT_ASSET_TYPE,
+                    "data": {"projectId": self.project_id, "lifecycleState": "ACTIVE"},
+                    "iam_policy": project_policy,
+                },
+                {
+                    "name": "//storage.googleapis.com/example-public-bucket",
+                    "asset_type": "storage.googleapis.com/Bucket",
+                    "location": "us",
+                    "parent": project,
+                    "data": {"name": "example-public-bucket", "iamConfiguration": {}},
+                    "iam_policy": {
+                        "bindings": [
+                            {"role": "roles/storage.objectViewer", "members": ["allUsers"]}
+                        ],
+                        "etag": "CAE=",
+                        "version": 1,
+                    },
+                },
+                {
+                    "name": f"//compute.googleapis.com/projects/{self.project_id}/global/"
+                    "firewalls/allow-ssh",
+                    "asset_type": "compute.googleapis.com/Firewall",
+                    "location": "global",
+                    "parent": project,
+                    "data": {
+                        "name": "allow-ssh",
+                        "direction": "INGRESS",
+                        "sourceRanges": ["0.0.0.0/0"],
+                        "allowed": [{"IPProtocol": "tcp", "ports": ["22"]}],
+                    },
+                },
+            ],
+        }
@@ -0,0 +1,352 @@
+"""
+Cloud Audit Logs Collector
+
+Reads the recent Admin Activity and Data Access audit log entries of a project
+and flags suspicious activity in them, so the audit covers what was done as
+well as how the project is configured.
+
+Enabled by ``[collector.audit_logs]`` in paddi.toml::
+
+    [collector.audit_logs]
+    window_hours = 24                       # how far back to read
+    log_types = ["activity", "data_access"]
+    filter = 'protoPayload.serviceName="iam.googleapis.com"'   # optional
+    max_entries = 5000
+    known_principals = ["terraform@ops.iam.gserviceaccount.com"]
+    denied_threshold = 10
+
+Only the suspicious activity is kept, under ``audit_logs`` in collected.json:
+
+- ``iam_policy_change_by_new_principal``: an IAM policy set by a principal that
+  is neither a member of the project's IAM policy nor in ``known_principals``
+- ``service_account_key_created``: a user-managed service account key created
+- ``audit_logging_changed``: a log sink or exclusion deleted or changed
+- ``repeated_permission_denied``: a principal denied ``denied_threshold`` times
+"""
+
+import logging
+from collections import Counter
+from datetime import datetime, timedelta, timezone
+from typing import Any, Dict, Iterable, List, Optional, Set
+
+from google.auth.exceptions import RefreshError
+
+from app.common.exceptions import AuthenticationError, CollectionError
+from app.common.i18n import tr
+from app.common.retry import RetryPolicy
+from app.config.paddi_config import PaddiConfig
+
+logger = logging.getLogger(__name__)
+
+LOG_TYPES = ("activity", "data_access")
+DEFAULT_WINDOW_HOURS = 24
+DEFAULT_MAX_ENTRIES = 5000
+DEFAULT_DENIED_THRESHOLD = 10
+PERMISSION_DENIED = 7
+
+KEY_CREATION_METHODS = ("google.iam.admin.v1.CreateServiceAccountKey",)
+LOGGING_CHANGE_METHODS = (
+    "google.logging.v2.ConfigServiceV2.DeleteSink",
+    "google.logging.v2.ConfigServiceV2.UpdateSink",
+    "google.logging.v2.ConfigServiceV2.CreateExclusion",
+    "google.logging.v2.ConfigServiceV2.UpdateExclusion",
+)
+
+
+def _principal(member: str) -> str:
+    """Email of an IAM member, e.g. ``user:a@example.com`` -> ``a@example.com``."""
+    return member.split(":", 1)[1] if ":" in member else member
+
+
+def policy_principals(iam_policy: Dict[str, Any]) -> Set[str]:
+    """Principals that are members of any binding of an IAM policy."""
+    return {
+        _principal(member)
+        for binding in iam_policy.get("bindings") or []
+        for member in binding.get("members") or []
+    }
+
+
+def normalize_entry(entry: Dict[str, Any]) -> Dict[str, Any]:
+    """Keep the fields of an audit log entry (``LogEntry`` API form) that rules use."""
+    payload = entry.get("protoPayload") or {}
+    log_name = entry.get("logName", "")
+    return {
+        "timestamp": entry.get("timestamp"),
+        "log": "data_access" if log_name.endswith("%2Fdata_access") else "activity",
+        "service": payload.get("serviceName"),
+        "method": payload.get("methodName", ""),
+        "principal": (payload.get("authenticationInfo") or {}).get("principalEmail", ""),
+        "resource": payload.get("resourceName"),
+        "caller_ip": (payload.get("requestMetadata") or {}).get("callerIp"),
+        "status_code": (payload.get("status") or {}).get("code", 0),
+    }
+
+
+def _activity(
+    pattern: str, severity: str, entry: Dict[str, Any], description: str, count: int = 1
+) -> Dict[str, Any]:
+    return {
+        "pattern": pattern,
+        "severity": severity,
+        "principal": entry["principal"],
+        "method": entry["method"],
+        "resource": entry["resource"],
+        "timestamp": entry["timestamp"],
+        "caller_ip": entry["caller_ip"],
+        "count": count,
+        "description": description,
+    }
+
+
+def detect_suspicious(
+    entries: Iterable[Dict[str, Any]],
+    known_principals: Iterable[str] = (),
+    denied_threshold: int = DEFAULT_DENIED_THRESHOLD,
+) -> List[Dict[str, Any]]:
+    """Flag suspicious activity in normalized audit log entries, newest first.
+
+    Args:
+        entries: Entries from :func:`normalize_entry`
+        known_principals: Principals expected to change IAM policies
+        denied_threshold: Permission denials by one principal that are suspicious
+    """
+    known = set(known_principals)
+    activity: List[Dict[str, Any]] = []
+    denied: Counter = Counter()
+    latest_denial: Dict[str, Dict[str, Any]] = {}
+    for entry in entries:
+        method, principal = entry["method"], entry["principal"]
+        if entry["status_code"] == PERMISSION_DENIED:
+            denied[principal] += 1
+            latest_denial.setdefault(principal, entry)
+            continue
+        if entry["status_code"]:
+            continue
+        if method.endswith("SetIamPolicy") and principal not in known:
+            activity.append(
+                _activity(
+                    "iam_policy_change_by_new_principal",
+                    "HIGH",
+                    entry,
+                    f"{principal} changed the IAM policy of {entry['resource']}, but is not a "
+                    "member of the project's IAM policy or a known principal.",
+                )
+            )
+        elif method in KEY_CREATION_METHODS:
+            activity.append(
+                _activity(
+                    "service_account_key_created",
+                    "MEDIUM",
+                    entry,
+                    f"{principal} created a user-managed key for {entry['resource']}.",
+                )
+            )
+        elif method in LOGGING_CHANGE_METHODS:
+            activity.append(
+                _activity(
+                    "audit_logging_changed",
+                    "HIGH",
+                    entry,
+                    f"{principal} called {method.rsplit('.', 1)[-1]} on {entry['resource']}, "
+                    "which can stop logs from being kept.",
+                )
+            )
+
+    for principal, count in denied.items():
+        if count >= denied_threshold:
+            activity.append(
+                _activity(
+                    "repeated_permission_denied",
+                    "MEDIUM",
+                    latest_denial[principal],
+                    f"{principal} was denied permission {count} times.",
+                    count,
+                )
+            )
+    return activity
+
+
+class AuditLogCollector:
+    """Collector for suspicious activity in Cloud Audit Logs."""
+
+    def __init__(
+        self,
+        project_id: str,
+        window_hours: int = DEFAULT_WINDOW_HOURS,
+        log_types: Optional[List[str]] = None,
+        log_filter: Optional[str] = None,
+        max_entries: int = DEFAULT_MAX_ENTRIES,
+        known_principals: Optional[List[str]] = None,
+        denied_threshold: int = DEFAULT_DENIED_THRESHOLD,
+        use_mock: bool = False,
+        retry: Optional[RetryPolicy] = None,
+        client: Any = None,
+    ):
+        """Initialize the collector.
+
+        Raises:
+            ValueError: If a log type is unknown
+        """
+        self.log_types = list(log_types or LOG_TYPES)
+        unknown = [log_type for log_type in self.log_types if log_type not in LOG_TYPES]
+        if unknown:
+            raise ValueError(
+                f"Unknown audit log type: {', '.join(unknown)} (supported: {', '.join(LOG_TYPES)})"
+            )
+        self.project_id = project_id
+        self.window_hours = window_hours
+        self.log_filter = log_filter
+        self.max_entries = max_entries
+        self.known_principals = list(known_principals or [])
+        self.denied_threshold = denied_threshold
+        self.use_mock = use_mock
+        self.retry = retry or RetryPolicy()
+        self._client = client
+
+    @classmethod
+    def from_config(
+        cls,
+        project_id: str,
+        use_mock: bool = False,
+        retry: Optional[RetryPolicy] = None,
+        config: Optional[PaddiConfig] = None,
+    ) -> Optional["AuditLogCollector"]:
+        """Create the collector from ``[collector.audit_logs]``, or None if not enabled."""
+        section = (config or PaddiConfig.load()).section("collector.audit_logs")
+        if not section or not section.get("enabled", True):
+            return None
+        return cls(
+            project_id,
+            window_hours=section.get("window_hours", DEFAULT_WINDOW_HOURS),
+            log_types=section.get("log_types"),
+            log_filter=section.get("filter"),
+            max_entries=section.get("max_entries", DEFAULT_MAX_ENTRIES),
+            known_principals=section.get("known_principals"),
+            denied_threshold=section.get("denied_threshold", DEFAULT_DENIED_THRESHOLD),
+            use_mock=use_mock,
+            retry=retry,
+        )
+
+    def build_filter(self, now: Optional[datetime] = None) -> str:
+        """Build the Logging query of the entries in the window."""
+        start = (now or datetime.now(timezone.utc)) - timedelta(hours=self.window_hours)
+        logs = " OR ".join(
+            f'"projects/{self.project_id}/logs/cloudaudit.googleapis.com%2F{log_type}"'
+            for log_type in self.log_types
+        )
+        query = f'logName=({logs}) AND timestamp>="{start.isoformat()}"'
+        if self.log_filter:
+            query += f" AND ({self.log_filter})"
+        return query
+
+    def collect(self, iam_policy: Optional[Dict[str, Any]] = None) -> Dict[str, Any]:
+        """Read the window's audit log entries and flag suspicious activity.
+
+        Args:
+            iam_policy: The project's IAM policy; its members are known principals
+
+        Raises:
+            AuthenticationError: If the credentials are not valid
+            CollectionError: If the Logging API cannot be called
+        """
+        known = policy_principals(iam_policy or {}) | set(self.known_principals)
+        if self.use_mock:
+            entries = self._get_mock_entries()
+        else:
+            try:
+                logger.info(
+                    tr("📜 Reading the audit logs of the last %d hours"), self.window_hours
+                )
+                entries = self._list_entries()
+            except ImportError as e:
+                logger.error(tr("google-cloud-logging is not installed"))
+                logger.info(tr("Run pip install google-cloud-logging"))
+                raise CollectionError(
+                    "Audit Logs", {"error_type": "ImportError", "error": str(e)}
+                ) from e
+            except RefreshError as e:
+                raise AuthenticationError(
+                    "GCP", {"solution": "gcloud auth application-default login"}
+                ) from e
+            except Exception as e:
+                error_type = type(e).__name__
+                logger.error(tr("Collecting audit log data failed: %s"), error_type)
+                raise CollectionError(
+                    "Audit Logs", {"error_type": error_type, "error": str(e)}
+                ) from e
+
+        suspicious = detect_suspicious(entries, known, self.denied_threshold)
+        logger.info(
+            "Read %d audit log entries: %d suspicious activities", len(entries), len(suspicious)
+        )
+        return {
+            "window_hours": self.window_hours,
+            "log_types": self.log_types,
+            "entries": len(entries),
+            "suspicious": suspicious,
+        }
+
+    def _client_instance(self) -> Any:
+        if self._client is None:
+            from google.cloud import logging as cloud_logging
+
+            self._client = cloud_logging.Client(project=self.project_id)
+        return self._client
+
+    def _list_entries(self) -> List[Dict[str, Any]]:
+        """List the window's entries, newest first, up to ``max_entries``."""
+        client = self._client_instance()
+        query = self.build_filter()
+
+        def list_all() -> List[Dict[str, Any]]:
+            return [
+                normalize_entry(entry.to_api_repr())
+                for entry in client.list_entries(
+                    resource_names=[f"projects/{self.project_id}"],
+                    filter_=query,
+                    order_by="timestamp desc",
+                    max_results=self.max_entries,
+                )
+            ]
+
+        return self.retry.call("logging.list_entries", list_all)
+
+    def _get_mock_entries(self) -> List[Dict[str, Any]]:
+        """Return mock audit log entries for testing."""
+        now = datetime.now(timezone.utc).isoformat()
+        project = f"projects/{self.project_id}"
+        entries = [
+            {
+                "logName": f"{project}/logs/cloudaudit.googleapis.com%2Factivity",
+                "timestamp": now,
+                "protoPayload": {
+                    "serviceName": service,
+                    "methodName": method,
+                    "resourceName": resource,
+                    "authenticationInfo": {"principalEmail": principal},
+                    "requestMetadata": {"callerIp": "203.0.113.7"},
+                },
+            }
+            for service, method, resource, principal in (
+                (
+                    "cloudresourcemanager.googleapis.com",
+                    "SetIamPolicy",
+                    project,
+                    "contractor@external.example",
+                ),
+                (
+                    "iam.googleapis.com",
+                    "google.iam.admin.v1.CreateServiceAccountKey",
+                    f"{project}/serviceAccounts/app-sa@project.iam.gserviceaccount.com",
+                    "admin@example.com",
+                ),
+                (
+                    "storage.googleapis.com",
+                    "storage.buckets.get",
+                    f"{project}/buckets/example-bucket",
+                    "developer@example.com",
+                ),
+            )
+        ]
+        return [normalize_entry(entry) for entry in entries]
@@ -0,0 +1,132 @@
+"""Cache of collection results, so repeated audits reuse a recent collection.
+
+During triage, ``paddi audit`` runs again and again on the same project,
+though the cloud configuration rarely changes between runs. A successful
+collection is kept in ``data/cache/collections/<key>.json``, where the key is a
+hash of everything that decides what is collected: the project, organization,
+mock mode, the Terraform file and its content, and ``[collector]`` in
+paddi.toml. A later collection with the same key, within the TTL, copies the
+cached file to ``data/collected.json`` instead of calling the cloud APIs.
+
+The cache is off unless ``[collector.cache]`` in paddi.toml turns it on
+(``enabled = true``), since an audit of cached data can miss a change made
+since; ``ttl_seconds`` sets the TTL (15 minutes by default). ``--no-cache``
+collects again for one run, and refreshes the cache. Cached files are copied
+as written, so encrypted artifacts stay encrypted.
+"""
+
+import hashlib
+import json
+import logging
+import shutil
+import time
+from dataclasses import dataclass
+from pathlib import Path
+from typing import Any, Dict, Optional
+
+from app.config.paddi_config import PaddiConfig
+
+logger = logging.getLogger(__name__)
+
+DEFAULT_TTL_SECONDS = 900
+CACHE_DIR = Path("data") / "cache" / "collections"
+
+
+@dataclass
+class CachedCollection:
+    """A cached collection found for a key."""
+
+    path: Path
+    age_seconds: float
+
+
+class CollectionCache:
+    """Stores collection results by the parameters that produced them."""
+
+    def __init__(
+        self,
+        directory: Path = CACHE_DIR,
+        ttl_seconds: float = DEFAULT_TTL_SECONDS,
+        enabled: bool = True,
+    ):
+        """Initialize CollectionCache.
+
+        Args:
+            directory: Where cached collections are kept
+            ttl_seconds: How long a collection is reused
+            enabled: Whether collections are cached at all
+        """
+        self.directory = Path(directory)
+        self.ttl_seconds = ttl_seconds
+        self.enabled = enabled and ttl_seconds > 0
+
+    @classmethod
+    def from_config(
+        cls, config: Optional[PaddiConfig] = None, directory: Path = CACHE_DIR
+    ) -> "CollectionCache":
+        """Create the cache from ``[collector.cache]`` in paddi.toml; it is off by default."""
+        section = (config or PaddiConfig.load()).section("collector.cache")
+        ttl = float(section.get("ttl_seconds", DEFAULT_TTL_SECONDS))
+        if ttl < 0:
+            raise ValueError("collector.cache.ttl_seconds must not be negative")
+        return cls(directory=directory, ttl_seconds=ttl, enabled=section.get("enabled", False))
+
+    def key(self, parameters: Dict[str, Any]) -> str:
+        """Return the cache key of a collection with these parameters."""
+        canonical = json.dumps(parameters, sort_keys=True, default=str)
+        return hashlib.sha256(canonical.encode("utf-8")).hexdigest()[:32]
+
+    def _path(self, key: str) -> Path:
+        return self.directory / f"{key}.json"
+
+    def lookup(self, key: str, now: Optional[float] = None) -> Optional[CachedCollection]:
+        """Return the cached collection for a key, unless it is missing or expired."""
+        if not self.enabled:
+            return None
+        path = self._path(key)
+        try:
+            age = (now if now is not None else time.time()) - path.stat().st_mtime
+        except OSError:
+            return None
+        if age > self.ttl_seconds:
+            logger.debug("Cached collection %s expired %.0fs ago", key, age - self.ttl_seconds)
+            return None
+        return CachedCollection(path, max(age, 0.0))
+
+    def restore(self, cached: CachedCollection, destination: Path) -> Path:
+        """Copy a cached collection to ``destination``."""
+        destination.parent.mkdir(parents=True, exist_ok=True)
+        shutil.copyfile(cached.path, destination)
+        return destination
+
+    def store(self, key: str, source: Path) -> Optional[Path]:
+        """Cache a collection written to ``source``."""
+        if not self.enabled:
+            return None
+        path = self._path(key)
+        path.parent.mkdir(parents=True, exist_ok=True)
+        temporary = path.with_suffix(".tmp")
+        shutil.copyfile(source, temporary)
+        temporary.replace(path)
+        self._prune()
+        return path
+
+    def _prune(self) -> None:
+        """Delete expired collections, so the cache does not grow without bound."""
+        now = time.time()
+        for path in self.directory.glob("*.json"):
+            try:
+                if now - path.stat().st_mtime > self.ttl_seconds:
+                    path.unlink()
+            except OSError:
+                continue
+
+
+def file_digest(path: Optional[str]) -> Optional[str]:
+    """Return the SHA-256 of a file's content, so edits to it change the cache key."""
+    if not path:
+        return None
+    try:
+        return hashlib.sha256(Path(path).read_bytes()).hexdigest()
+    except OSError:
+        return None
@@ -0,0 +1,220 @@
+"""
+VPC Firewall Rule Collector
+
+Lists the VPC firewall rules of a project through the Compute Engine API, in
+the same form as the Terraform collector's ``firewall_rules``, and marks the
+sensitive ports each rule opens to the internet (``0.0.0.0/0`` or ``::/0``)
+as ``exposed_ports``. The rule-based analyzer turns those into findings, so
+they are reported even when the LLM is unavailable.
+
+Collected by default; ``[collector.firewall]`` in paddi.toml changes the ports
+or turns it off::
+
+    [collector.firewall]
+    enabled = true
+    sensitive_ports = [22, 3389, 5432]   # replaces the default list
+"""
+
+import logging
+from typing import Any, Dict, Iterable, List, Optional, Tuple
+
+from app.common.i18n import tr
+from app.common.retry import RetryPolicy
+from app.config.paddi_config import PaddiConfig
+
+logger = logging.getLogger(__name__)
+
+OPEN_SOURCE_RANGES = ("0.0.0.0/0", "::/0")
+# Remote administration, databases, caches, and cluster control planes
+SENSITIVE_PORTS = {
+    21: "FTP",
+    22: "SSH",
+    23: "Telnet",
+    445: "SMB",
+    1433: "SQL Server",
+    1521: "Oracle",
+    2375: "Docker",
+    3306: "MySQL",
+    3389: "RDP",
+    5432: "PostgreSQL",
+    5984: "CouchDB",
+    6379: "Redis",
+    6443: "Kubernetes API",
+    9042: "Cassandra",
+    9200: "Elasticsearch",
+    10250: "kubelet",
+    11211: "Memcached",
+    27017: "MongoDB",
+}
+DEFAULT_SENSITIVE_PORTS = tuple(SENSITIVE_PORTS)
+PORT_PROTOCOLS = ("tcp", "udp", "sctp", "all")
+
+
+def _port_range(port: str) -> Tuple[int, int]:
+    """Parse ``"22"`` or ``"8000-8100"`` into an inclusive range."""
+    low, _, high = str(port).partition("-")
+    return int(low), int(high or low)
+
+
+def exposed_ports(rule: Dict[str, Any], sensitive_ports: Iterable[int]) -> List[int]:
+    """Return the sensitive ports a firewall rule opens to the internet.
+
+    A rule exposes a port when it is an enabled ingress rule from
+    ``0.0.0.0/0`` or ``::/0`` that allows the port. An allowed protocol without
+    ports allows every port.
+    """
+    if rule.get("disabled") or (rule.get("direction") or "INGRESS") != "INGRESS":
+        return []
+    if not any(source in OPEN_SOURCE_RANGES for source in rule.get("source_ranges") or []):
+        return []
+    exposed = set()
+    for allowed in rule.get("allowed") or []:
+        if str(allowed.get("protocol", "")).lower() not in PORT_PROTOCOLS:
+            continue
+        ranges = [_port_range(port) for port in allowed.get("ports") or []]
+        exposed.update(
+            port
+            for port in sensitive_ports
+            if not ranges or any(low <= port <= high for low, high in ranges)
+        )
+    return sorted(exposed)
+
+
+def mark_exposures(
+    rules: List[Dict[str, Any]], sensitive_ports: Iterable[int] = DEFAULT_SENSITIVE_PORTS
+) -> List[Dict[str, Any]]:
+    """Add ``exposed_ports`` to every rule that opens a sensitive port to the internet."""
+    ports = list(sensitive_ports)
+    for rule in rules:
+        exposed = exposed_ports(rule, ports)
+        if exposed:
+            rule["exposed_ports"] = exposed
+    return rules
+
+
+def sensitive_ports_from_config(config: Optional[PaddiConfig] = None) -> List[int]:
+    """Sensitive ports from ``[collector.firewall]``, or the default list."""
+    section = (config or PaddiConfig.load()).section("collector.firewall")
+    return [int(port) for port in section.get("sensitive_ports", DEFAULT_SENSITIVE_PORTS)]
+
+
+class FirewallCollector:
+    """Collector for VPC firewall rules through the Compute Engine API."""
+
+    def __init__(
+        self,
+        project_id: str,
+        sensitive_ports: Iterable[int] = DEFAULT_SENSITIVE_PORTS,
+        use_mock: bool = False,
+        retry: Optional[RetryPolicy] = None,
+        client: Any = None,
+    ):
+        """Initialize the collector."""
+        self.project_id = project_id
+        self.sensitive_ports = list(sensitive_ports)
+        self.use_mock = use_mock
+        self.retry = retry or RetryPolicy()
+        self._client = client
+
+    @classmethod
+    def from_config(
+        cls,
+        project_id: str,
+        use_mock: bool = False,
+        retry: Optional[RetryPolicy] = None,
+        config: Optional[PaddiConfig] = None,
+    ) -> Optional["FirewallCollector"]:
+        """Create the collector from ``[collector.firewall]``, or None if it is turned off."""
+        config = config or PaddiConfig.load()
+        if not config.section("collector.firewall").get("enabled", True):
+            return None
+        return cls(project_id, sensitive_ports_from_config(config), use_mock, retry)
+
+    def collect(self) -> Optional[List[Dict[str, Any]]]:
+        """List the project's firewall rules, with their exposed ports.
+
+        Returns:
+            The rules, or None if they cannot be listed. The audit then goes on
+            without them, as it does for collections that never had them.
+        """
+        if self.use_mock:
+            return mark_exposures(self._get_mock_rules(), self.sensitive_ports)
+
+        try:
+            logger.info(tr("🧱 Listing the firewall rules of %s"), self.project_id)
+            rules = self._list()
+        except ImportError:
+            logger.warning(tr("google-cloud-compute is not installed; skipping firewall rules"))
+            return None
+        except Exception as e:
+            logger.warning(
+                tr("Collecting firewall rules failed; skipping them: %s"), type(e).__name__
+            )
+            logger.debug("Firewall rule error: %s", e)
+            return None
+
+        mark_exposures(rules, self.sensitive_ports)
+        logger.info(
+            "Collected %d firewall rules: %d expose sensitive ports to the internet",
+            len(rules),
+            sum(1 for rule in rules if rule.get("exposed_ports")),
+        )
+        return rules
+
+    def _client_instance(self) -> Any:
+        if self._client is None:
+            from google.cloud import compute_v1
+
+            self._client = compute_v1.FirewallsClient()
+        return self._client
+
+    def _list(self) -> List[Dict[str, Any]]:
+        client = self._client_instance()
+
+        def list_all() -> List[Dict[str, Any]]:
+            return [
+                self._convert(type(firewall).to_dict(firewall))
+                for firewall in client.list(project=self.project_id)
+            ]
+
+        return self.retry.call("compute.firewalls.list", list_all)
+
+    def _convert(self, firewall: Dict[str, Any]) -> Dict[str, Any]:
+        """Convert a Firewall resource to the collected data schema."""
+        return {
+            "name": firewall.get("name"),
+            "project": self.project_id,
+            "network": (firewall.get("network") or "").rsplit("/", 1)[-1],
+            "direction": firewall.get("direction") or "INGRESS",
+            "disabled": bool(firewall.get("disabled")),
+            "priority": firewall.get("priority", 1000),
+            "source_ranges": list(firewall.get("source_ranges") or []),
+            "target_tags": list(firewall.get("target_tags") or []),
+            "allowed": [
+                # The client names IPProtocol I_p_protocol
+                {"protocol": rule.get("I_p_protocol"), "ports": list(rule.get("ports") or [])}
+                for rule in firewall.get("allowed") or []
+            ],
+        }
+
+    def _get_mock_rules(self) -> List[Dict[str, Any]]:
+        """Return mock firewall rules for testing."""
+        rules = [
+            ("allow-ssh-from-anywhere", ["0.0.0.0/0"], [{"protocol": "tcp", "ports": ["22"]}]),
+            ("allow-https", ["0.0.0.0/0"], [{"protocol": "tcp", "ports": ["443"]}]),
+            ("allow-internal", ["10.128.0.0/9"], [{"protocol": "all", "ports": []}]),
+        ]
+        return [
+            {
+                "name": name,
+                "project": self.project_id,
+                "network": "default",
+                "direction": "INGRESS",
+                "disabled": False,
+                "priority": 1000,
+                "source_ranges": sources,
+                "target_tags": [],
+                "allowed": allowed,
+            }
+            for name, sources, allowed in rules
+        ]
@@ -0,0 +1,163 @@
+"""
+GKE Security Posture Collector
+
+Lists the GKE clusters of a project through the Kubernetes Engine API and keeps
+the settings that decide how hardened each cluster is:
+
+- whether the control plane endpoint is public, and the networks authorized
+  to reach it;
+- whether legacy ABAC authorization is enabled;
+- the Workload Identity pool, if Workload Identity is enabled;
+- whether each node pool upgrades and repairs its nodes automatically.
+
+The rule-based analyzer turns weak settings into findings, so they are
+reported even when the LLM is unavailable.
+
+Collected by default; turn it off with ``[collector.gke] enabled = false``.
+"""
+
+import logging
+from typing import Any, Dict, List, Optional
+
+from app.common.i18n import tr
+from app.common.retry import RetryPolicy
+from app.config.paddi_config import PaddiConfig
+
+logger = logging.getLogger(__name__)
+
+
+class GkeCollector:
+    """Collector for GKE cluster settings through the Kubernetes Engine API."""
+
+    def __init__(
+        self,
+        project_id: str,
+        use_mock: bool = False,
+        retry: Optional[RetryPolicy] = None,
+        client: Any = None,
+    ):
+        """Initialize the collector."""
+        self.project_id = project_id
+        self.use_mock = use_mock
+        self.retry = retry or RetryPolicy()
+        self._client = client
+
+    @classmethod
+    def from_config(
+        cls,
+        project_id: str,
+        use_mock: bool = False,
+        retry: Optional[RetryPolicy] = None,
+        config: Optional[PaddiConfig] = None,
+    ) -> Optional["GkeCollector"]:
+        """Create the collector from ``[collector.gke]``, or None if it is turned off."""
+        if not (config or PaddiConfig.load()).section("collector.gke").get("enabled", True):
+            return None
+        return cls(project_id, use_mock, retry)
+
+    def collect(self) -> Optional[List[Dict[str, Any]]]:
+        """List the project's clusters in every location.
+
+        Returns:
+            The clusters, or None if they cannot be listed. The audit then goes
+            on without them, as it does for collections that never had them.
+        """
+        if self.use_mock:
+            return self._get_mock_clusters()
+
+        try:
+            logger.info(tr("☸️ Listing the GKE clusters of %s"), self.project_id)
+            clusters = self._list()
+        except ImportError:
+            logger.warning(tr("google-cloud-container is not installed; skipping GKE clusters"))
+            return None
+        except Exception as e:
+            logger.warning(
+                tr("Collecting GKE clusters failed; skipping them: %s"), type(e).__name__
+            )
+            logger.debug("GKE cluster error: %s", e)
+            return None
+
+        logger.info("Collected %d GKE clusters", len(clusters))
+        return clusters
+
+    def _client_instance(self) -> Any:
+        if self._client is None:
+            from google.cloud import container_v1
+
+            self._client = container_v1.ClusterManagerClient()
+        return self._client
+
+    def _list(self) -> List[Dict[str, Any]]:
+        client = self._client_instance()
+
+        def list_all() -> List[Dict[str, Any]]:
+            response = client.list_clusters(parent=f"projects/{self.project_id}/locations/-")
+            return [
+                self._convert(type(cluster).to_dict(cluster)) for cluster in response.clusters
+            ]
+
+        return self.retry.call("container.list_clusters", list_all)
+
+    def _convert(self, cluster: Dict[str, Any]) -> Dict[str, Any]:
+        """Convert a Cluster resource to the collected data schema."""
+        private = cluster.get("private_cluster_config") or {}
+        authorized = cluster.get("master_authorized_networks_config") or {}
+        identity = cluster.get("workload_identity_config") or {}
+        return {
+            "name": cluster.get("name"),
+            "project": self.project_id,
+            "location": cluster.get("location"),
+            "master_version": cluster.get("current_master_version"),
+            "release_channel": (cluster.get("release_channel") or {}).get("channel"),
+            "public_endpoint": not private.get("enable_private_endpoint", False),
+            # None when any address may reach the endpoint
+            "authorized_networks": (
+                [block.get("cidr_block") for block in authorized.get("cidr_blocks") or []]
+                if authorized.get("enabled")
+                else None
+            ),
+            "legacy_abac": bool((cluster.get("legacy_abac") or {}).get("enabled")),
+            "workload_identity_pool": identity.get("workload_pool") or None,
+            "node_pools": [
+                {
+                    "name": pool.get("name"),
+                    "auto_upgrade": bool((pool.get("management") or {}).get("auto_upgrade")),
+                    "auto_repair": bool((pool.get("management") or {}).get("auto_repair")),
+                }
+                for pool in cluster.get("node_pools") or []
+            ],
+        }
+
+    def _get_mock_clusters(self) -> List[Dict[str, Any]]:
+        """Return mock clusters for testing."""
+        return [
+            {
+                "name": "legacy-cluster",
+                "project": self.project_id,
+                "location": "us-central1-a",
+                "master_version": "1.27.8-gke.1067000",
+                "release_channel": None,
+                "public_endpoint": True,
+                "authorized_networks": None,
+                "legacy_abac": True,
+                "workload_identity_pool": None,
+                "node_pools": [
+                    {"name": "default-pool", "auto_upgrade": False, "auto_repair": True}
+                ],
+            },
+            {
+                "name": "hardened-cluster",
+                "project": self.project_id,
+                "location": "us-central1",
+                "master_version": "1.30.5-gke.1014001",
+                "release_channel": "REGULAR",
+                "public_endpoint": False,
+                "authorized_networks": ["10.0.0.0/8"],
+                "legacy_abac": False,
+                "workload_identity_pool": f"{self.project_id}.svc.id.goog",
+                "node_pools": [
+                    {"name": "default-pool", "auto_upgrade": True, "auto_repair": True}
+                ],
+            },
+        ]
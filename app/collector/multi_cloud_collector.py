@@ -2,10 +2,10 @@
 Multi-cloud collector module for handling data collection from multiple cloud providers.
 """
 
-import json
 from pathlib import Path
 from typing import Any, Dict, List
 
+from app.common.artifact_crypto import save_artifact
 from app.providers.factory import CloudProviderFactory
 
 
@@ -87,10 +87,7 @@ class MultiCloudCollector:
 
     def save_data(self, data: Dict[str, Any], filename: str = "collected.json") -> Path:
         """Save collected data to JSON file."""
-        output_path = self.output_dir / filename
-        with open(output_path, "w", encoding="utf-8") as f:
-            json.dump(data, f, indent=2)
-        return output_path
+        return save_artifact(self.output_dir / filename, data, indent=2)
 
     def _get_timestamp(self) -> str:
         """Get current timestamp in ISO format."""
@@ -13,12 +13,8 @@ from typing import Any, Dict, List, Optional
 
 from google.api_core import exceptions as gcp_exceptions
 from google.cloud import securitycenter_v1
-from tenacity import (
-    retry,
-    retry_if_exception_type,
-    stop_after_attempt,
-    wait_exponential,
-)
+
+from app.common.retry import RetryPolicy
 
 logger = logging.getLogger(__name__)
 
@@ -26,16 +22,22 @@ logger = logging.getLogger(__name__)
 class SCCCollector:
     """Collector for Security Command Center findings."""
 
-    def __init__(self, organization_id: Optional[str] = None):
+    def __init__(
+        self, organization_id: Optional[str] = None, retry: Optional[RetryPolicy] = None
+    ):
         """
         Initialize SCCCollector with organization configuration.
 
         Args:
             organization_id: GCP organization ID. If not provided, attempts to
                            read from environment variable GCP_ORGANIZATION_ID.
+            retry: Retries transient API errors (defaults to 3 retries)
         """
         self.organization_id = organization_id or os.getenv("GCP_ORGANIZATION_ID")
+        self.retry = retry or RetryPolicy()
         self._client: Optional[securitycenter_v1.SecurityCenterClient] = None
+        # With a time, only findings changed since then are listed, whatever their state
+        self.since: Optional[datetime] = None
 
     @property
     def client(self) -> securitycenter_v1.SecurityCenterClient:
@@ -44,12 +46,16 @@ class SCCCollector:
             self._client = securitycenter_v1.SecurityCenterClient()
         return self._client
 
-    def collect_findings(self, use_mock: bool = False) -> List[Dict[str, Any]]:
+    def collect_findings(
+        self, use_mock: bool = False, since: Optional[datetime] = None
+    ) -> List[Dict[str, Any]]:
         """
         Collect Security Command Center findings.
 
         Args:
             use_mock: If True, returns mock data instead of making API calls.
+            since: List only the findings whose event time is after this, including
+                   those that are no longer active (incremental collection).
 
         Returns:
             List of findings in internal format.
@@ -63,6 +69,7 @@ class SCCCollector:
             use_mock = use_mock.lower() in ("true", "1", "yes", "on")
         else:
             use_mock = bool(use_mock)
+        self.since = since
 
         logger.info("Collecting SCC findings for organization: %s", self.organization_id)
         logger.info("use_mock: %s (type: %s)", use_mock, type(use_mock))
@@ -84,13 +91,21 @@ class SCCCollector:
 
             # Collect findings from different sources
             logger.info("Collecting Security Health Analytics findings...")
-            findings.extend(self._get_sha_findings(self.client, parent))
+            findings.extend(
+                self.retry.call("scc.sha_findings", self._get_sha_findings, self.client, parent)
+            )
 
             logger.info("Collecting Web Security Scanner findings...")
-            findings.extend(self._get_wss_findings(self.client, parent))
+            findings.extend(
+                self.retry.call("scc.wss_findings", self._get_wss_findings, self.client, parent)
+            )
 
             logger.info("Collecting Container Analysis findings...")
-            findings.extend(self._get_container_findings(self.client, parent))
+            findings.extend(
+                self.retry.call(
+                    "scc.container_findings", self._get_container_findings, self.client, parent
+                )
+            )
 
             logger.info("Total findings collected: %d", len(findings))
             return findings
@@ -108,13 +123,6 @@ class SCCCollector:
             logger.error("SCC API call failed: %s", e)
             raise
 
-    @retry(
-        retry=retry_if_exception_type(
-            (gcp_exceptions.ServiceUnavailable, gcp_exceptions.DeadlineExceeded)
-        ),
-        stop=stop_after_attempt(3),
-        wait=wait_exponential(multiplier=1, min=2, max=10),
-    )
     def _get_sha_findings(
         self, client: securitycenter_v1.SecurityCenterClient, parent: str
     ) -> List[Dict[str, Any]]:
@@ -133,7 +141,7 @@ class SCCCollector:
         # Set filter for active SHA findings from the last 7 days
         time_filter = self._get_time_filter(days=7)
         filter_str = (
-            f'state="ACTIVE" AND '
+            f"{self._state_filter()}"
             f'finding_class="VULNERABILITY" AND '
             f'source_properties.source_id="SECURITY_HEALTH_ANALYTICS" AND '
             f"{time_filter}"
@@ -167,13 +175,6 @@ class SCCCollector:
         logger.info("Found %d SHA findings", len(findings))
         return findings
 
-    @retry(
-        retry=retry_if_exception_type(
-            (gcp_exceptions.ServiceUnavailable, gcp_exceptions.DeadlineExceeded)
-        ),
-        stop=stop_after_attempt(3),
-        wait=wait_exponential(multiplier=1, min=2, max=10),
-    )
     def _get_wss_findings(
         self, client: securitycenter_v1.SecurityCenterClient, parent: str
     ) -> List[Dict[str, Any]]:
@@ -192,7 +193,7 @@ class SCCCollector:
         # Set filter for WSS findings
         time_filter = self._get_time_filter(days=7)
         filter_str = (
-            f'state="ACTIVE" AND '
+            f"{self._state_filter()}"
             f'finding_class="VULNERABILITY" AND '
             f'source_properties.source_id="WEB_SECURITY_SCANNER" AND '
             f"{time_filter}"
@@ -217,13 +218,6 @@ class SCCCollector:
         logger.info("Found %d WSS findings", len(findings))
         return findings
 
-    @retry(
-        retry=retry_if_exception_type(
-            (gcp_exceptions.ServiceUnavailable, gcp_exceptions.DeadlineExceeded)
-        ),
-        stop=stop_after_attempt(3),
-        wait=wait_exponential(multiplier=1, min=2, max=10),
-    )
     def _get_container_findings(
         self, client: securitycenter_v1.SecurityCenterClient, parent: str
     ) -> List[Dict[str, Any]]:
@@ -242,7 +236,7 @@ class SCCCollector:
         # Set filter for Container Analysis findings
         time_filter = self._get_time_filter(days=7)
         filter_str = (
-            f'state="ACTIVE" AND '
+            f"{self._state_filter()}"
             f'finding_class="VULNERABILITY" AND '
             f'source_properties.source_id="CONTAINER_SCANNER" AND '
             f"{time_filter}"
@@ -339,15 +333,23 @@ class SCCCollector:
         Generate time filter for findings query.
 
         Args:
-            days: Number of days to look back.
+            days: Number of days to look back, unless only changes since a time are listed.
 
         Returns:
             Time filter string for SCC API.
         """
         end_time = datetime.now(timezone.utc)
-        start_time = end_time - timedelta(days=days)
+        start_time = self.since or end_time - timedelta(days=days)
         return f'event_time >= "{start_time.isoformat()}"'
 
+    def _state_filter(self) -> str:
+        """Return the state clause of findings queries.
+
+        Incremental collection lists inactive findings as well, so the findings
+        resolved since the previous run can be removed from its snapshot.
+        """
+        return "" if self.since else 'state="ACTIVE" AND '
+
     def _is_sha_finding(self, finding: securitycenter_v1.Finding) -> bool:
         """
         Check if a finding is from Security Health Analytics.
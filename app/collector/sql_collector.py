@@ -0,0 +1,165 @@
+"""
+Cloud SQL Configuration Collector
+
+Lists the Cloud SQL instances of a project through the Cloud SQL Admin API and
+keeps the settings that decide how exposed and recoverable each instance is:
+
+- whether it has a public IP, and the networks authorized to connect to it;
+- whether connections must use SSL/TLS;
+- whether automated backups and point-in-time recovery are enabled.
+
+The rule-based analyzer turns weak settings into findings, so they are
+reported even when the LLM is unavailable.
+
+Collected by default; turn it off with ``[collector.sql] enabled = false``.
+"""
+
+import logging
+from typing import Any, Dict, List, Optional
+
+from app.common.i18n import tr
+from app.common.retry import RetryPolicy
+from app.config.paddi_config import PaddiConfig
+
+logger = logging.getLogger(__name__)
+
+SSL_MODES = ("ENCRYPTED_ONLY", "TRUSTED_CLIENT_CERTIFICATE_REQUIRED")
+READ_REPLICA = "READ_REPLICA_INSTANCE"
+
+
+class SqlCollector:
+    """Collector for Cloud SQL instance settings through the Cloud SQL Admin API."""
+
+    def __init__(
+        self,
+        project_id: str,
+        use_mock: bool = False,
+        retry: Optional[RetryPolicy] = None,
+        client: Any = None,
+    ):
+        """Initialize the collector."""
+        self.project_id = project_id
+        self.use_mock = use_mock
+        self.retry = retry or RetryPolicy()
+        self._client = client
+
+    @classmethod
+    def from_config(
+        cls,
+        project_id: str,
+        use_mock: bool = False,
+        retry: Optional[RetryPolicy] = None,
+        config: Optional[PaddiConfig] = None,
+    ) -> Optional["SqlCollector"]:
+        """Create the collector from ``[collector.sql]``, or None if it is turned off."""
+        if not (config or PaddiConfig.load()).section("collector.sql").get("enabled", True):
+            return None
+        return cls(project_id, use_mock, retry)
+
+    def collect(self) -> Optional[List[Dict[str, Any]]]:
+        """List the project's Cloud SQL instances.
+
+        Returns:
+            The instances, or None if they cannot be listed. The audit then goes
+            on without them, as it does for collections that never had them.
+        """
+        if self.use_mock:
+            return self._get_mock_instances()
+
+        try:
+            logger.info(tr("🗄️ Listing the Cloud SQL instances of %s"), self.project_id)
+            instances = self._list()
+        except ImportError:
+            logger.warning(
+                tr("google-api-python-client is not installed; skipping Cloud SQL instances")
+            )
+            return None
+        except Exception as e:
+            logger.warning(
+                tr("Collecting Cloud SQL instances failed; skipping them: %s"), type(e).__name__
+            )
+            logger.debug("Cloud SQL error: %s", e)
+            return None
+
+        logger.info("Collected %d Cloud SQL instances", len(instances))
+        return instances
+
+    def _client_instance(self) -> Any:
+        if self._client is None:
+            from googleapiclient import discovery
+
+            self._client = discovery.build("sqladmin", "v1", cache_discovery=False)
+        return self._client
+
+    def _list(self) -> List[Dict[str, Any]]:
+        """List every instance, following all pages."""
+        instances = self._client_instance().instances()
+
+        def list_all() -> List[Dict[str, Any]]:
+            items: List[Dict[str, Any]] = []
+            request = instances.list(project=self.project_id)
+            while request is not None:
+                response = request.execute()
+                items.extend(response.get("items") or [])
+                request = instances.list_next(request, response)
+            return [self._convert(item) for item in items]
+
+        return self.retry.call("sqladmin.instances.list", list_all)
+
+    def _convert(self, instance: Dict[str, Any]) -> Dict[str, Any]:
+        """Convert a DatabaseInstance resource to the collected data schema."""
+        settings = instance.get("settings") or {}
+        ip = settings.get("ipConfiguration") or {}
+        backup = settings.get("backupConfiguration") or {}
+        version = instance.get("databaseVersion") or ""
+        # MySQL recovers to a point in time from binary logs
+        if version.startswith("MYSQL"):
+            recovery_key = "binaryLogEnabled"
+        else:
+            recovery_key = "pointInTimeRecoveryEnabled"
+        return {
+            "name": instance.get("name"),
+            "project": self.project_id,
+            "region": instance.get("region"),
+            "database_version": version,
+            "replica": instance.get("instanceType") == READ_REPLICA,
+            "public_ip": bool(ip.get("ipv4Enabled")),
+            "private_network": ip.get("privateNetwork"),
+            "authorized_networks": [
+                network.get("value") for network in ip.get("authorizedNetworks") or []
+            ],
+            "ssl_required": ip.get("sslMode") in SSL_MODES or bool(ip.get("requireSsl")),
+            "backups_enabled": bool(backup.get("enabled")),
+            "point_in_time_recovery": bool(backup.get(recovery_key)),
+        }
+
+    def _get_mock_instances(self) -> List[Dict[str, Any]]:
+        """Return mock instances for testing."""
+        return [
+            {
+                "name": "legacy-mysql",
+                "project": self.project_id,
+                "region": "us-central1",
+                "database_version": "MYSQL_5_7",
+                "replica": False,
+                "public_ip": True,
+                "private_network": None,
+                "authorized_networks": ["0.0.0.0/0"],
+                "ssl_required": False,
+                "backups_enabled": False,
+                "point_in_time_recovery": False,
+            },
+            {
+                "name": "orders-postgres",
+                "project": self.project_id,
+                "region": "us-central1",
+                "database_version": "POSTGRES_15",
+                "replica": False,
+                "public_ip": False,
+                "private_network": f"projects/{self.project_id}/global/networks/default",
+                "authorized_networks": [],
+                "ssl_required": True,
+                "backups_enabled": True,
+                "point_in_time_recovery": True,
+            },
+        ]
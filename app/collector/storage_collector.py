@@ -0,0 +1,158 @@
+"""
+Cloud Storage Bucket Collector
+
+Lists the Cloud Storage buckets of a project with their IAM policies and the
+settings that decide whether they can be made public:
+
+- the bucket's IAM bindings, in the same form as the Terraform collector's
+  ``bucket_iam_bindings``, so drift detection compares them too;
+- public access prevention (``enforced`` blocks public grants);
+- uniform bucket-level access (without it, object ACLs can grant access too).
+
+The rule-based analyzer turns public grants and missing protections into
+findings, so the public bucket checks work even when the LLM is unavailable.
+
+Collected by default; turn it off with ``[collector.storage] enabled = false``.
+"""
+
+import logging
+from typing import Any, Dict, List, Optional
+
+from app.common.i18n import tr
+from app.common.retry import RetryPolicy
+from app.config.paddi_config import PaddiConfig
+
+logger = logging.getLogger(__name__)
+
+
+class BucketCollector:
+    """Collector for Cloud Storage buckets and their IAM policies."""
+
+    def __init__(
+        self,
+        project_id: str,
+        use_mock: bool = False,
+        retry: Optional[RetryPolicy] = None,
+        client: Any = None,
+    ):
+        """Initialize the collector."""
+        self.project_id = project_id
+        self.use_mock = use_mock
+        self.retry = retry or RetryPolicy()
+        self._client = client
+
+    @classmethod
+    def from_config(
+        cls,
+        project_id: str,
+        use_mock: bool = False,
+        retry: Optional[RetryPolicy] = None,
+        config: Optional[PaddiConfig] = None,
+    ) -> Optional["BucketCollector"]:
+        """Create the collector from ``[collector.storage]``, or None if it is turned off."""
+        if not (config or PaddiConfig.load()).section("collector.storage").get("enabled", True):
+            return None
+        return cls(project_id, use_mock, retry)
+
+    def collect(self) -> Optional[Dict[str, List[Dict[str, Any]]]]:
+        """List the project's buckets and their IAM bindings.
+
+        Returns:
+            ``{"buckets": [...], "bucket_iam_bindings": [...]}``, or None if the
+            buckets cannot be listed. The audit then goes on without them, as
+            it does for collections that never had them.
+        """
+        if self.use_mock:
+            return self._get_mock_data()
+
+        try:
+            logger.info(tr("🪣 Listing the Cloud Storage buckets of %s"), self.project_id)
+            data = self._list()
+        except ImportError:
+            logger.warning(tr("google-cloud-storage is not installed; skipping buckets"))
+            return None
+        except Exception as e:
+            logger.warning(tr("Collecting buckets failed; skipping them: %s"), type(e).__name__)
+            logger.debug("Cloud Storage error: %s", e)
+            return None
+
+        logger.info(
+            "Collected %d buckets with %d IAM bindings",
+            len(data["buckets"]),
+            len(data["bucket_iam_bindings"]),
+        )
+        return data
+
+    def _client_instance(self) -> Any:
+        if self._client is None:
+            from google.cloud import storage
+
+            self._client = storage.Client(project=self.project_id)
+        return self._client
+
+    def _list(self) -> Dict[str, List[Dict[str, Any]]]:
+        client = self._client_instance()
+        buckets = self.retry.call(
+            "storage.buckets.list", lambda: list(client.list_buckets(project=self.project_id))
+        )
+        data: Dict[str, List[Dict[str, Any]]] = {"buckets": [], "bucket_iam_bindings": []}
+        for bucket in buckets:
+            iam = bucket.iam_configuration
+            data["buckets"].append(
+                {
+                    "name": bucket.name,
+                    "project": self.project_id,
+                    "location": bucket.location,
+                    "public_access_prevention": iam.public_access_prevention,
+                    "uniform_bucket_level_access": bool(
+                        iam.uniform_bucket_level_access_enabled
+                    ),
+                }
+            )
+            policy = self.retry.call(
+                "storage.buckets.get_iam_policy",
+                bucket.get_iam_policy,
+                requested_policy_version=3,
+            )
+            data["bucket_iam_bindings"].extend(
+                {
+                    "bucket": bucket.name,
+                    "role": binding["role"],
+                    "members": sorted(binding["members"]),
+                }
+                for binding in policy.bindings
+            )
+        return data
+
+    def _get_mock_data(self) -> Dict[str, List[Dict[str, Any]]]:
+        """Return mock buckets for testing."""
+        return {
+            "buckets": [
+                {
+                    "name": "example-public-bucket",
+                    "project": self.project_id,
+                    "location": "US",
+                    "public_access_prevention": "inherited",
+                    "uniform_bucket_level_access": False,
+                },
+                {
+                    "name": "example-private-bucket",
+                    "project": self.project_id,
+                    "location": "US",
+                    "public_access_prevention": "enforced",
+                    "uniform_bucket_level_access": True,
+                },
+            ],
+            "bucket_iam_bindings": [
+                {
+                    "bucket": "example-public-bucket",
+                    "role": "roles/storage.objectViewer",
+                    "members": ["allUsers"],
+                },
+                {
+                    "bucket": "example-private-bucket",
+                    "role": "roles/storage.objectAdmin",
+                    "members": [f"serviceAccount:app@{self.project_id}.iam.gserviceaccount.com"],
+                },
+            ],
+        }
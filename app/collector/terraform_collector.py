@@ -0,0 +1,181 @@
+"""
+Terraform State/Plan Collector
+
+Converts IAM and network resources declared in Terraform into the collected
+data schema, so infrastructure as code can be audited before it is deployed.
+
+Accepted inputs:
+    - A state file (terraform.tfstate, format version 4)
+    - ``terraform show -json`` output for a state or a saved plan
+"""
+
+import json
+import logging
+from datetime import datetime, timezone
+from pathlib import Path
+from typing import Any, Dict, Iterator, List, Optional, Tuple
+
+from app.collector.firewall_collector import mark_exposures, sensitive_ports_from_config
+from app.common.exceptions import CollectionError
+from app.common.models import CollectedData, CollectionMetadata, IamBinding, IamPolicy
+
+logger = logging.getLogger(__name__)
+
+PROJECT_IAM_TYPES = (
+    "google_project_iam_member",
+    "google_project_iam_binding",
+    "google_project_iam_policy",
+)
+BUCKET_IAM_TYPES = ("google_storage_bucket_iam_member", "google_storage_bucket_iam_binding")
+FIREWALL_TYPE = "google_compute_firewall"
+
+
+def _module_resources(module: Dict[str, Any]) -> Iterator[Tuple[str, str, Dict[str, Any]]]:
+    """Yield resources of a ``terraform show -json`` module and its children."""
+    for resource in module.get("resources", []):
+        if resource.get("mode", "managed") == "managed":
+            values = resource.get("values") or {}
+            yield resource.get("address", ""), resource.get("type", ""), values
+    for child in module.get("child_modules", []):
+        yield from _module_resources(child)
+
+
+def iter_resources(document: Dict[str, Any]) -> Iterator[Tuple[str, str, Dict[str, Any]]]:
+    """Yield ``(address, type, attributes)`` for every managed resource.
+
+    Raises:
+        ValueError: If the document is neither a state file nor show -json output
+    """
+    for key in ("planned_values", "values"):
+        if isinstance(document.get(key), dict) and "root_module" in document[key]:
+            yield from _module_resources(document[key]["root_module"])
+            return
+
+    if "resources" in document:
+        for resource in document["resources"]:
+            if resource.get("mode", "managed") != "managed":
+                continue
+            prefix = f"{resource['module']}." if resource.get("module") else ""
+            address = f"{prefix}{resource.get('type')}.{resource.get('name')}"
+            for instance in resource.get("instances", []):
+                index = instance.get("index_key")
+                suffix = "" if index is None else f"[{json.dumps(index)}]"
+                yield address + suffix, resource.get("type", ""), instance.get("attributes") or {}
+        return
+
+    raise ValueError("Not a Terraform state file or 'terraform show -json' output")
+
+
+class TerraformCollector:
+    """Collector that reads declared resources from Terraform output."""
+
+    def __init__(self, path: str, project_id: Optional[str] = None):
+        """Initialize TerraformCollector.
+
+        Args:
+            path: Terraform state file or ``terraform show -json`` output
+            project_id: Project to report; defaults to the first declared project
+        """
+        self.path = Path(path)
+        self.project_id = project_id
+
+    def _load(self) -> Dict[str, Any]:
+        """Read and parse the Terraform JSON document."""
+        try:
+            with open(self.path, encoding="utf-8") as f:
+                return json.load(f)
+        except (OSError, json.JSONDecodeError) as e:
+            raise CollectionError(
+                "Terraform", {"error_type": type(e).__name__, "error": str(e)}
+            ) from e
+
+    def collect(self) -> Dict[str, Any]:
+        """Convert declared resources into the collected data schema."""
+        try:
+            resources = list(iter_resources(self._load()))
+        except ValueError as e:
+            raise CollectionError(
+                "Terraform", {"error_type": "FormatError", "error": str(e)}
+            ) from e
+
+        bindings: Dict[str, Dict[str, Any]] = {}
+        bucket_bindings: List[Dict[str, Any]] = []
+        firewall_rules: List[Dict[str, Any]] = []
+        projects: List[str] = []
+
+        for address, resource_type, values in resources:
+            if values.get("project") and values["project"] not in projects:
+                projects.append(values["project"])
+
+            if resource_type in PROJECT_IAM_TYPES:
+                for role, members in self._project_bindings(resource_type, values):
+                    entry = bindings.setdefault(
+                        role, {"role": role, "members": [], "terraform_addresses": []}
+                    )
+                    entry["members"].extend(m for m in members if m not in entry["members"])
+                    entry["terraform_addresses"].append(address)
+            elif resource_type in BUCKET_IAM_TYPES:
+                bucket_bindings.append(
+                    {
+                        "bucket": (values.get("bucket") or "").replace("b/", "", 1),
+                        "role": values.get("role"),
+                        "members": values.get("members") or [values.get("member")],
+                        "terraform_address": address,
+                    }
+                )
+            elif resource_type == FIREWALL_TYPE:
+                firewall_rules.append(self._firewall_rule(address, values))
+
+        mark_exposures(firewall_rules, sensitive_ports_from_config())
+        project_id = self.project_id or (projects[0] if projects else None)
+        logger.info(
+            "Converted %d Terraform resources: %d IAM roles, %d bucket bindings, "
+            "%d firewall rules",
+            len(resources),
+            len(bindings),
+            len(bucket_bindings),
+            len(firewall_rules),
+        )
+        return CollectedData(
+            metadata=CollectionMetadata(
+                project_id=project_id,
+                source="terraform",
+                terraform_file=str(self.path),
+                timestamp=datetime.now(timezone.utc).isoformat(),
+            ),
+            iam_policies=IamPolicy([IamBinding.from_dict(b) for b in bindings.values()]),
+            extra={"bucket_iam_bindings": bucket_bindings, "firewall_rules": firewall_rules},
+        ).to_dict()
+
+    def _project_bindings(
+        self, resource_type: str, values: Dict[str, Any]
+    ) -> List[Tuple[str, List[str]]]:
+        """Return ``(role, members)`` pairs declared by a project IAM resource."""
+        if resource_type == "google_project_iam_member":
+            return [(values.get("role"), [values.get("member")])]
+        if resource_type == "google_project_iam_binding":
+            return [(values.get("role"), list(values.get("members") or []))]
+
+        try:
+            policy = json.loads(values.get("policy_data") or "{}")
+        except json.JSONDecodeError:
+            logger.warning("Skipping unreadable policy_data in google_project_iam_policy")
+            return []
+        return [(b.get("role"), list(b.get("members", []))) for b in policy.get("bindings", [])]
+
+    def _firewall_rule(self, address: str, values: Dict[str, Any]) -> Dict[str, Any]:
+        """Convert a google_compute_firewall resource."""
+        network = values.get("network") or ""
+        return {
+            "name": values.get("name"),
+            "project": values.get("project"),
+            "network": network.rsplit("/", 1)[-1],
+            "direction": values.get("direction") or "INGRESS",
+            "disabled": bool(values.get("disabled")),
+            "source_ranges": list(values.get("source_ranges") or []),
+            "allowed": [
+                {"protocol": rule.get("protocol"), "ports": list(rule.get("ports") or [])}
+                for rule in values.get("allow") or []
+            ],
+            "terraform_address": address,
+        }
@@ -0,0 +1,171 @@
+"""Encryption of artifacts at rest with age.
+
+When ``[security]`` lists age recipients, ``collected.json``,
+``explained.json``, and the Markdown and HTML reports are written encrypted
+to those recipients. Files keep their names. Readers recognize the age
+header and decrypt transparently with the identity from ``PADDI_AGE_IDENTITY``
+or ``age_identity_file``::
+
+    [security]
+    age_recipients = ["age1ql3z7hjy54pw3hyww5ayyfg7zqgvc7w3j2elw8zmrj2kg5sfn9aqmcac8p"]
+    age_identity_file = "~/.config/paddi/age.key"
+
+The files can also be decrypted with the ``age`` CLI: ``age -d -i age.key data/explained.json``.
+"""
+
+import io
+import json
+import logging
+import os
+from pathlib import Path
+from typing import Any, List, Optional, TextIO
+
+from app.common.exceptions import ConfigurationError
+from app.config.paddi_config import PaddiConfig
+
+logger = logging.getLogger(__name__)
+
+AGE_IDENTITY_ENV = "PADDI_AGE_IDENTITY"
+AGE_HEADER = b"age-encryption.org/v1"
+AGE_ARMOR_HEADER = b"-----BEGIN AGE ENCRYPTED FILE-----"
+
+
+def is_age_encrypted(data: bytes) -> bool:
+    """Return whether bytes are an age file (binary or armored)."""
+    return data.startswith(AGE_HEADER) or data.startswith(AGE_ARMOR_HEADER)
+
+
+def _pyrage():
+    """Import the age bindings."""
+    try:
+        import pyrage
+    except ImportError as e:
+        raise ConfigurationError(
+            "security.age_recipients", {"error": "pyrage is not installed (pip install pyrage)"}
+        ) from e
+    return pyrage
+
+
+class AgeCipher:
+    """Encrypt to age recipients and decrypt with age identities."""
+
+    def __init__(
+        self, recipients: Optional[List[str]] = None, identities: Optional[List[str]] = None
+    ):
+        """Initialize the cipher.
+
+        Args:
+            recipients: ``age1...`` public keys to encrypt to (no encryption if empty)
+            identities: ``AGE-SECRET-KEY-1...`` keys to decrypt with
+        """
+        self.recipients = list(recipients or [])
+        self.identities = list(identities or [])
+
+    @classmethod
+    def from_config(cls, config: Optional[PaddiConfig] = None) -> "AgeCipher":
+        """Create a cipher from ``[security]`` in paddi.toml.
+
+        The identity comes from ``PADDI_AGE_IDENTITY`` or, failing that, from
+        ``age_identity_file`` (one key per line; ``#`` comments are skipped).
+
+        Raises:
+            ConfigurationError: If the identity file cannot be read
+        """
+        section = (config or PaddiConfig.load()).section("security")
+        identities = [os.environ[AGE_IDENTITY_ENV]] if os.getenv(AGE_IDENTITY_ENV) else []
+        identity_file = section.get("age_identity_file")
+        if not identities and identity_file:
+            path = Path(identity_file).expanduser()
+            try:
+                lines = path.read_text(encoding="utf-8").splitlines()
+            except OSError as e:
+                raise ConfigurationError(
+                    "security.age_identity_file", {"error": f"cannot read {path}: {e}"}
+                ) from e
+            identities = [line.strip() for line in lines if line.strip().startswith("AGE-")]
+        return cls(section.get("age_recipients", []), identities)
+
+    @property
+    def enabled(self) -> bool:
+        """Whether artifacts are encrypted when written."""
+        return bool(self.recipients)
+
+    def encrypt(self, plaintext: bytes) -> bytes:
+        """Encrypt bytes to every recipient.
+
+        Raises:
+            ConfigurationError: If a recipient is not a valid age public key
+        """
+        pyrage = _pyrage()
+        try:
+            recipients = [pyrage.x25519.Recipient.from_str(r) for r in self.recipients]
+        except Exception as e:
+            raise ConfigurationError(
+                "security.age_recipients", {"error": f"invalid age recipient: {e}"}
+            ) from e
+        return pyrage.encrypt(plaintext, recipients)
+
+    def decrypt(self, ciphertext: bytes, source: str = "data") -> bytes:
+        """Decrypt age-encrypted bytes.
+
+        Raises:
+            ConfigurationError: If there is no identity or none of them can decrypt
+        """
+        if not self.identities:
+            raise ConfigurationError(
+                "security.age_identity_file",
+                {
+                    "error": f"{source} is age-encrypted; "
+                    f"set {AGE_IDENTITY_ENV} or age_identity_file"
+                },
+            )
+        pyrage = _pyrage()
+        try:
+            identities = [pyrage.x25519.Identity.from_str(i) for i in self.identities]
+            return pyrage.decrypt(ciphertext, identities)
+        except Exception as e:
+            raise ConfigurationError(
+                "security.age_identity_file", {"error": f"cannot decrypt {source}: {e}"}
+            ) from e
+
+
+def read_artifact_text(path: Path, cipher: Optional[AgeCipher] = None) -> str:
+    """Read a text artifact, decrypting it if it is age-encrypted."""
+    data = Path(path).read_bytes()
+    if is_age_encrypted(data):
+        data = (cipher or AgeCipher.from_config()).decrypt(data, str(path))
+    return data.decode("utf-8")
+
+
+def open_artifact(path: Path, cipher: Optional[AgeCipher] = None) -> TextIO:
+    """Open a text artifact for streaming.
+
+    An age-encrypted artifact is decrypted in memory first, as age has no
+    streaming interface in the bindings.
+    """
+    with open(path, "rb") as f:
+        header = f.read(len(AGE_ARMOR_HEADER))
+    if is_age_encrypted(header):
+        return io.StringIO(read_artifact_text(path, cipher))
+    return open(path, encoding="utf-8")
+
+
+def load_artifact(path: Path, cipher: Optional[AgeCipher] = None) -> Any:
+    """Load a JSON artifact, decrypting it if it is age-encrypted."""
+    return json.loads(read_artifact_text(path, cipher))
+
+
+def write_artifact_text(path: Path, text: str, cipher: Optional[AgeCipher] = None) -> Path:
+    """Write a text artifact, encrypted when age recipients are configured."""
+    cipher = cipher or AgeCipher.from_config()
+    data = text.encode("utf-8")
+    if cipher.enabled:
+        data = cipher.encrypt(data)
+        logger.debug("Encrypted %s to %d age recipient(s)", path, len(cipher.recipients))
+    Path(path).write_bytes(data)
+    return Path(path)
+
+
+def save_artifact(path: Path, data: Any, cipher: Optional[AgeCipher] = None, **kwargs) -> Path:
+    """Write a JSON artifact (``kwargs`` go to ``json.dumps``), encrypted if configured."""
+    return write_artifact_text(path, json.dumps(data, **kwargs), cipher)
@@ -0,0 +1,305 @@
+"""JSON Schemas for the artifacts passed between pipeline stages.
+
+The schemas are derived from the models in :mod:`app.common.models`, so they
+follow the dataclasses as fields are added. ``collected.json`` accepts every
+shape the collectors write: :class:`~app.common.models.CollectedData` (live or
+from Terraform), one provider's data, or the multi-cloud ``providers`` list.
+Objects allow extra keys, because later stages and plugins add their own.
+
+Files are validated as a stream (see :mod:`app.common.json_stream`): the
+elements of the large arrays are checked one at a time against their item
+schema, and the rest of the document against the whole schema, so memory does
+not grow with the number of findings or bindings.
+"""
+
+import dataclasses
+import json
+import typing
+from pathlib import Path
+from typing import Any, Callable, Dict, List, Optional, TextIO, Tuple
+
+from app.common.artifact_crypto import open_artifact
+from app.common.json_stream import JsonPath, iter_events
+from app.common.models import (
+    CollectionMetadata,
+    IamPolicy,
+    SccFinding,
+    SecurityFinding,
+)
+
+SCHEMA_DIALECT = "https://json-schema.org/draft/2020-12/schema"
+
+_SCALARS = {str: "string", int: "integer", float: "number", bool: "boolean"}
+
+
+def type_schema(hint: Any) -> Dict[str, Any]:
+    """Return the JSON Schema for a type annotation."""
+    origin = typing.get_origin(hint)
+    args = typing.get_args(hint)
+    if hint in _SCALARS:
+        return {"type": _SCALARS[hint]}
+    if dataclasses.is_dataclass(hint):
+        return dataclass_schema(hint)
+    if origin is typing.Union:
+        options = [type_schema(arg) for arg in args if arg is not type(None)]
+        schema = options[0] if len(options) == 1 else {"anyOf": options}
+        return {"anyOf": [schema, {"type": "null"}]} if type(None) in args else schema
+    if origin in (list, List):
+        return {"type": "array", "items": type_schema(args[0]) if args else {}}
+    if origin in (dict, Dict):
+        return {"type": "object", "additionalProperties": type_schema(args[1]) if args else {}}
+    return {}
+
+
+def dataclass_schema(cls: type) -> Dict[str, Any]:
+    """Return the JSON Schema of a dataclass; fields without defaults are required."""
+    hints = typing.get_type_hints(cls)
+    fields = [f for f in dataclasses.fields(cls) if not f.metadata.get("extra")]
+    return {
+        "type": "object",
+        "title": cls.__name__,
+        "description": (cls.__doc__ or "").strip().splitlines()[0],
+        "properties": {f.name: type_schema(hints[f.name]) for f in fields},
+        "required": [
+            f.name
+            for f in fields
+            if f.default is dataclasses.MISSING and f.default_factory is dataclasses.MISSING
+        ],
+    }
+
+
+def _document(name: str, schema: Dict[str, Any]) -> Dict[str, Any]:
+    """Add the dialect and ID to a top-level schema."""
+    return {"$schema": SCHEMA_DIALECT, "$id": f"urn:paddi:schema:{name}", **schema}
+
+
+def explained_schema() -> Dict[str, Any]:
+    """Return the schema of ``explained.json``: an array of findings."""
+    return _document(
+        "explained",
+        {
+            "title": "explained.json",
+            "description": "Findings written by the explainer",
+            "type": "array",
+            "items": dataclass_schema(SecurityFinding),
+        },
+    )
+
+
+def collected_schema() -> Dict[str, Any]:
+    """Return the schema of ``collected.json``."""
+    policy = dataclass_schema(IamPolicy)
+    provider = {
+        "type": "object",
+        "properties": {
+            "provider": {"type": "string"},
+            "iam_policies": {"$ref": "#/$defs/iamPolicies"},
+            "security_findings": {"type": "array", "items": {"type": "object"}},
+            "audit_logs": {"type": "array", "items": {"type": "object"}},
+            "status": {"type": "string"},
+            "error": {"type": "string"},
+        },
+        "required": ["provider"],
+    }
+    return _document(
+        "collected",
+        {
+            "title": "collected.json",
+            "description": "Cloud configuration written by the collector",
+            "type": "object",
+            "properties": {
+                "metadata": dataclass_schema(CollectionMetadata),
+                "provider": {"type": "string"},
+                "project_id": {"type": "string"},
+                "timestamp": {"type": "string"},
+                "iam_policies": {"$ref": "#/$defs/iamPolicies"},
+                "scc_findings": {"type": "array", "items": {"$ref": "#/$defs/sccFinding"}},
+                "security_findings": {"type": "array", "items": {"type": "object"}},
+                "bucket_iam_bindings": {"type": "array", "items": {"type": "object"}},
+                "firewall_rules": {"type": "array", "items": {"type": "object"}},
+                "providers": {"type": "array", "items": {"$ref": "#/$defs/provider"}},
+                "summary": {"type": "object"},
+                "plugins": {"type": "object"},
+            },
+            "anyOf": [
+                {"required": ["iam_policies"]},
+                {"required": ["scc_findings"]},
+                {"required": ["security_findings"]},
+                {"required": ["providers"]},
+            ],
+            "$defs": {
+                "iamPolicies": {
+                    "anyOf": [policy, {"type": "array", "items": policy}],
+                },
+                "sccFinding": dataclass_schema(SccFinding),
+                "provider": provider,
+            },
+        },
+    )
+
+
+ARTIFACTS: Dict[str, Callable[[], Dict[str, Any]]] = {
+    "collected": collected_schema,
+    "explained": explained_schema,
+}
+
+# Containers read element by element, so large files are processed in bounded memory
+STREAMED: Dict[str, List[JsonPath]] = {
+    "collected": [
+        (),
+        ("iam_policies",),
+        ("iam_policies", "bindings"),
+        ("scc_findings",),
+        ("security_findings",),
+        ("bucket_iam_bindings",),
+        ("firewall_rules",),
+        ("providers",),
+        ("providers", "*"),
+        ("providers", "*", "iam_policies"),
+        ("providers", "*", "iam_policies", "bindings"),
+        ("providers", "*", "security_findings"),
+        ("providers", "*", "audit_logs"),
+    ],
+    "explained": [()],
+}
+
+
+def artifact_schema(name: str) -> Dict[str, Any]:
+    """Return the schema of an artifact by name (``collected`` or ``explained``).
+
+    Raises:
+        ValueError: If the artifact is unknown
+    """
+    name = Path(name).stem if name.endswith(".json") else name
+    if name not in ARTIFACTS:
+        raise ValueError(f"Unknown artifact: {name} (available: {', '.join(ARTIFACTS)})")
+    return ARTIFACTS[name]()
+
+
+def artifact_name(path: Path) -> str:
+    """Guess the artifact of a file from its name (e.g. ``prod-explained.json``).
+
+    Raises:
+        ValueError: If the name matches no artifact
+    """
+    matches = [name for name in ARTIFACTS if name in path.name]
+    if len(matches) != 1:
+        raise ValueError(f"Cannot tell the schema of {path}; pass one of: {', '.join(ARTIFACTS)}")
+    return matches[0]
+
+
+def _json_path(parts) -> str:
+    """Format a jsonschema error path as ``$[0].severity``."""
+    return "$" + "".join(f"[{part}]" if isinstance(part, int) else f".{part}" for part in parts)
+
+
+def validate_artifact(data: Any, name: str) -> List[str]:
+    """Return ``<json path>: <problem>`` for each place the data breaks the artifact's schema."""
+    import jsonschema
+
+    validator = jsonschema.Draft202012Validator(artifact_schema(name))
+    errors = sorted(validator.iter_errors(data), key=lambda e: [str(p) for p in e.absolute_path])
+    return [f"{_json_path(error.absolute_path)}: {error.message}" for error in errors]
+
+
+def _resolve(root: Dict[str, Any], schema: Any) -> Dict[str, Any]:
+    """Follow local ``$ref`` pointers such as ``#/$defs/sccFinding``."""
+    while isinstance(schema, dict) and "$ref" in schema:
+        target: Any = root
+        for part in schema["$ref"].lstrip("#/").split("/"):
+            target = target[part]
+        schema = target
+    return schema if isinstance(schema, dict) else {}
+
+
+def schema_at(root: Dict[str, Any], path: JsonPath) -> Dict[str, Any]:
+    """Return the part of a schema that applies to the value at a path.
+
+    Of ``anyOf`` options, the one for an object or an array is taken, as the
+    path shows which of them the value is.
+    """
+    schema = root
+    for part in path:
+        schema = _resolve(root, schema)
+        kind = "array" if isinstance(part, int) else "object"
+        options = [_resolve(root, option) for option in schema.get("anyOf", [])]
+        schema = next((option for option in options if option.get("type") == kind), schema)
+        if isinstance(part, int):
+            schema = schema.get("items", {})
+        else:
+            schema = schema.get("properties", {}).get(part, schema.get("additionalProperties"))
+    return _resolve(root, schema)
+
+
+def validate_stream(stream: TextIO, name: str) -> List[str]:
+    """Validate a JSON artifact read from a stream, like :func:`validate_artifact`.
+
+    Each element of a streamed array is validated on its own; the document is
+    then validated with those arrays left empty.
+
+    Raises:
+        json.JSONDecodeError: If the stream is not valid JSON
+    """
+    import jsonschema
+
+    schema = artifact_schema(name)
+    validator = jsonschema.Draft202012Validator(schema)
+    errors: List[Tuple[JsonPath, str]] = []
+    containers: Dict[JsonPath, Any] = {}
+
+    def check(value: Any, path: JsonPath, subschema: Dict[str, Any]) -> None:
+        for error in validator.evolve(schema=subschema).iter_errors(value):
+            errors.append((path + tuple(error.absolute_path), error.message))
+
+    for kind, path, value in iter_events(stream, STREAMED.get(name, [()])):
+        parent = containers.get(path[:-1]) if path else None
+        if kind == "start":
+            containers[path] = value
+            if isinstance(parent, dict):
+                parent[path[-1]] = value
+            continue
+        if kind == "end":
+            del containers[path]
+        if not path:
+            check(value, path, schema)
+        elif isinstance(parent, list):
+            check(value, path, schema_at(schema, path))
+        elif kind == "value":
+            parent[path[-1]] = value
+
+    errors.sort(key=lambda error: [str(part) for part in error[0]])
+    return [f"{_json_path(path)}: {message}" for path, message in errors]
+
+
+def validate_file(path: Path, name: Optional[str] = None) -> List[str]:
+    """Validate a JSON artifact file, guessing its schema from the file name if not given.
+
+    Raises:
+        FileNotFoundError: If the file does not exist
+        ValueError: If the schema cannot be determined
+    """
+    name = name or artifact_name(path)
+    try:
+        with open_artifact(path) as stream:
+            return validate_stream(stream, name)
+    except json.JSONDecodeError as e:
+        return [f"$: invalid JSON at line {e.lineno}, column {e.colno}: {e.msg}"]
+
+
+def export_schemas(output_dir: Path, names: Optional[List[str]] = None) -> List[Path]:
+    """Write ``<artifact>.schema.json`` for each artifact (or the named ones).
+
+    Returns:
+        Paths of the written files
+
+    Raises:
+        ValueError: If an artifact is unknown
+    """
+    schemas = {name: artifact_schema(name) for name in names or list(ARTIFACTS)}
+    output_dir.mkdir(parents=True, exist_ok=True)
+    paths = []
+    for name, schema in schemas.items():
+        path = output_dir / f"{Path(name).stem}.schema.json"
+        path.write_text(json.dumps(schema, indent=2, ensure_ascii=False) + "\n", encoding="utf-8")
+        paths.append(path)
+    return paths
@@ -2,6 +2,10 @@
 
 import logging
 import os
+import subprocess  # nosec B404
+from typing import Any, Optional, Tuple
+
+from app.common.exceptions import AuthenticationError
 
 logger = logging.getLogger(__name__)
 
@@ -13,3 +17,73 @@ def check_gcp_credentials(use_mock: bool = False) -> None:
             logger.warning(
                 "GOOGLE_APPLICATION_CREDENTIALS not set. Using application default credentials."
             )
+
+
+ADC_LOGIN_COMMAND = ["gcloud", "auth", "application-default", "login"]
+
+
+def load_adc() -> Tuple[Any, Optional[str]]:
+    """Load Application Default Credentials.
+
+    Returns:
+        Credentials and the project they default to (may be None)
+
+    Raises:
+        AuthenticationError: If no usable credentials are found
+    """
+    import google.auth
+    from google.auth.exceptions import DefaultCredentialsError
+
+    try:
+        return google.auth.default()
+    except DefaultCredentialsError as e:
+        raise AuthenticationError(
+            "GCP", {"error": str(e), "solution": " ".join(ADC_LOGIN_COMMAND)}
+        ) from e
+
+
+def run_adc_login() -> None:
+    """Run ``gcloud auth application-default login`` in the foreground.
+
+    Raises:
+        AuthenticationError: If gcloud is not installed or the login fails
+    """
+    try:
+        subprocess.run(ADC_LOGIN_COMMAND, check=True)  # nosec B603
+    except FileNotFoundError as e:
+        raise AuthenticationError(
+            "GCP",
+            {
+                "error": "gcloud not found on PATH",
+                "solution": "https://cloud.google.com/sdk/docs/install",
+            },
+        ) from e
+    except subprocess.CalledProcessError as e:
+        raise AuthenticationError("GCP", {"error": f"gcloud exited with {e.returncode}"}) from e
+
+
+def validate_project_access(credentials: Any, project_id: str) -> str:
+    """Check that the credentials can read the project.
+
+    Returns:
+        The project's display name
+
+    Raises:
+        AuthenticationError: If the project is missing or not accessible
+    """
+    from google.api_core import exceptions as gcp_exceptions
+    from google.cloud import resourcemanager_v3
+
+    client = resourcemanager_v3.ProjectsClient(credentials=credentials)
+    try:
+        project = client.get_project(name=f"projects/{project_id}")
+    except (gcp_exceptions.PermissionDenied, gcp_exceptions.NotFound) as e:
+        raise AuthenticationError(
+            "GCP",
+            {
+                "error": f"cannot access project {project_id}: {e}",
+                "solution": "Check the project ID and that your account has "
+                "resourcemanager.projects.get on it",
+            },
+        ) from e
+    return project.display_name or project_id
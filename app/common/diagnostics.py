@@ -0,0 +1,243 @@
+"""Diagnostics for bug reports (``paddi debug bundle``).
+
+While a command runs, the most recent log lines are kept in memory. When the
+command fails, they are written with the error and its traceback to
+``data/debug/last-failure.json``. Agents run in-process, so their error output
+is part of this log (exec plugins forward their stderr to it as well).
+
+``paddi debug bundle`` then writes a ZIP with:
+
+- ``failure.json``: the last failure, with its log lines and traceback
+- ``paddi.log``: the end of ``PADDI_LOG_FILE``, if it is set
+- ``config.json``: paddi.toml as written, with secrets masked
+- ``environment.json``: Paddi, Python, OS, and package versions, and the
+  Paddi-related environment variables
+
+Everything in the bundle is sanitized first: values of keys such as ``token``
+or ``password`` are masked, and the redaction detectors (emails, API keys,
+tokens, private keys) and credentials in URLs are replaced with placeholders.
+"""
+
+import json
+import logging
+import os
+import platform
+import re
+import sys
+import traceback
+import zipfile
+from collections import deque
+from datetime import datetime, timezone
+from importlib import metadata
+from pathlib import Path
+from typing import Any, Deque, Dict, List, Optional
+
+from app.common.exceptions import ConfigurationError
+from app.common.logging_config import LoggingConfig
+from app.config.paddi_config import PaddiConfig
+from app.explainer.redaction import BUILTIN_DETECTORS, Redactor
+
+logger = logging.getLogger(__name__)
+
+DEBUG_DIR = Path("data") / "debug"
+LAST_FAILURE_NAME = "last-failure.json"
+LOG_BUFFER_LINES = 2000
+LOG_FILE_TAIL_BYTES = 1024 * 1024
+MASK = "********"
+
+# Keys whose values are masked wherever they appear
+_SECRET_KEYS = re.compile(
+    r"(?i)(token|password|passphrase|secret|api_?key|private_?key|credential|webhook|dsn)"
+)
+# Secret references name where a secret is stored, not the secret itself
+_SECRET_REFERENCES = ("vault:", "secretmanager:")
+_URL_CREDENTIALS = r"(?<=://)[^/\s:@]+:[^/\s@]+(?=@)"
+
+_ENV_PREFIXES = ("PADDI_", "AI_PROVIDER", "OLLAMA_", "VERTEX_AI_", "GOOGLE_CLOUD_PROJECT")
+_ENV_NAMES = ("HTTP_PROXY", "HTTPS_PROXY", "NO_PROXY", "PROJECT_ID", "GCP_ORGANIZATION_ID")
+PACKAGES = (
+    "paddi",
+    "google-cloud-aiplatform",
+    "google-cloud-resource-manager",
+    "google-cloud-securitycenter",
+    "requests",
+    "boto3",
+    "azure-identity",
+    "PyGithub",
+    "fire",
+    "jinja2",
+)
+
+
+class LogBuffer(logging.Handler):
+    """Keeps the most recent formatted log lines in memory."""
+
+    def __init__(self, capacity: int = LOG_BUFFER_LINES):
+        super().__init__()
+        self.lines: Deque[str] = deque(maxlen=capacity)
+        self.setFormatter(logging.Formatter(LoggingConfig.DEFAULT_FORMAT))
+
+    def emit(self, record: logging.LogRecord) -> None:
+        try:
+            self.lines.append(self.format(record))
+        except Exception:  # pylint: disable=broad-except
+            self.handleError(record)
+
+
+_buffer: Optional[LogBuffer] = None
+
+
+def install_log_buffer() -> LogBuffer:
+    """Attach the log buffer to the root logger (once)."""
+    global _buffer  # pylint: disable=global-statement
+    root = logging.getLogger()
+    if _buffer is None:
+        _buffer = LogBuffer()
+    if _buffer not in root.handlers:
+        root.addHandler(_buffer)
+    return _buffer
+
+
+def sanitize(data: Any) -> Any:
+    """Mask secret values and redact sensitive strings in JSON-like data."""
+    masked = _mask(data)
+    detectors = {name: re.compile(regex) for name, regex in BUILTIN_DETECTORS.items()}
+    detectors["url_credentials"] = re.compile(_URL_CREDENTIALS)
+    redacted, _ = Redactor(detectors).redact(masked)
+    return redacted
+
+
+def _mask(value: Any, key: str = "") -> Any:
+    """Mask the values of secret-looking keys, recursing into tables and arrays."""
+    if isinstance(value, dict):
+        return {name: _mask(item, str(name)) for name, item in value.items()}
+    if isinstance(value, list):
+        return [_mask(item, key) for item in value]
+    if key and _SECRET_KEYS.search(key) and value not in (None, "", False):
+        if isinstance(value, str) and value.startswith(_SECRET_REFERENCES):
+            return value
+        return MASK
+    return value
+
+
+def record_failure(
+    error: BaseException,
+    argv: Optional[List[str]] = None,
+    exit_code: Optional[int] = None,
+    debug_dir: Path = DEBUG_DIR,
+) -> Optional[Path]:
+    """Write the failure, its traceback, and the recent log lines for ``paddi debug bundle``.
+
+    Returns:
+        The written file, or None if it could not be written
+    """
+    failure = {
+        "timestamp": datetime.now(timezone.utc).isoformat(),
+        "command": list(sys.argv if argv is None else argv),
+        "exit_code": exit_code,
+        "error": {
+            "type": type(error).__name__,
+            "message": str(error),
+            "details": getattr(error, "details", None),
+            "agent": getattr(error, "agent", None),
+        },
+        "traceback": "".join(
+            traceback.format_exception(type(error), error, error.__traceback__)
+        ),
+        "log": list(_buffer.lines) if _buffer else [],
+    }
+    path = Path(debug_dir) / LAST_FAILURE_NAME
+    try:
+        path.parent.mkdir(parents=True, exist_ok=True)
+        path.write_text(
+            json.dumps(sanitize(failure), indent=2, ensure_ascii=False, default=str) + "\n",
+            encoding="utf-8",
+        )
+    except OSError as e:
+        logger.debug("Could not record the failure: %s", e)
+        return None
+    return path
+
+
+def _versions() -> Dict[str, str]:
+    """Return the installed versions of Paddi's main dependencies."""
+    versions = {}
+    for package in PACKAGES:
+        try:
+            versions[package] = metadata.version(package)
+        except metadata.PackageNotFoundError:
+            versions[package] = "not installed"
+    return versions
+
+
+def environment_info() -> Dict[str, Any]:
+    """Describe the runtime: versions, platform, and Paddi-related environment variables."""
+    variables = {
+        name: value
+        for name, value in os.environ.items()
+        if name.startswith(_ENV_PREFIXES) or name.upper() in _ENV_NAMES
+    }
+    return {
+        "python": sys.version.split()[0],
+        "platform": platform.platform(),
+        "packages": _versions(),
+        "environment": dict(sorted(variables.items())),
+    }
+
+
+def _log_file_tail() -> Optional[str]:
+    """Return the end of ``PADDI_LOG_FILE``, if it is set and exists."""
+    log_file = os.getenv("PADDI_LOG_FILE")
+    if not log_file or not Path(log_file).is_file():
+        return None
+    with open(log_file, "rb") as f:
+        f.seek(max(0, Path(log_file).stat().st_size - LOG_FILE_TAIL_BYTES))
+        return f.read().decode("utf-8", errors="replace")
+
+
+def create_debug_bundle(
+    bundle: Path, config: Optional[PaddiConfig] = None, debug_dir: Path = DEBUG_DIR
+) -> List[str]:
+    """Write the diagnostics ZIP.
+
+    Returns:
+        Names of the files in the bundle
+    """
+    config_error = None
+    if config is None:
+        try:
+            config = PaddiConfig.load(strict=False)
+        except ConfigurationError as e:
+            # A broken paddi.toml is worth reporting, not a reason to stop
+            config_error = f"{e} {e.details or ''}".strip()
+
+    contents: Dict[str, str] = {}
+    failure_path = Path(debug_dir) / LAST_FAILURE_NAME
+    if failure_path.is_file():
+        contents["failure.json"] = failure_path.read_text(encoding="utf-8")
+
+    tail = _log_file_tail()
+    if tail is not None:
+        contents["paddi.log"] = sanitize(tail)
+
+    if config is not None:
+        written = {"path": str(config.path) if config.path else None, "config": config.data}
+    else:
+        written = {"error": config_error}
+    contents["config.json"] = json.dumps(sanitize(written), indent=2, ensure_ascii=False)
+    contents["environment.json"] = json.dumps(
+        sanitize(environment_info()), indent=2, ensure_ascii=False
+    )
+
+    bundle.parent.mkdir(parents=True, exist_ok=True)
+    with zipfile.ZipFile(bundle, "w", compression=zipfile.ZIP_DEFLATED) as archive:
+        for name, text in contents.items():
+            archive.writestr(name, text)
+    logger.info("Wrote diagnostics to %s", bundle)
+    return list(contents)
+
+
+def default_debug_bundle_path(when: Optional[datetime] = None) -> Path:
+    """Return ``paddi-debug-<UTC timestamp>.zip``."""
+    timestamp = (when or datetime.now(timezone.utc)).strftime("%Y%m%dT%H%M%SZ")
+    return Path(f"paddi-debug-{timestamp}.zip")
@@ -3,6 +3,8 @@
 Custom exceptions for the application.
 """
 
+from app.common.i18n import tr
+
 
 class PaddiException(Exception):
     """Base exception for all Paddi-specific errors."""
@@ -17,7 +19,9 @@ class AuthenticationError(PaddiException):
     """Raised when authentication fails."""
 
     def __init__(self, provider: str = "GCP", details: dict = None):
-        message = f"認証エラー: {provider}への認証に失敗しました。"
+        message = tr(
+            "Authentication error: could not authenticate to {provider}.", provider=provider
+        )
         super().__init__(message, details)
         self.provider = provider
 
@@ -26,7 +30,9 @@ class CollectionError(PaddiException):
     """Raised when data collection fails."""
 
     def __init__(self, resource_type: str, details: dict = None):
-        message = f"収集エラー: {resource_type}のデータ収集に失敗しました。"
+        message = tr(
+            "Collection error: could not collect {resource_type}.", resource_type=resource_type
+        )
         super().__init__(message, details)
         self.resource_type = resource_type
 
@@ -35,6 +41,104 @@ class ConfigurationError(PaddiException):
     """Raised when configuration is invalid."""
 
     def __init__(self, config_item: str, details: dict = None):
-        message = f"設定エラー: {config_item}の設定が無効です。"
+        message = tr("Configuration error: {config_item} is invalid.", config_item=config_item)
         super().__init__(message, details)
         self.config_item = config_item
+
+
+class PluginError(PaddiException):
+    """Raised when a plugin cannot be loaded or executed."""
+
+    def __init__(self, plugin_name: str, details: dict = None):
+        message = tr("Plugin error: {plugin_name} failed.", plugin_name=plugin_name)
+        super().__init__(message, details)
+        self.plugin_name = plugin_name
+
+
+class AgentError(PaddiException):
+    """Raised when an agent (collector, explainer, reporter) fails unexpectedly."""
+
+    def __init__(self, agent: str, details: dict = None):
+        error = (details or {}).get("error")
+        message = tr("Agent error: {agent} failed.", agent=agent)
+        if error:
+            message += f" ({error})"
+        super().__init__(message, details)
+        self.agent = agent
+
+
+class DataValidationError(PaddiException):
+    """Raised when a pipeline artifact does not match its schema."""
+
+    def __init__(self, path: str, details: dict = None):
+        message = tr("Data validation error: {path} is invalid.", path=path)
+        super().__init__(message, details)
+        self.path = path
+
+
+class NewFindingsError(PaddiException):
+    """Raised by --fail-on-new when findings are not in the accepted baseline."""
+
+    def __init__(self, count: int, details: dict = None):
+        message = tr("{count} new finding(s) are not in the baseline.", count=count)
+        super().__init__(message, details)
+        self.count = count
+
+
+class FindingsThresholdError(PaddiException):
+    """Raised by --fail-on-severity when findings reach the given severity."""
+
+    def __init__(self, count: int, severity: str, details: dict = None):
+        message = tr("{count} finding(s) at or above {severity}.", count=count, severity=severity)
+        super().__init__(message, details)
+        self.count = count
+        self.severity = severity
+
+
+class PartialResultsError(PaddiException):
+    """Raised by --continue-on-error when steps failed and the results are partial."""
+
+    def __init__(self, count: int, details: dict = None):
+        message = tr("{count} step(s) failed, so the results are partial.", count=count)
+        super().__init__(message, details)
+        self.count = count
+
+
+class ProjectsFailedError(PaddiException):
+    """Raised when the audits of some projects in a multi-project audit fail."""
+
+    def __init__(self, failed: int, total: int, exit_code: int, details: dict = None):
+        message = tr(
+            "The audit of {failed} of {total} project(s) failed.", failed=failed, total=total
+        )
+        super().__init__(message, details)
+        self.failed = failed
+        self.total = total
+        self.exit_code = exit_code
+
+
+class QueryError(PaddiException):
+    """Raised when a --query expression cannot be parsed."""
+
+    def __init__(self, query: str, details: dict = None):
+        message = tr("Query error: cannot parse {query}.", query=query)
+        super().__init__(message, details)
+        self.query = query
+
+
+class SelfUpdateError(PaddiException):
+    """Raised when paddi self-update cannot find, verify, or install a release."""
+
+    def __init__(self, reason: str, details: dict = None):
+        message = tr("Update error: {reason}", reason=reason)
+        super().__init__(message, details)
+        self.reason = reason
+
+
+class AuditQueueFullError(PaddiException):
+    """Raised when paddi serve is asked to queue an audit while its queue is full."""
+
+    def __init__(self, limit: int, details: dict = None):
+        message = tr("The audit queue is full ({limit} audits waiting).", limit=limit)
+        super().__init__(message, details)
+        self.limit = limit
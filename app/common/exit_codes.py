@@ -0,0 +1,87 @@
+"""Process exit codes, one per failure class.
+
+The codes are a stable contract for wrappers and CI; see "Exit Codes" in
+docs/cli/commands.md. New failure classes get new codes; existing codes are
+never reassigned.
+"""
+
+from enum import IntEnum
+from typing import Iterator, Optional
+
+import requests
+
+from app.common.exceptions import (
+    AgentError,
+    AuthenticationError,
+    CollectionError,
+    ConfigurationError,
+    DataValidationError,
+    FindingsThresholdError,
+    NewFindingsError,
+    PartialResultsError,
+    PluginError,
+    ProjectsFailedError,
+    QueryError,
+)
+
+
+class ExitCode(IntEnum):
+    """Exit codes of the paddi command."""
+
+    SUCCESS = 0
+    GENERAL_ERROR = 1
+    CONFIGURATION_ERROR = 2
+    AGENT_FAILURE = 3
+    FINDINGS_OVER_THRESHOLD = 4
+    TIMEOUT = 5
+    INVALID_INPUT = 6
+    AUTHENTICATION_ERROR = 7
+    PARTIAL_RESULTS = 8
+    INTERRUPTED = 130
+
+
+# Checked in order; the first class that matches decides the code
+_CODES = (
+    (ConfigurationError, ExitCode.CONFIGURATION_ERROR),
+    (QueryError, ExitCode.CONFIGURATION_ERROR),
+    (AuthenticationError, ExitCode.AUTHENTICATION_ERROR),
+    (NewFindingsError, ExitCode.FINDINGS_OVER_THRESHOLD),
+    (FindingsThresholdError, ExitCode.FINDINGS_OVER_THRESHOLD),
+    (PartialResultsError, ExitCode.PARTIAL_RESULTS),
+    (DataValidationError, ExitCode.INVALID_INPUT),
+    (FileNotFoundError, ExitCode.INVALID_INPUT),
+    (CollectionError, ExitCode.AGENT_FAILURE),
+    (PluginError, ExitCode.AGENT_FAILURE),
+    (AgentError, ExitCode.AGENT_FAILURE),
+)
+
+_TIMEOUTS = (TimeoutError, requests.Timeout)
+
+
+def _chain(error: BaseException) -> Iterator[BaseException]:
+    """Yield an exception and the exceptions it was raised from."""
+    seen = set()
+    current: Optional[BaseException] = error
+    while current is not None and id(current) not in seen:
+        seen.add(id(current))
+        yield current
+        current = current.__cause__ or current.__context__
+
+
+def exit_code_for(error: BaseException) -> ExitCode:
+    """Return the exit code for an exception that ended a command.
+
+    A timeout anywhere in the exception chain is reported as a timeout, even
+    when an agent wrapped it in its own error.
+    """
+    if isinstance(error, KeyboardInterrupt):
+        return ExitCode.INTERRUPTED
+    if isinstance(error, ProjectsFailedError):
+        # Already aggregated from the projects' own exit codes
+        return ExitCode(error.exit_code)
+    if any(isinstance(cause, _TIMEOUTS) for cause in _chain(error)):
+        return ExitCode.TIMEOUT
+    for error_class, code in _CODES:
+        if isinstance(error, error_class):
+            return code
+    return ExitCode.GENERAL_ERROR
@@ -0,0 +1,95 @@
+"""Failures recorded while a run continues (--continue-on-error).
+
+By default the first failing step stops an audit. With ``--continue-on-error``,
+a failing cloud provider, report input, report format, notification, or upload
+is recorded instead, the remaining steps run, and the audit ends with a partial
+report, a failure summary in ``<output_dir>/failures.json``, and exit code 8.
+"""
+
+import json
+import logging
+from dataclasses import asdict, dataclass
+from pathlib import Path
+from typing import Any, Callable, Dict, Iterable, List
+
+logger = logging.getLogger(__name__)
+
+FAILURES_FILE = "failures.json"
+
+
+@dataclass
+class StageFailure:
+    """A step of the run that failed."""
+
+    stage: str
+    target: str
+    error: str
+
+    def to_dict(self) -> Dict[str, str]:
+        """Convert to a dictionary for JSON output."""
+        return asdict(self)
+
+    def __str__(self) -> str:
+        return f"{self.stage} {self.target}: {self.error}"
+
+
+class FailureLog:
+    """Collects the failures of a run.
+
+    With ``continue_on_error`` off, steps run as usual and their errors are raised.
+    """
+
+    def __init__(self, continue_on_error: bool = False):
+        """Initialize FailureLog.
+
+        Args:
+            continue_on_error: Record failing steps instead of raising their errors
+        """
+        self.continue_on_error = continue_on_error
+        self.failures: List[StageFailure] = []
+
+    def __len__(self) -> int:
+        return len(self.failures)
+
+    def record(self, stage: str, target: str, error: Any) -> None:
+        """Record a failure that was already handled."""
+        self.failures.append(StageFailure(stage, target, str(error)))
+
+    def extend(self, failures: Iterable[StageFailure]) -> None:
+        """Record failures collected elsewhere, e.g. by the reporter."""
+        self.failures.extend(failures)
+
+    def run(self, stage: str, target: str, step: Callable[..., Any], *args, **kwargs) -> Any:
+        """Run a step, recording its failure when continuing on errors.
+
+        Returns:
+            The step's result, or None if it failed and was recorded
+        """
+        if not self.continue_on_error:
+            return step(*args, **kwargs)
+        try:
+            return step(*args, **kwargs)
+        except Exception as e:
+            logger.error("%s %s failed: %s", stage.capitalize(), target, e)
+            self.record(stage, target, e)
+            return None
+
+
+def write_failures(failures: List[StageFailure], output_dir: Path) -> Path:
+    """Write the failure summary to ``failures.json`` in the output directory."""
+    output_dir.mkdir(parents=True, exist_ok=True)
+    path = output_dir / FAILURES_FILE
+    with open(path, "w", encoding="utf-8") as f:
+        json.dump([failure.to_dict() for failure in failures], f, indent=2, ensure_ascii=False)
+    return path
+
+
+def format_failures(failures: List[StageFailure]) -> str:
+    """Format the failure summary as a table."""
+    stage_width = max(len("Stage"), *(len(f.stage) for f in failures))
+    target_width = max(len("Target"), *(len(f.target) for f in failures))
+    lines = [f"{'Stage':<{stage_width}}  {'Target':<{target_width}}  Error"]
+    lines.extend(
+        f"{f.stage:<{stage_width}}  {f.target:<{target_width}}  {f.error}" for f in failures
+    )
+    return "\n".join(lines)
@@ -0,0 +1,134 @@
+"""Localization of user-facing CLI messages (English and Japanese).
+
+Messages are written in English in the code and translated when shown, using
+the message catalogs in ``app/locales/<lang>.json``: each maps an English
+message to its translation. A message missing from a catalog is shown in
+English, so a new message never breaks a command::
+
+    raise CollectionError("SCC Findings")    # 収集エラー: SCC Findingsのデータ収集に失敗しました。
+    tr("Keeping existing {path}", path=path)
+
+Placeholders are ``{name}`` fields filled by :func:`tr`, or ``%s`` fields of
+log messages, which the logger fills after translation.
+
+The language is, in order of precedence: ``--lang en|ja``, ``PADDI_LANG``,
+``[cli] lang`` in paddi.toml, and the system locale (``LC_ALL``,
+``LC_MESSAGES``, ``LANG``), falling back to English.
+"""
+
+import json
+import logging
+import os
+from functools import lru_cache
+from pathlib import Path
+from typing import Dict, List, Optional, Tuple
+
+logger = logging.getLogger(__name__)
+
+LANGUAGES = ("en", "ja")
+DEFAULT_LANGUAGE = "en"
+LANG_FLAG = "--lang"
+LANG_ENV = "PADDI_LANG"
+LOCALES_DIR = Path(__file__).resolve().parent.parent / "locales"
+
+_language: Optional[str] = None
+_resolving = False
+
+
+def _normalize(value: Optional[str]) -> Optional[str]:
+    """Map a language or locale name such as ``ja_JP.UTF-8`` to a supported language."""
+    if not value:
+        return None
+    language = value.strip().lower().replace("-", "_").split("_")[0].split(".")[0]
+    return language if language in LANGUAGES else None
+
+
+def set_language(language: Optional[str]) -> None:
+    """Set the language for this process (``None`` restores detection)."""
+    global _language
+    if language is not None and _normalize(language) is None:
+        raise ValueError(f"Unknown language: {language} (supported: {', '.join(LANGUAGES)})")
+    _language = _normalize(language)
+
+
+def _configured_language() -> Optional[str]:
+    """Return ``[cli] lang`` from paddi.toml, if set."""
+    # Imported here: the configuration reports its errors with localized exceptions
+    from app.config.paddi_config import PaddiConfig
+
+    try:
+        return PaddiConfig.load().section("cli").get("lang")
+    except Exception as e:  # a broken paddi.toml is reported by the command that reads it
+        logger.debug("Cannot read [cli] lang: %s", e)
+        return None
+
+
+def _system_language() -> Optional[str]:
+    """Return the language of the first locale variable that is set."""
+    for name in ("LC_ALL", "LC_MESSAGES", "LANG"):
+        if os.getenv(name):
+            return _normalize(os.getenv(name))
+    return None
+
+
+def get_language() -> str:
+    """Return the language messages are shown in."""
+    global _language, _resolving
+    if _language is not None:
+        return _language
+    if _resolving:
+        # Loading paddi.toml failed with an error that is being translated
+        return DEFAULT_LANGUAGE
+    _resolving = True
+    try:
+        _language = (
+            _normalize(os.getenv(LANG_ENV))
+            or _normalize(_configured_language())
+            or _system_language()
+            or DEFAULT_LANGUAGE
+        )
+    finally:
+        _resolving = False
+    return _language
+
+
+@lru_cache(maxsize=None)
+def catalog(language: str) -> Dict[str, str]:
+    """Return the message catalog of a language; English needs none."""
+    if language == DEFAULT_LANGUAGE:
+        return {}
+    path = LOCALES_DIR / f"{language}.json"
+    try:
+        return json.loads(path.read_text(encoding="utf-8"))
+    except (OSError, ValueError) as e:
+        logger.debug("Cannot read the message catalog %s: %s", path, e)
+        return {}
+
+
+def tr(message: str, **fields) -> str:
+    """Translate an English message into the current language and fill its fields."""
+    translated = catalog(get_language()).get(message, message)
+    return translated.format(**fields) if fields else translated
+
+
+def extract_lang_flag(argv: List[str]) -> Tuple[List[str], Optional[str]]:
+    """Remove ``--lang <language>`` or ``--lang=<language>`` from command-line arguments.
+
+    Returns:
+        Remaining arguments and the selected language, if any
+    """
+    remaining: List[str] = []
+    selected = None
+    skip = False
+    for index, arg in enumerate(argv):
+        if skip:
+            skip = False
+            continue
+        if arg.startswith(f"{LANG_FLAG}="):
+            selected = arg.split("=", 1)[1]
+        elif arg == LANG_FLAG and index + 1 < len(argv):
+            selected = argv[index + 1]
+            skip = True
+        else:
+            remaining.append(arg)
+    return remaining, selected
@@ -0,0 +1,196 @@
+"""Streaming JSON parsing with bounded memory.
+
+Organization-wide scans write ``collected.json`` files of hundreds of MB.
+Validation, summaries, and filters only need one part of such a file at a
+time, so they read it as a stream of events instead of loading it whole:
+
+- ``("start", path, container)`` when a streamed object or array begins; the
+  container is empty and stands in for the value
+- ``("value", path, value)`` for each value inside a streamed container
+- ``("end", path, container)`` when a streamed container ends
+
+A path is a tuple of object keys and array indexes, such as
+``("scc_findings", 3)``. Which containers are streamed is chosen by patterns
+where ``"*"`` matches any key or index; every other value is parsed whole. The
+memory used is that of the largest value parsed whole, not of the file::
+
+    streamed = [(), ("scc_findings",)]
+    for kind, path, value in iter_events(f, streamed):
+        if kind == "value" and path[0] == "scc_findings":
+            ...  # one SCC finding at a time
+"""
+
+import json
+from typing import Any, Iterator, List, Sequence, TextIO, Tuple, Union
+
+JsonPath = Tuple[Union[str, int], ...]
+Event = Tuple[str, JsonPath, Any]
+
+CHUNK_SIZE = 64 * 1024
+WHITESPACE = " \t\r\n"
+NUMBER_CHARS = "0123456789.eE+-"
+
+
+def matches(path: JsonPath, pattern: JsonPath) -> bool:
+    """Return whether a path matches a pattern (``"*"`` matches any key or index)."""
+    return len(path) == len(pattern) and all(
+        expected in ("*", part) for part, expected in zip(path, pattern)
+    )
+
+
+class JsonStream:
+    """Reads JSON values from a text stream, a chunk at a time."""
+
+    def __init__(self, stream: TextIO, chunk_size: int = CHUNK_SIZE):
+        """Initialize JsonStream.
+
+        Args:
+            stream: Text stream positioned at the start of the document
+            chunk_size: Characters read at a time
+        """
+        self._stream = stream
+        self._chunk_size = chunk_size
+        self._decoder = json.JSONDecoder()
+        self._buffer = ""
+        self._pos = 0
+        self._eof = False
+        # Line and column of the start of the buffer, for error messages
+        self._line = 1
+        self._column = 1
+
+    def events(self, streamed: Sequence[JsonPath]) -> Iterator[Event]:
+        """Yield the events of the document, streaming the containers matching ``streamed``.
+
+        Raises:
+            json.JSONDecodeError: If the document is not valid JSON
+        """
+        yield from self._walk((), list(streamed))
+        if self._peek():
+            raise self._error("Extra data", self._pos)
+
+    def _walk(self, path: JsonPath, streamed: List[JsonPath]) -> Iterator[Event]:
+        """Yield the events of the value at the current position."""
+        opening = self._peek()
+        if opening not in ("{", "[") or not any(matches(path, p) for p in streamed):
+            yield "value", path, self._value()
+            return
+
+        container: Union[dict, list] = {} if opening == "{" else []
+        closing = "}" if opening == "{" else "]"
+        self._pos += 1
+        yield "start", path, container
+        index = 0
+        if self._peek() == closing:
+            self._pos += 1
+        else:
+            while True:
+                if opening == "{":
+                    if self._peek() != '"':
+                        raise self._error("Expecting property name enclosed in double quotes")
+                    key = self._value()
+                    self._expect(":")
+                    yield from self._walk(path + (key,), streamed)
+                else:
+                    yield from self._walk(path + (index,), streamed)
+                    index += 1
+                separator = self._peek()
+                self._pos += 1
+                if separator == closing:
+                    break
+                if separator != ",":
+                    raise self._error("Expecting ',' delimiter", self._pos - 1)
+        yield "end", path, container
+
+    def _fill(self, size: int) -> bool:
+        """Append at least ``size`` characters to the buffer, dropping what was consumed."""
+        if self._eof:
+            return False
+        chunk = self._stream.read(size)
+        if not chunk:
+            self._eof = True
+            return False
+        consumed = self._buffer[: self._pos]
+        newlines = consumed.count("\n")
+        if newlines:
+            self._line += newlines
+            self._column = len(consumed) - consumed.rfind("\n")
+        else:
+            self._column += len(consumed)
+        self._buffer = self._buffer[self._pos :]
+        self._pos = 0
+        self._buffer += chunk
+        return True
+
+    def _peek(self) -> str:
+        """Skip whitespace and return the next character ("" at the end)."""
+        while True:
+            while self._pos < len(self._buffer) and self._buffer[self._pos] in WHITESPACE:
+                self._pos += 1
+            if self._pos < len(self._buffer):
+                return self._buffer[self._pos]
+            if not self._fill(self._chunk_size):
+                return ""
+
+    def _expect(self, char: str) -> None:
+        """Consume a character, which must come next."""
+        if self._peek() != char:
+            raise self._error(f"Expecting '{char}' delimiter")
+        self._pos += 1
+
+    def _value(self) -> Any:
+        """Parse the whole value at the current position."""
+        if not self._peek():
+            raise self._error("Expecting value")
+        while True:
+            try:
+                value, end = self._decoder.raw_decode(self._buffer, self._pos)
+            except json.JSONDecodeError as e:
+                # The value may continue past the buffer; reading twice as much
+                # keeps the cost of large values linear
+                if self._fill(max(self._chunk_size, len(self._buffer))):
+                    continue
+                raise self._error(e.msg, e.pos) from None
+            # A number cut at the end of the buffer may continue in the next
+            # chunk: "7." decodes as 7, leaving the "." behind
+            if (
+                isinstance(value, (int, float))
+                and not isinstance(value, bool)
+                and all(c in NUMBER_CHARS for c in self._buffer[end:])
+                and self._fill(self._chunk_size)
+            ):
+                continue
+            self._pos = end
+            return value
+
+    def _error(self, message: str, pos: int = -1) -> json.JSONDecodeError:
+        """Return a decoding error with its line and column in the whole document."""
+        pos = self._pos if pos < 0 else pos
+        error = json.JSONDecodeError(message, self._buffer, pos)
+        newlines = self._buffer.count("\n", 0, pos)
+        error.lineno = self._line + newlines
+        if newlines:
+            error.colno = pos - self._buffer.rfind("\n", 0, pos)
+        else:
+            error.colno = self._column + pos
+        return error
+
+
+def iter_events(
+    stream: TextIO, streamed: Sequence[JsonPath], chunk_size: int = CHUNK_SIZE
+) -> Iterator[Event]:
+    """Yield the events of a JSON document, streaming the containers matching ``streamed``."""
+    return JsonStream(stream, chunk_size).events(streamed)
+
+
+def iter_array(stream: TextIO, chunk_size: int = CHUNK_SIZE) -> Iterator[Any]:
+    """Yield the elements of a top-level JSON array one at a time.
+
+    Raises:
+        ValueError: If the document is not an array
+        json.JSONDecodeError: If the document is not valid JSON
+    """
+    for kind, path, value in iter_events(stream, [()], chunk_size):
+        if not path and (kind == "value" or not isinstance(value, list)):
+            raise ValueError(f"Expected a JSON array, found {type(value).__name__}")
+        if kind == "value":
+            yield value
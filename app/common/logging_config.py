@@ -3,9 +3,28 @@
 import logging
 import logging.handlers
 import os
+import re
 from pathlib import Path
 from typing import Optional
 
+# Emoji and pictographic symbols used as decoration in log and console messages
+_SYMBOLS = re.compile(
+    "[\U0001F000-\U0001FAFF\u2190-\u21FF\u2300-\u23FF\u2600-\u27BF\u2B00-\u2BFF"
+    "\uFE0F\u200D]+ *"
+)
+
+
+def strip_symbols(text: str) -> str:
+    """Remove emoji and decorative symbols (and the spaces after them) from text."""
+    return _SYMBOLS.sub("", text)
+
+
+class PlainFormatter(logging.Formatter):
+    """Formats records without emoji so output stays plain when piped or run by cron."""
+
+    def format(self, record: logging.LogRecord) -> str:
+        return strip_symbols(super().format(record))
+
 
 class LoggingConfig:
     """Centralized logging configuration."""
@@ -66,6 +85,22 @@ class LoggingConfig:
             file_handler.setFormatter(formatter)
             root_logger.addHandler(file_handler)
 
+    @staticmethod
+    def enable_quiet_mode() -> None:
+        """Show only errors, without timestamps or emoji, on the console.
+
+        Used by ``--quiet`` for scripts and cron jobs. Only console handlers change;
+        file handlers and in-memory buffers keep their level.
+        """
+        root_logger = logging.getLogger()
+        for handler in root_logger.handlers:
+            if not isinstance(handler, logging.StreamHandler) or isinstance(
+                handler, logging.FileHandler
+            ):
+                continue
+            handler.setLevel(logging.ERROR)
+            handler.setFormatter(PlainFormatter("%(levelname)s: %(message)s"))
+
     @staticmethod
     def setup_from_env() -> None:
         """Set up logging from environment variables."""
@@ -1,8 +1,186 @@
-"""Common data models shared across agents."""
+"""Common data models shared across agents.
 
-from dataclasses import dataclass, field
+These are the typed forms of the artifacts passed between stages: the
+collector writes :class:`CollectedData` as ``collected.json`` and the explainer
+writes :class:`SecurityFinding` lists as ``explained.json``. ``from_dict``
+accepts the JSON as written. The collected-data models keep keys they do not
+define in ``extra`` and write them back in ``to_dict``, so provider-specific
+data is not lost. :mod:`app.common.artifact_schemas` derives the JSON Schemas
+from these classes.
+"""
+
+from dataclasses import dataclass, field, fields
 from typing import Any, Dict, List, Optional
 
+# Severity levels from most to least severe
+SEVERITY_ORDER = ["CRITICAL", "HIGH", "MEDIUM", "LOW", "INFO"]
+
+
+def severity_rank(severity: str) -> int:
+    """Return a sortable rank for a severity (0 is most severe)."""
+    try:
+        return SEVERITY_ORDER.index(str(severity).upper())
+    except ValueError:
+        return len(SEVERITY_ORDER)
+
+
+def _extra_field() -> Any:
+    """Declare the field that keeps JSON keys a model does not define."""
+    return field(default_factory=dict, metadata={"extra": True})
+
+
+def _split_known(cls: type, data: Dict[str, Any]) -> tuple:
+    """Split JSON data into a model's known fields and the remaining keys."""
+    names = {f.name for f in fields(cls) if not f.metadata.get("extra")}
+    known = {key: value for key, value in data.items() if key in names}
+    extra = {key: value for key, value in data.items() if key not in names}
+    return known, extra
+
+
+def _to_dict(model: Any) -> Dict[str, Any]:
+    """Serialize a model, omitting unset optional values and restoring extra keys."""
+    result: Dict[str, Any] = {}
+    for f in fields(model):
+        value = getattr(model, f.name)
+        if f.metadata.get("extra"):
+            result.update(value)
+        elif value is not None:
+            result[f.name] = value.to_dict() if hasattr(value, "to_dict") else value
+    return result
+
+
+@dataclass
+class IamBinding:
+    """A role granted to members, as in a Cloud IAM policy."""
+
+    role: str
+    members: List[str] = field(default_factory=list)
+    condition: Optional[Dict[str, Any]] = None
+    extra: Dict[str, Any] = _extra_field()
+
+    @classmethod
+    def from_dict(cls, data: Dict[str, Any]) -> "IamBinding":
+        """Create a binding from its JSON form."""
+        known, extra = _split_known(cls, data)
+        known["members"] = list(known.get("members") or [])
+        return cls(**known, extra=extra)
+
+    def to_dict(self) -> Dict[str, Any]:
+        """Convert to dictionary for JSON serialization."""
+        return _to_dict(self)
+
+
+@dataclass
+class IamPolicy:
+    """The IAM policy of a resource."""
+
+    bindings: List[IamBinding] = field(default_factory=list)
+    resource: Optional[str] = None
+    etag: Optional[str] = None
+    version: Optional[int] = None
+    extra: Dict[str, Any] = _extra_field()
+
+    @classmethod
+    def from_dict(cls, data: Dict[str, Any]) -> "IamPolicy":
+        """Create a policy from its JSON form."""
+        known, extra = _split_known(cls, data)
+        known["bindings"] = [IamBinding.from_dict(b) for b in known.get("bindings") or []]
+        return cls(**known, extra=extra)
+
+    def to_dict(self) -> Dict[str, Any]:
+        """Convert to dictionary for JSON serialization."""
+        result = _to_dict(self)
+        result["bindings"] = [binding.to_dict() for binding in self.bindings]
+        return result
+
+
+@dataclass
+class SccFinding:
+    """A Security Command Center finding as collected."""
+
+    category: str
+    severity: str = "MEDIUM"
+    name: Optional[str] = None
+    resource_name: Optional[str] = None
+    resource_type: Optional[str] = None
+    state: Optional[str] = None
+    finding_class: Optional[str] = None
+    source_type: Optional[str] = None
+    create_time: Optional[str] = None
+    event_time: Optional[str] = None
+    description: Optional[str] = None
+    recommendation: Optional[str] = None
+    external_uri: Optional[str] = None
+    indicator: Optional[Dict[str, Any]] = None
+    source_properties: Optional[Dict[str, Any]] = None
+    extra: Dict[str, Any] = _extra_field()
+
+    @classmethod
+    def from_dict(cls, data: Dict[str, Any]) -> "SccFinding":
+        """Create a finding from its JSON form."""
+        known, extra = _split_known(cls, data)
+        known.setdefault("category", "UNKNOWN")
+        return cls(**known, extra=extra)
+
+    def to_dict(self) -> Dict[str, Any]:
+        """Convert to dictionary for JSON serialization."""
+        return _to_dict(self)
+
+
+@dataclass
+class CollectionMetadata:
+    """Where and when data was collected."""
+
+    project_id: Optional[str] = None
+    organization_id: Optional[str] = None
+    timestamp: Optional[str] = None
+    source: Optional[str] = None
+    terraform_file: Optional[str] = None
+    # Transient API errors retried during collection (see app.common.retry)
+    retries: Optional[Dict[str, Any]] = None
+    # What an incremental collection merged (see app.collector.incremental)
+    incremental: Optional[Dict[str, Any]] = None
+    extra: Dict[str, Any] = _extra_field()
+
+    @classmethod
+    def from_dict(cls, data: Dict[str, Any]) -> "CollectionMetadata":
+        """Create metadata from its JSON form."""
+        known, extra = _split_known(cls, data)
+        return cls(**known, extra=extra)
+
+    def to_dict(self) -> Dict[str, Any]:
+        """Convert to dictionary for JSON serialization."""
+        # The IDs are written even when unset, as readers look them up directly
+        ids = {"project_id": self.project_id, "organization_id": self.organization_id}
+        return {**ids, **_to_dict(self)}
+
+
+@dataclass
+class CollectedData:
+    """Single-project data written to ``collected.json`` by the GCP and Terraform collectors."""
+
+    metadata: CollectionMetadata
+    iam_policies: IamPolicy = field(default_factory=IamPolicy)
+    scc_findings: List[SccFinding] = field(default_factory=list)
+    extra: Dict[str, Any] = _extra_field()
+
+    @classmethod
+    def from_dict(cls, data: Dict[str, Any]) -> "CollectedData":
+        """Create collected data from its JSON form."""
+        known, extra = _split_known(cls, data)
+        return cls(
+            metadata=CollectionMetadata.from_dict(known.get("metadata") or {}),
+            iam_policies=IamPolicy.from_dict(known.get("iam_policies") or {}),
+            scc_findings=[SccFinding.from_dict(f) for f in known.get("scc_findings") or []],
+            extra=extra,
+        )
+
+    def to_dict(self) -> Dict[str, Any]:
+        """Convert to dictionary for JSON serialization."""
+        result = _to_dict(self)
+        result["scc_findings"] = [finding.to_dict() for finding in self.scc_findings]
+        return result
+
 
 @dataclass
 class RecommendationStep:
@@ -28,6 +206,12 @@ class RecommendationStep:
             result["validation"] = self.validation
         return result
 
+    @classmethod
+    def from_dict(cls, data: Dict[str, Any]) -> "RecommendationStep":
+        """Create a step from its JSON form."""
+        known, _ = _split_known(cls, data)
+        return cls(**known)
+
 
 @dataclass
 class EnhancedRecommendation:
@@ -47,6 +231,13 @@ class EnhancedRecommendation:
             result["required_skills"] = self.required_skills
         return result
 
+    @classmethod
+    def from_dict(cls, data: Dict[str, Any]) -> "EnhancedRecommendation":
+        """Create a recommendation from its JSON form."""
+        known, _ = _split_known(cls, data)
+        known["steps"] = [RecommendationStep.from_dict(step) for step in known.get("steps", [])]
+        return cls(**known)
+
 
 @dataclass
 class SecurityFinding:
@@ -58,6 +249,14 @@ class SecurityFinding:
     recommendation: str
 
     # Enhanced fields (optional for backward compatibility)
+    original_severity: Optional[str] = None
+    category: Optional[str] = None
+    resource: Optional[str] = None
+    fingerprint: Optional[str] = None
+    occurrences: int = 1
+    affected_resources: List[str] = field(default_factory=list)
+    cvss_vector: Optional[str] = None
+    cvss_score: Optional[float] = None
     finding_id: Optional[str] = None
     source: Optional[str] = None
     classification: Optional[str] = None
@@ -66,6 +265,10 @@ class SecurityFinding:
     enhanced_recommendation: Optional[EnhancedRecommendation] = None
     priority_score: Optional[int] = None
     compliance_mapping: Dict[str, str] = field(default_factory=dict)
+    owner: Optional[str] = None
+    tags: List[str] = field(default_factory=list)
+    # Ports a network finding exposes, for controls about particular ports
+    ports: List[int] = field(default_factory=list)
 
     def to_dict(self) -> Dict[str, Any]:
         """Convert to dictionary for JSON serialization."""
@@ -77,6 +280,22 @@ class SecurityFinding:
         }
 
         # Add enhanced fields if present
+        if self.original_severity:
+            result["original_severity"] = self.original_severity
+        if self.category:
+            result["category"] = self.category
+        if self.resource:
+            result["resource"] = self.resource
+        if self.fingerprint:
+            result["fingerprint"] = self.fingerprint
+        if self.occurrences > 1:
+            result["occurrences"] = self.occurrences
+        if self.affected_resources:
+            result["affected_resources"] = self.affected_resources
+        if self.cvss_vector:
+            result["cvss_vector"] = self.cvss_vector
+        if self.cvss_score is not None:
+            result["cvss_score"] = self.cvss_score
         if self.finding_id:
             result["finding_id"] = self.finding_id
         if self.source:
@@ -93,5 +312,33 @@ class SecurityFinding:
             result["priority_score"] = self.priority_score
         if self.compliance_mapping:
             result["compliance_mapping"] = self.compliance_mapping
+        if self.owner:
+            result["owner"] = self.owner
+        if self.tags:
+            result["tags"] = self.tags
+        if self.ports:
+            result["ports"] = self.ports
 
         return result
+
+    @classmethod
+    def from_dict(cls, data: Dict[str, Any]) -> "SecurityFinding":
+        """Create a finding from explained.json, filling defaults for missing text fields.
+
+        Keys the model does not define (added by plugins or later stages) are ignored.
+        """
+        known, _ = _split_known(cls, data)
+        known.setdefault("title", "Unknown Issue")
+        known.setdefault("severity", "INFO")
+        known.setdefault("explanation", "No explanation provided")
+        known.setdefault("recommendation", "No recommendation provided")
+        if known.get("occurrences") is None:
+            known.pop("occurrences", None)
+        for name in ("affected_resources", "compliance_mapping", "ports"):
+            if known.get(name) is None:
+                known.pop(name, None)
+        if isinstance(known.get("enhanced_recommendation"), dict):
+            known["enhanced_recommendation"] = EnhancedRecommendation.from_dict(
+                known["enhanced_recommendation"]
+            )
+        return cls(**known)
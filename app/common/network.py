@@ -0,0 +1,155 @@
+"""Outbound HTTP proxy settings.
+
+Every HTTP client Paddi uses (requests, the Google Cloud, AWS, and Azure SDKs,
+PyGithub, and gRPC) reads the standard proxy variables ``HTTP_PROXY``,
+``HTTPS_PROXY``, and ``NO_PROXY``, so those are honored as they are. The
+``[network.proxy]`` section of paddi.toml sets them for the run instead, with
+optional proxy authentication and a CA bundle for proxies that intercept TLS::
+
+    [network.proxy]
+    url = "http://proxy.example.com:3128"   # for both http and https
+    no_proxy = ["metadata.google.internal", ".corp.example.com"]
+    username = "paddi"
+    password_env = "PADDI_PROXY_PASSWORD"   # default
+    ca_bundle = "/etc/ssl/certs/corp-ca.pem"
+
+Local addresses are always added to ``no_proxy``, so a local Ollama is reached
+directly. Settings in paddi.toml override the environment.
+"""
+
+import logging
+import os
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import Dict, List, MutableMapping, Optional
+from urllib.parse import quote, urlsplit, urlunsplit
+
+from app.common.exceptions import ConfigurationError
+from app.config.paddi_config import PaddiConfig
+from app.config.secrets import resolve_secret
+
+logger = logging.getLogger(__name__)
+
+DEFAULT_PASSWORD_ENV = "PADDI_PROXY_PASSWORD"
+LOCAL_ADDRESSES = ["localhost", "127.0.0.1", "::1"]
+
+# Variables read by requests (and the SDKs built on it), botocore, gRPC, and ssl
+CA_BUNDLE_VARIABLES = (
+    "REQUESTS_CA_BUNDLE",
+    "AWS_CA_BUNDLE",
+    "GRPC_DEFAULT_SSL_ROOTS_FILE_PATH",
+    "SSL_CERT_FILE",
+)
+
+
+def _with_auth(url: str, username: Optional[str], password: Optional[str]) -> str:
+    """Add credentials to a proxy URL."""
+    if not username:
+        return url
+    parts = urlsplit(url)
+    credentials = quote(username, safe="")
+    if password:
+        credentials += ":" + quote(password, safe="")
+    netloc = f"{credentials}@{parts.netloc.rsplit('@', 1)[-1]}"
+    return urlunsplit(parts._replace(netloc=netloc))
+
+
+def _proxy_url(key: str, value: Optional[str]) -> Optional[str]:
+    """Check a proxy URL from the configuration."""
+    if value is None:
+        return None
+    if not urlsplit(str(value)).scheme or not urlsplit(str(value)).netloc:
+        raise ConfigurationError(
+            f"network.proxy.{key}", {"error": "use a URL such as http://proxy:3128"}
+        )
+    return str(value)
+
+
+@dataclass
+class ProxySettings:
+    """The ``[network.proxy]`` settings."""
+
+    http: Optional[str] = None
+    https: Optional[str] = None
+    no_proxy: List[str] = field(default_factory=list)
+    username: Optional[str] = None
+    password: Optional[str] = None
+    ca_bundle: Optional[str] = None
+
+    @classmethod
+    def from_config(cls, config: Optional[PaddiConfig] = None) -> "ProxySettings":
+        """Create settings from the ``[network.proxy]`` section of paddi.toml.
+
+        Raises:
+            ConfigurationError: If a proxy URL is malformed or the CA bundle is missing
+        """
+        section = (config or PaddiConfig.load()).section("network.proxy")
+        url = _proxy_url("url", section.get("url"))
+        no_proxy = section.get("no_proxy") or []
+        if isinstance(no_proxy, str):
+            no_proxy = no_proxy.split(",")
+        ca_bundle = section.get("ca_bundle")
+        if ca_bundle and not Path(ca_bundle).expanduser().is_file():
+            raise ConfigurationError(
+                "network.proxy.ca_bundle", {"error": f"file not found: {ca_bundle}"}
+            )
+        username = section.get("username")
+        password = None
+        if username:
+            password = section.get("password") or resolve_secret(
+                section.get("password_env", DEFAULT_PASSWORD_ENV)
+            )
+        return cls(
+            http=_proxy_url("http", section.get("http")) or url,
+            https=_proxy_url("https", section.get("https")) or url,
+            no_proxy=[host.strip() for host in no_proxy if host.strip()],
+            username=username,
+            password=password,
+            ca_bundle=str(Path(ca_bundle).expanduser()) if ca_bundle else None,
+        )
+
+    @property
+    def configured(self) -> bool:
+        """Return whether paddi.toml sets a proxy or a CA bundle."""
+        return bool(self.http or self.https or self.ca_bundle)
+
+    def environment(self) -> Dict[str, str]:
+        """Return the environment variables that route HTTP clients through the proxy."""
+        variables = {}
+        for name, url in (("http_proxy", self.http), ("https_proxy", self.https)):
+            if url:
+                variables[name] = _with_auth(url, self.username, self.password)
+        if self.http or self.https:
+            hosts = self.no_proxy + [host for host in LOCAL_ADDRESSES if host not in self.no_proxy]
+            variables["no_proxy"] = ",".join(hosts)
+        # Some clients read the lower-case names, others the upper-case ones
+        variables.update({name.upper(): value for name, value in variables.items()})
+        if self.ca_bundle:
+            variables.update({name: self.ca_bundle for name in CA_BUNDLE_VARIABLES})
+        return variables
+
+
+def configure_proxy(
+    config: Optional[PaddiConfig] = None, environ: Optional[MutableMapping[str, str]] = None
+) -> Optional[ProxySettings]:
+    """Apply ``[network.proxy]`` to the environment of this process.
+
+    Returns:
+        The settings, or None when paddi.toml sets no proxy
+
+    Raises:
+        ConfigurationError: If the proxy settings are invalid
+    """
+    environ = os.environ if environ is None else environ
+    if config is None:
+        try:
+            config = PaddiConfig.load(strict=False)
+        except ConfigurationError:
+            # An unreadable file is reported by the command that needs it
+            return None
+    settings = ProxySettings.from_config(config)
+    if not settings.configured:
+        return None
+    environ.update(settings.environment())
+    logger.debug("Routing outbound HTTP through %s", settings.https or settings.http or "-")
+    return settings
@@ -0,0 +1,210 @@
+"""Memory and CPU limits for child processes.
+
+Exec plugins run as separate processes, so a runaway plugin could exhaust
+the host's memory or CPU. ``[execution]`` in paddi.toml caps each of them:
+
+- ``max_memory_mb``: address space of the process. On Unix this is
+  ``RLIMIT_AS``; allocations past it fail (``MemoryError`` in Python). On
+  Windows the process runs in a Job Object with a process memory limit.
+- ``max_cpu_percent``: share of the CPU. Windows enforces it as a hard cap on
+  the Job Object's CPU rate. Unix rlimits cannot cap a rate, so the process
+  gets ``RLIMIT_CPU`` seconds of CPU time: this percentage of its timeout. A
+  process busy on one CPU for longer is stopped with ``SIGXCPU``.
+
+Without these keys, child processes run unlimited, as before.
+"""
+
+import os
+import signal
+import subprocess  # nosec B404
+import sys
+from dataclasses import dataclass
+from typing import Any, Callable, List, Optional
+
+from app.config.paddi_config import PaddiConfig
+
+MIB = 1024 * 1024
+
+
+@dataclass(frozen=True)
+class ProcessLimits:
+    """Limits applied to each spawned child process."""
+
+    max_memory_mb: Optional[int] = None
+    max_cpu_percent: Optional[float] = None
+
+    def __post_init__(self):
+        if self.max_memory_mb is not None and self.max_memory_mb < 1:
+            raise ValueError("max_memory_mb must be at least 1")
+        if self.max_cpu_percent is not None and not 0 < self.max_cpu_percent <= 100:
+            raise ValueError("max_cpu_percent must be greater than 0 and at most 100")
+
+    @classmethod
+    def from_config(cls, config: Optional[PaddiConfig] = None) -> "ProcessLimits":
+        """Create limits from ``[execution]`` in paddi.toml."""
+        section = (config or PaddiConfig.load()).section("execution")
+        memory = section.get("max_memory_mb")
+        cpu = section.get("max_cpu_percent")
+        return cls(
+            max_memory_mb=None if memory is None else int(memory),
+            max_cpu_percent=None if cpu is None else float(cpu),
+        )
+
+    @property
+    def enabled(self) -> bool:
+        """Return whether any limit is set."""
+        return self.max_memory_mb is not None or self.max_cpu_percent is not None
+
+    def cpu_seconds(self, timeout: float) -> Optional[int]:
+        """Return the CPU time allowed on Unix for a process with this timeout."""
+        if self.max_cpu_percent is None:
+            return None
+        return max(1, int(timeout * self.max_cpu_percent / 100))
+
+    def preexec(self, timeout: float) -> Optional[Callable[[], None]]:
+        """Return the function setting the rlimits in the child, on Unix."""
+        if not self.enabled or os.name != "posix":
+            return None
+        import resource
+
+        memory = None if self.max_memory_mb is None else self.max_memory_mb * MIB
+        cpu = self.cpu_seconds(timeout)
+
+        def apply() -> None:
+            if memory is not None:
+                resource.setrlimit(resource.RLIMIT_AS, (memory, memory))
+            if cpu is not None:
+                # The hard limit is one second later, so SIGXCPU is sent before SIGKILL
+                resource.setrlimit(resource.RLIMIT_CPU, (cpu, cpu + 1))
+
+        return apply
+
+    def describe_exit(self, returncode: int, stderr: str = "") -> Optional[str]:
+        """Return which limit a failed process most likely ran into, if any."""
+        if self.max_memory_mb is not None and "MemoryError" in stderr:
+            return f"exceeded the memory limit ({self.max_memory_mb} MB)"
+        cpu_signal = getattr(signal, "SIGXCPU", None)
+        if self.max_cpu_percent is not None and cpu_signal and returncode == -cpu_signal:
+            return f"exceeded the CPU limit ({self.max_cpu_percent:g}% of its timeout)"
+        return None
+
+
+def run_limited(
+    args: List[str],
+    limits: Optional[ProcessLimits] = None,
+    input: Optional[str] = None,  # noqa: A002 - mirrors subprocess.run
+    timeout: Optional[float] = None,
+) -> subprocess.CompletedProcess:
+    """Run a command with text I/O under ``limits``, like ``subprocess.run``.
+
+    Raises:
+        subprocess.TimeoutExpired: If the process runs longer than ``timeout``
+        OSError: If the process cannot be started
+    """
+    limits = limits or ProcessLimits()
+    process = subprocess.Popen(  # nosec B603
+        args,
+        stdin=subprocess.PIPE,
+        stdout=subprocess.PIPE,
+        stderr=subprocess.PIPE,
+        text=True,
+        preexec_fn=limits.preexec(timeout or 0),  # noqa: PLW1509 - sets rlimits only
+    )
+    job = None
+    try:
+        if limits.enabled and sys.platform == "win32":
+            job = _WindowsJob(limits)
+            job.assign(process)
+        stdout, stderr = process.communicate(input, timeout=timeout)
+    except BaseException:
+        process.kill()
+        process.wait()
+        raise
+    finally:
+        if job is not None:
+            job.close()
+    return subprocess.CompletedProcess(args, process.returncode, stdout, stderr)
+
+
+class _WindowsJob:
+    """A Job Object limiting the memory and CPU rate of its processes."""
+
+    JOB_OBJECT_LIMIT_PROCESS_MEMORY = 0x100
+    JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE = 0x2000
+    JOB_OBJECT_CPU_RATE_CONTROL_ENABLE = 0x1
+    JOB_OBJECT_CPU_RATE_CONTROL_HARD_CAP = 0x4
+    JobObjectExtendedLimitInformation = 9
+    JobObjectCpuRateControlInformation = 15
+
+    def __init__(self, limits: ProcessLimits):
+        import ctypes
+        from ctypes import wintypes
+
+        class BasicLimits(ctypes.Structure):
+            _fields_ = [
+                ("PerProcessUserTimeLimit", ctypes.c_int64),
+                ("PerJobUserTimeLimit", ctypes.c_int64),
+                ("LimitFlags", wintypes.DWORD),
+                ("MinimumWorkingSetSize", ctypes.c_size_t),
+                ("MaximumWorkingSetSize", ctypes.c_size_t),
+                ("ActiveProcessLimit", wintypes.DWORD),
+                ("Affinity", ctypes.c_size_t),
+                ("PriorityClass", wintypes.DWORD),
+                ("SchedulingClass", wintypes.DWORD),
+            ]
+
+        class ExtendedLimits(ctypes.Structure):
+            _fields_ = [
+                ("BasicLimitInformation", BasicLimits),
+                ("IoInfo", ctypes.c_uint64 * 6),
+                ("ProcessMemoryLimit", ctypes.c_size_t),
+                ("JobMemoryLimit", ctypes.c_size_t),
+                ("PeakProcessMemoryUsed", ctypes.c_size_t),
+                ("PeakJobMemoryUsed", ctypes.c_size_t),
+            ]
+
+        class CpuRateControl(ctypes.Structure):
+            _fields_ = [("ControlFlags", wintypes.DWORD), ("CpuRate", wintypes.DWORD)]
+
+        self._kernel32 = ctypes.WinDLL("kernel32", use_last_error=True)  # type: ignore
+        self._kernel32.CreateJobObjectW.restype = wintypes.HANDLE
+        self._handle = self._kernel32.CreateJobObjectW(None, None)
+        if not self._handle:
+            raise ctypes.WinError(ctypes.get_last_error())  # type: ignore
+
+        extended = ExtendedLimits()
+        # Child processes die with the job, even if Paddi is interrupted
+        extended.BasicLimitInformation.LimitFlags = self.JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE
+        if limits.max_memory_mb is not None:
+            extended.BasicLimitInformation.LimitFlags |= self.JOB_OBJECT_LIMIT_PROCESS_MEMORY
+            extended.ProcessMemoryLimit = limits.max_memory_mb * MIB
+        self._set(self.JobObjectExtendedLimitInformation, extended)
+
+        if limits.max_cpu_percent is not None:
+            # The rate is in hundredths of a percent of all CPUs
+            rate = CpuRateControl(
+                self.JOB_OBJECT_CPU_RATE_CONTROL_ENABLE | self.JOB_OBJECT_CPU_RATE_CONTROL_HARD_CAP,
+                max(1, int(limits.max_cpu_percent * 100)),
+            )
+            self._set(self.JobObjectCpuRateControlInformation, rate)
+
+    def _set(self, info_class: int, info: Any) -> None:
+        """Set one class of limits on the job."""
+        import ctypes
+
+        if not self._kernel32.SetInformationJobObject(
+            self._handle, info_class, ctypes.byref(info), ctypes.sizeof(info)
+        ):
+            raise ctypes.WinError(ctypes.get_last_error())  # type: ignore
+
+    def assign(self, process: subprocess.Popen) -> None:
+        """Put a started process in the job."""
+        import ctypes
+
+        handle = int(process._handle)  # type: ignore[attr-defined]
+        if not self._kernel32.AssignProcessToJobObject(self._handle, handle):
+            raise ctypes.WinError(ctypes.get_last_error())  # type: ignore
+
+    def close(self) -> None:
+        """Close the job, killing any process still in it."""
+        self._kernel32.CloseHandle(self._handle)
@@ -0,0 +1,133 @@
+"""Client-side rate limits for cloud API calls.
+
+Large organization scans can exhaust API quotas or trip abuse detection. Each
+API family gets a token bucket that refills at the configured requests per
+second and holds up to one second of requests, so short bursts are allowed but
+the average rate is not exceeded. Configured in paddi.toml::
+
+    [limits]
+    iam = 5          # IAM policy reads (Resource Manager)
+    scc = 10         # Security Command Center, for collection and export
+    default = 20     # each family not listed above
+
+Families without a limit (and no ``default``) are not throttled. The family of
+an operation is the part of its name before the first dot, so
+``scc.list_findings`` is limited by ``scc``.
+"""
+
+import logging
+import threading
+import time
+from typing import Callable, Dict, Optional
+
+from app.common.exceptions import ConfigurationError
+from app.config.paddi_config import PaddiConfig
+
+logger = logging.getLogger(__name__)
+
+DEFAULT_FAMILY = "default"
+
+
+class TokenBucket:
+    """Allows ``rate`` requests per second, with bursts of up to one second's worth."""
+
+    def __init__(
+        self,
+        rate: float,
+        clock: Callable[[], float] = time.monotonic,
+        sleep: Callable[[float], None] = time.sleep,
+    ):
+        """Initialize TokenBucket.
+
+        Args:
+            rate: Requests per second
+            clock: Monotonic clock, replaceable in tests
+            sleep: Sleep function, replaceable in tests
+        """
+        self.rate = rate
+        self.capacity = max(1.0, rate)
+        self.tokens = self.capacity
+        self._clock = clock
+        self._sleep = sleep
+        self._updated = clock()
+        self._lock = threading.Lock()
+
+    def acquire(self) -> float:
+        """Take one token, waiting for it if the bucket is empty.
+
+        Returns:
+            Seconds waited
+        """
+        with self._lock:
+            now = self._clock()
+            self.tokens = min(self.capacity, self.tokens + (now - self._updated) * self.rate)
+            self._updated = now
+            wait = 0.0
+            if self.tokens < 1:
+                wait = (1 - self.tokens) / self.rate
+                self._sleep(wait)
+                # The wait refills exactly the missing part of the token
+                self.tokens = 1.0
+                self._updated = self._clock()
+            self.tokens -= 1
+            return wait
+
+
+class RateLimiter:
+    """Token buckets per API family."""
+
+    def __init__(
+        self,
+        limits: Optional[Dict[str, float]] = None,
+        clock: Callable[[], float] = time.monotonic,
+        sleep: Callable[[float], None] = time.sleep,
+    ):
+        """Initialize RateLimiter.
+
+        Args:
+            limits: Requests per second by API family; ``default`` covers the rest
+            clock: Monotonic clock, replaceable in tests
+            sleep: Sleep function, replaceable in tests
+        """
+        self.limits = dict(limits or {})
+        self._clock = clock
+        self._sleep = sleep
+        self._buckets: Dict[str, TokenBucket] = {}
+        self._lock = threading.Lock()
+
+    @classmethod
+    def from_config(cls, config: Optional[PaddiConfig] = None) -> "RateLimiter":
+        """Create a limiter from the ``[limits]`` section of paddi.toml.
+
+        Raises:
+            ConfigurationError: If a limit is not a positive number
+        """
+        section = (config or PaddiConfig.load()).section("limits")
+        limits = {}
+        for family, rate in section.items():
+            if isinstance(rate, bool) or not isinstance(rate, (int, float)) or rate <= 0:
+                raise ConfigurationError(
+                    f"limits.{family}", {"error": "must be requests per second above 0"}
+                )
+            limits[family] = float(rate)
+        return cls(limits)
+
+    def _bucket(self, family: str) -> Optional[TokenBucket]:
+        """Return the bucket of a family, or None when it is not limited."""
+        rate = self.limits.get(family, self.limits.get(DEFAULT_FAMILY))
+        if rate is None:
+            return None
+        with self._lock:
+            if family not in self._buckets:
+                self._buckets[family] = TokenBucket(rate, self._clock, self._sleep)
+            return self._buckets[family]
+
+    def acquire(self, operation: str) -> None:
+        """Wait until the operation's API family may make another request."""
+        family = operation.split(".", 1)[0]
+        bucket = self._bucket(family)
+        if bucket is None:
+            return
+        waited = bucket.acquire()
+        if waited:
+            logger.debug("Throttled %s for %.2fs ([limits] %s)", operation, waited, family)
@@ -0,0 +1,131 @@
+"""Wall time, CPU time, and peak memory of each agent of a run.
+
+``paddi audit`` measures the collector, explainer, and reporter as they run.
+The measurements are recorded with the run in the history database and shown
+in the verbose summary, to help tune ``execution.timeout_seconds`` and spot
+stages that take far more time or memory than the others.
+
+- Wall time is measured with a monotonic clock.
+- CPU time is the user and system time of Paddi and of the child processes
+  (exec plugins) that ended during the stage.
+- Peak RSS is the largest resident memory of the Paddi process during the
+  stage. On Linux the peak is reset before each stage. Elsewhere on Unix it
+  cannot be reset, so it is the peak of the run up to the end of the stage. It
+  is not measured on Windows.
+"""
+
+import os
+import sys
+import time
+from contextlib import contextmanager
+from dataclasses import dataclass
+from typing import Any, Dict, Iterator, List, Optional
+
+_PROC_STATUS = "/proc/self/status"
+_PROC_CLEAR_REFS = "/proc/self/clear_refs"
+
+
+@dataclass
+class AgentUsage:
+    """Resources used by one agent."""
+
+    agent: str
+    wall_seconds: float
+    cpu_seconds: float
+    peak_rss: Optional[int] = None
+
+    @classmethod
+    def from_dict(cls, data: Dict[str, Any]) -> "AgentUsage":
+        """Create usage from its JSON form."""
+        return cls(
+            agent=data["agent"],
+            wall_seconds=data["wall_seconds"],
+            cpu_seconds=data["cpu_seconds"],
+            peak_rss=data.get("peak_rss"),
+        )
+
+    def to_dict(self) -> Dict[str, Any]:
+        """Convert to dictionary for JSON serialization."""
+        return {
+            "agent": self.agent,
+            "wall_seconds": round(self.wall_seconds, 3),
+            "cpu_seconds": round(self.cpu_seconds, 3),
+            "peak_rss": self.peak_rss,
+        }
+
+
+def _cpu_time() -> float:
+    """Return the CPU time of this process and its ended children, in seconds."""
+    times = os.times()
+    return times.user + times.system + times.children_user + times.children_system
+
+
+def reset_peak_rss() -> bool:
+    """Reset the peak RSS of this process, where the OS allows it (Linux only)."""
+    try:
+        with open(_PROC_CLEAR_REFS, "w", encoding="ascii") as f:
+            f.write("5")
+    except OSError:
+        return False
+    return True
+
+
+def peak_rss() -> Optional[int]:
+    """Return the peak RSS of this process in bytes, or None where it is not measured."""
+    try:
+        with open(_PROC_STATUS, encoding="ascii") as f:
+            for line in f:
+                if line.startswith("VmHWM:"):
+                    return int(line.split()[1]) * 1024
+    except (OSError, ValueError, IndexError):
+        pass
+    try:
+        import resource
+    except ImportError:
+        return None
+    peak = resource.getrusage(resource.RUSAGE_SELF).ru_maxrss
+    # ru_maxrss is in bytes on macOS and in KiB elsewhere
+    return peak if sys.platform == "darwin" else peak * 1024
+
+
+class ResourceUsageLog:
+    """Collects the resource usage of the agents of a run."""
+
+    def __init__(self):
+        self.agents: List[AgentUsage] = []
+
+    @contextmanager
+    def measure(self, agent: str) -> Iterator[None]:
+        """Measure the block as ``agent``; it is recorded even if the block fails."""
+        reset_peak_rss()
+        start_wall = time.perf_counter()
+        start_cpu = _cpu_time()
+        try:
+            yield
+        finally:
+            self.agents.append(
+                AgentUsage(
+                    agent=agent,
+                    wall_seconds=time.perf_counter() - start_wall,
+                    cpu_seconds=_cpu_time() - start_cpu,
+                    peak_rss=peak_rss(),
+                )
+            )
+
+    def to_list(self) -> List[Dict[str, Any]]:
+        """Convert to a list for JSON serialization."""
+        return [usage.to_dict() for usage in self.agents]
+
+
+def format_usage(agents: List[AgentUsage]) -> str:
+    """Format agent resource usage as a table."""
+    width = max(len("Agent"), *(len(usage.agent) for usage in agents))
+    lines = [f"{'Agent':<{width}}  {'Wall (s)':>9}  {'CPU (s)':>9}  {'Peak RSS MiB':>12}"]
+    for usage in agents:
+        rss = "-" if usage.peak_rss is None else f"{usage.peak_rss / 1024 / 1024:.1f}"
+        lines.append(
+            f"{usage.agent:<{width}}  {usage.wall_seconds:>9.3f}  "
+            f"{usage.cpu_seconds:>9.3f}  {rss:>12}"
+        )
+    return "\n".join(lines)
+
@@ -0,0 +1,165 @@
+"""Retries for transient cloud API errors.
+
+Rate limiting (429), server errors (5xx), and dropped connections are retried
+with exponential backoff: the delay doubles from ``retry_delay`` up to
+``retry_max_delay``, and each wait is a random time between half and all of
+that delay, so parallel runs do not retry in lockstep. Other errors are raised
+at once. Configured in paddi.toml::
+
+    [execution]
+    retry_count = 3          # retries after the first attempt
+    retry_delay = 1          # first backoff, in seconds
+    retry_max_delay = 30     # longest backoff, in seconds
+
+Every retry is counted in :class:`RetryStats`, which the collector writes to
+the ``retries`` metadata of ``collected.json``. Every attempt, retries
+included, also waits for the ``[limits]`` rate limit of its API family (see
+:mod:`app.common.rate_limit`).
+"""
+
+import logging
+import random
+import time
+from dataclasses import asdict, dataclass, field
+from typing import Any, Callable, Dict, Optional
+
+import requests
+from google.api_core import exceptions as gcp_exceptions
+from grpc import RpcError, StatusCode
+
+from app.common.rate_limit import RateLimiter
+from app.config.paddi_config import PaddiConfig
+
+logger = logging.getLogger(__name__)
+
+DEFAULT_RETRIES = 3
+DEFAULT_DELAY = 1.0
+DEFAULT_MAX_DELAY = 30.0
+
+_RETRYABLE_ERRORS = (
+    gcp_exceptions.TooManyRequests,
+    gcp_exceptions.ResourceExhausted,
+    gcp_exceptions.ServerError,
+    ConnectionError,
+    requests.ConnectionError,
+)
+_RETRYABLE_RPC_CODES = (
+    StatusCode.RESOURCE_EXHAUSTED,
+    StatusCode.UNAVAILABLE,
+    StatusCode.INTERNAL,
+    StatusCode.DEADLINE_EXCEEDED,
+)
+
+
+def is_retryable(error: BaseException) -> bool:
+    """Return whether an API error is transient: 429, 5xx, or a dropped connection."""
+    if isinstance(error, _RETRYABLE_ERRORS):
+        return True
+    if isinstance(error, RpcError) and callable(getattr(error, "code", None)):
+        return error.code() in _RETRYABLE_RPC_CODES
+    status = getattr(getattr(error, "response", None), "status_code", None)
+    return isinstance(status, int) and (status == 429 or status >= 500)
+
+
+@dataclass
+class RetryStats:
+    """Retries made during a run."""
+
+    calls: int = 0
+    retries: int = 0
+    # Calls that still failed after the last retry
+    exhausted: int = 0
+    # Retries per operation, e.g. {"scc.list_findings": 2}
+    operations: Dict[str, int] = field(default_factory=dict)
+
+    def to_dict(self) -> Dict[str, Any]:
+        """Convert to a dictionary for the run metadata."""
+        return asdict(self)
+
+
+class RetryPolicy:
+    """Calls an API operation, retrying transient errors with capped backoff."""
+
+    def __init__(
+        self,
+        retries: int = DEFAULT_RETRIES,
+        delay: float = DEFAULT_DELAY,
+        max_delay: float = DEFAULT_MAX_DELAY,
+        stats: Optional[RetryStats] = None,
+        limiter: Optional[RateLimiter] = None,
+        sleep: Callable[[float], None] = time.sleep,
+        jitter: Callable[[], float] = random.random,
+    ):
+        """Initialize RetryPolicy.
+
+        Args:
+            retries: Retries after the first attempt
+            delay: First backoff, in seconds
+            max_delay: Longest backoff, in seconds
+            stats: Where retries are counted (a new one by default)
+            limiter: Rate limits applied to every attempt (none by default)
+            sleep: Sleep function, replaceable in tests
+            jitter: Returns a random number in [0, 1), replaceable in tests
+        """
+        self.retries = retries
+        self.delay = delay
+        self.max_delay = max_delay
+        self.stats = stats or RetryStats()
+        self.limiter = limiter or RateLimiter()
+        self._sleep = sleep
+        self._jitter = jitter
+
+    @classmethod
+    def from_config(cls, config: Optional[PaddiConfig] = None) -> "RetryPolicy":
+        """Create a policy from ``[execution]`` and ``[limits]`` in paddi.toml."""
+        config = config or PaddiConfig.load()
+        section = config.section("execution")
+        return cls(
+            retries=int(section.get("retry_count", DEFAULT_RETRIES)),
+            delay=float(section.get("retry_delay", DEFAULT_DELAY)),
+            max_delay=float(section.get("retry_max_delay", DEFAULT_MAX_DELAY)),
+            limiter=RateLimiter.from_config(config),
+        )
+
+    def backoff(self, retry: int) -> float:
+        """Return the wait after failed attempt ``retry`` (counting from 0)."""
+        ceiling = min(self.max_delay, self.delay * (2**retry))
+        return ceiling / 2 + self._jitter() * ceiling / 2
+
+    def call(self, operation: str, func: Callable[..., Any], *args, **kwargs) -> Any:
+        """Call ``func``, retrying transient errors.
+
+        Args:
+            operation: Name of the API operation, for logs and stats
+
+        Raises:
+            Exception: The last error, when it is not transient or retries ran out
+        """
+        self.stats.calls += 1
+        attempt = 0
+        while True:
+            self.limiter.acquire(operation)
+            try:
+                return func(*args, **kwargs)
+            except Exception as e:
+                if not is_retryable(e):
+                    raise
+                if attempt == self.retries:
+                    self.stats.exhausted += 1
+                    logger.error(
+                        "%s failed after %d attempts: %s", operation, self.retries + 1, e
+                    )
+                    raise
+                wait = self.backoff(attempt)
+                logger.warning(
+                    "%s failed with a transient error (attempt %d/%d), retrying in %.1fs: %s",
+                    operation,
+                    attempt + 1,
+                    self.retries + 1,
+                    wait,
+                    e,
+                )
+                self.stats.retries += 1
+                self.stats.operations[operation] = self.stats.operations.get(operation, 0) + 1
+                self._sleep(wait)
+                attempt += 1
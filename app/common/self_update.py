@@ -0,0 +1,275 @@
+"""Update the installed Paddi from its GitHub releases.
+
+``paddi self-update`` looks for the newest release on a channel, downloads its
+wheel, verifies it, and installs it over the running version with pip:
+
+- ``stable`` (the default): releases that are not marked as pre-releases
+- ``beta``: pre-releases as well
+
+Every release publishes a ``SHA256SUMS`` file next to the wheel; the wheel is
+installed only if its SHA-256 matches. When ``[self_update] public_key`` is
+set (a base64 Ed25519 public key), ``SHA256SUMS.sig`` must also be a valid
+signature of ``SHA256SUMS`` by that key::
+
+    [self_update]
+    channel = "beta"
+    public_key = "MCowBQYDK2VwAyEA..."
+
+A source checkout (``git clone`` + ``pip install -e``) is not replaced; update
+it with ``git pull`` instead.
+"""
+
+import base64
+import hashlib
+import logging
+import re
+import subprocess  # nosec B404
+import sys
+import tempfile
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import Any, Dict, List, Optional, Tuple
+
+import requests
+
+from app.common.exceptions import SelfUpdateError
+from app.config.paddi_config import PaddiConfig
+
+logger = logging.getLogger(__name__)
+
+RELEASES_URL = "https://api.github.com/repos/susumutomita/Paddi/releases"
+CHANNELS = ("stable", "beta")
+CHECKSUMS_ASSET = "SHA256SUMS"
+SIGNATURE_ASSET = "SHA256SUMS.sig"
+REQUEST_TIMEOUT_SECONDS = 30
+
+_VERSION = re.compile(r"^v?(\d+(?:\.\d+)*)(?:[-.]?(alpha|beta|rc|a|b)\.?(\d*))?", re.IGNORECASE)
+# Order of pre-release kinds; a final release sorts after all of them
+_PRE_ORDER = {"a": 0, "alpha": 0, "b": 1, "beta": 1, "rc": 2}
+
+
+def version_key(version: str) -> Optional[Tuple]:
+    """Return a sort key for a version such as ``v1.2.0``, ``1.3.0-beta.2``, or ``1.3.0rc1``."""
+    match = _VERSION.match(version.strip())
+    if not match:
+        return None
+    numbers = [int(part) for part in match.group(1).split(".")]
+    numbers += [0] * (3 - len(numbers))
+    if match.group(2) is None:
+        return (*numbers, 1, 0, 0)
+    return (*numbers, 0, _PRE_ORDER[match.group(2).lower()], int(match.group(3) or 0))
+
+
+@dataclass
+class Release:
+    """A published release."""
+
+    tag: str
+    prerelease: bool
+    url: str = ""
+    # Download URL of each asset, by file name
+    assets: Dict[str, str] = field(default_factory=dict)
+
+    @property
+    def version(self) -> str:
+        """The release's version, without the ``v`` of its tag."""
+        return self.tag[1:] if self.tag[:1] in ("v", "V") else self.tag
+
+    def wheel(self) -> Optional[str]:
+        """Return the name of the release's wheel, if it has one."""
+        return next((name for name in sorted(self.assets) if name.endswith(".whl")), None)
+
+    def to_dict(self) -> Dict[str, Any]:
+        """Convert to dictionary for JSON serialization."""
+        return {
+            "tag": self.tag,
+            "version": self.version,
+            "prerelease": self.prerelease,
+            "url": self.url,
+        }
+
+
+def running_from_checkout() -> bool:
+    """Return whether Paddi runs from a source checkout rather than an installed wheel."""
+    package = Path(__file__).resolve().parents[2]
+    return (package / ".git").exists() or (package / "setup.py").exists()
+
+
+class SelfUpdater:
+    """Finds, verifies, and installs releases of Paddi."""
+
+    def __init__(
+        self,
+        channel: str = "stable",
+        public_key: Optional[str] = None,
+        releases_url: str = RELEASES_URL,
+        timeout: float = REQUEST_TIMEOUT_SECONDS,
+    ):
+        """Initialize SelfUpdater.
+
+        Args:
+            channel: 'stable' or 'beta'
+            public_key: Base64 Ed25519 key that must have signed SHA256SUMS, if any
+            releases_url: GitHub API URL listing the releases
+            timeout: Timeout of each request, in seconds
+        """
+        if channel not in CHANNELS:
+            raise SelfUpdateError(f"unknown channel {channel} (use {' or '.join(CHANNELS)})")
+        self.channel = channel
+        self.public_key = public_key
+        self.releases_url = releases_url
+        self.timeout = timeout
+
+    @classmethod
+    def from_config(
+        cls, config: Optional[PaddiConfig] = None, channel: Optional[str] = None
+    ) -> "SelfUpdater":
+        """Create the updater from ``[self_update]``; ``channel`` overrides the configured one."""
+        section = (config or PaddiConfig.load()).section("self_update")
+        return cls(
+            channel=channel or section.get("channel", "stable"),
+            public_key=section.get("public_key"),
+            releases_url=section.get("releases_url", RELEASES_URL),
+        )
+
+    def _get(self, url: str, **kwargs) -> requests.Response:
+        try:
+            response = requests.get(url, timeout=self.timeout, **kwargs)
+            response.raise_for_status()
+        except requests.RequestException as e:
+            raise SelfUpdateError(f"{url} could not be downloaded ({e})") from e
+        return response
+
+    def releases(self) -> List[Release]:
+        """Return the published releases of the channel."""
+        response = self._get(self.releases_url, headers={"Accept": "application/vnd.github+json"})
+        releases = []
+        for item in response.json():
+            if item.get("draft") or (item.get("prerelease") and self.channel == "stable"):
+                continue
+            assets = {
+                asset["name"]: asset["browser_download_url"] for asset in item.get("assets", [])
+            }
+            releases.append(
+                Release(
+                    tag=item["tag_name"],
+                    prerelease=bool(item.get("prerelease")),
+                    url=item.get("html_url", ""),
+                    assets=assets,
+                )
+            )
+        return releases
+
+    def latest(self) -> Optional[Release]:
+        """Return the newest release of the channel, if there is one."""
+        versioned = [(version_key(r.tag), r) for r in self.releases()]
+        versioned = [(key, release) for key, release in versioned if key is not None]
+        return max(versioned, key=lambda pair: pair[0])[1] if versioned else None
+
+    def check(self, current: str) -> Optional[Release]:
+        """Return the newest release if it is newer than ``current``."""
+        release = self.latest()
+        current_key = version_key(current)
+        if release is None:
+            return None
+        if current_key is not None and version_key(release.tag) <= current_key:
+            return None
+        return release
+
+    def download(self, release: Release, directory: Path) -> Path:
+        """Download the release's wheel to ``directory`` and verify it.
+
+        Raises:
+            SelfUpdateError: If the release has no wheel or checksums, or they do not match
+        """
+        wheel = release.wheel()
+        if wheel is None:
+            raise SelfUpdateError(f"release {release.tag} has no wheel to install")
+        if CHECKSUMS_ASSET not in release.assets:
+            raise SelfUpdateError(f"release {release.tag} has no {CHECKSUMS_ASSET} to verify")
+
+        checksums = self._get(release.assets[CHECKSUMS_ASSET]).content
+        if self.public_key:
+            if SIGNATURE_ASSET not in release.assets:
+                raise SelfUpdateError(f"release {release.tag} is not signed ({SIGNATURE_ASSET})")
+            signature = self._get(release.assets[SIGNATURE_ASSET]).content
+            verify_signature(checksums, signature, self.public_key)
+
+        expected = parse_checksums(checksums.decode("utf-8", "replace")).get(wheel)
+        if expected is None:
+            raise SelfUpdateError(f"{CHECKSUMS_ASSET} of {release.tag} does not list {wheel}")
+        path = Path(directory) / wheel
+        path.write_bytes(self._get(release.assets[wheel]).content)
+        actual = hashlib.sha256(path.read_bytes()).hexdigest()
+        if actual != expected:
+            path.unlink()
+            raise SelfUpdateError(
+                f"checksum of {wheel} does not match", {"expected": expected, "actual": actual}
+            )
+        return path
+
+    def install(self, release: Release) -> str:
+        """Download, verify, and install a release over the running version.
+
+        Returns:
+            The file name of the installed wheel
+        """
+        if running_from_checkout():
+            raise SelfUpdateError(
+                "Paddi runs from a source checkout; update it with 'git pull' instead"
+            )
+        with tempfile.TemporaryDirectory(prefix="paddi-update-") as directory:
+            wheel = self.download(release, Path(directory))
+            logger.info("Installing %s", wheel.name)
+            result = subprocess.run(  # nosec B603
+                [sys.executable, "-m", "pip", "install", "--upgrade", str(wheel)],
+                capture_output=True,
+                text=True,
+                check=False,
+            )
+            if result.returncode != 0:
+                raise SelfUpdateError(
+                    f"pip could not install {wheel.name}", {"output": result.stderr[-2000:]}
+                )
+            return wheel.name
+
+
+def parse_checksums(text: str) -> Dict[str, str]:
+    """Parse ``sha256sum`` output: ``<hex digest>  <file name>`` per line."""
+    checksums = {}
+    for line in text.splitlines():
+        parts = line.strip().split(maxsplit=1)
+        if len(parts) == 2:
+            checksums[parts[1].lstrip("*")] = parts[0].lower()
+    return checksums
+
+
+def verify_signature(data: bytes, signature: bytes, public_key: str) -> None:
+    """Verify an Ed25519 signature of ``data``, raw or base64-encoded.
+
+    Raises:
+        SelfUpdateError: If the signature is invalid or cannot be checked
+    """
+    try:
+        from cryptography.exceptions import InvalidSignature
+        from cryptography.hazmat.primitives.asymmetric.ed25519 import Ed25519PublicKey
+        from cryptography.hazmat.primitives.serialization import load_der_public_key
+    except ImportError as e:
+        raise SelfUpdateError(
+            "verifying signatures requires the cryptography package (pip install cryptography)"
+        ) from e
+
+    try:
+        key_bytes = base64.b64decode(public_key)
+        key = (
+            Ed25519PublicKey.from_public_bytes(key_bytes)
+            if len(key_bytes) == 32
+            else load_der_public_key(key_bytes)
+        )
+        if len(signature) != 64:
+            signature = base64.b64decode(signature.strip())
+        key.verify(signature, data)
+    except InvalidSignature as e:
+        raise SelfUpdateError(f"the signature of {CHECKSUMS_ASSET} is invalid") from e
+    except (ValueError, TypeError) as e:
+        raise SelfUpdateError(f"the signature could not be checked ({e})") from e
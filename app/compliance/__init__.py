@@ -0,0 +1,8 @@
+"""Compliance framework mappings and coverage reporting."""
+
+from app.compliance.cis_gcp import CIS_GCP
+from app.compliance.pci_dss import PCI_DSS
+from app.compliance.soc2 import SOC2
+
+# Frameworks by key, used in report sections and ``<key>-csv`` export formats
+FRAMEWORKS = {framework.key: framework for framework in (CIS_GCP, SOC2, PCI_DSS)}
@@ -0,0 +1,59 @@
+"""CIS Google Cloud Platform Foundation Benchmark control mapping."""
+
+from app.compliance.framework import ComplianceFramework, Control
+
+CIS_GCP = ComplianceFramework(
+    key="cis",
+    name="CIS Google Cloud Platform Foundation Benchmark",
+    version="v2.0.0",
+    controls=[
+        Control(
+            "1.5",
+            "Ensure that Service Account has no Admin privileges",
+            ["OVERPRIVILEGED_SERVICE_ACCOUNT", "OVER_PRIVILEGED_ACCOUNT"],
+        ),
+        Control(
+            "1.9",
+            "Ensure that Cloud KMS cryptokeys are not anonymously or publicly accessible",
+            ["CRYPTO_KEY_PUBLIC_ACCESS"],
+        ),
+        Control(
+            "2.1",
+            "Ensure that Cloud Audit Logging is configured properly",
+            ["AUDIT_LOGGING_DISABLED", "BUCKET_LOGGING_DISABLED"],
+        ),
+        Control(
+            "3.6",
+            "Ensure that SSH access is restricted from the internet",
+            ["OPEN_FIREWALL", "FIREWALL_RULE_OPEN"],
+            ports=(22,),
+        ),
+        Control(
+            "3.7",
+            "Ensure that RDP access is restricted from the internet",
+            ["OPEN_FIREWALL", "FIREWALL_RULE_OPEN"],
+            ports=(3389,),
+        ),
+        Control(
+            "3.9",
+            "Ensure no HTTPS or SSL proxy load balancers permit SSL policies with weak "
+            "cipher suites",
+            ["WEAK_SSL_POLICY"],
+        ),
+        Control(
+            "5.1",
+            "Ensure that Cloud Storage bucket is not anonymously or publicly accessible",
+            ["PUBLIC_BUCKET"],
+        ),
+        Control(
+            "5.2",
+            "Ensure that Cloud Storage buckets have uniform bucket-level access enabled",
+            ["BUCKET_UNIFORM_ACCESS_DISABLED"],
+        ),
+        Control(
+            "7.1",
+            "Ensure that BigQuery datasets are not anonymously or publicly accessible",
+            ["DATASET_PUBLIC_ACCESS"],
+        ),
+    ],
+)
@@ -0,0 +1,125 @@
+"""Compliance framework definitions and per-control coverage evaluation.
+
+A framework is a list of controls, each mapped to the finding categories that
+violate it. A control FAILs when any finding in one of its categories is
+present and PASSes otherwise. Controls about particular ports (SSH, RDP) only
+FAIL for findings that expose those ports or do not say which ports they
+expose. Frameworks with a configured scope (e.g. the PCI cardholder data
+environment) also flag findings on in-scope resources.
+"""
+
+import csv
+import io
+from dataclasses import dataclass, field
+from typing import Any, Callable, Dict, List, Optional, Tuple
+
+from app.findings.fingerprint import compute_fingerprint
+
+PASS = "PASS"
+FAIL = "FAIL"
+
+
+@dataclass(frozen=True)
+class Control:
+    """A single compliance control."""
+
+    control_id: str
+    title: str
+    categories: List[str] = field(default_factory=list)
+    ports: Tuple[int, ...] = ()
+
+    def matches(self, finding: Dict[str, Any]) -> bool:
+        """Return whether a finding violates the control."""
+        if str(finding.get("category") or "").upper() not in self.categories:
+            return False
+        exposed = finding.get("ports")
+        # A finding that does not say which ports it exposes may expose any of them
+        if not self.ports or not exposed:
+            return True
+        return bool(set(self.ports) & set(exposed))
+
+
+@dataclass(frozen=True)
+class ComplianceFramework:
+    """A compliance framework and its controls."""
+
+    key: str
+    name: str
+    version: str
+    controls: List[Control] = field(default_factory=list)
+
+    def controls_for(self, category: str) -> List[Control]:
+        """Return the controls a finding category maps to."""
+        category = (category or "").upper()
+        return [control for control in self.controls if category in control.categories]
+
+
+@dataclass
+class ControlResult:
+    """Evaluation result of one control."""
+
+    control: Control
+    status: str
+    findings: List[Dict[str, Any]] = field(default_factory=list)
+    in_scope: List[Dict[str, Any]] = field(default_factory=list)
+
+
+@dataclass
+class FrameworkCoverage:
+    """Evaluation results of every control in a framework."""
+
+    framework: ComplianceFramework
+    results: List[ControlResult] = field(default_factory=list)
+    scoped: bool = False
+
+    @property
+    def passed(self) -> int:
+        """Number of passing controls."""
+        return sum(1 for result in self.results if result.status == PASS)
+
+    @property
+    def failed(self) -> int:
+        """Number of failing controls."""
+        return sum(1 for result in self.results if result.status == FAIL)
+
+
+def evaluate(
+    framework: ComplianceFramework,
+    findings: List[Dict[str, Any]],
+    in_scope: Optional[Callable[[Dict[str, Any]], bool]] = None,
+) -> FrameworkCoverage:
+    """Evaluate each control of a framework against a set of findings.
+
+    Args:
+        framework: Framework to evaluate
+        findings: Findings as loaded from explained.json
+        in_scope: Optional predicate marking findings on in-scope resources
+    """
+    results = []
+    for control in framework.controls:
+        matched = [finding for finding in findings if control.matches(finding)]
+        scoped = [finding for finding in matched if in_scope and in_scope(finding)]
+        results.append(ControlResult(control, FAIL if matched else PASS, matched, scoped))
+    return FrameworkCoverage(framework, results, scoped=in_scope is not None)
+
+
+def coverage_to_csv(coverage: FrameworkCoverage) -> str:
+    """Render framework coverage as CSV with one row per control."""
+    output = io.StringIO()
+    writer = csv.writer(output, lineterminator="\n")
+    writer.writerow(["framework", "control_id", "title", "status", "findings", "fingerprints"])
+    for result in coverage.results:
+        writer.writerow(
+            [
+                f"{coverage.framework.name} {coverage.framework.version}",
+                result.control.control_id,
+                result.control.title,
+                result.status,
+                len(result.findings),
+                ";".join(
+                    finding.get("fingerprint") or compute_fingerprint(finding)
+                    for finding in result.findings
+                ),
+            ]
+        )
+    return output.getvalue()
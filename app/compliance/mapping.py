@@ -0,0 +1,85 @@
+"""Loading of user-supplied compliance mapping files.
+
+Auditors can adjust a framework, or add a new one, with a TOML mapping file
+referenced from paddi.toml::
+
+    [compliance.mappings]
+    soc2 = "compliance/soc2.toml"
+
+A mapping file replaces the built-in framework with the same key::
+
+    name = "SOC 2 Trust Services Criteria"
+    version = "2017 (revised 2022)"
+
+    [[controls]]
+    id = "CC6.1"
+    title = "Logical access security"
+    categories = ["OVERPRIVILEGED_ROLE", "PUBLIC_BUCKET"]
+"""
+
+import logging
+from pathlib import Path
+from typing import Dict, Optional
+
+try:
+    import tomllib
+except ModuleNotFoundError:  # Python < 3.11
+    import tomli as tomllib
+
+from app.common.exceptions import ConfigurationError
+from app.compliance import FRAMEWORKS
+from app.compliance.framework import ComplianceFramework, Control
+from app.config.paddi_config import PaddiConfig
+
+logger = logging.getLogger(__name__)
+
+
+def load_mapping_file(key: str, path: Path) -> ComplianceFramework:
+    """Load a compliance framework from a TOML mapping file.
+
+    Raises:
+        ConfigurationError: If the file is missing, unparsable, or incomplete
+    """
+    if not path.exists():
+        raise ConfigurationError(f"compliance.mappings.{key}", {"error": f"{path} not found"})
+
+    try:
+        with open(path, "rb") as f:
+            data = tomllib.load(f)
+    except tomllib.TOMLDecodeError as e:
+        raise ConfigurationError(str(path), {"error": str(e)}) from e
+
+    controls = []
+    for i, entry in enumerate(data.get("controls", [])):
+        if not entry.get("id") or not entry.get("title"):
+            raise ConfigurationError(str(path), {"error": f"controls[{i}] needs an id and title"})
+        controls.append(
+            Control(
+                str(entry["id"]),
+                str(entry["title"]),
+                [str(category).upper() for category in entry.get("categories", [])],
+            )
+        )
+
+    builtin = FRAMEWORKS.get(key)
+    return ComplianceFramework(
+        key=key,
+        name=data.get("name") or (builtin.name if builtin else key),
+        version=data.get("version") or (builtin.version if builtin else ""),
+        controls=controls,
+    )
+
+
+def load_frameworks(config: Optional[PaddiConfig] = None) -> Dict[str, ComplianceFramework]:
+    """Return the built-in frameworks with any configured mapping files applied."""
+    config = config or PaddiConfig.load()
+    base_dir = config.path.parent if config.path else Path.cwd()
+
+    frameworks = dict(FRAMEWORKS)
+    for key, path in config.section("compliance.mappings").items():
+        mapping_path = Path(path)
+        if not mapping_path.is_absolute():
+            mapping_path = base_dir / mapping_path
+        frameworks[key] = load_mapping_file(key, mapping_path)
+        logger.info("Loaded %s compliance mapping from %s", key, mapping_path)
+    return frameworks
@@ -0,0 +1,62 @@
+"""PCI DSS requirement mapping."""
+
+from app.compliance.framework import ComplianceFramework, Control
+
+PCI_DSS = ComplianceFramework(
+    key="pci-dss",
+    name="PCI DSS",
+    version="v4.0",
+    controls=[
+        Control(
+            "1.3",
+            "Network access to and from the cardholder data environment is restricted",
+            ["OPEN_FIREWALL", "FIREWALL_RULE_OPEN"],
+        ),
+        Control(
+            "1.4",
+            "Network connections between trusted and untrusted networks are controlled",
+            ["OPEN_FIREWALL", "FIREWALL_RULE_OPEN", "PUBLIC_BUCKET", "DATASET_PUBLIC_ACCESS"],
+        ),
+        Control(
+            "3.5",
+            "Primary account number (PAN) is secured wherever it is stored",
+            ["UNENCRYPTED_DATABASE", "LOG_NOT_ENCRYPTED"],
+        ),
+        Control(
+            "3.6",
+            "Cryptographic keys used to protect stored account data are secured",
+            ["CRYPTO_KEY_PUBLIC_ACCESS"],
+        ),
+        Control(
+            "4.2",
+            "PAN is protected with strong cryptography during transmission",
+            ["WEAK_SSL_POLICY", "SQL_SSL_NOT_REQUIRED"],
+        ),
+        Control(
+            "6.3",
+            "Security vulnerabilities are identified and addressed",
+            ["CONTAINER_VULNERABILITY"],
+        ),
+        Control(
+            "6.4",
+            "Public-facing web applications are protected against attacks",
+            ["XSS_SCRIPTING"],
+        ),
+        Control(
+            "7.2",
+            "Access to system components and data is appropriately defined and assigned",
+            [
+                "OVERPRIVILEGED_ROLE",
+                "OVERPRIVILEGED_SERVICE_ACCOUNT",
+                "OVER_PRIVILEGED_ACCOUNT",
+                "PUBLIC_BUCKET",
+                "DATASET_PUBLIC_ACCESS",
+            ],
+        ),
+        Control(
+            "10.2",
+            "Audit logs are implemented to support detection of anomalies and suspicious activity",
+            ["AUDIT_LOGGING_DISABLED", "BUCKET_LOGGING_DISABLED"],
+        ),
+    ],
+)
@@ -0,0 +1,53 @@
+"""SOC 2 Trust Services Criteria control mapping."""
+
+from app.compliance.framework import ComplianceFramework, Control
+
+SOC2 = ComplianceFramework(
+    key="soc2",
+    name="SOC 2 Trust Services Criteria",
+    version="2017 (revised 2022)",
+    controls=[
+        Control(
+            "CC6.1",
+            "Logical access security and encryption of information assets",
+            [
+                "OVERPRIVILEGED_ROLE",
+                "OVERPRIVILEGED_SERVICE_ACCOUNT",
+                "OVER_PRIVILEGED_ACCOUNT",
+                "CRYPTO_KEY_PUBLIC_ACCESS",
+                "UNENCRYPTED_DATABASE",
+                "LOG_NOT_ENCRYPTED",
+            ],
+        ),
+        Control(
+            "CC6.3",
+            "Role-based access following least privilege",
+            ["OVERPRIVILEGED_ROLE", "OVERPRIVILEGED_SERVICE_ACCOUNT", "OVER_PRIVILEGED_ACCOUNT"],
+        ),
+        Control(
+            "CC6.6",
+            "Logical access restricted from outside system boundaries",
+            ["OPEN_FIREWALL", "FIREWALL_RULE_OPEN", "PUBLIC_BUCKET", "DATASET_PUBLIC_ACCESS"],
+        ),
+        Control(
+            "CC6.7",
+            "Information protected during transmission",
+            ["WEAK_SSL_POLICY", "SQL_SSL_NOT_REQUIRED"],
+        ),
+        Control(
+            "CC7.1",
+            "Detection of vulnerabilities and configuration changes",
+            ["CONTAINER_VULNERABILITY", "XSS_SCRIPTING"],
+        ),
+        Control(
+            "CC7.2",
+            "Monitoring of system components for anomalies",
+            ["AUDIT_LOGGING_DISABLED", "BUCKET_LOGGING_DISABLED"],
+        ),
+        Control(
+            "C1.1",
+            "Confidential information identified and protected",
+            ["PUBLIC_BUCKET", "DATASET_PUBLIC_ACCESS", "UNENCRYPTED_DATABASE"],
+        ),
+    ],
+)
@@ -0,0 +1,219 @@
+"""Encrypted secret fields in paddi.toml.
+
+``paddi config encrypt`` replaces the plaintext value of every sensitive field
+(``api_key``, ``token``, ``secret``, ``password`` and names ending in them) with
+an ``enc:`` value, so the file can be committed. Values are decrypted when they
+are read through :class:`~app.config.paddi_config.PaddiConfig`.
+
+Two key sources are supported, chosen in ``[secrets.encryption]``::
+
+    [secrets.encryption]
+    provider = "keyring"  # default: Fernet key from PADDI_CONFIG_KEY or the OS keyring
+    # provider = "kms"
+    # kms_key = "projects/p/locations/global/keyRings/paddi/cryptoKeys/config"
+    fields = ["client_secret"]  # extra field names to encrypt
+"""
+
+import base64
+import logging
+from pathlib import Path
+from typing import Any, Dict, Iterator, List, Optional, Tuple
+
+from app.common.exceptions import ConfigurationError
+from app.config.secrets import resolve_secret, set_secret
+
+logger = logging.getLogger(__name__)
+
+ENCRYPTED_PREFIX = "enc:"
+FERNET_PREFIX = "enc:fernet:"
+KMS_PREFIX = "enc:kms:"
+CONFIG_KEY_ENV = "PADDI_CONFIG_KEY"
+CONFIG_KEY_NAME = "paddi_config_key"
+SENSITIVE_FIELDS = ("api_key", "token", "secret", "password")
+# Values that already avoid plaintext
+_REFERENCE_PREFIXES = (ENCRYPTED_PREFIX, "vault:", "secretmanager:")
+
+
+def is_encrypted(value: Any) -> bool:
+    """Return whether a config value is an ``enc:`` value."""
+    return isinstance(value, str) and value.startswith(ENCRYPTED_PREFIX)
+
+
+def is_sensitive(name: str, extra_fields: Tuple[str, ...] = ()) -> bool:
+    """Return whether a field holds a secret (``token``, ``hec_token``; not ``token_env``)."""
+    fields = SENSITIVE_FIELDS + tuple(extra_fields)
+    return name in fields or any(name.endswith(f"_{field}") for field in fields)
+
+
+class FernetCipher:
+    """Symmetric encryption with a Fernet key kept outside the config file."""
+
+    prefix = FERNET_PREFIX
+
+    def __init__(self, key: str):
+        """Initialize the cipher with a urlsafe base64 Fernet key."""
+        from cryptography.fernet import Fernet
+
+        try:
+            self._fernet = Fernet(key.encode("ascii"))
+        except ValueError as e:
+            raise ConfigurationError(CONFIG_KEY_NAME, {"error": "invalid Fernet key"}) from e
+
+    @classmethod
+    def load(cls, create: bool = False) -> "FernetCipher":
+        """Load the key from ``PADDI_CONFIG_KEY`` or the OS keyring.
+
+        Args:
+            create: Generate and store a key in the keyring when none exists
+
+        Raises:
+            ConfigurationError: If there is no key and ``create`` is false
+        """
+        key = resolve_secret(CONFIG_KEY_ENV, CONFIG_KEY_NAME)
+        if not key:
+            if not create:
+                raise ConfigurationError(
+                    CONFIG_KEY_NAME,
+                    {"error": f"set {CONFIG_KEY_ENV} or store the key in the OS keyring"},
+                )
+            from cryptography.fernet import Fernet
+
+            key = Fernet.generate_key().decode("ascii")
+            set_secret(CONFIG_KEY_NAME, key)
+            logger.info("Generated a config encryption key and stored it in the OS keyring")
+        return cls(key)
+
+    def encrypt(self, plaintext: str) -> str:
+        """Encrypt a value."""
+        return self.prefix + self._fernet.encrypt(plaintext.encode("utf-8")).decode("ascii")
+
+    def decrypt(self, value: str) -> str:
+        """Decrypt an ``enc:fernet:`` value."""
+        from cryptography.fernet import InvalidToken
+
+        try:
+            return self._fernet.decrypt(value[len(self.prefix) :].encode("ascii")).decode("utf-8")
+        except InvalidToken as e:
+            raise ConfigurationError(
+                "secrets.encryption", {"error": "value was encrypted with a different key"}
+            ) from e
+
+
+class KMSCipher:
+    """Encryption with a Cloud KMS key; the key never leaves KMS."""
+
+    prefix = KMS_PREFIX
+
+    def __init__(self, key_name: str, client: Optional[Any] = None):
+        """Initialize the cipher.
+
+        Args:
+            key_name: ``projects/<p>/locations/<l>/keyRings/<r>/cryptoKeys/<k>``
+            client: KeyManagementServiceClient (created on first use if omitted)
+        """
+        self.key_name = key_name
+        self._client = client
+
+    @property
+    def client(self):
+        """Lazily create the KMS client."""
+        if self._client is None:
+            from google.cloud import kms
+
+            self._client = kms.KeyManagementServiceClient()
+        return self._client
+
+    def encrypt(self, plaintext: str) -> str:
+        """Encrypt a value."""
+        response = self.client.encrypt(
+            request={"name": self.key_name, "plaintext": plaintext.encode("utf-8")}
+        )
+        return self.prefix + base64.b64encode(response.ciphertext).decode("ascii")
+
+    def decrypt(self, value: str) -> str:
+        """Decrypt an ``enc:kms:`` value."""
+        ciphertext = base64.b64decode(value[len(self.prefix) :])
+        try:
+            response = self.client.decrypt(
+                request={"name": self.key_name, "ciphertext": ciphertext}
+            )
+        except Exception as e:  # pylint: disable=broad-exception-caught
+            raise ConfigurationError("secrets.encryption", {"error": str(e)}) from e
+        return response.plaintext.decode("utf-8")
+
+
+def cipher_from_config(section: Dict[str, Any], create: bool = False):
+    """Create the cipher chosen in ``[secrets.encryption]``.
+
+    Raises:
+        ConfigurationError: If the provider is unknown or its key is missing
+    """
+    provider = section.get("provider", "keyring")
+    if provider == "keyring":
+        return FernetCipher.load(create=create)
+    if provider == "kms":
+        if not section.get("kms_key"):
+            raise ConfigurationError("secrets.encryption.kms_key", {"error": "required for kms"})
+        return KMSCipher(section["kms_key"])
+    raise ConfigurationError(
+        "secrets.encryption.provider", {"error": f"unknown provider: {provider}"}
+    )
+
+
+def decrypt_value(value: str, section: Dict[str, Any]) -> str:
+    """Decrypt an ``enc:`` value with the cipher its prefix names.
+
+    Raises:
+        ConfigurationError: If the value or key is invalid
+    """
+    if value.startswith(FERNET_PREFIX):
+        return FernetCipher.load().decrypt(value)
+    if value.startswith(KMS_PREFIX):
+        kms_section = dict(section, provider="kms")
+        return cipher_from_config(kms_section).decrypt(value)
+    raise ConfigurationError(value[:16], {"error": "unknown encryption scheme"})
+
+
+def sensitive_values(data: Dict[str, Any], extra_fields: Tuple[str, ...] = ()) -> Iterator:
+    """Yield ``(dotted key, value)`` for plaintext secrets in tables (not arrays)."""
+    pending = [("", data)]
+    while pending:
+        prefix, table = pending.pop(0)
+        for name, value in table.items():
+            if isinstance(value, dict):
+                pending.append((f"{prefix}{name}.", value))
+            elif (
+                isinstance(value, str)
+                and value
+                and prefix
+                and is_sensitive(name, extra_fields)
+                and not value.startswith(_REFERENCE_PREFIXES)
+            ):
+                yield f"{prefix}{name}", value
+
+
+def encrypt_file(path: Path) -> List[str]:
+    """Encrypt every plaintext secret field of a config file in place.
+
+    Returns:
+        Dotted keys that were encrypted
+
+    Raises:
+        ConfigurationError: If the file cannot be read or a value cannot be rewritten
+    """
+    from app.config.paddi_config import PaddiConfig
+    from app.config.writer import set_config_value
+
+    if not path.exists():
+        raise ConfigurationError(str(path), {"error": "file not found"})
+    data = PaddiConfig.load(str(path), strict=False).data
+    section = data.get("secrets", {}).get("encryption", {})
+    extra_fields = tuple(section.get("fields", []))
+    targets = list(sensitive_values(data, extra_fields))
+    if not targets:
+        return []
+
+    cipher = cipher_from_config(section, create=True)
+    for key, value in targets:
+        set_config_value(path, key, cipher.encrypt(value))
+    return [key for key, _ in targets]
@@ -0,0 +1,117 @@
+"""Loader for the optional ``paddi.toml`` configuration file."""
+
+import logging
+import os
+from pathlib import Path
+from typing import Any, Dict, Optional
+
+try:
+    import tomllib
+except ModuleNotFoundError:  # Python < 3.11
+    import tomli as tomllib
+
+from app.common.exceptions import ConfigurationError
+from app.config.encryption import decrypt_value, is_encrypted
+from app.config.schema import check_schema
+from app.config.secret_manager import SecretManagerResolver, is_secret_manager_reference
+from app.config.vault import VaultClient, is_vault_reference
+
+logger = logging.getLogger(__name__)
+
+DEFAULT_CONFIG_PATH = "paddi.toml"
+
+
+class PaddiConfig:
+    """Read-only view over the sections of ``paddi.toml``.
+
+    ``vault:<path>#<key>`` values (resolved against ``[secrets.vault]``) and
+    ``secretmanager:projects/<p>/secrets/<s>`` values are fetched, and ``enc:``
+    values are decrypted, when they are read through :meth:`get` or
+    :meth:`section`; ``data`` keeps the values as written.
+    """
+
+    def __init__(self, data: Optional[Dict[str, Any]] = None, path: Optional[Path] = None):
+        """Initialize PaddiConfig with parsed TOML data."""
+        self.data = data or {}
+        self.path = path
+        self._vault = None
+        self._secret_manager = None
+
+    @classmethod
+    def load(cls, path: Optional[str] = None, strict: bool = True) -> "PaddiConfig":
+        """Load configuration from a TOML file.
+
+        The file is resolved from ``path``, then ``PADDI_CONFIG``, then
+        ``./paddi.toml``. A missing default file yields an empty configuration.
+
+        Args:
+            path: Explicit path to a configuration file
+            strict: Reject unknown keys, wrong types, and out-of-range values
+
+        Returns:
+            Parsed configuration
+
+        Raises:
+            ConfigurationError: If the file cannot be parsed, breaks the schema in
+                strict mode, or an explicit path is missing
+        """
+        explicit = path or os.getenv("PADDI_CONFIG")
+        config_path = Path(explicit or DEFAULT_CONFIG_PATH)
+
+        if not config_path.exists():
+            if explicit:
+                raise ConfigurationError(str(config_path), {"error": "file not found"})
+            return cls(path=None)
+
+        try:
+            with open(config_path, "rb") as f:
+                data = tomllib.load(f)
+        except tomllib.TOMLDecodeError as e:
+            raise ConfigurationError(str(config_path), {"error": str(e)}) from e
+
+        diagnostics = check_schema(data) if strict else []
+        if diagnostics:
+            details = "; ".join(str(diagnostic) for diagnostic in diagnostics)
+            raise ConfigurationError(
+                str(config_path),
+                {"error": f"{details} (run 'paddi config validate' for details)"},
+            )
+
+        logger.debug("Loaded configuration from %s", config_path)
+        return cls(data, config_path)
+
+    def get(self, key: str, default: Any = None, resolve: bool = True) -> Any:
+        """Get a value by dotted key path (e.g. ``general.project_id``).
+
+        With ``resolve=False``, secret references are returned as written.
+        """
+        node: Any = self.data
+        for part in key.split("."):
+            if not isinstance(node, dict) or part not in node:
+                return default
+            node = node[part]
+        return self._resolve(node) if resolve else node
+
+    def section(self, name: str) -> Dict[str, Any]:
+        """Get a table by dotted name, returning an empty dict when absent."""
+        value = self.get(name, {})
+        return value if isinstance(value, dict) else {}
+
+    def _resolve(self, value: Any) -> Any:
+        """Replace ``vault:`` references in a value (recursively for tables and arrays)."""
+        if isinstance(value, dict):
+            return {key: self._resolve(item) for key, item in value.items()}
+        if isinstance(value, list):
+            return [self._resolve(item) for item in value]
+        if is_vault_reference(value):
+            if self._vault is None:
+                vault = self.data.get("secrets", {}).get("vault", {})
+                self._vault = VaultClient.from_config(vault)
+            return self._vault.resolve(value)
+        if is_encrypted(value):
+            return decrypt_value(value, self.data.get("secrets", {}).get("encryption", {}))
+        if is_secret_manager_reference(value):
+            if self._secret_manager is None:
+                self._secret_manager = SecretManagerResolver()
+            return self._secret_manager.resolve(value)
+        return value
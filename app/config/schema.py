@@ -0,0 +1,429 @@
+"""Schema of ``paddi.toml``: the tables and keys Paddi reads, with types and ranges.
+
+:func:`check_schema` reports unknown keys (with a "did you mean" suggestion),
+values of the wrong type, and values out of range. It works on the file as
+written, so secret references are checked as strings and never resolved.
+"""
+
+import difflib
+from dataclasses import dataclass
+from datetime import date
+from typing import Any, Dict, List, Optional, Sequence, Tuple
+
+from app.common.models import SEVERITY_ORDER
+
+_TYPE_NAMES = {
+    str: "a string",
+    bool: "true or false",
+    int: "an integer",
+    list: "an array",
+    date: "a date",
+}
+
+
+@dataclass(frozen=True)
+class Field:
+    """A leaf value: allowed types, and optional range or choices."""
+
+    types: Tuple[type, ...]
+    minimum: Optional[float] = None
+    maximum: Optional[float] = None
+    exclusive_minimum: bool = False
+    choices: Optional[Sequence[Any]] = None
+
+    def describe(self) -> str:
+        """Describe the accepted types."""
+        if float in self.types:
+            return "a number"
+        return " or ".join(_TYPE_NAMES.get(t, "a table") for t in self.types)
+
+
+@dataclass(frozen=True)
+class MapOf:
+    """A table whose keys are chosen by the user (e.g. webhook names or categories)."""
+
+    value: Any
+
+
+# Anything goes: free-form tables documented as examples, plugin settings, etc.
+ANY = object()
+
+STRING = Field((str,))
+BOOL = Field((bool,))
+STRINGS = Field((list,))
+STRING_OR_LIST = Field((str, list))
+TABLE = Field((dict,))
+SEVERITY = Field((str,), choices=SEVERITY_ORDER)
+SEVERITIES = Field((list,))
+POSITIVE = Field((int, float), minimum=0, exclusive_minimum=True)
+NON_NEGATIVE = Field((int, float), minimum=0)
+POSITIVE_INT = Field((int,), minimum=1)
+NON_NEGATIVE_INT = Field((int,), minimum=0)
+URL = Field((str,))
+ROLE = Field((str,), choices=("viewer", "operator", "admin"))
+
+SCHEMA: Dict[str, Any] = {
+    "general": {
+        "project_id": STRING,
+        "organization_id": STRING,
+        "use_mock": BOOL,
+        "output_dir": STRING,
+        "log_level": Field((str,), choices=("DEBUG", "INFO", "WARNING", "ERROR")),
+        "provider": Field((str,), choices=("gcp", "aws", "azure", "github")),
+        "ai_provider": Field((str,), choices=("gemini", "ollama")),
+        "ollama_model": STRING,
+        "ollama_endpoint": URL,
+    },
+    "paths": {
+        "python_interpreter": STRING,
+        "agents_dir": STRING,
+        "data_dir": STRING,
+        "templates_dir": STRING,
+        "credentials_path": STRING,
+    },
+    "execution": {
+        "timeout_seconds": POSITIVE,
+        "parallelization": POSITIVE_INT,
+        "retry_count": NON_NEGATIVE_INT,
+        "retry_delay": NON_NEGATIVE,
+        "retry_max_delay": NON_NEGATIVE,
+        "max_memory_mb": POSITIVE_INT,
+        "max_cpu_percent": Field((int, float), minimum=0, maximum=100, exclusive_minimum=True),
+    },
+    "vertex_ai": {
+        "region": STRING,
+        "model": STRING,
+        "temperature": Field((int, float), minimum=0, maximum=2),
+        "max_tokens": POSITIVE_INT,
+        "prompt_template": STRING,
+    },
+    "collector": {
+        "resource_types": STRINGS,
+        "iam_filters": TABLE,
+        "scc_settings": TABLE,
+        "rate_limit": TABLE,
+        "cache": {"enabled": BOOL, "ttl_seconds": NON_NEGATIVE},
+        "asset_inventory": {
+            "enabled": BOOL,
+            "scope": Field((str,), choices=("project", "organization")),
+            "asset_types": STRINGS,
+        },
+        "firewall": {"enabled": BOOL, "sensitive_ports": Field((list,))},
+        "gke": {"enabled": BOOL},
+        "sql": {"enabled": BOOL},
+        "storage": {"enabled": BOOL},
+        "audit_logs": {
+            "enabled": BOOL,
+            "window_hours": POSITIVE_INT,
+            "log_types": STRINGS,
+            "filter": STRING,
+            "max_entries": POSITIVE_INT,
+            "known_principals": STRINGS,
+            "denied_threshold": POSITIVE_INT,
+        },
+    },
+    "reporter": {
+        "formats": STRINGS,
+        "template_dir": STRING,
+        "template_settings": TABLE,
+        "html_settings": TABLE,
+        "markdown_settings": TABLE,
+        "branding": {
+            "company_name": STRING,
+            "logo": STRING,
+            "primary_color": STRING,
+            "background_color": STRING,
+            "severity_colors": TABLE,
+            "footer": STRING,
+        },
+    },
+    "filters": {
+        "min_severity": SEVERITY,
+        "fail_on_severity": SEVERITY,
+        "resource_filters": TABLE,
+        "finding_filters": TABLE,
+    },
+    "notifications": {
+        "webhook_url": URL,
+        "webhook_on_severity": SEVERITIES,
+        "email": TABLE,
+        "webhooks": MapOf(Field((str, dict))),  # a URL, or a table checked against _WEBHOOK
+        "datadog": {
+            "enabled": BOOL,
+            "site": STRING,
+            "api_key": STRING,
+            "api_key_env": STRING,
+            "tags": STRINGS,
+            "metric_prefix": STRING,
+        },
+        "pubsub": {"topic": STRING, "min_severity": SEVERITY},
+    },
+    "severity_overrides": MapOf(SEVERITY),
+    "suppressions": {"rules": Field((list,))},
+    "owners": MapOf({"resources": STRING_OR_LIST, "categories": STRING_OR_LIST, "contact": STRING}),
+    "risk": {
+        "use_cvss": BOOL,
+        "severity_weights": MapOf(NON_NEGATIVE),
+        "grade_thresholds": MapOf(NON_NEGATIVE),
+    },
+    "cvss": {"vectors": MapOf(STRING)},
+    "tags": MapOf(STRING_OR_LIST),
+    "compliance": {"mappings": MapOf(STRING), "scope": MapOf(STRINGS)},
+    "history": {"enabled": BOOL},
+    "retention": {
+        "max_runs": POSITIVE_INT,
+        "max_age_days": POSITIVE,
+        "max_disk_bytes": POSITIVE_INT,
+    },
+    "archive": {"dir": STRING},
+    "limits": MapOf(POSITIVE),
+    "network": {
+        "proxy": {
+            "url": URL,
+            "http": URL,
+            "https": URL,
+            "no_proxy": STRING_OR_LIST,
+            "username": STRING,
+            "password": STRING,
+            "password_env": STRING,
+            "ca_bundle": STRING,
+        },
+    },
+    "llm": {
+        "local_only": BOOL,
+        "local_hosts": STRINGS,
+        "circuit_breaker": {
+            "failure_threshold": POSITIVE_INT,
+            "cooldown_seconds": NON_NEGATIVE,
+            "on_open": Field((str,), choices=("rules", "pause", "fallback")),
+            "fallback_provider": Field((str,), choices=("gemini", "ollama")),
+            "max_pauses": NON_NEGATIVE_INT,
+        },
+    },
+    "redaction": {"enabled": BOOL, "detectors": STRINGS, "patterns": MapOf(STRING)},
+    "export": {
+        "github_issues": {
+            "repo": STRING,
+            "labels": STRINGS,
+            "min_severity": SEVERITY,
+            "token": STRING,
+            "token_env": STRING,
+        },
+        "pr_comment": {"max_findings": POSITIVE_INT, "token": STRING, "token_env": STRING},
+        "scc": {"source": STRING},
+        "splunk_hec": {
+            "url": URL,
+            "token": STRING,
+            "token_env": STRING,
+            "index": STRING,
+            "sourcetype": STRING,
+            "batch_size": POSITIVE_INT,
+            "retries": NON_NEGATIVE_INT,
+            "timeout": POSITIVE,
+            "verify_tls": BOOL,
+        },
+    },
+    "storage": {
+        "gcs": {
+            "bucket": STRING,
+            "prefix": STRING,
+            "signed_urls": BOOL,
+            "signed_url_expiration": Field((int,), minimum=1, maximum=7 * 24 * 3600),
+        },
+        "s3": {
+            "bucket": STRING,
+            "prefix": STRING,
+            "region": STRING,
+            "endpoint_url": URL,
+            "profile": STRING,
+            "access_key_env": STRING,
+            "secret_key_env": STRING,
+            "signed_urls": BOOL,
+            "signed_url_expiration": Field((int,), minimum=1, maximum=7 * 24 * 3600),
+        },
+    },
+    "secrets": {
+        "vault": {
+            "address": URL,
+            "token_env": STRING,
+            "kv_version": Field((int,), choices=(1, 2)),
+            "namespace": STRING,
+            "timeout": POSITIVE,
+        },
+        "encryption": {
+            "provider": Field((str,), choices=("keyring", "kms")),
+            "kms_key": STRING,
+            "fields": STRINGS,
+        },
+    },
+    "plugins": {
+        "dir": STRING,
+        "fuel": POSITIVE_INT,
+        "max_memory_mb": POSITIVE_INT,
+        "config": ANY,
+    },
+    "agents": MapOf(ANY),
+    "cli": {
+        "default_command": STRING,
+        "confirm_critical_actions": BOOL,
+        "progress_bar": BOOL,
+        "lang": Field((str,), choices=("en", "ja")),
+    },
+    "server": {
+        "host": STRING,
+        "port": Field((int,), minimum=0, maximum=65535),
+        "max_concurrent_audits": POSITIVE_INT,
+        "max_queued_audits": POSITIVE_INT,
+        "users": MapOf(ROLE),
+        "oidc": {
+            "issuer": URL,
+            "audience": STRING,
+            "jwks_uri": URL,
+            "user_claim": STRING,
+            "groups_claim": STRING,
+            "group_roles": MapOf(ROLE),
+            "default_role": ROLE,
+        },
+    },
+    "integrations": {
+        "github_app": {
+            "enabled": BOOL,
+            "app_id": Field((int, str)),
+            "installation_id": POSITIVE_INT,
+            "private_key": STRING,
+            "private_key_path": STRING,
+            "private_key_env": STRING,
+            "repository": STRING,
+            "api_url": URL,
+            "check_name": STRING,
+            "fail_on_severity": SEVERITY,
+            "annotation_path": STRING,
+        },
+    },
+    "workers": {
+        "broker": URL,
+        "prefix": STRING,
+    },
+    "self_update": {
+        "channel": Field((str,), choices=("stable", "beta")),
+        "public_key": STRING,
+        "releases_url": URL,
+    },
+    "security": {
+        "encrypt_reports": BOOL,
+        "redact_sensitive_data": BOOL,
+        "allowed_regions": STRINGS,
+        "data_residency_check": BOOL,
+        "age_recipients": STRINGS,
+        "age_identity_file": STRING,
+    },
+}
+
+_WEBHOOK = {
+    "url": URL,
+    "secret": STRING,
+    "secret_env": STRING,
+    "retries": NON_NEGATIVE_INT,
+    "timeout": POSITIVE,
+    "owners": STRINGS,
+}
+_OVERRIDE_RULE = {"category": STRING, "resource": STRING, "severity": SEVERITY}
+_SUPPRESSION_RULE = {
+    "fingerprint": STRING,
+    "category": STRING,
+    "resource": STRING,
+    "owner": STRING,
+    "justification": STRING,
+    "expires": Field((date, str)),
+}
+
+
+@dataclass
+class Diagnostic:
+    """One problem found in the configuration."""
+
+    key: str
+    message: str
+    help: Optional[str] = None
+    line: Optional[int] = None
+
+    def __str__(self) -> str:
+        text = f"{self.key}: {self.message}"
+        return f"{text} ({self.help})" if self.help else text
+
+
+def _suggest(name: str, known: Sequence[str]) -> Optional[str]:
+    """Suggest the closest known key for a misspelled one."""
+    matches = difflib.get_close_matches(name, list(known), n=1, cutoff=0.6)
+    return f"did you mean '{matches[0]}'?" if matches else None
+
+
+def _check_field(key: str, value: Any, field: Field) -> List[Diagnostic]:
+    """Check one leaf value."""
+    types = field.types + ((float,) if int in field.types and field.minimum is not None else ())
+    matches_type = isinstance(value, types) and not (
+        isinstance(value, bool) and bool not in field.types
+    )
+    if not matches_type:
+        return [Diagnostic(key, f"expected {field.describe()}, got {value!r}")]
+
+    if field.choices is not None:
+        candidate = value.upper() if field.choices is SEVERITY_ORDER else value
+        if candidate not in field.choices:
+            choices = ", ".join(str(choice) for choice in field.choices)
+            return [Diagnostic(key, f"invalid value {value!r}", help=f"expected one of: {choices}")]
+    if isinstance(value, (int, float)) and not isinstance(value, bool):
+        if field.minimum is not None:
+            too_small = value <= field.minimum if field.exclusive_minimum else value < field.minimum
+            if too_small:
+                bound = "greater than" if field.exclusive_minimum else "at least"
+                return [Diagnostic(key, f"must be {bound} {field.minimum:g}, got {value}")]
+        if field.maximum is not None and value > field.maximum:
+            return [Diagnostic(key, f"must be at most {field.maximum:g}, got {value}")]
+    return []
+
+
+def _check(key: str, value: Any, spec: Any) -> List[Diagnostic]:
+    """Check a value against a spec, recursively."""
+    if spec is ANY:
+        return []
+    if isinstance(spec, Field):
+        return _check_field(key, value, spec)
+    if isinstance(spec, MapOf):
+        if not isinstance(value, dict):
+            return [Diagnostic(key, f"expected a table, got {value!r}")]
+        return [
+            diagnostic
+            for name, item in value.items()
+            for diagnostic in _check(f"{key}.{name}", item, _entry_spec(key, name, item, spec))
+        ]
+
+    if not isinstance(value, dict):
+        return [Diagnostic(key, f"expected a table, got {value!r}")]
+    diagnostics = []
+    for name, item in value.items():
+        child = f"{key}.{name}" if key else name
+        if name not in spec:
+            diagnostics.append(Diagnostic(child, "unknown key", help=_suggest(name, spec)))
+            continue
+        diagnostics.extend(_check(child, item, spec[name]))
+    return diagnostics
+
+
+def _entry_spec(key: str, name: str, item: Any, spec: MapOf) -> Any:
+    """Return the spec for one entry of a user-keyed table (handles special entries)."""
+    if key == "notifications.webhooks" and isinstance(item, dict):
+        return _WEBHOOK
+    if key == "severity_overrides" and name == "rules":
+        return Field((list,))
+    return spec.value
+
+
+def check_schema(data: Dict[str, Any]) -> List[Diagnostic]:
+    """Return unknown keys, type errors, and out-of-range values in parsed config data."""
+    diagnostics = _check("", data, SCHEMA)
+    for i, rule in enumerate(data.get("severity_overrides", {}).get("rules", []) or []):
+        diagnostics.extend(_check(f"severity_overrides.rules[{i}]", rule, _OVERRIDE_RULE))
+    for i, rule in enumerate(data.get("suppressions", {}).get("rules", []) or []):
+        diagnostics.extend(_check(f"suppressions.rules[{i}]", rule, _SUPPRESSION_RULE))
+    return diagnostics
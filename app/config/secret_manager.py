@@ -0,0 +1,83 @@
+"""Resolve ``secretmanager:`` references in paddi.toml against GCP Secret Manager.
+
+A value such as ``api_key = "secretmanager:projects/my-proj/secrets/datadog-key"``
+is fetched with Application Default Credentials when the setting is used, so
+audit servers never keep the secret on disk. The latest version is read unless
+the reference ends in ``/versions/<n>``.
+"""
+
+import logging
+import re
+from typing import Any, Dict, Optional
+
+from app.common.exceptions import ConfigurationError
+
+logger = logging.getLogger(__name__)
+
+SECRET_MANAGER_PREFIX = "secretmanager:"
+_SECRET_NAME = re.compile(r"^projects/[^/]+/secrets/[^/]+(/versions/[^/]+)?$")
+
+
+def is_secret_manager_reference(value: Any) -> bool:
+    """Return whether a config value is a ``secretmanager:`` reference."""
+    return isinstance(value, str) and value.startswith(SECRET_MANAGER_PREFIX)
+
+
+def version_name(reference: str) -> str:
+    """Return the secret version resource name for a reference.
+
+    Raises:
+        ConfigurationError: If the reference is not ``projects/<p>/secrets/<s>[/versions/<v>]``
+    """
+    name = reference[len(SECRET_MANAGER_PREFIX) :].strip("/")
+    if not _SECRET_NAME.match(name):
+        raise ConfigurationError(
+            reference,
+            {"error": "expected secretmanager:projects/<project>/secrets/<name>[/versions/<v>]"},
+        )
+    return name if "/versions/" in name else f"{name}/versions/latest"
+
+
+class SecretManagerResolver:
+    """Read secret versions from GCP Secret Manager, once per process."""
+
+    def __init__(self, client: Optional[Any] = None):
+        """Initialize the resolver.
+
+        Args:
+            client: SecretManagerServiceClient (created on first use if omitted)
+        """
+        self._client = client
+        self._cache: Dict[str, str] = {}
+
+    @property
+    def client(self):
+        """Lazily create the Secret Manager client."""
+        if self._client is None:
+            try:
+                from google.cloud import secretmanager
+            except ImportError as e:
+                raise ConfigurationError(
+                    "secretmanager",
+                    {"error": "google-cloud-secret-manager is not installed"},
+                ) from e
+            self._client = secretmanager.SecretManagerServiceClient()
+        return self._client
+
+    def resolve(self, reference: str) -> str:
+        """Return the secret value a reference points to.
+
+        Raises:
+            ConfigurationError: If the secret cannot be read
+        """
+        name = version_name(reference)
+        if name not in self._cache:
+            try:
+                response = self.client.access_secret_version(request={"name": name})
+            except ConfigurationError:
+                raise
+            except Exception as e:  # pylint: disable=broad-exception-caught
+                raise ConfigurationError(reference, {"error": str(e)}) from e
+            self._cache[name] = response.payload.data.decode("utf-8")
+            logger.debug("Read %s from Secret Manager", name)
+        return self._cache[name]
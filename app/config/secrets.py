@@ -0,0 +1,86 @@
+"""API keys and tokens kept in the OS keyring instead of env vars or paddi.toml.
+
+Secrets are stored under the ``paddi`` service with ``paddi config set-secret <name>``.
+Lookups check the environment variable first, so CI and containers keep working
+without a keyring; the keyring entry is named after the variable in lower case
+(``GITHUB_TOKEN`` -> ``github_token``) unless a name is given.
+"""
+
+import logging
+import os
+import re
+from typing import Optional
+
+from app.common.exceptions import ConfigurationError
+
+logger = logging.getLogger(__name__)
+
+KEYRING_SERVICE = "paddi"
+_SECRET_NAME = re.compile(r"^[a-z0-9_.-]+$")
+
+
+def _keyring():
+    """Import the optional keyring package."""
+    try:
+        import keyring
+    except ImportError as e:
+        raise ConfigurationError(
+            "keyring", {"error": "keyring is not installed (pip install keyring)"}
+        ) from e
+    return keyring
+
+
+def check_secret_name(name: str) -> str:
+    """Validate a secret name."""
+    if not name or not _SECRET_NAME.match(name):
+        raise ConfigurationError(
+            name or "secret", {"error": "use lower-case letters, digits, '_', '.', or '-'"}
+        )
+    return name
+
+
+def set_secret(name: str, value: str) -> None:
+    """Store a secret in the OS keyring."""
+    check_secret_name(name)
+    if not value:
+        raise ConfigurationError(name, {"error": "empty secret"})
+    _keyring().set_password(KEYRING_SERVICE, name, value)
+
+
+def delete_secret(name: str) -> None:
+    """Remove a secret from the OS keyring."""
+    keyring = _keyring()
+    try:
+        keyring.delete_password(KEYRING_SERVICE, check_secret_name(name))
+    except keyring.errors.PasswordDeleteError as e:
+        raise ConfigurationError(name, {"error": "no such secret in the keyring"}) from e
+
+
+def get_secret(name: str) -> Optional[str]:
+    """Read a secret from the OS keyring, or None if it (or a keyring) is not available."""
+    try:
+        return _keyring().get_password(KEYRING_SERVICE, name)
+    except ConfigurationError:
+        return None
+    except Exception as e:  # pylint: disable=broad-exception-caught
+        # No usable backend (e.g. headless Linux without Secret Service)
+        logger.debug("Keyring lookup for %s failed: %s", name, e)
+        return None
+
+
+def resolve_secret(env_var: str, name: Optional[str] = None) -> Optional[str]:
+    """Return ``env_var`` from the environment, falling back to the keyring.
+
+    Args:
+        env_var: Environment variable to check first
+        name: Keyring entry to check next (defaults to ``env_var`` in lower case)
+    """
+    return os.getenv(env_var) or get_secret(name or env_var.lower())
+
+
+def missing_secret_message(env_var: str) -> str:
+    """Explain how to provide a secret that was not found."""
+    return (
+        f"environment variable {env_var} is not set "
+        f"(or run 'paddi config set-secret {env_var.lower()}')"
+    )
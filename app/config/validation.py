@@ -0,0 +1,153 @@
+"""Validation of ``paddi.toml`` beyond TOML syntax.
+
+The file is first checked against :data:`~app.config.schema.SCHEMA` (unknown
+keys, types, and ranges). Each configured section is then checked by the
+loader that reads it at run time, so ``paddi config validate`` reports the
+same errors a command would hit. Sections that are absent are skipped, and
+loaders that contact external services (such as GitHub) are not called.
+``vault:`` references in checked sections are resolved, so a secret that
+cannot be read is reported too.
+"""
+
+import re
+from typing import Any, Callable, Dict, List, Optional
+
+from app.common.exceptions import ConfigurationError, PaddiException
+from app.config.paddi_config import PaddiConfig
+from app.config.schema import Diagnostic, check_schema
+from app.config.vault import VaultClient
+
+_HEADER = re.compile(r"^\s*\[\[?\s*([^\[\]]+?)\s*\]\]?\s*(#.*)?$")
+_KEY = re.compile(r"^\s*([A-Za-z0-9_-]+|\"[^\"]*\"|'[^']*')\s*=")
+_INDEX = re.compile(r"\[\d+\]")
+
+
+def _loaders(project_id: Optional[str]) -> Dict[str, Callable[[PaddiConfig], Any]]:
+    """Return the run-time loaders by the section they read."""
+    from app.export.scc import SCCExporter
+    from app.common.network import ProxySettings
+    from app.common.rate_limit import RateLimiter
+    from app.explainer.circuit_breaker import CircuitBreaker
+    from app.explainer.redaction import Redactor
+    from app.export.splunk_hec import SplunkHECExporter
+    from app.findings.cvss import CvssScorer
+    from app.findings.ownership import OwnershipRules
+    from app.findings.risk import RiskModel
+    from app.findings.severity_overrides import SeverityOverrides
+    from app.findings.suppressions import Suppressions
+    from app.findings.tags import TagRules
+    from app.history.retention import RetentionPolicy
+    from app.notifications.datadog import DatadogNotifier
+    from app.notifications.pubsub import PubSubPublisher
+    from app.notifications.webhooks import WebhookNotifier
+    from app.storage.gcs import GCSStorage
+    from app.storage.s3 import S3Storage
+
+    return {
+        "secrets.vault": lambda config: VaultClient.from_config(config.section("secrets.vault")),
+        "severity_overrides": SeverityOverrides.from_config,
+        "suppressions": Suppressions.from_config,
+        "cvss.vectors": CvssScorer.from_config,
+        "tags": TagRules.from_config,
+        "owners": OwnershipRules.from_config,
+        "risk": RiskModel.from_config,
+        "retention": RetentionPolicy.from_config,
+        "redaction": Redactor.from_config,
+        "limits": RateLimiter.from_config,
+        "network.proxy": ProxySettings.from_config,
+        "llm.circuit_breaker": CircuitBreaker.from_config,
+        "notifications.webhooks": WebhookNotifier.from_config,
+        "notifications.datadog": DatadogNotifier.from_config,
+        "notifications.pubsub": lambda config: PubSubPublisher.from_config(config, project_id),
+        "storage.gcs": GCSStorage.from_config,
+        "storage.s3": S3Storage.from_config,
+        "export.splunk_hec": SplunkHECExporter.from_config,
+        "export.scc": SCCExporter.from_config,
+    }
+
+
+def find_line(text: str, key: str) -> Optional[int]:
+    """Return the 1-based line where a dotted key (or its closest parent) is defined."""
+    parts = _INDEX.sub("", key).split(".")
+    table: List[str] = []
+    best, best_depth = None, 0
+    for number, line in enumerate(text.splitlines(), start=1):
+        header = _HEADER.match(line)
+        if header:
+            table = [part.strip().strip("\"'") for part in header.group(1).split(".")]
+            defined = table
+        else:
+            match = _KEY.match(line)
+            if not match:
+                continue
+            defined = table + [match.group(1).strip("\"'")]
+        if defined == parts[: len(defined)] and len(defined) > best_depth:
+            best, best_depth = number, len(defined)
+            if best_depth == len(parts):
+                break
+    return best
+
+
+def diagnose_config(config: PaddiConfig, project_id: Optional[str] = None) -> List[Diagnostic]:
+    """Return schema and loader errors, with the line of each key when the file is known.
+
+    A loader is skipped when the schema already reported an error in its
+    section, so one mistake is not reported twice.
+
+    Args:
+        config: Loaded configuration
+        project_id: Audited project, for options that default to it
+    """
+    diagnostics = check_schema(config.data)
+    for section, loader in _loaders(project_id).items():
+        if config.get(section, resolve=False) is None:
+            continue
+        if any(
+            d.key == section or d.key.startswith(f"{section}.") or section.startswith(f"{d.key}.")
+            for d in diagnostics
+        ):
+            continue
+        try:
+            loader(config)
+        except ConfigurationError as e:
+            diagnostics.append(Diagnostic(e.config_item, e.details.get("error", e.message)))
+        except (PaddiException, ValueError, TypeError) as e:
+            diagnostics.append(Diagnostic(section, str(e)))
+
+    if config.path and config.path.exists():
+        text = config.path.read_text(encoding="utf-8")
+        for diagnostic in diagnostics:
+            diagnostic.line = find_line(text, diagnostic.key)
+    return diagnostics
+
+
+def validate_config(config: PaddiConfig, project_id: Optional[str] = None) -> List[str]:
+    """Return the configuration errors as ``key: message`` strings.
+
+    Args:
+        config: Loaded configuration
+        project_id: Audited project, for options that default to it
+    """
+    return [str(diagnostic) for diagnostic in diagnose_config(config, project_id)]
+
+
+def format_diagnostic(diagnostic: Diagnostic, path: Optional[str], text: str = "") -> List[str]:
+    """Render a diagnostic with its location and source line, compiler style."""
+    lines = [f"error: {diagnostic.key}: {diagnostic.message}"]
+    source = text.splitlines()
+    if path and diagnostic.line and diagnostic.line <= len(source):
+        width = len(str(diagnostic.line))
+        pad = " " * width
+        lines.extend(
+            [
+                f"{pad}--> {path}:{diagnostic.line}",
+                f"{pad} |",
+                f"{diagnostic.line} | {source[diagnostic.line - 1]}",
+                f"{pad} |",
+            ]
+        )
+        if diagnostic.help:
+            lines.append(f"{pad} = help: {diagnostic.help}")
+    elif diagnostic.help:
+        lines.append(f"  = help: {diagnostic.help}")
+    return lines
@@ -0,0 +1,149 @@
+"""Resolve ``vault:<path>#<key>`` references in paddi.toml against HashiCorp Vault.
+
+A value such as ``api_key = "vault:secret/paddi#gemini"`` is read from the KV
+secrets engine when the setting is used, so the secret never has to be written
+to disk. The server is configured in ``[secrets.vault]``::
+
+    [secrets.vault]
+    address = "https://vault.example.com:8200"  # or VAULT_ADDR
+    token_env = "VAULT_TOKEN"
+    kv_version = 2
+    namespace = "admin"  # Vault Enterprise only
+"""
+
+import logging
+import os
+from typing import Any, Dict, Optional, Tuple
+
+import requests
+
+from app.common.exceptions import ConfigurationError
+from app.config.secrets import missing_secret_message, resolve_secret
+
+logger = logging.getLogger(__name__)
+
+VAULT_PREFIX = "vault:"
+
+
+def is_vault_reference(value: Any) -> bool:
+    """Return whether a config value is a ``vault:`` reference."""
+    return isinstance(value, str) and value.startswith(VAULT_PREFIX)
+
+
+def parse_reference(reference: str) -> Tuple[str, str]:
+    """Split ``vault:<path>#<key>`` into path and key.
+
+    Raises:
+        ConfigurationError: If the reference has no path or key
+    """
+    path, _, key = reference[len(VAULT_PREFIX) :].partition("#")
+    path = path.strip("/")
+    if not path or not key:
+        raise ConfigurationError(reference, {"error": "expected vault:<path>#<key>"})
+    return path, key
+
+
+class VaultClient:
+    """Minimal reader for the Vault KV secrets engine over the HTTP API."""
+
+    def __init__(
+        self,
+        address: str,
+        token: str,
+        kv_version: int = 2,
+        namespace: Optional[str] = None,
+        timeout: float = 10.0,
+    ):
+        """Initialize the Vault client.
+
+        Args:
+            address: Vault server URL
+            token: Vault token
+            kv_version: KV secrets engine version (1 or 2)
+            namespace: Vault Enterprise namespace
+            timeout: Seconds per request
+        """
+        self.address = address.rstrip("/")
+        self.token = token
+        self.kv_version = kv_version
+        self.namespace = namespace
+        self.timeout = timeout
+        self._cache: Dict[str, Dict[str, Any]] = {}
+
+    @classmethod
+    def from_config(cls, section: Dict[str, Any]) -> "VaultClient":
+        """Create a client from the ``[secrets.vault]`` section.
+
+        Raises:
+            ConfigurationError: If the address or token is missing or invalid
+        """
+        address = section.get("address") or os.getenv("VAULT_ADDR")
+        if not address or not str(address).startswith(("https://", "http://")):
+            raise ConfigurationError(
+                "secrets.vault.address", {"error": "set address or VAULT_ADDR to an http(s) URL"}
+            )
+
+        token_env = section.get("token_env", "VAULT_TOKEN")
+        token = resolve_secret(token_env)
+        if not token:
+            raise ConfigurationError("secrets.vault", {"error": missing_secret_message(token_env)})
+
+        kv_version = section.get("kv_version", 2)
+        if kv_version not in (1, 2):
+            raise ConfigurationError("secrets.vault.kv_version", {"error": "must be 1 or 2"})
+
+        return cls(
+            address=str(address),
+            token=token,
+            kv_version=kv_version,
+            namespace=section.get("namespace"),
+            timeout=float(section.get("timeout", 10.0)),
+        )
+
+    def _api_path(self, path: str) -> str:
+        """Map a secret path to its HTTP API path (KV v2 inserts ``data/`` after the mount)."""
+        if self.kv_version == 1:
+            return path
+        mount, _, rest = path.partition("/")
+        return f"{mount}/data/{rest}"
+
+    def read(self, path: str) -> Dict[str, Any]:
+        """Read all keys of a secret, caching the result for this process.
+
+        Raises:
+            ConfigurationError: If the secret cannot be read
+        """
+        if path in self._cache:
+            return self._cache[path]
+
+        headers = {"X-Vault-Token": self.token}
+        if self.namespace:
+            headers["X-Vault-Namespace"] = self.namespace
+        url = f"{self.address}/v1/{self._api_path(path)}"
+        try:
+            response = requests.get(url, headers=headers, timeout=self.timeout)
+        except requests.RequestException as e:
+            raise ConfigurationError(f"vault:{path}", {"error": str(e)}) from e
+        if response.status_code != 200:
+            raise ConfigurationError(
+                f"vault:{path}", {"error": f"Vault returned HTTP {response.status_code}"}
+            )
+
+        data = response.json().get("data") or {}
+        if self.kv_version == 2:
+            data = data.get("data") or {}
+        logger.debug("Read %d key(s) from vault:%s", len(data), path)
+        self._cache[path] = data
+        return data
+
+    def resolve(self, reference: str) -> Any:
+        """Return the value a ``vault:<path>#<key>`` reference points to.
+
+        Raises:
+            ConfigurationError: If the secret or key does not exist
+        """
+        path, key = parse_reference(reference)
+        data = self.read(path)
+        if key not in data:
+            raise ConfigurationError(reference, {"error": f"key {key!r} not found in {path}"})
+        return data[key]
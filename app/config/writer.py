@@ -0,0 +1,133 @@
+"""Update single values in ``paddi.toml`` while keeping the rest of the file as written."""
+
+import datetime
+import json
+import re
+from pathlib import Path
+from typing import Any
+
+from app.common.exceptions import ConfigurationError
+from app.config.paddi_config import PaddiConfig, tomllib
+
+_TABLE_HEADER = re.compile(r"^\s*\[([^\[\]]+)\]\s*(#.*)?$")
+_ARRAY_HEADER = re.compile(r"^\s*\[\[")
+
+
+def toml_value(value: Any) -> str:
+    """Format a string, number, boolean, or list as a TOML value."""
+    if isinstance(value, bool):
+        return "true" if value else "false"
+    if isinstance(value, (int, float)):
+        return repr(value)
+    if isinstance(value, (list, tuple)):
+        return "[" + ", ".join(toml_value(item) for item in value) + "]"
+    return json.dumps(str(value), ensure_ascii=False)
+
+
+def parse_value(raw: Any, current: Any = None) -> Any:
+    """Convert a command-line value to the TOML type of the current value.
+
+    Without a current value, ``true``/``false``, numbers, and ``[...]`` arrays
+    are recognized; anything else is a string.
+
+    Raises:
+        ConfigurationError: If the value does not fit the current type
+    """
+    if isinstance(raw, str):
+        try:
+            value = tomllib.loads(f"v = {raw}")["v"]
+        except tomllib.TOMLDecodeError:
+            value = raw
+    else:
+        value = list(raw) if isinstance(raw, tuple) else raw
+    if isinstance(value, (dict, datetime.date, datetime.time)):
+        value = str(raw)
+
+    if current is None or isinstance(current, dict):
+        return value
+    if isinstance(current, str):
+        return raw if isinstance(raw, str) else toml_value(value).strip('"')
+    if isinstance(current, float) and type(value) is int:
+        return float(value)
+    if type(value) is not type(current):
+        expected = {bool: "true or false", int: "an integer", float: "a number", list: "an array"}
+        raise ConfigurationError(
+            str(raw), {"error": f"expected {expected.get(type(current), type(current).__name__)}"}
+        )
+    return value
+
+
+def update_toml_text(text: str, key: str, value: Any) -> str:
+    """Return TOML text with ``section.name = value`` set.
+
+    Comments and the order of other keys are preserved. Only plain ``key = value``
+    lines directly under a ``[section]`` header are replaced; a missing table is
+    appended.
+
+    Raises:
+        ConfigurationError: If the key has no table part
+    """
+    section, _, name = key.rpartition(".")
+    if not section or not name:
+        raise ConfigurationError(key, {"error": "expected <section>.<key>"})
+
+    lines = text.splitlines()
+    entry = f"{name} = {toml_value(value)}"
+    key_line = re.compile(rf"^\s*{re.escape(name)}\s*=")
+
+    start = None
+    end = len(lines)
+    for i, line in enumerate(lines):
+        header = _TABLE_HEADER.match(line)
+        if start is None:
+            if header and header.group(1).strip() == section:
+                start = i + 1
+        elif header or _ARRAY_HEADER.match(line):
+            end = i
+            break
+
+    if start is None:
+        if lines and lines[-1].strip():
+            lines.append("")
+        lines.extend([f"[{section}]", entry])
+    else:
+        for i in range(start, end):
+            if key_line.match(lines[i]):
+                lines[i] = entry
+                break
+        else:
+            insert_at = end
+            while insert_at > start and not lines[insert_at - 1].strip():
+                insert_at -= 1
+            lines.insert(insert_at, entry)
+
+    return "\n".join(lines) + "\n"
+
+
+def set_config_value(path: Path, key: str, value: Any) -> None:
+    """Set ``section.name = value`` in a TOML file, creating the file or table if needed.
+
+    The result is parsed before it is written, so keys kept in inline tables or
+    dotted keys (which this writer cannot edit) fail instead of corrupting the file.
+
+    Args:
+        path: Configuration file to update
+        key: Dotted key; everything before the last dot is the table name
+        value: New value
+
+    Raises:
+        ConfigurationError: If the key has no table part or cannot be updated
+    """
+    text = path.read_text(encoding="utf-8") if path.exists() else ""
+    updated = update_toml_text(text, key, value)
+
+    expected = list(value) if isinstance(value, tuple) else value
+    try:
+        ok = PaddiConfig(tomllib.loads(updated)).get(key, resolve=False) == expected
+    except tomllib.TOMLDecodeError:
+        ok = False
+    if not ok:
+        raise ConfigurationError(
+            key, {"error": f"cannot update {key} in {path} automatically; edit it by hand"}
+        )
+    path.write_text(updated, encoding="utf-8")
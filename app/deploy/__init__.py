@@ -0,0 +1 @@
+"""Manifests for running Paddi audits on a schedule in other environments."""
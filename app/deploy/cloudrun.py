@@ -0,0 +1,267 @@
+"""Cloud Run job for scheduled audits.
+
+``paddi deploy cloudrun`` turns paddi.toml and a cron schedule into a Cloud Run
+job (``job.yaml``) and a gcloud script that sets it up:
+
+- a service account the job runs as. Cloud Run gives the job its credentials,
+  so no key is created or stored;
+- read access for it to the audited project or organization, and to Vertex AI
+  and the ``[storage.gcs]`` bucket when the audit uses them;
+- Secret Manager secrets holding paddi.toml, mounted at ``/etc/paddi``, and
+  each environment variable paddi.toml reads a secret from, taken from the
+  environment of whoever runs the script;
+- the job itself, and a Cloud Scheduler job that runs it on the schedule.
+
+The job's filesystem does not outlive an execution, so history and reports
+are kept only where paddi.toml uploads or sends them (``[storage.gcs]``,
+notifications, exports).
+"""
+
+import re
+import shlex
+from dataclasses import dataclass
+from datetime import datetime
+from pathlib import Path
+from typing import Any, Dict, List, Optional, Tuple
+
+from app.deploy.schedule import CONFIG_MOUNT, ScheduledAudit, render_yaml
+
+JOB_FILE = "job.yaml"
+SCRIPT_FILE = "deploy.sh"
+DEFAULT_REGION = "us-central1"
+DEFAULT_TIME_ZONE = "Etc/UTC"
+RUN_API = "https://run.googleapis.com/v2"
+AUDIT_ROLES = (
+    "roles/iam.securityReviewer",
+    "roles/securitycenter.findingsViewer",
+    "roles/logging.viewer",
+)
+_ACCOUNT_ID = re.compile(r"^[a-z]([a-z0-9-]{4,28}[a-z0-9])$")
+
+
+@dataclass
+class CloudRunJobOptions(ScheduledAudit):
+    """A scheduled audit run as a Cloud Run job."""
+
+    region: str = DEFAULT_REGION
+    job_project: Optional[str] = None
+    service_account: Optional[str] = None
+    time_zone: str = DEFAULT_TIME_ZONE
+    timeout_seconds: int = 3600
+
+    @property
+    def host_project(self) -> str:
+        """Project the job runs in: job_project, else the audited project."""
+        return self.job_project or self.project_id or ""
+
+    @property
+    def account_id(self) -> Optional[str]:
+        """ID of the service account to create, or None if an existing one is given."""
+        if self.service_account and "@" in self.service_account:
+            return None
+        return self.service_account or f"{self.name}-auditor"
+
+    @property
+    def service_account_email(self) -> str:
+        """Email of the service account the job runs as."""
+        if self.account_id is None:
+            return str(self.service_account)
+        return f"{self.account_id}@{self.host_project}.iam.gserviceaccount.com"
+
+    @property
+    def image_uri(self) -> str:
+        """Image of the job (default: gcr.io/<project>/paddi)."""
+        return self.image or f"gcr.io/{self.host_project}/paddi"
+
+    def validate(self, macros: Tuple[str, ...] = ()) -> None:
+        """Check the schedule, names, and projects.
+
+        Cloud Scheduler takes only five-field cron schedules, not macros.
+
+        Raises:
+            ValueError: If an option is invalid or no project is given
+        """
+        super().validate(macros)
+        if not self.host_project:
+            raise ValueError("deploy cloudrun requires --project_id or --job_project")
+        if self.account_id is not None and not _ACCOUNT_ID.match(self.account_id):
+            raise ValueError(
+                f"Invalid service account ID: {self.account_id!r} (6-30 lower-case letters, "
+                "digits, and '-', starting with a letter)"
+            )
+        if self.timeout_seconds <= 0:
+            raise ValueError("timeout_seconds must be positive")
+
+
+def secret_name(options: CloudRunJobOptions, env_var: str) -> str:
+    """Secret Manager secret of an environment variable."""
+    return f"{options.name}-{env_var.lower().replace('_', '-')}"
+
+
+def build_job(options: CloudRunJobOptions, env_vars: List[str], with_config: bool) -> Dict:
+    """Build the Cloud Run job of a scheduled audit.
+
+    Args:
+        options: Schedule, image, and audit options
+        env_vars: Environment variables read from Secret Manager secrets
+        with_config: Whether paddi.toml is mounted from its secret
+
+    Raises:
+        ValueError: If the options are invalid
+    """
+    options.validate()
+    env: List[Dict[str, Any]] = [{"name": "PADDI_QUIET", "value": "1"}]
+    container: Dict[str, Any] = {"image": options.image_uri, "command": options.audit_command()}
+    task: Dict[str, Any] = {
+        "serviceAccountName": options.service_account_email,
+        "maxRetries": 0,
+        "timeoutSeconds": options.timeout_seconds,
+        "containers": [container],
+    }
+    if with_config:
+        env.insert(0, {"name": "PADDI_CONFIG", "value": f"{CONFIG_MOUNT}/paddi.toml"})
+        container["volumeMounts"] = [{"name": "config", "mountPath": CONFIG_MOUNT}]
+        task["volumes"] = [
+            {
+                "name": "config",
+                "secret": {
+                    "secretName": f"{options.name}-config",
+                    "items": [{"key": "latest", "path": "paddi.toml"}],
+                },
+            }
+        ]
+    for name in env_vars:
+        secret = {"name": secret_name(options, name), "key": "latest"}
+        env.append({"name": name, "valueFrom": {"secretKeyRef": secret}})
+    container["env"] = env
+    return {
+        "apiVersion": "run.googleapis.com/v1",
+        "kind": "Job",
+        "metadata": {"name": options.name},
+        "spec": {"template": {"spec": {"taskCount": 1, "template": {"spec": task}}}},
+    }
+
+
+def _grant(scope: str, target: str, role: str) -> str:
+    return (
+        f"gcloud {scope} add-iam-policy-binding {shlex.quote(target)} "
+        f'--member "serviceAccount:$SERVICE_ACCOUNT" --role {role} --condition None'
+    )
+
+
+def _secret(name: str, data_file: str, stdin: str = "") -> List[str]:
+    return [
+        f'{stdin}gcloud secrets create {name} --project "$PROJECT" --data-file {data_file}',
+        f'gcloud secrets add-iam-policy-binding {name} --project "$PROJECT" '
+        '--member "serviceAccount:$SERVICE_ACCOUNT" --role roles/secretmanager.secretAccessor',
+    ]
+
+
+def build_setup_script(
+    options: CloudRunJobOptions,
+    env_vars: List[str],
+    config_path: Optional[Path],
+    job_yaml: Optional[str] = None,
+    gcs_bucket: Optional[str] = None,
+) -> str:
+    """Build the gcloud script that sets up the job and its schedule.
+
+    Args:
+        options: Schedule, image, and audit options
+        env_vars: Environment variables to store as secrets
+        config_path: paddi.toml to store as a secret, if any
+        job_yaml: The job to embed in the script; without it, the script
+            deploys ``job.yaml`` from its own directory
+        gcs_bucket: ``[storage.gcs]`` bucket the job uploads artifacts to
+
+    Raises:
+        ValueError: If the options are invalid
+    """
+    options.validate()
+    name = options.name
+    services = ["run", "cloudscheduler", "secretmanager"]
+    uses_vertex = not options.use_mock and options.ai_provider != "ollama"
+    if uses_vertex:
+        services.append("aiplatform")
+
+    lines = [
+        "#!/usr/bin/env bash",
+        f"# Paddi scheduled audit: Cloud Run job {name}",
+        f"# Generated: {datetime.now().isoformat(timespec='seconds')}",
+        "#",
+        "# Run once, after building the image, e.g.:",
+        f"#   gcloud builds submit --tag {options.image_uri} .",
+        "# To change the job later, edit it and run the `gcloud run jobs replace` step again.",
+        "set -euo pipefail",
+        "",
+        f"PROJECT={shlex.quote(options.host_project)}",
+        f"REGION={shlex.quote(options.region)}",
+        f"SERVICE_ACCOUNT={shlex.quote(options.service_account_email)}",
+        "",
+        " ".join(["gcloud services enable"] + [f"{s}.googleapis.com" for s in services])
+        + ' --project "$PROJECT"',
+    ]
+    if options.account_id:
+        lines += [
+            "",
+            "# Service account the job runs as; Cloud Run provides its credentials",
+            f'gcloud iam service-accounts create {options.account_id} --project "$PROJECT" '
+            '--display-name "Paddi audits"',
+        ]
+
+    lines += ["", "# Read access for the audit"]
+    if options.organization_id:
+        scope, target = "organizations", options.organization_id
+    else:
+        scope, target = "projects", options.project_id or options.host_project
+    lines += [_grant(scope, target, role) for role in AUDIT_ROLES]
+    if uses_vertex:
+        lines.append(_grant("projects", options.host_project, "roles/aiplatform.user"))
+    if gcs_bucket:
+        lines.append(
+            f"gcloud storage buckets add-iam-policy-binding gs://{shlex.quote(gcs_bucket)} "
+            '--member "serviceAccount:$SERVICE_ACCOUNT" --role roles/storage.objectCreator'
+        )
+
+    if config_path or env_vars:
+        lines += ["", "# paddi.toml and the secrets it reads, in Secret Manager"]
+    if config_path:
+        lines += _secret(f"{name}-config", shlex.quote(str(config_path)))
+    for env_var in env_vars:
+        lines += _secret(secret_name(options, env_var), "-", f'printf %s "${env_var}" | ')
+
+    lines += ["", "# The job"]
+    if job_yaml is None:
+        lines.append(f'JOB_FILE="$(dirname "$0")/{JOB_FILE}"')
+    else:
+        lines += ['JOB_FILE="$(mktemp)"', "cat > \"$JOB_FILE\" <<'YAML'"]
+        lines += [job_yaml.rstrip("\n"), "YAML"]
+    lines += [
+        'gcloud run jobs replace "$JOB_FILE" --project "$PROJECT" --region "$REGION"',
+        "",
+        "# The schedule",
+        f'gcloud run jobs add-iam-policy-binding {name} --project "$PROJECT" --region "$REGION" '
+        '--member "serviceAccount:$SERVICE_ACCOUNT" --role roles/run.invoker',
+        f'gcloud scheduler jobs create http {name}-schedule --project "$PROJECT" '
+        f'--location "$REGION" --schedule {shlex.quote(options.schedule)} '
+        f"--time-zone {shlex.quote(options.time_zone)} --http-method POST "
+        f'--uri "{RUN_API}/projects/$PROJECT/locations/$REGION/jobs/{name}:run" '
+        '--oauth-service-account-email "$SERVICE_ACCOUNT"',
+    ]
+    return "\n".join(lines) + "\n"
+
+
+def render_job(job: Dict[str, Any]) -> str:
+    """Serialize the job as YAML."""
+    return render_yaml([job])
+
+
+def write_deployment(job: Dict[str, Any], script: str, output_dir: Path) -> List[Path]:
+    """Write job.yaml and the script that deploys it."""
+    output_dir.mkdir(parents=True, exist_ok=True)
+    job_path = output_dir / JOB_FILE
+    job_path.write_text(render_job(job), encoding="utf-8")
+    script_path = output_dir / SCRIPT_FILE
+    script_path.write_text(script, encoding="utf-8")
+    script_path.chmod(0o755)
+    return [job_path, script_path]
@@ -0,0 +1,192 @@
+"""Kubernetes manifests for scheduled audits.
+
+``paddi deploy k8s`` turns paddi.toml and a cron schedule into:
+
+- a Secret holding paddi.toml, mounted at ``/etc/paddi`` and read through
+  ``PADDI_CONFIG``, since the file may hold secrets that are not ``enc:``
+  values;
+- Secret templates: one with the environment variables paddi.toml reads
+  secrets from (``token_env``, ``api_key_env``, ...), and one with a Google
+  service account key unless the pod runs as a Workload Identity service
+  account. Their values are ``REPLACE_ME`` and must be filled in before
+  applying, or the Secrets created another way;
+- a CronJob running ``paddi audit`` in the image built from the repository's
+  Dockerfile, one audit at a time.
+
+``data/`` and ``output/`` are an ``emptyDir`` unless a PersistentVolumeClaim is
+given, so history and reports of earlier runs are lost between jobs without one.
+"""
+
+from dataclasses import dataclass
+from pathlib import Path
+from typing import Any, Dict, List, Optional, Tuple
+
+from app.deploy.schedule import (
+    CONFIG_MOUNT,
+    MACROS,
+    WORKDIR,
+    ScheduledAudit,
+    check_name,
+    render_yaml,
+)
+
+DEFAULT_IMAGE = "paddi:latest"
+CREDENTIALS_MOUNT = "/var/secrets/google"
+PLACEHOLDER = "REPLACE_ME"
+# The Secret holding paddi.toml gets its own file, apart from the templates
+CONFIG_FILE = "config.yaml"
+MANIFEST_FILES = {
+    "Secret": "secrets.yaml",
+    "CronJob": "cronjob.yaml",
+}
+
+
+@dataclass
+class CronJobOptions(ScheduledAudit):
+    """A scheduled audit run as a Kubernetes CronJob."""
+
+    namespace: Optional[str] = None
+    service_account: Optional[str] = None
+    volume_claim: Optional[str] = None
+
+    def validate(self, macros: Tuple[str, ...] = MACROS) -> None:
+        """Check the schedule and names.
+
+        Raises:
+            ValueError: If the schedule is not a cron expression or a name is invalid
+        """
+        super().validate(macros)
+        check_name("namespace", self.namespace)
+
+
+def _metadata(options: CronJobOptions, name: str) -> Dict[str, Any]:
+    metadata: Dict[str, Any] = {"name": name, "labels": {"app.kubernetes.io/name": "paddi"}}
+    if options.namespace:
+        metadata["namespace"] = options.namespace
+    return metadata
+
+
+def build_manifests(
+    options: CronJobOptions, config_text: str, env_vars: List[str]
+) -> List[Dict[str, Any]]:
+    """Build the paddi.toml Secret, Secret templates, and CronJob of a scheduled audit.
+
+    Args:
+        options: Schedule, image, and audit options
+        config_text: Contents of paddi.toml
+        env_vars: Environment variables to put in the Secret template
+
+    Raises:
+        ValueError: If the options are invalid
+    """
+    options.validate()
+    config_name = f"{options.name}-config"
+    env_name = f"{options.name}-env"
+    credentials_name = f"{options.name}-gcp-credentials"
+
+    manifests: List[Dict[str, Any]] = [
+        {
+            "apiVersion": "v1",
+            "kind": "Secret",
+            "metadata": _metadata(options, config_name),
+            "type": "Opaque",
+            "stringData": {"paddi.toml": config_text},
+        }
+    ]
+    env = [
+        {"name": "PADDI_CONFIG", "value": f"{CONFIG_MOUNT}/paddi.toml"},
+        {"name": "PADDI_QUIET", "value": "1"},
+    ]
+    volume_mounts = [
+        {"name": "config", "mountPath": CONFIG_MOUNT, "readOnly": True},
+        {"name": "data", "mountPath": f"{WORKDIR}/data", "subPath": "data"},
+        {"name": "data", "mountPath": f"{WORKDIR}/output", "subPath": "output"},
+    ]
+    volumes: List[Dict[str, Any]] = [
+        {"name": "config", "secret": {"secretName": config_name}},
+        {"name": "data", "persistentVolumeClaim": {"claimName": options.volume_claim}}
+        if options.volume_claim
+        else {"name": "data", "emptyDir": {}},
+    ]
+    container: Dict[str, Any] = {"name": "paddi", "image": options.image or DEFAULT_IMAGE}
+
+    if env_vars:
+        manifests.append(
+            {
+                "apiVersion": "v1",
+                "kind": "Secret",
+                "metadata": _metadata(options, env_name),
+                "type": "Opaque",
+                "stringData": {name: PLACEHOLDER for name in env_vars},
+            }
+        )
+        container["envFrom"] = [{"secretRef": {"name": env_name}}]
+    if not options.service_account:
+        manifests.append(
+            {
+                "apiVersion": "v1",
+                "kind": "Secret",
+                "metadata": _metadata(options, credentials_name),
+                "type": "Opaque",
+                "stringData": {"key.json": PLACEHOLDER},
+            }
+        )
+        env.append(
+            {"name": "GOOGLE_APPLICATION_CREDENTIALS", "value": f"{CREDENTIALS_MOUNT}/key.json"}
+        )
+        volume_mounts.append(
+            {"name": "gcp-credentials", "mountPath": CREDENTIALS_MOUNT, "readOnly": True}
+        )
+        volumes.append({"name": "gcp-credentials", "secret": {"secretName": credentials_name}})
+
+    container.update(command=options.audit_command(), env=env, volumeMounts=volume_mounts)
+    pod: Dict[str, Any] = {"restartPolicy": "Never", "containers": [container], "volumes": volumes}
+    if options.service_account:
+        pod["serviceAccountName"] = options.service_account
+    manifests.append(
+        {
+            "apiVersion": "batch/v1",
+            "kind": "CronJob",
+            "metadata": _metadata(options, options.name),
+            "spec": {
+                "schedule": options.schedule,
+                "concurrencyPolicy": "Forbid",
+                "successfulJobsHistoryLimit": 3,
+                "failedJobsHistoryLimit": 3,
+                "jobTemplate": {
+                    "spec": {
+                        "backoffLimit": 0,
+                        "template": {
+                            "metadata": {"labels": {"app.kubernetes.io/name": "paddi"}},
+                            "spec": pod,
+                        },
+                    }
+                },
+            },
+        }
+    )
+    return manifests
+
+
+def render_manifests(manifests: List[Dict[str, Any]]) -> str:
+    """Serialize manifests as one multi-document YAML stream."""
+    return render_yaml(manifests)
+
+
+def _filename(manifest: Dict[str, Any]) -> str:
+    if "paddi.toml" in manifest.get("stringData", {}):
+        return CONFIG_FILE
+    return MANIFEST_FILES[manifest["kind"]]
+
+
+def write_manifests(manifests: List[Dict[str, Any]], output_dir: Path) -> List[Path]:
+    """Write paddi.toml and the manifests of each kind to a file, for ``kubectl apply -f <dir>``."""
+    output_dir.mkdir(parents=True, exist_ok=True)
+    paths = []
+    for filename in (CONFIG_FILE, *MANIFEST_FILES.values()):
+        documents = [m for m in manifests if _filename(m) == filename]
+        if documents:
+            path = output_dir / filename
+            path.write_text(render_manifests(documents), encoding="utf-8")
+            paths.append(path)
+    return paths
@@ -0,0 +1,130 @@
+"""What every scheduled audit shares, wherever it is deployed.
+
+That is its schedule, the ``paddi audit`` command it runs in the image built
+from the repository's Dockerfile, and the secrets its paddi.toml reads from
+the environment.
+"""
+
+import re
+from dataclasses import dataclass
+from typing import Any, Dict, Iterator, List, Optional, Set, Tuple
+
+from app.common.network import DEFAULT_PASSWORD_ENV
+from app.config.encryption import CONFIG_KEY_ENV, is_encrypted
+from app.config.paddi_config import PaddiConfig
+
+DEFAULT_NAME = "paddi"
+CONFIG_MOUNT = "/etc/paddi"
+WORKDIR = "/app"  # WORKDIR of the Dockerfile
+
+# Sections that read a secret from an environment variable, the key naming the
+# variable, the default variable, and the keys that give the secret directly
+_SECRET_ENV_DEFAULTS = (
+    ("notifications.datadog", "api_key_env", "DD_API_KEY", ("api_key",)),
+    ("export.splunk_hec", "token_env", "SPLUNK_HEC_TOKEN", ("token",)),
+    ("export.github_issues", "token_env", "GITHUB_TOKEN", ("token",)),
+    ("secrets.vault", "token_env", "VAULT_TOKEN", ()),
+    ("network.proxy", "password_env", DEFAULT_PASSWORD_ENV, ("password",)),
+)
+MACROS = ("@yearly", "@annually", "@monthly", "@weekly", "@daily", "@midnight", "@hourly")
+_CRON_FIELD = re.compile(r"^[\d*/,\-A-Za-z?]+$")
+_NAME = re.compile(r"^[a-z0-9]([a-z0-9-]{0,50}[a-z0-9])?$")
+
+
+@dataclass
+class ScheduledAudit:
+    """When a scheduled audit runs, in what image, and with which audit options."""
+
+    schedule: str
+    name: str = DEFAULT_NAME
+    image: Optional[str] = None
+    project_id: Optional[str] = None
+    organization_id: Optional[str] = None
+    use_mock: bool = False
+    ai_provider: Optional[str] = None
+    formats: Optional[str] = None
+
+    def validate(self, macros: Tuple[str, ...] = MACROS) -> None:
+        """Check the schedule and the name.
+
+        Args:
+            macros: Schedule macros (like ``@daily``) the scheduler accepts
+
+        Raises:
+            ValueError: If the schedule is not a cron expression or the name is invalid
+        """
+        fields = self.schedule.split()
+        if not (
+            (len(fields) == 1 and fields[0] in macros)
+            or (len(fields) == 5 and all(_CRON_FIELD.match(f) for f in fields))
+        ):
+            expected = "five cron fields, e.g. '0 3 * * *'"
+            if macros:
+                expected += ", or a macro like @daily"
+            raise ValueError(f"Invalid schedule: {self.schedule!r} (expected {expected})")
+        check_name("name", self.name)
+
+    def audit_command(self) -> List[str]:
+        """Command that runs the audit in the image built from the Dockerfile."""
+        args = ["python", "main.py", "audit", f"--use_mock={self.use_mock}"]
+        for flag, value in (
+            ("project_id", self.project_id),
+            ("organization_id", self.organization_id),
+            ("ai_provider", self.ai_provider),
+            ("formats", self.formats),
+        ):
+            if value:
+                args.append(f"--{flag}={value}")
+        return args
+
+
+def check_name(label: str, value: Optional[str]) -> None:
+    """Check a name that becomes part of resource names.
+
+    Raises:
+        ValueError: If it is not at most 52 lower-case letters, digits, and '-'
+    """
+    if value is not None and not _NAME.match(value):
+        raise ValueError(
+            f"Invalid {label}: {value!r} (use at most 52 lower-case letters, digits, and '-')"
+        )
+
+
+def _walk(data: Dict[str, Any]) -> Iterator[Tuple[str, Any]]:
+    """Yield every key and value of nested tables."""
+    for key, value in data.items():
+        yield key, value
+        if isinstance(value, dict):
+            yield from _walk(value)
+
+
+def secret_env_vars(config: PaddiConfig) -> List[str]:
+    """Environment variables paddi.toml reads secrets from, sorted."""
+    names: Set[str] = {
+        value for key, value in _walk(config.data) if key.endswith("_env") and value
+    }
+    for section_name, env_key, default, direct_keys in _SECRET_ENV_DEFAULTS:
+        section = config.section(section_name)
+        if section and env_key not in section and not any(k in section for k in direct_keys):
+            names.add(default)
+    encryption = config.section("secrets.encryption")
+    if encryption.get("provider", "keyring") == "keyring" and any(
+        is_encrypted(value) for _, value in _walk(config.data)
+    ):
+        names.add(CONFIG_KEY_ENV)
+    return sorted(str(name) for name in names)
+
+
+def render_yaml(documents: List[Dict[str, Any]]) -> str:
+    """Serialize documents as one multi-document YAML stream."""
+    import yaml
+
+    class Dumper(yaml.SafeDumper):
+        """Writes multi-line strings, such as paddi.toml, as literal blocks."""
+
+    def represent_str(dumper, value):
+        style = "|" if "\n" in value else None
+        return dumper.represent_scalar("tag:yaml.org,2002:str", value, style=style)
+
+    Dumper.add_representer(str, represent_str)
+    return yaml.dump_all(documents, Dumper=Dumper, sort_keys=False, allow_unicode=True)
@@ -0,0 +1 @@
+"""Distributed audits: workers on several machines audit projects from a shared queue."""
@@ -0,0 +1,120 @@
+"""Shared queue of distributed audits, kept in Redis.
+
+Configured in paddi.toml::
+
+    [workers]
+    broker = "redis://queue.internal:6379/0"
+    prefix = "paddi"
+
+Keys:
+
+    <prefix>:jobs              audit jobs, one project each, taken by any worker
+    <prefix>:results:<batch>   results of one coordinator's batch, kept for a day
+
+A job is taken off the queue when a worker starts it, so a job whose worker
+dies is not retried; the coordinator reports it as failed when it times out.
+A coordinator that times out also takes the jobs of its batch that no worker
+started off the queue, so nobody runs audits whose results nobody reads.
+"""
+
+import json
+import math
+from typing import Any, Dict, Optional
+
+from app.common.exceptions import ConfigurationError
+from app.config.paddi_config import PaddiConfig
+
+DEFAULT_PREFIX = "paddi"
+RESULT_TTL_SECONDS = 24 * 60 * 60
+
+
+class RedisBroker:
+    """Passes audit jobs to workers and their results back through Redis lists."""
+
+    def __init__(self, url: str, client: Any = None, prefix: str = DEFAULT_PREFIX):
+        """Initialize RedisBroker.
+
+        Args:
+            url: ``redis://`` or ``rediss://`` URL of the server
+            client: redis client (created on first use if omitted)
+            prefix: Prefix of the keys, to share a server between queues
+        """
+        self.url = url
+        self._client = client
+        self.prefix = prefix
+
+    @classmethod
+    def from_config(cls, config: Optional[PaddiConfig] = None) -> Optional["RedisBroker"]:
+        """Create a broker from the ``[workers]`` section of paddi.toml.
+
+        Returns:
+            None when no broker is configured
+
+        Raises:
+            ConfigurationError: If the broker is not a Redis URL
+        """
+        section = (config or PaddiConfig.load()).section("workers")
+        url = section.get("broker")
+        if not url:
+            return None
+        if not str(url).startswith(("redis://", "rediss://")):
+            raise ConfigurationError(
+                "workers.broker", {"error": f"expected a redis:// or rediss:// URL, got {url}"}
+            )
+        return cls(str(url), prefix=section.get("prefix", DEFAULT_PREFIX))
+
+    @property
+    def client(self) -> Any:
+        """Return the Redis client."""
+        if self._client is None:
+            try:
+                import redis
+            except ImportError as e:
+                raise ImportError("redis is not installed (pip install redis)") from e
+
+            self._client = redis.Redis.from_url(self.url)
+        return self._client
+
+    @property
+    def jobs_key(self) -> str:
+        """Key of the job queue."""
+        return f"{self.prefix}:jobs"
+
+    def results_key(self, batch_id: str) -> str:
+        """Key of the results of a batch."""
+        return f"{self.prefix}:results:{batch_id}"
+
+    def _pop(self, key: str, timeout: float) -> Optional[Dict[str, Any]]:
+        # BRPOP takes whole seconds, and 0 would block forever
+        item = self.client.brpop([key], timeout=max(1, math.ceil(timeout)))
+        return json.loads(item[1]) if item else None
+
+    def push_job(self, job: Dict[str, Any]) -> None:
+        """Queue a job for any worker."""
+        self.client.lpush(self.jobs_key, json.dumps(job))
+
+    def cancel_jobs(self, batch_id: str) -> int:
+        """Take the jobs of a batch that no worker started off the queue, and count them."""
+        removed = 0
+        for item in self.client.lrange(self.jobs_key, 0, -1):
+            if json.loads(item).get("batch_id") == batch_id:
+                removed += self.client.lrem(self.jobs_key, 0, item)
+        return removed
+
+    def pop_job(self, timeout: float) -> Optional[Dict[str, Any]]:
+        """Take the oldest job, waiting up to timeout seconds for one."""
+        return self._pop(self.jobs_key, timeout)
+
+    def push_result(self, batch_id: str, result: Dict[str, Any]) -> None:
+        """Return the result of a job to the coordinator of its batch."""
+        key = self.results_key(batch_id)
+        self.client.lpush(key, json.dumps(result))
+        self.client.expire(key, RESULT_TTL_SECONDS)
+
+    def pop_result(self, batch_id: str, timeout: float) -> Optional[Dict[str, Any]]:
+        """Take the next result of a batch, waiting up to timeout seconds for one."""
+        return self._pop(self.results_key(batch_id), timeout)
+
+    def clear_results(self, batch_id: str) -> None:
+        """Delete the results of a batch that were not taken."""
+        self.client.delete(self.results_key(batch_id))
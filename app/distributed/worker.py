@@ -0,0 +1,163 @@
+"""Workers that audit projects taken from the shared queue, and the coordinator
+that queues the projects and records the workers' results in one history store.
+
+A worker audits a project as ``paddi audit`` would on its own machine, then
+returns the project's findings, as its report shows them, with the
+environment of the run. The coordinator records each project as a run of its
+``data/history.db``, so trends, baselines, and ``paddi serve`` see every
+project of the scan in one place.
+"""
+
+import logging
+import socket
+import time
+import uuid
+from dataclasses import dataclass, field
+from typing import Any, Callable, Dict, List, Optional
+
+from app.cli.multi_project import FAILED, PASSED, ProjectResult
+from app.common.exceptions import PaddiException
+from app.common.exit_codes import ExitCode, exit_code_for
+from app.history.environment import run_environment
+from app.history.store import RunStore
+
+from .broker import RedisBroker
+
+logger = logging.getLogger(__name__)
+
+# Audits a project with the job's options, returning the findings of its report
+ProjectAudit = Callable[[str, Dict[str, Any]], List[dict]]
+
+
+class Worker:
+    """Takes audit jobs off the shared queue and returns their results."""
+
+    def __init__(self, broker: RedisBroker, audit: ProjectAudit, name: Optional[str] = None):
+        """Initialize Worker with the queue and the function that audits a project."""
+        self.broker = broker
+        self.audit = audit
+        self.name = name or socket.gethostname()
+
+    def run_once(self, timeout: float = 5.0) -> Optional[Dict[str, Any]]:
+        """Run the next job, waiting up to timeout seconds for one.
+
+        Returns:
+            The result sent back, or None if no job came
+        """
+        job = self.broker.pop_job(timeout)
+        if job is None:
+            return None
+        project_id = job["project_id"]
+        logger.info("Worker %s auditing %s (batch %s)", self.name, project_id, job["batch_id"])
+        result: Dict[str, Any] = {
+            "job_id": job["job_id"],
+            "project_id": project_id,
+            "worker": self.name,
+        }
+        try:
+            findings = self.audit(project_id, job.get("options") or {})
+        except Exception as e:  # pylint: disable=broad-except
+            error = e.message if isinstance(e, PaddiException) else str(e)
+            logger.warning("Audit of %s failed: %s", project_id, error)
+            result.update(status=FAILED, exit_code=int(exit_code_for(e)), error=error)
+        else:
+            result.update(
+                status=PASSED,
+                exit_code=int(ExitCode.SUCCESS),
+                findings=findings,
+                environment=run_environment(),
+            )
+        self.broker.push_result(job["batch_id"], result)
+        return result
+
+    def run(self, max_jobs: Optional[int] = None, poll_seconds: float = 5.0) -> int:
+        """Run jobs until stopped, or until max_jobs have run; returns the jobs run."""
+        done = 0
+        while max_jobs is None or done < max_jobs:
+            if self.run_once(poll_seconds) is not None:
+                done += 1
+        return done
+
+
+@dataclass
+class BatchResult:
+    """Outcome of a distributed audit of several projects."""
+
+    results: List[ProjectResult]
+    findings_by_project: Dict[str, List[dict]] = field(default_factory=dict)
+    workers: Dict[str, str] = field(default_factory=dict)
+
+
+class Coordinator:
+    """Queues projects for the workers and records their results in the history store."""
+
+    def __init__(self, broker: RedisBroker, store: RunStore):
+        """Initialize Coordinator with the queue and the history store to record runs in."""
+        self.broker = broker
+        self.store = store
+
+    def run(
+        self, projects: List[str], options: Dict[str, Any], timeout: float
+    ) -> BatchResult:
+        """Audit the projects on the workers, waiting up to timeout seconds for all results.
+
+        Projects without a result by then are reported as failed with a timeout, and
+        their jobs that no worker started are taken off the queue.
+        """
+        batch_id = uuid.uuid4().hex[:12]
+        for project_id in projects:
+            self.broker.push_job(
+                {
+                    "job_id": uuid.uuid4().hex[:12],
+                    "batch_id": batch_id,
+                    "project_id": project_id,
+                    "options": options,
+                }
+            )
+        logger.info("Queued %d project(s) as batch %s", len(projects), batch_id)
+
+        outcomes: Dict[str, ProjectResult] = {}
+        batch = BatchResult(results=[])
+        deadline = time.monotonic() + timeout
+        while len(outcomes) < len(projects):
+            remaining = deadline - time.monotonic()
+            if remaining <= 0:
+                break
+            result = self.broker.pop_result(batch_id, min(remaining, 5.0))
+            if result is None or result.get("project_id") not in projects:
+                continue
+            project_id = result["project_id"]
+            if project_id in outcomes:
+                continue
+            batch.workers[project_id] = result.get("worker", "")
+            if result.get("status") == PASSED:
+                findings = result.get("findings") or []
+                run = self.store.record_run(findings, project_id, result.get("environment"))
+                logger.info(
+                    "Recorded %s from %s as run %d", project_id, result.get("worker"), run.run_id
+                )
+                batch.findings_by_project[project_id] = findings
+                outcomes[project_id] = ProjectResult(project_id, PASSED, int(ExitCode.SUCCESS))
+            else:
+                outcomes[project_id] = ProjectResult(
+                    project_id, FAILED, result.get("exit_code"), result.get("error")
+                )
+        if len(outcomes) < len(projects):
+            cancelled = self.broker.cancel_jobs(batch_id)
+            if cancelled:
+                logger.info(
+                    "Took %d unstarted job(s) of batch %s off the queue", cancelled, batch_id
+                )
+        self.broker.clear_results(batch_id)
+
+        for project_id in projects:
+            batch.results.append(
+                outcomes.get(project_id)
+                or ProjectResult(
+                    project_id,
+                    FAILED,
+                    int(ExitCode.TIMEOUT),
+                    f"no result from a worker within {timeout:g}s",
+                )
+            )
+        return batch
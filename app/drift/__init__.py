@@ -0,0 +1 @@
+"""Drift detection between infrastructure as code and live configuration."""
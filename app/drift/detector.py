@@ -0,0 +1,197 @@
+"""Compare resources declared in Terraform with live collected data.
+
+Both sides use the collected data schema: the IaC side comes from
+:class:`app.collector.terraform_collector.TerraformCollector`, the live side
+from ``paddi collect``. A resource is *unmanaged* when it exists in the cloud
+but not in IaC (e.g. a manual IAM grant) and *missing* when IaC declares it
+but it is not live.
+"""
+
+from dataclasses import asdict, dataclass, field
+from typing import Any, Dict, Iterable, List, Set, Tuple
+
+UNMANAGED = "unmanaged"
+MISSING = "missing"
+
+PUBLIC_MEMBERS = ("allUsers", "allAuthenticatedUsers")
+PRIVILEGED_ROLES = ("roles/owner", "roles/editor")
+OPEN_SOURCE_RANGES = ("0.0.0.0/0", "::/0")
+
+
+@dataclass
+class DriftItem:
+    """One resource that differs between IaC and the live configuration."""
+
+    kind: str
+    resource: str
+    detail: str
+    status: str
+    severity: str
+
+
+@dataclass
+class DriftReport:
+    """Result of a drift comparison."""
+
+    items: List[DriftItem] = field(default_factory=list)
+
+    @property
+    def unmanaged(self) -> List[DriftItem]:
+        """Resources present in the cloud but absent from IaC."""
+        return [item for item in self.items if item.status == UNMANAGED]
+
+    @property
+    def missing(self) -> List[DriftItem]:
+        """Resources declared in IaC but absent from the cloud."""
+        return [item for item in self.items if item.status == MISSING]
+
+    def to_dict(self) -> Dict[str, Any]:
+        """Convert to dictionary for JSON serialization."""
+        return {
+            "unmanaged": len(self.unmanaged),
+            "missing": len(self.missing),
+            "items": [asdict(item) for item in self.items],
+        }
+
+
+def _gcp_data(data: Dict[str, Any]) -> Dict[str, Any]:
+    """Return the GCP section of single- or multi-cloud collected data."""
+    for provider in data.get("providers", []):
+        if provider.get("provider") == "gcp":
+            return provider
+    return data
+
+
+def _iam_grants(data: Dict[str, Any]) -> Set[Tuple[str, str]]:
+    """Return project IAM grants as ``(role, member)`` pairs."""
+    bindings = (data.get("iam_policies") or {}).get("bindings", [])
+    return {(b.get("role"), member) for b in bindings for member in b.get("members", [])}
+
+
+def _bucket_grants(data: Dict[str, Any]) -> Set[Tuple[str, str, str]]:
+    """Return bucket IAM grants as ``(bucket, role, member)`` triples."""
+    return {
+        (b.get("bucket"), b.get("role"), member)
+        for b in data.get("bucket_iam_bindings", [])
+        for member in b.get("members", [])
+    }
+
+
+def _firewall_rules(data: Dict[str, Any]) -> Dict[str, Dict[str, Any]]:
+    """Return firewall rules by name."""
+    return {rule.get("name"): rule for rule in data.get("firewall_rules", [])}
+
+
+def _grant_severity(role: str, member: str) -> str:
+    """Rate an unmanaged grant by how much access it gives away."""
+    if member in PUBLIC_MEMBERS:
+        return "CRITICAL"
+    if role in PRIVILEGED_ROLES or str(role).endswith(".admin"):
+        return "HIGH"
+    return "MEDIUM"
+
+
+def _rule_severity(rule: Dict[str, Any]) -> str:
+    """Rate an unmanaged firewall rule by its exposure."""
+    if not rule.get("disabled") and any(
+        source in OPEN_SOURCE_RANGES for source in rule.get("source_ranges", [])
+    ):
+        return "HIGH"
+    return "MEDIUM"
+
+
+def _sorted(items: Iterable[Tuple]) -> List[Tuple]:
+    """Sort comparison keys for stable output."""
+    return sorted(items, key=lambda key: tuple(str(part) for part in key))
+
+
+def detect_drift(iac: Dict[str, Any], live: Dict[str, Any]) -> DriftReport:
+    """Compare IaC and live collected data.
+
+    Firewall rules and bucket bindings are only compared when the live data
+    includes them, so a collection without those resources does not report
+    every declared rule as missing.
+    """
+    iac, live = _gcp_data(iac), _gcp_data(live)
+    project = (live.get("metadata") or {}).get("project_id") or (
+        iac.get("metadata") or {}
+    ).get("project_id")
+    project_resource = f"projects/{project}" if project else "project"
+    items: List[DriftItem] = []
+
+    iac_grants, live_grants = _iam_grants(iac), _iam_grants(live)
+    for role, member in _sorted(live_grants - iac_grants):
+        items.append(
+            DriftItem(
+                "iam",
+                project_resource,
+                f"{member} has {role}",
+                UNMANAGED,
+                _grant_severity(role, member),
+            )
+        )
+    for role, member in _sorted(iac_grants - live_grants):
+        items.append(DriftItem("iam", project_resource, f"{member} has {role}", MISSING, "LOW"))
+
+    if "bucket_iam_bindings" in live:
+        iac_buckets, live_buckets = _bucket_grants(iac), _bucket_grants(live)
+        for bucket, role, member in _sorted(live_buckets - iac_buckets):
+            items.append(
+                DriftItem(
+                    "bucket_iam",
+                    f"//storage.googleapis.com/{bucket}",
+                    f"{member} has {role}",
+                    UNMANAGED,
+                    _grant_severity(role, member),
+                )
+            )
+        for bucket, role, member in _sorted(iac_buckets - live_buckets):
+            items.append(
+                DriftItem(
+                    "bucket_iam",
+                    f"//storage.googleapis.com/{bucket}",
+                    f"{member} has {role}",
+                    MISSING,
+                    "LOW",
+                )
+            )
+
+    if "firewall_rules" in live:
+        iac_rules, live_rules = _firewall_rules(iac), _firewall_rules(live)
+        for name in sorted(set(live_rules) - set(iac_rules), key=str):
+            rule = live_rules[name]
+            sources = ", ".join(rule.get("source_ranges", [])) or "-"
+            items.append(
+                DriftItem(
+                    "firewall",
+                    f"{project_resource}/global/firewalls/{name}",
+                    f"sources {sources}",
+                    UNMANAGED,
+                    _rule_severity(rule),
+                )
+            )
+        for name in sorted(set(iac_rules) - set(live_rules), key=str):
+            items.append(
+                DriftItem(
+                    "firewall",
+                    f"{project_resource}/global/firewalls/{name}",
+                    "declared in Terraform",
+                    MISSING,
+                    "LOW",
+                )
+            )
+
+    return DriftReport(items)
+
+
+def format_drift_table(report: DriftReport) -> str:
+    """Format drift items as a fixed-width text table."""
+    columns = [("STATUS", 10), ("SEVERITY", 9), ("KIND", 11), ("RESOURCE", 45), ("DETAIL", 60)]
+    header = " ".join(name.ljust(width) for name, width in columns)
+    lines = [header, "-" * len(header)]
+    for item in report.items:
+        values = [item.status, item.severity, item.kind, item.resource, item.detail]
+        lines.append(
+            " ".join(value.ljust(width) for value, (_, width) in zip(values, columns)).rstrip()
+        )
+    return "\n".join(lines)
@@ -12,7 +12,7 @@ import os
 import time
 from abc import ABC, abstractmethod
 from pathlib import Path
-from typing import Any, Dict, List, Optional
+from typing import Any, Dict, List, Optional, Tuple
 
 import fire
 
@@ -24,9 +24,19 @@ except ImportError:
     models = None
 
 from app.common.auth import check_gcp_credentials
+from app.common.artifact_crypto import load_artifact, save_artifact
 from app.common.models import SecurityFinding
+from app.explainer.circuit_breaker import CircuitBreaker, CircuitOpenError
+from app.explainer.feedback import drop_false_positives, false_positive_prompt, load_false_positives
 from app.explainer.mock_data_factory import MockDataFactory
 from app.explainer.prompt_templates import SYSTEM_PROMPT_ENHANCED, build_analysis_prompt
+from app.explainer.redaction import Redactor, restore
+from app.explainer.residency import LOCAL_PROVIDERS, enforce_local_only
+from app.explainer.rule_analyzer import RuleBasedAnalyzer
+from app.explainer.token_usage import TokenUsage
+from app.findings.cvss import CvssScorer
+from app.findings.fingerprint import add_fingerprints
+from app.plugins.wasm_host import WasmPluginHost
 
 # Configure logging
 logging.basicConfig(
@@ -85,6 +95,8 @@ Provide findings in this JSON format:
   {{
     "title": "Finding title",
     "severity": "HIGH|MEDIUM|LOW",
+    "category": "UPPER_SNAKE_CASE category (e.g. OVERPRIVILEGED_ROLE, PUBLIC_BUCKET)",
+    "resource": "Affected resource name",
     "explanation": "Detailed explanation",
     "recommendation": "Specific recommendation"
   }}
@@ -105,6 +117,8 @@ Provide analysis in this JSON format:
   {{
     "title": "Finding title",
     "severity": "HIGH|MEDIUM|LOW",
+    "category": "UPPER_SNAKE_CASE category (e.g. OVERPRIVILEGED_ROLE, PUBLIC_BUCKET)",
+    "resource": "Affected resource name",
     "explanation": "Detailed explanation",
     "recommendation": "Specific recommendation"
   }}
@@ -123,8 +137,15 @@ class GeminiSecurityAnalyzer(LLMInterface):
         max_output_tokens: int = 2048,
         use_mock: bool = False,
         project_context: Optional[Dict[str, Any]] = None,
+        feedback: str = "",
+        circuit_breaker: Optional[CircuitBreaker] = None,
     ):
-        """Initialize GeminiSecurityAnalyzer with configuration."""
+        """Initialize GeminiSecurityAnalyzer with configuration.
+
+        Args:
+            feedback: Known false positives, appended to the system prompt
+            circuit_breaker: Stops retries once Vertex AI keeps failing
+        """
         self.project_id = project_id
         self.location = location
         self.model_name = model_name
@@ -132,9 +153,12 @@ class GeminiSecurityAnalyzer(LLMInterface):
         self.max_output_tokens = max_output_tokens
         self.use_mock = use_mock
         self.project_context = project_context or {}
+        self.feedback = feedback
+        self.circuit_breaker = circuit_breaker or CircuitBreaker()
         self._model = None
         self._rate_limit_delay = 1.0  # Delay between API calls in seconds
         self._mock_factory = MockDataFactory()
+        self.usage = TokenUsage()
 
         if not use_mock:
             self._initialize_vertex_ai()
@@ -252,6 +276,8 @@ class GeminiSecurityAnalyzer(LLMInterface):
             response = self._call_llm_with_retry(prompt)
             findings_data = self._parse_llm_response(response)
             return [SecurityFinding(**finding) for finding in findings_data]
+        except CircuitOpenError:
+            raise
         except Exception as e:
             logger.error("Error analyzing IAM policies: %s", e)
             return self._get_mock_iam_findings()
@@ -269,6 +295,8 @@ class GeminiSecurityAnalyzer(LLMInterface):
             response = self._call_llm_with_retry(prompt)
             findings_data = self._parse_llm_response(response)
             return [SecurityFinding(**finding) for finding in findings_data]
+        except CircuitOpenError:
+            raise
         except Exception as e:
             logger.error("Error analyzing SCC findings: %s", e)
             return self._get_mock_scc_findings()
@@ -278,6 +306,7 @@ class GeminiSecurityAnalyzer(LLMInterface):
         last_exception = None
 
         for attempt in range(max_retries):
+            self.circuit_breaker.before_call()
             try:
                 # Rate limiting
                 time.sleep(self._rate_limit_delay)
@@ -295,17 +324,31 @@ class GeminiSecurityAnalyzer(LLMInterface):
                     if self.project_context
                     else self._get_basic_system_prompt()
                 )
+                if self.feedback:
+                    system_prompt += "\n\n" + self.feedback
                 response = self._model.generate_content(
                     [system_prompt, prompt],
                     generation_config=generation_config,
                 )
 
+                self.circuit_breaker.record_success()
+                metadata = getattr(response, "usage_metadata", None)
+                self.usage.add(
+                    system_prompt + prompt,
+                    response.text,
+                    getattr(metadata, "prompt_token_count", None),
+                    getattr(metadata, "candidates_token_count", None),
+                )
                 return response.text
 
             except Exception as e:
                 last_exception = e
+                self.circuit_breaker.record_failure()
                 logger.warning("LLM call failed (attempt %d/%d): %s", attempt + 1, max_retries, e)
-                if attempt < max_retries - 1:
+                if self.circuit_breaker.is_open and self.circuit_breaker.on_open != "pause":
+                    # Stop retrying a backend that keeps failing
+                    raise CircuitOpenError(self.circuit_breaker) from e
+                if attempt < max_retries - 1 and not self.circuit_breaker.is_open:
                     # Exponential backoff
                     time.sleep((2**attempt) * self._rate_limit_delay)
 
@@ -364,6 +407,8 @@ class GeminiSecurityAnalyzer(LLMInterface):
                 findings.append(basic_finding)
 
             return findings
+        except CircuitOpenError:
+            raise
         except Exception as e:
             logger.error("Error in enhanced analysis: %s", e)
             return self._get_enhanced_mock_findings()
@@ -437,6 +482,8 @@ Provide analysis in this JSON format:
   {{
     "title": "Finding title",
     "severity": "HIGH|MEDIUM|LOW",
+    "category": "UPPER_SNAKE_CASE category (e.g. OVERPRIVILEGED_ROLE, PUBLIC_BUCKET)",
+    "resource": "Affected resource name",
     "explanation": "Detailed explanation",
     "recommendation": "Specific recommendation"
   }}
@@ -446,6 +493,8 @@ Provide analysis in this JSON format:
             response = self._call_llm_with_retry(prompt)
             findings_data = self._parse_llm_response(response)
             return [SecurityFinding(**finding) for finding in findings_data]
+        except CircuitOpenError:
+            raise
         except Exception as e:
             logger.error("Error analyzing %s security findings: %s", provider_name, e)
             return self._get_mock_findings_for_provider(provider_name)
@@ -482,13 +531,16 @@ Provide analysis in this JSON format:
 def get_analyzer(config: Dict[str, Any]) -> LLMInterface:
     """設定に基づいてAIアナライザーを取得"""
     provider = config.get("ai_provider", "gemini")
+    endpoint = config.get("ollama_endpoint", "http://localhost:11434")
+    enforce_local_only(provider, endpoint if provider == "ollama" else None)
 
     if provider == "ollama":
         from .ollama_explainer import OllamaSecurityAnalyzer
 
         return OllamaSecurityAnalyzer(
             model=config.get("ollama_model", "gemma3:latest"),
-            endpoint=config.get("ollama_endpoint", "http://localhost:11434"),
+            endpoint=endpoint,
+            feedback=config.get("feedback", ""),
         )
     # Gemini
     return GeminiSecurityAnalyzer(
@@ -496,6 +548,8 @@ def get_analyzer(config: Dict[str, Any]) -> LLMInterface:
         location=config.get("location", "asia-northeast1"),
         use_mock=config.get("use_mock", False),
         project_context=config.get("project_context"),
+        feedback=config.get("feedback", ""),
+        circuit_breaker=config.get("circuit_breaker"),
     )
 
 
@@ -558,11 +612,26 @@ class SecurityRiskExplainer:
                 location = os.getenv("VERTEX_AI_LOCATION", "asia-northeast1")
             config["location"] = location
 
+        self.ai_provider = config["ai_provider"]
+
         # Add project context to config
         if self.project_context:
             config["project_context"] = self.project_context
 
+        # Values redacted from the feedback and the configuration, by placeholder,
+        # so they can be put back into the findings
+        self.placeholders: Dict[Tuple[str, str], str] = {}
+
+        # Tell the analyzer about findings marked with `paddi findings mark-fp`
+        self.false_positives = load_false_positives(self.output_dir)
+        feedback = false_positive_prompt(self.false_positives)
+        if feedback and self.ai_provider not in LOCAL_PROVIDERS:
+            feedback, _ = Redactor.from_config().redact(feedback, self.placeholders)
+        config["feedback"] = feedback
+        config["circuit_breaker"] = CircuitBreaker.from_config()
+
         # Initialize analyzer using factory
+        self._analyzer_config = config
         self.analyzer = get_analyzer(config)
 
     def load_configuration(self) -> Dict[str, Any]:
@@ -570,8 +639,7 @@ class SecurityRiskExplainer:
         if not self.input_file.exists():
             raise FileNotFoundError(f"Input file not found: {self.input_file}")
 
-        with open(self.input_file, "r", encoding="utf-8") as f:
-            return json.load(f)
+        return load_artifact(self.input_file)
 
     def analyze(self) -> List[SecurityFinding]:
         """Perform security analysis on collected configuration"""
@@ -579,22 +647,92 @@ class SecurityRiskExplainer:
         configuration = self.load_configuration()
 
         logger.info("Starting security risk analysis...")
-        findings = self.analyzer.analyze_security_risks(configuration)
+        redacted = self.redact(configuration)
+        try:
+            findings = self.analyzer.analyze_security_risks(redacted)
+        except CircuitOpenError as e:
+            findings = self._analyze_without_backend(e.breaker, configuration, redacted)
+        findings = self.restore(findings)
+
+        # Add open firewall rules and suspicious audit log activity, if collected
+        findings.extend(RuleBasedAnalyzer().native_findings(configuration))
+
+        # Add findings from WASM analyzer plugins, if any are installed
+        findings.extend(WasmPluginHost.from_config().run_analyzers(configuration))
+
+        findings, dropped = drop_false_positives(findings, self.false_positives)
+        if dropped:
+            logger.info("Dropped %d finding(s) marked as false positives", len(dropped))
 
         logger.info("Analysis complete. Found %d security issues.", len(findings))
         return findings
 
+    def _analyze_without_backend(
+        self, breaker: CircuitBreaker, configuration: Dict[str, Any], redacted: Dict[str, Any]
+    ) -> List[SecurityFinding]:
+        """Finish the analysis after the circuit breaker tripped on the AI provider.
+
+        With ``on_open = "fallback"``, the fallback provider analyzes the data;
+        otherwise, or if the fallback fails too, the built-in rules do.
+        """
+        provider = breaker.fallback_provider
+        if breaker.on_open == "fallback" and provider:
+            logger.warning("Switching analysis from %s to %s", self.ai_provider, provider)
+            config = {**self._analyzer_config, "ai_provider": provider}
+            config["circuit_breaker"] = CircuitBreaker()
+            if provider == "ollama":
+                config.setdefault("ollama_model", os.getenv("OLLAMA_MODEL", "gemma3:latest"))
+                config.setdefault(
+                    "ollama_endpoint", os.getenv("OLLAMA_ENDPOINT", "http://localhost:11434")
+                )
+            data = configuration if provider in LOCAL_PROVIDERS else redacted
+            try:
+                return get_analyzer(config).analyze_security_risks(data)
+            except Exception as e:
+                logger.error("Fallback provider %s failed: %s", provider, e)
+
+        logger.warning("Degrading to rule-only analysis; findings have no AI explanations")
+        return RuleBasedAnalyzer().analyze_security_risks(configuration)
+
+    def redact(self, configuration: Dict[str, Any]) -> Dict[str, Any]:
+        """Redact secrets and PII before the data is sent to a hosted LLM.
+
+        The report of what was redacted is written to ``redactions.json``.
+        Local providers get the data unchanged.
+        """
+        if self.ai_provider in LOCAL_PROVIDERS:
+            return configuration
+
+        redacted, report = Redactor.from_config().redact(configuration, self.placeholders)
+        path = report.save(self.output_dir)
+        if report.total:
+            summary = ", ".join(f"{name}={count}" for name, count in sorted(report.counts.items()))
+            logger.info(
+                "Redacted %d value(s) before analysis (%s): %s", report.total, summary, path
+            )
+        return redacted
+
+    def restore(self, findings: List[SecurityFinding]) -> List[SecurityFinding]:
+        """Put the redacted values back into the findings the model returned."""
+        if not self.placeholders:
+            return findings
+        return [
+            SecurityFinding.from_dict(restore(finding.to_dict(), self.placeholders))
+            for finding in findings
+        ]
+
     def save_findings(
         self, findings: List[SecurityFinding], filename: str = "explained.json"
     ) -> Path:
         """Save analysis findings to JSON file"""
         output_path = self.output_dir / filename
 
-        # Convert findings to dict format
-        findings_data = [finding.to_dict() for finding in findings]
+        # Convert findings to dict format, tagging each with a stable fingerprint
+        # and a CVSS base score
+        scored = CvssScorer.from_config().add_scores(add_fingerprints(findings))
+        findings_data = [finding.to_dict() for finding in scored]
 
-        with open(output_path, "w", encoding="utf-8") as f:
-            json.dump(findings_data, f, indent=2, ensure_ascii=False)
+        save_artifact(output_path, findings_data, indent=2, ensure_ascii=False)
 
         logger.info("Findings saved to: %s", output_path)
         return output_path
@@ -626,6 +764,8 @@ def main(
     try:
         # Determine AI provider
         provider = ai_provider or os.getenv("AI_PROVIDER", "gemini")
+        # Refuse hosted providers before touching their credentials
+        enforce_local_only(provider)
 
         # Set up Google Cloud authentication if using Gemini and not mock
         if provider == "gemini" and not use_mock:
@@ -0,0 +1,158 @@
+"""Circuit breaker for the LLM backend used in analysis.
+
+Every failed LLM call (each retry attempt counts) adds to a run of consecutive
+failures; a successful call resets it. When the run reaches the threshold, the
+breaker trips: retries stop at once instead of spending the timeout on a
+backend that is down, and the analysis does what ``on_open`` says::
+
+    [llm.circuit_breaker]
+    failure_threshold = 3      # consecutive failures that trip the breaker
+    cooldown_seconds = 30      # pause length for on_open = "pause"
+    on_open = "fallback"       # "rules" (default), "pause", or "fallback"
+    fallback_provider = "ollama"
+
+- ``rules``: analyze with the built-in rules only (no LLM)
+- ``pause``: wait ``cooldown_seconds``, then let one call through; once it
+  has paused ``max_pauses`` times (default 3), analysis falls back to the rules
+- ``fallback``: analyze with ``fallback_provider``; if that fails too, the
+  rules are used
+"""
+
+import logging
+import time
+from typing import Callable, Optional
+
+from app.common.exceptions import ConfigurationError
+from app.config.paddi_config import PaddiConfig
+
+logger = logging.getLogger(__name__)
+
+CLOSED = "closed"
+OPEN = "open"
+HALF_OPEN = "half-open"
+
+ON_OPEN_ACTIONS = ("rules", "pause", "fallback")
+
+DEFAULT_FAILURE_THRESHOLD = 3
+DEFAULT_COOLDOWN_SECONDS = 30.0
+DEFAULT_MAX_PAUSES = 3
+
+
+class CircuitOpenError(RuntimeError):
+    """Raised instead of calling the backend while the breaker is open."""
+
+    def __init__(self, breaker: "CircuitBreaker"):
+        super().__init__(
+            f"LLM circuit breaker is open after {breaker.failures} consecutive failures"
+        )
+        self.breaker = breaker
+
+
+class CircuitBreaker:
+    """Counts consecutive backend failures and trips at a threshold."""
+
+    def __init__(
+        self,
+        failure_threshold: int = DEFAULT_FAILURE_THRESHOLD,
+        cooldown_seconds: float = DEFAULT_COOLDOWN_SECONDS,
+        on_open: str = "rules",
+        fallback_provider: Optional[str] = None,
+        max_pauses: int = DEFAULT_MAX_PAUSES,
+        clock: Callable[[], float] = time.monotonic,
+        sleep: Callable[[float], None] = time.sleep,
+    ):
+        """Initialize CircuitBreaker.
+
+        Args:
+            failure_threshold: Consecutive failures that trip the breaker
+            cooldown_seconds: How long "pause" waits before the next call
+            on_open: What analysis does once the breaker trips
+            fallback_provider: AI provider used by "fallback"
+            max_pauses: Trips that "pause" waits out before giving up
+            clock: Monotonic clock, replaceable in tests
+            sleep: Sleep function, replaceable in tests
+        """
+        self.failure_threshold = failure_threshold
+        self.cooldown_seconds = cooldown_seconds
+        self.on_open = on_open
+        self.fallback_provider = fallback_provider
+        self.max_pauses = max_pauses
+        self.state = CLOSED
+        self.failures = 0
+        self.trips = 0
+        self._opened_at = 0.0
+        self._clock = clock
+        self._sleep = sleep
+
+    @classmethod
+    def from_config(cls, config: Optional[PaddiConfig] = None) -> "CircuitBreaker":
+        """Create a breaker from ``[llm.circuit_breaker]`` in paddi.toml.
+
+        Raises:
+            ConfigurationError: If on_open is unknown or "fallback" has no provider
+        """
+        section = (config or PaddiConfig.load()).section("llm.circuit_breaker")
+        on_open = section.get("on_open", "rules")
+        if on_open not in ON_OPEN_ACTIONS:
+            raise ConfigurationError(
+                "llm.circuit_breaker.on_open",
+                {"error": f"use one of {', '.join(ON_OPEN_ACTIONS)}"},
+            )
+        fallback_provider = section.get("fallback_provider")
+        if on_open == "fallback" and not fallback_provider:
+            raise ConfigurationError(
+                "llm.circuit_breaker.fallback_provider",
+                {"error": 'required when on_open = "fallback"'},
+            )
+        return cls(
+            failure_threshold=int(section.get("failure_threshold", DEFAULT_FAILURE_THRESHOLD)),
+            cooldown_seconds=float(section.get("cooldown_seconds", DEFAULT_COOLDOWN_SECONDS)),
+            on_open=on_open,
+            fallback_provider=fallback_provider,
+            max_pauses=int(section.get("max_pauses", DEFAULT_MAX_PAUSES)),
+        )
+
+    @property
+    def is_open(self) -> bool:
+        """Return whether calls are currently refused."""
+        return self.state == OPEN
+
+    def before_call(self) -> None:
+        """Check the breaker before calling the backend.
+
+        With on_open = "pause", an open breaker waits out the cooldown and lets
+        one trial call through.
+
+        Raises:
+            CircuitOpenError: If the breaker is open
+        """
+        if self.state != OPEN:
+            return
+        if self.on_open == "pause" and self.trips <= self.max_pauses:
+            remaining = self.cooldown_seconds - (self._clock() - self._opened_at)
+            if remaining > 0:
+                logger.warning("LLM backend is failing; pausing %.0fs before retrying", remaining)
+                self._sleep(remaining)
+            self.state = HALF_OPEN
+            return
+        raise CircuitOpenError(self)
+
+    def record_success(self) -> None:
+        """Close the breaker after a successful call."""
+        self.state = CLOSED
+        self.failures = 0
+
+    def record_failure(self) -> None:
+        """Count a failed call, tripping the breaker at the threshold.
+
+        A failed trial call after a pause trips the breaker again at once.
+        """
+        self.failures += 1
+        if self.state == HALF_OPEN or self.failures >= self.failure_threshold:
+            if self.state != OPEN:
+                self.trips += 1
+                logger.warning(
+                    "LLM circuit breaker tripped after %d consecutive failures", self.failures
+                )
+            self.state = OPEN
+            self._opened_at = self._clock()
@@ -0,0 +1,64 @@
+"""False-positive feedback from ``paddi findings mark-fp``, fed back into analysis.
+
+Marked findings are kept in the run history (``data/history.db``). Before
+analysis they are described in the LLM prompt, so the model does not report
+them again. After analysis, findings whose fingerprint was marked are dropped,
+so the feedback also holds for mock data and analyzer plugins.
+"""
+
+import logging
+import sqlite3
+from pathlib import Path
+from typing import List, Tuple
+
+from app.common.models import SecurityFinding
+from app.findings.fingerprint import compute_fingerprint
+from app.history.store import HISTORY_DB_NAME, FalsePositive, RunStore
+
+logger = logging.getLogger(__name__)
+
+# Keeps the prompt short when many findings have been marked
+MAX_PROMPT_ENTRIES = 50
+
+
+def load_false_positives(data_dir: Path) -> List[FalsePositive]:
+    """Return the findings marked as false positives in the run history, if there is one."""
+    history = Path(data_dir) / HISTORY_DB_NAME
+    if not history.exists():
+        return []
+    try:
+        return RunStore(history).false_positives()
+    except sqlite3.Error as e:
+        logger.warning("False-positive feedback unavailable: %s", e)
+        return []
+
+
+def false_positive_prompt(false_positives: List[FalsePositive]) -> str:
+    """Describe marked false positives for the LLM prompt (empty if there are none)."""
+    if not false_positives:
+        return ""
+    lines = [
+        "The team reviewed these findings in earlier audits and marked them as false "
+        "positives. Do not report them again:"
+    ]
+    for entry in false_positives[-MAX_PROMPT_ENTRIES:]:
+        what = " on ".join(part for part in (entry.category, entry.resource) if part)
+        text = f"- {what or entry.title}"
+        if entry.title and what:
+            text += f" ({entry.title})"
+        if entry.reason:
+            text += f": {entry.reason}"
+        lines.append(text)
+    return "\n".join(lines)
+
+
+def drop_false_positives(
+    findings: List[SecurityFinding], false_positives: List[FalsePositive]
+) -> Tuple[List[SecurityFinding], List[SecurityFinding]]:
+    """Split findings into kept ones and ones marked as false positives."""
+    marked = {entry.fingerprint for entry in false_positives}
+    kept, dropped = [], []
+    for finding in findings:
+        fingerprint = finding.fingerprint or compute_fingerprint(finding)
+        (dropped if fingerprint in marked else kept).append(finding)
+    return kept, dropped
@@ -1,6 +1,6 @@
 """Mock data factory for consistent test data generation."""
 
-from typing import List
+from typing import List, Optional
 
 from app.common.models import SecurityFinding
 
@@ -9,11 +9,22 @@ class MockDataFactory:
     """Factory for creating mock security findings."""
 
     def create_finding(
-        self, title: str, severity: str, explanation: str, recommendation: str
+        self,
+        title: str,
+        severity: str,
+        explanation: str,
+        recommendation: str,
+        category: Optional[str] = None,
+        resource: Optional[str] = None,
     ) -> SecurityFinding:
         """Create a single security finding."""
         return SecurityFinding(
-            title=title, severity=severity, explanation=explanation, recommendation=recommendation
+            title=title,
+            severity=severity,
+            explanation=explanation,
+            recommendation=recommendation,
+            category=category,
+            resource=resource,
         )
 
     def create_iam_findings(self) -> List[SecurityFinding]:
@@ -34,6 +45,8 @@ class MockDataFactory:
                     "permissions for their tasks. Consider using roles like "
                     "'roles/editor' or custom roles with limited scope."
                 ),
+                category="OVERPRIVILEGED_ROLE",
+                resource="projects/example-project-123",
             ),
             self.create_finding(
                 title="Service Account with Editor Role",
@@ -49,6 +62,11 @@ class MockDataFactory:
                     "roles like 'roles/storage.objectAdmin' or create a custom "
                     "role with minimal permissions."
                 ),
+                category="OVERPRIVILEGED_SERVICE_ACCOUNT",
+                resource=(
+                    "projects/example-project-123/serviceAccounts/"
+                    "app-sa@project.iam.gserviceaccount.com"
+                ),
             ),
         ]
 
@@ -79,6 +97,8 @@ class MockDataFactory:
                     "with only the specific permissions needed. Consider using "
                     "AWS IAM Access Analyzer to identify actual permissions used."
                 ),
+                category="OVERPRIVILEGED_ROLE",
+                resource="arn:aws:iam::123456789012:user/admin-user",
             ),
             self.create_finding(
                 title="EC2 Role with Overly Permissive Assume Role Policy",
@@ -93,6 +113,8 @@ class MockDataFactory:
                     "or use instance tags. Replace AdministratorAccess with "
                     "minimal required permissions for the workload."
                 ),
+                category="OVERPRIVILEGED_ROLE",
+                resource="arn:aws:iam::123456789012:role/EC2-Admin-Role",
             ),
         ]
 
@@ -113,6 +135,8 @@ class MockDataFactory:
                     "daily operations. Implement Privileged Identity Management "
                     "(PIM) for just-in-time access."
                 ),
+                category="OVERPRIVILEGED_ROLE",
+                resource="/subscriptions/00000000-0000-0000-0000-000000000000",
             ),
             self.create_finding(
                 title="Service Principal with Broad Contributor Access",
@@ -127,6 +151,8 @@ class MockDataFactory:
                     "resource groups or resources. Create custom roles with "
                     "minimal required permissions."
                 ),
+                category="OVERPRIVILEGED_SERVICE_ACCOUNT",
+                resource="/subscriptions/00000000-0000-0000-0000-000000000000",
             ),
         ]
 
@@ -154,6 +180,8 @@ class MockDataFactory:
                     "Enable S3 Block Public Access at the account level. "
                     "Use CloudFront with signed URLs for controlled public access."
                 ),
+                category="PUBLIC_BUCKET",
+                resource="arn:aws:s3:::example-public-bucket",
             ),
             self.create_finding(
                 title="RDS Database Instance Lacks Encryption",
@@ -168,6 +196,8 @@ class MockDataFactory:
                     "snapshot and restore to a new encrypted instance. Update "
                     "applications to use the new endpoint."
                 ),
+                category="UNENCRYPTED_DATABASE",
+                resource="arn:aws:rds:us-east-1:123456789012:db:production-db",
             ),
         ]
 
@@ -187,6 +217,11 @@ class MockDataFactory:
                     "Implement private endpoints and use SAS tokens for "
                     "controlled access. Enable Azure Defender for Storage."
                 ),
+                category="PUBLIC_BUCKET",
+                resource=(
+                    "/subscriptions/00000000-0000-0000-0000-000000000000/"
+                    "storageAccounts/publicstorageaccount"
+                ),
             ),
             self.create_finding(
                 title="SQL Database Missing Auditing Configuration",
@@ -201,6 +236,11 @@ class MockDataFactory:
                     "or Event Hub as destination. Configure audit retention "
                     "per compliance requirements. Set up alerts for suspicious activities."
                 ),
+                category="AUDIT_LOGGING_DISABLED",
+                resource=(
+                    "/subscriptions/00000000-0000-0000-0000-000000000000/"
+                    "databases/productiondb"
+                ),
             ),
         ]
 
@@ -222,6 +262,11 @@ class MockDataFactory:
                     "granting only the minimum permissions required for its "
                     "specific functions."
                 ),
+                category="OVERPRIVILEGED_SERVICE_ACCOUNT",
+                resource=(
+                    "//iam.googleapis.com/projects/example-project/serviceAccounts/"
+                    "over-privileged-sa@example-project.iam.gserviceaccount.com"
+                ),
             ),
             self.create_finding(
                 title="Publicly Accessible Storage Bucket",
@@ -236,6 +281,8 @@ class MockDataFactory:
                     "unless explicitly required. Implement bucket-level IAM "
                     "policies and use signed URLs for temporary access when needed."
                 ),
+                category="PUBLIC_BUCKET",
+                resource="//storage.googleapis.com/example-public-bucket",
             ),
         ]
 
@@ -256,6 +303,11 @@ class MockDataFactory:
                     "2. カスタムロールを作成: gcloud iam roles create\n"
                     "3. Owner権限を削除し、必要最小限の権限のみ付与"
                 ),
+                category="OVERPRIVILEGED_SERVICE_ACCOUNT",
+                resource=(
+                    "projects/example-project-123/serviceAccounts/"
+                    "prod-app-sa@project.iam.gserviceaccount.com"
+                ),
             ),
             self.create_finding(
                 title="公開アクセス可能なCloud Storageバケット",
@@ -268,5 +320,7 @@ class MockDataFactory:
                     "バケットの公開アクセスを無効化し、必要に応じて署名付きURLを使用してください。"
                     "コマンド: gsutil iam ch -d allUsers gs://public-data-bucket"
                 ),
+                category="PUBLIC_BUCKET",
+                resource="//storage.googleapis.com/public-data-bucket",
             ),
         ]
@@ -7,6 +7,7 @@ from typing import Any, Dict, List
 import requests
 
 from app.common.models import SecurityFinding
+from app.explainer.token_usage import TokenUsage
 
 logger = logging.getLogger(__name__)
 
@@ -14,9 +15,16 @@ logger = logging.getLogger(__name__)
 class OllamaSecurityAnalyzer:
     """Ollamaを使用したセキュリティ分析クラス"""
 
-    def __init__(self, model: str = "gemma3:latest", endpoint: str = "http://localhost:11434"):
+    def __init__(
+        self,
+        model: str = "gemma3:latest",
+        endpoint: str = "http://localhost:11434",
+        feedback: str = "",
+    ):
         self.model = model
         self.endpoint = endpoint
+        self.feedback = feedback
+        self.usage = TokenUsage()
         self._verify_connection()
 
     def _verify_connection(self) -> None:
@@ -66,6 +74,12 @@ class OllamaSecurityAnalyzer:
             response.raise_for_status()
 
             result = response.json()
+            self.usage.add(
+                prompt,
+                result["response"],
+                result.get("prompt_eval_count"),
+                result.get("eval_count"),
+            )
             return self._parse_ollama_response(result["response"])
 
         except Exception as e:
@@ -101,6 +115,8 @@ IAMポリシー:
 
 重要: 必ず有効なJSONフォーマットで返答してください。
 """
+        if self.feedback:
+            prompt += f"\n{self.feedback}\n"
 
         return prompt
 
@@ -0,0 +1,166 @@
+"""Redaction of PII and secrets in collected data before it is sent to a hosted LLM.
+
+Built-in detectors find email addresses, cloud API keys, tokens, and private
+keys; ``[redaction]`` in paddi.toml selects detectors and adds patterns::
+
+    [redaction]
+    enabled = true
+    detectors = ["email", "gcp_api_key", "private_key"]  # default: all
+    patterns = { employee_id = "EMP-[0-9]{6}" }
+
+Each distinct value is replaced by a stable placeholder such as
+``[REDACTED:email:1]``, so the model can still tell two principals apart.
+The report lists where values were found, never the values themselves.
+``restore`` puts the values back into what the model returns.
+"""
+
+import json
+import logging
+import re
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import Any, Dict, List, Optional, Pattern, Tuple
+
+from app.common.exceptions import ConfigurationError
+from app.config.paddi_config import PaddiConfig
+
+logger = logging.getLogger(__name__)
+
+REDACTION_REPORT_NAME = "redactions.json"
+
+BUILTIN_DETECTORS: Dict[str, str] = {
+    "email": r"[A-Za-z0-9._%+-]+@[A-Za-z0-9-]+(?:\.[A-Za-z0-9-]+)*\.[A-Za-z]{2,}",
+    "gcp_api_key": r"\bAIza[0-9A-Za-z_-]{35}\b",
+    "aws_access_key": r"\b(?:AKIA|ASIA)[0-9A-Z]{16}\b",
+    "github_token": r"\b(?:gh[pousr]_[A-Za-z0-9]{36,}|github_pat_[A-Za-z0-9_]{22,})\b",
+    "slack_token": r"\bxox[abprs]-[A-Za-z0-9-]{10,}",
+    "jwt": r"\beyJ[A-Za-z0-9_-]{8,}\.[A-Za-z0-9_-]{8,}\.[A-Za-z0-9_-]{8,}",
+    "bearer_token": r"(?i)\bbearer\s+[A-Za-z0-9._~+/-]{20,}=*",
+    "private_key": (
+        r"-----BEGIN [A-Z ]*PRIVATE KEY-----[\s\S]*?-----END [A-Z ]*PRIVATE KEY-----"
+    ),
+}
+
+
+@dataclass
+class RedactionReport:
+    """What was redacted: counts per detector and the JSON path of each value."""
+
+    counts: Dict[str, int] = field(default_factory=dict)
+    locations: List[Dict[str, str]] = field(default_factory=list)
+
+    @property
+    def total(self) -> int:
+        """Number of values redacted."""
+        return sum(self.counts.values())
+
+    def to_dict(self) -> Dict[str, Any]:
+        """Convert to dictionary for JSON serialization."""
+        return {"total": self.total, "counts": self.counts, "locations": self.locations}
+
+    def save(self, output_dir: Path) -> Path:
+        """Write the report as ``redactions.json``."""
+        path = output_dir / REDACTION_REPORT_NAME
+        path.write_text(json.dumps(self.to_dict(), indent=2) + "\n", encoding="utf-8")
+        return path
+
+
+class Redactor:
+    """Replace sensitive values in JSON-like data with placeholders."""
+
+    def __init__(self, detectors: Optional[Dict[str, Pattern]] = None, enabled: bool = True):
+        """Initialize the redactor.
+
+        Args:
+            detectors: Compiled pattern by detector name (defaults to all built-in)
+            enabled: When false, data is passed through unchanged
+        """
+        if detectors is None:
+            detectors = {name: re.compile(regex) for name, regex in BUILTIN_DETECTORS.items()}
+        self.detectors = detectors
+        self.enabled = enabled
+
+    @classmethod
+    def from_config(cls, config: Optional[PaddiConfig] = None) -> "Redactor":
+        """Create a redactor from the ``[redaction]`` section of paddi.toml.
+
+        Raises:
+            ConfigurationError: If a detector is unknown or a pattern is not a valid regex
+        """
+        section = (config or PaddiConfig.load()).section("redaction")
+        names = section.get("detectors", list(BUILTIN_DETECTORS))
+        unknown = [name for name in names if name not in BUILTIN_DETECTORS]
+        if unknown:
+            raise ConfigurationError(
+                "redaction.detectors",
+                {"error": f"unknown detector(s): {', '.join(unknown)}"},
+            )
+
+        detectors = {name: re.compile(BUILTIN_DETECTORS[name]) for name in names}
+        for name, regex in section.get("patterns", {}).items():
+            try:
+                detectors[name] = re.compile(regex)
+            except re.error as e:
+                raise ConfigurationError(
+                    f"redaction.patterns.{name}", {"error": f"invalid regex: {e}"}
+                ) from e
+        return cls(detectors, enabled=bool(section.get("enabled", True)))
+
+    def redact(
+        self, data: Any, placeholders: Optional[Dict[Tuple[str, str], str]] = None
+    ) -> Tuple[Any, RedactionReport]:
+        """Return a redacted copy of the data and a report of what was replaced.
+
+        Args:
+            data: JSON-like data to redact
+            placeholders: Placeholder by (detector, value), filled in as values are found;
+                share it between calls so a value gets the same placeholder in each
+        """
+        report = RedactionReport()
+        if not self.enabled:
+            return data, report
+        if placeholders is None:
+            placeholders = {}
+        return self._redact(data, "$", report, placeholders), report
+
+    def _redact(self, value: Any, path: str, report: RedactionReport, placeholders) -> Any:
+        """Redact a value, recursing into tables and arrays."""
+        if isinstance(value, dict):
+            return {
+                key: self._redact(item, f"{path}.{key}", report, placeholders)
+                for key, item in value.items()
+            }
+        if isinstance(value, list):
+            return [
+                self._redact(item, f"{path}[{i}]", report, placeholders)
+                for i, item in enumerate(value)
+            ]
+        if not isinstance(value, str):
+            return value
+
+        for name, pattern in self.detectors.items():
+
+            def replace(match, name=name):
+                key = (name, match.group(0))
+                if key not in placeholders:
+                    number = sum(1 for detector, _ in placeholders if detector == name) + 1
+                    placeholders[key] = f"[REDACTED:{name}:{number}]"
+                report.counts[name] = report.counts.get(name, 0) + 1
+                report.locations.append({"path": path, "detector": name})
+                return placeholders[key]
+
+            value = pattern.sub(replace, value)
+        return value
+
+
+def restore(data: Any, placeholders: Dict[Tuple[str, str], str]) -> Any:
+    """Return a copy of the data with placeholders replaced by the values they stand for."""
+    if isinstance(data, dict):
+        return {key: restore(item, placeholders) for key, item in data.items()}
+    if isinstance(data, list):
+        return [restore(item, placeholders) for item in data]
+    if not isinstance(data, str):
+        return data
+    for (_, value), placeholder in placeholders.items():
+        data = data.replace(placeholder, value)
+    return data
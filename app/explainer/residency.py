@@ -0,0 +1,75 @@
+"""Data residency enforcement: keep analysis on machines the user controls.
+
+With ``local_only`` set, Paddi refuses to start any analysis backend that is
+not local, and fails closed if the Ollama endpoint points at a hosted
+service::
+
+    [llm]
+    local_only = true
+    local_hosts = ["ollama.internal"]  # more hosts to treat as local
+
+Loopback and private (RFC 1918 / unique local) addresses are local. Host
+names are not resolved: apart from ``localhost``, they must be listed in
+``local_hosts``.
+"""
+
+import ipaddress
+import logging
+from typing import Optional
+from urllib.parse import urlparse
+
+from app.common.exceptions import ConfigurationError
+from app.config.paddi_config import PaddiConfig
+
+logger = logging.getLogger(__name__)
+
+# Providers that run on this machine or network; all others are hosted
+LOCAL_PROVIDERS = ("ollama",)
+
+
+def is_local_endpoint(url: str, local_hosts=()) -> bool:
+    """Return whether an endpoint URL is on a loopback, private, or listed host."""
+    host = (urlparse(url if "//" in url else f"//{url}").hostname or "").lower()
+    if host == "localhost" or host in {h.lower() for h in local_hosts}:
+        return True
+    try:
+        address = ipaddress.ip_address(host)
+    except ValueError:
+        return False
+    return address.is_loopback or address.is_private
+
+
+def enforce_local_only(
+    provider: str, endpoint: Optional[str] = None, config: Optional[PaddiConfig] = None
+) -> None:
+    """Refuse a hosted analysis backend when ``llm.local_only`` is set.
+
+    Args:
+        provider: AI provider about to be used
+        endpoint: Its endpoint URL, for local providers
+        config: Loaded configuration (loaded from paddi.toml if omitted)
+
+    Raises:
+        ConfigurationError: If local-only mode is on and the backend is not local
+    """
+    section = (config or PaddiConfig.load()).section("llm")
+    if not section.get("local_only", False):
+        return
+
+    if provider not in LOCAL_PROVIDERS:
+        raise ConfigurationError(
+            "llm.local_only",
+            {
+                "error": f"AI provider '{provider}' is hosted; local-only mode allows: "
+                f"{', '.join(LOCAL_PROVIDERS)}"
+            },
+        )
+    if endpoint and not is_local_endpoint(endpoint, section.get("local_hosts", [])):
+        raise ConfigurationError(
+            "llm.local_only",
+            {
+                "error": f"endpoint {endpoint} is not local "
+                "(add its host to llm.local_hosts if it runs on your network)"
+            },
+        )
+    logger.debug("Local-only mode: using %s at %s", provider, endpoint)
@@ -0,0 +1,464 @@
+"""Rule-only analysis, used when the LLM backend is unavailable.
+
+Findings come from fixed rules instead of an LLM: primitive roles and public
+members in IAM bindings, and the findings that the cloud's own security service
+(Security Command Center, Security Hub, Defender) already reported. The
+explanations are generic, and every finding has ``source = "rules"``.
+
+Firewall rules that open sensitive ports to the internet, public bucket grants,
+buckets, GKE clusters, and Cloud SQL instances that miss hardening settings,
+and suspicious activity flagged in the audit logs become findings whichever
+backend analyzes the rest (see ``native_findings``).
+"""
+
+import ipaddress
+import logging
+from typing import Any, Dict, List, Optional
+
+from app.collector.firewall_collector import OPEN_SOURCE_RANGES, SENSITIVE_PORTS
+from app.common.models import SEVERITY_ORDER, SecurityFinding
+
+logger = logging.getLogger(__name__)
+
+RULES_SOURCE = "rules"
+
+# Basic roles that grant broad access to every resource in a project
+PRIMITIVE_ROLES = {"roles/owner": "HIGH", "roles/editor": "MEDIUM"}
+PUBLIC_MEMBERS = ("allUsers", "allAuthenticatedUsers")
+
+# Cloud SQL authorized networks with shorter prefixes are too broad
+BROAD_PREFIX_LENGTH = 16
+
+# Suspicious audit log activity (see app.collector.audit_logs): title and recommendation
+ACTIVITY_PATTERNS = {
+    "iam_policy_change_by_new_principal": (
+        "IAM policy changed by an unfamiliar principal",
+        "Confirm the change was authorized. If not, revert it and disable the principal; "
+        "otherwise add it to [collector.audit_logs] known_principals.",
+    ),
+    "service_account_key_created": (
+        "Service account key created",
+        "Confirm the key is needed, and prefer Workload Identity or impersonation over "
+        "long-lived keys.",
+    ),
+    "audit_logging_changed": (
+        "Audit log sink or exclusion changed",
+        "Confirm the change was authorized, and restore the sink or remove the exclusion "
+        "if it was not.",
+    ),
+    "repeated_permission_denied": (
+        "Repeated permission denials",
+        "Check whether the principal is probing for access, e.g. with stolen credentials.",
+    ),
+}
+ACTIVITY_SOURCE = "audit_logs"
+
+# Remote administration ports; exposing them is rated higher than other sensitive ports
+ADMIN_PORTS = (22, 23, 3389)
+
+
+def _severity(value: Any) -> str:
+    """Normalize a severity reported by a cloud security service."""
+    severity = str(value or "").upper()
+    return severity if severity in SEVERITY_ORDER else "MEDIUM"
+
+
+def _finding(
+    resource: str,
+    title: str,
+    severity: str,
+    explanation: str,
+    recommendation: str,
+    category: str,
+) -> SecurityFinding:
+    """Build a rule finding on a resource."""
+    return SecurityFinding(
+        title=title,
+        severity=severity,
+        explanation=explanation,
+        recommendation=recommendation,
+        category=category,
+        resource=resource,
+        source=RULES_SOURCE,
+    )
+
+
+def _prefix_length(network: str) -> int:
+    """Prefix length of a CIDR block; a bare address counts as a single host."""
+    try:
+        return ipaddress.ip_network(network, strict=False).prefixlen
+    except ValueError:
+        return 128
+
+
+class RuleBasedAnalyzer:
+    """Analyzes collected data with fixed rules, without an LLM."""
+
+    def analyze_security_risks(self, configuration: Dict[str, Any]) -> List[SecurityFinding]:
+        """Analyze single-provider or multi-cloud collected data."""
+        sources = configuration.get("providers") or [configuration]
+        findings = []
+        for data in sources:
+            if "error" in data:
+                continue
+            findings.extend(self.iam_findings(data.get("iam_policies") or {}))
+            findings.extend(
+                self.reported_findings(
+                    data.get("scc_findings") or data.get("security_findings") or []
+                )
+            )
+        logger.info("Rule-only analysis found %d issues", len(findings))
+        return findings
+
+    def iam_findings(self, policy: Dict[str, Any]) -> List[SecurityFinding]:
+        """Flag public members and primitive roles in IAM bindings."""
+        resource = policy.get("resource") or "project"
+        findings = []
+        for binding in policy.get("bindings") or []:
+            role = binding.get("role", "")
+            members = binding.get("members") or []
+            public = [member for member in members if member in PUBLIC_MEMBERS]
+            if public:
+                findings.append(
+                    SecurityFinding(
+                        title=f"Public access granted with {role}",
+                        severity="CRITICAL",
+                        explanation=(
+                            f"{', '.join(public)} can use {role}, so anyone on the internet "
+                            "has this role's permissions."
+                        ),
+                        recommendation=f"Remove {', '.join(public)} from the {role} binding.",
+                        category="PUBLIC_ACCESS",
+                        resource=resource,
+                        source=RULES_SOURCE,
+                    )
+                )
+            if role in PRIMITIVE_ROLES:
+                findings.append(
+                    SecurityFinding(
+                        title=f"Primitive role {role} granted",
+                        severity=PRIMITIVE_ROLES[role],
+                        explanation=(
+                            f"{role} grants broad access to every resource in the project. "
+                            f"Members: {', '.join(members)}."
+                        ),
+                        recommendation=(
+                            f"Replace {role} with predefined or custom roles that grant "
+                            "only the permissions each member needs."
+                        ),
+                        category="OVERPRIVILEGED_ROLE",
+                        resource=resource,
+                        source=RULES_SOURCE,
+                    )
+                )
+        return findings
+
+    def native_findings(self, configuration: Dict[str, Any]) -> List[SecurityFinding]:
+        """Built-in findings on collected network, GKE, Cloud SQL, bucket, and audit log data."""
+        project = (configuration.get("metadata") or {}).get("project_id")
+        return (
+            self.firewall_findings(configuration.get("firewall_rules") or [], project)
+            + self.gke_findings(configuration.get("gke_clusters") or [], project)
+            + self.sql_findings(configuration.get("sql_instances") or [], project)
+            + self.bucket_findings(
+                configuration.get("buckets") or [], configuration.get("bucket_iam_bindings") or []
+            )
+            + self.activity_findings(configuration.get("audit_logs") or {})
+        )
+
+    def firewall_findings(
+        self, rules: List[Dict[str, Any]], project: Optional[str] = None
+    ) -> List[SecurityFinding]:
+        """Flag firewall rules that open sensitive ports to the internet."""
+        findings = []
+        for rule in rules:
+            ports = rule.get("exposed_ports")
+            if not ports:
+                continue
+            names = ", ".join(
+                f"{port} ({SENSITIVE_PORTS[port]})" if port in SENSITIVE_PORTS else str(port)
+                for port in ports
+            )
+            sources = ", ".join(rule.get("source_ranges") or [])
+            findings.append(
+                SecurityFinding(
+                    title=f"Firewall rule {rule.get('name')} opens sensitive ports to the internet",
+                    severity="HIGH" if set(ports) & set(ADMIN_PORTS) else "MEDIUM",
+                    explanation=(
+                        f"Ingress from {sources} is allowed to port {names} on network "
+                        f"{rule.get('network') or 'default'}, so anyone can reach these "
+                        "services and try to log in or exploit them."
+                    ),
+                    recommendation=(
+                        "Restrict the rule's source ranges to the addresses that need access, "
+                        "or use IAP TCP forwarding (35.235.240.0/20) for administration."
+                    ),
+                    category="OPEN_FIREWALL",
+                    resource=(
+                        f"projects/{rule.get('project') or project or '-'}/global/firewalls/"
+                        f"{rule.get('name')}"
+                    ),
+                    source=RULES_SOURCE,
+                    ports=list(ports),
+                )
+            )
+        return findings
+
+    def gke_findings(
+        self, clusters: List[Dict[str, Any]], project: Optional[str] = None
+    ) -> List[SecurityFinding]:
+        """Flag GKE clusters and node pools that miss hardening settings."""
+        findings = []
+        for cluster in clusters:
+            name = cluster.get("name")
+            resource = (
+                f"projects/{cluster.get('project') or project or '-'}/locations/"
+                f"{cluster.get('location')}/clusters/{name}"
+            )
+            networks = cluster.get("authorized_networks")
+            if cluster.get("public_endpoint") and (
+                networks is None or any(n in OPEN_SOURCE_RANGES for n in networks)
+            ):
+                findings.append(
+                    _finding(
+                        resource,
+                        f"GKE cluster {name} has a public control plane endpoint",
+                        "HIGH",
+                        "Any address on the internet can reach the Kubernetes API server, "
+                        "so a leaked credential or an API server vulnerability is directly "
+                        "exploitable.",
+                        "Enable authorized networks limited to your administration ranges, "
+                        "or use a private endpoint.",
+                        "GKE_PUBLIC_ENDPOINT",
+                    )
+                )
+            if cluster.get("legacy_abac"):
+                findings.append(
+                    _finding(
+                        resource,
+                        f"GKE cluster {name} uses legacy ABAC authorization",
+                        "HIGH",
+                        "Legacy ABAC grants broad, static permissions that RBAC policies "
+                        "cannot restrict.",
+                        "Disable legacy authorization and grant access with Kubernetes RBAC.",
+                        "GKE_LEGACY_ABAC",
+                    )
+                )
+            if not cluster.get("workload_identity_pool"):
+                findings.append(
+                    _finding(
+                        resource,
+                        f"GKE cluster {name} does not use Workload Identity",
+                        "MEDIUM",
+                        "Pods use the node's service account, so every workload on a node "
+                        "shares its permissions and can read the metadata server.",
+                        "Enable Workload Identity and map each workload to its own service "
+                        "account.",
+                        "GKE_WORKLOAD_IDENTITY_DISABLED",
+                    )
+                )
+            for pool in cluster.get("node_pools") or []:
+                if pool.get("auto_upgrade"):
+                    continue
+                findings.append(
+                    _finding(
+                        f"{resource}/nodePools/{pool.get('name')}",
+                        f"Node pool {pool.get('name')} of GKE cluster {name} does not "
+                        "auto-upgrade",
+                        "MEDIUM",
+                        "Nodes keep running outdated versions, missing security patches "
+                        "for the kubelet, container runtime, and OS.",
+                        "Enable node auto-upgrade, ideally with a release channel.",
+                        "GKE_NODE_AUTO_UPGRADE_DISABLED",
+                    )
+                )
+        return findings
+
+    def sql_findings(
+        self, instances: List[Dict[str, Any]], project: Optional[str] = None
+    ) -> List[SecurityFinding]:
+        """Flag Cloud SQL instances that are exposed, unencrypted in transit, or unrecoverable."""
+        findings = []
+        for instance in instances:
+            name = instance.get("name")
+            resource = f"projects/{instance.get('project') or project or '-'}/instances/{name}"
+            broad = [
+                network
+                for network in instance.get("authorized_networks") or []
+                if _prefix_length(network) < BROAD_PREFIX_LENGTH
+            ]
+            if instance.get("public_ip"):
+                findings.append(
+                    _finding(
+                        resource,
+                        f"Cloud SQL instance {name} has a public IP address",
+                        "MEDIUM",
+                        "The instance is reachable from the internet, so its exposure "
+                        "depends only on authorized networks and database credentials.",
+                        "Connect over a private IP, or through the Cloud SQL Auth Proxy, "
+                        "and remove the public IP.",
+                        "SQL_PUBLIC_IP",
+                    )
+                )
+            if instance.get("public_ip") and broad:
+                is_open = any(network in OPEN_SOURCE_RANGES for network in broad)
+                findings.append(
+                    _finding(
+                        resource,
+                        f"Cloud SQL instance {name} authorizes broad networks",
+                        "HIGH" if is_open else "MEDIUM",
+                        f"Connections are allowed from {', '.join(broad)}, so "
+                        f"{'anyone on the internet' if is_open else 'a large address range'} "
+                        "can try to log in to the database.",
+                        "Authorize only the addresses of the clients that connect, "
+                        "or use the Cloud SQL Auth Proxy instead.",
+                        "SQL_BROAD_AUTHORIZED_NETWORK",
+                    )
+                )
+            if not instance.get("ssl_required"):
+                findings.append(
+                    _finding(
+                        resource,
+                        f"Cloud SQL instance {name} does not require SSL",
+                        "MEDIUM",
+                        "Clients may connect without TLS, so credentials and data can be "
+                        "read or altered in transit.",
+                        "Set the SSL mode to ENCRYPTED_ONLY, or require client certificates.",
+                        "SQL_SSL_NOT_REQUIRED",
+                    )
+                )
+            # Read replicas are backed up through their primary
+            if instance.get("replica"):
+                continue
+            if not instance.get("backups_enabled"):
+                findings.append(
+                    _finding(
+                        resource,
+                        f"Cloud SQL instance {name} has automated backups disabled",
+                        "MEDIUM",
+                        "Data lost to deletion, corruption, or ransomware cannot be restored.",
+                        "Enable automated backups with a retention that meets your "
+                        "recovery objectives.",
+                        "SQL_BACKUPS_DISABLED",
+                    )
+                )
+            elif not instance.get("point_in_time_recovery"):
+                findings.append(
+                    _finding(
+                        resource,
+                        f"Cloud SQL instance {name} has point-in-time recovery disabled",
+                        "LOW",
+                        "The instance can only be restored to its last backup, losing every "
+                        "change made since.",
+                        "Enable point-in-time recovery (binary logging for MySQL).",
+                        "SQL_POINT_IN_TIME_RECOVERY_DISABLED",
+                    )
+                )
+        return findings
+
+    def bucket_findings(
+        self, buckets: List[Dict[str, Any]], bindings: List[Dict[str, Any]]
+    ) -> List[SecurityFinding]:
+        """Flag public bucket grants, and buckets without public access protections.
+
+        Public grants on a bucket that enforces public access prevention have
+        no effect, so they are not flagged.
+        """
+        settings = {bucket.get("name"): bucket for bucket in buckets}
+        findings = []
+        for binding in bindings:
+            name = binding.get("bucket")
+            public = [m for m in binding.get("members") or [] if m in PUBLIC_MEMBERS]
+            if not public or settings.get(name, {}).get("public_access_prevention") == "enforced":
+                continue
+            role = binding.get("role")
+            findings.append(
+                _finding(
+                    f"//storage.googleapis.com/{name}",
+                    f"Bucket {name} is public through {role}",
+                    "CRITICAL",
+                    f"{', '.join(public)} can use {role} on the bucket, so anyone on the "
+                    "internet has this role's access to its objects.",
+                    f"Remove {', '.join(public)} from the {role} binding, and enforce public "
+                    "access prevention unless the bucket must be public.",
+                    "PUBLIC_BUCKET",
+                )
+            )
+        for bucket in buckets:
+            name = bucket.get("name")
+            resource = f"//storage.googleapis.com/{name}"
+            if bucket.get("public_access_prevention") != "enforced":
+                findings.append(
+                    _finding(
+                        resource,
+                        f"Bucket {name} does not enforce public access prevention",
+                        "LOW",
+                        "Nothing stops an IAM binding or ACL from making the bucket or its "
+                        "objects public later.",
+                        "Enforce public access prevention on the bucket, or on the "
+                        "organization with the storage.publicAccessPrevention constraint.",
+                        "BUCKET_PUBLIC_ACCESS_PREVENTION_OFF",
+                    )
+                )
+            if not bucket.get("uniform_bucket_level_access"):
+                findings.append(
+                    _finding(
+                        resource,
+                        f"Bucket {name} does not use uniform bucket-level access",
+                        "MEDIUM",
+                        "Object ACLs can grant access that the bucket's IAM policy does not "
+                        "show, so access is hard to audit.",
+                        "Enable uniform bucket-level access, after moving any access granted "
+                        "by ACLs into IAM.",
+                        "BUCKET_UNIFORM_ACCESS_DISABLED",
+                    )
+                )
+        return findings
+
+    def activity_findings(self, audit_logs: Dict[str, Any]) -> List[SecurityFinding]:
+        """Turn suspicious audit log activity into findings."""
+        findings = []
+        for activity in audit_logs.get("suspicious") or []:
+            title, recommendation = ACTIVITY_PATTERNS.get(
+                activity.get("pattern"),
+                ("Suspicious activity", "Confirm this activity was authorized."),
+            )
+            explanation = activity.get("description", "")
+            if activity.get("timestamp"):
+                explanation += f" Last seen at {activity['timestamp']}"
+                if activity.get("caller_ip"):
+                    explanation += f" from {activity['caller_ip']}"
+                explanation += "."
+            findings.append(
+                SecurityFinding(
+                    title=title,
+                    severity=_severity(activity.get("severity")),
+                    explanation=explanation,
+                    recommendation=recommendation,
+                    category="SUSPICIOUS_ACTIVITY",
+                    resource=activity.get("resource"),
+                    occurrences=activity.get("count") or 1,
+                    source=ACTIVITY_SOURCE,
+                )
+            )
+        return findings
+
+    def reported_findings(self, reported: List[Dict[str, Any]]) -> List[SecurityFinding]:
+        """Keep the findings that the cloud security service reported, as they are."""
+        findings = []
+        for entry in reported:
+            category = entry.get("category") or entry.get("type") or "SECURITY_FINDING"
+            findings.append(
+                SecurityFinding(
+                    title=entry.get("title") or str(category).replace("_", " ").title(),
+                    severity=_severity(entry.get("severity")),
+                    explanation=entry.get("description")
+                    or "Reported by the cloud provider's security service.",
+                    recommendation=entry.get("recommendation")
+                    or "Review this finding in the cloud provider's security console.",
+                    category=str(category).upper(),
+                    resource=entry.get("resource_name") or entry.get("resource"),
+                    source=RULES_SOURCE,
+                )
+            )
+        return findings
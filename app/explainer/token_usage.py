@@ -0,0 +1,55 @@
+"""Token usage of the LLM calls made during an analysis.
+
+Counts come from the backend's response (Vertex AI ``usage_metadata``, Ollama
+``prompt_eval_count`` and ``eval_count``). When a response has no counts, they
+are estimated from the text length, and the usage is marked as estimated.
+"""
+
+from dataclasses import asdict, dataclass
+from typing import Any, Dict
+
+# A rough average for English and JSON text, used when the backend reports no counts
+CHARS_PER_TOKEN = 4
+
+
+def estimate_tokens(text: str) -> int:
+    """Estimate the number of tokens in a text."""
+    return (len(text) + CHARS_PER_TOKEN - 1) // CHARS_PER_TOKEN
+
+
+@dataclass
+class TokenUsage:
+    """Tokens sent to and received from an LLM backend."""
+
+    calls: int = 0
+    prompt_tokens: int = 0
+    completion_tokens: int = 0
+    estimated: bool = False
+
+    @property
+    def total_tokens(self) -> int:
+        """Return the prompt and completion tokens together."""
+        return self.prompt_tokens + self.completion_tokens
+
+    def add(
+        self,
+        prompt: str,
+        completion: str = "",
+        prompt_tokens: Any = None,
+        completion_tokens: Any = None,
+    ) -> None:
+        """Count one call, estimating the counts the backend did not report."""
+        self.calls += 1
+        # Backends without usage reporting may return None or omit the fields
+        prompt_tokens = prompt_tokens if isinstance(prompt_tokens, int) else None
+        completion_tokens = completion_tokens if isinstance(completion_tokens, int) else None
+        if prompt_tokens is None or completion_tokens is None:
+            self.estimated = True
+        self.prompt_tokens += estimate_tokens(prompt) if prompt_tokens is None else prompt_tokens
+        self.completion_tokens += (
+            estimate_tokens(completion) if completion_tokens is None else completion_tokens
+        )
+
+    def to_dict(self) -> Dict[str, Any]:
+        """Convert to dictionary for JSON serialization."""
+        return {**asdict(self), "total_tokens": self.total_tokens}
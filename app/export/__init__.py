@@ -0,0 +1 @@
+"""Exporters that publish findings to external trackers and services."""
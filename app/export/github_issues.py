@@ -0,0 +1,192 @@
+"""Sync findings to GitHub Issues.
+
+Each finding gets one open issue, identified by a fingerprint marker in the
+issue body. Issues are opened for findings that have no open issue, and closed
+with a comment when their finding is no longer reported.
+
+Configured in paddi.toml::
+
+    [export.github_issues]
+    repo = "my-org/security-findings"
+    labels = ["security"]
+    min_severity = "MEDIUM"
+
+The token is read from ``GITHUB_TOKEN`` unless ``token_env`` names another
+environment variable.
+"""
+
+import logging
+import re
+from dataclasses import dataclass, field
+from typing import Any, Dict, List, Optional
+
+from app.common.exceptions import ConfigurationError
+from app.common.models import SEVERITY_ORDER, severity_rank
+from app.config.paddi_config import PaddiConfig
+from app.config.secrets import missing_secret_message, resolve_secret
+from app.findings.fingerprint import compute_fingerprint
+
+logger = logging.getLogger(__name__)
+
+PADDI_LABEL = "paddi"
+
+_MARKER = "<!-- paddi-fingerprint: {} -->"
+_MARKER_PATTERN = re.compile(r"<!-- paddi-fingerprint: ([0-9a-f]+) -->")
+
+
+@dataclass
+class IssueSyncResult:
+    """Issues changed by a sync."""
+
+    opened: List[str] = field(default_factory=list)
+    closed: List[str] = field(default_factory=list)
+    unchanged: int = 0
+
+
+def issue_title(finding: Dict[str, Any]) -> str:
+    """Build the issue title for a finding."""
+    title = f"[{finding.get('severity', 'INFO')}] {finding.get('title', 'Unknown Issue')}"
+    if finding.get("resource"):
+        title += f" ({finding['resource']})"
+    return title
+
+
+def issue_body(finding: Dict[str, Any], fingerprint: str) -> str:
+    """Build the issue body for a finding."""
+    lines = [
+        f"**Severity:** {finding.get('severity', 'INFO')}",
+        f"**Category:** {finding.get('category') or '-'}",
+        f"**Resource:** `{finding.get('resource') or '-'}`",
+    ]
+    if finding.get("cvss_score") is not None:
+        lines.append(f"**CVSS:** {finding['cvss_score']}")
+    lines.extend(
+        [
+            "",
+            "## Explanation",
+            "",
+            finding.get("explanation") or finding.get("description") or "-",
+            "",
+            "## Recommendation",
+            "",
+            finding.get("recommendation") or "-",
+            "",
+            "---",
+            "Opened by Paddi. This issue is closed automatically when the finding is resolved.",
+            _MARKER.format(fingerprint),
+        ]
+    )
+    return "\n".join(lines)
+
+
+def fingerprint_of_issue(body: Optional[str]) -> Optional[str]:
+    """Return the finding fingerprint recorded in an issue body."""
+    match = _MARKER_PATTERN.search(body or "")
+    return match.group(1) if match else None
+
+
+class GitHubIssuesExporter:
+    """Opens and closes GitHub issues to match the current findings."""
+
+    def __init__(
+        self,
+        repo: Any,
+        labels: Optional[List[str]] = None,
+        min_severity: str = "INFO",
+    ):
+        """Initialize GitHubIssuesExporter.
+
+        Args:
+            repo: PyGithub ``Repository`` (or an object with the same methods)
+            labels: Extra labels for opened issues
+            min_severity: Least severe finding that gets an issue
+        """
+        self.repo = repo
+        self.labels = list(labels or [])
+        self.min_severity = min_severity.upper()
+
+    @classmethod
+    def from_config(cls, config: Optional[PaddiConfig] = None) -> "GitHubIssuesExporter":
+        """Create an exporter from the ``[export.github_issues]`` section of paddi.toml.
+
+        Raises:
+            ConfigurationError: If the repository, token, or severity is missing or invalid
+        """
+        section = (config or PaddiConfig.load()).section("export.github_issues")
+        repo_name = section.get("repo")
+        if not repo_name or "/" not in repo_name:
+            raise ConfigurationError(
+                "export.github_issues.repo", {"error": "set repo = \"owner/name\""}
+            )
+
+        token_env = section.get("token_env", "GITHUB_TOKEN")
+        token = section.get("token") or resolve_secret(token_env)
+        if not token:
+            raise ConfigurationError(
+                "export.github_issues", {"error": missing_secret_message(token_env)}
+            )
+
+        min_severity = str(section.get("min_severity", "INFO")).upper()
+        if min_severity not in SEVERITY_ORDER:
+            raise ConfigurationError(
+                "export.github_issues.min_severity", {"error": f"invalid severity: {min_severity}"}
+            )
+
+        from github import Github
+
+        repo = Github(token).get_repo(repo_name)
+        return cls(repo, labels=section.get("labels"), min_severity=min_severity)
+
+    def _open_issues(self) -> Dict[str, Any]:
+        """Return open Paddi issues by finding fingerprint."""
+        issues = {}
+        for issue in self.repo.get_issues(state="open", labels=[PADDI_LABEL]):
+            fingerprint = fingerprint_of_issue(issue.body)
+            if fingerprint:
+                issues[fingerprint] = issue
+        return issues
+
+    def sync(self, findings: List[Dict[str, Any]], dry_run: bool = False) -> IssueSyncResult:
+        """Open issues for new findings and close issues for resolved ones.
+
+        Args:
+            findings: Current findings, deduplicated
+            dry_run: Compute the changes without writing to GitHub
+        """
+        limit = severity_rank(self.min_severity)
+        reported = set()
+        current = {}
+        for finding in findings:
+            fingerprint = finding.get("fingerprint") or compute_fingerprint(finding)
+            reported.add(fingerprint)
+            if severity_rank(finding.get("severity", "")) <= limit:
+                current[fingerprint] = finding
+
+        open_issues = self._open_issues()
+        result = IssueSyncResult()
+
+        for fingerprint, finding in current.items():
+            if fingerprint in open_issues:
+                result.unchanged += 1
+                continue
+            title = issue_title(finding)
+            result.opened.append(title)
+            if dry_run:
+                continue
+            labels = [PADDI_LABEL, f"severity:{str(finding.get('severity', 'INFO')).lower()}"]
+            issue = self.repo.create_issue(
+                title=title, body=issue_body(finding, fingerprint), labels=labels + self.labels
+            )
+            logger.info("Opened issue #%s: %s", issue.number, title)
+
+        for fingerprint, issue in open_issues.items():
+            if fingerprint in reported:
+                continue
+            result.closed.append(issue.title)
+            if dry_run:
+                continue
+            issue.create_comment("Paddi no longer reports this finding. Closing as resolved.")
+            issue.edit(state="closed")
+            logger.info("Closed issue #%s: %s", issue.number, issue.title)
+
+        return result
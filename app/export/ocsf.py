@@ -0,0 +1,112 @@
+"""Export findings in the Open Cybersecurity Schema Framework (OCSF) format.
+
+Each finding becomes an OCSF 1.1 Detection Finding (class 2004) event. Events
+are written as JSON Lines, which Amazon Security Lake custom sources and most
+OCSF-compatible data lakes ingest directly.
+"""
+
+import json
+import logging
+from datetime import datetime, timezone
+from pathlib import Path
+from typing import Any, Dict, List, Optional
+
+from app.findings.fingerprint import compute_fingerprint
+
+logger = logging.getLogger(__name__)
+
+OCSF_VERSION = "1.1.0"
+OCSF_FILE = "findings.ocsf.jsonl"
+PRODUCT_VERSION = "0.1"
+
+CATEGORY_FINDINGS = 2
+CLASS_DETECTION_FINDING = 2004
+ACTIVITY_CREATE = 1
+STATUS_NEW = 1
+
+SEVERITY_IDS = {
+    "INFO": 1,
+    "LOW": 2,
+    "MEDIUM": 3,
+    "HIGH": 4,
+    "CRITICAL": 5,
+}
+
+_SEVERITY_NAMES = {1: "Informational", 2: "Low", 3: "Medium", 4: "High", 5: "Critical"}
+
+_CLOUD_PROVIDERS = {"gcp": "GCP", "aws": "AWS", "azure": "Azure"}
+
+
+def ocsf_event(finding: Dict[str, Any], project_id: Optional[str], time_ms: int) -> Dict[str, Any]:
+    """Map a finding to an OCSF Detection Finding event."""
+    severity_id = SEVERITY_IDS.get(str(finding.get("severity", "")).upper(), 0)
+    fingerprint = finding.get("fingerprint") or compute_fingerprint(finding)
+    provider = _CLOUD_PROVIDERS.get(str(finding.get("provider", "gcp")).lower())
+
+    finding_info: Dict[str, Any] = {
+        "uid": fingerprint,
+        "title": finding.get("title") or "Unknown Issue",
+        "desc": finding.get("explanation") or finding.get("description") or "",
+        "created_time": time_ms,
+        "product_uid": "paddi",
+    }
+    if finding.get("category"):
+        finding_info["types"] = [finding["category"]]
+
+    resources = [
+        {"uid": resource, "type": "cloud_resource"}
+        for resource in (finding.get("affected_resources") or [finding.get("resource")])
+        if resource
+    ]
+
+    event: Dict[str, Any] = {
+        "category_uid": CATEGORY_FINDINGS,
+        "class_uid": CLASS_DETECTION_FINDING,
+        "activity_id": ACTIVITY_CREATE,
+        "type_uid": CLASS_DETECTION_FINDING * 100 + ACTIVITY_CREATE,
+        "time": time_ms,
+        "severity_id": severity_id,
+        "severity": _SEVERITY_NAMES.get(severity_id, "Unknown"),
+        "status_id": STATUS_NEW,
+        "message": finding.get("title") or "Unknown Issue",
+        "metadata": {
+            "version": OCSF_VERSION,
+            "product": {"name": "Paddi", "vendor_name": "Paddi", "version": PRODUCT_VERSION},
+        },
+        "finding_info": finding_info,
+        "resources": resources,
+        "unmapped": {"paddi_severity": finding.get("severity")},
+    }
+    if finding.get("recommendation"):
+        event["remediation"] = {"desc": finding["recommendation"]}
+    if provider:
+        event["cloud"] = {"provider": provider}
+        if project_id:
+            event["cloud"]["account"] = {"uid": project_id, "type": f"{provider} Account"}
+    if finding.get("cvss_score") is not None:
+        event["vulnerabilities"] = [
+            {"cve": {"cvss": [{"base_score": finding["cvss_score"], "version": "3.1"}]}}
+        ]
+    return event
+
+
+def build_events(
+    findings: List[Dict[str, Any]], project_id: Optional[str] = None
+) -> List[Dict[str, Any]]:
+    """Map findings to OCSF events sharing one event time."""
+    time_ms = int(datetime.now(timezone.utc).timestamp() * 1000)
+    return [ocsf_event(finding, project_id, time_ms) for finding in findings]
+
+
+def write_events(
+    findings: List[Dict[str, Any]], output_dir: Path, project_id: Optional[str] = None
+) -> Path:
+    """Write findings as OCSF JSON Lines and return the file path."""
+    output_dir = Path(output_dir)
+    output_dir.mkdir(parents=True, exist_ok=True)
+    path = output_dir / OCSF_FILE
+    with open(path, "w", encoding="utf-8") as f:
+        for event in build_events(findings, project_id):
+            f.write(json.dumps(event, ensure_ascii=False) + "\n")
+    logger.info("Wrote %d OCSF event(s) to %s", len(findings), path)
+    return path
@@ -0,0 +1,261 @@
+"""Post a findings-delta comment on the current pull or merge request.
+
+The pull request is detected from the CI environment:
+
+- GitHub Actions: ``GITHUB_REPOSITORY`` and the ``pull_request`` event in
+  ``GITHUB_EVENT_PATH`` (or a ``refs/pull/<n>/...`` ``GITHUB_REF``)
+- GitLab CI: ``CI_PROJECT_ID`` and ``CI_MERGE_REQUEST_IID``
+
+The comment carries a hidden marker, so later runs update it in place instead
+of adding a new comment. Options are set in paddi.toml::
+
+    [export.pr_comment]
+    max_findings = 20
+
+The token is read from ``GITHUB_TOKEN`` on GitHub and ``GITLAB_TOKEN`` on
+GitLab, unless ``token_env`` names another environment variable.
+"""
+
+import json
+import logging
+import os
+import re
+from dataclasses import dataclass
+from typing import Any, Dict, List, Mapping, Optional
+from urllib.parse import quote
+
+import requests
+
+from app.common.exceptions import ConfigurationError
+from app.config.paddi_config import PaddiConfig
+from app.config.secrets import missing_secret_message, resolve_secret
+from app.findings.listing import sort_findings
+
+logger = logging.getLogger(__name__)
+
+COMMENT_MARKER = "<!-- paddi-pr-comment -->"
+DEFAULT_MAX_FINDINGS = 20
+DEFAULT_TOKEN_ENV = {"github": "GITHUB_TOKEN", "gitlab": "GITLAB_TOKEN"}
+
+_PULL_REF = re.compile(r"^refs/pull/(\d+)/")
+
+
+@dataclass
+class PullRequest:
+    """The pull request (GitHub) or merge request (GitLab) of a CI run."""
+
+    platform: str
+    api_url: str
+    project: str
+    number: int
+
+    def __str__(self) -> str:
+        separator = "#" if self.platform == "github" else "!"
+        return f"{self.project}{separator}{self.number}"
+
+
+def _github_pr_number(env: Mapping[str, str]) -> Optional[int]:
+    """Read the pull request number of a GitHub Actions run."""
+    event_path = env.get("GITHUB_EVENT_PATH")
+    if event_path and os.path.exists(event_path):
+        with open(event_path, encoding="utf-8") as f:
+            event = json.load(f)
+        number = (event.get("pull_request") or {}).get("number")
+        if number:
+            return int(number)
+    match = _PULL_REF.match(env.get("GITHUB_REF", ""))
+    return int(match.group(1)) if match else None
+
+
+def detect_pull_request(env: Optional[Mapping[str, str]] = None) -> Optional[PullRequest]:
+    """Return the pull request of the current CI run, if it runs for one."""
+    env = os.environ if env is None else env
+    if env.get("GITHUB_ACTIONS") == "true" and env.get("GITHUB_REPOSITORY"):
+        number = _github_pr_number(env)
+        if number is None:
+            return None
+        return PullRequest(
+            platform="github",
+            api_url=env.get("GITHUB_API_URL", "https://api.github.com").rstrip("/"),
+            project=env["GITHUB_REPOSITORY"],
+            number=number,
+        )
+    if env.get("GITLAB_CI") == "true" and env.get("CI_MERGE_REQUEST_IID"):
+        return PullRequest(
+            platform="gitlab",
+            api_url=env.get("CI_API_V4_URL", "https://gitlab.com/api/v4").rstrip("/"),
+            project=env.get("CI_PROJECT_ID", ""),
+            number=int(env["CI_MERGE_REQUEST_IID"]),
+        )
+    return None
+
+
+def _cell(value: Any) -> str:
+    """Make a value safe for a Markdown table cell."""
+    return str(value or "-").replace("|", "\\|").replace("\n", " ")
+
+
+def _table(findings: List[Dict[str, Any]], max_findings: int) -> List[str]:
+    """Render findings as a Markdown table, most severe first."""
+    ordered = sort_findings(findings)
+    lines = ["| Severity | Finding | Resource |", "|---|---|---|"]
+    lines.extend(
+        f"| {_cell(f.get('severity'))} | {_cell(f.get('title'))} | `{_cell(f.get('resource'))}` |"
+        for f in ordered[:max_findings]
+    )
+    if len(ordered) > max_findings:
+        lines.append(f"\n…and {len(ordered) - max_findings} more")
+    return lines
+
+
+def comment_body(
+    new: List[Dict[str, Any]],
+    resolved: List[Dict[str, Any]],
+    total: int,
+    reference: Optional[str] = None,
+    max_findings: int = DEFAULT_MAX_FINDINGS,
+) -> str:
+    """Build the comment for the findings that are new or resolved.
+
+    Args:
+        new: Findings that are not in the reference run
+        resolved: Reference findings that are no longer reported
+        total: Number of current findings
+        reference: The run compared with, e.g. "baseline run #3"; without one,
+            every current finding counts as new
+        max_findings: Rows shown per table
+    """
+    compared = f"Compared with {reference}" if reference else "No earlier run to compare with"
+    lines = [
+        COMMENT_MARKER,
+        "## Paddi security findings",
+        "",
+        f"{compared}: **{len(new)} new**, **{len(resolved)} resolved**, {total} total.",
+        "",
+    ]
+    if new:
+        lines.extend(["### New findings", "", *_table(new, max_findings), ""])
+    if resolved:
+        lines.extend(["### Resolved findings", "", *_table(resolved, max_findings), ""])
+    if not new and not resolved:
+        lines.extend(["No findings were added or resolved.", ""])
+    return "\n".join(lines)
+
+
+class PRCommentExporter:
+    """Creates or updates the Paddi comment on a pull or merge request."""
+
+    def __init__(
+        self,
+        pull_request: PullRequest,
+        token: str,
+        max_findings: int = DEFAULT_MAX_FINDINGS,
+        timeout: float = 10.0,
+    ):
+        """Initialize PRCommentExporter.
+
+        Args:
+            pull_request: Where to comment
+            token: GitHub token, or GitLab token with the ``api`` scope
+            max_findings: Rows shown per table in the comment
+            timeout: Seconds per request
+        """
+        self.pull_request = pull_request
+        self.token = token
+        self.max_findings = max_findings
+        self.timeout = timeout
+
+    @classmethod
+    def from_config(
+        cls, config: Optional[PaddiConfig] = None, env: Optional[Mapping[str, str]] = None
+    ) -> "PRCommentExporter":
+        """Create an exporter for the current CI run and ``[export.pr_comment]``.
+
+        Raises:
+            ConfigurationError: If no pull request is detected or the token is missing
+        """
+        section = (config or PaddiConfig.load()).section("export.pr_comment")
+        pull_request = detect_pull_request(env)
+        if pull_request is None:
+            raise ConfigurationError(
+                "export.pr_comment",
+                {"error": "no pull request in the CI environment (GitHub Actions or GitLab CI)"},
+            )
+
+        token_env = section.get("token_env", DEFAULT_TOKEN_ENV[pull_request.platform])
+        token = section.get("token") or resolve_secret(token_env)
+        if not token:
+            raise ConfigurationError(
+                "export.pr_comment", {"error": missing_secret_message(token_env)}
+            )
+
+        return cls(
+            pull_request,
+            token,
+            max_findings=int(section.get("max_findings", DEFAULT_MAX_FINDINGS)),
+        )
+
+    def _request(self, method: str, path: str, **kwargs) -> Any:
+        """Call the platform API and return the decoded response."""
+        if self.pull_request.platform == "github":
+            headers = {
+                "Authorization": f"Bearer {self.token}",
+                "Accept": "application/vnd.github+json",
+            }
+        else:
+            headers = {"PRIVATE-TOKEN": self.token}
+        response = requests.request(
+            method,
+            f"{self.pull_request.api_url}{path}",
+            headers=headers,
+            timeout=self.timeout,
+            **kwargs,
+        )
+        response.raise_for_status()
+        return response.json()
+
+    def _comments_path(self) -> str:
+        """Return the API path of the pull request's comments."""
+        pr = self.pull_request
+        if pr.platform == "github":
+            return f"/repos/{pr.project}/issues/{pr.number}/comments"
+        project = quote(pr.project, safe="")
+        return f"/projects/{project}/merge_requests/{pr.number}/notes"
+
+    def _existing_comment(self) -> Optional[Dict[str, Any]]:
+        """Return the earlier Paddi comment, if there is one."""
+        page = 1
+        while True:
+            comments = self._request(
+                "GET", self._comments_path(), params={"per_page": 100, "page": page}
+            )
+            for comment in comments:
+                if COMMENT_MARKER in (comment.get("body") or ""):
+                    return comment
+            if len(comments) < 100:
+                return None
+            page += 1
+
+    def post(self, body: str) -> str:
+        """Create the comment, or update the earlier one.
+
+        Returns:
+            "created" or "updated"
+
+        Raises:
+            requests.HTTPError: If the API rejects a request
+        """
+        existing = self._existing_comment()
+        if existing is None:
+            self._request("POST", self._comments_path(), json={"body": body})
+            logger.info("Commented on %s", self.pull_request)
+            return "created"
+
+        if self.pull_request.platform == "github":
+            path = f"/repos/{self.pull_request.project}/issues/comments/{existing['id']}"
+            self._request("PATCH", path, json={"body": body})
+        else:
+            path = f"{self._comments_path()}/{existing['id']}"
+            self._request("PUT", path, json={"body": body})
+        logger.info("Updated the comment on %s", self.pull_request)
+        return "updated"
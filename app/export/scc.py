@@ -0,0 +1,226 @@
+"""Write findings back to Security Command Center.
+
+Findings are created in a custom SCC source, so they appear next to the
+built-in detectors. Each finding ID is derived from the Paddi fingerprint, so
+later runs update the same SCC finding instead of creating duplicates, and
+findings that are no longer reported are set to INACTIVE. Configured in
+paddi.toml::
+
+    [export.scc]
+    source = "organizations/123456789/sources/987654321"
+
+The source must already exist (``gcloud scc sources create`` is not available,
+so create it once with the API). The credentials need
+``roles/securitycenter.findingsEditor`` on the organization. Transient API
+errors are retried as configured in ``[execution]`` (see :mod:`app.common.retry`).
+"""
+
+import logging
+import re
+from dataclasses import dataclass, field
+from datetime import datetime, timezone
+from typing import Any, Dict, List, Optional
+
+from google.api_core import exceptions as gcp_exceptions
+
+from app.common.exceptions import ConfigurationError
+from app.common.retry import RetryPolicy
+from app.config.paddi_config import PaddiConfig
+from app.findings.fingerprint import compute_fingerprint
+
+logger = logging.getLogger(__name__)
+
+FINDING_ID_PREFIX = "paddi"
+
+# SCC has no INFO severity; informational findings are reported as LOW
+SEVERITY_MAP = {
+    "CRITICAL": "CRITICAL",
+    "HIGH": "HIGH",
+    "MEDIUM": "MEDIUM",
+    "LOW": "LOW",
+    "INFO": "LOW",
+}
+
+_SOURCE_PATTERN = re.compile(r"^(organizations|folders|projects)/[^/]+/sources/[0-9]+$")
+
+# Fields replaced when an existing SCC finding is updated
+_UPDATE_MASK = "state,severity,category,event_time,source_properties,external_uri"
+
+
+@dataclass
+class SCCSyncResult:
+    """SCC findings changed by a sync."""
+
+    created: List[str] = field(default_factory=list)
+    updated: List[str] = field(default_factory=list)
+    deactivated: List[str] = field(default_factory=list)
+    # Transient API errors that were retried
+    retries: int = 0
+
+
+def finding_id(fingerprint: str) -> str:
+    """Return the SCC finding ID for a Paddi fingerprint (alphanumeric, at most 32 chars)."""
+    return f"{FINDING_ID_PREFIX}{fingerprint}"[:32]
+
+
+def resource_name(finding: Dict[str, Any], project_id: Optional[str]) -> str:
+    """Return the full resource name SCC attaches the finding to."""
+    resource = finding.get("resource") or ""
+    if resource.startswith("//"):
+        return resource
+    if resource.startswith("projects/"):
+        return f"//cloudresourcemanager.googleapis.com/{resource}"
+    return f"//cloudresourcemanager.googleapis.com/projects/{project_id or 'unknown'}"
+
+
+def scc_finding(
+    finding: Dict[str, Any], fingerprint: str, project_id: Optional[str], event_time: datetime
+) -> Dict[str, Any]:
+    """Map a Paddi finding to an SCC finding."""
+    properties = {
+        "paddi_fingerprint": fingerprint,
+        "title": finding.get("title") or "",
+        "explanation": finding.get("explanation") or finding.get("description") or "",
+        "recommendation": finding.get("recommendation") or "",
+    }
+    if finding.get("resource"):
+        properties["resource"] = finding["resource"]
+
+    return {
+        "state": "ACTIVE",
+        "resource_name": resource_name(finding, project_id),
+        "category": finding.get("category") or "PADDI_FINDING",
+        "severity": SEVERITY_MAP.get(str(finding.get("severity", "")).upper(), "LOW"),
+        "event_time": event_time,
+        "source_properties": properties,
+        "external_uri": finding.get("url") or "",
+    }
+
+
+class SCCExporter:
+    """Creates, updates, and deactivates findings in a custom SCC source."""
+
+    def __init__(self, source: str, client: Any = None, retry: Optional[RetryPolicy] = None):
+        """Initialize SCCExporter.
+
+        Args:
+            source: Source name, ``organizations/<org>/sources/<id>``
+            client: ``securitycenter_v1.SecurityCenterClient`` (created on first use)
+            retry: Retries transient API errors (defaults to 3 retries)
+        """
+        self.source = source
+        self._client = client
+        self.retry = retry or RetryPolicy()
+
+    @classmethod
+    def from_config(cls, config: Optional[PaddiConfig] = None) -> "SCCExporter":
+        """Create an exporter from the ``[export.scc]`` section of paddi.toml.
+
+        Raises:
+            ConfigurationError: If the source is missing or malformed
+        """
+        config = config or PaddiConfig.load()
+        section = config.section("export.scc")
+        source = section.get("source") or ""
+        if not _SOURCE_PATTERN.match(source):
+            raise ConfigurationError(
+                "export.scc.source",
+                {"error": 'set source = "organizations/<org>/sources/<id>"'},
+            )
+        return cls(source, retry=RetryPolicy.from_config(config))
+
+    @property
+    def client(self) -> Any:
+        """Return the Security Command Center client."""
+        if self._client is None:
+            from google.cloud import securitycenter_v1
+
+            self._client = securitycenter_v1.SecurityCenterClient()
+        return self._client
+
+    def _active_findings(self) -> Dict[str, str]:
+        """Return active findings of the source as ``{finding ID: finding name}``."""
+        active = {}
+        request = {"parent": self.source, "filter": 'state="ACTIVE"'}
+        # Listing the pages inside the retry, as each page is a separate request
+        results = self.retry.call(
+            "scc.list_findings", lambda: list(self.client.list_findings(request=request))
+        )
+        for result in results:
+            name = result.finding.name
+            finding_id_ = name.rsplit("/", 1)[-1]
+            if finding_id_.startswith(FINDING_ID_PREFIX):
+                active[finding_id_] = name
+        return active
+
+    def sync(
+        self,
+        findings: List[Dict[str, Any]],
+        project_id: Optional[str] = None,
+        dry_run: bool = False,
+    ) -> SCCSyncResult:
+        """Create or update SCC findings and deactivate those no longer reported.
+
+        Args:
+            findings: Current findings, deduplicated
+            project_id: Audited project, for findings without a full resource name
+            dry_run: Compute the changes without writing to SCC
+        """
+        now = datetime.now(timezone.utc)
+        retries_before = self.retry.stats.retries
+        active = self._active_findings()
+        result = SCCSyncResult()
+        current = set()
+
+        for finding in findings:
+            fingerprint = finding.get("fingerprint") or compute_fingerprint(finding)
+            scc_id = finding_id(fingerprint)
+            current.add(scc_id)
+            title = finding.get("title", "Unknown Issue")
+            body = scc_finding(finding, fingerprint, project_id, now)
+
+            if scc_id in active:
+                result.updated.append(title)
+                if not dry_run:
+                    body["name"] = active[scc_id]
+                    self._update(body)
+            else:
+                result.created.append(title)
+                if not dry_run:
+                    self._upsert(scc_id, body)
+
+        for scc_id, name in active.items():
+            if scc_id in current:
+                continue
+            result.deactivated.append(scc_id)
+            if not dry_run:
+                self.retry.call(
+                    "scc.set_finding_state",
+                    self.client.set_finding_state,
+                    request={"name": name, "state": "INACTIVE", "start_time": now},
+                )
+                logger.info("Deactivated SCC finding %s", name)
+
+        result.retries = self.retry.stats.retries - retries_before
+        return result
+
+    def _update(self, body: Dict[str, Any]) -> None:
+        """Replace the fields of an existing finding."""
+        self.retry.call(
+            "scc.update_finding",
+            self.client.update_finding,
+            request={"finding": body, "update_mask": {"paths": _UPDATE_MASK.split(",")}},
+        )
+
+    def _upsert(self, scc_id: str, body: Dict[str, Any]) -> None:
+        """Create a finding, or reactivate it when an inactive one has the same ID."""
+        try:
+            self.retry.call(
+                "scc.create_finding",
+                self.client.create_finding,
+                request={"parent": self.source, "finding_id": scc_id, "finding": body},
+            )
+        except gcp_exceptions.AlreadyExists:
+            body["name"] = f"{self.source}/findings/{scc_id}"
+            self._update(body)
+        logger.info("Wrote SCC finding %s/findings/%s", self.source, scc_id)
@@ -0,0 +1,198 @@
+"""Send findings to a Splunk HTTP Event Collector (HEC).
+
+Configured in paddi.toml::
+
+    [export.splunk_hec]
+    url = "https://splunk.example.com:8088"
+    index = "security"
+    sourcetype = "paddi:finding"
+    batch_size = 100
+
+The HEC token is read from ``SPLUNK_HEC_TOKEN`` unless ``token_env`` names
+another environment variable. Each finding is one event; events are sent in
+batches, and batches that fail with a transient error are retried.
+"""
+
+import json
+import logging
+import time
+from datetime import datetime, timezone
+from typing import Any, Dict, List, Optional
+
+import requests
+
+from app.common.exceptions import ConfigurationError
+from app.config.paddi_config import PaddiConfig
+from app.config.secrets import missing_secret_message, resolve_secret
+from app.findings.fingerprint import compute_fingerprint
+
+logger = logging.getLogger(__name__)
+
+EVENT_ENDPOINT = "/services/collector/event"
+DEFAULT_SOURCETYPE = "paddi:finding"
+
+
+def hec_event(
+    finding: Dict[str, Any],
+    timestamp: float,
+    index: Optional[str] = None,
+    sourcetype: str = DEFAULT_SOURCETYPE,
+    host: Optional[str] = None,
+) -> Dict[str, Any]:
+    """Wrap a finding in a HEC event envelope."""
+    event: Dict[str, Any] = {
+        "time": timestamp,
+        "source": "paddi",
+        "sourcetype": sourcetype,
+        "event": dict(
+            finding, fingerprint=finding.get("fingerprint") or compute_fingerprint(finding)
+        ),
+    }
+    if index:
+        event["index"] = index
+    if host:
+        event["host"] = host
+    return event
+
+
+class SplunkHECExporter:
+    """Sends findings to a Splunk HTTP Event Collector in batches."""
+
+    def __init__(
+        self,
+        url: str,
+        token: str,
+        index: Optional[str] = None,
+        sourcetype: str = DEFAULT_SOURCETYPE,
+        batch_size: int = 100,
+        retries: int = 3,
+        timeout: float = 10.0,
+        verify_tls: bool = True,
+    ):
+        """Initialize SplunkHECExporter.
+
+        Args:
+            url: Base URL of the HEC (the event endpoint path is added if missing)
+            token: HEC token
+            index: Target index (the token's default index if omitted)
+            sourcetype: Event sourcetype
+            batch_size: Events per request
+            retries: Retries per batch after the first attempt
+            timeout: Seconds per request
+            verify_tls: Verify the HEC certificate
+        """
+        url = url.rstrip("/")
+        self.url = url if url.endswith(EVENT_ENDPOINT) else url + EVENT_ENDPOINT
+        self.token = token
+        self.index = index
+        self.sourcetype = sourcetype
+        self.batch_size = batch_size
+        self.retries = retries
+        self.timeout = timeout
+        self.verify_tls = verify_tls
+
+    @classmethod
+    def from_config(cls, config: Optional[PaddiConfig] = None) -> "SplunkHECExporter":
+        """Create an exporter from the ``[export.splunk_hec]`` section of paddi.toml.
+
+        Raises:
+            ConfigurationError: If the URL or token is missing, or an option is invalid
+        """
+        section = (config or PaddiConfig.load()).section("export.splunk_hec")
+        url = section.get("url")
+        if not url or not str(url).startswith(("https://", "http://")):
+            raise ConfigurationError(
+                "export.splunk_hec.url", {"error": "url must be an http(s) URL"}
+            )
+
+        token_env = section.get("token_env", "SPLUNK_HEC_TOKEN")
+        token = section.get("token") or resolve_secret(token_env)
+        if not token:
+            raise ConfigurationError(
+                "export.splunk_hec", {"error": missing_secret_message(token_env)}
+            )
+
+        try:
+            batch_size = int(section.get("batch_size", 100))
+            retries = int(section.get("retries", 3))
+            timeout = float(section.get("timeout", 10.0))
+        except (TypeError, ValueError) as e:
+            raise ConfigurationError("export.splunk_hec", {"error": str(e)}) from e
+        if batch_size < 1:
+            raise ConfigurationError(
+                "export.splunk_hec.batch_size", {"error": "must be at least 1"}
+            )
+
+        return cls(
+            url=url,
+            token=token,
+            index=section.get("index"),
+            sourcetype=section.get("sourcetype", DEFAULT_SOURCETYPE),
+            batch_size=batch_size,
+            retries=retries,
+            timeout=timeout,
+            verify_tls=bool(section.get("verify_tls", True)),
+        )
+
+    def _post(self, body: str) -> bool:
+        """Post one batch, retrying transient failures."""
+        headers = {"Authorization": f"Splunk {self.token}", "Content-Type": "application/json"}
+
+        for attempt in range(self.retries + 1):
+            if attempt:
+                time.sleep(2 ** (attempt - 1))
+            try:
+                response = requests.post(
+                    self.url,
+                    data=body.encode("utf-8"),
+                    headers=headers,
+                    timeout=self.timeout,
+                    verify=self.verify_tls,
+                )
+            except requests.RequestException as e:
+                logger.warning("Splunk HEC request failed (attempt %d): %s", attempt + 1, e)
+                continue
+
+            if response.status_code < 300:
+                return True
+            if response.status_code != 429 and response.status_code < 500:
+                logger.error(
+                    "Splunk HEC rejected the batch with HTTP %d: %s",
+                    response.status_code,
+                    response.text,
+                )
+                return False
+            logger.warning(
+                "Splunk HEC returned HTTP %d (attempt %d)", response.status_code, attempt + 1
+            )
+
+        logger.error("Splunk HEC batch failed after %d attempts", self.retries + 1)
+        return False
+
+    def send(self, findings: List[Dict[str, Any]], host: Optional[str] = None) -> Dict[str, int]:
+        """Send findings as HEC events.
+
+        Args:
+            findings: Findings to send
+            host: Value for the event ``host`` field, e.g. the audited project
+
+        Returns:
+            Counts of ``sent`` and ``failed`` events
+        """
+        timestamp = round(datetime.now(timezone.utc).timestamp(), 3)
+        events = [
+            json.dumps(
+                hec_event(finding, timestamp, self.index, self.sourcetype, host),
+                ensure_ascii=False,
+            )
+            for finding in findings
+        ]
+
+        result = {"sent": 0, "failed": 0}
+        for start in range(0, len(events), self.batch_size):
+            batch = events[start : start + self.batch_size]
+            key = "sent" if self._post("\n".join(batch)) else "failed"
+            result[key] += len(batch)
+
+        logger.info("Splunk HEC: %d event(s) sent, %d failed", result["sent"], result["failed"])
+        return result
@@ -0,0 +1 @@
+"""Finding management utilities shared by CLI commands and exporters."""
@@ -0,0 +1,175 @@
+"""CVSS v3.1 base scoring for findings.
+
+Each finding category maps to a CVSS v3.1 base vector that describes the
+typical exposure of that misconfiguration. The defaults below can be replaced
+or extended per category in paddi.toml::
+
+    [cvss.vectors]
+    WEAK_SSL_POLICY = "CVSS:3.1/AV:N/AC:H/PR:N/UI:N/S:U/C:L/I:N/A:N"
+"""
+
+import logging
+import math
+from typing import Dict, Iterable, List, Optional
+
+from app.common.exceptions import ConfigurationError
+from app.common.models import SecurityFinding
+from app.config.paddi_config import PaddiConfig
+
+logger = logging.getLogger(__name__)
+
+CVSS_PREFIX = "CVSS:3.1/"
+
+DEFAULT_VECTORS: Dict[str, str] = {
+    "AUDIT_LOGGING_DISABLED": "CVSS:3.1/AV:N/AC:H/PR:H/UI:N/S:U/C:L/I:N/A:N",
+    "BUCKET_LOGGING_DISABLED": "CVSS:3.1/AV:N/AC:H/PR:H/UI:N/S:U/C:L/I:N/A:N",
+    "CONTAINER_VULNERABILITY": "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+    "CRYPTO_KEY_PUBLIC_ACCESS": "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:C/C:H/I:N/A:N",
+    "DATASET_PUBLIC_ACCESS": "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:N/A:N",
+    "FIREWALL_RULE_OPEN": "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:L/I:L/A:L",
+    "LOG_NOT_ENCRYPTED": "CVSS:3.1/AV:L/AC:L/PR:H/UI:N/S:U/C:L/I:N/A:N",
+    "OPEN_FIREWALL": "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:L/I:L/A:L",
+    "OVERPRIVILEGED_ROLE": "CVSS:3.1/AV:N/AC:L/PR:L/UI:N/S:U/C:H/I:H/A:H",
+    "OVERPRIVILEGED_SERVICE_ACCOUNT": "CVSS:3.1/AV:N/AC:H/PR:L/UI:N/S:C/C:H/I:H/A:N",
+    "OVER_PRIVILEGED_ACCOUNT": "CVSS:3.1/AV:N/AC:H/PR:L/UI:N/S:C/C:H/I:H/A:N",
+    "PUBLIC_BUCKET": "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:N/A:N",
+    "UNENCRYPTED_DATABASE": "CVSS:3.1/AV:P/AC:L/PR:N/UI:N/S:U/C:H/I:N/A:N",
+    "WEAK_SSL_POLICY": "CVSS:3.1/AV:N/AC:H/PR:N/UI:N/S:U/C:H/I:N/A:N",
+    "XSS_SCRIPTING": "CVSS:3.1/AV:N/AC:L/PR:N/UI:R/S:C/C:L/I:L/A:N",
+}
+
+_WEIGHTS = {
+    "AV": {"N": 0.85, "A": 0.62, "L": 0.55, "P": 0.2},
+    "AC": {"L": 0.77, "H": 0.44},
+    "PR": {"N": 0.85, "L": 0.62, "H": 0.27},
+    "UI": {"N": 0.85, "R": 0.62},
+    "S": {"U": None, "C": None},
+    "C": {"H": 0.56, "L": 0.22, "N": 0.0},
+    "I": {"H": 0.56, "L": 0.22, "N": 0.0},
+    "A": {"H": 0.56, "L": 0.22, "N": 0.0},
+}
+
+# Privileges Required weights when the scope is changed
+_PR_SCOPE_CHANGED = {"N": 0.85, "L": 0.68, "H": 0.5}
+
+
+def parse_vector(vector: str) -> Dict[str, str]:
+    """Parse a CVSS v3.1 base vector into its metric values.
+
+    Raises:
+        ValueError: If the vector is not a complete, valid v3.1 base vector
+    """
+    if not vector or not vector.startswith(CVSS_PREFIX):
+        raise ValueError(f"Not a CVSS v3.1 vector: {vector!r}")
+
+    metrics = {}
+    for part in vector[len(CVSS_PREFIX) :].split("/"):
+        name, _, value = part.partition(":")
+        if name not in _WEIGHTS or value not in _WEIGHTS[name]:
+            raise ValueError(f"Invalid CVSS metric {part!r} in {vector!r}")
+        metrics[name] = value
+
+    missing = set(_WEIGHTS) - set(metrics)
+    if missing:
+        raise ValueError(f"Missing CVSS metrics {sorted(missing)} in {vector!r}")
+    return metrics
+
+
+def _roundup(value: float) -> float:
+    """Round up to one decimal as defined in CVSS v3.1 Appendix A."""
+    int_input = round(value * 100000)
+    if int_input % 10000 == 0:
+        return int_input / 100000.0
+    return (math.floor(int_input / 10000) + 1) / 10.0
+
+
+def base_score(vector: str) -> float:
+    """Compute the CVSS v3.1 base score of a vector."""
+    metrics = parse_vector(vector)
+    scope_changed = metrics["S"] == "C"
+
+    iss = 1 - (
+        (1 - _WEIGHTS["C"][metrics["C"]])
+        * (1 - _WEIGHTS["I"][metrics["I"]])
+        * (1 - _WEIGHTS["A"][metrics["A"]])
+    )
+    if scope_changed:
+        impact = 7.52 * (iss - 0.029) - 3.25 * (iss - 0.02) ** 15
+    else:
+        impact = 6.42 * iss
+
+    privileges = (_PR_SCOPE_CHANGED if scope_changed else _WEIGHTS["PR"])[metrics["PR"]]
+    exploitability = (
+        8.22
+        * _WEIGHTS["AV"][metrics["AV"]]
+        * _WEIGHTS["AC"][metrics["AC"]]
+        * privileges
+        * _WEIGHTS["UI"][metrics["UI"]]
+    )
+
+    if impact <= 0:
+        return 0.0
+    if scope_changed:
+        return _roundup(min(1.08 * (impact + exploitability), 10))
+    return _roundup(min(impact + exploitability, 10))
+
+
+def score_rating(score: float) -> str:
+    """Return the CVSS v3.1 qualitative rating for a base score."""
+    if score == 0:
+        return "NONE"
+    if score < 4.0:
+        return "LOW"
+    if score < 7.0:
+        return "MEDIUM"
+    if score < 9.0:
+        return "HIGH"
+    return "CRITICAL"
+
+
+class CvssScorer:
+    """Assigns CVSS vectors and base scores to findings by category."""
+
+    def __init__(self, vectors: Optional[Dict[str, str]] = None):
+        """Initialize CvssScorer with category vectors merged over the defaults."""
+        self.vectors = dict(DEFAULT_VECTORS)
+        for category, vector in (vectors or {}).items():
+            self.vectors[category.upper()] = vector
+
+    @classmethod
+    def from_config(cls, config: Optional[PaddiConfig] = None) -> "CvssScorer":
+        """Create a scorer from the ``[cvss.vectors]`` section of paddi.toml.
+
+        Raises:
+            ConfigurationError: If a configured vector is invalid
+        """
+        vectors = (config or PaddiConfig.load()).section("cvss.vectors")
+        for category, vector in vectors.items():
+            try:
+                parse_vector(vector)
+            except ValueError as e:
+                raise ConfigurationError(f"cvss.vectors.{category}", {"error": str(e)}) from e
+        return cls(vectors)
+
+    def vector_for(self, category: Optional[str]) -> Optional[str]:
+        """Return the vector configured for a category, if any."""
+        if not category:
+            return None
+        return self.vectors.get(category.upper())
+
+    def add_scores(self, findings: Iterable[SecurityFinding]) -> List[SecurityFinding]:
+        """Set the CVSS vector and base score on each finding with a known category.
+
+        A vector already present on a finding (e.g. from a plugin) is kept.
+        """
+        result = []
+        for finding in findings:
+            vector = finding.cvss_vector or self.vector_for(finding.category)
+            if vector:
+                try:
+                    finding.cvss_score = base_score(vector)
+                    finding.cvss_vector = vector
+                except ValueError as e:
+                    logger.warning("Skipping CVSS score for %s: %s", finding.title, e)
+            result.append(finding)
+        return result
@@ -0,0 +1,56 @@
+"""Deduplication of findings aggregated from multiple projects or runs.
+
+Findings that share a fingerprint describe the same issue. They are merged
+into a single finding that keeps the most severe rating, counts how many times
+the issue was reported, and lists every resource spelling it was reported on.
+"""
+
+from typing import Any, Dict, List
+
+from app.common.models import severity_rank
+from app.findings.fingerprint import compute_fingerprint
+
+
+def _resources_of(finding: Dict[str, Any]) -> List[str]:
+    """Return the resources a finding applies to."""
+    resources = list(finding.get("affected_resources") or [])
+    if not resources and finding.get("resource"):
+        resources = [finding["resource"]]
+    return resources
+
+
+def deduplicate_findings(findings: List[Dict[str, Any]]) -> List[Dict[str, Any]]:
+    """Merge findings that share a fingerprint.
+
+    Args:
+        findings: Findings as loaded from one or more explained.json files
+
+    Returns:
+        One finding per fingerprint, in order of first appearance, with
+        ``occurrences`` and ``affected_resources`` set
+    """
+    merged: Dict[str, Dict[str, Any]] = {}
+
+    for finding in findings:
+        fingerprint = finding.get("fingerprint") or compute_fingerprint(finding)
+        occurrences = int(finding.get("occurrences") or 1)
+
+        existing = merged.get(fingerprint)
+        if existing is None:
+            entry = dict(finding)
+            entry["fingerprint"] = fingerprint
+            entry["occurrences"] = occurrences
+            entry["affected_resources"] = _resources_of(finding)
+            merged[fingerprint] = entry
+            continue
+
+        existing["occurrences"] += occurrences
+        for resource in _resources_of(finding):
+            if resource not in existing["affected_resources"]:
+                existing["affected_resources"].append(resource)
+        if severity_rank(finding.get("severity", "")) < severity_rank(
+            existing.get("severity", "")
+        ):
+            existing["severity"] = finding["severity"]
+
+    return list(merged.values())
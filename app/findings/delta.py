@@ -0,0 +1,23 @@
+"""Differences between the findings of two runs, matched by fingerprint."""
+
+from typing import Any, Dict, List, Tuple
+
+from app.findings.fingerprint import compute_fingerprint
+
+
+def _by_fingerprint(findings: List[Dict[str, Any]]) -> Dict[str, Dict[str, Any]]:
+    """Index findings by fingerprint, keeping the first of each."""
+    indexed: Dict[str, Dict[str, Any]] = {}
+    for finding in findings:
+        indexed.setdefault(finding.get("fingerprint") or compute_fingerprint(finding), finding)
+    return indexed
+
+
+def findings_delta(
+    current: List[Dict[str, Any]], previous: List[Dict[str, Any]]
+) -> Tuple[List[Dict[str, Any]], List[Dict[str, Any]]]:
+    """Return the findings that are new in ``current`` and resolved since ``previous``."""
+    now, before = _by_fingerprint(current), _by_fingerprint(previous)
+    new = [finding for key, finding in now.items() if key not in before]
+    resolved = [finding for key, finding in before.items() if key not in now]
+    return new, resolved
@@ -0,0 +1,62 @@
+"""Deterministic fingerprints that identify a finding across runs.
+
+A fingerprint hashes the finding category, the normalized resource name, and
+the key attributes that distinguish two findings on the same resource. Values
+that change between runs (severity, explanation wording, timestamps) are left
+out so the same issue keeps the same fingerprint.
+"""
+
+import hashlib
+import re
+from typing import Any, Dict, Iterable, List, Union
+
+from app.common.models import SecurityFinding
+
+FINGERPRINT_VERSION = "v1"
+FINGERPRINT_LENGTH = 16
+
+_RESOURCE_PREFIXES = ("https://", "http://", "//")
+
+
+def normalize_resource(resource: str) -> str:
+    """Normalize a resource name so equivalent spellings compare equal."""
+    value = (resource or "").strip().lower()
+    for prefix in _RESOURCE_PREFIXES:
+        if value.startswith(prefix):
+            value = value[len(prefix) :]
+            break
+    return value.rstrip("/")
+
+
+def _normalize_text(value: str) -> str:
+    """Collapse case and whitespace in free-text attributes."""
+    return re.sub(r"\s+", " ", (value or "").strip().lower())
+
+
+def compute_fingerprint(finding: Union[SecurityFinding, Dict[str, Any]]) -> str:
+    """Compute the fingerprint of a finding.
+
+    The category is the primary key attribute; findings without a category
+    (e.g. free-form LLM output) fall back to their normalized title.
+    """
+    data = finding.to_dict() if isinstance(finding, SecurityFinding) else finding
+
+    category = (data.get("category") or "").strip().upper()
+    parts = [
+        FINGERPRINT_VERSION,
+        category,
+        normalize_resource(data.get("resource", "")),
+        "" if category else _normalize_text(data.get("title", "")),
+    ]
+    digest = hashlib.sha256("|".join(parts).encode("utf-8")).hexdigest()
+    return digest[:FINGERPRINT_LENGTH]
+
+
+def add_fingerprints(findings: Iterable[SecurityFinding]) -> List[SecurityFinding]:
+    """Set the fingerprint on each finding that does not already have one."""
+    result = []
+    for finding in findings:
+        if not finding.fingerprint:
+            finding.fingerprint = compute_fingerprint(finding)
+        result.append(finding)
+    return result
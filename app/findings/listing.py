@@ -0,0 +1,203 @@
+"""Loading, filtering, and formatting findings from explained.json."""
+
+import math
+from itertools import islice
+from pathlib import Path
+from typing import Any, Callable, Dict, Iterator, List, Optional, Tuple
+
+from app.common.artifact_crypto import load_artifact, open_artifact
+from app.common.json_stream import iter_array
+from app.common.models import SEVERITY_ORDER, severity_rank
+
+# Findings shown in the summary printed after an audit
+SUMMARY_TOP_FINDINGS = 10
+# Findings read from explained.json at a time when filtering a stream
+FILTER_BATCH_SIZE = 1000
+
+
+def load_findings(path: str = "data/explained.json") -> List[Dict[str, Any]]:
+    """Load findings from an explained.json file.
+
+    Raises:
+        FileNotFoundError: If the file does not exist
+    """
+    findings_file = Path(path)
+    if not findings_file.exists():
+        raise FileNotFoundError(f"Findings file not found: {findings_file}")
+
+    return load_artifact(findings_file)
+
+
+def iter_findings(path: str = "data/explained.json") -> Iterator[List[Dict[str, Any]]]:
+    """Read an explained.json file as a stream, in batches of FILTER_BATCH_SIZE findings.
+
+    Raises:
+        FileNotFoundError: If the file does not exist
+    """
+    findings_file = Path(path)
+    if not findings_file.exists():
+        raise FileNotFoundError(f"Findings file not found: {findings_file}")
+    return _batches(findings_file)
+
+
+def _batches(path: Path) -> Iterator[List[Dict[str, Any]]]:
+    """Yield the findings of a file in batches."""
+    with open_artifact(path) as stream:
+        findings = iter_array(stream)
+        while True:
+            batch = list(islice(findings, FILTER_BATCH_SIZE))
+            if not batch:
+                return
+            yield batch
+
+
+def _split(values: Optional[str]) -> List[str]:
+    """Split a comma-separated CLI value into upper-cased items."""
+    if not values:
+        return []
+    if isinstance(values, (list, tuple)):
+        values = ",".join(str(v) for v in values)
+    return [v.strip().upper() for v in str(values).split(",") if v.strip()]
+
+
+def filter_findings(
+    findings: List[Dict[str, Any]],
+    severity: Optional[str] = None,
+    category: Optional[str] = None,
+    resource: Optional[str] = None,
+    min_score: Optional[float] = None,
+    tag: Optional[str] = None,
+) -> List[Dict[str, Any]]:
+    """Filter findings by severity, category, resource, CVSS score, and tags.
+
+    Args:
+        findings: Findings as loaded from explained.json
+        severity: Comma-separated severities to keep (e.g. "CRITICAL,HIGH")
+        category: Comma-separated categories to keep (case-insensitive)
+        resource: Substring that the finding's resource must contain
+        min_score: Minimum CVSS base score; unscored findings are excluded
+        tag: Comma-separated tags; findings with any of them are kept (case-insensitive)
+
+    Returns:
+        Matching findings in their original order
+    """
+    severities = _split(severity)
+    categories = _split(category)
+    resource_pattern = resource.lower() if resource else None
+    tags = set(_split(tag))
+
+    result = []
+    for finding in findings:
+        if severities and str(finding.get("severity", "")).upper() not in severities:
+            continue
+        if categories and str(finding.get("category", "")).upper() not in categories:
+            continue
+        if resource_pattern and resource_pattern not in str(finding.get("resource", "")).lower():
+            continue
+        if min_score is not None and (finding.get("cvss_score") or 0.0) < float(min_score):
+            continue
+        if tags and not tags & {str(t).upper() for t in finding.get("tags") or []}:
+            continue
+        result.append(finding)
+    return result
+
+
+def sort_findings(findings: List[Dict[str, Any]], by: str = "severity") -> List[Dict[str, Any]]:
+    """Sort findings from most to least severe, keeping order within ties.
+
+    Args:
+        findings: Findings to sort
+        by: 'severity' or 'score' (CVSS base score, unscored findings last)
+    """
+    if by == "score":
+        return sorted(findings, key=lambda f: -(f.get("cvss_score") or 0.0))
+    if by != "severity":
+        raise ValueError(f"Unknown sort key: {by}")
+    return sorted(findings, key=lambda f: severity_rank(f.get("severity", "")))
+
+
+def paginate(
+    findings: List[Dict[str, Any]], page: int = 1, page_size: int = 20
+) -> Tuple[List[Dict[str, Any]], int]:
+    """Return one page of findings and the total number of pages."""
+    page_size = max(1, int(page_size))
+    total_pages = max(1, math.ceil(len(findings) / page_size))
+    page = min(max(1, int(page)), total_pages)
+    start = (page - 1) * page_size
+    return findings[start : start + page_size], total_pages
+
+
+def _truncate(value: str, width: int) -> str:
+    """Truncate a string to fit a table column."""
+    return value if len(value) <= width else value[: width - 1] + "…"
+
+
+def format_table(
+    findings: List[Dict[str, Any]],
+    start_index: int = 1,
+    severity_style: Optional[Callable[[str, str], str]] = None,
+) -> str:
+    """Format findings as a fixed-width text table.
+
+    Args:
+        findings: Findings to format
+        start_index: Number shown for the first row
+        severity_style: Optional callable ``(padded_cell, severity) -> str`` used to
+            style the severity column (e.g. color it) after padding
+    """
+    columns = [
+        ("#", 4),
+        ("SEVERITY", 9),
+        ("CVSS", 4),
+        ("CATEGORY", 30),
+        ("RESOURCE", 40),
+        ("TITLE", 50),
+    ]
+    header = " ".join(name.ljust(width) for name, width in columns)
+    lines = [header, "-" * len(header)]
+
+    for i, finding in enumerate(findings, start_index):
+        values = [
+            str(i),
+            str(finding.get("severity", "")),
+            str(finding.get("cvss_score") if finding.get("cvss_score") is not None else "-"),
+            str(finding.get("category") or "-"),
+            str(finding.get("resource") or "-"),
+            str(finding.get("title", "")),
+        ]
+        cells = [_truncate(value, width).ljust(width) for value, (_, width) in zip(values, columns)]
+        if severity_style:
+            cells[1] = severity_style(cells[1], values[1])
+        lines.append(" ".join(cells).rstrip())
+    return "\n".join(lines)
+
+
+def severity_counts(findings: List[Dict[str, Any]]) -> Dict[str, int]:
+    """Count findings per severity, most severe first, omitting severities with none."""
+    counts: Dict[str, int] = {}
+    for finding in sort_findings(findings):
+        severity = str(finding.get("severity") or "INFO").upper()
+        counts[severity] = counts.get(severity, 0) + 1
+    return counts
+
+
+def format_summary(
+    findings: List[Dict[str, Any]],
+    top: int = SUMMARY_TOP_FINDINGS,
+    severity_style: Optional[Callable[[str, str], str]] = None,
+) -> str:
+    """Format severity counts and the most severe findings for the terminal."""
+    counts = severity_counts(findings)
+    style = severity_style or (lambda cell, _severity: cell)
+    line = " | ".join(
+        f"{style(severity, severity)} {counts.get(severity, 0)}" for severity in SEVERITY_ORDER
+    )
+    lines = [f"Findings by severity: {line} (total {len(findings)})"]
+    if not findings:
+        return lines[0]
+
+    table = format_table(sort_findings(findings)[:top], severity_style=severity_style)
+    lines.extend(["", table])
+    if len(findings) > top:
+        lines.append(f"... and {len(findings) - top} more (paddi findings list)")
+    return "\n".join(lines)
@@ -0,0 +1,164 @@
+"""Config-driven owners of findings, by resource pattern and category.
+
+Owners are teams defined in paddi.toml::
+
+    [owners.platform]
+    resources = ["projects/prod-*", "storage.googleapis.com/*"]
+    contact = "platform@example.com"
+
+    [owners.identity]
+    categories = ["OVERPRIVILEGED_ROLE", "SERVICE_ACCOUNT_KEY"]
+    contact = "#identity-team"
+
+Resource patterns are globs over normalized resource names (lower-case,
+without ``//`` or ``https://`` prefix). A finding belongs to the first owner,
+in definition order, with a matching category or resource pattern. The owner
+is recorded in the finding's ``owner`` field; a finding that already names an
+owner keeps it.
+"""
+
+import fnmatch
+from dataclasses import dataclass, field
+from typing import Any, Dict, List, Optional
+
+from app.common.exceptions import ConfigurationError
+from app.common.models import SEVERITY_ORDER
+from app.config.paddi_config import PaddiConfig
+from app.findings.fingerprint import normalize_resource
+from app.findings.listing import sort_findings
+
+UNASSIGNED = "unassigned"
+
+
+@dataclass
+class Owner:
+    """A team that owns findings on some resources or in some categories."""
+
+    name: str
+    resources: List[str] = field(default_factory=list)
+    categories: List[str] = field(default_factory=list)
+    contact: Optional[str] = None
+
+    def matches(self, finding: Dict[str, Any]) -> bool:
+        """Check whether the owner is responsible for a finding."""
+        if str(finding.get("category") or "").upper() in self.categories:
+            return True
+        resources = finding.get("affected_resources") or [finding.get("resource")]
+        names = [normalize_resource(resource or "") for resource in resources]
+        return any(
+            fnmatch.fnmatchcase(name, pattern)
+            for name in names
+            if name
+            for pattern in self.resources
+        )
+
+
+@dataclass
+class OwnerSummary:
+    """Findings of one owner, for the per-owner report section."""
+
+    owner: str
+    contact: Optional[str] = None
+    severity_counts: Dict[str, int] = field(default_factory=dict)
+    findings: List[Dict[str, Any]] = field(default_factory=list)
+
+    @property
+    def total(self) -> int:
+        """Number of findings."""
+        return len(self.findings)
+
+
+def _strings(value: Any, location: str) -> List[str]:
+    """Read a string or an array of strings."""
+    if value is None:
+        return []
+    if isinstance(value, str):
+        return [value]
+    if isinstance(value, list) and all(isinstance(item, str) for item in value):
+        return list(value)
+    raise ConfigurationError(location, {"error": f"expected strings, got {value!r}"})
+
+
+class OwnershipRules:
+    """Ordered set of owners."""
+
+    def __init__(self, owners: Optional[List[Owner]] = None):
+        """Initialize OwnershipRules with owners in match order."""
+        self.owners = owners or []
+
+    @classmethod
+    def from_config(cls, config: Optional[PaddiConfig] = None) -> "OwnershipRules":
+        """Create rules from the ``[owners]`` section of paddi.toml.
+
+        Raises:
+            ConfigurationError: If an owner has no resources or categories
+        """
+        section = (config or PaddiConfig.load()).section("owners")
+        owners = []
+        for name, entry in section.items():
+            location = f"owners.{name}"
+            if not isinstance(entry, dict):
+                raise ConfigurationError(location, {"error": "expected a table"})
+            owner = Owner(
+                name=name,
+                resources=[
+                    normalize_resource(pattern)
+                    for pattern in _strings(entry.get("resources"), f"{location}.resources")
+                ],
+                categories=[
+                    category.upper()
+                    for category in _strings(entry.get("categories"), f"{location}.categories")
+                ],
+                contact=entry.get("contact"),
+            )
+            if not owner.resources and not owner.categories:
+                raise ConfigurationError(location, {"error": "owner needs resources or categories"})
+            owners.append(owner)
+        return cls(owners)
+
+    def owner_for(self, finding: Dict[str, Any]) -> Optional[str]:
+        """Return the owner of a finding, if any."""
+        if finding.get("owner"):
+            return finding["owner"]
+        return next((owner.name for owner in self.owners if owner.matches(finding)), None)
+
+    def assign(self, findings: List[Dict[str, Any]]) -> List[Dict[str, Any]]:
+        """Return findings with their ``owner`` set where an owner matches."""
+        if not self.owners:
+            return findings
+        result = []
+        for finding in findings:
+            owner = self.owner_for(finding)
+            result.append({**finding, "owner": owner} if owner else finding)
+        return result
+
+    def owned_by(self, findings: List[Dict[str, Any]], owners: List[str]) -> List[Dict[str, Any]]:
+        """Return the findings that belong to any of the given owners."""
+        wanted = set(owners)
+        return [f for f in findings if (self.owner_for(f) or UNASSIGNED) in wanted]
+
+    def summarize(self, findings: List[Dict[str, Any]]) -> List[OwnerSummary]:
+        """Count findings per owner, in definition order, with unassigned findings last."""
+        contacts = {owner.name: owner.contact for owner in self.owners}
+        summaries: Dict[str, OwnerSummary] = {}
+        for finding in findings:
+            name = self.owner_for(finding) or UNASSIGNED
+            summary = summaries.setdefault(name, OwnerSummary(name, contacts.get(name)))
+            severity = str(finding.get("severity", "INFO")).upper()
+            summary.severity_counts[severity] = summary.severity_counts.get(severity, 0) + 1
+            summary.findings.append(finding)
+
+        order = {owner.name: i for i, owner in enumerate(self.owners)}
+        unassigned = len(order) + 1
+        ordered = sorted(
+            summaries.values(),
+            key=lambda s: unassigned if s.owner == UNASSIGNED else order.get(s.owner, len(order)),
+        )
+        for summary in ordered:
+            summary.severity_counts = {
+                severity: summary.severity_counts[severity]
+                for severity in SEVERITY_ORDER
+                if severity in summary.severity_counts
+            }
+            summary.findings = sort_findings(summary.findings)
+        return ordered
@@ -0,0 +1,264 @@
+"""Filter expressions over findings, for the ``--query`` option.
+
+A query compares finding fields with values and combines the comparisons with
+``and``, ``or``, ``not``, and parentheses::
+
+    severity>=HIGH and category=~"IAM|ROLE" and resource contains "prod"
+    tags = pci or (cvss_score > 7 and not owner = platform)
+    severity in (CRITICAL, HIGH)
+
+Operators are ``=``, ``!=``, ``>``, ``>=``, ``<``, ``<=``, ``=~`` (regular
+expression search), ``!~``, ``contains``, and ``in``. String comparisons are
+case-insensitive. ``severity`` and ``original_severity`` are ordered from INFO
+to CRITICAL; other fields can only be ordered against numbers. For list fields
+such as ``tags`` and ``affected_resources``, a comparison matches if any item
+matches (``!=`` and ``!~`` if no item does). A missing field compares as an
+empty string.
+"""
+
+import operator
+import re
+from dataclasses import dataclass
+from typing import Any, Callable, Dict, List, Optional, Tuple
+
+from app.common.exceptions import QueryError
+from app.common.models import severity_rank
+
+SEVERITY_FIELDS = ("severity", "original_severity")
+
+_TOKEN = re.compile(
+    r"""\s*(?:
+        (?P<string>"(?:[^"\\]|\\.)*"|'(?:[^'\\]|\\.)*')
+      | (?P<op>>=|<=|!=|=~|!~|=|>|<)
+      | (?P<punct>[(),])
+      | (?P<word>[^\s()=!<>,"']+)
+    )""",
+    re.VERBOSE,
+)
+
+_KEYWORDS = ("and", "or", "not", "contains", "in")
+_ORDERING = {">": operator.gt, ">=": operator.ge, "<": operator.lt, "<=": operator.le}
+_OPERATORS = ("=", "!=", "=~", "!~", *_ORDERING, "contains", "in")
+
+
+@dataclass
+class _Token:
+    kind: str
+    text: str
+    position: int
+
+
+def _tokenize(text: str) -> List[_Token]:
+    """Split a query into tokens, keeping their positions for error messages."""
+    tokens = []
+    position = 0
+    while position < len(text):
+        if text[position:].strip() == "":
+            break
+        match = _TOKEN.match(text, position)
+        if not match:
+            raise QueryError(text, {"error": "unexpected character", "position": position})
+        kind = match.lastgroup
+        value = match.group(kind)
+        start = match.start(kind)
+        if kind == "string":
+            value = re.sub(r"\\(.)", r"\1", value[1:-1])
+        elif kind == "word" and value.lower() in _KEYWORDS:
+            kind, value = "keyword", value.lower()
+        tokens.append(_Token(kind, value, start))
+        position = match.end()
+    return tokens
+
+
+def _number(value: Any) -> Optional[float]:
+    """Return a value as a number, or None if it is not one."""
+    if isinstance(value, bool):
+        return None
+    try:
+        return float(value)
+    except (TypeError, ValueError):
+        return None
+
+
+Predicate = Callable[[Dict[str, Any]], bool]
+
+
+def _comparison(field: str, op: str, values: List[str], query: str, position: int) -> Predicate:
+    """Build the predicate for one ``field op value`` comparison."""
+    if field in SEVERITY_FIELDS:
+
+        def key(item: Any) -> Any:
+            # Lower ranks are more severe; negate them so that INFO < ... < CRITICAL
+            return -severity_rank(item)
+
+    elif op in _ORDERING:
+        key = _number
+    else:
+
+        def key(item: Any) -> Any:
+            return str(item).lower()
+
+    targets = [key(value) for value in values]
+    if op in _ORDERING and targets[0] is None:
+        raise QueryError(
+            query,
+            {"error": f"{field} {op} needs a number, got {values[0]!r}", "position": position},
+        )
+    if op in ("=~", "!~"):
+        try:
+            pattern = re.compile(values[0], re.IGNORECASE)
+        except re.error as e:
+            raise QueryError(
+                query, {"error": f"invalid regular expression: {e}", "position": position}
+            ) from e
+
+    def test(item: Any) -> bool:
+        if op in ("=~", "!~"):
+            return bool(pattern.search(str(item)))
+        if op == "contains":
+            return values[0].lower() in str(item).lower()
+        item_key = key(item)
+        if op in ("=", "!=", "in"):
+            return item_key in targets
+        return item_key is not None and _ORDERING[op](item_key, targets[0])
+
+    negated = op in ("!=", "!~")
+
+    def predicate(finding: Dict[str, Any]) -> bool:
+        matched = any(test(item) for item in _items(finding, field))
+        return not matched if negated else matched
+
+    return predicate
+
+
+def _items(finding: Dict[str, Any], field: str) -> List[Any]:
+    """Return the values of a field, as a list."""
+    value = finding.get(field)
+    if isinstance(value, list):
+        return value
+    return ["" if value is None else value]
+
+
+class _Parser:
+    """Recursive-descent parser producing a predicate over findings."""
+
+    def __init__(self, query: str):
+        self.query = query
+        self.tokens = _tokenize(query)
+        self.index = 0
+
+    def _peek(self) -> Optional[_Token]:
+        return self.tokens[self.index] if self.index < len(self.tokens) else None
+
+    def _next(self, expected: str) -> _Token:
+        token = self._peek()
+        if token is None:
+            position = len(self.query)
+            raise QueryError(self.query, {"error": f"expected {expected}", "position": position})
+        self.index += 1
+        return token
+
+    def _fail(self, token: _Token, expected: str) -> QueryError:
+        return QueryError(
+            self.query,
+            {"error": f"expected {expected}, got {token.text!r}", "position": token.position},
+        )
+
+    def _accept(self, kind: str, text: str) -> bool:
+        token = self._peek()
+        if token is not None and token.kind == kind and token.text == text:
+            self.index += 1
+            return True
+        return False
+
+    def parse(self) -> Predicate:
+        if not self.tokens:
+            raise QueryError(self.query, {"error": "empty query", "position": 0})
+        predicate = self._or()
+        token = self._peek()
+        if token is not None:
+            raise self._fail(token, "'and', 'or', or the end of the query")
+        return predicate
+
+    def _or(self) -> Predicate:
+        terms = [self._and()]
+        while self._accept("keyword", "or"):
+            terms.append(self._and())
+        if len(terms) == 1:
+            return terms[0]
+        return lambda finding: any(term(finding) for term in terms)
+
+    def _and(self) -> Predicate:
+        terms = [self._not()]
+        while self._accept("keyword", "and"):
+            terms.append(self._not())
+        if len(terms) == 1:
+            return terms[0]
+        return lambda finding: all(term(finding) for term in terms)
+
+    def _not(self) -> Predicate:
+        if self._accept("keyword", "not"):
+            term = self._not()
+            return lambda finding: not term(finding)
+        if self._accept("punct", "("):
+            term = self._or()
+            token = self._next("')'")
+            if token.text != ")":
+                raise self._fail(token, "')'")
+            return term
+        return self._comparison()
+
+    def _comparison(self) -> Predicate:
+        field = self._next("a field name")
+        if field.kind != "word":
+            raise self._fail(field, "a field name")
+        op = self._next("an operator")
+        if op.kind not in ("op", "keyword") or op.text not in _OPERATORS:
+            raise self._fail(op, "an operator")
+        values = self._values() if op.text == "in" else [self._value()]
+        return _comparison(field.text.lower(), op.text, values, self.query, op.position)
+
+    def _value(self) -> str:
+        token = self._next("a value")
+        if token.kind not in ("word", "string"):
+            raise self._fail(token, "a value")
+        return token.text
+
+    def _values(self) -> List[str]:
+        token = self._next("'('")
+        if token.text != "(":
+            raise self._fail(token, "'('")
+        values = [self._value()]
+        while self._accept("punct", ","):
+            values.append(self._value())
+        token = self._next("')'")
+        if token.text != ")":
+            raise self._fail(token, "')'")
+        return values
+
+
+class Query:
+    """A parsed ``--query`` expression."""
+
+    def __init__(self, text: str):
+        """Parse a query.
+
+        Raises:
+            QueryError: If the query is not valid
+        """
+        self.text = text
+        self._predicate = _Parser(text).parse()
+
+    def matches(self, finding: Dict[str, Any]) -> bool:
+        """Check whether a finding matches the query."""
+        return self._predicate(finding)
+
+    def filter(self, findings: List[Dict[str, Any]]) -> List[Dict[str, Any]]:
+        """Return the matching findings in their original order."""
+        return [finding for finding in findings if self.matches(finding)]
+
+
+def describe_error(error: QueryError) -> Tuple[str, str]:
+    """Return a query error as its message and a caret line under the query."""
+    position = error.details.get("position", 0)
+    return error.details.get("error", error.message), " " * position + "^"
@@ -0,0 +1,141 @@
+"""Aggregated risk scores per resource and an overall project grade.
+
+Each finding contributes ``severity weight × CVSS factor × occurrences``, where
+the CVSS factor is ``cvss_score / 10`` when the finding is scored (and the
+factor is enabled) and 1 otherwise. Resource scores sum their findings; the
+project score sums all findings and maps to a grade from A to F.
+
+The formula is configurable in paddi.toml::
+
+    [risk]
+    use_cvss = true
+    severity_weights = { CRITICAL = 10, HIGH = 5, MEDIUM = 2, LOW = 0.5, INFO = 0 }
+    grade_thresholds = { A = 5, B = 15, C = 30, D = 60 }
+"""
+
+from dataclasses import dataclass, field
+from typing import Any, Dict, List, Optional
+
+from app.common.exceptions import ConfigurationError
+from app.config.paddi_config import PaddiConfig
+
+DEFAULT_SEVERITY_WEIGHTS = {"CRITICAL": 10.0, "HIGH": 5.0, "MEDIUM": 2.0, "LOW": 0.5, "INFO": 0.0}
+
+# Highest project score that still earns each grade; anything above D is F
+DEFAULT_GRADE_THRESHOLDS = {"A": 5.0, "B": 15.0, "C": 30.0, "D": 60.0}
+
+GRADES = ["A", "B", "C", "D"]
+
+
+@dataclass
+class ResourceRisk:
+    """Risk rolled up for a single resource."""
+
+    resource: str
+    score: float
+    findings: int
+
+
+@dataclass
+class RiskSummary:
+    """Risk rolled up for a whole project."""
+
+    score: float
+    grade: str
+    resources: List[ResourceRisk] = field(default_factory=list)
+
+    def to_dict(self) -> Dict[str, Any]:
+        """Convert to dictionary for JSON serialization."""
+        return {
+            "score": self.score,
+            "grade": self.grade,
+            "resources": [
+                {"resource": r.resource, "score": r.score, "findings": r.findings}
+                for r in self.resources
+            ],
+        }
+
+
+class RiskModel:
+    """Weighted risk scoring with configurable weights and grade thresholds."""
+
+    def __init__(
+        self,
+        severity_weights: Optional[Dict[str, float]] = None,
+        grade_thresholds: Optional[Dict[str, float]] = None,
+        use_cvss: bool = True,
+    ):
+        """Initialize RiskModel, merging the given values over the defaults."""
+        self.severity_weights = dict(DEFAULT_SEVERITY_WEIGHTS)
+        for severity, weight in (severity_weights or {}).items():
+            self.severity_weights[severity.upper()] = float(weight)
+
+        self.grade_thresholds = dict(DEFAULT_GRADE_THRESHOLDS)
+        for grade, threshold in (grade_thresholds or {}).items():
+            self.grade_thresholds[grade.upper()] = float(threshold)
+
+        self.use_cvss = use_cvss
+
+    @classmethod
+    def from_config(cls, config: Optional[PaddiConfig] = None) -> "RiskModel":
+        """Create a model from the ``[risk]`` section of paddi.toml.
+
+        Raises:
+            ConfigurationError: If weights or thresholds are not numbers, or
+                thresholds do not increase from A to D
+        """
+        section = (config or PaddiConfig.load()).section("risk")
+        try:
+            model = cls(
+                severity_weights=section.get("severity_weights"),
+                grade_thresholds=section.get("grade_thresholds"),
+                use_cvss=bool(section.get("use_cvss", True)),
+            )
+        except (TypeError, ValueError) as e:
+            raise ConfigurationError("risk", {"error": str(e)}) from e
+
+        limits = [model.grade_thresholds[grade] for grade in GRADES]
+        if limits != sorted(limits):
+            raise ConfigurationError(
+                "risk.grade_thresholds", {"error": "thresholds must increase from A to D"}
+            )
+        return model
+
+    def finding_score(self, finding: Dict[str, Any]) -> float:
+        """Compute the risk contribution of one finding."""
+        weight = self.severity_weights.get(str(finding.get("severity", "")).upper(), 0.0)
+        factor = 1.0
+        if self.use_cvss and finding.get("cvss_score") is not None:
+            factor = float(finding["cvss_score"]) / 10.0
+        return weight * factor * int(finding.get("occurrences") or 1)
+
+    def grade(self, score: float) -> str:
+        """Map a project score to a letter grade."""
+        for grade in GRADES:
+            if score <= self.grade_thresholds[grade]:
+                return grade
+        return "F"
+
+    def summarize(self, findings: List[Dict[str, Any]]) -> RiskSummary:
+        """Roll findings up into per-resource scores and a project grade."""
+        total = 0.0
+        by_resource: Dict[str, ResourceRisk] = {}
+
+        for finding in findings:
+            score = self.finding_score(finding)
+            total += score
+
+            resources = finding.get("affected_resources") or (
+                [finding["resource"]] if finding.get("resource") else []
+            )
+            for resource in resources:
+                entry = by_resource.setdefault(resource, ResourceRisk(resource, 0.0, 0))
+                entry.score += score
+                entry.findings += 1
+
+        resources = sorted(by_resource.values(), key=lambda r: (-r.score, r.resource))
+        for entry in resources:
+            entry.score = round(entry.score, 2)
+
+        total = round(total, 2)
+        return RiskSummary(score=total, grade=self.grade(total), resources=resources)
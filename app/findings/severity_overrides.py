@@ -0,0 +1,119 @@
+"""Config-driven severity remapping applied between analysis and reporting.
+
+Overrides live in the ``[severity_overrides]`` table of paddi.toml::
+
+    [severity_overrides]
+    WEAK_SSL_POLICY = "LOW"
+
+    [[severity_overrides.rules]]
+    category = "WEAK_SSL_POLICY"
+    resource = "projects/*-dev/*"
+    severity = "INFO"
+
+Plain ``CATEGORY = "SEVERITY"`` entries remap a category everywhere. Entries in
+``rules`` may also match a glob against the normalized resource name; they are
+checked in order before the plain entries, and the first match wins.
+"""
+
+import fnmatch
+import logging
+from dataclasses import dataclass
+from typing import Any, Dict, List, Optional
+
+from app.common.exceptions import ConfigurationError
+from app.common.models import SEVERITY_ORDER
+from app.config.paddi_config import PaddiConfig
+from app.findings.fingerprint import normalize_resource
+
+logger = logging.getLogger(__name__)
+
+
+@dataclass
+class SeverityOverrideRule:
+    """A single severity remapping rule."""
+
+    severity: str
+    category: Optional[str] = None
+    resource: Optional[str] = None
+
+    def matches(self, finding: Dict[str, Any]) -> bool:
+        """Check whether the rule applies to a finding."""
+        if self.category and str(finding.get("category") or "").upper() != self.category:
+            return False
+        if self.resource and not fnmatch.fnmatchcase(
+            normalize_resource(finding.get("resource", "")), self.resource
+        ):
+            return False
+        return True
+
+
+def _parse_rule(entry: Dict[str, Any], location: str) -> SeverityOverrideRule:
+    """Validate one rule entry from the configuration."""
+    severity = str(entry.get("severity", "")).upper()
+    if severity not in SEVERITY_ORDER:
+        raise ConfigurationError(
+            location, {"error": f"invalid severity: {entry.get('severity')!r}"}
+        )
+    if not entry.get("category") and not entry.get("resource"):
+        raise ConfigurationError(location, {"error": "rule needs a category or resource"})
+
+    return SeverityOverrideRule(
+        severity=severity,
+        category=str(entry["category"]).upper() if entry.get("category") else None,
+        resource=normalize_resource(entry["resource"]) if entry.get("resource") else None,
+    )
+
+
+class SeverityOverrides:
+    """Ordered set of severity remapping rules."""
+
+    def __init__(self, rules: Optional[List[SeverityOverrideRule]] = None):
+        """Initialize SeverityOverrides with rules in match order."""
+        self.rules = rules or []
+
+    @classmethod
+    def from_config(cls, config: Optional[PaddiConfig] = None) -> "SeverityOverrides":
+        """Create overrides from the ``[severity_overrides]`` section of paddi.toml.
+
+        Raises:
+            ConfigurationError: If an entry has an unknown severity or no match criteria
+        """
+        section = (config or PaddiConfig.load()).section("severity_overrides")
+
+        rules = [
+            _parse_rule(entry, f"severity_overrides.rules[{i}]")
+            for i, entry in enumerate(section.get("rules", []))
+        ]
+        for category, severity in section.items():
+            if category == "rules":
+                continue
+            rules.append(
+                _parse_rule(
+                    {"category": category, "severity": severity},
+                    f"severity_overrides.{category}",
+                )
+            )
+        return cls(rules)
+
+    def apply(self, findings: List[Dict[str, Any]]) -> List[Dict[str, Any]]:
+        """Return findings with overridden severities.
+
+        Changed findings keep their previous rating in ``original_severity``.
+        """
+        result = []
+        changed = 0
+        for finding in findings:
+            rule = next((r for r in self.rules if r.matches(finding)), None)
+            if rule is None or str(finding.get("severity", "")).upper() == rule.severity:
+                result.append(finding)
+                continue
+
+            updated = dict(finding)
+            updated.setdefault("original_severity", finding.get("severity"))
+            updated["severity"] = rule.severity
+            result.append(updated)
+            changed += 1
+
+        if changed:
+            logger.info("Applied severity overrides to %d findings", changed)
+        return result
@@ -0,0 +1,190 @@
+"""Suppressions of accepted findings, each with an owner, a justification, and an expiry.
+
+Suppressions live in the ``[suppressions]`` table of paddi.toml::
+
+    [[suppressions.rules]]
+    fingerprint = "3f2a9c1d5e7b8a40"
+    owner = "platform-team@example.com"
+    justification = "Public by design: serves the marketing site"
+    expires = 2025-12-31
+
+    [[suppressions.rules]]
+    category = "WEAK_SSL_POLICY"
+    resource = "projects/*-dev/*"
+    owner = "alice@example.com"
+    justification = "Dev load balancers are replaced in Q3"
+    expires = 2025-09-30
+
+A rule matches a finding by fingerprint, or by category and a glob against the
+normalized resource name. Every rule needs an owner, a justification, and an
+expiry date. A suppression applies up to and including its expiry date; after
+that its findings are reported again, and the rule is listed in the report's
+"Expired Suppressions" section until it is renewed or removed.
+"""
+
+import fnmatch
+import logging
+from dataclasses import dataclass, field
+from datetime import date
+from typing import Any, Dict, List, Optional
+
+from app.common.exceptions import ConfigurationError
+from app.config.paddi_config import PaddiConfig
+from app.findings.fingerprint import compute_fingerprint, normalize_resource
+
+logger = logging.getLogger(__name__)
+
+REQUIRED_FIELDS = ("owner", "justification", "expires")
+
+
+@dataclass
+class Suppression:
+    """A single accepted-risk rule."""
+
+    owner: str
+    justification: str
+    expires: date
+    fingerprint: Optional[str] = None
+    category: Optional[str] = None
+    resource: Optional[str] = None
+
+    def matches(self, finding: Dict[str, Any]) -> bool:
+        """Check whether the rule applies to a finding."""
+        if self.fingerprint:
+            return (finding.get("fingerprint") or compute_fingerprint(finding)) == self.fingerprint
+        if self.category and str(finding.get("category") or "").upper() != self.category:
+            return False
+        if self.resource and not fnmatch.fnmatchcase(
+            normalize_resource(finding.get("resource", "")), self.resource
+        ):
+            return False
+        return True
+
+    def expired(self, today: date) -> bool:
+        """Whether the suppression no longer applies on the given day."""
+        return today > self.expires
+
+    @property
+    def target(self) -> str:
+        """Describe what the rule matches."""
+        if self.fingerprint:
+            return f"fingerprint {self.fingerprint}"
+        return " ".join(part for part in (self.category, self.resource) if part)
+
+    def to_dict(self) -> Dict[str, Any]:
+        """Convert to dictionary for JSON serialization."""
+        return {
+            "owner": self.owner,
+            "justification": self.justification,
+            "expires": self.expires.isoformat(),
+            "fingerprint": self.fingerprint,
+            "category": self.category,
+            "resource": self.resource,
+        }
+
+
+@dataclass
+class ExpiredSuppression:
+    """An expired suppression and the findings it no longer hides."""
+
+    suppression: Suppression
+    findings: List[Dict[str, Any]] = field(default_factory=list)
+
+
+@dataclass
+class SuppressionResult:
+    """Findings split into reported and suppressed ones."""
+
+    findings: List[Dict[str, Any]] = field(default_factory=list)
+    suppressed: List[Dict[str, Any]] = field(default_factory=list)
+    expired: List[ExpiredSuppression] = field(default_factory=list)
+
+
+def _parse_expiry(value: Any, location: str) -> date:
+    """Read an expiry given as a TOML date or an ISO date string."""
+    if isinstance(value, date):
+        return value
+    try:
+        return date.fromisoformat(str(value))
+    except ValueError:
+        raise ConfigurationError(
+            location, {"error": f"expires must be a date (YYYY-MM-DD), got {value!r}"}
+        ) from None
+
+
+def _parse_rule(entry: Dict[str, Any], location: str) -> Suppression:
+    """Validate one rule entry from the configuration."""
+    missing = [name for name in REQUIRED_FIELDS if not entry.get(name)]
+    if missing:
+        raise ConfigurationError(location, {"error": f"missing {', '.join(missing)}"})
+    if not any(entry.get(name) for name in ("fingerprint", "category", "resource")):
+        raise ConfigurationError(
+            location, {"error": "rule needs a fingerprint, category, or resource"}
+        )
+
+    return Suppression(
+        owner=str(entry["owner"]),
+        justification=str(entry["justification"]),
+        expires=_parse_expiry(entry["expires"], location),
+        fingerprint=str(entry["fingerprint"]) if entry.get("fingerprint") else None,
+        category=str(entry["category"]).upper() if entry.get("category") else None,
+        resource=normalize_resource(entry["resource"]) if entry.get("resource") else None,
+    )
+
+
+class Suppressions:
+    """Ordered set of suppression rules."""
+
+    def __init__(self, rules: Optional[List[Suppression]] = None):
+        """Initialize Suppressions with rules in match order."""
+        self.rules = rules or []
+
+    @classmethod
+    def from_config(cls, config: Optional[PaddiConfig] = None) -> "Suppressions":
+        """Create suppressions from the ``[suppressions]`` section of paddi.toml.
+
+        Raises:
+            ConfigurationError: If a rule lacks an owner, justification, expiry, or match
+        """
+        section = (config or PaddiConfig.load()).section("suppressions")
+        return cls(
+            [
+                _parse_rule(entry, f"suppressions.rules[{i}]")
+                for i, entry in enumerate(section.get("rules", []))
+            ]
+        )
+
+    def apply(
+        self, findings: List[Dict[str, Any]], today: Optional[date] = None
+    ) -> SuppressionResult:
+        """Split findings into reported and suppressed ones.
+
+        Suppressed findings carry the matching rule in ``suppression``. Findings
+        matched only by expired rules are reported again.
+        """
+        today = today or date.today()
+        active = [rule for rule in self.rules if not rule.expired(today)]
+        expired = {id(rule): ExpiredSuppression(rule) for rule in self.rules if rule.expired(today)}
+
+        result = SuppressionResult(expired=list(expired.values()))
+        for finding in findings:
+            rule = next((r for r in active if r.matches(finding)), None)
+            if rule is not None:
+                result.suppressed.append({**finding, "suppression": rule.to_dict()})
+                continue
+            for entry in expired.values():
+                if entry.suppression.matches(finding):
+                    entry.findings.append(finding)
+            result.findings.append(finding)
+
+        if result.suppressed:
+            logger.info("Suppressed %d findings", len(result.suppressed))
+        for entry in result.expired:
+            logger.warning(
+                "Suppression of %s (owner %s) expired on %s; %d finding(s) reported again",
+                entry.suppression.target,
+                entry.suppression.owner,
+                entry.suppression.expires,
+                len(entry.findings),
+            )
+        return result
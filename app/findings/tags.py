@@ -0,0 +1,101 @@
+"""Config-driven tags assigned to resources.
+
+Tags are defined in paddi.toml as glob patterns over normalized resource
+names (lower-case, without ``//`` or ``https://`` prefix)::
+
+    [tags]
+    pci = ["compute.googleapis.com/projects/payments-*", "storage.googleapis.com/cardholder-*"]
+    prod = ["*prod*"]
+
+Users can also tag single findings with ``paddi findings tag``; those tags are
+kept in the run store by finding fingerprint. A finding's ``tags`` field holds
+both kinds, config-driven tags first.
+"""
+
+import fnmatch
+from pathlib import Path
+from typing import Any, Dict, List, Optional, Union
+
+from app.config.paddi_config import PaddiConfig
+from app.findings.fingerprint import compute_fingerprint, normalize_resource
+from app.history.store import HISTORY_DB_NAME, RunStore
+
+
+class TagRules:
+    """Maps resources to tags by glob pattern."""
+
+    def __init__(self, rules: Optional[Dict[str, List[str]]] = None):
+        """Initialize TagRules with tag names mapped to resource patterns."""
+        self.rules = {
+            tag: [normalize_resource(pattern) for pattern in patterns]
+            for tag, patterns in (rules or {}).items()
+        }
+
+    @classmethod
+    def from_config(cls, config: Optional[PaddiConfig] = None) -> "TagRules":
+        """Create rules from the ``[tags]`` section of paddi.toml."""
+        section = (config or PaddiConfig.load()).section("tags")
+        return cls(
+            {
+                tag: [patterns] if isinstance(patterns, str) else list(patterns)
+                for tag, patterns in section.items()
+            }
+        )
+
+    def tags_for(self, resource: Optional[str]) -> List[str]:
+        """Return the tags whose patterns match a resource, in definition order."""
+        name = normalize_resource(resource or "")
+        if not name:
+            return []
+        return [
+            tag
+            for tag, patterns in self.rules.items()
+            if any(fnmatch.fnmatchcase(name, pattern) for pattern in patterns)
+        ]
+
+    def tags_for_finding(self, finding: Dict[str, Any]) -> List[str]:
+        """Return the tags of every resource a finding applies to."""
+        resources = finding.get("affected_resources") or [finding.get("resource")]
+        tags: List[str] = []
+        for resource in resources:
+            for tag in self.tags_for(resource):
+                if tag not in tags:
+                    tags.append(tag)
+        return tags
+
+    def apply(
+        self, findings: List[Dict[str, Any]], stored: Optional[Dict[str, List[str]]] = None
+    ) -> List[Dict[str, Any]]:
+        """Return findings with their ``tags`` set from the rules and stored tags.
+
+        Args:
+            findings: Findings as loaded from explained.json
+            stored: Tags by finding fingerprint, as kept in the run store
+        """
+        result = []
+        for finding in findings:
+            tags = list(finding.get("tags") or [])
+            extra = self.tags_for_finding(finding)
+            if stored:
+                fingerprint = finding.get("fingerprint") or compute_fingerprint(finding)
+                extra += stored.get(fingerprint, [])
+            for tag in extra:
+                if tag not in tags:
+                    tags.append(tag)
+            result.append({**finding, "tags": tags} if tags else finding)
+        return result
+
+
+def load_finding_tags(data_dir: Union[str, Path]) -> Dict[str, List[str]]:
+    """Load the tags that users put on findings, if the run store exists."""
+    history = Path(data_dir) / HISTORY_DB_NAME
+    if not history.exists():
+        return {}
+    return RunStore(history).finding_tags()
+
+
+def tag_findings(
+    findings: List[Dict[str, Any]], data_dir: Union[str, Path] = "data"
+) -> List[Dict[str, Any]]:
+    """Tag findings from the ``[tags]`` rules and the tags stored under data_dir."""
+    return TagRules.from_config().apply(findings, load_finding_tags(data_dir))
@@ -0,0 +1 @@
+"""Run history: findings from past audits for trends, baselines, and diffs."""
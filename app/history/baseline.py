@@ -0,0 +1,67 @@
+"""Comparison of findings against the accepted baseline run.
+
+``paddi baseline set`` marks a recorded run as accepted. Later audits report
+only the deviations from it: findings that are not in the baseline (new) and
+baseline findings that are gone (resolved). ``--fail-on-new`` gates CI on new
+findings alone, so an accepted backlog does not fail every build.
+"""
+
+from dataclasses import dataclass, field
+from typing import Any, Callable, Dict, List, Optional
+
+from app.findings.delta import findings_delta
+from app.findings.listing import format_table, sort_findings
+
+from .store import RunRecord, RunStore
+
+
+@dataclass
+class BaselineComparison:
+    """Deviations of the current findings from the baseline run."""
+
+    baseline: RunRecord
+    new: List[Dict[str, Any]] = field(default_factory=list)
+    resolved: List[Dict[str, Any]] = field(default_factory=list)
+    unchanged: int = 0
+
+    def to_dict(self) -> Dict[str, Any]:
+        """Convert to dictionary for JSON serialization."""
+        return {
+            "baseline": {
+                "run_id": self.baseline.run_id,
+                "created_at": self.baseline.created_at,
+                "total": self.baseline.total,
+            },
+            "new": self.new,
+            "resolved": self.resolved,
+            "unchanged": self.unchanged,
+        }
+
+
+def compare_to_baseline(
+    store: RunStore, findings: List[Dict[str, Any]]
+) -> Optional[BaselineComparison]:
+    """Compare findings with the baseline run; None when no baseline is set."""
+    baseline = store.baseline()
+    if baseline is None:
+        return None
+    new, resolved = findings_delta(findings, store.findings_for(baseline.run_id))
+    return BaselineComparison(baseline, new, resolved, len(findings) - len(new))
+
+
+def format_comparison(
+    comparison: BaselineComparison,
+    severity_style: Optional[Callable[[str, str], str]] = None,
+) -> str:
+    """Format the deviations from the baseline for the terminal."""
+    baseline = comparison.baseline
+    lines = [
+        f"Compared with baseline run #{baseline.run_id} ({baseline.created_at[:10]}): "
+        f"{len(comparison.new)} new, {len(comparison.resolved)} resolved, "
+        f"{comparison.unchanged} unchanged"
+    ]
+    for label, findings in (("New", comparison.new), ("Resolved", comparison.resolved)):
+        if findings:
+            table = format_table(sort_findings(findings), severity_style=severity_style)
+            lines.extend(["", f"{label}:", table])
+    return "\n".join(lines)
@@ -0,0 +1,124 @@
+"""Where a run was made: git commit, host, versions, and configuration.
+
+Each run recorded in the history database carries this snapshot, so its
+findings can be traced to the infrastructure-as-code change that introduced
+or fixed them::
+
+    paddi query "SELECT id, json_extract(environment, '$.git.commit') FROM runs"
+
+- ``git``: commit, branch, whether the working tree had uncommitted changes,
+  and the ``origin`` remote without credentials, when Paddi runs inside a git
+  repository
+- ``hostname`` and ``cli_version``
+- ``agents``: version of each built-in agent, and a digest of each installed
+  plugin, which changes whenever the plugin is updated
+- ``config_hash``: SHA-256 of ``paddi.toml`` as written (secret references are
+  hashed, never resolved)
+"""
+
+import hashlib
+import json
+import socket
+import subprocess  # nosec B404
+from importlib import metadata
+from pathlib import Path
+from typing import Any, Dict, Optional
+from urllib.parse import urlsplit, urlunsplit
+
+from app.config.paddi_config import PaddiConfig
+from app.plugins.exec_plugins import discover_exec_plugins
+from app.plugins.wasm_host import WasmPluginHost
+
+AGENTS = ("collector", "explainer", "reporter")
+GIT_TIMEOUT_SECONDS = 5
+# Length of the plugin digests; enough to tell versions apart
+DIGEST_LENGTH = 12
+
+
+def _git(args: list, cwd: Optional[Path]) -> Optional[str]:
+    """Run a git command, returning its output or None if it fails."""
+    try:
+        result = subprocess.run(  # nosec B603 B607
+            ["git", *args],
+            cwd=cwd,
+            capture_output=True,
+            text=True,
+            timeout=GIT_TIMEOUT_SECONDS,
+            check=False,
+        )
+    except (OSError, subprocess.TimeoutExpired):
+        return None
+    return result.stdout.strip() if result.returncode == 0 else None
+
+
+def _without_credentials(url: str) -> str:
+    """Remove a user name and password from a remote URL."""
+    parts = urlsplit(url)
+    if not parts.scheme or "@" not in parts.netloc:
+        return url
+    return urlunsplit(parts._replace(netloc=parts.netloc.rsplit("@", 1)[1]))
+
+
+def git_info(cwd: Optional[Path] = None) -> Optional[Dict[str, Any]]:
+    """Describe the git checkout Paddi runs in, or None outside a repository."""
+    commit = _git(["rev-parse", "HEAD"], cwd)
+    if not commit:
+        return None
+    branch = _git(["rev-parse", "--abbrev-ref", "HEAD"], cwd)
+    status = _git(["status", "--porcelain", "--untracked-files=no"], cwd)
+    remote = _git(["remote", "get-url", "origin"], cwd)
+    return {
+        "commit": commit,
+        "branch": None if branch in (None, "HEAD") else branch,
+        "dirty": bool(status),
+        "remote": _without_credentials(remote) if remote else None,
+    }
+
+
+def cli_version() -> str:
+    """Return the installed version of Paddi."""
+    try:
+        return metadata.version("paddi")
+    except metadata.PackageNotFoundError:
+        return "unknown"
+
+
+def _digest(path: Path) -> Optional[str]:
+    """Return a short SHA-256 of a file."""
+    try:
+        return hashlib.sha256(path.read_bytes()).hexdigest()[:DIGEST_LENGTH]
+    except OSError:
+        return None
+
+
+def agent_versions(config: Optional[PaddiConfig] = None) -> Dict[str, Any]:
+    """Return the version of each built-in agent and the digest of each plugin."""
+    version = cli_version()
+    agents: Dict[str, Any] = {agent: version for agent in AGENTS}
+    for name, plugin in sorted(discover_exec_plugins().items()):
+        agents[f"plugin:{name}"] = _digest(plugin.path)
+    for path in WasmPluginHost.from_config(config).discover():
+        agents[f"wasm:{path.stem}"] = _digest(path)
+    return agents
+
+
+def config_hash(config: PaddiConfig) -> Optional[str]:
+    """Return the SHA-256 of the configuration as written, or None without one."""
+    if not config.data:
+        return None
+    canonical = json.dumps(config.data, sort_keys=True, default=str)
+    return hashlib.sha256(canonical.encode("utf-8")).hexdigest()
+
+
+def run_environment(
+    config: Optional[PaddiConfig] = None, cwd: Optional[Path] = None
+) -> Dict[str, Any]:
+    """Describe the environment of the current run."""
+    config = config or PaddiConfig.load()
+    return {
+        "git": git_info(cwd),
+        "hostname": socket.gethostname(),
+        "cli_version": cli_version(),
+        "agents": agent_versions(config),
+        "config_hash": config_hash(config),
+    }
@@ -0,0 +1,107 @@
+"""Estimates of how long each audit stage will take, from earlier runs.
+
+``paddi audit`` records the wall time of each agent with its run (see
+``app.common.resource_usage``). The estimate of a stage is the median of its
+wall time over the most recent runs of the same project, or of any project when
+that one has not been audited yet. The median keeps one slow or interrupted
+run from skewing the estimate.
+"""
+
+import logging
+import sqlite3
+import statistics
+from dataclasses import dataclass, field
+from datetime import datetime, timedelta
+from pathlib import Path
+from typing import Dict, List, Optional
+
+from .store import HISTORY_DB_NAME, RunRecord, RunStore
+
+logger = logging.getLogger(__name__)
+
+# Runs the estimates are taken from
+DEFAULT_SAMPLE_RUNS = 10
+# Agent whose wall time is recorded for each stage of the audit
+STAGE_AGENTS = {"collect": "collector", "explain": "explainer", "report": "reporter"}
+
+
+@dataclass
+class StageEstimates:
+    """Expected duration of each stage, in seconds."""
+
+    seconds: Dict[str, float] = field(default_factory=dict)
+    # Number of runs the estimates were taken from
+    samples: int = 0
+
+    def __bool__(self) -> bool:
+        return bool(self.seconds)
+
+    def stage(self, stage: str) -> Optional[float]:
+        """Return the expected duration of a stage, if it ran before."""
+        return self.seconds.get(stage)
+
+    def remaining(self, stages: List[str]) -> Optional[float]:
+        """Return the expected duration of the given stages, if all of them ran before."""
+        durations = [self.seconds.get(stage) for stage in stages]
+        if not durations or any(duration is None for duration in durations):
+            return None
+        return sum(durations)
+
+    @classmethod
+    def from_runs(cls, runs: List[RunRecord]) -> "StageEstimates":
+        """Estimate from the resource usage recorded with runs."""
+        durations: Dict[str, List[float]] = {}
+        samples = 0
+        for run in runs:
+            walls = {usage.get("agent"): usage.get("wall_seconds") for usage in run.resource_usage}
+            if not walls:
+                continue
+            samples += 1
+            for stage, agent in STAGE_AGENTS.items():
+                if isinstance(walls.get(agent), (int, float)):
+                    durations.setdefault(stage, []).append(float(walls[agent]))
+        seconds = {stage: statistics.median(values) for stage, values in durations.items()}
+        return cls(seconds, samples)
+
+    @classmethod
+    def from_store(
+        cls,
+        store: RunStore,
+        project_id: Optional[str] = None,
+        sample_runs: int = DEFAULT_SAMPLE_RUNS,
+    ) -> "StageEstimates":
+        """Estimate from the latest runs of a project, or of any project without one."""
+        runs = [run for run in store.runs() if run.resource_usage]
+        own = [run for run in runs if run.project_id == project_id]
+        return cls.from_runs((own or runs)[:sample_runs])
+
+    @classmethod
+    def load(
+        cls, project_id: Optional[str] = None, data_dir: Path = Path("data")
+    ) -> "StageEstimates":
+        """Estimate from ``<data_dir>/history.db``; empty if there is no history yet."""
+        history = Path(data_dir) / HISTORY_DB_NAME
+        if not history.exists():
+            return cls()
+        try:
+            return cls.from_store(RunStore(history), project_id)
+        except sqlite3.Error as e:
+            logger.debug("Cannot estimate stage durations from %s: %s", history, e)
+            return cls()
+
+
+def format_seconds(seconds: float) -> str:
+    """Format a duration as e.g. ``45s``, ``3m 20s``, or ``1h 05m``."""
+    seconds = int(round(seconds))
+    if seconds < 60:
+        return f"{seconds}s"
+    minutes, seconds = divmod(seconds, 60)
+    if minutes < 60:
+        return f"{minutes}m {seconds:02d}s"
+    hours, minutes = divmod(minutes, 60)
+    return f"{hours}h {minutes:02d}m"
+
+
+def finish_time(seconds: float, now: Optional[datetime] = None) -> str:
+    """Return the local clock time ``seconds`` from now, e.g. ``14:32:05``."""
+    return ((now or datetime.now()) + timedelta(seconds=seconds)).strftime("%H:%M:%S")
@@ -0,0 +1,294 @@
+"""Run manifests: a structured record of what each audit did.
+
+Every ``paddi audit`` writes ``data/runs/<run_id>/run.json``, so downstream
+tooling and ``paddi runs`` read the state of a run from one file instead of
+inferring it from the files lying in ``data/`` and ``output/``::
+
+    {
+      "version": 1,
+      "run_id": "20240610T120000Z-3f2a9c",
+      "command": "audit",
+      "status": "succeeded",
+      "exit_code": 0,
+      "started_at": "...", "finished_at": "...", "duration_seconds": 12.5,
+      "inputs": {"project_id": "prod", "use_mock": false, ...},
+      "stages": [{"name": "collect", "status": "succeeded", "duration_seconds": 3.1, ...}],
+      "artifacts": [{"stage": "collect", "path": "data/collected.json", "size": 123,
+                     "sha256": "..."}],
+      "failures": [],
+      "history_run_id": 42
+    }
+
+``status`` is ``succeeded``, ``partial`` (``--continue-on-error`` recorded
+failures), or ``failed``. A stage that did not run is ``skipped``. An artifact
+is a file that its stage wrote; its checksum lets tooling verify it later.
+"""
+
+import hashlib
+import json
+import logging
+import secrets
+import time
+from contextlib import contextmanager
+from dataclasses import dataclass, field
+from datetime import datetime, timezone
+from pathlib import Path
+from typing import Any, Dict, Iterator, List, Optional, Sequence, Union
+
+logger = logging.getLogger(__name__)
+
+MANIFEST_VERSION = 1
+MANIFEST_NAME = "run.json"
+RUNS_DIR = Path("data") / "runs"
+
+SUCCEEDED = "succeeded"
+PARTIAL = "partial"
+FAILED = "failed"
+SKIPPED = "skipped"
+
+
+def _now() -> str:
+    return datetime.now(timezone.utc).isoformat(timespec="seconds")
+
+
+def _sha256(path: Path) -> str:
+    digest = hashlib.sha256()
+    with open(path, "rb") as f:
+        for chunk in iter(lambda: f.read(1024 * 1024), b""):
+            digest.update(chunk)
+    return digest.hexdigest()
+
+
+def new_run_id(when: Optional[datetime] = None) -> str:
+    """Return a run ID that sorts by start time: ``<UTC timestamp>-<random>``."""
+    timestamp = (when or datetime.now(timezone.utc)).strftime("%Y%m%dT%H%M%SZ")
+    return f"{timestamp}-{secrets.token_hex(3)}"
+
+
+@dataclass
+class StageRecord:
+    """One stage of a run."""
+
+    name: str
+    status: str = SKIPPED
+    started_at: Optional[str] = None
+    duration_seconds: Optional[float] = None
+    error: Optional[str] = None
+
+    def to_dict(self) -> Dict[str, Any]:
+        """Convert to dictionary for JSON serialization."""
+        return {
+            "name": self.name,
+            "status": self.status,
+            "started_at": self.started_at,
+            "duration_seconds": self.duration_seconds,
+            "error": self.error,
+        }
+
+
+@dataclass
+class ArtifactRecord:
+    """A file written by a stage."""
+
+    stage: str
+    path: str
+    size: int
+    sha256: str
+
+    def to_dict(self) -> Dict[str, Any]:
+        """Convert to dictionary for JSON serialization."""
+        return {"stage": self.stage, "path": self.path, "size": self.size, "sha256": self.sha256}
+
+
+@dataclass
+class RunManifest:
+    """The manifest of one run, filled in as the run goes."""
+
+    command: str
+    inputs: Dict[str, Any] = field(default_factory=dict)
+    run_id: str = field(default_factory=new_run_id)
+    started_at: str = field(default_factory=_now)
+    stages: List[StageRecord] = field(default_factory=list)
+    artifacts: List[ArtifactRecord] = field(default_factory=list)
+    failures: List[Dict[str, Any]] = field(default_factory=list)
+    status: Optional[str] = None
+    exit_code: Optional[int] = None
+    error: Optional[str] = None
+    finished_at: Optional[str] = None
+    duration_seconds: Optional[float] = None
+    history_run_id: Optional[int] = None
+    _clock: float = field(default_factory=time.monotonic, repr=False)
+
+    @classmethod
+    def start(
+        cls, command: str, stages: Sequence[str], inputs: Optional[Dict[str, Any]] = None
+    ) -> "RunManifest":
+        """Begin the manifest of a run with the given stages, all skipped so far."""
+        return cls(
+            command=command,
+            inputs=dict(inputs or {}),
+            stages=[StageRecord(name) for name in stages],
+        )
+
+    def _stage(self, name: str) -> StageRecord:
+        record = next((stage for stage in self.stages if stage.name == name), None)
+        if record is None:
+            record = StageRecord(name)
+            self.stages.append(record)
+        return record
+
+    @contextmanager
+    def stage(
+        self, name: str, outputs: Sequence[Union[str, Path]] = ()
+    ) -> Iterator[StageRecord]:
+        """Record the block as stage ``name``, with the files it wrote under ``outputs``.
+
+        ``outputs`` are files or directories; files in them changed during the
+        stage are recorded as its artifacts.
+        """
+        record = self._stage(name)
+        record.started_at = _now()
+        started = time.monotonic()
+        wall_start = time.time()
+        try:
+            yield record
+        except BaseException as e:
+            record.status = FAILED
+            record.error = getattr(e, "message", None) or str(e) or type(e).__name__
+            raise
+        else:
+            record.status = SUCCEEDED
+        finally:
+            record.duration_seconds = round(time.monotonic() - started, 3)
+            self._add_artifacts(name, outputs, wall_start)
+
+    def _add_artifacts(
+        self, stage: str, outputs: Sequence[Union[str, Path]], since: float
+    ) -> None:
+        """Record the files under ``outputs`` modified since ``since``."""
+        for output in outputs:
+            root = Path(output)
+            files = sorted(p for p in root.rglob("*") if p.is_file()) if root.is_dir() else [root]
+            for path in files:
+                try:
+                    stat = path.stat()
+                    # Allow for file systems with coarse modification times
+                    if stat.st_mtime < since - 1:
+                        continue
+                    self.artifacts.append(
+                        ArtifactRecord(stage, path.as_posix(), stat.st_size, _sha256(path))
+                    )
+                except OSError:
+                    continue
+
+    def finish(self, error: Optional[BaseException] = None, exit_code: int = 0) -> None:
+        """Set the outcome of the run."""
+        self.finished_at = _now()
+        self.duration_seconds = round(time.monotonic() - self._clock, 3)
+        self.exit_code = exit_code
+        if error is not None:
+            self.error = getattr(error, "message", None) or str(error) or type(error).__name__
+        if self.failures:
+            self.status = PARTIAL
+        elif error is not None:
+            self.status = FAILED
+        else:
+            self.status = SUCCEEDED
+
+    def to_dict(self) -> Dict[str, Any]:
+        """Convert to dictionary for JSON serialization."""
+        return {
+            "version": MANIFEST_VERSION,
+            "run_id": self.run_id,
+            "command": self.command,
+            "status": self.status,
+            "exit_code": self.exit_code,
+            "error": self.error,
+            "started_at": self.started_at,
+            "finished_at": self.finished_at,
+            "duration_seconds": self.duration_seconds,
+            "inputs": self.inputs,
+            "stages": [stage.to_dict() for stage in self.stages],
+            "artifacts": [artifact.to_dict() for artifact in self.artifacts],
+            "failures": self.failures,
+            "history_run_id": self.history_run_id,
+        }
+
+    def save(self, runs_dir: Path = RUNS_DIR) -> Path:
+        """Write ``<runs_dir>/<run_id>/run.json``."""
+        path = Path(runs_dir) / self.run_id / MANIFEST_NAME
+        path.parent.mkdir(parents=True, exist_ok=True)
+        path.write_text(
+            json.dumps(self.to_dict(), indent=2, ensure_ascii=False, default=str), encoding="utf-8"
+        )
+        logger.debug("Run manifest written to %s", path)
+        return path
+
+
+def load_manifests(runs_dir: Path = RUNS_DIR, limit: Optional[int] = None) -> List[Dict[str, Any]]:
+    """Return the manifests in ``runs_dir``, newest first; unreadable ones are skipped."""
+    manifests = []
+    paths = sorted(Path(runs_dir).glob(f"*/{MANIFEST_NAME}"), reverse=True)
+    for path in paths:
+        try:
+            manifests.append(json.loads(path.read_text(encoding="utf-8")))
+        except (OSError, ValueError) as e:
+            logger.warning("Skipping unreadable run manifest %s: %s", path, e)
+            continue
+        if limit is not None and len(manifests) >= limit:
+            break
+    return manifests
+
+
+def load_manifest(run_id: str, runs_dir: Path = RUNS_DIR) -> Dict[str, Any]:
+    """Return the manifest of a run; a unique prefix of its ID is enough.
+
+    Raises:
+        FileNotFoundError: If no run, or more than one, matches
+    """
+    matches = sorted(p for p in Path(runs_dir).glob(f"{run_id}*/{MANIFEST_NAME}"))
+    if len(matches) != 1:
+        problem = "is ambiguous" if matches else "not found"
+        raise FileNotFoundError(f"Run {run_id} {problem} in {runs_dir}")
+    return json.loads(matches[0].read_text(encoding="utf-8"))
+
+
+def format_manifests(manifests: List[Dict[str, Any]]) -> str:
+    """Format manifests as a table of runs."""
+    lines = [f"{'Run ID':<24}  {'Status':<9}  {'Exit':>4}  {'Duration':>9}  Project"]
+    for manifest in manifests:
+        duration = manifest.get("duration_seconds")
+        lines.append(
+            f"{manifest.get('run_id', ''):<24}  {manifest.get('status') or '-':<9}  "
+            f"{manifest.get('exit_code') if manifest.get('exit_code') is not None else '-':>4}  "
+            f"{'-' if duration is None else f'{duration:.1f}s':>9}  "
+            f"{(manifest.get('inputs') or {}).get('project_id') or '-'}"
+        )
+    return "\n".join(lines)
+
+
+def format_manifest(manifest: Dict[str, Any]) -> str:
+    """Format one manifest: the run, its stages, and its artifacts."""
+    lines = [
+        f"Run {manifest.get('run_id')}: {manifest.get('status')} "
+        f"(exit {manifest.get('exit_code')}), {manifest.get('duration_seconds')}s",
+        f"Started {manifest.get('started_at')}, finished {manifest.get('finished_at')}",
+    ]
+    if manifest.get("error"):
+        lines.append(f"Error: {manifest['error']}")
+    if manifest.get("history_run_id") is not None:
+        lines.append(f"History run: #{manifest['history_run_id']}")
+    lines.append("")
+    lines.append(f"{'Stage':<10}  {'Status':<9}  {'Duration':>9}  Error")
+    for stage in manifest.get("stages", []):
+        duration = stage.get("duration_seconds")
+        lines.append(
+            f"{stage.get('name', ''):<10}  {stage.get('status', ''):<9}  "
+            f"{'-' if duration is None else f'{duration:.1f}s':>9}  {stage.get('error') or ''}"
+        )
+    if manifest.get("artifacts"):
+        lines.append("")
+        for artifact in manifest["artifacts"]:
+            digest = artifact["sha256"][:12]
+            lines.append(f"  {artifact['path']}  ({artifact['size']} bytes, sha256 {digest})")
+    return "\n".join(lines)
@@ -0,0 +1,176 @@
+"""Retention of recorded runs and archives, so unattended deployments don't fill disks.
+
+Configured in paddi.toml; every limit is optional::
+
+    [retention]
+    max_runs = 90                 # runs kept in the history store (newest first)
+    max_age_days = 365            # runs and archives older than this are deleted
+    max_disk_bytes = 1073741824   # budget for history.db plus the archives
+
+The policy applies to the run history in ``data/history.db`` and to the
+``.tar.zst`` archives in ``[archive] dir``. The newest run is always kept,
+because the next run's delta is computed against it, and so is the baseline
+run. The disk budget is met by deleting the oldest archives first, then the
+oldest runs.
+"""
+
+import logging
+import math
+from dataclasses import dataclass, field
+from datetime import datetime, timedelta, timezone
+from pathlib import Path
+from typing import List, Optional
+
+from app.common.exceptions import ConfigurationError
+from app.config.paddi_config import PaddiConfig
+from app.storage.archive import ARCHIVE_SUFFIX, checksum_path
+
+from .store import HISTORY_DB_NAME, RunRecord, RunStore
+
+logger = logging.getLogger(__name__)
+
+
+@dataclass
+class RetentionPolicy:
+    """Limits on kept runs and archives; None means unlimited."""
+
+    max_runs: Optional[int] = None
+    max_age_days: Optional[float] = None
+    max_disk_bytes: Optional[int] = None
+
+    @classmethod
+    def from_config(cls, config: Optional[PaddiConfig] = None) -> "RetentionPolicy":
+        """Create a policy from the ``[retention]`` section of paddi.toml.
+
+        Raises:
+            ConfigurationError: If a limit is not a positive number
+        """
+        section = (config or PaddiConfig.load()).section("retention")
+        limits = {}
+        for name in ("max_runs", "max_age_days", "max_disk_bytes"):
+            value = section.get(name)
+            if value is None:
+                continue
+            if isinstance(value, bool) or not isinstance(value, (int, float)) or value <= 0:
+                raise ConfigurationError(
+                    f"retention.{name}", {"error": f"must be a positive number, got {value!r}"}
+                )
+            limits[name] = value
+        return cls(**limits)
+
+    @property
+    def enabled(self) -> bool:
+        """Whether any limit is set."""
+        return any(v is not None for v in (self.max_runs, self.max_age_days, self.max_disk_bytes))
+
+
+@dataclass
+class RetentionResult:
+    """What a retention pass deleted (or would delete, in a dry run)."""
+
+    deleted_runs: List[int] = field(default_factory=list)
+    deleted_archives: List[Path] = field(default_factory=list)
+    freed_bytes: int = 0
+
+
+def archive_files(archive_dir: Path) -> List[Path]:
+    """Return the archives in a directory, oldest first."""
+    if not archive_dir.is_dir():
+        return []
+    return sorted(archive_dir.glob(f"*{ARCHIVE_SUFFIX}"), key=lambda p: p.stat().st_mtime)
+
+
+def _archive_size(archive: Path) -> int:
+    """Return the size of an archive and its checksum file."""
+    sidecar = checksum_path(archive)
+    return archive.stat().st_size + (sidecar.stat().st_size if sidecar.exists() else 0)
+
+
+def _modified_at(path: Path) -> datetime:
+    """Return the modification time of a file."""
+    return datetime.fromtimestamp(path.stat().st_mtime, timezone.utc)
+
+
+def _created_at(run: RunRecord) -> datetime:
+    """Parse the creation time of a run."""
+    created = datetime.fromisoformat(run.created_at)
+    return created if created.tzinfo else created.replace(tzinfo=timezone.utc)
+
+
+def apply_retention(
+    policy: RetentionPolicy,
+    data_dir: Path,
+    archive_dir: Path,
+    now: Optional[datetime] = None,
+    dry_run: bool = False,
+) -> RetentionResult:
+    """Delete runs and archives beyond the policy's limits.
+
+    Args:
+        policy: Limits to enforce
+        data_dir: Directory with history.db
+        archive_dir: Directory with the .tar.zst archives
+        now: Current time (for tests)
+        dry_run: Report what would be deleted without deleting it
+    """
+    result = RetentionResult()
+    if not policy.enabled:
+        return result
+
+    now = now or datetime.now(timezone.utc)
+    cutoff = now - timedelta(days=policy.max_age_days) if policy.max_age_days else None
+    db_path = Path(data_dir) / HISTORY_DB_NAME
+    store = RunStore(db_path) if db_path.exists() else None
+
+    # Newest first; the newest run and the baseline are always kept
+    runs = store.runs() if store else []
+    baseline = store.baseline() if store else None
+    protected = {runs[0].run_id if runs else None, baseline.run_id if baseline else None}
+    expired_runs = [
+        run
+        for i, run in enumerate(runs)
+        if run.run_id not in protected
+        and (
+            (policy.max_runs is not None and i >= policy.max_runs)
+            or (cutoff is not None and _created_at(run) < cutoff)
+        )
+    ]
+    kept_runs = [run for run in runs if run not in expired_runs]
+
+    archives = archive_files(Path(archive_dir))
+    expired_archives = [
+        path
+        for i, path in enumerate(archives)
+        if (policy.max_runs is not None and i < len(archives) - policy.max_runs)
+        or (cutoff is not None and _modified_at(path) < cutoff)
+    ]
+    kept_archives = [path for path in archives if path not in expired_archives]
+
+    if policy.max_disk_bytes is not None:
+        db_size = db_path.stat().st_size if db_path.exists() else 0
+        run_size = db_size / len(runs) if runs else 0
+        used = sum(_archive_size(p) for p in kept_archives) + run_size * len(kept_runs)
+        while used > policy.max_disk_bytes and kept_archives:
+            archive = kept_archives.pop(0)
+            expired_archives.append(archive)
+            used -= _archive_size(archive)
+        deletable = [run for run in kept_runs if run.run_id not in protected]
+        if used > policy.max_disk_bytes and deletable and run_size:
+            count = min(math.ceil((used - policy.max_disk_bytes) / run_size), len(deletable))
+            expired_runs.extend(deletable[-count:])
+
+    result.deleted_runs = sorted(run.run_id for run in expired_runs)
+    result.deleted_archives = expired_archives
+    result.freed_bytes = sum(_archive_size(p) for p in expired_archives)
+
+    if dry_run:
+        return result
+    for archive in expired_archives:
+        archive.unlink()
+        checksum_path(archive).unlink(missing_ok=True)
+        logger.info("Deleted archive %s", archive)
+    if store and result.deleted_runs:
+        before = db_path.stat().st_size
+        store.delete_runs(result.deleted_runs)
+        result.freed_bytes += max(before - db_path.stat().st_size, 0)
+    return result
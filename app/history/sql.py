@@ -0,0 +1,111 @@
+"""Read-only SQL over the run store, for ``paddi query``.
+
+The database is opened read-only, so a query cannot change recorded runs.
+The tables are those of the run store:
+
+- ``runs``: one row per recorded run (``id``, ``project_id``, ``created_at``,
+  ``total``, and ``severity_counts``, ``resource_usage``, and ``environment``
+  as JSON)
+- ``run_findings``: the findings of each run (``run_id``, ``fingerprint``,
+  ``severity``, ``category``, ``resource``, ``title``, and the whole finding
+  as JSON in ``data``)
+- ``baseline``, ``false_positives``, and ``finding_tags``
+"""
+
+import csv
+import io
+import sqlite3
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import Any, Dict, List
+
+# Longest cell shown in the terminal table; structured output is not truncated
+MAX_CELL_WIDTH = 60
+
+
+@dataclass
+class QueryResult:
+    """Columns and rows returned by a query."""
+
+    columns: List[str] = field(default_factory=list)
+    rows: List[tuple] = field(default_factory=list)
+
+    def to_dict(self) -> List[Dict[str, Any]]:
+        """Convert to one dictionary per row, for JSON or YAML output."""
+        return [dict(zip(self.columns, row)) for row in self.rows]
+
+    def to_csv(self) -> str:
+        """Convert to CSV with a header row."""
+        buffer = io.StringIO()
+        writer = csv.writer(buffer)
+        writer.writerow(self.columns)
+        writer.writerows(self.rows)
+        return buffer.getvalue()
+
+
+def _connect(path: Path) -> sqlite3.Connection:
+    """Open the run store read-only."""
+    conn = sqlite3.connect(f"{Path(path).resolve().as_uri()}?mode=ro", uri=True)
+    conn.execute("PRAGMA query_only = ON")
+    return conn
+
+
+def run_query(path: Path, sql: str) -> QueryResult:
+    """Run one SQL statement against the run store.
+
+    Raises:
+        sqlite3.Error: If the statement is invalid or tries to write
+    """
+    conn = _connect(path)
+    try:
+        cursor = conn.execute(sql)
+        columns = [column[0] for column in cursor.description or []]
+        return QueryResult(columns, [tuple(row) for row in cursor.fetchall()])
+    finally:
+        conn.close()
+
+
+def describe_tables(path: Path) -> Dict[str, List[str]]:
+    """Return the columns of each table in the run store."""
+    conn = _connect(path)
+    try:
+        names = [
+            row[0]
+            for row in conn.execute(
+                "SELECT name FROM sqlite_master WHERE type = 'table' "
+                "AND name NOT LIKE 'sqlite_%' ORDER BY rowid"
+            )
+        ]
+        return {
+            name: [row[1] for row in conn.execute(f'PRAGMA table_info("{name}")')]
+            for name in names
+        }
+    finally:
+        conn.close()
+
+
+def _cell(value: Any) -> str:
+    """Render a value for the terminal table."""
+    text = "NULL" if value is None else str(value).replace("\n", " ")
+    if len(text) > MAX_CELL_WIDTH:
+        return text[: MAX_CELL_WIDTH - 1] + "…"
+    return text
+
+
+def format_result(result: QueryResult) -> str:
+    """Format query results as a table for the terminal."""
+    if not result.columns:
+        return "Query returned no columns"
+
+    cells = [[_cell(value) for value in row] for row in result.rows]
+    widths = [
+        max([len(column), *(len(row[i]) for row in cells)])
+        for i, column in enumerate(result.columns)
+    ]
+    lines = [
+        "  ".join(column.ljust(width) for column, width in zip(result.columns, widths)),
+        "  ".join("-" * width for width in widths),
+    ]
+    lines.extend("  ".join(cell.ljust(width) for cell, width in zip(row, widths)) for row in cells)
+    lines.append(f"\n({len(result.rows)} row(s))")
+    return "\n".join(line.rstrip() for line in lines)
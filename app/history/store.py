@@ -0,0 +1,404 @@
+"""SQLite-backed store of audit runs and their findings.
+
+Each time a report is generated, its findings are recorded as a run of its
+project. A run is keyed by a hash of its findings, so regenerating a report from
+the same explained.json does not create a duplicate run of the project, and
+runs are compared with earlier runs of the same project only. One run can be marked as the
+accepted baseline that later runs are compared against. Findings that users
+mark as false positives are kept across runs, so analysis can skip them, and so
+are the tags that users put on findings. ``paddi audit`` also records the time
+and memory its agents used with the run (see :mod:`app.common.resource_usage`),
+and every run records where it was made: git commit, host, versions, and
+configuration hash (see :mod:`app.history.environment`).
+"""
+
+import hashlib
+import json
+import logging
+import sqlite3
+from contextlib import contextmanager
+from dataclasses import dataclass, field
+from datetime import datetime, timezone
+from pathlib import Path
+from typing import Any, Dict, Iterator, List, Optional
+
+from app.findings.fingerprint import compute_fingerprint
+
+logger = logging.getLogger(__name__)
+
+HISTORY_DB_NAME = "history.db"
+
+_SCHEMA = """
+CREATE TABLE IF NOT EXISTS runs (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    project_id TEXT,
+    created_at TEXT NOT NULL,
+    content_hash TEXT NOT NULL,
+    total INTEGER NOT NULL,
+    severity_counts TEXT NOT NULL,
+    resource_usage TEXT,
+    environment TEXT
+);
+CREATE TABLE IF NOT EXISTS run_findings (
+    run_id INTEGER NOT NULL REFERENCES runs(id) ON DELETE CASCADE,
+    fingerprint TEXT NOT NULL,
+    severity TEXT,
+    category TEXT,
+    resource TEXT,
+    title TEXT,
+    data TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS idx_run_findings_run ON run_findings(run_id);
+CREATE INDEX IF NOT EXISTS idx_run_findings_fingerprint ON run_findings(fingerprint);
+CREATE TABLE IF NOT EXISTS baseline (
+    id INTEGER PRIMARY KEY CHECK (id = 1),
+    run_id INTEGER NOT NULL REFERENCES runs(id) ON DELETE CASCADE,
+    set_at TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS false_positives (
+    fingerprint TEXT PRIMARY KEY,
+    category TEXT,
+    resource TEXT,
+    title TEXT,
+    reason TEXT,
+    marked_by TEXT,
+    marked_at TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS finding_tags (
+    fingerprint TEXT NOT NULL,
+    tag TEXT NOT NULL,
+    tagged_by TEXT,
+    tagged_at TEXT NOT NULL,
+    PRIMARY KEY (fingerprint, tag)
+);
+"""
+
+# Columns added to existing databases after their creation, with their types
+_ADDED_COLUMNS = {"runs": {"resource_usage": "TEXT", "environment": "TEXT"}}
+
+
+@dataclass
+class RunRecord:
+    """Summary of one recorded run."""
+
+    run_id: int
+    project_id: Optional[str]
+    created_at: str
+    total: int
+    severity_counts: Dict[str, int] = field(default_factory=dict)
+    # Time and memory used by each agent, when recorded by paddi audit
+    resource_usage: List[Dict[str, Any]] = field(default_factory=list)
+    # Git commit, host, and versions the run was made with (see app.history.environment)
+    environment: Dict[str, Any] = field(default_factory=dict)
+
+
+@dataclass
+class FalsePositive:
+    """A finding that a user marked as a false positive."""
+
+    fingerprint: str
+    category: Optional[str] = None
+    resource: Optional[str] = None
+    title: Optional[str] = None
+    reason: Optional[str] = None
+    marked_by: Optional[str] = None
+    marked_at: str = ""
+
+
+def _content_hash(findings: List[Dict[str, Any]]) -> str:
+    """Hash findings independent of their order."""
+    canonical = sorted(json.dumps(f, sort_keys=True, ensure_ascii=False) for f in findings)
+    return hashlib.sha256("\n".join(canonical).encode("utf-8")).hexdigest()
+
+
+class RunStore:
+    """Records audit runs in a local SQLite database."""
+
+    def __init__(self, path: Path):
+        """Initialize RunStore, creating the database schema if needed."""
+        self.path = Path(path)
+        self.path.parent.mkdir(parents=True, exist_ok=True)
+        with self._connect() as conn:
+            conn.executescript(_SCHEMA)
+            for table, columns in _ADDED_COLUMNS.items():
+                existing = {row["name"] for row in conn.execute(f"PRAGMA table_info({table})")}
+                for name, column_type in columns.items():
+                    if name not in existing:
+                        conn.execute(f"ALTER TABLE {table} ADD COLUMN {name} {column_type}")
+
+    @contextmanager
+    def _connect(self) -> Iterator[sqlite3.Connection]:
+        """Open a connection that commits on success and is always closed."""
+        conn = sqlite3.connect(self.path)
+        conn.row_factory = sqlite3.Row
+        conn.execute("PRAGMA foreign_keys = ON")
+        try:
+            with conn:
+                yield conn
+        finally:
+            conn.close()
+
+    def record_run(
+        self,
+        findings: List[Dict[str, Any]],
+        project_id: Optional[str] = None,
+        environment: Optional[Dict[str, Any]] = None,
+    ) -> RunRecord:
+        """Record a run, or return the project's latest run if its findings are identical.
+
+        Args:
+            environment: Where the run was made, kept with a new run
+        """
+        content_hash = _content_hash(findings)
+        latest = next(iter(self._select("WHERE project_id IS ?", (project_id,), limit=1)), None)
+        if latest is not None and self._hash_of(latest.run_id) == content_hash:
+            logger.debug("Findings unchanged since run %d; not recording", latest.run_id)
+            return latest
+
+        severity_counts: Dict[str, int] = {}
+        for finding in findings:
+            severity = str(finding.get("severity", "INFO"))
+            severity_counts[severity] = severity_counts.get(severity, 0) + 1
+
+        created_at = datetime.now(timezone.utc).isoformat(timespec="seconds")
+        with self._connect() as conn:
+            cursor = conn.execute(
+                "INSERT INTO runs "
+                "(project_id, created_at, content_hash, total, severity_counts, environment) "
+                "VALUES (?, ?, ?, ?, ?, ?)",
+                (
+                    project_id,
+                    created_at,
+                    content_hash,
+                    len(findings),
+                    json.dumps(severity_counts),
+                    json.dumps(environment) if environment else None,
+                ),
+            )
+            run_id = cursor.lastrowid
+            conn.executemany(
+                "INSERT INTO run_findings "
+                "(run_id, fingerprint, severity, category, resource, title, data) "
+                "VALUES (?, ?, ?, ?, ?, ?, ?)",
+                [
+                    (
+                        run_id,
+                        f.get("fingerprint") or compute_fingerprint(f),
+                        f.get("severity"),
+                        f.get("category"),
+                        f.get("resource"),
+                        f.get("title"),
+                        json.dumps(f, ensure_ascii=False),
+                    )
+                    for f in findings
+                ],
+            )
+        logger.info("Recorded run %d with %d findings in %s", run_id, len(findings), self.path)
+        return RunRecord(
+            run_id,
+            project_id,
+            created_at,
+            len(findings),
+            severity_counts,
+            environment=environment or {},
+        )
+
+    def _hash_of(self, run_id: int) -> Optional[str]:
+        """Return the content hash of a run."""
+        with self._connect() as conn:
+            row = conn.execute("SELECT content_hash FROM runs WHERE id = ?", (run_id,)).fetchone()
+        return row["content_hash"] if row else None
+
+    def runs(self, limit: Optional[int] = None) -> List[RunRecord]:
+        """Return recorded runs, newest first."""
+        return self._select(limit=limit)
+
+    def _select(
+        self, where: str = "", params: tuple = (), limit: Optional[int] = None
+    ) -> List[RunRecord]:
+        """Return the runs matching a WHERE clause, newest first."""
+        query = f"SELECT * FROM runs {where} ORDER BY id DESC"  # nosec B608
+        if limit is not None:
+            query += " LIMIT ?"
+            params += (limit,)
+        with self._connect() as conn:
+            rows = conn.execute(query, params).fetchall()
+        return [
+            RunRecord(
+                run_id=row["id"],
+                project_id=row["project_id"],
+                created_at=row["created_at"],
+                total=row["total"],
+                severity_counts=json.loads(row["severity_counts"]),
+                resource_usage=json.loads(row["resource_usage"] or "[]"),
+                environment=json.loads(row["environment"] or "{}"),
+            )
+            for row in rows
+        ]
+
+    def record_resource_usage(self, run_id: int, usage: List[Dict[str, Any]]) -> None:
+        """Record the time and memory used by the agents of a run."""
+        with self._connect() as conn:
+            conn.execute(
+                "UPDATE runs SET resource_usage = ? WHERE id = ?", (json.dumps(usage), run_id)
+            )
+
+    def run(self, run_id: int) -> Optional[RunRecord]:
+        """Return a run by ID."""
+        return next((run for run in self.runs() if run.run_id == run_id), None)
+
+    def latest_run(self) -> Optional[RunRecord]:
+        """Return the most recent run, if any."""
+        runs = self.runs(limit=1)
+        return runs[0] if runs else None
+
+    def previous_run(self, run_id: int) -> Optional[RunRecord]:
+        """Return the run of the same project recorded immediately before ``run_id``."""
+        runs = self._select(
+            "WHERE id < ? AND project_id IS (SELECT project_id FROM runs WHERE id = ?)",
+            (run_id, run_id),
+            limit=1,
+        )
+        return runs[0] if runs else None
+
+    def findings_for(self, run_id: int) -> List[Dict[str, Any]]:
+        """Return the findings recorded for a run."""
+        with self._connect() as conn:
+            rows = conn.execute(
+                "SELECT data FROM run_findings WHERE run_id = ? ORDER BY rowid", (run_id,)
+            ).fetchall()
+        return [json.loads(row["data"]) for row in rows]
+
+    def delete_runs(self, run_ids: List[int]) -> int:
+        """Delete runs and their findings, then reclaim the freed disk space."""
+        if not run_ids:
+            return 0
+        placeholders = ", ".join("?" for _ in run_ids)
+        with self._connect() as conn:
+            deleted = conn.execute(
+                f"DELETE FROM runs WHERE id IN ({placeholders})", list(run_ids)  # nosec B608
+            ).rowcount
+        conn = sqlite3.connect(self.path)
+        try:
+            conn.execute("VACUUM")
+        finally:
+            conn.close()
+        logger.info("Deleted %d run(s) from %s", deleted, self.path)
+        return deleted
+
+    def set_baseline(self, run_id: int) -> RunRecord:
+        """Mark a run as the accepted baseline, replacing any previous one.
+
+        Raises:
+            ValueError: If the run does not exist
+        """
+        run = self.run(run_id)
+        if run is None:
+            raise ValueError(f"Run {run_id} not found in {self.path}")
+        set_at = datetime.now(timezone.utc).isoformat(timespec="seconds")
+        with self._connect() as conn:
+            conn.execute(
+                "INSERT OR REPLACE INTO baseline (id, run_id, set_at) VALUES (1, ?, ?)",
+                (run_id, set_at),
+            )
+        logger.info("Run %d is now the baseline", run_id)
+        return run
+
+    def baseline(self) -> Optional[RunRecord]:
+        """Return the baseline run, if one is set."""
+        with self._connect() as conn:
+            row = conn.execute("SELECT run_id FROM baseline WHERE id = 1").fetchone()
+        return self.run(row["run_id"]) if row else None
+
+    def clear_baseline(self) -> None:
+        """Remove the baseline."""
+        with self._connect() as conn:
+            conn.execute("DELETE FROM baseline")
+
+    def mark_false_positive(
+        self,
+        finding: Dict[str, Any],
+        reason: Optional[str] = None,
+        marked_by: Optional[str] = None,
+    ) -> FalsePositive:
+        """Record a finding as a false positive, replacing any earlier feedback on it."""
+        entry = FalsePositive(
+            fingerprint=finding.get("fingerprint") or compute_fingerprint(finding),
+            category=finding.get("category"),
+            resource=finding.get("resource"),
+            title=finding.get("title"),
+            reason=reason,
+            marked_by=marked_by,
+            marked_at=datetime.now(timezone.utc).isoformat(timespec="seconds"),
+        )
+        with self._connect() as conn:
+            conn.execute(
+                "INSERT OR REPLACE INTO false_positives "
+                "(fingerprint, category, resource, title, reason, marked_by, marked_at) "
+                "VALUES (?, ?, ?, ?, ?, ?, ?)",
+                (
+                    entry.fingerprint,
+                    entry.category,
+                    entry.resource,
+                    entry.title,
+                    entry.reason,
+                    entry.marked_by,
+                    entry.marked_at,
+                ),
+            )
+        logger.info("Marked %s as a false positive", entry.fingerprint)
+        return entry
+
+    def false_positives(self) -> List[FalsePositive]:
+        """Return the findings marked as false positives, oldest first."""
+        with self._connect() as conn:
+            rows = conn.execute(
+                "SELECT fingerprint, category, resource, title, reason, marked_by, marked_at "
+                "FROM false_positives ORDER BY marked_at, fingerprint"
+            ).fetchall()
+        return [FalsePositive(**dict(row)) for row in rows]
+
+    def unmark_false_positive(self, fingerprint: str) -> bool:
+        """Remove false-positive feedback; returns whether there was any."""
+        with self._connect() as conn:
+            deleted = conn.execute(
+                "DELETE FROM false_positives WHERE fingerprint = ?", (fingerprint,)
+            ).rowcount
+        return bool(deleted)
+
+    def tag_finding(
+        self, fingerprint: str, tags: List[str], tagged_by: Optional[str] = None
+    ) -> List[str]:
+        """Add tags to a finding; returns all of its stored tags."""
+        tagged_at = datetime.now(timezone.utc).isoformat(timespec="seconds")
+        with self._connect() as conn:
+            conn.executemany(
+                "INSERT OR IGNORE INTO finding_tags (fingerprint, tag, tagged_by, tagged_at) "
+                "VALUES (?, ?, ?, ?)",
+                [(fingerprint, tag, tagged_by, tagged_at) for tag in tags],
+            )
+        return self.finding_tags().get(fingerprint, [])
+
+    def untag_finding(self, fingerprint: str, tags: Optional[List[str]] = None) -> int:
+        """Remove the given tags (or all tags) from a finding; returns how many were removed."""
+        with self._connect() as conn:
+            if not tags:
+                return conn.execute(
+                    "DELETE FROM finding_tags WHERE fingerprint = ?", (fingerprint,)
+                ).rowcount
+            return conn.execute(
+                "DELETE FROM finding_tags WHERE fingerprint = ? AND tag IN (%s)"
+                % ",".join("?" * len(tags)),
+                (fingerprint, *tags),
+            ).rowcount
+
+    def finding_tags(self) -> Dict[str, List[str]]:
+        """Return stored tags by finding fingerprint, each in the order they were added."""
+        with self._connect() as conn:
+            rows = conn.execute(
+                "SELECT fingerprint, tag FROM finding_tags ORDER BY tagged_at, rowid"
+            ).fetchall()
+        tags: Dict[str, List[str]] = {}
+        for row in rows:
+            tags.setdefault(row["fingerprint"], []).append(row["tag"])
+        return tags
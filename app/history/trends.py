@@ -0,0 +1,178 @@
+"""Trends across the runs in the history store.
+
+For each run: the total and per-severity counts, and how many findings were
+new or resolved since the run before. Across runs: the mean time to
+remediation (from the first run that reported a finding to the first run
+without it) and categories that came back in the latest run after being
+cleared.
+"""
+
+import csv
+import io
+from dataclasses import asdict, dataclass, field
+from datetime import datetime
+from typing import Any, Callable, Dict, List, Optional
+
+from app.common.models import SEVERITY_ORDER, severity_rank
+from app.findings.delta import findings_delta
+from app.findings.fingerprint import compute_fingerprint
+
+from .store import RunStore
+
+SPARK_CHARS = "▁▂▃▄▅▆▇█"
+CSV_FIELDS = ["run_id", "created_at", "total", *SEVERITY_ORDER, "new", "resolved"]
+
+
+@dataclass
+class TrendPoint:
+    """Counts for one run."""
+
+    run_id: int
+    created_at: str
+    total: int
+    severity_counts: Dict[str, int]
+    new: int
+    resolved: int
+
+
+@dataclass
+class Trends:
+    """Findings over time, remediation times, and recurring categories."""
+
+    points: List[TrendPoint] = field(default_factory=list)
+    mttr_days: Optional[float] = None
+    mttr_days_by_severity: Dict[str, float] = field(default_factory=dict)
+    resolved_findings: int = 0
+    recurring_categories: List[str] = field(default_factory=list)
+
+    def to_dict(self) -> Dict[str, Any]:
+        """Convert to dictionary for JSON serialization."""
+        return asdict(self)
+
+    def to_csv(self) -> str:
+        """Return one CSV row per run."""
+        buffer = io.StringIO()
+        writer = csv.DictWriter(buffer, fieldnames=CSV_FIELDS, lineterminator="\n")
+        writer.writeheader()
+        for point in self.points:
+            row = {key: point.severity_counts.get(key, 0) for key in SEVERITY_ORDER}
+            row.update(
+                run_id=point.run_id,
+                created_at=point.created_at,
+                total=point.total,
+                new=point.new,
+                resolved=point.resolved,
+            )
+            writer.writerow(row)
+        return buffer.getvalue()
+
+
+def sparkline(values: List[int]) -> str:
+    """Draw values as a one-line bar chart."""
+    if not values:
+        return ""
+    low, high = min(values), max(values)
+    if high == low:
+        return SPARK_CHARS[0 if high == 0 else len(SPARK_CHARS) // 2] * len(values)
+    scale = (len(SPARK_CHARS) - 1) / (high - low)
+    return "".join(SPARK_CHARS[round((value - low) * scale)] for value in values)
+
+
+def _fingerprint(finding: Dict[str, Any]) -> str:
+    """Return the fingerprint of a recorded finding."""
+    return finding.get("fingerprint") or compute_fingerprint(finding)
+
+
+def compute_trends(store: RunStore, limit: Optional[int] = None) -> Trends:
+    """Compute trends over the most recent runs (all runs if no limit)."""
+    runs = list(reversed(store.runs(limit=limit)))
+    trends = Trends()
+    first_seen: Dict[str, datetime] = {}
+    remediation_days: Dict[str, List[float]] = {}
+    categories_seen: set = set()
+    previous_categories: set = set()
+    previous: List[Dict[str, Any]] = []
+
+    for index, run in enumerate(runs):
+        findings = store.findings_for(run.run_id)
+        created = datetime.fromisoformat(run.created_at)
+        new, resolved = findings_delta(findings, previous) if index else ([], [])
+        trends.points.append(
+            TrendPoint(
+                run.run_id,
+                run.created_at,
+                run.total,
+                run.severity_counts,
+                len(new),
+                len(resolved),
+            )
+        )
+
+        for finding in resolved:
+            opened = first_seen.pop(_fingerprint(finding), None)
+            if opened is not None:
+                severity = str(finding.get("severity", "INFO")).upper()
+                days = (created - opened).total_seconds() / 86400
+                remediation_days.setdefault(severity, []).append(days)
+        for finding in findings:
+            first_seen.setdefault(_fingerprint(finding), created)
+
+        categories = {str(f.get("category")) for f in findings if f.get("category")}
+        if index == len(runs) - 1 and index:
+            trends.recurring_categories = sorted(
+                (categories - previous_categories) & categories_seen
+            )
+        categories_seen |= previous_categories
+        previous_categories = categories
+        previous = findings
+
+    all_days = [days for values in remediation_days.values() for days in values]
+    trends.resolved_findings = len(all_days)
+    if all_days:
+        trends.mttr_days = round(sum(all_days) / len(all_days), 2)
+        trends.mttr_days_by_severity = {
+            severity: round(sum(days) / len(days), 2)
+            for severity, days in sorted(
+                remediation_days.items(), key=lambda item: severity_rank(item[0])
+            )
+        }
+    return trends
+
+
+def format_trends(
+    trends: Trends, severity_style: Optional[Callable[[str, str], str]] = None
+) -> str:
+    """Format trends for the terminal, with a sparkline per severity."""
+    if not trends.points:
+        return "No runs recorded yet"
+
+    style = severity_style or (lambda cell, _severity: cell)
+    first, last = trends.points[0], trends.points[-1]
+    lines = [
+        f"Findings over {len(trends.points)} run(s) "
+        f"({first.created_at[:10]} → {last.created_at[:10]})"
+    ]
+    rows = [("Total", "", [p.total for p in trends.points])]
+    rows += [
+        (severity, severity, [p.severity_counts.get(severity, 0) for p in trends.points])
+        for severity in SEVERITY_ORDER
+    ]
+    for label, severity, values in rows:
+        change = values[-1] - values[0]
+        name = style(f"{label:<9}", severity) if severity else f"{label:<9}"
+        lines.append(f"  {name} {sparkline(values)}  {values[-1]} ({change:+d})")
+    lines.append(f"  New/resolved in the latest run: +{last.new} / -{last.resolved}")
+
+    if trends.mttr_days is None:
+        lines.append("\nMean time to remediation: no findings resolved yet")
+    else:
+        lines.append(
+            f"\nMean time to remediation: {trends.mttr_days:g} days "
+            f"({trends.resolved_findings} resolved finding(s))"
+        )
+        for severity, days in trends.mttr_days_by_severity.items():
+            lines.append(f"  {style(f'{severity:<9}', severity)} {days:g} days")
+
+    if trends.recurring_categories:
+        lines.append(f"\nNewly recurring categories: {', '.join(trends.recurring_categories)}")
+    return "\n".join(lines)
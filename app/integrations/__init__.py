@@ -0,0 +1 @@
+"""Integrations that run Paddi as part of another platform."""
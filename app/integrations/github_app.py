@@ -0,0 +1,343 @@
+"""Check Runs created as a GitHub App.
+
+With ``[integrations.github_app]`` enabled, every ``paddi audit`` creates a
+Check Run on the audited commit, authenticated as the app rather than with a
+personal token. The Check Run carries a summary of the findings and one
+annotation per finding; ``paddi export --to check-run`` creates one from the
+latest findings. Configured in paddi.toml::
+
+    [integrations.github_app]
+    enabled = true
+    app_id = 123456
+    private_key_path = "/etc/paddi/github-app.pem"   # or private_key / private_key_env
+    # installation_id = 7890                          # default: the repository's installation
+    # repository = "acme/infra"                       # default: GITHUB_REPOSITORY
+    check_name = "Paddi security audit"
+    fail_on_severity = "HIGH"
+    annotation_path = "paddi.toml"
+
+The app needs the ``checks: write`` permission and must be installed on the
+repository. The commit is ``GITHUB_SHA``, else the ``HEAD`` of the working
+directory. Cloud findings have no source file, so those without a ``file`` and
+``line`` (or ``code_locations``) are annotated on ``annotation_path``.
+"""
+
+import base64
+import json
+import logging
+import os
+import subprocess
+import time
+from pathlib import Path
+from typing import Any, Dict, List, Mapping, Optional
+
+import requests
+
+from app.common.exceptions import ConfigurationError
+from app.common.models import SEVERITY_ORDER, severity_rank
+from app.config.paddi_config import PaddiConfig
+from app.config.secrets import missing_secret_message, resolve_secret
+from app.findings.listing import severity_counts, sort_findings
+from app.reporter.github_annotations import ANNOTATION_LEVELS
+
+logger = logging.getLogger(__name__)
+
+DEFAULT_API_URL = "https://api.github.com"
+DEFAULT_CHECK_NAME = "Paddi security audit"
+DEFAULT_PRIVATE_KEY_ENV = "PADDI_GITHUB_APP_PRIVATE_KEY"
+DEFAULT_FAIL_ON_SEVERITY = "HIGH"
+DEFAULT_ANNOTATION_PATH = "paddi.toml"
+# GitHub accepts at most 50 annotations per request
+ANNOTATIONS_PER_REQUEST = 50
+SUMMARY_FINDINGS = 20
+# App JWTs may live at most 10 minutes; iat is backdated for clock drift
+_JWT_LIFETIME = 540
+_JWT_BACKDATE = 60
+
+
+def _b64url(data: bytes) -> str:
+    return base64.urlsafe_b64encode(data).rstrip(b"=").decode("ascii")
+
+
+def app_jwt(app_id: str, private_key: str, now: Optional[float] = None) -> str:
+    """Sign the RS256 JWT that authenticates as the app.
+
+    Raises:
+        ConfigurationError: If the private key is not a PEM RSA key
+    """
+    from cryptography.hazmat.primitives import hashes, serialization
+    from cryptography.hazmat.primitives.asymmetric import padding, rsa
+
+    try:
+        key = serialization.load_pem_private_key(private_key.encode(), password=None)
+    except (ValueError, TypeError) as e:
+        raise ConfigurationError(
+            "integrations.github_app.private_key", {"error": f"not a PEM private key: {e}"}
+        ) from e
+    if not isinstance(key, rsa.RSAPrivateKey):
+        raise ConfigurationError(
+            "integrations.github_app.private_key", {"error": "GitHub App keys are RSA keys"}
+        )
+
+    issued = int(now if now is not None else time.time()) - _JWT_BACKDATE
+    header = _b64url(json.dumps({"alg": "RS256", "typ": "JWT"}).encode())
+    claims = {"iat": issued, "exp": issued + _JWT_BACKDATE + _JWT_LIFETIME, "iss": str(app_id)}
+    signing_input = f"{header}.{_b64url(json.dumps(claims).encode())}"
+    signature = key.sign(signing_input.encode(), padding.PKCS1v15(), hashes.SHA256())
+    return f"{signing_input}.{_b64url(signature)}"
+
+
+def finding_location(finding: Dict[str, Any], default_path: str) -> Dict[str, Any]:
+    """Return the file and line to annotate for a finding."""
+    location = finding
+    if not finding.get("file") and finding.get("code_locations"):
+        location = finding["code_locations"][0]
+    if location.get("file"):
+        line = int(location.get("line") or 1)
+        return {"path": str(location["file"]), "start_line": line, "end_line": line}
+    return {"path": default_path, "start_line": 1, "end_line": 1}
+
+
+def check_annotation(finding: Dict[str, Any], default_path: str) -> Dict[str, Any]:
+    """Return the Check Run annotation of one finding."""
+    severity = str(finding.get("severity", "INFO")).upper()
+    level = ANNOTATION_LEVELS.get(severity, "notice")
+    lines = []
+    if finding.get("resource"):
+        lines.append(f"Resource: {finding['resource']}")
+    if finding.get("explanation"):
+        lines.append(str(finding["explanation"]))
+    if finding.get("recommendation"):
+        lines.append(f"Recommendation: {finding['recommendation']}")
+    title = f"{severity}: {finding.get('title', 'Unknown Issue')}"
+    return {
+        **finding_location(finding, default_path),
+        "annotation_level": "failure" if level == "error" else level,
+        "title": title[:255],
+        "message": "\n".join(lines) or title,
+    }
+
+
+def _cell(value: Any) -> str:
+    """Make a value safe for a Markdown table cell."""
+    return str(value or "-").replace("|", "\\|").replace("\n", " ")
+
+
+def check_conclusion(findings: List[Dict[str, Any]], fail_on_severity: str) -> str:
+    """Return the conclusion: failure at or above the severity, else neutral or success."""
+    limit = severity_rank(fail_on_severity)
+    if any(severity_rank(f.get("severity", "")) <= limit for f in findings):
+        return "failure"
+    return "neutral" if findings else "success"
+
+
+def check_output(findings: List[Dict[str, Any]], project: str) -> Dict[str, str]:
+    """Return the title and Markdown summary of the Check Run."""
+    counts = severity_counts(findings)
+    if not findings:
+        return {"title": "No findings", "summary": f"Paddi found no issues in `{project}`."}
+    title = ", ".join(f"{count} {severity.lower()}" for severity, count in counts.items())
+    lines = [
+        f"Paddi found **{len(findings)}** finding(s) in `{project}`.",
+        "",
+        "| " + " | ".join(SEVERITY_ORDER) + " |",
+        "|" + "---|" * len(SEVERITY_ORDER),
+        "| " + " | ".join(str(counts.get(s, 0)) for s in SEVERITY_ORDER) + " |",
+        "",
+        "| Severity | Finding | Resource |",
+        "|---|---|---|",
+    ]
+    ordered = sort_findings(findings)
+    lines.extend(
+        f"| {_cell(f.get('severity'))} | {_cell(f.get('title'))} | `{_cell(f.get('resource'))}` |"
+        for f in ordered[:SUMMARY_FINDINGS]
+    )
+    if len(ordered) > SUMMARY_FINDINGS:
+        lines.append(f"\n…and {len(ordered) - SUMMARY_FINDINGS} more in the annotations")
+    return {"title": title, "summary": "\n".join(lines)}
+
+
+def _head_sha(env: Mapping[str, str]) -> Optional[str]:
+    """Return the commit being audited: GITHUB_SHA, else the working directory's HEAD."""
+    if env.get("GITHUB_SHA"):
+        return env["GITHUB_SHA"]
+    try:
+        result = subprocess.run(
+            ["git", "rev-parse", "HEAD"], capture_output=True, text=True, check=True, timeout=10
+        )
+    except (OSError, subprocess.SubprocessError):
+        return None
+    return result.stdout.strip() or None
+
+
+class CheckRunPublisher:
+    """Creates Check Runs with the findings of an audit, authenticated as a GitHub App."""
+
+    def __init__(
+        self,
+        app_id: str,
+        private_key: str,
+        repository: str,
+        head_sha: str,
+        installation_id: Optional[int] = None,
+        api_url: str = DEFAULT_API_URL,
+        check_name: str = DEFAULT_CHECK_NAME,
+        fail_on_severity: str = DEFAULT_FAIL_ON_SEVERITY,
+        annotation_path: str = DEFAULT_ANNOTATION_PATH,
+        timeout: float = 10.0,
+    ):
+        """Initialize CheckRunPublisher.
+
+        Args:
+            app_id: ID of the GitHub App
+            private_key: PEM private key of the app
+            repository: "owner/name" of the repository
+            head_sha: Commit the Check Run belongs to
+            installation_id: Installation of the app (looked up from the repository if omitted)
+            api_url: GitHub API URL (GitHub Enterprise Server: https://<host>/api/v3)
+            check_name: Name of the Check Run
+            fail_on_severity: Least severe finding that makes the Check Run fail
+            annotation_path: File annotated for findings without a source location
+            timeout: Seconds per request
+        """
+        self.app_id = str(app_id)
+        self.private_key = private_key
+        self.repository = repository
+        self.head_sha = head_sha
+        self.installation_id = installation_id
+        self.api_url = api_url.rstrip("/")
+        self.check_name = check_name
+        self.fail_on_severity = fail_on_severity.upper()
+        self.annotation_path = annotation_path
+        self.timeout = timeout
+        self._token: Optional[str] = None
+
+    @classmethod
+    def from_config(
+        cls, config: Optional[PaddiConfig] = None, env: Optional[Mapping[str, str]] = None
+    ) -> Optional["CheckRunPublisher"]:
+        """Create a publisher from ``[integrations.github_app]``.
+
+        Returns:
+            None when the section is absent or ``enabled = false``
+
+        Raises:
+            ConfigurationError: If the app ID, key, repository, or commit is missing
+        """
+        env = os.environ if env is None else env
+        section = (config or PaddiConfig.load()).section("integrations.github_app")
+        if not section or not section.get("enabled", True):
+            return None
+
+        if not section.get("app_id"):
+            raise ConfigurationError("integrations.github_app.app_id", {"error": "not set"})
+        private_key_env = section.get("private_key_env", DEFAULT_PRIVATE_KEY_ENV)
+        private_key = section.get("private_key")
+        if not private_key and section.get("private_key_path"):
+            path = Path(section["private_key_path"]).expanduser()
+            try:
+                private_key = path.read_text(encoding="utf-8")
+            except OSError as e:
+                raise ConfigurationError(
+                    "integrations.github_app.private_key_path", {"error": str(e)}
+                ) from e
+        private_key = private_key or resolve_secret(private_key_env)
+        if not private_key:
+            raise ConfigurationError(
+                "integrations.github_app", {"error": missing_secret_message(private_key_env)}
+            )
+
+        repository = section.get("repository") or env.get("GITHUB_REPOSITORY")
+        if not repository or "/" not in repository:
+            raise ConfigurationError(
+                "integrations.github_app.repository",
+                {"error": 'set repository = "owner/name", or run in GitHub Actions'},
+            )
+        head_sha = _head_sha(env)
+        if not head_sha:
+            raise ConfigurationError(
+                "integrations.github_app",
+                {"error": "no commit to check: set GITHUB_SHA or run in a git checkout"},
+            )
+
+        fail_on_severity = str(section.get("fail_on_severity", DEFAULT_FAIL_ON_SEVERITY)).upper()
+        if fail_on_severity not in SEVERITY_ORDER:
+            raise ConfigurationError(
+                "integrations.github_app.fail_on_severity",
+                {"error": f"use one of {', '.join(SEVERITY_ORDER)}"},
+            )
+        return cls(
+            section["app_id"],
+            private_key,
+            repository,
+            head_sha,
+            installation_id=section.get("installation_id"),
+            api_url=section.get("api_url") or env.get("GITHUB_API_URL") or DEFAULT_API_URL,
+            check_name=section.get("check_name", DEFAULT_CHECK_NAME),
+            fail_on_severity=fail_on_severity,
+            annotation_path=section.get("annotation_path", DEFAULT_ANNOTATION_PATH),
+        )
+
+    def _request(self, method: str, path: str, token: str, **kwargs) -> Any:
+        """Call the GitHub API and return the decoded response."""
+        response = requests.request(
+            method,
+            f"{self.api_url}{path}",
+            headers={
+                "Authorization": f"Bearer {token}",
+                "Accept": "application/vnd.github+json",
+                "X-GitHub-Api-Version": "2022-11-28",
+            },
+            timeout=self.timeout,
+            **kwargs,
+        )
+        response.raise_for_status()
+        return response.json()
+
+    def installation_token(self) -> str:
+        """Return an installation access token of the app for the repository."""
+        if self._token is None:
+            jwt = app_jwt(self.app_id, self.private_key)
+            installation_id = self.installation_id
+            if installation_id is None:
+                installation = self._request("GET", f"/repos/{self.repository}/installation", jwt)
+                installation_id = installation["id"]
+            path = f"/app/installations/{installation_id}/access_tokens"
+            self._token = self._request("POST", path, jwt)["token"]
+        return self._token
+
+    def publish(
+        self, findings: List[Dict[str, Any]], project: str, details_url: Optional[str] = None
+    ) -> Dict[str, Any]:
+        """Create a completed Check Run with the findings.
+
+        Annotations past the first 50 are added by updating the Check Run.
+
+        Returns:
+            The Check Run as GitHub returns it
+
+        Raises:
+            requests.HTTPError: If the API rejects a request
+        """
+        token = self.installation_token()
+        ordered = sort_findings(findings)
+        annotations = [check_annotation(f, self.annotation_path) for f in ordered]
+        output = check_output(findings, project)
+        body: Dict[str, Any] = {
+            "name": self.check_name,
+            "head_sha": self.head_sha,
+            "status": "completed",
+            "conclusion": check_conclusion(findings, self.fail_on_severity),
+            "output": {**output, "annotations": annotations[:ANNOTATIONS_PER_REQUEST]},
+        }
+        if details_url:
+            body["details_url"] = details_url
+        check_run = self._request("POST", f"/repos/{self.repository}/check-runs", token, json=body)
+        path = f"/repos/{self.repository}/check-runs/{check_run['id']}"
+        for start in range(ANNOTATIONS_PER_REQUEST, len(annotations), ANNOTATIONS_PER_REQUEST):
+            batch = annotations[start : start + ANNOTATIONS_PER_REQUEST]
+            self._request("PATCH", path, token, json={"output": {**output, "annotations": batch}})
+        logger.info(
+            "Created check run %s on %s@%s", self.check_name, self.repository, self.head_sha[:7]
+        )
+        return check_run
@@ -5,12 +5,26 @@ Main entry point for Paddi Python agents orchestration.
 
 import logging
 import sys
+import traceback
 import warnings
 
 import fire
 
-from app.cli.paddi_cli import PaddiCLI
+from app.cli.output import (
+    extract_output_flag,
+    extract_quiet_flag,
+    is_quiet,
+    set_output_format,
+    set_quiet,
+)
+from app.cli.paddi_cli import PaddiCLI, join_compare_runs, offer_debug_bundle
+from app.cli.theme import extract_no_color_flag, set_color
+from app.common.exceptions import ConfigurationError
+from app.common.diagnostics import install_log_buffer
+from app.common.exit_codes import ExitCode, exit_code_for
+from app.common.i18n import extract_lang_flag, set_language
 from app.common.logging_config import LoggingConfig
+from app.common.network import configure_proxy
 
 # Suppress Google auth warnings
 warnings.filterwarnings(
@@ -20,12 +34,40 @@ warnings.filterwarnings(
 # Configure logging before any other imports
 LoggingConfig.setup(level="INFO")
 LoggingConfig.silence_noisy_libraries()
+# Keep recent log lines for the diagnostics of a failed run (paddi debug bundle)
+install_log_buffer()
 
 logger = logging.getLogger(__name__)
 
 
 def main():
     """Main entry point with natural language support."""
+    # Global --output, --quiet, --no-color and --lang flags are handled here for every command
+    sys.argv[1:], output_format = extract_output_flag(sys.argv[1:])
+    set_output_format(output_format)
+    sys.argv[1:], quiet = extract_quiet_flag(sys.argv[1:])
+    set_quiet(quiet)
+    if is_quiet():
+        LoggingConfig.enable_quiet_mode()
+    sys.argv[1:], no_color = extract_no_color_flag(sys.argv[1:])
+    if no_color:
+        set_color(False)
+    sys.argv[1:], lang = extract_lang_flag(sys.argv[1:])
+    if lang:
+        try:
+            set_language(lang)
+        except ValueError as e:
+            logger.error("%s", e)
+            sys.exit(ExitCode.INVALID_INPUT)
+    sys.argv[1:] = join_compare_runs(sys.argv[1:])
+
+    # Route every outbound HTTP client through the [network.proxy] settings, if any
+    try:
+        configure_proxy()
+    except ConfigurationError as e:
+        logger.error("%s", e)
+        sys.exit(exit_code_for(e))
+
     # Check if natural language command is provided
     if len(sys.argv) == 2 and not sys.argv[1].startswith("-"):
         # Single argument that doesn't start with dash - likely natural language
@@ -54,6 +96,32 @@ def main():
             "audit_log",
             "safety_demo",
             "audit_logs",
+            "plugins",
+            "findings",
+            "remediate",
+            "drift",
+            "export",
+            "config",
+            "login",
+            "schema",
+            "validate-data",
+            "validate_data",
+            "archive",
+            "bundle",
+            "debug",
+            "benchmark",
+            "clean",
+            "trends",
+            "baseline",
+            "query",
+            "runs",
+            "site",
+            "serve",
+            "worker",
+            "deploy",
+            "self-update",
+            "self_update",
+            "completion",
         ]
 
         if natural_language_input not in known_commands:
@@ -64,8 +132,16 @@ def main():
             result = cli.execute_one_shot(natural_language_input)
             sys.exit(0 if result.get("success") else 1)
 
-    # Otherwise, use normal Fire CLI
-    fire.Fire(PaddiCLI)
+    # Otherwise, use normal Fire CLI; errors that reach here exit with their failure class's code
+    try:
+        fire.Fire(PaddiCLI)
+    except KeyboardInterrupt:
+        sys.exit(ExitCode.INTERRUPTED)
+    except Exception as e:
+        traceback.print_exc()
+        exit_code = exit_code_for(e)
+        offer_debug_bundle(e, exit_code)
+        sys.exit(exit_code)
 
 
 if __name__ == "__main__":
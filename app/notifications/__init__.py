@@ -0,0 +1 @@
+"""Outbound notifications about completed audit runs."""
@@ -0,0 +1,201 @@
+"""Send audit summary metrics and an event to Datadog after each run.
+
+Configured in paddi.toml::
+
+    [notifications.datadog]
+    site = "datadoghq.com"   # or datadoghq.eu, us5.datadoghq.com, ...
+    tags = ["env:prod", "team:security"]
+
+The API key is read from ``DD_API_KEY`` unless ``api_key_env`` names another
+environment variable. Metrics are gauges tagged with ``project`` (and
+``severity`` for the per-severity count), so they can be graphed next to
+existing dashboards:
+
+- ``paddi.findings.total``
+- ``paddi.findings.count`` (one series per severity)
+- ``paddi.findings.new`` / ``paddi.findings.resolved`` (when a previous run exists)
+- ``paddi.risk.score``
+"""
+
+import logging
+import time
+from pathlib import Path
+from typing import Any, Dict, List, Optional
+
+import requests
+
+from app.common.exceptions import ConfigurationError
+from app.common.models import SEVERITY_ORDER
+from app.config.paddi_config import PaddiConfig
+from app.config.secrets import missing_secret_message, resolve_secret
+from app.findings.risk import RiskModel
+from app.history.store import HISTORY_DB_NAME, RunStore
+
+from .webhooks import build_payload
+
+logger = logging.getLogger(__name__)
+
+DEFAULT_METRIC_PREFIX = "paddi"
+
+# Datadog gauge type in the v2 series API
+_GAUGE = 3
+
+
+def build_series(
+    payload: Dict[str, Any], timestamp: int, tags: List[str], prefix: str = DEFAULT_METRIC_PREFIX
+) -> List[Dict[str, Any]]:
+    """Build metric series from a run payload (see ``webhooks.build_payload``)."""
+    tags = tags + [f"project:{payload.get('project_id') or 'unknown'}"]
+    summary = payload["summary"]
+
+    def gauge(name: str, value: float, extra: Optional[List[str]] = None) -> Dict[str, Any]:
+        return {
+            "metric": f"{prefix}.{name}",
+            "type": _GAUGE,
+            "points": [{"timestamp": timestamp, "value": value}],
+            "tags": tags + (extra or []),
+        }
+
+    series = [gauge("findings.total", summary["total"]), gauge("risk.score", summary["risk_score"])]
+    for severity in SEVERITY_ORDER:
+        count = summary["severity_counts"].get(severity, 0)
+        series.append(gauge("findings.count", count, [f"severity:{severity.lower()}"]))
+
+    if payload.get("delta"):
+        series.append(gauge("findings.new", payload["delta"]["new_count"]))
+        series.append(gauge("findings.resolved", payload["delta"]["resolved_count"]))
+    return series
+
+
+def build_event(payload: Dict[str, Any], tags: List[str]) -> Dict[str, Any]:
+    """Build the run event from a run payload."""
+    summary = payload["summary"]
+    counts = summary["severity_counts"]
+    if counts.get("CRITICAL"):
+        alert_type = "error"
+    elif counts.get("HIGH"):
+        alert_type = "warning"
+    else:
+        alert_type = "info"
+
+    lines = [
+        f"Risk grade {summary['risk_grade']} (score {summary['risk_score']})",
+        "Findings: "
+        + (", ".join(f"{s} {counts[s]}" for s in SEVERITY_ORDER if counts.get(s)) or "none"),
+    ]
+    if payload.get("delta"):
+        delta = payload["delta"]
+        lines.append(
+            f"Since previous run: {delta['new_count']} new, {delta['resolved_count']} resolved"
+        )
+
+    project = payload.get("project_id") or "unknown"
+    return {
+        "title": f"Paddi audit completed for {project}: {summary['total']} finding(s)",
+        "text": "\n".join(lines),
+        "alert_type": alert_type,
+        "source_type_name": "paddi",
+        "tags": tags + [f"project:{project}"],
+    }
+
+
+class DatadogNotifier:
+    """Sends run metrics and events to the Datadog API."""
+
+    def __init__(
+        self,
+        api_key: str,
+        site: str = "datadoghq.com",
+        tags: Optional[List[str]] = None,
+        metric_prefix: str = DEFAULT_METRIC_PREFIX,
+        timeout: float = 10.0,
+    ):
+        """Initialize DatadogNotifier.
+
+        Args:
+            api_key: Datadog API key
+            site: Datadog site, e.g. ``datadoghq.eu``
+            tags: Tags added to every metric and event
+            metric_prefix: Prefix of metric names
+            timeout: Seconds per request
+        """
+        self.api_key = api_key
+        self.base_url = f"https://api.{site}"
+        self.tags = list(tags or [])
+        self.metric_prefix = metric_prefix
+        self.timeout = timeout
+
+    @classmethod
+    def from_config(cls, config: Optional[PaddiConfig] = None) -> Optional["DatadogNotifier"]:
+        """Create a notifier from the ``[notifications.datadog]`` section of paddi.toml.
+
+        Returns:
+            None when the section is absent or ``enabled = false``
+
+        Raises:
+            ConfigurationError: If the API key variable is not set
+        """
+        section = (config or PaddiConfig.load()).section("notifications.datadog")
+        if not section or not section.get("enabled", True):
+            return None
+
+        api_key_env = section.get("api_key_env", "DD_API_KEY")
+        api_key = section.get("api_key") or resolve_secret(api_key_env)
+        if not api_key:
+            raise ConfigurationError(
+                "notifications.datadog", {"error": missing_secret_message(api_key_env)}
+            )
+        return cls(
+            api_key=api_key,
+            site=section.get("site", "datadoghq.com"),
+            tags=section.get("tags"),
+            metric_prefix=section.get("metric_prefix", DEFAULT_METRIC_PREFIX),
+        )
+
+    def _post(self, path: str, body: Dict[str, Any]) -> bool:
+        """Post a JSON body to the Datadog API."""
+        try:
+            response = requests.post(
+                self.base_url + path,
+                json=body,
+                headers={"DD-API-KEY": self.api_key},
+                timeout=self.timeout,
+            )
+        except requests.RequestException as e:
+            logger.error("Datadog request to %s failed: %s", path, e)
+            return False
+        if response.status_code >= 300:
+            logger.error("Datadog rejected %s with HTTP %d", path, response.status_code)
+            return False
+        return True
+
+    def send(self, payload: Dict[str, Any], timestamp: int) -> Dict[str, bool]:
+        """Send the metrics and the event for a run payload.
+
+        Returns:
+            Delivery result for ``metrics`` and ``event``
+        """
+        series = build_series(payload, timestamp, self.tags, self.metric_prefix)
+        return {
+            "metrics": self._post("/api/v2/series", {"series": series}),
+            "event": self._post("/api/v1/events", build_event(payload, self.tags)),
+        }
+
+    def notify_run(self, data_dir: Path) -> Dict[str, bool]:
+        """Send the latest recorded run in ``data_dir`` to Datadog."""
+        store = RunStore(Path(data_dir) / HISTORY_DB_NAME)
+        latest = store.latest_run()
+        if latest is None:
+            logger.warning("No recorded run to send to Datadog")
+            return {}
+
+        previous_run = store.previous_run(latest.run_id)
+        previous = None
+        if previous_run is not None:
+            previous = (previous_run, store.findings_for(previous_run.run_id))
+        payload = build_payload(
+            store.findings_for(latest.run_id), previous, latest.project_id, RiskModel.from_config()
+        )
+        result = self.send(payload, int(time.time()))
+        logger.info("Sent run summary to Datadog")
+        return result
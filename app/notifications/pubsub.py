@@ -0,0 +1,147 @@
+"""Publish new findings to a Pub/Sub topic after analysis.
+
+Each finding not present in the previous recorded run becomes one message,
+so downstream pipelines (SOAR, ticketing) can react without polling reports.
+Configured in paddi.toml::
+
+    [notifications.pubsub]
+    topic = "projects/sec-ops/topics/paddi-findings"  # or a topic ID in the audited project
+    min_severity = "MEDIUM"
+
+The message data is the finding as JSON. Attributes carry the event type,
+fingerprint, severity, category, and project so subscriptions can filter.
+"""
+
+import json
+import logging
+from pathlib import Path
+from typing import Any, Dict, List, Optional, Tuple
+
+from app.common.exceptions import ConfigurationError
+from app.common.models import SEVERITY_ORDER, severity_rank
+from app.config.paddi_config import PaddiConfig
+from app.findings.delta import findings_delta
+from app.findings.fingerprint import compute_fingerprint
+from app.history.store import HISTORY_DB_NAME, RunStore
+
+logger = logging.getLogger(__name__)
+
+EVENT_FINDING_NEW = "finding.new"
+
+# Seconds to wait for the publisher to acknowledge each message
+PUBLISH_TIMEOUT = 30
+
+
+def topic_path(topic: str, project_id: Optional[str]) -> str:
+    """Return the full topic path for a topic ID or path."""
+    if topic.startswith("projects/"):
+        return topic
+    if not project_id:
+        raise ConfigurationError(
+            "notifications.pubsub.topic", {"error": "use projects/<project>/topics/<topic>"}
+        )
+    return f"projects/{project_id}/topics/{topic}"
+
+
+def message_for(
+    finding: Dict[str, Any], project_id: Optional[str]
+) -> Tuple[bytes, Dict[str, str]]:
+    """Build the message data and attributes for a finding."""
+    fingerprint = finding.get("fingerprint") or compute_fingerprint(finding)
+    data = json.dumps(dict(finding, fingerprint=fingerprint), ensure_ascii=False).encode("utf-8")
+    attributes = {
+        "event": EVENT_FINDING_NEW,
+        "fingerprint": fingerprint,
+        "severity": str(finding.get("severity", "INFO")).upper(),
+        "category": str(finding.get("category") or ""),
+        "project_id": project_id or "",
+    }
+    return data, attributes
+
+
+class PubSubPublisher:
+    """Publishes findings to a Pub/Sub topic."""
+
+    def __init__(self, topic: str, min_severity: str = "INFO", publisher: Any = None):
+        """Initialize PubSubPublisher.
+
+        Args:
+            topic: Full topic path
+            min_severity: Least severe finding that is published
+            publisher: ``google.cloud.pubsub_v1.PublisherClient`` (created on first use)
+        """
+        self.topic = topic
+        self.min_severity = min_severity.upper()
+        self._publisher = publisher
+
+    @classmethod
+    def from_config(
+        cls, config: Optional[PaddiConfig] = None, project_id: Optional[str] = None
+    ) -> Optional["PubSubPublisher"]:
+        """Create a publisher from the ``[notifications.pubsub]`` section of paddi.toml.
+
+        Returns:
+            None when no topic is configured
+
+        Raises:
+            ConfigurationError: If the topic or severity is invalid
+        """
+        section = (config or PaddiConfig.load()).section("notifications.pubsub")
+        if not section.get("topic"):
+            return None
+
+        min_severity = str(section.get("min_severity", "INFO")).upper()
+        if min_severity not in SEVERITY_ORDER:
+            raise ConfigurationError(
+                "notifications.pubsub.min_severity", {"error": f"invalid severity: {min_severity}"}
+            )
+        return cls(topic_path(section["topic"], project_id), min_severity)
+
+    @property
+    def publisher(self) -> Any:
+        """Return the publisher client."""
+        if self._publisher is None:
+            from google.cloud import pubsub_v1
+
+            self._publisher = pubsub_v1.PublisherClient()
+        return self._publisher
+
+    def publish(self, findings: List[Dict[str, Any]], project_id: Optional[str] = None) -> int:
+        """Publish findings at or above the minimum severity.
+
+        Returns:
+            Number of messages published
+        """
+        limit = severity_rank(self.min_severity)
+        futures = []
+        for finding in findings:
+            if severity_rank(finding.get("severity", "")) > limit:
+                continue
+            data, attributes = message_for(finding, project_id)
+            futures.append(self.publisher.publish(self.topic, data, **attributes))
+
+        for future in futures:
+            future.result(timeout=PUBLISH_TIMEOUT)
+        logger.info("Published %d finding(s) to %s", len(futures), self.topic)
+        return len(futures)
+
+    def publish_new(
+        self,
+        findings: List[Dict[str, Any]],
+        data_dir: Path,
+        project_id: Optional[str] = None,
+    ) -> int:
+        """Publish the findings that are not in the latest recorded run.
+
+        Called before the report records the current run, so the latest
+        recorded run is the previous one. Without history every finding is new.
+        """
+        previous: List[Dict[str, Any]] = []
+        history = Path(data_dir) / HISTORY_DB_NAME
+        if history.exists():
+            store = RunStore(history)
+            latest = store.latest_run()
+            if latest is not None:
+                previous = store.findings_for(latest.run_id)
+        new, _ = findings_delta(findings, previous)
+        return self.publish(new, project_id)
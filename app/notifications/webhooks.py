@@ -0,0 +1,258 @@
+"""Generic outbound webhooks sent when an audit completes.
+
+Each webhook receives a JSON payload with the run summary and the findings
+delta since the previous run. Webhooks are configured by name in paddi.toml::
+
+    [notifications.webhooks]
+    chat = "https://hooks.example.com/T000/B000"
+
+    [notifications.webhooks.siem]
+    url = "https://siem.example.com/ingest/paddi"
+    secret_env = "PADDI_WEBHOOK_SECRET"
+    retries = 3
+    timeout = 10
+
+    [notifications.webhooks.platform-chat]
+    url = "https://hooks.example.com/T000/B001"
+    owners = ["platform"]
+
+When a secret is configured, the raw request body is signed with HMAC-SHA256
+and sent as ``X-Paddi-Signature-256: sha256=<hex digest>``. A webhook with
+``owners`` only receives the findings of those ``[owners]`` teams (use
+``"unassigned"`` for findings without an owner).
+"""
+
+import hashlib
+import hmac
+import json
+import logging
+import time
+from dataclasses import dataclass, field
+from datetime import datetime, timezone
+from pathlib import Path
+from typing import Any, Dict, List, Optional, Tuple
+
+import requests
+
+from app.common.exceptions import ConfigurationError
+from app.config.paddi_config import PaddiConfig
+from app.config.secrets import missing_secret_message, resolve_secret
+from app.findings.delta import findings_delta
+from app.findings.fingerprint import compute_fingerprint
+from app.findings.ownership import OwnershipRules
+from app.findings.risk import RiskModel
+from app.history.store import HISTORY_DB_NAME, RunRecord, RunStore
+
+logger = logging.getLogger(__name__)
+
+EVENT_AUDIT_COMPLETED = "audit.completed"
+SIGNATURE_HEADER = "X-Paddi-Signature-256"
+
+# Findings listed per delta section; the counts always cover all of them
+MAX_DELTA_FINDINGS = 50
+
+
+@dataclass
+class Webhook:
+    """A configured webhook endpoint."""
+
+    name: str
+    url: str
+    secret: Optional[str] = None
+    retries: int = 3
+    timeout: float = 10.0
+    owners: List[str] = field(default_factory=list)
+
+
+def sign(body: bytes, secret: str) -> str:
+    """Return the signature header value for a request body."""
+    digest = hmac.new(secret.encode("utf-8"), body, hashlib.sha256).hexdigest()
+    return f"sha256={digest}"
+
+
+def _summary_of(finding: Dict[str, Any]) -> Dict[str, Any]:
+    """Reduce a finding to the fields sent in webhook payloads."""
+    return {
+        "fingerprint": finding.get("fingerprint") or compute_fingerprint(finding),
+        "title": finding.get("title"),
+        "severity": finding.get("severity"),
+        "category": finding.get("category"),
+        "resource": finding.get("resource"),
+        "owner": finding.get("owner"),
+    }
+
+
+def build_payload(
+    findings: List[Dict[str, Any]],
+    previous: Optional[Tuple[RunRecord, List[Dict[str, Any]]]] = None,
+    project_id: Optional[str] = None,
+    risk_model: Optional[RiskModel] = None,
+) -> Dict[str, Any]:
+    """Build the webhook payload for a run.
+
+    Args:
+        findings: Findings of the completed run
+        previous: The previous run and its findings, for the delta
+        project_id: Audited project
+        risk_model: Model for the risk grade (defaults to the built-in weights)
+    """
+    severity_counts: Dict[str, int] = {}
+    for finding in findings:
+        severity = str(finding.get("severity", "INFO")).upper()
+        severity_counts[severity] = severity_counts.get(severity, 0) + 1
+    risk = (risk_model or RiskModel()).summarize(findings)
+
+    payload: Dict[str, Any] = {
+        "event": EVENT_AUDIT_COMPLETED,
+        "project_id": project_id,
+        "generated_at": datetime.now(timezone.utc).isoformat(timespec="seconds"),
+        "summary": {
+            "total": len(findings),
+            "severity_counts": severity_counts,
+            "risk_score": risk.score,
+            "risk_grade": risk.grade,
+        },
+        "delta": None,
+    }
+
+    if previous is not None:
+        previous_run, previous_findings = previous
+        new, resolved = findings_delta(findings, previous_findings)
+        payload["delta"] = {
+            "previous_run_at": previous_run.created_at,
+            "new_count": len(new),
+            "resolved_count": len(resolved),
+            "new": [_summary_of(f) for f in new[:MAX_DELTA_FINDINGS]],
+            "resolved": [_summary_of(f) for f in resolved[:MAX_DELTA_FINDINGS]],
+        }
+    return payload
+
+
+def _parse_webhook(name: str, entry: Any) -> Webhook:
+    """Validate one ``[notifications.webhooks]`` entry."""
+    if isinstance(entry, str):
+        entry = {"url": entry}
+    url = entry.get("url") if isinstance(entry, dict) else None
+    if not url or not str(url).startswith(("https://", "http://")):
+        raise ConfigurationError(
+            f"notifications.webhooks.{name}", {"error": "url must be an http(s) URL"}
+        )
+
+    secret = entry.get("secret")
+    if entry.get("secret_env"):
+        secret = resolve_secret(entry["secret_env"])
+        if not secret:
+            raise ConfigurationError(
+                f"notifications.webhooks.{name}",
+                {"error": missing_secret_message(entry["secret_env"])},
+            )
+
+    try:
+        return Webhook(
+            name=name,
+            url=url,
+            secret=secret,
+            retries=int(entry.get("retries", 3)),
+            timeout=float(entry.get("timeout", 10.0)),
+            owners=list(entry.get("owners", [])),
+        )
+    except (TypeError, ValueError) as e:
+        raise ConfigurationError(f"notifications.webhooks.{name}", {"error": str(e)}) from e
+
+
+class WebhookNotifier:
+    """Posts run payloads to the configured webhooks."""
+
+    def __init__(self, webhooks: Optional[List[Webhook]] = None):
+        """Initialize WebhookNotifier."""
+        self.webhooks = webhooks or []
+
+    @classmethod
+    def from_config(cls, config: Optional[PaddiConfig] = None) -> "WebhookNotifier":
+        """Create a notifier from the ``[notifications.webhooks]`` section of paddi.toml.
+
+        Raises:
+            ConfigurationError: If a webhook has no valid URL or its secret is missing
+        """
+        section = (config or PaddiConfig.load()).section("notifications.webhooks")
+        return cls([_parse_webhook(name, entry) for name, entry in section.items()])
+
+    def _post(self, webhook: Webhook, body: bytes) -> bool:
+        """Post a body to one webhook, retrying transient failures."""
+        headers = {
+            "Content-Type": "application/json",
+            "User-Agent": "paddi-webhook",
+            "X-Paddi-Event": EVENT_AUDIT_COMPLETED,
+        }
+        if webhook.secret:
+            headers[SIGNATURE_HEADER] = sign(body, webhook.secret)
+
+        for attempt in range(webhook.retries + 1):
+            if attempt:
+                time.sleep(2 ** (attempt - 1))
+            try:
+                response = requests.post(
+                    webhook.url, data=body, headers=headers, timeout=webhook.timeout
+                )
+            except requests.RequestException as e:
+                logger.warning("Webhook %s failed (attempt %d): %s", webhook.name, attempt + 1, e)
+                continue
+
+            if response.status_code < 300:
+                logger.info("Webhook %s delivered", webhook.name)
+                return True
+            if response.status_code != 429 and response.status_code < 500:
+                logger.error("Webhook %s rejected with HTTP %d", webhook.name, response.status_code)
+                return False
+            logger.warning(
+                "Webhook %s returned HTTP %d (attempt %d)",
+                webhook.name,
+                response.status_code,
+                attempt + 1,
+            )
+
+        logger.error("Webhook %s failed after %d attempts", webhook.name, webhook.retries + 1)
+        return False
+
+    def send(self, payload: Dict[str, Any]) -> Dict[str, bool]:
+        """Send a payload to every webhook.
+
+        Returns:
+            Delivery result by webhook name
+        """
+        body = json.dumps(payload, ensure_ascii=False).encode("utf-8")
+        return {webhook.name: self._post(webhook, body) for webhook in self.webhooks}
+
+    def notify_run(self, data_dir: Path, run_id: int) -> Dict[str, bool]:
+        """Send a run recorded in ``data_dir`` to every webhook, with its delta from the
+        project's previous run."""
+        if not self.webhooks:
+            return {}
+
+        store = RunStore(Path(data_dir) / HISTORY_DB_NAME)
+        latest = store.run(run_id)
+        if latest is None:
+            logger.warning("Run %d is not in the run history; not notifying", run_id)
+            return {}
+
+        previous_run = store.previous_run(latest.run_id)
+        findings = store.findings_for(latest.run_id)
+        previous_findings = store.findings_for(previous_run.run_id) if previous_run else []
+        risk_model = RiskModel.from_config()
+        ownership = OwnershipRules.from_config()
+
+        results = {}
+        for webhook in self.webhooks:
+            current, before = findings, previous_findings
+            if webhook.owners:
+                current = ownership.owned_by(findings, webhook.owners)
+                before = ownership.owned_by(previous_findings, webhook.owners)
+            payload = build_payload(
+                ownership.assign(current),
+                (previous_run, before) if previous_run else None,
+                latest.project_id,
+                risk_model,
+            )
+            body = json.dumps(payload, ensure_ascii=False).encode("utf-8")
+            results[webhook.name] = self._post(webhook, body)
+        return results
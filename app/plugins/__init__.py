@@ -0,0 +1 @@
+"""Plugin support for third-party collectors and analyzers."""
@@ -0,0 +1,138 @@
+"""Exec-based plugin protocol for plugins written in any language.
+
+A plugin is any executable named ``paddi-plugin-<name>`` found on ``PATH``.
+Paddi writes a single JSON request to its stdin and reads a single JSON
+response from its stdout:
+
+Request::
+
+    {"protocol_version": 1, "action": "analyze", "config": {...}, "input": {...}}
+
+Response::
+
+    {"findings": [{"title": ..., "severity": ..., ...}], "data": {...}}
+
+A non-zero exit status marks the run as failed; stderr is forwarded to the log.
+Plugins run under the memory and CPU limits of ``[execution]`` (see
+:mod:`app.common.process_limits`).
+"""
+
+import json
+import logging
+import os
+import subprocess  # nosec B404
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import Any, Dict, List, Optional
+
+from app.common.exceptions import PluginError
+from app.common.process_limits import ProcessLimits, run_limited
+
+logger = logging.getLogger(__name__)
+
+PLUGIN_PREFIX = "paddi-plugin-"
+PROTOCOL_VERSION = 1
+DEFAULT_TIMEOUT_SECONDS = 300
+
+
+@dataclass
+class PluginResponse:
+    """Parsed response from an exec plugin."""
+
+    findings: List[Dict[str, Any]] = field(default_factory=list)
+    data: Dict[str, Any] = field(default_factory=dict)
+
+    def to_dict(self) -> Dict[str, Any]:
+        """Convert to dictionary for JSON serialization."""
+        return {"findings": self.findings, "data": self.data}
+
+
+class ExecPlugin:
+    """An external plugin binary speaking JSON over stdin/stdout."""
+
+    def __init__(self, name: str, path: Path):
+        """Initialize ExecPlugin with its name and executable path."""
+        self.name = name
+        self.path = path
+
+    def run(
+        self,
+        action: str,
+        payload: Optional[Dict[str, Any]] = None,
+        config: Optional[Dict[str, Any]] = None,
+        timeout: int = DEFAULT_TIMEOUT_SECONDS,
+        limits: Optional[ProcessLimits] = None,
+    ) -> PluginResponse:
+        """Invoke the plugin with one request and parse its response.
+
+        Args:
+            limits: Memory and CPU limits of the plugin process (none by default)
+        """
+        request = {
+            "protocol_version": PROTOCOL_VERSION,
+            "action": action,
+            "config": config or {},
+            "input": payload or {},
+        }
+
+        try:
+            result = run_limited(
+                [str(self.path)], limits, input=json.dumps(request), timeout=timeout
+            )
+        except subprocess.TimeoutExpired as e:
+            raise PluginError(self.name, {"error": f"timed out after {timeout}s"}) from e
+        except OSError as e:
+            raise PluginError(self.name, {"error": str(e)}) from e
+
+        for line in result.stderr.splitlines():
+            logger.info("[plugin:%s] %s", self.name, line)
+
+        if result.returncode != 0:
+            error = f"exited with status {result.returncode}"
+            reason = limits.describe_exit(result.returncode, result.stderr) if limits else None
+            if reason:
+                error = f"{reason}; {error}"
+            raise PluginError(self.name, {"error": error, "action": action})
+
+        try:
+            response = json.loads(result.stdout or "{}")
+        except json.JSONDecodeError as e:
+            raise PluginError(self.name, {"error": f"invalid JSON response: {e}"}) from e
+
+        if not isinstance(response, dict):
+            raise PluginError(self.name, {"error": "response must be a JSON object"})
+
+        return PluginResponse(
+            findings=response.get("findings", []),
+            data=response.get("data", {}),
+        )
+
+
+def discover_exec_plugins(search_path: Optional[str] = None) -> Dict[str, ExecPlugin]:
+    """Find ``paddi-plugin-*`` executables on PATH.
+
+    Args:
+        search_path: PATH-style directory list (defaults to ``$PATH``)
+
+    Returns:
+        Plugins keyed by name; earlier PATH entries take precedence
+    """
+    plugins: Dict[str, ExecPlugin] = {}
+    for directory in (search_path or os.getenv("PATH", "")).split(os.pathsep):
+        if not directory or not os.path.isdir(directory):
+            continue
+        try:
+            entries = sorted(Path(directory).iterdir())
+        except OSError:
+            # Unreadable PATH entries are skipped, as the shell does
+            continue
+        for entry in entries:
+            if not entry.name.startswith(PLUGIN_PREFIX):
+                continue
+            if not entry.is_file() or not os.access(entry, os.X_OK):
+                continue
+            name = entry.name[len(PLUGIN_PREFIX) :]
+            if name.endswith(".exe"):
+                name = name[:-4]
+            plugins.setdefault(name, ExecPlugin(name, entry))
+    return plugins
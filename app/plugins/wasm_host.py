@@ -0,0 +1,279 @@
+"""Sandboxed WebAssembly plugin host for third-party collectors and analyzers.
+
+Plugins are ``.wasm`` modules placed in the plugins directory. They run without
+WASI, so they have no filesystem or network access, and are bounded by a fuel
+(instruction) budget and a linear memory limit.
+
+Host interface v1 (import module ``paddi_v1``):
+
+- ``config_len() -> i32`` / ``config_read(ptr: i32)``: plugin config as JSON
+- ``input_len() -> i32`` / ``input_read(ptr: i32)``: collected data as JSON
+- ``emit_finding(ptr: i32, len: i32)``: report one finding as a JSON object
+- ``emit_data(ptr: i32, len: i32)``: contribute a JSON object to collected data
+- ``log(level: i32, ptr: i32, len: i32)``: write a UTF-8 message to the host log
+
+Required exports: ``memory`` and ``paddi_interface_version() -> i32``. A plugin
+exports ``paddi_collect() -> i32`` to act as a collector and/or
+``paddi_analyze() -> i32`` to act as an analyzer; a non-zero return is a failure.
+"""
+
+import json
+import logging
+from pathlib import Path
+from typing import Any, Dict, List, Optional
+
+try:
+    import wasmtime
+except ImportError:
+    wasmtime = None
+
+from app.common.exceptions import PluginError
+from app.common.models import SecurityFinding
+from app.config.paddi_config import PaddiConfig
+
+logger = logging.getLogger(__name__)
+
+HOST_INTERFACE_VERSION = 1
+HOST_MODULE = "paddi_v1"
+
+DEFAULT_PLUGINS_DIR = "plugins"
+DEFAULT_FUEL = 100_000_000
+DEFAULT_MAX_MEMORY_MB = 64
+
+LOG_LEVELS = {0: logging.DEBUG, 1: logging.INFO, 2: logging.WARNING, 3: logging.ERROR}
+
+
+class _Invocation:
+    """Per-call state shared between the host functions and the caller."""
+
+    def __init__(self, config: Dict[str, Any], payload: Dict[str, Any]):
+        self.config_bytes = json.dumps(config).encode("utf-8")
+        self.input_bytes = json.dumps(payload).encode("utf-8")
+        self.findings: List[Dict[str, Any]] = []
+        self.data: Dict[str, Any] = {}
+
+
+class WasmPlugin:
+    """A single WebAssembly plugin module."""
+
+    def __init__(
+        self,
+        path: Path,
+        config: Optional[Dict[str, Any]] = None,
+        fuel: int = DEFAULT_FUEL,
+        max_memory_mb: int = DEFAULT_MAX_MEMORY_MB,
+    ):
+        """Initialize WasmPlugin from a module path."""
+        if wasmtime is None:
+            raise PluginError(path.stem, {"error": "wasmtime is not installed"})
+
+        self.path = path
+        self.name = path.stem
+        self.config = config or {}
+        self.fuel = fuel
+        self.max_memory_mb = max_memory_mb
+
+        engine_config = wasmtime.Config()
+        engine_config.consume_fuel = True
+        self._engine = wasmtime.Engine(engine_config)
+        try:
+            self._module = wasmtime.Module.from_file(self._engine, str(path))
+        except Exception as e:
+            raise PluginError(self.name, {"error": str(e)}) from e
+
+        export_names = {export.name for export in self._module.exports}
+        self.is_collector = "paddi_collect" in export_names
+        self.is_analyzer = "paddi_analyze" in export_names
+
+    def collect(self) -> Dict[str, Any]:
+        """Run the plugin as a collector and return the data it emitted."""
+        invocation = self._invoke("paddi_collect", {})
+        return invocation.data
+
+    def analyze(self, collected: Dict[str, Any]) -> List[SecurityFinding]:
+        """Run the plugin as an analyzer over collected data."""
+        invocation = self._invoke("paddi_analyze", collected)
+        return [self._to_finding(raw) for raw in invocation.findings]
+
+    def _invoke(self, entrypoint: str, payload: Dict[str, Any]) -> _Invocation:
+        """Instantiate the module in a fresh store and call an entrypoint."""
+        invocation = _Invocation(self.config, payload)
+
+        store = wasmtime.Store(self._engine)
+        store.set_fuel(self.fuel)
+        store.set_limits(memory_size=self.max_memory_mb * 1024 * 1024)
+
+        linker = wasmtime.Linker(self._engine)
+        self._define_host_functions(linker, invocation)
+
+        try:
+            instance = linker.instantiate(store, self._module)
+            exports = instance.exports(store)
+
+            version = exports["paddi_interface_version"](store)
+            if version != HOST_INTERFACE_VERSION:
+                raise PluginError(
+                    self.name,
+                    {
+                        "error": "unsupported host interface version",
+                        "plugin_version": version,
+                        "host_version": HOST_INTERFACE_VERSION,
+                    },
+                )
+
+            status = exports[entrypoint](store)
+        except PluginError:
+            raise
+        except Exception as e:
+            raise PluginError(self.name, {"error": str(e), "entrypoint": entrypoint}) from e
+
+        if status != 0:
+            raise PluginError(self.name, {"error": f"{entrypoint} returned {status}"})
+        return invocation
+
+    def _define_host_functions(self, linker, invocation: _Invocation) -> None:
+        """Register the ``paddi_v1`` host functions for one invocation."""
+        i32 = wasmtime.ValType.i32()
+
+        def read_string(caller, ptr: int, length: int) -> str:
+            memory = caller.get("memory")
+            return bytes(memory.read(caller, ptr, ptr + length)).decode("utf-8")
+
+        def write_bytes(caller, ptr: int, data: bytes) -> None:
+            memory = caller.get("memory")
+            memory.write(caller, data, ptr)
+
+        def emit_finding(caller, ptr: int, length: int) -> None:
+            invocation.findings.append(json.loads(read_string(caller, ptr, length)))
+
+        def emit_data(caller, ptr: int, length: int) -> None:
+            invocation.data.update(json.loads(read_string(caller, ptr, length)))
+
+        def log(caller, level: int, ptr: int, length: int) -> None:
+            message = read_string(caller, ptr, length)
+            logger.log(LOG_LEVELS.get(level, logging.INFO), "[plugin:%s] %s", self.name, message)
+
+        functions = [
+            ("config_len", [], [i32], lambda: len(invocation.config_bytes), False),
+            (
+                "config_read",
+                [i32],
+                [],
+                lambda caller, ptr: write_bytes(caller, ptr, invocation.config_bytes),
+                True,
+            ),
+            ("input_len", [], [i32], lambda: len(invocation.input_bytes), False),
+            (
+                "input_read",
+                [i32],
+                [],
+                lambda caller, ptr: write_bytes(caller, ptr, invocation.input_bytes),
+                True,
+            ),
+            ("emit_finding", [i32, i32], [], emit_finding, True),
+            ("emit_data", [i32, i32], [], emit_data, True),
+            ("log", [i32, i32, i32], [], log, True),
+        ]
+        for name, params, results, func, access_caller in functions:
+            linker.define_func(
+                HOST_MODULE,
+                name,
+                wasmtime.FuncType(params, results),
+                func,
+                access_caller=access_caller,
+            )
+
+    def _to_finding(self, raw: Dict[str, Any]) -> SecurityFinding:
+        """Convert a finding emitted by the plugin to a SecurityFinding."""
+        return SecurityFinding(
+            title=raw.get("title", "Unknown Issue"),
+            severity=str(raw.get("severity", "INFO")).upper(),
+            explanation=raw.get("explanation", ""),
+            recommendation=raw.get("recommendation", ""),
+            source=f"plugin:{self.name}",
+        )
+
+
+class WasmPluginHost:
+    """Discovers and runs WebAssembly plugins from the plugins directory."""
+
+    def __init__(
+        self,
+        plugins_dir: str = DEFAULT_PLUGINS_DIR,
+        plugin_configs: Optional[Dict[str, Dict[str, Any]]] = None,
+        fuel: int = DEFAULT_FUEL,
+        max_memory_mb: int = DEFAULT_MAX_MEMORY_MB,
+    ):
+        """Initialize WasmPluginHost with sandbox limits."""
+        self.plugins_dir = Path(plugins_dir)
+        self.plugin_configs = plugin_configs or {}
+        self.fuel = fuel
+        self.max_memory_mb = max_memory_mb
+
+    @classmethod
+    def from_config(cls, config: Optional[PaddiConfig] = None) -> "WasmPluginHost":
+        """Create a host from the ``[plugins]`` section of paddi.toml."""
+        section = (config or PaddiConfig.load()).section("plugins")
+        return cls(
+            plugins_dir=section.get("dir", DEFAULT_PLUGINS_DIR),
+            plugin_configs=section.get("config", {}),
+            fuel=section.get("fuel", DEFAULT_FUEL),
+            max_memory_mb=section.get("max_memory_mb", DEFAULT_MAX_MEMORY_MB),
+        )
+
+    def discover(self) -> List[Path]:
+        """List plugin modules in the plugins directory."""
+        if not self.plugins_dir.is_dir():
+            return []
+        return sorted(self.plugins_dir.glob("*.wasm"))
+
+    def load_plugins(self) -> List[WasmPlugin]:
+        """Load every discovered plugin, skipping ones that fail to load."""
+        paths = self.discover()
+        if paths and wasmtime is None:
+            logger.warning(
+                "Found %d WASM plugin(s) but wasmtime is not installed; skipping", len(paths)
+            )
+            return []
+
+        plugins = []
+        for path in paths:
+            try:
+                plugins.append(
+                    WasmPlugin(
+                        path,
+                        config=self.plugin_configs.get(path.stem, {}),
+                        fuel=self.fuel,
+                        max_memory_mb=self.max_memory_mb,
+                    )
+                )
+            except PluginError as e:
+                logger.error("%s %s", e.message, e.details)
+        return plugins
+
+    def run_collectors(self) -> Dict[str, Dict[str, Any]]:
+        """Run collector plugins and return their data keyed by plugin name."""
+        results = {}
+        for plugin in self.load_plugins():
+            if not plugin.is_collector:
+                continue
+            try:
+                results[plugin.name] = plugin.collect()
+                logger.info("Collected data from plugin: %s", plugin.name)
+            except PluginError as e:
+                logger.error("%s %s", e.message, e.details)
+        return results
+
+    def run_analyzers(self, collected: Dict[str, Any]) -> List[SecurityFinding]:
+        """Run analyzer plugins over collected data and gather their findings."""
+        findings = []
+        for plugin in self.load_plugins():
+            if not plugin.is_analyzer:
+                continue
+            try:
+                plugin_findings = plugin.analyze(collected)
+                logger.info("Plugin %s reported %d findings", plugin.name, len(plugin_findings))
+                findings.extend(plugin_findings)
+            except PluginError as e:
+                logger.error("%s %s", e.message, e.details)
+        return findings
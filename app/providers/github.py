@@ -7,6 +7,8 @@ from typing import Any, Dict, List
 
 import requests
 
+from app.config.secrets import resolve_secret
+
 from .base import CloudProvider
 
 logger = logging.getLogger(__name__)
@@ -33,7 +35,7 @@ class GitHubProvider(CloudProvider):
             **kwargs: Additional configuration
         """
         super().__init__(**kwargs)
-        self.access_token = access_token or os.getenv("GITHUB_TOKEN")
+        self.access_token = access_token or resolve_secret("GITHUB_TOKEN")
         self.owner = owner or os.getenv("GITHUB_OWNER") or "example-org"
         self.repo = repo or os.getenv("GITHUB_REPO") or "example-repo"
         self.repository = f"{self.owner}/{self.repo}"
@@ -0,0 +1,92 @@
+"""Generate a gcloud remediation script from findings.
+
+Each finding with a known category becomes a commented block of ``gcloud``
+commands from :data:`app.remediation.templates.GCLOUD_TEMPLATES`. Commands
+whose parameters cannot be derived from the resource name (e.g. the IAM role
+to remove) are commented out with a note, so running the script never executes
+a command with a placeholder in it.
+"""
+
+import logging
+from datetime import datetime
+from pathlib import Path
+from typing import Any, Dict, List, Optional
+
+from app.remediation.templates import (
+    GCLOUD_TEMPLATES,
+    comment_text,
+    is_gcp_resource,
+    render,
+    resource_params,
+)
+
+logger = logging.getLogger(__name__)
+
+SCRIPT_NAME = "remediation.sh"
+
+_HEADER = """#!/usr/bin/env bash
+# Paddi remediation script
+# Generated: {generated_at}
+#
+# Review every command before running it. Commands marked TODO need values
+# that could not be derived from the finding and are commented out.
+set -euo pipefail
+"""
+
+
+def _finding_block(index: int, finding: Dict[str, Any]) -> Optional[List[str]]:
+    """Render the commands for one finding, or None if there are none."""
+    category = str(finding.get("category") or "").upper()
+    resource = finding.get("resource", "")
+    templates = GCLOUD_TEMPLATES.get(category)
+    if not templates or not is_gcp_resource(resource):
+        return None
+
+    lines = [
+        "",
+        f"# [{index}] {comment_text(finding.get('severity', 'INFO'))} "
+        f"{comment_text(finding.get('title', 'Unknown Issue'))}",
+        f"# Category: {comment_text(category)}",
+        f"# Resource: {comment_text(resource)}",
+    ]
+    params = resource_params(resource)
+    for template in templates:
+        command, missing = render(template, params)
+        if missing:
+            placeholders = ", ".join(f"<{name.upper()}>" for name in missing)
+            lines.append(f"# TODO: replace {placeholders}")
+            lines.append(f"# {command}")
+        else:
+            lines.append(command)
+    return lines
+
+
+def build_script(
+    findings: List[Dict[str, Any]], generated_at: Optional[datetime] = None
+) -> str:
+    """Build the remediation script for a list of findings."""
+    generated_at = generated_at or datetime.now()
+    lines = [_HEADER.format(generated_at=generated_at.isoformat(timespec="seconds"))]
+
+    skipped = 0
+    for index, finding in enumerate(findings, 1):
+        block = _finding_block(index, finding)
+        if block is None:
+            skipped += 1
+            continue
+        lines.extend(block)
+
+    if skipped:
+        lines.extend(["", f"# {skipped} finding(s) have no gcloud remediation template"])
+    return "\n".join(lines) + "\n"
+
+
+def write_script(findings: List[Dict[str, Any]], output_dir: Path) -> Path:
+    """Write the remediation script to ``output_dir`` and make it executable."""
+    output_dir = Path(output_dir)
+    output_dir.mkdir(parents=True, exist_ok=True)
+    path = output_dir / SCRIPT_NAME
+    path.write_text(build_script(findings), encoding="utf-8")
+    path.chmod(0o755)
+    logger.info("Remediation script generated: %s", path)
+    return path
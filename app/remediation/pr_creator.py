@@ -14,6 +14,8 @@ from typing import Any, Dict, List, Optional
 import fire
 from github import Github, GithubException
 
+from app.config.secrets import resolve_secret
+
 logger = logging.getLogger(__name__)
 
 
@@ -36,7 +38,7 @@ class PullRequestCreator:
             github_repo: GitHub repository name
             base_branch: Base branch for pull requests
         """
-        self.github_token = github_token or os.getenv("GITHUB_TOKEN")
+        self.github_token = github_token or resolve_secret("GITHUB_TOKEN")
         self.github_owner = github_owner
         self.github_repo = github_repo
         self.base_branch = base_branch
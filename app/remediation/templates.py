@@ -0,0 +1,250 @@
+"""Remediation templates per finding category.
+
+There is one template set per target: ``gcloud`` commands and Terraform (HCL)
+snippets. Templates use ``{name}`` placeholders. Values are taken from the
+finding's resource name (project, bucket, service account, ...) by
+:func:`resource_params`. A placeholder that cannot be resolved is rendered as
+``<NAME>`` and reported as missing, so the caller can comment the command or
+snippet out until someone fills it in. A ``gcloud`` template starting with
+``#`` is a manual step that no command can do, and stays a comment.
+"""
+
+import json
+import re
+import shlex
+import string
+from typing import Any, Callable, Dict, List, Tuple
+
+# Resource name patterns that yield template parameters
+_RESOURCE_PATTERNS = {
+    "project": re.compile(r"projects/([^/]+)"),
+    "bucket": re.compile(r"storage\.googleapis\.com/(?:b/)?([^/]+)"),
+    "service_account": re.compile(r"serviceAccounts/([^/]+)"),
+    "firewall": re.compile(r"firewalls/([^/]+)"),
+    "location": re.compile(r"locations/([^/]+)"),
+    "keyring": re.compile(r"keyRings/([^/]+)"),
+    "key": re.compile(r"cryptoKeys/([^/]+)"),
+    "instance": re.compile(r"instances/([^/]+)"),
+    "dataset": re.compile(r"datasets/([^/]+)"),
+    "target_proxy": re.compile(r"targetHttpsProxies/([^/]+)"),
+    "cluster": re.compile(r"clusters/([^/]+)"),
+    "node_pool": re.compile(r"nodePools/([^/]+)"),
+}
+
+GCLOUD_TEMPLATES: Dict[str, List[str]] = {
+    "PUBLIC_BUCKET": [
+        "gcloud storage buckets remove-iam-policy-binding gs://{bucket} "
+        "--member=allUsers --role={role}",
+        "gcloud storage buckets remove-iam-policy-binding gs://{bucket} "
+        "--member=allAuthenticatedUsers --role={role}",
+        "gcloud storage buckets update gs://{bucket} --public-access-prevention",
+    ],
+    "BUCKET_LOGGING_DISABLED": [
+        "gcloud storage buckets update gs://{bucket} --log-bucket=gs://{log_bucket}",
+    ],
+    "BUCKET_PUBLIC_ACCESS_PREVENTION_OFF": [
+        "gcloud storage buckets update gs://{bucket} --public-access-prevention",
+    ],
+    "BUCKET_UNIFORM_ACCESS_DISABLED": [
+        "gcloud storage buckets update gs://{bucket} --uniform-bucket-level-access",
+    ],
+    "OVERPRIVILEGED_ROLE": [
+        "gcloud projects remove-iam-policy-binding {project} --member={member} --role={role}",
+    ],
+    "OVERPRIVILEGED_SERVICE_ACCOUNT": [
+        "gcloud projects remove-iam-policy-binding {project} "
+        "--member=serviceAccount:{service_account} --role={role}",
+    ],
+    "OVER_PRIVILEGED_ACCOUNT": [
+        "gcloud projects remove-iam-policy-binding {project} --member={member} --role={role}",
+    ],
+    "OPEN_FIREWALL": [
+        "gcloud compute firewall-rules update {firewall} --project={project} "
+        "--source-ranges={source_ranges}",
+    ],
+    "FIREWALL_RULE_OPEN": [
+        "gcloud compute firewall-rules update {firewall} --project={project} "
+        "--source-ranges={source_ranges}",
+    ],
+    "CRYPTO_KEY_PUBLIC_ACCESS": [
+        "gcloud kms keys remove-iam-policy-binding {key} --keyring={keyring} "
+        "--location={location} --project={project} --member=allUsers --role={role}",
+    ],
+    "WEAK_SSL_POLICY": [
+        "gcloud compute ssl-policies create paddi-modern-tls --profile=MODERN "
+        "--min-tls-version=1.2 --project={project}",
+        "gcloud compute target-https-proxies update {target_proxy} "
+        "--ssl-policy=paddi-modern-tls --project={project}",
+    ],
+    "UNENCRYPTED_DATABASE": [
+        "# Manual step: the encryption at rest of an existing Cloud SQL instance cannot be "
+        "changed. Create an instance with --disk-encryption-key set to a Cloud KMS key, "
+        "then migrate the data to it.",
+    ],
+    "SQL_PUBLIC_IP": [
+        "gcloud sql instances patch {instance} --project={project} "
+        "--network={private_network} --no-assign-ip",
+    ],
+    "SQL_BROAD_AUTHORIZED_NETWORK": [
+        "gcloud sql instances patch {instance} --project={project} "
+        "--authorized-networks={authorized_networks}",
+    ],
+    "SQL_SSL_NOT_REQUIRED": [
+        "gcloud sql instances patch {instance} --project={project} --ssl-mode=ENCRYPTED_ONLY",
+    ],
+    "SQL_BACKUPS_DISABLED": [
+        "gcloud sql instances patch {instance} --project={project} "
+        "--backup-start-time={backup_start_time}",
+    ],
+    "GKE_PUBLIC_ENDPOINT": [
+        "gcloud container clusters update {cluster} --location={location} --project={project} "
+        "--enable-master-authorized-networks --master-authorized-networks={authorized_networks}",
+    ],
+    "GKE_LEGACY_ABAC": [
+        "gcloud container clusters update {cluster} --location={location} --project={project} "
+        "--no-enable-legacy-authorization",
+    ],
+    "GKE_WORKLOAD_IDENTITY_DISABLED": [
+        "gcloud container clusters update {cluster} --location={location} --project={project} "
+        "--workload-pool={project}.svc.id.goog",
+    ],
+    "GKE_NODE_AUTO_UPGRADE_DISABLED": [
+        "gcloud container node-pools update {node_pool} --cluster={cluster} "
+        "--location={location} --project={project} --enable-autoupgrade",
+    ],
+}
+
+
+TERRAFORM_TEMPLATES: Dict[str, str] = {
+    "PUBLIC_BUCKET": """resource "google_storage_bucket" {label} {{
+  name                        = {bucket}
+  uniform_bucket_level_access = true
+  public_access_prevention    = "enforced"
+}}""",
+    "BUCKET_LOGGING_DISABLED": """resource "google_storage_bucket" {label} {{
+  name = {bucket}
+
+  logging {{
+    log_bucket = {log_bucket}
+  }}
+}}""",
+    "OVERPRIVILEGED_ROLE": """# Drop the broad binding and grant a narrower role
+resource "google_project_iam_member" {label} {{
+  project = {project}
+  role    = {replacement_role}
+  member  = {member}
+}}""",
+    "OVERPRIVILEGED_SERVICE_ACCOUNT": """# Drop the broad binding and grant a narrower role
+resource "google_project_iam_member" {label} {{
+  project = {project}
+  role    = {replacement_role}
+  member  = {service_account_member}
+}}""",
+    "OVER_PRIVILEGED_ACCOUNT": """# Drop the broad binding and grant a narrower role
+resource "google_project_iam_member" {label} {{
+  project = {project}
+  role    = {replacement_role}
+  member  = {member}
+}}""",
+    "OPEN_FIREWALL": """resource "google_compute_firewall" {label} {{
+  name          = {firewall}
+  project       = {project}
+  network       = {network}
+  source_ranges = [{source_range}]
+}}""",
+    "FIREWALL_RULE_OPEN": """resource "google_compute_firewall" {label} {{
+  name          = {firewall}
+  project       = {project}
+  network       = {network}
+  source_ranges = [{source_range}]
+}}""",
+    "AUDIT_LOGGING_DISABLED": """resource "google_project_iam_audit_config" {label} {{
+  project = {project}
+  service = "allServices"
+
+  audit_log_config {{
+    log_type = "ADMIN_READ"
+  }}
+  audit_log_config {{
+    log_type = "DATA_READ"
+  }}
+  audit_log_config {{
+    log_type = "DATA_WRITE"
+  }}
+}}""",
+    "WEAK_SSL_POLICY": """resource "google_compute_ssl_policy" {label} {{
+  name            = "paddi-modern-tls"
+  project         = {project}
+  profile         = "MODERN"
+  min_tls_version = "TLS_1_2"
+}}""",
+    "UNENCRYPTED_DATABASE": """# Setting a key replaces the instance; migrate its data first
+resource "google_sql_database_instance" {label} {{
+  name                = {instance}
+  project             = {project}
+  encryption_key_name = {kms_key}
+}}""",
+}
+
+
+def is_gcp_resource(resource: str) -> bool:
+    """Check whether a resource name looks like a GCP resource."""
+    return bool(resource) and not resource.startswith(("arn:", "/subscriptions/"))
+
+
+def comment_text(text: Any) -> str:
+    """Flatten text so it stays on a single comment line."""
+    return " ".join(str(text).split())
+
+
+def resource_params(resource: str) -> Dict[str, str]:
+    """Extract template parameters from a GCP resource name."""
+    params = {"resource": resource} if resource else {}
+    for name, pattern in _RESOURCE_PATTERNS.items():
+        match = pattern.search(resource or "")
+        if match:
+            params[name] = match.group(1)
+    if "service_account" in params:
+        params["service_account_member"] = f"serviceAccount:{params['service_account']}"
+    return params
+
+
+def hcl_label(resource: str) -> str:
+    """Derive a Terraform resource label from the last segment of a resource name."""
+    segment = (resource or "").rstrip("/").rsplit("/", 1)[-1].split("@", 1)[0]
+    label = re.sub(r"[^A-Za-z0-9_]", "_", segment).strip("_").lower()
+    if not label or not label[0].isalpha():
+        label = f"r_{label}"
+    return label
+
+
+def render(
+    template: str, params: Dict[str, str], quote: Callable[[str], str] = shlex.quote
+) -> Tuple[str, List[str]]:
+    """Fill a template with quoted parameters.
+
+    Args:
+        template: Template with ``{name}`` placeholders
+        params: Parameter values
+        quote: Quoting for values (shell quoting by default; use
+            :func:`hcl_quote` for Terraform)
+
+    Returns:
+        The rendered command and the names of placeholders that had no value
+    """
+    missing = []
+    values = {}
+    for _, name, _, _ in string.Formatter().parse(template):
+        if not name or name in values:
+            continue
+        if name in params:
+            values[name] = quote(params[name])
+        else:
+            values[name] = f"<{name.upper()}>"
+            missing.append(name)
+    return template.format(**values), missing
+
+
+def hcl_quote(value: str) -> str:
+    """Quote a value as an HCL string literal."""
+    return json.dumps(value).replace("${", "$${")
@@ -0,0 +1,98 @@
+"""Generate Terraform remediation snippets from findings.
+
+Each finding with a known category becomes an HCL block from
+:data:`app.remediation.templates.TERRAFORM_TEMPLATES`, preceded by a comment
+naming the finding. Snippets that need values which cannot be derived from the
+resource name are commented out with a note, so the file stays valid HCL.
+"""
+
+import logging
+from pathlib import Path
+from typing import Any, Dict, List, Optional
+
+from app.remediation.templates import (
+    TERRAFORM_TEMPLATES,
+    comment_text,
+    hcl_label,
+    hcl_quote,
+    is_gcp_resource,
+    render,
+    resource_params,
+)
+
+logger = logging.getLogger(__name__)
+
+TERRAFORM_FILE = "remediation.tf"
+
+_HEADER = """# Paddi remediation snippets
+#
+# Copy the blocks for the resources you manage into your Terraform
+# configuration and review them in a pull request. Blocks marked TODO need
+# values that could not be derived from the finding and are commented out.
+"""
+
+
+def snippet_for(finding: Dict[str, Any], labels: Optional[set] = None) -> Optional[str]:
+    """Render the HCL snippet for one finding, or None if there is none.
+
+    Args:
+        finding: Finding as loaded from explained.json
+        labels: Labels already used in the file; the new label is added and
+            made unique with a numeric suffix
+    """
+    category = str(finding.get("category") or "").upper()
+    resource = finding.get("resource", "")
+    template = TERRAFORM_TEMPLATES.get(category)
+    if not template or not is_gcp_resource(resource):
+        return None
+
+    label = hcl_label(resource)
+    if labels is not None:
+        base, suffix = label, 2
+        while label in labels:
+            label, suffix = f"{base}_{suffix}", suffix + 1
+        labels.add(label)
+
+    params = dict(resource_params(resource), label=label)
+    snippet, missing = render(template, params, quote=hcl_quote)
+
+    lines = [
+        f"# {comment_text(finding.get('severity', 'INFO'))} "
+        f"{comment_text(finding.get('title', 'Unknown Issue'))}",
+        f"# Category: {comment_text(category)}",
+        f"# Resource: {comment_text(resource)}",
+    ]
+    if missing:
+        placeholders = ", ".join(f"<{name.upper()}>" for name in missing)
+        lines.append(f"# TODO: replace {placeholders}")
+        lines.extend(f"# {line}" if line else "#" for line in snippet.splitlines())
+    else:
+        lines.append(snippet)
+    return "\n".join(lines)
+
+
+def build_snippets(findings: List[Dict[str, Any]]) -> str:
+    """Build the Terraform remediation file for a list of findings."""
+    blocks = [_HEADER]
+    labels: set = set()
+    skipped = 0
+    for finding in findings:
+        snippet = snippet_for(finding, labels)
+        if snippet is None:
+            skipped += 1
+            continue
+        blocks.append(snippet)
+
+    if skipped:
+        blocks.append(f"# {skipped} finding(s) have no Terraform remediation template")
+    return "\n\n".join(blocks) + "\n"
+
+
+def write_snippets(findings: List[Dict[str, Any]], output_dir: Path) -> Path:
+    """Write the Terraform remediation file to ``output_dir``."""
+    output_dir = Path(output_dir)
+    output_dir.mkdir(parents=True, exist_ok=True)
+    path = output_dir / TERRAFORM_FILE
+    path.write_text(build_snippets(findings), encoding="utf-8")
+    logger.info("Terraform remediation snippets generated: %s", path)
+    return path
@@ -6,20 +6,63 @@ human-readable audit reports in Markdown and HTML formats.
 
 import json
 import logging
+import sqlite3
 from abc import ABC, abstractmethod
 from dataclasses import dataclass
 from datetime import datetime
+from html import escape
 from pathlib import Path
-from typing import Any, Dict, List, Optional
+from typing import Any, Dict, List, Optional, Tuple, Union
 
 import fire
 from jinja2 import Environment, FileSystemLoader, select_autoescape
 
-from app.common.models import SecurityFinding
+from app.common.artifact_crypto import load_artifact, open_artifact, write_artifact_text
+from app.common.artifact_schemas import STREAMED, validate_file
+from app.common.exceptions import ConfigurationError, DataValidationError
+from app.common.failures import FailureLog, StageFailure
+from app.common.json_stream import iter_events
+from app.common.models import SEVERITY_ORDER, SecurityFinding, severity_rank
+from app.compliance.framework import FAIL, FrameworkCoverage, coverage_to_csv, evaluate
+from app.compliance.mapping import load_frameworks
+from app.config.paddi_config import PaddiConfig
+from app.findings.dedup import deduplicate_findings
+from app.findings.fingerprint import compute_fingerprint
+from app.findings.listing import filter_findings
+from app.findings.ownership import OwnerSummary, OwnershipRules
+from app.findings.query import Query
+from app.findings.risk import RiskModel, RiskSummary
+from app.findings.severity_overrides import SeverityOverrides
+from app.findings.suppressions import Suppressions, SuppressionResult
+from app.findings.tags import TagRules, load_finding_tags
+from app.history.environment import run_environment
+from app.history.store import HISTORY_DB_NAME, RunRecord, RunStore
+from app.remediation.terraform import write_snippets
+from app.reporter.branding import Branding
+from app.reporter.charts import TREND_RUNS, severity_chart, trend_chart
+from app.reporter.github_annotations import write_annotations
+from app.reporter.markdown_nav import (
+    anchor_tag,
+    finding_anchor,
+    group_by_category,
+    severity_badge,
+    severity_summary,
+    slugify,
+    toc_entry,
+)
+from app.reporter.gitlab_security import write_gitlab_report
+from app.reporter.executive_summary import ExecutiveSummary, build_executive_summary
 
 logging.basicConfig(level=logging.INFO)
 logger = logging.getLogger(__name__)
 
+# Number of riskiest resources listed in the executive summary
+TOP_RISK_RESOURCES = 10
+# Directory of the Markdown report split by category, in the output directory
+SPLIT_MARKDOWN_DIR = "audit-by-category"
+# Template file of each format that can be rendered from a template
+TEMPLATE_NAMES = {"markdown": "report.md.j2", "html": "report.html.j2"}
+
 
 @dataclass
 class AuditReport:
@@ -32,6 +75,15 @@ class AuditReport:
     severity_counts: Dict[str, int]
     providers: Optional[List[str]] = None
     provider_distribution: Optional[Dict[str, int]] = None
+    risk: Optional[RiskSummary] = None
+    compliance: Optional[List[FrameworkCoverage]] = None
+    executive: Optional[ExecutiveSummary] = None
+    suppressions: Optional[SuppressionResult] = None
+    owners: Optional[List[OwnerSummary]] = None
+    failures: Optional[List[StageFailure]] = None
+    branding: Optional[Branding] = None
+    # Earlier runs of the project, oldest first, ending with this one
+    history: Optional[List[RunRecord]] = None
 
 
 class ReportGenerator(ABC):
@@ -59,6 +111,9 @@ class MarkdownGenerator(ReportGenerator):
             f"**Audit Date:** {report.audit_date}",
             f"**Total Findings:** {report.total_findings}",
             "",
+            *self._table_of_contents(report),
+            *self._failures_section(report.failures),
+            anchor_tag("executive-summary"),
             "## Executive Summary",
             "",
             (
@@ -75,30 +130,309 @@ class MarkdownGenerator(ReportGenerator):
         for severity, count in sorted(report.severity_counts.items()):
             lines.append(f"- **{severity}**: {count} findings")
 
+        if report.risk:
+            lines.extend(
+                [
+                    "",
+                    "### Risk Score",
+                    "",
+                    f"**Overall Grade:** {report.risk.grade} (score {report.risk.score})",
+                ]
+            )
+            if report.risk.resources:
+                lines.extend(
+                    [
+                        "",
+                        "| Resource | Findings | Risk Score |",
+                        "|----------|----------|------------|",
+                    ]
+                )
+                for resource in report.risk.resources[:TOP_RISK_RESOURCES]:
+                    lines.append(
+                        f"| `{resource.resource}` | {resource.findings} | {resource.score} |"
+                    )
+
+        if report.executive:
+            lines.extend(self._executive_details(report.executive))
+
         if report.providers and len(report.providers) > 1:
             lines.extend(["", "### Provider Distribution", ""])
             for provider, count in sorted(report.provider_distribution.items()):
                 lines.append(f"- **{provider.upper()}**: {count} findings")
 
-        lines.extend(["", "## Detailed Findings", ""])
-
+        lines.extend(["", anchor_tag("detailed-findings"), "## Detailed Findings", ""])
         for i, finding in enumerate(report.findings, 1):
+            lines.extend(self._finding_lines(finding, i))
+
+        if report.owners:
+            lines.extend(self._owner_sections(report.owners))
+
+        if report.suppressions:
+            lines.extend(self._suppressions_section(report.suppressions))
+
+        for coverage in report.compliance or []:
+            lines.extend(self._compliance_appendix(coverage))
+
+        return "\n".join(lines)
+
+    def _table_of_contents(self, report: AuditReport) -> List[str]:
+        """List the report's sections and link every finding."""
+        lines = [
+            anchor_tag("contents"),
+            "## Contents",
+            "",
+            "- [Executive Summary](#executive-summary)",
+            "- [Detailed Findings](#detailed-findings)",
+        ]
+        lines.extend(f"  {toc_entry(finding, i)}" for i, finding in enumerate(report.findings, 1))
+        if report.owners:
+            lines.append("- [Findings by Owner](#findings-by-owner)")
+        if report.suppressions and (report.suppressions.suppressed or report.suppressions.expired):
+            lines.append("- [Suppressions](#suppressions)")
+        for coverage in report.compliance or []:
+            name = f"{coverage.framework.name} {coverage.framework.version}"
+            lines.append(f"- [{name}](#compliance-{slugify(coverage.framework.key)})")
+        if report.severity_counts:
+            lines.extend(["", severity_summary(report.severity_counts)])
+        lines.append("")
+        return lines
+
+    def _finding_lines(self, finding: SecurityFinding, i: int, target: str = "") -> List[str]:
+        """Render one finding under its anchor and numbered heading."""
+        lines = [
+            anchor_tag(finding_anchor(finding, i)),
+            f"### {i}. {finding.title}",
+            "",
+            f"**Severity:** {severity_badge(finding.severity)}"
+            + (
+                f" (overridden from {finding.original_severity})"
+                if finding.original_severity
+                else ""
+            ),
+            "",
+        ]
+        if finding.fingerprint:
+            lines.extend([f"**Fingerprint:** `{finding.fingerprint}`", ""])
+        if finding.owner:
+            lines.extend([f"**Owner:** {finding.owner}", ""])
+        if finding.tags:
+            lines.extend([f"**Tags:** {', '.join(finding.tags)}", ""])
+        if finding.cvss_score is not None:
+            vector = f" (`{finding.cvss_vector}`)" if finding.cvss_vector else ""
+            lines.extend([f"**CVSS:** {finding.cvss_score}{vector}", ""])
+        if finding.occurrences > 1:
+            lines.extend([f"**Occurrences:** {finding.occurrences}", ""])
+        if len(finding.affected_resources) > 1:
+            lines.append("**Affected Resources:**")
+            lines.extend(f"- `{resource}`" for resource in finding.affected_resources)
+            lines.append("")
+        lines.extend(
+            [
+                f"**Explanation:** {finding.explanation}",
+                "",
+                f"**Recommendation:** {finding.recommendation}",
+                "",
+                f"[Back to contents]({target}#contents)",
+                "",
+                "---",
+                "",
+            ]
+        )
+        return lines
+
+    def split_by_category(self, report: AuditReport) -> Dict[str, str]:
+        """Render the report as an index and one file per finding category.
+
+        For audits too large to read as one file. Findings keep the numbers
+        and anchors they have in the full report.
+
+        Returns:
+            Content of each file, by file name; the index is README.md
+        """
+        groups = group_by_category(report.findings)
+        files: Dict[str, str] = {}
+        for category in groups:
+            name = f"{slugify(category)}.md"
+            # Categories that differ only in case or punctuation get their own files
+            if name in files.values() or name == "readme.md":
+                name = f"{slugify(category)}-{len(files) + 1}.md"
+            files[category] = name
+        index = [
+            f"# Security Audit Report - {report.project_name}",
+            "",
+            f"**Audit Date:** {report.audit_date}",
+            f"**Total Findings:** {report.total_findings}",
+            "",
+            severity_summary(report.severity_counts),
+            "",
+            *self._failures_section(report.failures),
+            anchor_tag("contents"),
+            "## Findings by Category",
+            "",
+            "| Category | Findings | Highest Severity |",
+            "|----------|----------|------------------|",
+        ]
+        for category, findings in groups.items():
+            worst = min((finding.severity for _, finding in findings), key=severity_rank)
+            index.append(
+                f"| [{category}]({files[category]}) | {len(findings)} "
+                f"| {severity_badge(worst)} |"
+            )
+        content = {}
+        for category, findings in groups.items():
+            index.extend(["", f"### [{category}]({files[category]})", ""])
+            index.extend(toc_entry(finding, i, files[category]) for i, finding in findings)
+            lines = [
+                f"# {category} - {report.project_name}",
+                "",
+                f"{len(findings)} finding(s). [Back to the index](README.md)",
+                "",
+            ]
+            for i, finding in findings:
+                lines.extend(self._finding_lines(finding, i, "README.md"))
+            content[files[category]] = "\n".join(lines)
+        content["README.md"] = "\n".join(index + [""])
+        return content
+
+    def _failures_section(self, failures: Optional[List[StageFailure]]) -> List[str]:
+        """Warn that the report is partial and list what failed."""
+        if not failures:
+            return []
+        lines = [
+            "## Incomplete Results",
+            "",
+            "This report is partial: the findings of these steps are missing.",
+            "",
+            "| Stage | Target | Error |",
+            "|-------|--------|-------|",
+        ]
+        for failure in failures:
+            error = failure.error.replace("|", "\\|").replace("\n", " ")
+            lines.append(f"| {failure.stage} | `{failure.target}` | {error} |")
+        lines.append("")
+        return lines
+
+    def _owner_sections(self, owners: List[OwnerSummary]) -> List[str]:
+        """Render finding counts per owner, then each owner's findings."""
+        lines = [
+            anchor_tag("findings-by-owner"),
+            "## Findings by Owner",
+            "",
+            "| Owner | Contact | " + " | ".join(SEVERITY_ORDER) + " | Total |",
+            "|-------|---------|" + "|".join("---" for _ in SEVERITY_ORDER) + "|-------|",
+        ]
+        for summary in owners:
+            counts = " | ".join(str(summary.severity_counts.get(s, 0)) for s in SEVERITY_ORDER)
+            lines.append(
+                f"| {summary.owner} | {summary.contact or '-'} | {counts} | {summary.total} |"
+            )
+        for summary in owners:
+            contact = f" ({summary.contact})" if summary.contact else ""
+            lines.extend(["", f"### {summary.owner}{contact}", ""])
+            for finding in summary.findings:
+                resource = f" (`{finding['resource']}`)" if finding.get("resource") else ""
+                lines.append(
+                    f"- **{finding.get('severity', 'INFO')}** "
+                    f"{finding.get('title', 'Unknown Issue')}{resource}"
+                )
+        lines.append("")
+        return lines
+
+    def _suppressions_section(self, suppressions: SuppressionResult) -> List[str]:
+        """Render the count of suppressed findings and the expired suppressions."""
+        if not suppressions.suppressed and not suppressions.expired:
+            return []
+        lines = [
+            anchor_tag("suppressions"),
+            "## Suppressions",
+            "",
+            f"{len(suppressions.suppressed)} findings are suppressed as accepted risks.",
+            "",
+        ]
+        if suppressions.expired:
             lines.extend(
                 [
-                    f"### {i}. {finding.title}",
+                    "### Expired Suppressions",
                     "",
-                    f"**Severity:** {finding.severity}",
+                    "These suppressions have expired, so their findings are reported again.",
                     "",
-                    f"**Explanation:** {finding.explanation}",
-                    "",
-                    f"**Recommendation:** {finding.recommendation}",
+                    "| Match | Owner | Justification | Expired | Findings |",
+                    "|-------|-------|---------------|---------|----------|",
+                ]
+            )
+            for entry in suppressions.expired:
+                rule = entry.suppression
+                lines.append(
+                    f"| `{rule.target}` | {rule.owner} | {rule.justification} "
+                    f"| {rule.expires.isoformat()} | {len(entry.findings)} |"
+                )
+            lines.append("")
+        return lines
+
+    def _executive_details(self, executive: ExecutiveSummary) -> List[str]:
+        """Render trend, top risks, and recommended priorities."""
+        lines = []
+        if executive.trend:
+            trend = executive.trend
+            lines.extend(
+                [
                     "",
-                    "---",
+                    "### Trend vs. Previous Run",
                     "",
+                    f"- **Total:** {trend.current_total} findings "
+                    f"({trend.delta:+d} since {trend.previous_date})",
+                    f"- **New:** {trend.new}",
+                    f"- **Resolved:** {trend.resolved}",
                 ]
             )
 
-        return "\n".join(lines)
+        if executive.top_risks:
+            lines.extend(["", "### Top Risks", ""])
+            for i, finding in enumerate(executive.top_risks, 1):
+                resource = f" (`{finding['resource']}`)" if finding.get("resource") else ""
+                lines.append(
+                    f"{i}. **{finding.get('severity', 'INFO')}** "
+                    f"{finding.get('title', 'Unknown Issue')}{resource}"
+                )
+
+        if executive.priorities:
+            lines.extend(["", "### Recommended Priorities", ""])
+            lines.extend(f"{i}. {text}" for i, text in enumerate(executive.priorities, 1))
+        return lines
+
+    def _compliance_appendix(self, coverage: FrameworkCoverage) -> List[str]:
+        """Render control coverage and exceptions for one framework."""
+        lines = [
+            anchor_tag(f"compliance-{slugify(coverage.framework.key)}"),
+            f"## {coverage.framework.name} {coverage.framework.version}",
+            "",
+            f"{coverage.passed} of {len(coverage.results)} mapped controls passing.",
+            "",
+        ]
+        header = "| Control | Title | Status | Findings |"
+        divider = "|---------|-------|--------|----------|"
+        if coverage.scoped:
+            header += " In Scope |"
+            divider += "----------|"
+        lines.extend([header, divider])
+        for result in coverage.results:
+            row = (
+                f"| {result.control.control_id} | {result.control.title} "
+                f"| {result.status} | {len(result.findings)} |"
+            )
+            lines.append(row + (f" {len(result.in_scope)} |" if coverage.scoped else ""))
+
+        exceptions = [result for result in coverage.results if result.status == FAIL]
+        if exceptions:
+            lines.extend(["", "### Exceptions", ""])
+            for result in exceptions:
+                lines.append(f"- **{result.control.control_id}** {result.control.title}")
+                for finding in result.findings:
+                    resource = f" (`{finding['resource']}`)" if finding.get("resource") else ""
+                    scope = " **[in scope]**" if finding in result.in_scope else ""
+                    lines.append(f"  - {finding.get('title', 'Unknown Issue')}{resource}{scope}")
+        lines.append("")
+        return lines
 
     def _generate_from_template(self, report: AuditReport, template_path: Path) -> str:
         """Generate Markdown report from template."""
@@ -106,6 +440,11 @@ class MarkdownGenerator(ReportGenerator):
             loader=FileSystemLoader(template_path.parent),
             autoescape=select_autoescape(),
         )
+        # Navigation helpers, also available to custom templates
+        env.filters.update(
+            severity_badge=severity_badge, finding_anchor=finding_anchor, slugify=slugify
+        )
+        env.globals.update(toc_entry=toc_entry, severity_summary=severity_summary)
         template = env.get_template(template_path.name)
         return template.render(report=report)
 
@@ -113,6 +452,14 @@ class MarkdownGenerator(ReportGenerator):
 class HTMLGenerator(ReportGenerator):
     """Generates HTML reports."""
 
+    def __init__(self, charts: bool = True):
+        """Initialize HTMLGenerator.
+
+        Args:
+            charts: Whether to embed the severity and trend charts
+        """
+        self.charts = charts
+
     def generate(self, report: AuditReport, template_path: Optional[Path] = None) -> str:
         """Generate HTML report content."""
         if template_path:
@@ -121,13 +468,38 @@ class HTMLGenerator(ReportGenerator):
 
     def _generate_default(self, report: AuditReport) -> str:
         """Generate default HTML report with styling."""
-        severity_colors = {
-            "CRITICAL": "#D32F2F",
-            "HIGH": "#F44336",
-            "MEDIUM": "#FF9800",
-            "LOW": "#FFC107",
-            "INFO": "#2196F3",
-        }
+        branding = report.branding or Branding()
+
+        risk = ""
+        if report.risk:
+            risk = (
+                f"        <p><strong>Overall Risk Grade:</strong> {report.risk.grade} "
+                f"(score {report.risk.score})</p>\n"
+            )
+
+        executive = ""
+        if report.executive and report.executive.trend:
+            trend = report.executive.trend
+            executive += (
+                f"        <p><strong>Trend:</strong> {trend.delta:+d} findings since "
+                f"{trend.previous_date} ({trend.new} new, {trend.resolved} resolved)</p>\n"
+            )
+        if report.executive and report.executive.priorities:
+            executive += "        <h3>Recommended Priorities</h3>\n        <ol>\n"
+            for text in report.executive.priorities:
+                executive += f"            <li>{text}</li>\n"
+            executive += "        </ol>\n"
+
+        failures = ""
+        if report.failures:
+            failures = (
+                '        <div class="failures">\n'
+                "            <strong>Incomplete results:</strong> "
+                "the findings of these steps are missing.\n            <ul>\n"
+            )
+            for failure in report.failures:
+                failures += f"                <li>{escape(str(failure))}</li>\n"
+            failures += "            </ul>\n        </div>\n"
 
         html = f"""<!DOCTYPE html>
 <html lang="en">
@@ -143,7 +515,7 @@ class HTMLGenerator(ReportGenerator):
             max-width: 900px;
             margin: 0 auto;
             padding: 20px;
-            background-color: #f5f5f5;
+            background-color: {branding.background_color};
         }}
         .container {{
             background-color: white;
@@ -152,8 +524,8 @@ class HTMLGenerator(ReportGenerator):
             box-shadow: 0 2px 10px rgba(0,0,0,0.1);
         }}
         h1 {{
-            color: #1a73e8;
-            border-bottom: 3px solid #1a73e8;
+            color: {branding.primary_color};
+            border-bottom: 3px solid {branding.primary_color};
             padding-bottom: 10px;
         }}
         h2 {{
@@ -195,6 +567,12 @@ class HTMLGenerator(ReportGenerator):
             border-radius: 5px;
             margin-top: 10px;
         }}
+        .failures {{
+            background-color: #fff3e0;
+            border-left: 4px solid #FF9800;
+            padding: 10px 15px;
+            margin: 20px 0;
+        }}
         .summary-grid {{
             display: grid;
             grid-template-columns: repeat(auto-fit, minmax(150px, 1fr));
@@ -216,27 +594,64 @@ class HTMLGenerator(ReportGenerator):
             font-weight: bold;
             margin: 10px 0;
         }}
+        table {{
+            border-collapse: collapse;
+            width: 100%;
+        }}
+        th, td {{
+            border: 1px solid #e0e0e0;
+            padding: 6px 10px;
+            text-align: left;
+        }}
+        .branding {{
+            display: flex;
+            align-items: center;
+            gap: 15px;
+            margin-bottom: 10px;
+        }}
+        .branding .logo {{
+            max-height: 48px;
+        }}
+        .branding .company {{
+            font-size: 20px;
+            font-weight: bold;
+            color: {branding.primary_color};
+        }}
+        footer.confidential {{
+            text-align: center;
+            font-size: 12px;
+            color: #5f6368;
+            margin-top: 20px;
+        }}
+        @media print {{
+            footer.confidential {{
+                position: fixed;
+                bottom: 0;
+                width: 100%;
+            }}
+        }}
     </style>
 </head>
 <body>
     <div class="container">
-        <h1>Security Audit Report - {report.project_name}</h1>
+{branding.header_html()}        <h1>Security Audit Report - {report.project_name}</h1>
 
         <div class="metadata">
             <strong>Audit Date:</strong> {report.audit_date}<br>
             <strong>Total Findings:</strong> {report.total_findings}
         </div>
-
+{failures}
         <h2>Executive Summary</h2>
         <p>This security audit identified {report.total_findings} findings
         across your GCP infrastructure.</p>
 
+{risk}{executive}
         <h3>Severity Breakdown</h3>
         <div class="summary-grid">
 """
 
         for severity, count in sorted(report.severity_counts.items()):
-            color = severity_colors.get(severity, "#9E9E9E")
+            color = branding.severity_color(severity)
             html += f"""
             <div class="summary-card">
                 <h4>{severity}</h4>
@@ -246,18 +661,40 @@ class HTMLGenerator(ReportGenerator):
 
         html += """
         </div>
+"""
+        if self.charts:
+            html += self._charts(report, branding)
 
+        html += """
         <h2>Detailed Findings</h2>
 """
 
         for i, finding in enumerate(report.findings, 1):
             severity_class = f"finding-{finding.severity.lower()}"
-            badge_color = severity_colors.get(finding.severity, "#9E9E9E")
+            badge_color = branding.severity_color(finding.severity)
+            fingerprint = (
+                f' <code class="fingerprint">{finding.fingerprint}</code>'
+                if finding.fingerprint
+                else ""
+            )
+            cvss = (
+                f"<p><strong>CVSS:</strong> {finding.cvss_score} "
+                f"<code>{finding.cvss_vector or ''}</code></p>"
+                if finding.cvss_score is not None
+                else ""
+            )
+            occurrences = (
+                f"<p><strong>Occurrences:</strong> {finding.occurrences}</p>"
+                if finding.occurrences > 1
+                else ""
+            )
             html += f"""
         <div class="finding {severity_class}">
             <h3>{i}. {finding.title}</h3>
             <p><span class="severity-badge"
-            style="background-color: {badge_color};">{finding.severity}</span></p>
+            style="background-color: {badge_color};">{finding.severity}</span>{fingerprint}</p>
+            {cvss}
+            {occurrences}
             <p><strong>Explanation:</strong> {finding.explanation}</p>
             <div class="recommendation">
                 <strong>Recommendation:</strong> {finding.recommendation}
@@ -265,12 +702,82 @@ class HTMLGenerator(ReportGenerator):
         </div>
 """
 
-        html += """
+        if report.owners:
+            html += """
+        <h2>Findings by Owner</h2>
+        <table>
+            <tr><th>Owner</th><th>Contact</th>"""
+            html += "".join(f"<th>{severity}</th>" for severity in SEVERITY_ORDER)
+            html += "<th>Total</th></tr>\n"
+            for summary in report.owners:
+                counts = "".join(
+                    f"<td>{summary.severity_counts.get(severity, 0)}</td>"
+                    for severity in SEVERITY_ORDER
+                )
+                html += (
+                    f"            <tr><td>{summary.owner}</td><td>{summary.contact or '-'}</td>"
+                    f"{counts}<td>{summary.total}</td></tr>\n"
+                )
+            html += "        </table>\n"
+
+        suppressions = report.suppressions
+        if suppressions and (suppressions.suppressed or suppressions.expired):
+            html += f"""
+        <h2>Suppressions</h2>
+        <p>{len(suppressions.suppressed)} findings are suppressed as accepted risks.</p>
+"""
+        if suppressions and suppressions.expired:
+            html += """        <h3>Expired Suppressions</h3>
+        <p>These suppressions have expired, so their findings are reported again.</p>
+        <table>
+            <tr><th>Match</th><th>Owner</th><th>Justification</th><th>Expired</th>
+                <th>Findings</th></tr>
+"""
+            for entry in suppressions.expired:
+                rule = entry.suppression
+                html += (
+                    f"            <tr><td><code>{rule.target}</code></td><td>{rule.owner}</td>"
+                    f"<td>{rule.justification}</td><td>{rule.expires.isoformat()}</td>"
+                    f"<td>{len(entry.findings)}</td></tr>\n"
+                )
+            html += "        </table>\n"
+
+        for coverage in report.compliance or []:
+            html += f"""
+        <h2>{coverage.framework.name} {coverage.framework.version}</h2>
+        <p>{coverage.passed} of {len(coverage.results)} mapped controls passing.</p>
+        <table>
+            <tr><th>Control</th><th>Title</th><th>Status</th><th>Findings</th></tr>
+"""
+            for result in coverage.results:
+                html += (
+                    f"            <tr><td>{result.control.control_id}</td>"
+                    f"<td>{result.control.title}</td><td>{result.status}</td>"
+                    f"<td>{len(result.findings)}</td></tr>\n"
+                )
+            html += "        </table>\n"
+
+        html += f"""
     </div>
-</body>
+{branding.footer_html()}</body>
 </html>"""
         return html
 
+    def _charts(self, report: AuditReport, branding: Branding) -> str:
+        """Render the severity distribution and, with earlier runs, the trend as inline SVG."""
+        html = ""
+        chart = severity_chart(report.severity_counts, branding.severity_colors)
+        if chart:
+            html += f'\n        <div class="chart">\n{chart}\n        </div>\n'
+        chart = trend_chart(report.history or [], branding.primary_color)
+        if chart:
+            html += (
+                "\n        <h3>Trend</h3>\n"
+                f"        <p>Findings over the last {len(report.history[-TREND_RUNS:])} runs.</p>\n"
+                f'        <div class="chart">\n{chart}\n        </div>\n'
+            )
+        return html
+
     def _generate_from_template(self, report: AuditReport, template_path: Path) -> str:
         """Generate HTML report from template."""
         env = Environment(
@@ -482,22 +989,50 @@ class ReportService:
         input_dir: Path = Path("data"),
         output_dir: Path = Path("output"),
         template_dir: Optional[Path] = None,
+        custom_template_dir: Optional[Path] = None,
+        extra_inputs: Optional[List[Path]] = None,
+        continue_on_error: bool = False,
     ):
-        """Initialize ReportService with directories."""
+        """Initialize ReportService with directories.
+
+        A template in ``custom_template_dir`` replaces the one of its format in
+        ``template_dir`` (see ``TEMPLATE_NAMES``); formats without one keep the
+        built-in layout.
+        With continue_on_error, an extra input that cannot be read and a report
+        format that fails are recorded in ``failure_log`` instead of stopping.
+        """
         self.input_dir = input_dir
         self.output_dir = output_dir
         self.template_dir = template_dir
+        self.custom_template_dir = custom_template_dir
+        self.extra_inputs = extra_inputs or []
+        self.failure_log = FailureLog(continue_on_error)
         self.output_dir.mkdir(exist_ok=True)
 
     def load_findings(self) -> List[Dict[str, Any]]:
-        """Load security findings from explained.json."""
-        explained_file = self.input_dir / "explained.json"
-        if not explained_file.exists():
-            logger.error("Input file not found: %s", explained_file)
-            return []
-
-        with open(explained_file, "r", encoding="utf-8") as f:
-            return json.load(f)
+        """Load security findings from explained.json and any extra inputs."""
+        findings = []
+        for explained_file in [self.input_dir / "explained.json", *self.extra_inputs]:
+            if not explained_file.exists():
+                logger.error("Input file not found: %s", explained_file)
+                continue
+
+            if self.failure_log.continue_on_error and explained_file in self.extra_inputs:
+                loaded = self.failure_log.run(
+                    "input", str(explained_file), self._load_input, explained_file
+                )
+                findings.extend(loaded or [])
+                continue
+
+            findings.extend(load_artifact(explained_file))
+        return findings
+
+    def _load_input(self, path: Path) -> List[Dict[str, Any]]:
+        """Load an extra input that the report command did not check up front."""
+        errors = validate_file(path, "explained")
+        if errors:
+            raise DataValidationError(str(path), {"errors": errors})
+        return load_artifact(path)
 
     def load_metadata(self) -> Dict[str, Any]:
         """Load project metadata from collected.json."""
@@ -506,40 +1041,79 @@ class ReportService:
             logger.warning("Metadata file not found: %s", collected_file)
             return {"project_id": "unknown-project"}
 
-        with open(collected_file, "r", encoding="utf-8") as f:
-            data = json.load(f)
-            # Handle multi-cloud data structure
-            if "providers" in data:
-                providers = []
-                project_names = []
-                for provider_data in data.get("providers", []):
-                    provider_name = provider_data.get("provider", "unknown")
-                    providers.append(provider_name)
-                    if provider_name == "gcp":
-                        project_names.append(provider_data.get("project_id", "unknown"))
-                    elif provider_name == "aws":
-                        project_names.append(provider_data.get("account_id", "unknown"))
-                    elif provider_name == "azure":
-                        project_names.append(provider_data.get("subscription_id", "unknown"))
-
-                return {
-                    "project_id": " / ".join(project_names) if project_names else "Multi-Cloud",
-                    "providers": providers,
-                    "multi_cloud": True,
-                }
-            # Handle single provider (backward compatibility)
-            return data.get("metadata", {"project_id": "unknown-project"})
+        data = self._collected_summary(collected_file)
+        # Handle multi-cloud data structure
+        if "providers" in data:
+            providers = []
+            project_names = []
+            for provider_data in data.get("providers", []):
+                provider_name = provider_data.get("provider", "unknown")
+                if provider_data.get("status") == "failed":
+                    # Collection went on without this provider, so the report is partial
+                    self.failure_log.record(
+                        "collect", provider_name, provider_data.get("error", "unknown error")
+                    )
+                    continue
+                providers.append(provider_name)
+                if provider_name == "gcp":
+                    project_names.append(provider_data.get("project_id", "unknown"))
+                elif provider_name == "aws":
+                    project_names.append(provider_data.get("account_id", "unknown"))
+                elif provider_name == "azure":
+                    project_names.append(provider_data.get("subscription_id", "unknown"))
+
+            return {
+                "project_id": " / ".join(project_names) if project_names else "Multi-Cloud",
+                "providers": providers,
+                "multi_cloud": True,
+            }
+        # Handle single provider (backward compatibility)
+        return data.get("metadata", {"project_id": "unknown-project"})
+
+    @staticmethod
+    def _collected_summary(path: Path) -> Dict[str, Any]:
+        """Read the metadata and the provider statuses of collected.json as a stream.
+
+        The collected resources and findings are skipped one at a time, so the
+        summary of an organization-wide scan takes little memory.
+        """
+        summary: Dict[str, Any] = {}
+        with open_artifact(path) as stream:
+            for kind, parts, value in iter_events(stream, STREAMED["collected"]):
+                if parts == ("metadata",) and kind == "value":
+                    summary["metadata"] = value
+                elif parts == ("providers",) and kind == "start":
+                    summary["providers"] = []
+                elif len(parts) == 2 and parts[0] == "providers" and kind != "end":
+                    # Large members of each provider are streamed, so only its fields are kept
+                    summary["providers"].append({} if kind == "start" else value)
+                elif len(parts) == 3 and parts[0] == "providers" and kind == "value":
+                    if isinstance(parts[2], str) and not isinstance(value, (dict, list)):
+                        summary["providers"][-1][parts[2]] = value
+        return summary
 
     def create_report(
-        self, findings_data: List[Dict[str, Any]], metadata: Dict[str, Any]
+        self,
+        findings_data: List[Dict[str, Any]],
+        metadata: Dict[str, Any],
+        frameworks: Optional[List[str]] = None,
+        previous: Optional[Tuple[RunRecord, List[Dict[str, Any]]]] = None,
+        suppressions: Optional[SuppressionResult] = None,
+        history: Optional[List[RunRecord]] = None,
     ) -> AuditReport:
-        """Create AuditReport from raw data."""
+        """Create AuditReport from raw data.
+
+        Args:
+            findings_data: Findings as loaded from explained.json
+            metadata: Project metadata
+            frameworks: Compliance framework keys to include (defaults to all)
+            previous: The previous run and its findings, for the executive trend
+            suppressions: Suppressed findings and expired suppressions
+            history: Recorded runs of the project, oldest first, for the trend chart
+        """
         findings = [
-            SecurityFinding(
-                title=f.get("title", "Unknown Issue"),
-                severity=f.get("severity", "INFO"),
-                explanation=f.get("explanation", "No explanation provided"),
-                recommendation=f.get("recommendation", "No recommendation provided"),
+            SecurityFinding.from_dict(
+                {**f, "fingerprint": f.get("fingerprint") or compute_fingerprint(f)}
             )
             for f in findings_data
         ]
@@ -559,6 +1133,8 @@ class ReportService:
                 provider = metadata["providers"][provider_idx]
                 provider_distribution[provider] = provider_distribution.get(provider, 0) + 1
 
+        risk_model = RiskModel.from_config()
+        ownership = OwnershipRules.from_config()
         return AuditReport(
             findings=findings,
             project_name=metadata.get("project_id", "Unknown Project"),
@@ -567,14 +1143,100 @@ class ReportService:
             severity_counts=severity_counts,
             providers=metadata.get("providers"),
             provider_distribution=provider_distribution if metadata.get("multi_cloud") else None,
+            risk=risk_model.summarize(findings_data),
+            compliance=self._evaluate_compliance(findings_data, frameworks),
+            executive=build_executive_summary(findings_data, previous, risk_model),
+            suppressions=suppressions,
+            owners=ownership.summarize(findings_data) if ownership.owners else None,
+            failures=list(self.failure_log.failures) or None,
+            branding=Branding.from_config(),
+            history=history,
         )
 
-    def generate_reports(self, formats: Optional[List[str]] = None):
+    def _evaluate_compliance(
+        self, findings_data: List[Dict[str, Any]], frameworks: Optional[List[str]] = None
+    ) -> List[FrameworkCoverage]:
+        """Evaluate the selected compliance frameworks, flagging in-scope findings."""
+        config = PaddiConfig.load()
+        available = load_frameworks(config)
+        unknown = [key for key in frameworks or [] if key not in available]
+        if unknown:
+            raise ValueError(
+                f"Unknown compliance framework: {', '.join(unknown)} "
+                f"(available: {', '.join(available)})"
+            )
+
+        tag_rules = TagRules.from_config(config)
+        scope = config.section("compliance.scope")
+
+        def scope_predicate(scope_tags):
+            def in_scope(finding):
+                return bool(scope_tags & set(tag_rules.tags_for_finding(finding)))
+
+            return in_scope if scope_tags else None
+
+        return [
+            evaluate(available[key], findings_data, scope_predicate(set(scope.get(key, []))))
+            for key in frameworks or list(available)
+        ]
+
+    def _record_run(
+        self, findings_data: List[Dict[str, Any]], project_id: Optional[str]
+    ) -> Optional[Tuple[RunRecord, List[Dict[str, Any]]]]:
+        """Record this run in the history store and return the previous run."""
+        config = PaddiConfig.load()
+        if not config.get("history.enabled", True):
+            return None
+
+        try:
+            store = RunStore(self.input_dir / HISTORY_DB_NAME)
+            current = store.record_run(findings_data, project_id, run_environment(config))
+            previous = store.previous_run(current.run_id)
+            if previous is None:
+                return None
+            return previous, store.findings_for(previous.run_id)
+        except sqlite3.Error as e:
+            logger.warning("Run history unavailable: %s", e)
+            return None
+
+    def _run_history(self, project_id: Optional[str]) -> Optional[List[RunRecord]]:
+        """Return the latest recorded runs of a project, oldest first."""
+        history = self.input_dir / HISTORY_DB_NAME
+        if not history.exists():
+            return None
+        try:
+            runs = [run for run in RunStore(history).runs() if run.project_id == project_id]
+        except sqlite3.Error as e:
+            logger.warning("Run history unavailable: %s", e)
+            return None
+        return list(reversed(runs[:TREND_RUNS]))
+
+    def generate_reports(
+        self,
+        formats: Optional[List[str]] = None,
+        frameworks: Optional[List[str]] = None,
+        tags: Optional[List[str]] = None,
+        query: Optional[str] = None,
+    ) -> List[StageFailure]:
         """Generate reports in specified formats.
 
         Args:
             formats: List of formats to generate. Defaults to ["markdown", "html"].
-                    Supported formats: "markdown", "html", "honkit"
+                    Supported formats: "markdown", "markdown-split" (one file per
+                    finding category), "html", "honkit", "terraform"
+                    (remediation snippets), "github-annotations" (GitHub Actions
+                    workflow commands and job summary), "gitlab-sast" (GitLab
+                    security report), and "<framework>-csv" (e.g. "cis-csv")
+                    for compliance coverage
+            frameworks: Compliance framework keys to include (defaults to all)
+            tags: Only report findings with any of these tags
+            query: Only report findings matching this ``--query`` expression
+
+        A report filtered by tags or a query is not recorded in the run history.
+
+        Returns:
+            The steps that failed: providers whose collection failed, and with
+            continue_on_error, extra inputs and formats that failed
         """
         if formats is None:
             formats = ["markdown", "html"]
@@ -582,68 +1244,189 @@ class ReportService:
         findings_data = self.load_findings()
         if not findings_data:
             logger.warning("No findings to report")
-            return
+            return self.failure_log.failures
+
+        findings_data = SeverityOverrides.from_config().apply(findings_data)
+        suppressions = Suppressions.from_config().apply(findings_data)
+        findings_data = OwnershipRules.from_config().assign(suppressions.findings)
+        deduplicated = deduplicate_findings(findings_data)
+        if len(deduplicated) < len(findings_data):
+            logger.info(
+                "Merged %d findings into %d unique findings",
+                len(findings_data),
+                len(deduplicated),
+            )
+        deduplicated = TagRules.from_config().apply(
+            deduplicated, load_finding_tags(self.input_dir)
+        )
+        if tags:
+            deduplicated = filter_findings(deduplicated, tag=",".join(tags))
+            logger.info("%d finding(s) tagged %s", len(deduplicated), ", ".join(tags))
+        if query:
+            deduplicated = Query(query).filter(deduplicated)
+            logger.info("%d finding(s) match the query", len(deduplicated))
 
         metadata = self.load_metadata()
-        report = self.create_report(findings_data, metadata)
-
-        # Generate Markdown report
-        if "markdown" in formats:
-            md_generator = MarkdownGenerator()
-            md_template = None
-            if self.template_dir:
-                md_template_path = self.template_dir / "report.md.j2"
-                if md_template_path.exists():
-                    md_template = md_template_path
-
-            md_content = md_generator.generate(report, md_template)
-            md_output = self.output_dir / "audit.md"
-            with open(md_output, "w", encoding="utf-8") as f:
-                f.write(md_content)
-            logger.info("Markdown report generated: %s", md_output)
-
-        # Generate HTML report
-        if "html" in formats:
-            html_generator = HTMLGenerator()
-            html_template = None
-            if self.template_dir:
-                html_template_path = self.template_dir / "report.html.j2"
-                if html_template_path.exists():
-                    html_template = html_template_path
-
-            html_content = html_generator.generate(report, html_template)
-            html_output = self.output_dir / "audit.html"
-            with open(html_output, "w", encoding="utf-8") as f:
-                f.write(html_content)
-            logger.info("HTML report generated: %s", html_output)
-
-        # Generate HonKit documentation
-        if "honkit" in formats:
-            honkit_generator = HonKitGenerator(self.output_dir.parent)
-            docs_dir = honkit_generator.generate(report)
-            logger.info("HonKit documentation generated: %s", docs_dir)
+        previous = history = None
+        if not tags and not query:
+            previous = self._record_run(deduplicated, metadata.get("project_id"))
+            history = self._run_history(metadata.get("project_id"))
+        report = self.create_report(
+            deduplicated, metadata, frameworks, previous, suppressions, history
+        )
+
+        # Each format is written on its own, so with continue_on_error one failing
+        # format does not stop the others
+        writers = [
+            ("markdown", self._write_markdown, report),
+            ("markdown-split", self._write_markdown_split, report),
+            ("html", self._write_html, report),
+            *(
+                (f"{coverage.framework.key}-csv", self._write_coverage_csv, coverage)
+                for coverage in report.compliance or []
+            ),
+            ("terraform", write_snippets, deduplicated, self.output_dir),
+            ("github-annotations", self._write_annotations, deduplicated, report),
+            ("gitlab-sast", write_gitlab_report, deduplicated, self.output_dir),
+            ("honkit", self._write_honkit, report),
+        ]
+        for name, writer, *args in writers:
+            if name in formats:
+                self.failure_log.run("report", name, writer, *args)
+        return self.failure_log.failures
+
+    def template(self, report_format: str) -> Optional[Path]:
+        """Return the template of a format: the custom one, else the built-in one, if any."""
+        name = TEMPLATE_NAMES.get(report_format)
+        if name is None:
+            return None
+        for directory in (self.custom_template_dir, self.template_dir):
+            if directory and (directory / name).is_file():
+                return directory / name
+        return None
+
+    def _write_markdown(self, report: AuditReport) -> None:
+        """Generate the Markdown report."""
+        md_generator = MarkdownGenerator()
+        md_template = self.template("markdown")
+        if md_template:
+            logger.info("Markdown template: %s", md_template)
+
+        md_content = md_generator.generate(report, md_template)
+        md_output = self.output_dir / "audit.md"
+        write_artifact_text(md_output, md_content)
+        logger.info("Markdown report generated: %s", md_output)
+
+    def _write_markdown_split(self, report: AuditReport) -> None:
+        """Generate the Markdown report split into one file per finding category."""
+        split_dir = self.output_dir / SPLIT_MARKDOWN_DIR
+        split_dir.mkdir(parents=True, exist_ok=True)
+        for name, content in MarkdownGenerator().split_by_category(report).items():
+            write_artifact_text(split_dir / name, content)
+        logger.info("Markdown report split by category: %s", split_dir)
+
+    def _write_html(self, report: AuditReport) -> None:
+        """Generate the HTML report."""
+        html_settings = PaddiConfig.load().section("reporter").get("html_settings") or {}
+        html_generator = HTMLGenerator(charts=html_settings.get("include_charts", True))
+        html_template = self.template("html")
+        if html_template:
+            logger.info("HTML template: %s", html_template)
+
+        html_content = html_generator.generate(report, html_template)
+        html_output = self.output_dir / "audit.html"
+        write_artifact_text(html_output, html_content)
+        logger.info("HTML report generated: %s", html_output)
+
+    def _write_coverage_csv(self, coverage: FrameworkCoverage) -> None:
+        """Export compliance coverage as CSV."""
+        csv_output = self.output_dir / f"{coverage.framework.key}_coverage.csv"
+        with open(csv_output, "w", encoding="utf-8", newline="") as f:
+            f.write(coverage_to_csv(coverage))
+        logger.info("Compliance coverage exported: %s", csv_output)
+
+    def _write_annotations(self, findings: List[Dict[str, Any]], report: AuditReport) -> None:
+        """Annotate the GitHub Actions run."""
+        summary = write_annotations(findings, report.project_name, report.risk)
+        if summary:
+            logger.info("GitHub job summary written: %s", summary)
+
+    def _write_honkit(self, report: AuditReport) -> None:
+        """Generate HonKit documentation."""
+        honkit_generator = HonKitGenerator(self.output_dir.parent)
+        docs_dir = honkit_generator.generate(report)
+        logger.info("HonKit documentation generated: %s", docs_dir)
+
+def configured_template_dir() -> Optional[Path]:
+    """Return the ``[reporter] template_dir`` of custom templates, if one is set.
+
+    Raises:
+        ConfigurationError: If it is set but is not a directory
+    """
+    template_dir = PaddiConfig.load().section("reporter").get("template_dir")
+    if not template_dir:
+        return None
+    if not Path(template_dir).expanduser().is_dir():
+        raise ConfigurationError(
+            "reporter.template_dir", {"error": f"{template_dir} is not a directory"}
+        )
+    return Path(template_dir).expanduser()
 
 
 def main(
     input_dir: str = "data",
     output_dir: str = "output",
     template_dir: Optional[str] = "app/templates",
-    formats: Optional[List[str]] = None,
-):
+    custom_template_dir: Optional[str] = None,
+    formats: Optional[Union[str, List[str]]] = None,
+    inputs: Optional[Union[str, List[str]]] = None,
+    framework: Optional[Union[str, List[str]]] = None,
+    tag: Optional[Union[str, List[str]]] = None,
+    query: Optional[str] = None,
+    continue_on_error: bool = False,
+) -> List[StageFailure]:
     """Generate security audit reports from explained findings.
 
     Args:
         input_dir: Directory containing explained.json
         output_dir: Directory to save generated reports
-        template_dir: Optional directory containing custom templates
-        formats: List of formats to generate (markdown, html, honkit)
+        template_dir: Optional directory containing the built-in templates
+        custom_template_dir: Directory of templates (report.md.j2, report.html.j2)
+            that replace the built-in ones; defaults to [reporter] template_dir
+        formats: Formats to generate, as a list or comma-separated
+            (markdown, markdown-split, html, honkit, terraform, github-annotations,
+            gitlab-sast, cis-csv)
+        inputs: Additional explained.json files (list or comma-separated) from
+            other projects or runs to aggregate into the same report
+        framework: Compliance frameworks to include (list or comma-separated,
+            e.g. "pci-dss"); defaults to all
+        tag: Only report findings with any of these tags (list or comma-separated)
+        query: Only report findings matching this filter expression
+            (e.g. 'severity>=HIGH and resource contains "prod"')
+        continue_on_error: Skip extra inputs and formats that fail, returning
+            them instead of stopping
     """
+    if isinstance(formats, str):
+        formats = [fmt.strip() for fmt in formats.split(",") if fmt.strip()]
+    if isinstance(framework, str):
+        framework = [key.strip() for key in framework.split(",") if key.strip()]
+    if isinstance(inputs, str):
+        inputs = [path.strip() for path in inputs.split(",") if path.strip()]
+    if isinstance(tag, str):
+        tag = [name.strip() for name in tag.split(",") if name.strip()]
+
+    if custom_template_dir is None:
+        custom_template_dir = configured_template_dir()
+
     service = ReportService(
         input_dir=Path(input_dir),
         output_dir=Path(output_dir),
         template_dir=Path(template_dir) if template_dir else None,
+        custom_template_dir=Path(custom_template_dir).expanduser() if custom_template_dir else None,
+        extra_inputs=[Path(path) for path in inputs or []],
+        continue_on_error=continue_on_error,
     )
-    service.generate_reports(formats)
+    return service.generate_reports(formats, framework, tag, query)
 
 
 if __name__ == "__main__":
@@ -0,0 +1,170 @@
+"""Company branding of the HTML report: name, logo, colors, and footer.
+
+Set in ``[reporter.branding]`` of paddi.toml::
+
+    [reporter.branding]
+    company_name = "Example Corp"
+    logo = "assets/logo.png"
+    primary_color = "#0b3d91"
+    footer = "CONFIDENTIAL - for Example Corp and its auditors only"
+
+The logo is embedded in the report as a data URI, so the HTML file stays
+self-contained when it is mailed or printed to PDF. When printing, the footer
+is repeated at the bottom of every page.
+"""
+
+import base64
+import re
+from dataclasses import dataclass, field
+from html import escape
+from pathlib import Path
+from typing import Dict, Optional
+
+from app.common.exceptions import ConfigurationError
+from app.config.paddi_config import PaddiConfig
+
+DEFAULT_PRIMARY_COLOR = "#1a73e8"
+DEFAULT_BACKGROUND_COLOR = "#f5f5f5"
+SEVERITY_COLORS = {
+    "CRITICAL": "#D32F2F",
+    "HIGH": "#F44336",
+    "MEDIUM": "#FF9800",
+    "LOW": "#FFC107",
+    "INFO": "#2196F3",
+}
+LOGO_TYPES = {
+    ".png": "image/png",
+    ".jpg": "image/jpeg",
+    ".jpeg": "image/jpeg",
+    ".gif": "image/gif",
+    ".svg": "image/svg+xml",
+    ".webp": "image/webp",
+}
+
+_COLOR = re.compile(r"^#(?:[0-9a-fA-F]{3}|[0-9a-fA-F]{6})$")
+
+
+def _color(value: str, location: str) -> str:
+    """Check that a color is a CSS hex color, as it is written into the report's style sheet."""
+    if not isinstance(value, str) or not _COLOR.match(value):
+        raise ConfigurationError(
+            location, {"error": f"expected a color like #1a73e8, got {value!r}"}
+        )
+    return value
+
+
+@dataclass
+class Branding:
+    """How the HTML report presents the company that shares it."""
+
+    company_name: Optional[str] = None
+    # Data URI of the logo image
+    logo: Optional[str] = None
+    primary_color: str = DEFAULT_PRIMARY_COLOR
+    background_color: str = DEFAULT_BACKGROUND_COLOR
+    severity_colors: Dict[str, str] = field(default_factory=lambda: dict(SEVERITY_COLORS))
+    footer: Optional[str] = None
+
+    @classmethod
+    def from_config(cls, config: Optional[PaddiConfig] = None) -> "Branding":
+        """Create the branding from ``[reporter.branding]``.
+
+        Raises:
+            ConfigurationError: If a color is not a hex color, or the logo cannot be read
+        """
+        section = (config or PaddiConfig.load()).section("reporter.branding")
+        severity_colors = dict(SEVERITY_COLORS)
+        for severity, color in (section.get("severity_colors") or {}).items():
+            location = f"reporter.branding.severity_colors.{severity}"
+            severity_colors[severity.upper()] = _color(color, location)
+        return cls(
+            company_name=section.get("company_name"),
+            logo=load_logo(section["logo"]) if section.get("logo") else None,
+            primary_color=_color(
+                section.get("primary_color", DEFAULT_PRIMARY_COLOR),
+                "reporter.branding.primary_color",
+            ),
+            background_color=_color(
+                section.get("background_color", DEFAULT_BACKGROUND_COLOR),
+                "reporter.branding.background_color",
+            ),
+            severity_colors=severity_colors,
+            footer=section.get("footer"),
+        )
+
+    def severity_color(self, severity: str) -> str:
+        """Return the color of a severity, gray for unknown ones."""
+        return self.severity_colors.get(severity.upper(), "#9E9E9E")
+
+    def header_html(self) -> str:
+        """Return the logo and company name shown above the report title."""
+        if not self.logo and not self.company_name:
+            return ""
+        parts = ['        <div class="branding">\n']
+        if self.logo:
+            alt = escape(self.company_name or "Logo", quote=True)
+            parts.append(f'            <img class="logo" src="{self.logo}" alt="{alt}">\n')
+        if self.company_name:
+            parts.append(f'            <span class="company">{escape(self.company_name)}</span>\n')
+        parts.append("        </div>\n")
+        return "".join(parts)
+
+    def footer_html(self) -> str:
+        """Return the confidentiality footer, if one is configured."""
+        if not self.footer:
+            return ""
+        return f'    <footer class="confidential">{escape(self.footer)}</footer>\n'
+
+    def page_html(self, title: str, body: str) -> str:
+        """Return a self-contained HTML page with the branding around body.
+
+        Used by the reports that summarize several runs or projects, which have
+        no template of their own.
+        """
+        return (
+            "<!DOCTYPE html>\n"
+            '<html lang="en">\n'
+            "<head>\n"
+            '    <meta charset="UTF-8">\n'
+            f"    <title>{escape(title)}</title>\n"
+            "    <style>\n"
+            f"        body {{ font-family: sans-serif; background: {self.background_color}; "
+            "margin: 0; padding: 20px; }\n"
+            "        .container { max-width: 1200px; margin: 0 auto; background: white; "
+            "padding: 30px; }\n"
+            f"        h1, h2 {{ color: {self.primary_color}; }}\n"
+            "        table { border-collapse: collapse; width: 100%; }\n"
+            "        th, td { border: 1px solid #ddd; padding: 8px; text-align: left; }\n"
+            "        .severity { font-weight: bold; }\n"
+            "    </style>\n"
+            "</head>\n"
+            "<body>\n"
+            '    <div class="container">\n'
+            f"{self.header_html()}"
+            f"        <h1>{escape(title)}</h1>\n"
+            f"{body}"
+            "    </div>\n"
+            f"{self.footer_html()}"
+            "</body>\n"
+            "</html>\n"
+        )
+
+
+def load_logo(path: str) -> str:
+    """Read a logo image and return it as a data URI.
+
+    Raises:
+        ConfigurationError: If the file is missing or not a supported image type
+    """
+    logo = Path(path).expanduser()
+    mime_type = LOGO_TYPES.get(logo.suffix.lower())
+    if mime_type is None:
+        raise ConfigurationError(
+            "reporter.branding.logo",
+            {"error": f"{path} is not one of {', '.join(sorted(LOGO_TYPES))}"},
+        )
+    try:
+        data = logo.read_bytes()
+    except OSError as e:
+        raise ConfigurationError("reporter.branding.logo", {"error": str(e)}) from e
+    return f"data:{mime_type};base64,{base64.b64encode(data).decode('ascii')}"
@@ -0,0 +1,107 @@
+"""Inline SVG charts for the HTML report.
+
+The charts are plain SVG markup embedded in the report, so it needs no
+JavaScript or CDN and renders the same offline, in mail clients that allow
+SVG, and when printed to PDF.
+"""
+
+from html import escape
+from typing import Dict, List, Sequence
+
+from app.common.models import SEVERITY_ORDER
+from app.history.store import RunRecord
+
+WIDTH = 640
+BAR_HEIGHT = 22
+BAR_GAP = 8
+LABEL_WIDTH = 90
+TREND_HEIGHT = 220
+TREND_MARGIN = 40
+AXIS_COLOR = "#9E9E9E"
+LINE_COLOR = "#1a73e8"
+# Runs shown in the trend chart, most recent last
+TREND_RUNS = 12
+
+
+def severity_chart(severity_counts: Dict[str, int], colors: Dict[str, str]) -> str:
+    """Return a horizontal bar chart of the findings per severity.
+
+    Args:
+        severity_counts: Number of findings of each severity
+        colors: Bar color of each severity
+    """
+    severities = [s for s in SEVERITY_ORDER if s in severity_counts]
+    severities += sorted(s for s in severity_counts if s not in SEVERITY_ORDER)
+    if not severities:
+        return ""
+    largest = max(severity_counts.values()) or 1
+    bar_space = WIDTH - LABEL_WIDTH - 50
+    height = len(severities) * (BAR_HEIGHT + BAR_GAP) + BAR_GAP
+    parts = [_open_svg(WIDTH, height, "Findings by severity")]
+    for index, severity in enumerate(severities):
+        count = severity_counts[severity]
+        y = BAR_GAP + index * (BAR_HEIGHT + BAR_GAP)
+        width = round(bar_space * count / largest)
+        text_y = y + BAR_HEIGHT - 6
+        parts.append(
+            f'  <text x="{LABEL_WIDTH - 8}" y="{text_y}" text-anchor="end">'
+            f"{escape(severity)}</text>\n"
+            f'  <rect x="{LABEL_WIDTH}" y="{y}" width="{width}" height="{BAR_HEIGHT}" '
+            f'rx="3" fill="{colors.get(severity, AXIS_COLOR)}"/>\n'
+            f'  <text x="{LABEL_WIDTH + width + 6}" y="{text_y}">{count}</text>\n'
+        )
+    parts.append("</svg>")
+    return "".join(parts)
+
+
+def trend_chart(runs: Sequence[RunRecord], color: str = LINE_COLOR) -> str:
+    """Return a line chart of the total findings of earlier runs, oldest first.
+
+    Returns an empty string for fewer than two runs, which make no trend.
+    """
+    runs = list(runs)[-TREND_RUNS:]
+    if len(runs) < 2:
+        return ""
+    largest = max(run.total for run in runs) or 1
+    plot_width = WIDTH - 2 * TREND_MARGIN
+    plot_height = TREND_HEIGHT - 2 * TREND_MARGIN
+    bottom = TREND_MARGIN + plot_height
+    step = plot_width / (len(runs) - 1)
+
+    points: List[str] = []
+    parts = [
+        _open_svg(WIDTH, TREND_HEIGHT, "Findings over the last runs"),
+        f'  <line x1="{TREND_MARGIN}" y1="{bottom}" x2="{WIDTH - TREND_MARGIN}" '
+        f'y2="{bottom}" stroke="{AXIS_COLOR}"/>\n',
+    ]
+    for index, run in enumerate(runs):
+        x = round(TREND_MARGIN + index * step, 1)
+        y = round(bottom - plot_height * run.total / largest, 1)
+        points.append(f"{x},{y}")
+        parts.append(
+            f'  <circle cx="{x}" cy="{y}" r="4" fill="{color}">'
+            f"<title>{escape(run.created_at)}: {run.total}</title></circle>\n"
+            f'  <text x="{x}" y="{y - 10}" text-anchor="middle">{run.total}</text>\n'
+        )
+    parts.insert(
+        2,
+        f'  <polyline points="{" ".join(points)}" fill="none" stroke="{color}" '
+        f'stroke-width="2"/>\n',
+    )
+    # Dates of the first and last run under the ends of the axis
+    labels = ((runs[0], "start", TREND_MARGIN), (runs[-1], "end", WIDTH - TREND_MARGIN))
+    for run, anchor, x in labels:
+        parts.append(
+            f'  <text x="{x}" y="{bottom + 20}" text-anchor="{anchor}">'
+            f"{escape(run.created_at[:10])}</text>\n"
+        )
+    parts.append("</svg>")
+    return "".join(parts)
+
+
+def _open_svg(width: int, height: int, title: str) -> str:
+    return (
+        f'<svg xmlns="http://www.w3.org/2000/svg" class="chart" role="img" '
+        f'viewBox="0 0 {width} {height}" width="100%" font-size="13" '
+        f'font-family="sans-serif" fill="#333">\n  <title>{escape(title)}</title>\n'
+    )
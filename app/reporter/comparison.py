@@ -0,0 +1,210 @@
+"""Report of what changed between two recorded runs (``paddi report --compare A B``).
+
+Findings are matched by fingerprint, which leaves out the severity, so a
+finding whose severity went up between the runs is reported as regressed
+rather than as one resolved and one new finding. The report is meant for
+change-review meetings, so it shows only what changed.
+"""
+
+from dataclasses import dataclass, field
+from html import escape
+from pathlib import Path
+from typing import Any, Dict, List, Optional, Sequence, Tuple
+
+from app.common.artifact_crypto import write_artifact_text
+from app.common.models import severity_rank
+from app.findings.delta import findings_delta
+from app.findings.fingerprint import compute_fingerprint
+from app.findings.listing import sort_findings
+from app.history.store import RunRecord, RunStore
+from app.reporter.branding import Branding
+from app.reporter.markdown_nav import severity_badge
+
+COMPARISON_SUFFIXES = {"markdown": ".md", "html": ".html"}
+
+
+@dataclass
+class RunComparison:
+    """Findings that changed from one run to a later one."""
+
+    before: RunRecord
+    after: RunRecord
+    new: List[Dict[str, Any]] = field(default_factory=list)
+    resolved: List[Dict[str, Any]] = field(default_factory=list)
+    # Pairs of the finding in the earlier run and the same finding, more severe, in the later one
+    regressed: List[Tuple[Dict[str, Any], Dict[str, Any]]] = field(default_factory=list)
+    unchanged: int = 0
+
+    @property
+    def name(self) -> str:
+        """File name of the report, without its suffix."""
+        return f"comparison-{self.before.run_id}-{self.after.run_id}"
+
+    def to_dict(self) -> Dict[str, Any]:
+        """Convert to dictionary for JSON serialization."""
+        return {
+            "before": {"run_id": self.before.run_id, "created_at": self.before.created_at},
+            "after": {"run_id": self.after.run_id, "created_at": self.after.created_at},
+            "new": self.new,
+            "resolved": self.resolved,
+            "regressed": [{"before": b, "after": a} for b, a in self.regressed],
+            "unchanged": self.unchanged,
+        }
+
+
+def _fingerprint(finding: Dict[str, Any]) -> str:
+    return finding.get("fingerprint") or compute_fingerprint(finding)
+
+
+def compare_runs(store: RunStore, before_id: int, after_id: int) -> RunComparison:
+    """Compare the findings of two recorded runs.
+
+    Raises:
+        ValueError: If either run is not recorded
+    """
+    runs = []
+    for run_id in (before_id, after_id):
+        run = store.run(run_id)
+        if run is None:
+            raise ValueError(f"Run {run_id} not found in {store.path}")
+        runs.append(run)
+    before_findings = store.findings_for(before_id)
+    after_findings = store.findings_for(after_id)
+
+    new, resolved = findings_delta(after_findings, before_findings)
+    earlier = {_fingerprint(finding): finding for finding in before_findings}
+    regressed = []
+    for finding in after_findings:
+        previous = earlier.get(_fingerprint(finding))
+        if previous is not None and severity_rank(finding.get("severity", "")) < severity_rank(
+            previous.get("severity", "")
+        ):
+            regressed.append((previous, finding))
+
+    return RunComparison(
+        before=runs[0],
+        after=runs[1],
+        new=sort_findings(new),
+        resolved=sort_findings(resolved),
+        regressed=regressed,
+        unchanged=len(after_findings) - len(new) - len(regressed),
+    )
+
+
+def _run_label(run: RunRecord) -> str:
+    return f"run #{run.run_id} ({run.created_at[:10]}, {run.total} finding(s))"
+
+
+def _finding_line(finding: Dict[str, Any]) -> str:
+    line = f"{severity_badge(finding.get('severity', 'INFO'))} **{finding.get('title', '')}**"
+    if finding.get("resource"):
+        line += f" `{finding['resource']}`"
+    return line
+
+
+def render_markdown(comparison: RunComparison) -> str:
+    """Render the comparison as Markdown."""
+    lines = [
+        "# Audit Comparison Report",
+        "",
+        f"**Before:** {_run_label(comparison.before)}",
+        f"**After:** {_run_label(comparison.after)}",
+        "",
+        f"{len(comparison.new)} new, {len(comparison.resolved)} resolved, "
+        f"{len(comparison.regressed)} regressed, {comparison.unchanged} unchanged",
+        "",
+    ]
+    sections = (
+        ("New Findings", [_finding_line(f) for f in comparison.new]),
+        (
+            "Regressed Findings",
+            [
+                f"{_finding_line(after)} (was {str(before.get('severity', '')).upper()})"
+                for before, after in comparison.regressed
+            ],
+        ),
+        ("Resolved Findings", [_finding_line(f) for f in comparison.resolved]),
+    )
+    for title, entries in sections:
+        lines.extend([f"## {title}", ""])
+        lines.extend(f"- {entry}" for entry in entries)
+        if not entries:
+            lines.append("None.")
+        lines.append("")
+    return "\n".join(lines).rstrip() + "\n"
+
+
+def _html_list(entries: List[str]) -> str:
+    if not entries:
+        return "        <p>None.</p>\n"
+    items = "".join(f"            <li>{entry}</li>\n" for entry in entries)
+    return f"        <ul>\n{items}        </ul>\n"
+
+
+def render_html(comparison: RunComparison, branding: Optional[Branding] = None) -> str:
+    """Render the comparison as a self-contained HTML page."""
+    branding = branding or Branding()
+
+    def entry(finding: Dict[str, Any], suffix: str = "") -> str:
+        severity = str(finding.get("severity", "INFO")).upper()
+        resource = finding.get("resource")
+        return (
+            f'<span class="severity" style="color: {branding.severity_color(severity)};">'
+            f"{escape(severity)}</span> <strong>{escape(str(finding.get('title', '')))}</strong>"
+            + (f" <code>{escape(str(resource))}</code>" if resource else "")
+            + suffix
+        )
+
+    regressed = [
+        entry(after, f" (was {escape(str(before.get('severity', '')).upper())})")
+        for before, after in comparison.regressed
+    ]
+    body = (
+        f"        <p><strong>Before:</strong> {escape(_run_label(comparison.before))}</p>\n"
+        f"        <p><strong>After:</strong> {escape(_run_label(comparison.after))}</p>\n"
+        f"        <p>{len(comparison.new)} new, {len(comparison.resolved)} resolved, "
+        f"{len(comparison.regressed)} regressed, {comparison.unchanged} unchanged</p>\n"
+        "        <h2>New Findings</h2>\n"
+        f"{_html_list([entry(f) for f in comparison.new])}"
+        "        <h2>Regressed Findings</h2>\n"
+        f"{_html_list(regressed)}"
+        "        <h2>Resolved Findings</h2>\n"
+        f"{_html_list([entry(f) for f in comparison.resolved])}"
+    )
+    return branding.page_html("Audit Comparison Report", body)
+
+
+def write_comparison_report(
+    comparison: RunComparison,
+    output_dir: Path,
+    formats: Optional[Sequence[str]] = None,
+    branding: Optional[Branding] = None,
+) -> List[Path]:
+    """Write the comparison to ``comparison-<before>-<after>.md`` and ``.html``.
+
+    Args:
+        comparison: The runs' differences
+        output_dir: Directory for the report
+        formats: Formats to write, of markdown and html (defaults to both)
+        branding: Branding of the HTML report (defaults to ``[reporter.branding]``)
+
+    Returns:
+        The files written
+
+    Raises:
+        ValueError: If a format is not markdown or html
+    """
+    formats = list(formats) if formats else ["markdown", "html"]
+    unknown = [fmt for fmt in formats if fmt not in COMPARISON_SUFFIXES]
+    if unknown:
+        raise ValueError(f"Comparison reports are Markdown or HTML, not {', '.join(unknown)}")
+    Path(output_dir).mkdir(parents=True, exist_ok=True)
+    written = []
+    for fmt in formats:
+        path = Path(output_dir) / f"{comparison.name}{COMPARISON_SUFFIXES[fmt]}"
+        if fmt == "html":
+            content = render_html(comparison, branding or Branding.from_config())
+        else:
+            content = render_markdown(comparison)
+        written.append(write_artifact_text(path, content))
+    return written
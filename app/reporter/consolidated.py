@@ -0,0 +1,229 @@
+"""Consolidated report of an audit over several projects (``paddi audit --projects``).
+
+Each project keeps its own reports under ``<output_dir>/<project>``. The
+consolidated report in ``<output_dir>`` adds a breakdown per project, with its
+finding counts, risk grade, and a link to its report, and the top risks across
+the whole organization, each labeled with its project.
+"""
+
+from dataclasses import dataclass, field
+from datetime import datetime
+from html import escape
+from pathlib import Path
+from typing import Any, Dict, List, Optional, Sequence
+
+from app.common.artifact_crypto import write_artifact_text
+from app.common.models import SEVERITY_ORDER, severity_rank
+from app.findings.risk import RiskModel, RiskSummary
+from app.reporter.branding import Branding
+from app.reporter.markdown_nav import severity_badge, severity_summary
+
+# Findings listed in the organization-wide top risks
+TOP_RISKS = 10
+CONSOLIDATED_NAMES = {"markdown": "consolidated.md", "html": "consolidated.html"}
+
+
+@dataclass
+class ProjectSummary:
+    """Findings and risk of one audited project."""
+
+    project_id: str
+    total: int
+    severity_counts: Dict[str, int]
+    risk: RiskSummary
+
+
+@dataclass
+class ConsolidatedReport:
+    """Findings of several projects, summarized per project and across all of them."""
+
+    audit_date: str
+    projects: List[ProjectSummary]
+    severity_counts: Dict[str, int]
+    risk: RiskSummary
+    # Findings with a "project" key naming the project they were found in
+    top_risks: List[Dict[str, Any]] = field(default_factory=list)
+
+    @property
+    def total(self) -> int:
+        """Number of findings across all projects."""
+        return sum(project.total for project in self.projects)
+
+
+def _severity_counts(findings: List[Dict[str, Any]]) -> Dict[str, int]:
+    counts: Dict[str, int] = {}
+    for finding in findings:
+        severity = str(finding.get("severity", "INFO")).upper()
+        counts[severity] = counts.get(severity, 0) + 1
+    order = {severity: i for i, severity in enumerate(SEVERITY_ORDER)}
+    return dict(sorted(counts.items(), key=lambda item: (order.get(item[0], len(order)), item[0])))
+
+
+def build_consolidated_report(
+    findings_by_project: Dict[str, List[Dict[str, Any]]],
+    risk_model: Optional[RiskModel] = None,
+) -> ConsolidatedReport:
+    """Summarize the reported findings of each project.
+
+    Args:
+        findings_by_project: Findings of each project, as its report shows them
+        risk_model: Model used to grade projects and rank risks (defaults to ``[risk]``)
+    """
+    risk_model = risk_model or RiskModel.from_config()
+    projects = []
+    all_findings = []
+    for project_id, findings in findings_by_project.items():
+        projects.append(
+            ProjectSummary(
+                project_id=project_id,
+                total=len(findings),
+                severity_counts=_severity_counts(findings),
+                risk=risk_model.summarize(findings),
+            )
+        )
+        all_findings.extend({**finding, "project": project_id} for finding in findings)
+
+    top_risks = sorted(
+        all_findings,
+        key=lambda f: (severity_rank(f.get("severity", "")), -risk_model.finding_score(f)),
+    )[:TOP_RISKS]
+    return ConsolidatedReport(
+        audit_date=datetime.now().strftime("%Y-%m-%d %H:%M:%S"),
+        projects=sorted(projects, key=lambda p: (-p.risk.score, p.project_id)),
+        severity_counts=_severity_counts(all_findings),
+        risk=risk_model.summarize(all_findings),
+        top_risks=top_risks,
+    )
+
+
+def _report_link(project_id: str, report_format: str) -> str:
+    """Return the path of a project's own report, relative to the consolidated report."""
+    name = "audit.html" if report_format == "html" else "audit.md"
+    return f"{project_id}/{name}"
+
+
+def _md_cell(text: Any) -> str:
+    return str(text).replace("|", "\\|").replace("\n", " ")
+
+
+def render_markdown(report: ConsolidatedReport) -> str:
+    """Render the consolidated report as Markdown."""
+    lines = [
+        "# Consolidated Security Audit Report",
+        "",
+        f"**Audit Date:** {report.audit_date}",
+        f"**Projects:** {len(report.projects)}",
+        f"**Total Findings:** {report.total}",
+        f"**Overall Grade:** {report.risk.grade} (score {report.risk.score})",
+        "",
+    ]
+    if report.severity_counts:
+        lines.extend([severity_summary(report.severity_counts), ""])
+
+    lines.extend(
+        [
+            "## Projects",
+            "",
+            "| Project | Grade | Score | Findings | Severities |",
+            "|---------|-------|-------|----------|------------|",
+        ]
+    )
+    for project in report.projects:
+        link = f"[{_md_cell(project.project_id)}]({_report_link(project.project_id, 'markdown')})"
+        lines.append(
+            f"| {link} | {project.risk.grade} | {project.risk.score} | {project.total} "
+            f"| {severity_summary(project.severity_counts) or '-'} |"
+        )
+    lines.append("")
+
+    lines.extend(["## Top Risks Across the Organization", ""])
+    if not report.top_risks:
+        lines.extend(["No findings in any project.", ""])
+    for i, finding in enumerate(report.top_risks, 1):
+        lines.append(
+            f"{i}. {severity_badge(finding.get('severity', 'INFO'))} "
+            f"**{finding.get('title', 'Unknown Issue')}** ({finding['project']})"
+        )
+        if finding.get("resource"):
+            lines.append(f"   - Resource: `{finding['resource']}`")
+        if finding.get("recommendation"):
+            lines.append(f"   - Recommendation: {finding['recommendation']}")
+    return "\n".join(lines).rstrip() + "\n"
+
+
+def render_html(report: ConsolidatedReport, branding: Optional[Branding] = None) -> str:
+    """Render the consolidated report as a self-contained HTML page."""
+    branding = branding or Branding()
+    rows = []
+    for project in report.projects:
+        counts = ", ".join(f"{s} {n}" for s, n in project.severity_counts.items()) or "-"
+        rows.append(
+            "            <tr>"
+            f'<td><a href="{escape(_report_link(project.project_id, "html"), quote=True)}">'
+            f"{escape(project.project_id)}</a></td>"
+            f"<td>{project.risk.grade}</td><td>{project.risk.score}</td>"
+            f"<td>{project.total}</td><td>{escape(counts)}</td></tr>\n"
+        )
+    risks = []
+    for finding in report.top_risks:
+        severity = str(finding.get("severity", "INFO")).upper()
+        resource = finding.get("resource")
+        risks.append(
+            f'            <li><span class="severity" style="color: '
+            f'{branding.severity_color(severity)};">{escape(severity)}</span> '
+            f"<strong>{escape(str(finding.get('title', 'Unknown Issue')))}</strong> "
+            f'<span class="project">({escape(finding["project"])})</span>'
+            + (f" <code>{escape(str(resource))}</code>" if resource else "")
+            + "</li>\n"
+        )
+    top_risks = (
+        "        <ol>\n" + "".join(risks) + "        </ol>\n"
+        if risks
+        else "        <p>No findings in any project.</p>\n"
+    )
+    body = (
+        f"        <p><strong>Audit Date:</strong> {escape(report.audit_date)}</p>\n"
+        f"        <p><strong>Projects:</strong> {len(report.projects)} &middot; "
+        f"<strong>Total Findings:</strong> {report.total} &middot; "
+        f"<strong>Overall Risk Grade:</strong> {report.risk.grade} "
+        f"(score {report.risk.score})</p>\n"
+        "        <h2>Projects</h2>\n"
+        "        <table>\n"
+        "            <tr><th>Project</th><th>Grade</th><th>Score</th><th>Findings</th>"
+        "<th>Severities</th></tr>\n"
+        f"{''.join(rows)}"
+        "        </table>\n"
+        "        <h2>Top Risks Across the Organization</h2>\n"
+        f"{top_risks}"
+    )
+    return branding.page_html("Consolidated Security Audit Report", body)
+
+
+def write_consolidated_report(
+    report: ConsolidatedReport,
+    output_dir: Path,
+    formats: Optional[Sequence[str]] = None,
+    branding: Optional[Branding] = None,
+) -> List[Path]:
+    """Write the consolidated report in each of the formats that has one.
+
+    Args:
+        report: The consolidated report
+        output_dir: Directory holding the per-project report directories
+        formats: Report formats of the audit (defaults to Markdown and HTML)
+        branding: Branding of the HTML report (defaults to ``[reporter.branding]``)
+
+    Returns:
+        The files written
+    """
+    formats = list(formats) if formats else ["markdown", "html"]
+    Path(output_dir).mkdir(parents=True, exist_ok=True)
+    written = []
+    if "markdown" in formats:
+        path = Path(output_dir) / CONSOLIDATED_NAMES["markdown"]
+        written.append(write_artifact_text(path, render_markdown(report)))
+    if "html" in formats:
+        path = Path(output_dir) / CONSOLIDATED_NAMES["html"]
+        html = render_html(report, branding or Branding.from_config())
+        written.append(write_artifact_text(path, html))
+    return written
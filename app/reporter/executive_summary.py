@@ -0,0 +1,119 @@
+"""Executive summary for leadership: counts, top risks, trend, and priorities."""
+
+from dataclasses import dataclass, field
+from typing import Any, Dict, List, Optional, Tuple
+
+from app.common.models import SEVERITY_ORDER, severity_rank
+from app.findings.delta import findings_delta
+from app.findings.risk import RiskModel
+from app.history.store import RunRecord
+
+TOP_RISKS = 5
+MAX_PRIORITIES = 3
+
+
+@dataclass
+class Trend:
+    """Change in findings since the previous run."""
+
+    previous_date: str
+    previous_total: int
+    current_total: int
+    new: int
+    resolved: int
+
+    @property
+    def delta(self) -> int:
+        """Change in the total number of findings."""
+        return self.current_total - self.previous_total
+
+
+@dataclass
+class ExecutiveSummary:
+    """Leadership-oriented summary of an audit."""
+
+    total: int
+    severity_counts: Dict[str, int]
+    top_risks: List[Dict[str, Any]] = field(default_factory=list)
+    priorities: List[str] = field(default_factory=list)
+    trend: Optional[Trend] = None
+
+
+def compute_trend(
+    findings: List[Dict[str, Any]],
+    previous_run: RunRecord,
+    previous_findings: List[Dict[str, Any]],
+) -> Trend:
+    """Compare current findings with those of the previous run by fingerprint."""
+    new, resolved = findings_delta(findings, previous_findings)
+    return Trend(
+        previous_date=previous_run.created_at,
+        previous_total=previous_run.total,
+        current_total=len(findings),
+        new=len(new),
+        resolved=len(resolved),
+    )
+
+
+def _priorities(
+    findings: List[Dict[str, Any]], risk_model: RiskModel, trend: Optional[Trend]
+) -> List[str]:
+    """Recommend the finding groups that remove the most risk first."""
+    groups: Dict[str, Dict[str, Any]] = {}
+    for finding in findings:
+        key = finding.get("category") or finding.get("title", "Unknown Issue")
+        group = groups.setdefault(key, {"score": 0.0, "count": 0, "worst": None})
+        group["score"] += risk_model.finding_score(finding)
+        group["count"] += 1
+        if group["worst"] is None or severity_rank(finding.get("severity", "")) < severity_rank(
+            group["worst"].get("severity", "")
+        ):
+            group["worst"] = finding
+
+    ranked = sorted(groups.items(), key=lambda item: -item[1]["score"])
+    priorities = []
+    for key, group in ranked[:MAX_PRIORITIES]:
+        worst = group["worst"]
+        text = f"Fix {group['count']} {key} finding(s), up to {worst.get('severity', 'INFO')}"
+        if worst.get("resource"):
+            text += f", starting with {worst['resource']}"
+        priorities.append(text)
+
+    if trend and trend.new:
+        priorities.append(f"Review {trend.new} new finding(s) introduced since the previous run")
+    return priorities
+
+
+def build_executive_summary(
+    findings: List[Dict[str, Any]],
+    previous: Optional[Tuple[RunRecord, List[Dict[str, Any]]]] = None,
+    risk_model: Optional[RiskModel] = None,
+) -> ExecutiveSummary:
+    """Build the executive summary for a set of findings.
+
+    Args:
+        findings: Findings as loaded from explained.json
+        previous: The previous run and its findings, for the trend
+        risk_model: Model used to rank risks (defaults to the built-in weights)
+    """
+    risk_model = risk_model or RiskModel()
+
+    severity_counts = {severity: 0 for severity in SEVERITY_ORDER}
+    for finding in findings:
+        severity = str(finding.get("severity", "INFO")).upper()
+        severity_counts[severity] = severity_counts.get(severity, 0) + 1
+
+    top_risks = sorted(
+        findings,
+        key=lambda f: (severity_rank(f.get("severity", "")), -risk_model.finding_score(f)),
+    )[:TOP_RISKS]
+
+    trend = compute_trend(findings, *previous) if previous else None
+
+    return ExecutiveSummary(
+        total=len(findings),
+        severity_counts={k: v for k, v in severity_counts.items() if v},
+        top_risks=top_risks,
+        priorities=_priorities(findings, risk_model, trend),
+        trend=trend,
+    )
@@ -0,0 +1,117 @@
+"""GitHub Actions workflow commands and job summary for findings.
+
+With the ``github-annotations`` report format, each finding is printed as an
+``::error``, ``::warning``, or ``::notice`` workflow command, so it shows up
+as an annotation on the Actions run. When ``GITHUB_STEP_SUMMARY`` is set, a
+Markdown summary of the findings is appended to the job summary as well.
+"""
+
+import os
+import sys
+from pathlib import Path
+from typing import Any, Dict, List, Optional, TextIO
+
+from app.common.models import SEVERITY_ORDER
+from app.findings.listing import severity_counts, sort_findings
+from app.findings.risk import RiskSummary
+
+# Workflow command per severity; GitHub has no level above error
+ANNOTATION_LEVELS = {
+    "CRITICAL": "error",
+    "HIGH": "error",
+    "MEDIUM": "warning",
+    "LOW": "notice",
+    "INFO": "notice",
+}
+
+
+def _escape_data(value: str) -> str:
+    """Escape a workflow command message."""
+    return value.replace("%", "%25").replace("\r", "%0D").replace("\n", "%0A")
+
+
+def _escape_property(value: str) -> str:
+    """Escape a workflow command property value."""
+    return _escape_data(value).replace(":", "%3A").replace(",", "%2C")
+
+
+def annotation(finding: Dict[str, Any]) -> str:
+    """Return the workflow command that annotates one finding."""
+    severity = str(finding.get("severity", "INFO")).upper()
+    level = ANNOTATION_LEVELS.get(severity, "notice")
+    title = f"{severity}: {finding.get('title', 'Unknown Issue')}"
+
+    lines = []
+    if finding.get("resource"):
+        lines.append(f"Resource: {finding['resource']}")
+    if finding.get("explanation"):
+        lines.append(str(finding["explanation"]))
+    if finding.get("recommendation"):
+        lines.append(f"Recommendation: {finding['recommendation']}")
+    message = "\n".join(lines) or title
+    return f"::{level} title={_escape_property(title)}::{_escape_data(message)}"
+
+
+def format_annotations(findings: List[Dict[str, Any]]) -> str:
+    """Return one workflow command per finding, most severe first."""
+    return "\n".join(annotation(finding) for finding in sort_findings(findings))
+
+
+def _cell(value: Any) -> str:
+    """Make a value safe for a Markdown table cell."""
+    return str(value or "-").replace("|", "\\|").replace("\n", " ")
+
+
+def job_summary(
+    findings: List[Dict[str, Any]], project_name: str, risk: Optional[RiskSummary] = None
+) -> str:
+    """Return the Markdown job summary for a run."""
+    counts = severity_counts(findings)
+    lines = [
+        f"## Paddi Security Audit: {project_name}",
+        "",
+        f"**Total findings:** {len(findings)}",
+        "",
+    ]
+    if risk is not None:
+        lines.extend([f"**Risk grade:** {risk.grade} (score {risk.score})", ""])
+    lines.extend(
+        [
+            "| " + " | ".join(SEVERITY_ORDER) + " |",
+            "|" + "---|" * len(SEVERITY_ORDER),
+            "| " + " | ".join(str(counts.get(s, 0)) for s in SEVERITY_ORDER) + " |",
+            "",
+        ]
+    )
+    if findings:
+        lines.extend(["| Severity | Finding | Resource |", "|---|---|---|"])
+        lines.extend(
+            f"| {_cell(f.get('severity'))} | {_cell(f.get('title'))} | "
+            f"{_cell(f.get('resource'))} |"
+            for f in sort_findings(findings)
+        )
+        lines.append("")
+    return "\n".join(lines)
+
+
+def write_annotations(
+    findings: List[Dict[str, Any]],
+    project_name: str,
+    risk: Optional[RiskSummary] = None,
+    stream: Optional[TextIO] = None,
+) -> Optional[Path]:
+    """Print annotations for the findings and append to the job summary, if any.
+
+    Returns:
+        The job summary file, or None outside GitHub Actions
+    """
+    if findings:
+        print(format_annotations(findings), file=stream or sys.stdout)
+
+    summary_file = os.environ.get("GITHUB_STEP_SUMMARY")
+    if not summary_file:
+        return None
+    path = Path(summary_file)
+    with open(path, "a", encoding="utf-8") as f:
+        f.write(job_summary(findings, project_name, risk) + "\n")
+    return path
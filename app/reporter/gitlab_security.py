@@ -0,0 +1,102 @@
+"""GitLab security report for findings.
+
+With the ``gitlab-sast`` report format, findings are written to
+``gl-sast-report.json`` in GitLab's security report schema. Declared as an
+``artifacts:reports:sast`` artifact, the file makes the findings appear in
+GitLab's Vulnerability Report and merge request security widget.
+
+Vulnerability IDs are derived from the finding fingerprint, so GitLab tracks
+the same finding across pipelines.
+"""
+
+import json
+import logging
+import uuid
+from datetime import datetime, timezone
+from pathlib import Path
+from typing import Any, Dict, List, Optional
+
+from app.export.ocsf import PRODUCT_VERSION
+from app.findings.fingerprint import compute_fingerprint
+from app.findings.listing import sort_findings
+
+logger = logging.getLogger(__name__)
+
+GITLAB_SCHEMA_VERSION = "15.0.7"
+GITLAB_REPORT_FILE = "gl-sast-report.json"
+
+# Namespace for vulnerability IDs derived from fingerprints
+_ID_NAMESPACE = uuid.uuid5(uuid.NAMESPACE_URL, "https://github.com/susumutomita/Paddi")
+
+SEVERITIES = {
+    "CRITICAL": "Critical",
+    "HIGH": "High",
+    "MEDIUM": "Medium",
+    "LOW": "Low",
+    "INFO": "Info",
+}
+
+_TOOL = {
+    "id": "paddi",
+    "name": "Paddi",
+    "version": PRODUCT_VERSION,
+    "vendor": {"name": "Paddi"},
+}
+
+
+def _timestamp(moment: datetime) -> str:
+    """Format a time the way the schema expects (UTC, no offset)."""
+    return moment.astimezone(timezone.utc).strftime("%Y-%m-%dT%H:%M:%S")
+
+
+def vulnerability(finding: Dict[str, Any]) -> Dict[str, Any]:
+    """Map a finding to a GitLab vulnerability."""
+    fingerprint = finding.get("fingerprint") or compute_fingerprint(finding)
+    category = finding.get("category") or "UNCATEGORIZED"
+    result: Dict[str, Any] = {
+        "id": str(uuid.uuid5(_ID_NAMESPACE, fingerprint)),
+        "name": finding.get("title") or "Unknown Issue",
+        "description": finding.get("explanation") or finding.get("description") or "",
+        "severity": SEVERITIES.get(str(finding.get("severity", "")).upper(), "Unknown"),
+        "identifiers": [
+            {"type": "paddi_category", "name": f"Paddi {category}", "value": category},
+            {"type": "paddi_fingerprint", "name": "Paddi fingerprint", "value": fingerprint},
+        ],
+        "location": {"file": finding.get("resource") or "unknown"},
+    }
+    if finding.get("recommendation"):
+        result["solution"] = finding["recommendation"]
+    if finding.get("cvss_vector"):
+        result["cvss_vectors"] = [{"vendor": "Paddi", "vector": finding["cvss_vector"]}]
+    return result
+
+
+def gitlab_report(
+    findings: List[Dict[str, Any]],
+    start_time: Optional[datetime] = None,
+    end_time: Optional[datetime] = None,
+) -> Dict[str, Any]:
+    """Build the security report, most severe findings first."""
+    end_time = end_time or datetime.now(timezone.utc)
+    return {
+        "version": GITLAB_SCHEMA_VERSION,
+        "scan": {
+            "analyzer": _TOOL,
+            "scanner": _TOOL,
+            "type": "sast",
+            "start_time": _timestamp(start_time or end_time),
+            "end_time": _timestamp(end_time),
+            "status": "success",
+        },
+        "vulnerabilities": [vulnerability(f) for f in sort_findings(findings)],
+    }
+
+
+def write_gitlab_report(findings: List[Dict[str, Any]], output_dir: Path) -> Path:
+    """Write gl-sast-report.json to the output directory."""
+    output_dir.mkdir(parents=True, exist_ok=True)
+    path = output_dir / GITLAB_REPORT_FILE
+    with open(path, "w", encoding="utf-8") as f:
+        json.dump(gitlab_report(findings), f, indent=2, ensure_ascii=False)
+    logger.info("GitLab security report generated: %s", path)
+    return path
@@ -0,0 +1,78 @@
+"""Navigation helpers for the Markdown report: anchors, badges, and categories.
+
+Each finding gets an explicit ``<a id>`` anchor, so the table of contents and
+links from tickets keep working however a renderer slugs headings. Anchors use
+the finding's fingerprint when it has one, so a link to a finding stays valid
+from one run to the next.
+"""
+
+import re
+from collections import OrderedDict
+from typing import Dict, List, Tuple
+
+from app.common.models import SEVERITY_ORDER, SecurityFinding, severity_rank
+
+SEVERITY_BADGES = {
+    "CRITICAL": "🔴",
+    "HIGH": "🟠",
+    "MEDIUM": "🟡",
+    "LOW": "🔵",
+    "INFO": "⚪",
+}
+UNCATEGORIZED = "uncategorized"
+
+
+def slugify(text: str) -> str:
+    """Return a lowercase, hyphenated form of text for anchors and file names."""
+    slug = re.sub(r"[^a-z0-9]+", "-", text.lower()).strip("-")
+    return slug or "item"
+
+
+def severity_badge(severity: str) -> str:
+    """Return the badge of a severity, e.g. ``🔴 `CRITICAL```."""
+    severity = str(severity).upper()
+    return f"{SEVERITY_BADGES.get(severity, '⚫')} `{severity}`"
+
+
+def finding_anchor(finding: SecurityFinding, index: int) -> str:
+    """Return the anchor ID of a finding."""
+    if finding.fingerprint:
+        return f"finding-{finding.fingerprint}"
+    return f"finding-{index}"
+
+
+def anchor_tag(anchor: str) -> str:
+    """Return the HTML tag that makes an anchor linkable from Markdown."""
+    return f'<a id="{anchor}"></a>'
+
+
+def toc_entry(finding: SecurityFinding, index: int, target: str = "") -> str:
+    """Return the table of contents line of a finding; ``target`` is its file, if another."""
+    return (
+        f"{index}. {severity_badge(finding.severity)} "
+        f"[{finding.title}]({target}#{finding_anchor(finding, index)})"
+    )
+
+
+def severity_summary(severity_counts: Dict[str, int]) -> str:
+    """Return the badges and counts of all severities on one line."""
+    severities = [s for s in SEVERITY_ORDER if severity_counts.get(s)]
+    severities += sorted(s for s in severity_counts if s not in SEVERITY_ORDER)
+    return " ".join(f"{severity_badge(s)} {severity_counts[s]}" for s in severities)
+
+
+def group_by_category(
+    findings: List[SecurityFinding],
+) -> "OrderedDict[str, List[Tuple[int, SecurityFinding]]]":
+    """Group findings by category, keeping each finding's number in the full report.
+
+    Categories are ordered by their most severe finding, then by name.
+    """
+    groups: Dict[str, List[Tuple[int, SecurityFinding]]] = {}
+    for index, finding in enumerate(findings, 1):
+        groups.setdefault(finding.category or UNCATEGORIZED, []).append((index, finding))
+
+    def worst(item: Tuple[str, List[Tuple[int, SecurityFinding]]]) -> Tuple[int, str]:
+        return min(severity_rank(f.severity) for _, f in item[1]), item[0]
+
+    return OrderedDict(sorted(groups.items(), key=worst))
@@ -0,0 +1,173 @@
+"""Static dashboard site of the run history (``paddi site build``).
+
+The site is plain HTML with relative links, so the output directory can be
+published as is to a GCS or S3 bucket or to GitHub Pages::
+
+    site/
+      index.html          every recorded run, newest first
+      trends.html         findings over time and mean time to remediation
+      runs/<run_id>.html  the HTML report of each run
+"""
+
+import logging
+from html import escape
+from pathlib import Path
+from typing import Dict, List, Optional
+
+from app.common.artifact_crypto import write_artifact_text
+from app.config.paddi_config import PaddiConfig
+from app.history.store import RunRecord, RunStore
+from app.history.trends import Trends, compute_trends
+from app.reporter.agent_reporter import HTMLGenerator, ReportService, configured_template_dir
+from app.reporter.branding import Branding
+from app.reporter.charts import severity_chart, trend_chart
+
+logger = logging.getLogger(__name__)
+
+SITE_DIR = "site"
+RUNS_DIR = "runs"
+SITE_TITLE = "Security Audit Dashboard"
+
+
+def _nav() -> str:
+    return (
+        '        <p><a href="index.html">Runs</a> &middot; '
+        '<a href="trends.html">Trends</a></p>\n'
+    )
+
+
+def _counts(severity_counts: Dict[str, int]) -> str:
+    return ", ".join(f"{s} {n}" for s, n in severity_counts.items() if n) or "-"
+
+
+def render_index(runs: List[RunRecord], trends: Trends, branding: Branding) -> str:
+    """Render the index page: one row per run, newest first, linking its report."""
+    changes = {point.run_id: point for point in trends.points}
+    rows = []
+    for run in runs:
+        point = changes.get(run.run_id)
+        change = f"+{point.new} / -{point.resolved}" if point else "-"
+        rows.append(
+            "            <tr>"
+            f'<td><a href="{RUNS_DIR}/{run.run_id}.html">#{run.run_id}</a></td>'
+            f"<td>{escape(run.created_at)}</td><td>{escape(run.project_id or '-')}</td>"
+            f"<td>{run.total}</td><td>{escape(_counts(run.severity_counts))}</td>"
+            f"<td>{change}</td></tr>\n"
+        )
+    body = (
+        _nav()
+        + f"        <p>{len(runs)} recorded run(s)</p>\n"
+        "        <table>\n"
+        "            <tr><th>Run</th><th>Date</th><th>Project</th><th>Findings</th>"
+        "<th>Severities</th><th>New / Resolved</th></tr>\n"
+        f"{''.join(rows)}"
+        "        </table>\n"
+    )
+    return branding.page_html(SITE_TITLE, body)
+
+
+def render_trends(runs: List[RunRecord], trends: Trends, branding: Branding) -> str:
+    """Render the trends page: the findings of each run and the mean time to remediation."""
+    oldest_first = list(reversed(runs))
+    latest = runs[0].severity_counts if runs else {}
+    if trends.mttr_days is None:
+        mttr = "        <p>Mean time to remediation: no findings resolved yet</p>\n"
+    else:
+        by_severity = "".join(
+            f"            <li>{escape(severity)}: {days:g} days</li>\n"
+            for severity, days in trends.mttr_days_by_severity.items()
+        )
+        mttr = (
+            f"        <p>Mean time to remediation: {trends.mttr_days:g} days "
+            f"({trends.resolved_findings} resolved finding(s))</p>\n"
+            f"        <ul>\n{by_severity}        </ul>\n"
+        )
+    recurring = ""
+    if trends.recurring_categories:
+        recurring = (
+            "        <p>Newly recurring categories: "
+            f"{escape(', '.join(trends.recurring_categories))}</p>\n"
+        )
+    body = (
+        _nav()
+        + "        <h2>Findings Over Time</h2>\n"
+        f"{trend_chart(oldest_first, branding.primary_color)}\n"
+        "        <h2>Latest Run by Severity</h2>\n"
+        f"{severity_chart(latest, branding.severity_colors)}\n"
+        "        <h2>Remediation</h2>\n"
+        f"{mttr}{recurring}"
+    )
+    return branding.page_html(f"{SITE_TITLE}: Trends", body)
+
+
+class RunReportRenderer:
+    """Renders the HTML report of a recorded run from the findings it recorded."""
+
+    def __init__(self, store: RunStore, output_dir: Path):
+        """Initialize RunReportRenderer with the run history and a directory for the reports."""
+        self.store = store
+        # The reports of earlier runs look like audit.html, custom template included
+        self.service = ReportService(
+            output_dir=Path(output_dir), custom_template_dir=configured_template_dir()
+        )
+        self.template = self.service.template("html")
+        html_settings = PaddiConfig.load().section("reporter").get("html_settings") or {}
+        self.generator = HTMLGenerator(charts=html_settings.get("include_charts", True))
+
+    def render(self, run: RunRecord) -> str:
+        """Return the HTML report of a run, with its trend up to that run."""
+        previous_run = self.store.previous_run(run.run_id)
+        previous = None
+        if previous_run:
+            previous = (previous_run, self.store.findings_for(previous_run.run_id))
+        history = [
+            earlier
+            for earlier in reversed(self.store.runs())
+            if earlier.run_id <= run.run_id and earlier.project_id == run.project_id
+        ]
+        metadata = {"project_id": run.project_id} if run.project_id else {}
+        report = self.service.create_report(
+            self.store.findings_for(run.run_id), metadata, previous=previous, history=history
+        )
+        report.audit_date = run.created_at
+        return self.generator.generate(report, self.template)
+
+
+def build_site(
+    store: RunStore,
+    output_dir: Path,
+    limit: Optional[int] = None,
+    branding: Optional[Branding] = None,
+) -> List[Path]:
+    """Render the run history into a static site.
+
+    Args:
+        store: Run history to render
+        output_dir: Directory for the site
+        limit: Only the most recent N runs (all runs if None)
+        branding: Branding of the pages (defaults to ``[reporter.branding]``)
+
+    Returns:
+        The files written
+    """
+    branding = branding or Branding.from_config()
+    output_dir = Path(output_dir)
+    runs_dir = output_dir / RUNS_DIR
+    runs_dir.mkdir(parents=True, exist_ok=True)
+    runs = store.runs(limit=limit)
+    trends = compute_trends(store, limit=limit)
+
+    renderer = RunReportRenderer(store, runs_dir)
+    written = []
+    for run in runs:
+        path = runs_dir / f"{run.run_id}.html"
+        written.append(write_artifact_text(path, renderer.render(run)))
+
+    written.append(
+        write_artifact_text(output_dir / "index.html", render_index(runs, trends, branding))
+    )
+    written.append(
+        write_artifact_text(output_dir / "trends.html", render_trends(runs, trends, branding))
+    )
+    logger.info("Static site of %d run(s) written to %s", len(runs), output_dir)
+    return written
@@ -0,0 +1 @@
+"""Server mode (``paddi serve``): a browser UI and JSON API over the run history."""
@@ -0,0 +1,306 @@
+"""Roles of ``paddi serve`` users, and who a request comes from.
+
+Every route needs a role, and each role includes the ones before it:
+
+    viewer    browse runs, findings, and reports, and follow audits
+    operator  start audits
+    admin     list and revoke API tokens
+
+A request is made by a principal, identified by a bearer token that is
+either an API token of ``data/tokens.db`` or, when ``[server.oidc]`` is set,
+an ID token of that OpenID Connect provider (RS256 only). Roles come from
+paddi.toml::
+
+    [server.users]
+    "alice@example.com" = "admin"
+
+    [server.oidc]
+    issuer = "https://accounts.google.com"
+    audience = "1234.apps.googleusercontent.com"
+    groups_claim = "groups"
+    group_roles = { "secops@example.com" = "operator" }
+    default_role = "viewer"
+
+An OIDC user gets the role listed in ``[server.users]``, else the highest
+role of their groups, else ``default_role``; with none of these, the user is
+refused. An API token created for a user acts as that user, with no more than
+the token's scope allows, and stops working when the user is removed from
+``[server.users]``.
+"""
+
+import base64
+import json
+import logging
+import threading
+import time
+from dataclasses import asdict, dataclass, field
+from typing import Any, Dict, Optional
+
+from app.common.exceptions import ConfigurationError
+from app.config.paddi_config import PaddiConfig
+
+from .tokens import TOKEN_PREFIX, ApiToken, TokenStore
+
+logger = logging.getLogger(__name__)
+
+VIEWER = "viewer"
+OPERATOR = "operator"
+ADMIN = "admin"
+# Lowest first; each role includes the ones before it
+ROLES = (VIEWER, OPERATOR, ADMIN)
+# Highest role an API token of each scope can act with
+SCOPE_ROLES = {"read": VIEWER, "audit": OPERATOR, "admin": ADMIN}
+# Seconds of clock difference accepted when checking exp and nbf of ID tokens
+CLOCK_LEEWAY = 60
+# Least seconds between fetches of the provider's keys for an unknown key ID
+JWKS_REFRESH_SECONDS = 300
+
+
+def role_allows(role: Optional[str], required: str) -> bool:
+    """Whether ``role`` includes ``required``."""
+    return role in ROLES and ROLES.index(role) >= ROLES.index(required)
+
+
+def _lowest(*roles: str) -> str:
+    return min(roles, key=ROLES.index)
+
+
+def _b64decode(segment: str) -> bytes:
+    return base64.urlsafe_b64decode(segment + "=" * (-len(segment) % 4))
+
+
+@dataclass
+class Principal:
+    """Who a request comes from, and their role."""
+
+    name: str
+    role: str
+    source: str
+
+    def to_dict(self) -> Dict[str, Any]:
+        """Convert to dictionary for JSON serialization."""
+        return asdict(self)
+
+
+class OidcVerifier:
+    """Verifies ID tokens signed by an OpenID Connect provider."""
+
+    def __init__(
+        self,
+        issuer: str,
+        audience: str,
+        jwks_uri: Optional[str] = None,
+        timeout: float = 10.0,
+    ):
+        """Initialize OidcVerifier; without ``jwks_uri``, the issuer's discovery names it."""
+        self.issuer = issuer
+        self.audience = audience
+        self.jwks_uri = jwks_uri
+        self.timeout = timeout
+        self._keys: Dict[Optional[str], Dict[str, Any]] = {}
+        self._fetched_at = 0.0
+        self._lock = threading.Lock()
+
+    def _get_json(self, url: str) -> Dict[str, Any]:
+        import requests
+
+        try:
+            response = requests.get(url, timeout=self.timeout)
+            response.raise_for_status()
+            document = response.json()
+            if not isinstance(document, dict):
+                raise ValueError("expected a JSON object")
+            return document
+        except (requests.RequestException, ValueError) as e:
+            logger.warning("Could not fetch %s: %s", url, e)
+            raise ValueError(f"{url} could not be fetched") from e
+
+    def _signing_key(self, kid: Optional[str]) -> Dict[str, Any]:
+        """Return the provider's key with this ID, fetching the keys again if it is unknown."""
+        with self._lock:
+            stale = time.monotonic() - self._fetched_at > JWKS_REFRESH_SECONDS
+            if kid not in self._keys and (not self._keys or stale):
+                if not self.jwks_uri:
+                    discovery = f"{self.issuer.rstrip('/')}/.well-known/openid-configuration"
+                    jwks_uri = self._get_json(discovery).get("jwks_uri")
+                    if not isinstance(jwks_uri, str):
+                        raise ValueError(f"{discovery} has no jwks_uri")
+                    self.jwks_uri = jwks_uri
+                keys = self._get_json(self.jwks_uri).get("keys")
+                self._keys = {
+                    key.get("kid"): key
+                    for key in (keys if isinstance(keys, list) else [])
+                    if isinstance(key, dict) and key.get("kty") == "RSA"
+                }
+                self._fetched_at = time.monotonic()
+            if kid is None and len(self._keys) == 1:
+                return next(iter(self._keys.values()))
+            if kid not in self._keys:
+                raise ValueError(f"unknown signing key {kid}")
+            return self._keys[kid]
+
+    def verify(self, token: str) -> Dict[str, Any]:
+        """Verify the signature, issuer, audience, and lifetime of an ID token.
+
+        Returns:
+            The token's claims
+
+        Raises:
+            ValueError: If the token is not valid
+        """
+        from cryptography.exceptions import InvalidSignature
+        from cryptography.hazmat.primitives import hashes
+        from cryptography.hazmat.primitives.asymmetric import padding
+        from cryptography.hazmat.primitives.asymmetric.rsa import RSAPublicNumbers
+
+        try:
+            header_b64, payload_b64, signature_b64 = token.split(".")
+            header = json.loads(_b64decode(header_b64))
+            claims = json.loads(_b64decode(payload_b64))
+            signature = _b64decode(signature_b64)
+        except (ValueError, TypeError) as e:
+            raise ValueError("not a JSON web token") from e
+        if not isinstance(header, dict) or not isinstance(claims, dict):
+            raise ValueError("not a JSON web token")
+        if header.get("alg") != "RS256":
+            raise ValueError(f"unsupported algorithm {header.get('alg')}")
+
+        key = self._signing_key(header.get("kid"))
+        try:
+            public_key = RSAPublicNumbers(
+                int.from_bytes(_b64decode(key["e"]), "big"),
+                int.from_bytes(_b64decode(key["n"]), "big"),
+            ).public_key()
+        except (KeyError, TypeError, ValueError) as e:
+            raise ValueError(f"malformed signing key {header.get('kid')}") from e
+        try:
+            public_key.verify(
+                signature,
+                f"{header_b64}.{payload_b64}".encode("ascii"),
+                padding.PKCS1v15(),
+                hashes.SHA256(),
+            )
+        except InvalidSignature as e:
+            raise ValueError("invalid signature") from e
+
+        audiences = claims.get("aud")
+        if isinstance(audiences, str):
+            audiences = [audiences]
+        now = time.time()
+        if claims.get("iss") != self.issuer:
+            raise ValueError(f"unexpected issuer {claims.get('iss')}")
+        if self.audience not in (audiences or []):
+            raise ValueError("the token is for another audience")
+        if not isinstance(claims.get("exp"), (int, float)) or now > claims["exp"] + CLOCK_LEEWAY:
+            raise ValueError("the token has expired")
+        if isinstance(claims.get("nbf"), (int, float)) and now + CLOCK_LEEWAY < claims["nbf"]:
+            raise ValueError("the token is not valid yet")
+        return claims
+
+
+@dataclass
+class AccessPolicy:
+    """Roles of users, as set in ``[server.users]`` and ``[server.oidc]``."""
+
+    users: Dict[str, str] = field(default_factory=dict)
+    oidc: Optional[OidcVerifier] = None
+    user_claim: str = "email"
+    groups_claim: Optional[str] = None
+    group_roles: Dict[str, str] = field(default_factory=dict)
+    default_role: Optional[str] = None
+
+    @classmethod
+    def from_config(cls, config: Optional[PaddiConfig] = None) -> "AccessPolicy":
+        """Create the policy from the ``[server]`` section of paddi.toml.
+
+        Raises:
+            ConfigurationError: If a role is unknown, or the OIDC issuer or audience is missing
+        """
+        section = (config or PaddiConfig.load()).section("server")
+        users = dict(section.get("users") or {})
+        oidc = dict(section.get("oidc") or {})
+        group_roles = dict(oidc.get("group_roles") or {})
+        default_role = oidc.get("default_role")
+        for key, roles in (("server.users", users), ("server.oidc.group_roles", group_roles)):
+            for name, role in roles.items():
+                if role not in ROLES:
+                    raise ConfigurationError(key, {"error": f"unknown role {role!r} of {name}"})
+        if default_role is not None and default_role not in ROLES:
+            raise ConfigurationError(
+                "server.oidc.default_role", {"error": f"unknown role {default_role!r}"}
+            )
+
+        verifier = None
+        if oidc:
+            if not oidc.get("issuer") or not oidc.get("audience"):
+                raise ConfigurationError(
+                    "server.oidc", {"error": "issuer and audience are required"}
+                )
+            verifier = OidcVerifier(oidc["issuer"], oidc["audience"], oidc.get("jwks_uri"))
+        return cls(
+            users=users,
+            oidc=verifier,
+            user_claim=oidc.get("user_claim", "email"),
+            groups_claim=oidc.get("groups_claim"),
+            group_roles=group_roles,
+            default_role=default_role,
+        )
+
+    @property
+    def enabled(self) -> bool:
+        """Whether users or an OIDC provider are configured, so requests need a token."""
+        return bool(self.users or self.oidc)
+
+    def token_principal(self, token: ApiToken) -> Optional[Principal]:
+        """Return who an API token acts as; None if its user has no role anymore."""
+        role = SCOPE_ROLES.get(token.scope)
+        if role is None:
+            return None
+        if token.user is None:
+            return Principal(f"token:{token.name}", role, "token")
+        user_role = self.users.get(token.user)
+        if user_role is None:
+            return None
+        return Principal(token.user, _lowest(role, user_role), "token")
+
+    def oidc_principal(self, claims: Dict[str, Any]) -> Optional[Principal]:
+        """Return the principal of a verified ID token; None if the user has no role."""
+        name = claims.get(self.user_claim) or claims.get("sub")
+        if not name:
+            return None
+        role = self.users.get(name)
+        if role is None and self.groups_claim:
+            groups = claims.get(self.groups_claim) or []
+            if isinstance(groups, str):
+                groups = [groups]
+            roles = [self.group_roles[group] for group in groups if group in self.group_roles]
+            role = max(roles, key=ROLES.index) if roles else None
+        role = role or self.default_role
+        return Principal(str(name), role, "oidc") if role else None
+
+
+class Authenticator:
+    """Finds the principal of a request's bearer token."""
+
+    def __init__(self, tokens: Optional[TokenStore] = None, policy: Optional[AccessPolicy] = None):
+        """Initialize Authenticator with the API tokens and the access policy."""
+        self.tokens = tokens
+        self.policy = policy or AccessPolicy()
+
+    def required(self) -> bool:
+        """Whether requests need a token: users or OIDC are configured, or a token was created."""
+        return self.policy.enabled or (self.tokens is not None and self.tokens.required())
+
+    def authenticate(self, bearer: str) -> Optional[Principal]:
+        """Return the principal of a bearer token; None if the token is not valid."""
+        if bearer.startswith(TOKEN_PREFIX):
+            token = self.tokens.authenticate(bearer) if self.tokens is not None else None
+            return self.policy.token_principal(token) if token else None
+        if self.policy.oidc is None:
+            return None
+        try:
+            claims = self.policy.oidc.verify(bearer)
+        except ValueError as e:
+            logger.debug("Rejected ID token: %s", e)
+            return None
+        return self.policy.oidc_principal(claims)
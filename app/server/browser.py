@@ -0,0 +1,116 @@
+"""Read access to runs, findings, and reports for the server's browser UI.
+
+Findings come from the run history in ``data/history.db``, as each run
+recorded them after overrides, suppressions, and deduplication, so the UI
+shows the same findings as that run's report. Report files are served from
+the output directory only.
+"""
+
+from dataclasses import asdict
+from datetime import datetime
+from pathlib import Path
+from typing import Any, Dict, List, Optional
+
+from app.findings.listing import filter_findings, sort_findings
+from app.findings.query import Query
+from app.history.store import HISTORY_DB_NAME, RunRecord, RunStore
+from app.reporter.site import RunReportRenderer
+
+# Report files listed in the UI; other files in the output directory are not served
+REPORT_SUFFIXES = {".html", ".md", ".json", ".csv", ".sarif", ".tf"}
+
+
+class ReportBrowser:
+    """Runs, findings, and report files of one data and output directory."""
+
+    def __init__(self, data_dir: Path = Path("data"), output_dir: Path = Path("output")):
+        """Initialize ReportBrowser with the directories of the run history and reports."""
+        self.data_dir = Path(data_dir)
+        self.output_dir = Path(output_dir)
+
+    def _store(self) -> Optional[RunStore]:
+        """Open the run history, if any run has been recorded."""
+        history = self.data_dir / HISTORY_DB_NAME
+        return RunStore(history) if history.exists() else None
+
+    def runs(self, limit: Optional[int] = None) -> List[Dict[str, Any]]:
+        """Return the recorded runs, newest first."""
+        store = self._store()
+        return [asdict(run) for run in store.runs(limit=limit)] if store else []
+
+    def run(self, run_id: Optional[int] = None) -> Optional[RunRecord]:
+        """Return a run by ID, or the latest run."""
+        store = self._store()
+        if store is None:
+            return None
+        return store.latest_run() if run_id is None else store.run(run_id)
+
+    def findings(
+        self,
+        run_id: Optional[int] = None,
+        severity: Optional[str] = None,
+        category: Optional[str] = None,
+        resource: Optional[str] = None,
+        tag: Optional[str] = None,
+        query: Optional[str] = None,
+    ) -> Optional[Dict[str, Any]]:
+        """Return the findings of a run (the latest by default) that match the filters.
+
+        Returns:
+            The run, the number of findings it recorded, and the matching
+            findings, most severe first; None if there is no such run
+
+        Raises:
+            QueryError: If the query is not a valid query expression
+        """
+        run = self.run(run_id)
+        if run is None:
+            return None
+        recorded = self._store().findings_for(run.run_id)
+        findings = filter_findings(
+            recorded, severity=severity, category=category, resource=resource, tag=tag
+        )
+        if query:
+            findings = Query(query).filter(findings)
+        return {"run": asdict(run), "total": len(recorded), "findings": sort_findings(findings)}
+
+    def reports(self) -> List[Dict[str, Any]]:
+        """Return the report files in the output directory, newest first."""
+        if not self.output_dir.is_dir():
+            return []
+        files = [
+            path
+            for path in self.output_dir.rglob("*")
+            if path.is_file() and path.suffix.lower() in REPORT_SUFFIXES
+        ]
+        files.sort(key=lambda path: path.stat().st_mtime, reverse=True)
+        return [
+            {
+                "path": path.relative_to(self.output_dir).as_posix(),
+                "size": path.stat().st_size,
+                "modified": datetime.fromtimestamp(path.stat().st_mtime).isoformat(
+                    timespec="seconds"
+                ),
+            }
+            for path in files
+        ]
+
+    def report_file(self, relative: str) -> Optional[Path]:
+        """Return a report file by its path in the output directory.
+
+        Returns None for paths that leave the output directory or are not reports.
+        """
+        root = self.output_dir.resolve()
+        path = (root / relative).resolve()
+        if root not in path.parents or path.suffix.lower() not in REPORT_SUFFIXES:
+            return None
+        return path if path.is_file() else None
+
+    def run_report(self, run_id: int) -> Optional[str]:
+        """Render the HTML report of a recorded run."""
+        store = self._store()
+        run = store.run(run_id) if store else None
+        if run is None:
+            return None
+        self.output_dir.mkdir(parents=True, exist_ok=True)
+        return RunReportRenderer(store, self.output_dir).render(run)
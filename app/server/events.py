@@ -0,0 +1,311 @@
+"""Queue of audits started from ``paddi serve``, and their progress events.
+
+Audits wait in a queue and run in background threads, highest priority
+first, at most ``max_concurrency`` at a time and never two of the same
+project at once. A request for an audit that is already queued with the same
+options is collapsed into the queued job. Queued and running jobs are saved
+to ``data/audit_queue.json``, so they are queued again when the server
+restarts.
+
+Each job records a sequence of events, which the server streams to web
+clients as server-sent events:
+
+    status  the job was queued, started, succeeded, or failed (with its exit
+            code and error)
+    stage   a pipeline stage started (collect, explain, report), with its
+            expected duration when earlier runs are known
+    log     a log line written while the audit ran
+
+Events are numbered, so a client that reconnects with ``Last-Event-ID``
+receives only the events it missed.
+"""
+
+import json
+import logging
+import threading
+import uuid
+from dataclasses import dataclass, field
+from datetime import datetime, timezone
+from pathlib import Path
+from typing import Any, Callable, Dict, List, Optional
+
+from app.common.exceptions import AuditQueueFullError, PaddiException
+from app.common.exit_codes import ExitCode, exit_code_for
+
+logger = logging.getLogger(__name__)
+
+QUEUED = "queued"
+RUNNING = "running"
+SUCCEEDED = "succeeded"
+FAILED = "failed"
+# Options of POST /api/audits that are passed on to the audit
+AUDIT_OPTIONS = ("project_id", "organization_id", "use_mock", "ai_provider", "formats")
+# Finished jobs kept for clients that connect late
+MAX_FINISHED_JOBS = 20
+DEFAULT_MAX_QUEUED = 50
+QUEUE_STATE_NAME = "audit_queue.json"
+# Fields of a job saved in the queue state
+_STATE_FIELDS = ("id", "options", "priority", "started_by", "requests", "queued_at")
+
+# Runs an audit with the given options, calling the callback as each stage starts
+AuditRunner = Callable[[Dict[str, Any], Callable[[str, Optional[float]], None]], None]
+
+
+def _now() -> str:
+    return datetime.now(timezone.utc).isoformat(timespec="seconds")
+
+
+@dataclass
+class AuditEvent:
+    """One numbered event of an audit job."""
+
+    id: int
+    type: str
+    data: Dict[str, Any]
+
+    def to_sse(self) -> str:
+        """Format the event as a server-sent event."""
+        return f"id: {self.id}\nevent: {self.type}\ndata: {json.dumps(self.data)}\n\n"
+
+
+@dataclass
+class AuditJob:
+    """An audit started from the server."""
+
+    id: str
+    options: Dict[str, Any]
+    status: str = QUEUED
+    priority: int = 0
+    started_by: Optional[str] = None
+    # Requests collapsed into this job, the first included
+    requests: int = 1
+    queued_at: str = field(default_factory=_now)
+    started_at: Optional[str] = None
+    events: List[AuditEvent] = field(default_factory=list)
+
+    def __post_init__(self):
+        self._changed = threading.Condition()
+
+    @property
+    def finished(self) -> bool:
+        """Whether the audit has ended."""
+        return self.status in (SUCCEEDED, FAILED)
+
+    @property
+    def project(self) -> str:
+        """The project or organization audited, of which one audit runs at a time."""
+        return self.options.get("project_id") or self.options.get("organization_id") or ""
+
+    def publish(self, event_type: str, data: Dict[str, Any]) -> None:
+        """Record an event and wake the clients waiting for one."""
+        with self._changed:
+            self.events.append(AuditEvent(len(self.events) + 1, event_type, data))
+            self._changed.notify_all()
+
+    def set_status(self, status: str, **data: Any) -> None:
+        """Change the job's status, with a status event."""
+        with self._changed:
+            self.status = status
+            self.publish("status", {"status": status, **data})
+
+    def events_after(self, last_id: int, timeout: Optional[float] = None) -> List[AuditEvent]:
+        """Return the events after ``last_id``, waiting up to timeout for one if there are none."""
+        with self._changed:
+            if len(self.events) <= last_id and not self.finished:
+                self._changed.wait(timeout)
+            return self.events[last_id:]
+
+    def to_dict(self) -> Dict[str, Any]:
+        """Convert to dictionary for JSON serialization."""
+        return {
+            "id": self.id,
+            "status": self.status,
+            "options": self.options,
+            "priority": self.priority,
+            "started_by": self.started_by,
+            "requests": self.requests,
+            "queued_at": self.queued_at,
+            "started_at": self.started_at,
+            "events": len(self.events),
+        }
+
+
+class _JobLogHandler(logging.Handler):
+    """Publishes the log records of the audit's thread as events of its job."""
+
+    def __init__(self, job: AuditJob, thread_id: int):
+        super().__init__(logging.INFO)
+        self.job = job
+        self.thread_id = thread_id
+
+    def emit(self, record: logging.LogRecord) -> None:
+        if record.thread != self.thread_id:
+            return
+        try:
+            message = record.getMessage()
+        except Exception:  # pylint: disable=broad-except
+            return
+        self.job.publish(
+            "log", {"level": record.levelname, "logger": record.name, "message": message}
+        )
+
+
+class AuditJobs:
+    """Queues audits, runs them in the background, and keeps their events."""
+
+    def __init__(
+        self,
+        runner: AuditRunner,
+        max_concurrency: int = 1,
+        max_queued: int = DEFAULT_MAX_QUEUED,
+        state_path: Optional[Path] = None,
+    ):
+        """Initialize AuditJobs, queueing again the jobs saved in ``state_path``."""
+        self.runner = runner
+        self.max_concurrency = max_concurrency
+        self.max_queued = max_queued
+        self.state_path = Path(state_path) if state_path else None
+        self._jobs: Dict[str, AuditJob] = {}
+        self._lock = threading.Lock()
+        with self._lock:
+            self._restore()
+            self._dispatch()
+
+    def start(
+        self, options: Dict[str, Any], started_by: Optional[str] = None, priority: int = 0
+    ) -> AuditJob:
+        """Queue an audit with the known options of a request, on behalf of ``started_by``.
+
+        Returns:
+            The new job, or the queued job with the same options that the request was
+            collapsed into
+
+        Raises:
+            ValueError: If an option or the priority has the wrong type
+            AuditQueueFullError: If max_queued audits are already waiting
+        """
+        known = {key: options[key] for key in AUDIT_OPTIONS if key in options}
+        for key, value in known.items():
+            expected = bool if key == "use_mock" else str
+            if value is not None and not isinstance(value, expected):
+                raise ValueError(f"{key} must be a {expected.__name__}")
+        if not isinstance(priority, int) or isinstance(priority, bool):
+            raise ValueError("priority must be an integer")
+        with self._lock:
+            queued = self._queued()
+            duplicate = next((job for job in queued if job.options == known), None)
+            if duplicate is not None:
+                duplicate.requests += 1
+                duplicate.priority = max(duplicate.priority, priority)
+                logger.info("Audit request collapsed into queued audit %s", duplicate.id)
+                self._save()
+                return duplicate
+            if len(queued) >= self.max_queued:
+                raise AuditQueueFullError(self.max_queued)
+            job = AuditJob(uuid.uuid4().hex[:12], known, priority=priority, started_by=started_by)
+            self._jobs[job.id] = job
+            job.publish("status", {"status": QUEUED, "options": job.options})
+            self._forget_finished()
+            self._dispatch()
+            self._save()
+        return job
+
+    def get(self, job_id: str) -> Optional[AuditJob]:
+        """Return a job by ID."""
+        return self._jobs.get(job_id)
+
+    def jobs(self) -> List[AuditJob]:
+        """Return the running jobs, the queued jobs in the order they will run, then the
+        finished jobs, newest first."""
+        with self._lock:
+            running = [job for job in self._jobs.values() if job.status == RUNNING]
+            finished = [job for job in reversed(self._jobs.values()) if job.finished]
+            return running + self._queued() + finished
+
+    def _queued(self) -> List[AuditJob]:
+        """Return the queued jobs, highest priority first, then oldest first."""
+        queued = [job for job in self._jobs.values() if job.status == QUEUED]
+        return sorted(queued, key=lambda job: -job.priority)
+
+    def _dispatch(self) -> None:
+        """Start queued jobs while fewer than max_concurrency run, one per project."""
+        running = [job for job in self._jobs.values() if job.status == RUNNING]
+        busy = {job.project for job in running}
+        for job in self._queued():
+            if len(running) >= self.max_concurrency:
+                break
+            if job.project in busy:
+                continue
+            job.started_at = _now()
+            job.set_status(RUNNING)
+            running.append(job)
+            busy.add(job.project)
+            thread = threading.Thread(target=self._run, args=(job,), name=f"audit-{job.id}")
+            thread.daemon = True
+            thread.start()
+
+    def _forget_finished(self) -> None:
+        finished = [job_id for job_id, job in self._jobs.items() if job.finished]
+        for job_id in finished[:-MAX_FINISHED_JOBS]:
+            del self._jobs[job_id]
+
+    def _save(self, exclude: Optional[AuditJob] = None) -> None:
+        """Save the queued and running jobs, so a restart queues them again.
+
+        Args:
+            exclude: A job that is ending, to leave out of the state
+        """
+        if self.state_path is None:
+            return
+        state = [
+            {name: getattr(job, name) for name in _STATE_FIELDS}
+            for job in self._jobs.values()
+            if not job.finished and job is not exclude
+        ]
+        self.state_path.parent.mkdir(parents=True, exist_ok=True)
+        temporary = self.state_path.with_suffix(".tmp")
+        temporary.write_text(json.dumps(state, indent=2), encoding="utf-8")
+        temporary.replace(self.state_path)
+
+    def _restore(self) -> None:
+        """Queue again the jobs that were queued or running when the server stopped."""
+        if self.state_path is None or not self.state_path.exists():
+            return
+        try:
+            state = json.loads(self.state_path.read_text(encoding="utf-8"))
+            jobs = [AuditJob(**{name: entry[name] for name in _STATE_FIELDS}) for entry in state]
+        except (OSError, ValueError, TypeError, KeyError) as e:
+            logger.warning("Could not restore the audit queue from %s: %s", self.state_path, e)
+            return
+        for job in jobs:
+            self._jobs[job.id] = job
+            job.publish("status", {"status": QUEUED, "options": job.options, "restored": True})
+        if jobs:
+            logger.info("Restored %d queued audit(s) from %s", len(jobs), self.state_path)
+
+    def _run(self, job: AuditJob) -> None:
+        handler = _JobLogHandler(job, threading.get_ident())
+        app_logger = logging.getLogger("app")
+        app_logger.addHandler(handler)
+        try:
+            self.runner(
+                dict(job.options),
+                lambda stage, expected: job.publish(
+                    "stage", {"stage": stage, "expected_seconds": expected}
+                ),
+            )
+        except Exception as e:  # pylint: disable=broad-except
+            error = e.message if isinstance(e, PaddiException) else str(e)
+            status, result = FAILED, {"exit_code": int(exit_code_for(e)), "error": error}
+        else:
+            status, result = SUCCEEDED, {"exit_code": int(ExitCode.SUCCESS)}
+        finally:
+            app_logger.removeHandler(handler)
+        with self._lock:
+            # Save before publishing the end, so whoever waits for it reads a
+            # state that no longer queues the job
+            self._save(exclude=job)
+            job.set_status(status, **result)
+            self._forget_finished()
+            self._dispatch()
+            self._save()
@@ -0,0 +1,324 @@
+"""HTTP server of ``paddi serve``: the browser UI and its JSON API.
+
+The server uses only the standard library, and the UI is plain HTML, CSS,
+and JavaScript shipped in ``app/server/static``, so a small team can browse
+its audits without a web framework, a build step, or a CDN.
+
+Routes:
+
+    GET /                      the browser UI
+    GET /static/<file>         assets of the UI
+    GET /api/runs              recorded runs, newest first (?limit=N)
+    GET /api/findings          findings of a run (?run=, severity=, category=,
+                               resource=, tag=, query=)
+    GET /api/reports           report files in the output directory
+    GET /reports/<path>        a report file
+    GET /runs/<run_id>/report  the HTML report of a recorded run
+    POST /api/audits           queue an audit (JSON body of AUDIT_OPTIONS and priority)
+    GET /api/audits            running, queued, and finished audits
+    GET /api/audits/<id>       status of an audit
+    GET /api/audits/<id>/events  progress of an audit as server-sent events
+    GET /api/me                who the request comes from, and their role
+    GET /api/tokens            API tokens (admin)
+    DELETE /api/tokens/<id>    revoke an API token (admin)
+
+Once an API token has been created (``paddi serve token create``), or users
+or an OIDC provider are configured, every route but the UI and its assets
+needs a bearer token: ``Authorization: Bearer <token>`` or, where a browser
+cannot send headers (event streams and report links), ``?access_token=``.
+Reading needs the viewer role, starting an audit the operator role, and
+managing tokens the admin role (see :mod:`app.server.access`).
+"""
+
+import json
+import logging
+import mimetypes
+import re
+from dataclasses import asdict, dataclass
+from http import HTTPStatus
+from http.server import BaseHTTPRequestHandler, ThreadingHTTPServer
+from pathlib import Path
+from typing import Any, Dict, List, Optional, Type
+from urllib.parse import parse_qs, unquote, urlsplit
+
+from app.common.exceptions import AuditQueueFullError, ConfigurationError, QueryError
+from app.config.paddi_config import PaddiConfig
+
+from .browser import ReportBrowser
+from .access import ADMIN, OPERATOR, VIEWER, AccessPolicy, Authenticator, Principal, role_allows
+from .events import DEFAULT_MAX_QUEUED, AuditJob, AuditJobs
+from .tokens import TokenStore
+
+logger = logging.getLogger(__name__)
+
+STATIC_DIR = Path(__file__).parent / "static"
+DEFAULT_HOST = "127.0.0.1"
+DEFAULT_PORT = 8080
+FINDING_FILTERS = ("severity", "category", "resource", "tag", "query")
+# Seconds between keep-alive comments on an idle event stream
+KEEPALIVE_SECONDS = 15.0
+MAX_BODY_BYTES = 64 * 1024
+# Routes that need a token once one has been created; the UI and its assets do not
+PROTECTED_ROUTES = ("api", "reports", "runs")
+_ACCESS_TOKEN_PARAM = re.compile(r"access_token=[^&\s]*")
+# Who requests come from while no token is needed: anyone who can connect may do anything
+ANONYMOUS = Principal("anonymous", ADMIN, "none")
+
+
+@dataclass
+class ServerSettings:
+    """Where ``paddi serve`` listens, and how many audits it runs and queues."""
+
+    host: str = DEFAULT_HOST
+    port: int = DEFAULT_PORT
+    max_concurrent_audits: int = 1
+    max_queued_audits: int = DEFAULT_MAX_QUEUED
+
+    @classmethod
+    def from_config(cls, config: Optional[PaddiConfig] = None) -> "ServerSettings":
+        """Create the settings from the ``[server]`` section of paddi.toml.
+
+        Raises:
+            ConfigurationError: If the port is not a number from 0 to 65535, or an audit
+                limit is not a positive integer
+        """
+        section = (config or PaddiConfig.load()).section("server")
+        port = section.get("port", DEFAULT_PORT)
+        if not isinstance(port, int) or isinstance(port, bool) or not 0 <= port <= 65535:
+            raise ConfigurationError("server.port", {"error": f"invalid port {port!r}"})
+        limits = {"max_concurrent_audits": 1, "max_queued_audits": DEFAULT_MAX_QUEUED}
+        for key, default in limits.items():
+            value = section.get(key, default)
+            if not isinstance(value, int) or isinstance(value, bool) or value < 1:
+                raise ConfigurationError(f"server.{key}", {"error": f"invalid limit {value!r}"})
+            limits[key] = value
+        return cls(host=str(section.get("host", DEFAULT_HOST)), port=port, **limits)
+
+
+class RequestHandler(BaseHTTPRequestHandler):
+    """Serves the UI, its API, and the reports of ``browser``."""
+
+    browser: ReportBrowser
+    jobs: Optional[AuditJobs] = None
+    auth: Optional[Authenticator] = None
+    server_version = "Paddi"
+    principal: Optional[Principal] = None
+
+    def do_GET(self) -> None:  # noqa: N802 - name required by BaseHTTPRequestHandler
+        """Route a GET request."""
+        url = urlsplit(self.path)
+        path = unquote(url.path)
+        params = {key: values[-1] for key, values in parse_qs(url.query).items()}
+        access_token = params.pop("access_token", None)
+        try:
+            parts = [part for part in path.split("/") if part]
+            if parts and parts[0] in PROTECTED_ROUTES:
+                role = ADMIN if parts[:2] == ["api", "tokens"] else VIEWER
+                if not self._authorized(role, access_token):
+                    return
+            self._route(path, params)
+        except (QueryError, ValueError) as e:
+            self._send_json({"error": getattr(e, "message", str(e))}, HTTPStatus.BAD_REQUEST)
+        except Exception:  # pylint: disable=broad-except
+            logger.exception("Request failed: %s", self.path)
+            self._send_json({"error": "internal error"}, HTTPStatus.INTERNAL_SERVER_ERROR)
+
+    def do_POST(self) -> None:  # noqa: N802 - name required by BaseHTTPRequestHandler
+        """Start an audit (POST /api/audits)."""
+        if urlsplit(self.path).path.rstrip("/") != "/api/audits" or self.jobs is None:
+            self._send_json({"error": "not found"}, HTTPStatus.NOT_FOUND)
+            return
+        try:
+            length = int(self.headers.get("Content-Length") or 0)
+        except ValueError:
+            length = -1
+        if length < 0:
+            self._send_json({"error": "invalid Content-Length"}, HTTPStatus.BAD_REQUEST)
+            return
+        if length > MAX_BODY_BYTES:
+            self._send_json({"error": "request too large"}, HTTPStatus.REQUEST_ENTITY_TOO_LARGE)
+            return
+        if not self._authorized(OPERATOR):
+            return
+        try:
+            options = json.loads(self.rfile.read(length) or b"{}")
+            if not isinstance(options, dict):
+                raise ValueError("expected a JSON object")
+            priority = options.pop("priority", 0)
+            job = self.jobs.start(options, started_by=self.principal.name, priority=priority)
+        except AuditQueueFullError as e:
+            self._send_json({"error": e.message}, HTTPStatus.TOO_MANY_REQUESTS)
+        except ValueError as e:
+            self._send_json({"error": str(e)}, HTTPStatus.BAD_REQUEST)
+        else:
+            logger.info("Audit %s requested by %s", job.id, self.principal.name)
+            self._send_json(job.to_dict(), HTTPStatus.ACCEPTED)
+
+    def do_DELETE(self) -> None:  # noqa: N802 - name required by BaseHTTPRequestHandler
+        """Revoke an API token (DELETE /api/tokens/<id>)."""
+        parts = [part for part in urlsplit(self.path).path.split("/") if part]
+        if len(parts) != 3 or parts[:2] != ["api", "tokens"] or not self._tokens():
+            self._send_json({"error": "not found"}, HTTPStatus.NOT_FOUND)
+            return
+        if not self._authorized(ADMIN):
+            return
+        token = self._tokens().revoke(parts[2])
+        if token is not None:
+            logger.info("Token %s revoked by %s", token.token_id, self.principal.name)
+        self._send_json(asdict(token) if token else None)
+
+    def _tokens(self) -> Optional[TokenStore]:
+        return self.auth.tokens if self.auth is not None else None
+
+    def _authorized(self, role: str, access_token: Optional[str] = None) -> bool:
+        """Find who the request comes from, answering 401 or 403 if they lack the role."""
+        if self.auth is None or not self.auth.required():
+            self.principal = ANONYMOUS
+            return True
+        header = self.headers.get("Authorization", "")
+        if header[:7].lower() == "bearer ":
+            access_token = header[7:].strip()
+        principal = self.auth.authenticate(access_token) if access_token else None
+        if principal is None:
+            self._send_json(
+                {"error": "a valid token is required"},
+                HTTPStatus.UNAUTHORIZED,
+                {"WWW-Authenticate": 'Bearer realm="paddi"'},
+            )
+            return False
+        if not role_allows(principal.role, role):
+            self._send_json(
+                {"error": f"{principal.name} has the {principal.role} role; {role} is required"},
+                HTTPStatus.FORBIDDEN,
+                {"WWW-Authenticate": 'Bearer realm="paddi", error="insufficient_scope"'},
+            )
+            return False
+        self.principal = principal
+        return True
+
+    def _route(self, path: str, params: Dict[str, str]) -> None:
+        parts = [part for part in path.split("/") if part]
+        if not parts:
+            self._send_file(STATIC_DIR / "index.html")
+        elif parts[0] == "static" and len(parts) == 2 and parts[1] != "..":
+            self._send_file(STATIC_DIR / parts[1])
+        elif parts == ["api", "runs"]:
+            limit = int(params["limit"]) if params.get("limit") else None
+            self._send_json(self.browser.runs(limit))
+        elif parts == ["api", "findings"]:
+            run_id = int(params["run"]) if params.get("run") else None
+            filters = {key: params.get(key) or None for key in FINDING_FILTERS}
+            self._send_json(self.browser.findings(run_id, **filters))
+        elif parts == ["api", "reports"]:
+            self._send_json(self.browser.reports())
+        elif parts[0] == "reports" and len(parts) > 1:
+            self._send_file(self.browser.report_file("/".join(parts[1:])))
+        elif parts[:2] == ["api", "audits"] and self.jobs is not None:
+            self._route_audits(parts[2:], params)
+        elif parts == ["api", "me"]:
+            self._send_json(self.principal.to_dict())
+        elif parts == ["api", "tokens"] and self._tokens() is not None:
+            self._send_json([asdict(token) for token in self._tokens().tokens()])
+        elif len(parts) == 3 and parts[0] == "runs" and parts[2] == "report":
+            html = self.browser.run_report(int(parts[1]))
+            if html is None:
+                self._send_json({"error": "run not found"}, HTTPStatus.NOT_FOUND)
+            else:
+                self._send(html.encode("utf-8"), "text/html; charset=utf-8")
+        else:
+            self._send_json({"error": "not found"}, HTTPStatus.NOT_FOUND)
+
+    def _route_audits(self, parts: List[str], params: Dict[str, str]) -> None:
+        if not parts:
+            self._send_json([job.to_dict() for job in self.jobs.jobs()])
+            return
+        job = self.jobs.get(parts[0])
+        if job is None or parts[1:] not in ([], ["events"]):
+            self._send_json({"error": "not found"}, HTTPStatus.NOT_FOUND)
+        elif len(parts) == 1:
+            self._send_json(job.to_dict())
+        else:
+            last_id = self.headers.get("Last-Event-ID") or params.get("after") or 0
+            self._stream_events(job, int(last_id))
+
+    def _stream_events(self, job: AuditJob, last_id: int) -> None:
+        """Send the job's events as they happen, until it has finished."""
+        self.send_response(HTTPStatus.OK)
+        self.send_header("Content-Type", "text/event-stream; charset=utf-8")
+        self.send_header("Cache-Control", "no-cache")
+        self.end_headers()
+        try:
+            while True:
+                events = job.events_after(last_id, KEEPALIVE_SECONDS)
+                for event in events:
+                    self.wfile.write(event.to_sse().encode("utf-8"))
+                    last_id = event.id
+                if not events:
+                    self.wfile.write(b": keep-alive\n\n")
+                self.wfile.flush()
+                if job.finished and last_id >= len(job.events):
+                    break
+        except (BrokenPipeError, ConnectionResetError):
+            logger.debug("Event stream of audit %s closed by the client", job.id)
+        self.close_connection = True
+
+    def _send(
+        self,
+        body: bytes,
+        content_type: str,
+        status: HTTPStatus = HTTPStatus.OK,
+        headers: Optional[Dict[str, str]] = None,
+    ) -> None:
+        self.send_response(status)
+        for name, value in (headers or {}).items():
+            self.send_header(name, value)
+        self.send_header("Content-Type", content_type)
+        self.send_header("Content-Length", str(len(body)))
+        self.send_header("X-Content-Type-Options", "nosniff")
+        self.end_headers()
+        self.wfile.write(body)
+
+    def _send_json(
+        self,
+        data: Any,
+        status: HTTPStatus = HTTPStatus.OK,
+        headers: Optional[Dict[str, str]] = None,
+    ) -> None:
+        if data is None:
+            data, status = {"error": "not found"}, HTTPStatus.NOT_FOUND
+        body = json.dumps(data, ensure_ascii=False).encode("utf-8")
+        self._send(body, "application/json; charset=utf-8", status, headers)
+
+    def _send_file(self, path: Optional[Path]) -> None:
+        if path is None or not path.is_file():
+            self._send_json({"error": "not found"}, HTTPStatus.NOT_FOUND)
+            return
+        content_type = mimetypes.guess_type(path.name)[0] or "application/octet-stream"
+        if content_type.startswith("text/") or content_type == "application/javascript":
+            content_type += "; charset=utf-8"
+        self._send(path.read_bytes(), content_type)
+
+    def log_message(self, format: str, *args: Any) -> None:  # pylint: disable=redefined-builtin
+        """Log requests through logging instead of stderr, without their tokens."""
+        message = _ACCESS_TOKEN_PARAM.sub("access_token=***", format % args)
+        logger.debug("%s - %s", self.address_string(), message)
+
+
+def create_server(
+    settings: ServerSettings,
+    browser: ReportBrowser,
+    jobs: Optional[AuditJobs] = None,
+    tokens: Optional[TokenStore] = None,
+    policy: Optional[AccessPolicy] = None,
+) -> ThreadingHTTPServer:
+    """Create the server; call ``serve_forever()`` on it to start serving.
+
+    Without ``jobs``, audits cannot be started from the server. Without
+    ``tokens`` and a ``policy`` that sets users or OIDC, no request needs a token.
+    """
+    handler: Type[RequestHandler] = type(
+        "PaddiRequestHandler",
+        (RequestHandler,),
+        {"browser": browser, "jobs": jobs, "auth": Authenticator(tokens, policy)},
+    )
+    return ThreadingHTTPServer((settings.host, settings.port), handler)
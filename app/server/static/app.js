@@ -0,0 +1,259 @@
+// Browser UI of `paddi serve`: runs, findings with filters, reports, and live audits.
+// Values from findings are always set as text, never as HTML.
+"use strict";
+
+const SEVERITIES = ["CRITICAL", "HIGH", "MEDIUM", "LOW", "INFO"];
+
+const TOKEN_KEY = "paddi-token";
+
+// Once an API token has been created, the API needs one; it is kept for this tab only
+function authHeaders() {
+    const token = sessionStorage.getItem(TOKEN_KEY);
+    return token ? { Authorization: `Bearer ${token}` } : {};
+}
+
+// Event streams and report links cannot send headers, so they pass the token in the URL
+function withToken(url) {
+    const token = sessionStorage.getItem(TOKEN_KEY);
+    if (!token) {
+        return url;
+    }
+    return `${url}${url.includes("?") ? "&" : "?"}access_token=${encodeURIComponent(token)}`;
+}
+
+async function getJSON(url) {
+    const response = await fetch(url, { headers: authHeaders() });
+    const data = await response.json();
+    if (response.status === 401) {
+        document.getElementById("token-form").hidden = false;
+    }
+    if (!response.ok) {
+        throw new Error(data.error || response.statusText);
+    }
+    return data;
+}
+
+function cell(row, text, className) {
+    const td = row.insertCell();
+    td.textContent = text === undefined || text === null ? "" : String(text);
+    if (className) {
+        td.className = className;
+    }
+    return td;
+}
+
+function link(parent, text, href, newTab) {
+    const a = document.createElement("a");
+    a.textContent = text;
+    a.href = href;
+    if (newTab) {
+        a.target = "_blank";
+        a.rel = "noopener";
+    }
+    parent.appendChild(a);
+    return a;
+}
+
+function severityCounts(counts) {
+    return SEVERITIES.filter((s) => counts[s]).map((s) => `${s} ${counts[s]}`).join(", ");
+}
+
+function showView(name) {
+    document.querySelectorAll(".view").forEach((view) => {
+        view.hidden = view.id !== name;
+    });
+    document.querySelectorAll("nav a").forEach((a) => {
+        a.classList.toggle("active", a.dataset.view === name);
+    });
+}
+
+// Viewers cannot start audits, so the Audit view is only shown to operators and admins
+async function loadPrincipal() {
+    const principal = await getJSON("/api/me");
+    document.querySelector("nav a[data-view=audit]").hidden = principal.role === "viewer";
+}
+
+async function loadRuns() {
+    const runs = await getJSON("/api/runs");
+    const section = document.getElementById("runs");
+    const body = section.querySelector("tbody");
+    body.replaceChildren();
+    section.querySelector(".empty").hidden = runs.length > 0;
+
+    const select = document.querySelector("#filters select[name=run]");
+    select.replaceChildren();
+    for (const run of runs) {
+        const row = body.insertRow();
+        cell(row, `#${run.run_id}`);
+        cell(row, run.created_at);
+        cell(row, run.project_id || "-");
+        cell(row, run.total);
+        cell(row, severityCounts(run.severity_counts));
+        const actions = row.insertCell();
+        link(actions, "Findings", `#findings?run=${run.run_id}`);
+        actions.append(" · ");
+        link(actions, "Report", withToken(`/runs/${run.run_id}/report`), true);
+
+        const option = document.createElement("option");
+        option.value = run.run_id;
+        option.textContent = `Run #${run.run_id} (${run.created_at.slice(0, 10)})`;
+        select.appendChild(option);
+    }
+}
+
+function toggleDetail(row, finding) {
+    const next = row.nextElementSibling;
+    if (next && next.classList.contains("finding-detail")) {
+        next.remove();
+        return;
+    }
+    const detail = document.createElement("tr");
+    detail.className = "finding-detail";
+    const td = cell(detail, "");
+    td.colSpan = 5;
+    td.textContent = [
+        finding.explanation,
+        finding.recommendation ? `Recommendation: ${finding.recommendation}` : "",
+    ].filter(Boolean).join("\n\n");
+    row.after(detail);
+}
+
+async function loadFindings() {
+    const form = document.getElementById("filters");
+    const params = new URLSearchParams();
+    for (const [key, value] of new FormData(form)) {
+        if (value) {
+            params.set(key, value);
+        }
+    }
+    const section = document.getElementById("findings");
+    const error = section.querySelector(".error");
+    const body = section.querySelector("tbody");
+    body.replaceChildren();
+    try {
+        const data = await getJSON(`/api/findings?${params}`);
+        error.hidden = true;
+        section.querySelector(".run-label").textContent = `(run #${data.run.run_id})`;
+        section.querySelector(".count").textContent =
+            `${data.findings.length} of ${data.total} finding(s)`;
+        for (const finding of data.findings) {
+            const row = body.insertRow();
+            const severity = String(finding.severity || "INFO").toUpperCase();
+            cell(row, severity, `severity severity-${severity}`);
+            cell(row, finding.title);
+            cell(row, finding.category);
+            cell(row, finding.resource);
+            cell(row, finding.owner);
+            row.addEventListener("click", () => toggleDetail(row, finding));
+        }
+    } catch (e) {
+        error.textContent = e.message;
+        error.hidden = false;
+        section.querySelector(".count").textContent = "";
+    }
+}
+
+async function loadReports() {
+    const reports = await getJSON("/api/reports");
+    const section = document.getElementById("reports");
+    const body = section.querySelector("tbody");
+    body.replaceChildren();
+    section.querySelector(".empty").hidden = reports.length > 0;
+    for (const report of reports) {
+        const row = body.insertRow();
+        link(row.insertCell(), report.path, withToken(`/reports/${report.path}`), true);
+        cell(row, report.modified);
+        cell(row, `${Math.ceil(report.size / 1024)} KB`);
+    }
+}
+
+function followAudit(job) {
+    const section = document.getElementById("audit");
+    const status = section.querySelector(".audit-status");
+    const stages = section.querySelector(".stages");
+    const log = section.querySelector(".audit-log");
+    stages.replaceChildren();
+    log.textContent = "";
+    status.textContent = `Audit ${job.id}: ${job.status}`;
+
+    // EventSource reconnects by itself, sending Last-Event-ID so no event is repeated
+    const events = new EventSource(withToken(`/api/audits/${job.id}/events`));
+    events.addEventListener("stage", (event) => {
+        const data = JSON.parse(event.data);
+        const item = document.createElement("li");
+        const expected = data.expected_seconds
+            ? ` (usually ${Math.round(data.expected_seconds)}s)`
+            : "";
+        item.textContent = `${data.stage}${expected}`;
+        stages.appendChild(item);
+    });
+    events.addEventListener("log", (event) => {
+        const data = JSON.parse(event.data);
+        log.textContent += `${data.level} ${data.message}\n`;
+        log.scrollTop = log.scrollHeight;
+    });
+    events.addEventListener("status", (event) => {
+        const data = JSON.parse(event.data);
+        const error = data.error ? ` - ${data.error}` : "";
+        status.textContent = `Audit ${job.id}: ${data.status}${error}`;
+        if (data.status === "succeeded" || data.status === "failed") {
+            events.close();
+            loadRuns();
+        }
+    });
+}
+
+async function startAudit(form) {
+    const error = document.querySelector("#audit .error");
+    const body = {
+        project_id: form.elements.project_id.value,
+        use_mock: form.elements.use_mock.checked,
+    };
+    const response = await fetch("/api/audits", {
+        method: "POST",
+        headers: { "Content-Type": "application/json", ...authHeaders() },
+        body: JSON.stringify(body),
+    });
+    const data = await response.json();
+    if (response.status === 401) {
+        document.getElementById("token-form").hidden = false;
+    }
+    if (!response.ok) {
+        error.textContent = data.error || response.statusText;
+        error.hidden = false;
+        return;
+    }
+    error.hidden = true;
+    followAudit(data);
+}
+
+async function route() {
+    const [name, query] = (location.hash.slice(1) || "runs").split("?");
+    showView(name);
+    if (name === "findings") {
+        const run = new URLSearchParams(query || "").get("run");
+        if (run) {
+            document.querySelector("#filters select[name=run]").value = run;
+        }
+        await loadFindings();
+    } else if (name === "reports") {
+        await loadReports();
+    }
+}
+
+document.getElementById("filters").addEventListener("submit", (event) => {
+    event.preventDefault();
+    loadFindings();
+});
+document.getElementById("audit-form").addEventListener("submit", (event) => {
+    event.preventDefault();
+    startAudit(event.target);
+});
+document.getElementById("token-form").addEventListener("submit", (event) => {
+    event.preventDefault();
+    sessionStorage.setItem(TOKEN_KEY, event.target.elements.token.value);
+    event.target.hidden = true;
+    loadPrincipal().then(loadRuns).then(route);
+});
+window.addEventListener("hashchange", route);
+loadPrincipal().then(loadRuns).then(route);
@@ -0,0 +1,165 @@
+"""API tokens of ``paddi serve``, stored hashed in ``data/tokens.db``.
+
+A token is shown once, when it is created; only its SHA-256 hash is stored,
+so a copy of the database does not give access to the server. Each token has
+a scope, the highest role it can act with (see :mod:`app.server.access`):
+
+    read   viewer: browse runs, findings, and reports, and follow audits
+    audit  operator: also start audits
+    admin  admin: also list and revoke tokens
+
+A token can be created for a user of ``[server.users]``, so it acts as that
+user.
+
+Once a token has been created, every request to the API and the reports needs
+one. Revoked tokens are kept, so revoking the last token does not open the
+server again.
+"""
+
+import hashlib
+import secrets
+import sqlite3
+from contextlib import contextmanager
+from dataclasses import dataclass
+from datetime import datetime, timezone
+from pathlib import Path
+from typing import Iterator, List, Optional, Tuple
+
+TOKENS_DB_NAME = "tokens.db"
+READ = "read"
+AUDIT = "audit"
+ADMIN = "admin"
+SCOPES = (READ, AUDIT, ADMIN)
+TOKEN_PREFIX = "paddi_"
+
+_SCHEMA = """
+CREATE TABLE IF NOT EXISTS api_tokens (
+    token_id TEXT PRIMARY KEY,
+    name TEXT NOT NULL,
+    token_hash TEXT NOT NULL UNIQUE,
+    scope TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    last_used_at TEXT,
+    revoked_at TEXT
+);
+"""
+# Columns added after the first release, added to existing databases on open
+_ADDED_COLUMNS = {"api_tokens": {"user": "TEXT"}}
+_COLUMNS = "token_id, name, scope, created_at, last_used_at, revoked_at, user"
+
+
+def _now() -> str:
+    return datetime.now(timezone.utc).isoformat(timespec="seconds")
+
+
+def _hash(token: str) -> str:
+    return hashlib.sha256(token.encode("utf-8")).hexdigest()
+
+
+@dataclass
+class ApiToken:
+    """A token as stored, without its secret."""
+
+    token_id: str
+    name: str
+    scope: str
+    created_at: str
+    last_used_at: Optional[str] = None
+    revoked_at: Optional[str] = None
+    user: Optional[str] = None
+
+
+class TokenStore:
+    """Creates, checks, and revokes API tokens in a local SQLite database."""
+
+    def __init__(self, path: Path):
+        """Initialize TokenStore, creating the database schema if needed."""
+        self.path = Path(path)
+        self.path.parent.mkdir(parents=True, exist_ok=True)
+        with self._connect() as conn:
+            conn.executescript(_SCHEMA)
+            for table, columns in _ADDED_COLUMNS.items():
+                existing = {row["name"] for row in conn.execute(f"PRAGMA table_info({table})")}
+                for name, column_type in columns.items():
+                    if name not in existing:
+                        conn.execute(f"ALTER TABLE {table} ADD COLUMN {name} {column_type}")
+
+    @contextmanager
+    def _connect(self) -> Iterator[sqlite3.Connection]:
+        """Open a connection that commits on success and is always closed."""
+        conn = sqlite3.connect(self.path)
+        conn.row_factory = sqlite3.Row
+        try:
+            with conn:
+                yield conn
+        finally:
+            conn.close()
+
+    def create(
+        self, name: str, scope: str = READ, user: Optional[str] = None
+    ) -> Tuple[ApiToken, str]:
+        """Create a token, acting as ``user`` if one is given.
+
+        Returns:
+            The stored token and its secret, which is not stored and cannot be shown again
+
+        Raises:
+            ValueError: If the name is empty or the scope is unknown
+        """
+        if not name:
+            raise ValueError("A token needs a name (e.g. serve token create ci)")
+        if scope not in SCOPES:
+            raise ValueError(f"Unknown token scope: {scope} (supported: {', '.join(SCOPES)})")
+        token = ApiToken(secrets.token_hex(4), name, scope, _now(), user=user)
+        secret = TOKEN_PREFIX + secrets.token_urlsafe(32)
+        with self._connect() as conn:
+            conn.execute(
+                "INSERT INTO api_tokens (token_id, name, token_hash, scope, created_at, user) "
+                "VALUES (?, ?, ?, ?, ?, ?)",
+                (token.token_id, name, _hash(secret), scope, token.created_at, user),
+            )
+        return token, secret
+
+    def tokens(self) -> List[ApiToken]:
+        """Return every token, revoked ones included, oldest first."""
+        with self._connect() as conn:
+            rows = conn.execute(
+                f"SELECT {_COLUMNS} FROM api_tokens ORDER BY created_at, token_id"  # nosec B608
+            ).fetchall()
+        return [ApiToken(**dict(row)) for row in rows]
+
+    def required(self) -> bool:
+        """Whether requests need a token, i.e. any token has been created."""
+        with self._connect() as conn:
+            return conn.execute("SELECT 1 FROM api_tokens LIMIT 1").fetchone() is not None
+
+    def authenticate(self, secret: str) -> Optional[ApiToken]:
+        """Return the active token with this secret, recording that it was used."""
+        digest = _hash(secret)
+        with self._connect() as conn:
+            row = conn.execute(
+                f"SELECT {_COLUMNS} FROM api_tokens "  # nosec B608
+                "WHERE token_hash = ? AND revoked_at IS NULL",
+                (digest,),
+            ).fetchone()
+            if row is None:
+                return None
+            token = ApiToken(**dict(row))
+            token.last_used_at = _now()
+            conn.execute(
+                "UPDATE api_tokens SET last_used_at = ? WHERE token_id = ?",
+                (token.last_used_at, token.token_id),
+            )
+        return token
+
+    def revoke(self, token_id: str) -> Optional[ApiToken]:
+        """Revoke a token by ID; returns None if there is no active token with that ID."""
+        revoked_at = _now()
+        with self._connect() as conn:
+            updated = conn.execute(
+                "UPDATE api_tokens SET revoked_at = ? WHERE token_id = ? AND revoked_at IS NULL",
+                (revoked_at, token_id),
+            ).rowcount
+        if not updated:
+            return None
+        return next(token for token in self.tokens() if token.token_id == token_id)
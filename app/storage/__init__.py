@@ -0,0 +1 @@
+"""Off-box storage for audit artifacts (collected data, findings, and reports)."""
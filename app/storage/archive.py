@@ -0,0 +1,254 @@
+"""Compressed, checksummed archives of a run's artifacts for long-term retention.
+
+Archives are written to ``[archive] dir`` (default ``archives/``), where
+``[retention]`` limits how many are kept. An archive is a tar stream
+compressed with zstd (``.tar.zst``). Its first member is ``manifest.json``,
+which lists every artifact with its size and SHA-256. A ``<archive>.sha256``
+file next to it holds the checksum of the archive itself, in ``sha256sum``
+format. Extraction verifies both before anything is written.
+
+Artifacts are stored under the same names as in
+:func:`~app.storage.base.run_artifacts`: data files at the top level and
+reports under ``reports/``.
+"""
+
+import hashlib
+import io
+import json
+import logging
+import tarfile
+from dataclasses import asdict, dataclass, field
+from datetime import datetime, timezone
+from pathlib import Path, PurePosixPath
+from typing import Any, Dict, List, Optional, Tuple
+
+from app.common.exceptions import DataValidationError
+from app.config.paddi_config import PaddiConfig
+
+from .base import run_artifacts
+
+logger = logging.getLogger(__name__)
+
+ARCHIVE_SUFFIX = ".tar.zst"
+DEFAULT_ARCHIVE_DIR = "archives"
+MANIFEST_NAME = "manifest.json"
+MANIFEST_FORMAT = 1
+DEFAULT_LEVEL = 19
+_CHUNK_SIZE = 1024 * 1024
+
+
+@dataclass
+class ArchiveEntry:
+    """One artifact in an archive."""
+
+    name: str
+    size: int
+    sha256: str
+
+
+@dataclass
+class ArchiveManifest:
+    """Contents of an archive, stored as its first member."""
+
+    created_at: str
+    files: List[ArchiveEntry] = field(default_factory=list)
+    format: int = MANIFEST_FORMAT
+    # "archive", or "bundle" for collected data moved between networks (app.storage.bundle)
+    kind: str = "archive"
+
+    def to_dict(self) -> Dict[str, Any]:
+        """Convert to dictionary for JSON serialization."""
+        return asdict(self)
+
+    @classmethod
+    def from_dict(cls, data: Dict[str, Any]) -> "ArchiveManifest":
+        """Create a manifest from its JSON form."""
+        return cls(
+            created_at=data.get("created_at", ""),
+            files=[ArchiveEntry(**entry) for entry in data.get("files", [])],
+            format=data.get("format", MANIFEST_FORMAT),
+            kind=data.get("kind", "archive"),
+        )
+
+
+def _zstd():
+    """Import the zstd bindings."""
+    try:
+        import zstandard
+    except ImportError as e:
+        raise ImportError("zstandard is not installed (pip install zstandard)") from e
+    return zstandard
+
+
+def _sha256(path: Path) -> str:
+    """Return the SHA-256 of a file."""
+    digest = hashlib.sha256()
+    with open(path, "rb") as f:
+        for chunk in iter(lambda: f.read(_CHUNK_SIZE), b""):
+            digest.update(chunk)
+    return digest.hexdigest()
+
+
+def archive_dir(config: Optional[PaddiConfig] = None) -> Path:
+    """Return the archive directory from ``[archive] dir`` in paddi.toml."""
+    return Path((config or PaddiConfig.load()).get("archive.dir", DEFAULT_ARCHIVE_DIR))
+
+
+def default_archive_path(directory: Path, when: Optional[datetime] = None) -> Path:
+    """Return ``<directory>/paddi-<UTC timestamp>.tar.zst``."""
+    timestamp = (when or datetime.now(timezone.utc)).strftime("%Y%m%dT%H%M%SZ")
+    return directory / f"paddi-{timestamp}{ARCHIVE_SUFFIX}"
+
+
+def checksum_path(archive: Path) -> Path:
+    """Return the path of an archive's ``.sha256`` file."""
+    return archive.with_name(archive.name + ".sha256")
+
+
+def create_archive(
+    archive: Path, data_dir: Path, output_dir: Path, level: int = DEFAULT_LEVEL
+) -> ArchiveManifest:
+    """Write the data files and reports of a run to a ``.tar.zst`` archive.
+
+    Args:
+        archive: Archive to create
+        data_dir: Directory with collected.json and explained.json
+        output_dir: Directory with the reports
+        level: zstd compression level (1-22)
+
+    Raises:
+        FileNotFoundError: If there are no artifacts to archive
+    """
+    artifacts = run_artifacts(data_dir, output_dir)
+    if not artifacts:
+        raise FileNotFoundError(f"No artifacts in {data_dir} or {output_dir} to archive")
+    return write_archive(archive, artifacts, level)
+
+
+def write_archive(
+    archive: Path,
+    artifacts: List[Tuple[str, Path]],
+    level: int = DEFAULT_LEVEL,
+    kind: str = "archive",
+) -> ArchiveManifest:
+    """Write ``(name, path)`` artifacts to a ``.tar.zst`` archive with a manifest and checksum.
+
+    Args:
+        archive: Archive to create
+        artifacts: Member names and the files to store under them
+        level: zstd compression level (1-22)
+        kind: Kind recorded in the manifest
+    """
+    manifest = ArchiveManifest(
+        created_at=datetime.now(timezone.utc).isoformat(),
+        files=[ArchiveEntry(name, path.stat().st_size, _sha256(path)) for name, path in artifacts],
+        kind=kind,
+    )
+    manifest_bytes = json.dumps(manifest.to_dict(), indent=2).encode("utf-8")
+
+    zstd = _zstd()
+    archive.parent.mkdir(parents=True, exist_ok=True)
+    with open(archive, "wb") as f:
+        with zstd.ZstdCompressor(level=level).stream_writer(f) as writer:
+            with tarfile.open(fileobj=writer, mode="w|") as tar:
+                info = tarfile.TarInfo(MANIFEST_NAME)
+                info.size = len(manifest_bytes)
+                tar.addfile(info, io.BytesIO(manifest_bytes))
+                for name, path in artifacts:
+                    tar.add(str(path), arcname=name, recursive=False)
+
+    checksum_path(archive).write_text(f"{_sha256(archive)}  {archive.name}\n", encoding="utf-8")
+    logger.info("Archived %d artifact(s) to %s", len(manifest.files), archive)
+    return manifest
+
+
+def _safe_name(name: str) -> bool:
+    """Return whether a member name stays inside the extraction directory."""
+    path = PurePosixPath(name)
+    return not path.is_absolute() and ".." not in path.parts and name != ""
+
+
+def _read_archive(archive: Path, dest: Optional[Path] = None) -> ArchiveManifest:
+    """Stream an archive, checking each member against the manifest (and writing it to dest)."""
+    zstd = _zstd()
+    problems = []
+    manifest = None
+    seen = set()
+    with open(archive, "rb") as f:
+        with zstd.ZstdDecompressor().stream_reader(f) as reader:
+            with tarfile.open(fileobj=reader, mode="r|") as tar:
+                for member in tar:
+                    source = tar.extractfile(member) if member.isfile() else None
+                    if manifest is None:
+                        if member.name != MANIFEST_NAME or source is None:
+                            raise DataValidationError(
+                                str(archive), {"errors": [f"first member is not {MANIFEST_NAME}"]}
+                            )
+                        manifest = ArchiveManifest.from_dict(json.loads(source.read()))
+                        expected = {entry.name: entry for entry in manifest.files}
+                        continue
+
+                    entry = expected.get(member.name)
+                    if source is None or entry is None or not _safe_name(member.name):
+                        problems.append(f"{member.name}: not listed in the manifest")
+                        continue
+                    digest = hashlib.sha256()
+                    out = None
+                    if dest is not None:
+                        target = dest / member.name
+                        target.parent.mkdir(parents=True, exist_ok=True)
+                        out = open(target, "wb")
+                    try:
+                        for chunk in iter(lambda: source.read(_CHUNK_SIZE), b""):
+                            digest.update(chunk)
+                            if out:
+                                out.write(chunk)
+                    finally:
+                        if out:
+                            out.close()
+                    seen.add(member.name)
+                    if digest.hexdigest() != entry.sha256:
+                        problems.append(f"{member.name}: checksum mismatch")
+
+    if manifest is None:
+        raise DataValidationError(str(archive), {"errors": ["archive is empty"]})
+    problems.extend(f"{entry.name}: missing" for entry in manifest.files if entry.name not in seen)
+    if problems:
+        raise DataValidationError(str(archive), {"errors": problems})
+    return manifest
+
+
+def verify_archive(archive: Path) -> ArchiveManifest:
+    """Check an archive against its ``.sha256`` file (if present) and its manifest.
+
+    Raises:
+        FileNotFoundError: If the archive does not exist
+        DataValidationError: If a checksum does not match or a file is missing
+    """
+    if not archive.is_file():
+        raise FileNotFoundError(f"Archive not found: {archive}")
+
+    sidecar = checksum_path(archive)
+    if sidecar.is_file():
+        expected = sidecar.read_text(encoding="utf-8").split()[0]
+        if _sha256(archive) != expected:
+            raise DataValidationError(
+                str(archive), {"errors": [f"archive does not match {sidecar.name}"]}
+            )
+    else:
+        logger.warning("No %s; checking the manifest only", sidecar.name)
+    return _read_archive(archive)
+
+
+def extract_archive(archive: Path, dest: Path) -> ArchiveManifest:
+    """Verify an archive, then extract its artifacts into dest.
+
+    Raises:
+        FileNotFoundError: If the archive does not exist
+        DataValidationError: If the archive fails verification
+    """
+    verify_archive(archive)
+    dest.mkdir(parents=True, exist_ok=True)
+    manifest = _read_archive(archive, dest)
+    logger.info("Extracted %d artifact(s) to %s", len(manifest.files), dest)
+    return manifest
@@ -0,0 +1,120 @@
+"""Base module for artifact storage backends."""
+
+import logging
+from abc import ABC, abstractmethod
+from dataclasses import dataclass
+from datetime import datetime, timezone
+from pathlib import Path
+from typing import List, Optional, Tuple
+
+from app.common.exceptions import ConfigurationError
+
+logger = logging.getLogger(__name__)
+
+DATA_FILES = ("collected.json", "explained.json")
+DEFAULT_PREFIX = "paddi"
+
+# Longest lifetime accepted for signed URLs by both GCS (V4) and S3
+MAX_SIGNED_URL_EXPIRATION = 7 * 24 * 3600
+
+
+@dataclass
+class UploadedArtifact:
+    """An artifact stored in the backend."""
+
+    name: str
+    uri: str
+    signed_url: Optional[str] = None
+
+
+def run_prefix(prefix: str, project_id: Optional[str], when: Optional[datetime] = None) -> str:
+    """Return the object prefix for one run."""
+    timestamp = (when or datetime.now(timezone.utc)).strftime("%Y%m%dT%H%M%SZ")
+    parts = [prefix.strip("/"), project_id or "unknown-project", timestamp]
+    return "/".join(part for part in parts if part)
+
+
+def run_artifacts(data_dir: Path, output_dir: Path) -> List[Tuple[str, Path]]:
+    """List ``(object name, local path)`` pairs for a run's artifacts."""
+    artifacts = []
+    for name in DATA_FILES:
+        path = Path(data_dir) / name
+        if path.is_file():
+            artifacts.append((name, path))
+
+    output_dir = Path(output_dir)
+    if output_dir.is_dir():
+        for path in sorted(output_dir.rglob("*")):
+            if path.is_file():
+                artifacts.append((f"reports/{path.relative_to(output_dir).as_posix()}", path))
+    return artifacts
+
+
+def signed_url_expiration(section: dict, config_item: str) -> int:
+    """Read and validate ``signed_url_expiration`` from a storage config section."""
+    try:
+        expiration = int(section.get("signed_url_expiration", 3600))
+    except (TypeError, ValueError) as e:
+        raise ConfigurationError(config_item, {"error": str(e)}) from e
+    if not 0 < expiration <= MAX_SIGNED_URL_EXPIRATION:
+        raise ConfigurationError(config_item, {"error": "must be between 1 second and 7 days"})
+    return expiration
+
+
+class Storage(ABC):
+    """Abstract base class for artifact storage backends."""
+
+    def __init__(
+        self,
+        bucket: str,
+        prefix: str = DEFAULT_PREFIX,
+        signed_urls: bool = False,
+        signed_url_expiration: int = 3600,
+    ):
+        """Initialize the backend.
+
+        Args:
+            bucket: Bucket name
+            prefix: Object prefix for all runs
+            signed_urls: Generate a signed GET URL for each upload
+            signed_url_expiration: Lifetime of signed URLs in seconds
+        """
+        self.bucket_name = bucket
+        self.prefix = prefix
+        self.signed_urls = signed_urls
+        self.signed_url_expiration = signed_url_expiration
+
+    @abstractmethod
+    def get_name(self) -> str:
+        """Return the name of the backend."""
+
+    @abstractmethod
+    def uri(self, key: str) -> str:
+        """Return the URI of an object, e.g. ``gs://bucket/key``."""
+
+    @abstractmethod
+    def put(self, key: str, path: Path) -> None:
+        """Upload a local file to ``key``."""
+
+    @abstractmethod
+    def signed_url(self, key: str) -> str:
+        """Return a signed GET URL for ``key``."""
+
+    def upload_run(
+        self, data_dir: Path, output_dir: Path, project_id: Optional[str] = None
+    ) -> List[UploadedArtifact]:
+        """Upload the data files and reports of a run under a new run prefix."""
+        base = run_prefix(self.prefix, project_id)
+        uploaded = []
+
+        for name, path in run_artifacts(data_dir, output_dir):
+            key = f"{base}/{name}"
+            self.put(key, path)
+            artifact = UploadedArtifact(name, self.uri(key))
+            if self.signed_urls:
+                artifact.signed_url = self.signed_url(key)
+            uploaded.append(artifact)
+            logger.debug("Uploaded %s to %s", path, artifact.uri)
+
+        logger.info("Uploaded %d artifact(s) to %s/", len(uploaded), self.uri(base))
+        return uploaded
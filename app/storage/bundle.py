@@ -0,0 +1,67 @@
+"""Bundles of collected data for air-gapped environments.
+
+When collection and analysis happen on separate networks, ``paddi bundle
+export`` packages ``collected.json`` on the collecting machine, and ``paddi
+bundle import`` unpacks it on a connected machine, where it is analyzed and
+reported. A bundle is an archive (see :mod:`app.storage.archive`) whose
+manifest has ``kind = "bundle"``: a zstd-compressed tar with a ``manifest.json``
+of SHA-256 checksums and a ``.sha256`` file next to it, both verified on import.
+
+An encrypted ``collected.json`` (``[security] age_recipients``) is bundled as it
+is, so the data stays encrypted in transit.
+"""
+
+import logging
+from datetime import datetime, timezone
+from pathlib import Path
+from typing import Optional
+
+from app.common.exceptions import DataValidationError
+
+from .archive import ARCHIVE_SUFFIX, ArchiveManifest, extract_archive, verify_archive, write_archive
+
+logger = logging.getLogger(__name__)
+
+BUNDLE_KIND = "bundle"
+BUNDLE_FILES = ("collected.json",)
+
+
+def default_bundle_path(directory: Path = Path("."), when: Optional[datetime] = None) -> Path:
+    """Return ``<directory>/paddi-bundle-<UTC timestamp>.tar.zst``."""
+    timestamp = (when or datetime.now(timezone.utc)).strftime("%Y%m%dT%H%M%SZ")
+    return directory / f"paddi-bundle-{timestamp}{ARCHIVE_SUFFIX}"
+
+
+def export_bundle(bundle: Path, data_dir: Path) -> ArchiveManifest:
+    """Package the collected data in data_dir for transfer.
+
+    Raises:
+        FileNotFoundError: If there is no collected.json to bundle
+    """
+    artifacts = [(name, Path(data_dir) / name) for name in BUNDLE_FILES]
+    missing = [str(path) for _, path in artifacts if not path.is_file()]
+    if missing:
+        raise FileNotFoundError(
+            f"Nothing to bundle: {', '.join(missing)} not found (run 'paddi collect' first)"
+        )
+    manifest = write_archive(bundle, artifacts, kind=BUNDLE_KIND)
+    logger.info("Bundled %d file(s) to %s", len(manifest.files), bundle)
+    return manifest
+
+
+def import_bundle(bundle: Path, data_dir: Path) -> ArchiveManifest:
+    """Verify a bundle and unpack its collected data into data_dir.
+
+    Files of the same name in data_dir are replaced, as a new collection would.
+
+    Raises:
+        FileNotFoundError: If the bundle does not exist
+        DataValidationError: If the bundle fails verification or is not a bundle
+    """
+    manifest = verify_archive(bundle)
+    if manifest.kind != BUNDLE_KIND:
+        raise DataValidationError(
+            str(bundle),
+            {"errors": [f"not a bundle (kind: {manifest.kind}); use 'paddi archive extract'"]},
+        )
+    return extract_archive(bundle, Path(data_dir))
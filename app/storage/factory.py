@@ -0,0 +1,34 @@
+"""Factory module for creating the configured storage backends."""
+
+from typing import List, Optional
+
+from app.config.paddi_config import PaddiConfig
+
+from .base import Storage
+from .gcs import GCSStorage
+from .s3 import S3Storage
+
+
+class StorageFactory:
+    """Factory class for creating storage backends."""
+
+    _backends = {
+        "gcs": GCSStorage,
+        "s3": S3Storage,
+    }
+
+    @classmethod
+    def from_config(cls, config: Optional[PaddiConfig] = None) -> List[Storage]:
+        """Create every backend that has a bucket in ``[storage.<name>]``."""
+        config = config or PaddiConfig.load()
+        backends = []
+        for backend_class in cls._backends.values():
+            backend = backend_class.from_config(config)
+            if backend is not None:
+                backends.append(backend)
+        return backends
+
+    @classmethod
+    def get_supported_backends(cls) -> List[str]:
+        """Return the names of supported backends."""
+        return list(cls._backends.keys())
@@ -0,0 +1,96 @@
+"""Upload audit artifacts to Google Cloud Storage.
+
+Configured in paddi.toml::
+
+    [storage.gcs]
+    bucket = "my-audit-artifacts"
+    prefix = "paddi"              # optional, default "paddi"
+    signed_urls = true            # print a signed GET URL for each upload
+    signed_url_expiration = 3600  # seconds
+
+Each run is uploaded under ``<prefix>/<project_id>/<UTC timestamp>/``, with the
+data files at the top level and reports under ``reports/``.
+"""
+
+from datetime import timedelta
+from pathlib import Path
+from typing import Any, Optional
+
+from app.config.paddi_config import PaddiConfig
+
+from .base import DEFAULT_PREFIX, Storage, signed_url_expiration
+
+
+class GCSStorage(Storage):
+    """Stores run artifacts in a GCS bucket."""
+
+    def __init__(self, bucket: str, client: Any = None, **kwargs):
+        """Initialize GCSStorage.
+
+        Args:
+            bucket: Bucket name
+            client: ``google.cloud.storage.Client`` (created on first use if omitted)
+            **kwargs: Options of ``Storage``
+        """
+        super().__init__(bucket, **kwargs)
+        self._client = client
+        self._bucket = None
+
+    @classmethod
+    def from_config(cls, config: Optional[PaddiConfig] = None) -> Optional["GCSStorage"]:
+        """Create a backend from the ``[storage.gcs]`` section of paddi.toml.
+
+        Returns:
+            None when no bucket is configured
+
+        Raises:
+            ConfigurationError: If the signed URL expiration is invalid
+        """
+        section = (config or PaddiConfig.load()).section("storage.gcs")
+        if not section.get("bucket"):
+            return None
+
+        return cls(
+            bucket=section["bucket"],
+            prefix=section.get("prefix", DEFAULT_PREFIX),
+            signed_urls=bool(section.get("signed_urls", False)),
+            signed_url_expiration=signed_url_expiration(
+                section, "storage.gcs.signed_url_expiration"
+            ),
+        )
+
+    @property
+    def client(self) -> Any:
+        """Return the storage client."""
+        if self._client is None:
+            from google.cloud import storage
+
+            self._client = storage.Client()
+        return self._client
+
+    @property
+    def bucket(self) -> Any:
+        """Return the bucket handle."""
+        if self._bucket is None:
+            self._bucket = self.client.bucket(self.bucket_name)
+        return self._bucket
+
+    def get_name(self) -> str:
+        """Return the name of the backend."""
+        return "gcs"
+
+    def uri(self, key: str) -> str:
+        """Return the ``gs://`` URI of an object."""
+        return f"gs://{self.bucket_name}/{key}"
+
+    def put(self, key: str, path: Path) -> None:
+        """Upload a local file to ``key``."""
+        self.bucket.blob(key).upload_from_filename(str(path))
+
+    def signed_url(self, key: str) -> str:
+        """Return a V4 signed GET URL for ``key``."""
+        return self.bucket.blob(key).generate_signed_url(
+            version="v4",
+            expiration=timedelta(seconds=self.signed_url_expiration),
+            method="GET",
+        )
@@ -0,0 +1,128 @@
+"""Upload audit artifacts to Amazon S3 or an S3-compatible service such as MinIO.
+
+Configured in paddi.toml::
+
+    [storage.s3]
+    bucket = "my-audit-artifacts"
+    prefix = "paddi"
+    region = "ap-northeast-1"
+    endpoint_url = "https://minio.example.com"  # only for S3-compatible services
+    profile = "audit"                           # or access_key_env / secret_key_env
+
+Without ``profile`` or key variables, credentials come from the standard AWS
+chain (``AWS_ACCESS_KEY_ID``/``AWS_SECRET_ACCESS_KEY``, shared config, or an
+instance role).
+"""
+
+import os
+from pathlib import Path
+from typing import Any, Dict, Optional
+
+from app.common.exceptions import ConfigurationError
+from app.config.paddi_config import PaddiConfig
+
+from .base import DEFAULT_PREFIX, Storage, signed_url_expiration
+
+
+class S3Storage(Storage):
+    """Stores run artifacts in an S3 bucket."""
+
+    def __init__(
+        self,
+        bucket: str,
+        client: Any = None,
+        region: Optional[str] = None,
+        endpoint_url: Optional[str] = None,
+        profile: Optional[str] = None,
+        credentials: Optional[Dict[str, str]] = None,
+        **kwargs,
+    ):
+        """Initialize S3Storage.
+
+        Args:
+            bucket: Bucket name
+            client: boto3 S3 client (created on first use if omitted)
+            region: AWS region
+            endpoint_url: Endpoint of an S3-compatible service
+            profile: Named profile from the AWS shared config
+            credentials: ``aws_access_key_id`` and ``aws_secret_access_key``
+            **kwargs: Options of ``Storage``
+        """
+        super().__init__(bucket, **kwargs)
+        self._client = client
+        self.region = region
+        self.endpoint_url = endpoint_url
+        self.profile = profile
+        self.credentials = credentials or {}
+
+    @classmethod
+    def from_config(cls, config: Optional[PaddiConfig] = None) -> Optional["S3Storage"]:
+        """Create a backend from the ``[storage.s3]`` section of paddi.toml.
+
+        Returns:
+            None when no bucket is configured
+
+        Raises:
+            ConfigurationError: If a credential variable is unset or an option is invalid
+        """
+        section = (config or PaddiConfig.load()).section("storage.s3")
+        if not section.get("bucket"):
+            return None
+
+        credentials = {}
+        key_envs = (section.get("access_key_env"), section.get("secret_key_env"))
+        if any(key_envs):
+            if section.get("profile"):
+                raise ConfigurationError(
+                    "storage.s3", {"error": "set either profile or access_key_env/secret_key_env"}
+                )
+            for option, env in zip(("aws_access_key_id", "aws_secret_access_key"), key_envs):
+                if not env or not os.getenv(env):
+                    raise ConfigurationError(
+                        "storage.s3",
+                        {"error": f"environment variable {env or option} is not set"},
+                    )
+                credentials[option] = os.environ[env]
+
+        return cls(
+            bucket=section["bucket"],
+            region=section.get("region"),
+            endpoint_url=section.get("endpoint_url"),
+            profile=section.get("profile"),
+            credentials=credentials,
+            prefix=section.get("prefix", DEFAULT_PREFIX),
+            signed_urls=bool(section.get("signed_urls", False)),
+            signed_url_expiration=signed_url_expiration(
+                section, "storage.s3.signed_url_expiration"
+            ),
+        )
+
+    @property
+    def client(self) -> Any:
+        """Return the S3 client."""
+        if self._client is None:
+            import boto3
+
+            session = boto3.Session(profile_name=self.profile, region_name=self.region)
+            self._client = session.client("s3", endpoint_url=self.endpoint_url, **self.credentials)
+        return self._client
+
+    def get_name(self) -> str:
+        """Return the name of the backend."""
+        return "s3"
+
+    def uri(self, key: str) -> str:
+        """Return the ``s3://`` URI of an object."""
+        return f"s3://{self.bucket_name}/{key}"
+
+    def put(self, key: str, path: Path) -> None:
+        """Upload a local file to ``key``."""
+        self.client.upload_file(str(path), self.bucket_name, key)
+
+    def signed_url(self, key: str) -> str:
+        """Return a presigned GET URL for ``key``."""
+        return self.client.generate_presigned_url(
+            "get_object",
+            Params={"Bucket": self.bucket_name, "Key": key},
+            ExpiresIn=self.signed_url_expiration,
+        )
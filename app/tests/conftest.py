@@ -0,0 +1,22 @@
+"""Fixtures shared by the tests."""
+
+import pytest
+
+
+@pytest.fixture
+def make_finding():
+    """Return a factory of finding dicts for a public bucket, with fields overridden by keyword."""
+
+    def make(**overrides):
+        finding = {
+            "title": "Public bucket",
+            "severity": "MEDIUM",
+            "explanation": "Bucket is public",
+            "recommendation": "Remove allUsers",
+            "category": "PUBLIC_BUCKET",
+            "resource": "//storage.googleapis.com/prod-assets",
+        }
+        finding.update(overrides)
+        return finding
+
+    return make
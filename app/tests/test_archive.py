@@ -0,0 +1,178 @@
+"""Tests for run artifact archives."""
+
+import io
+import sys
+import tarfile
+import types
+
+from unittest.mock import patch
+
+import pytest
+
+from app.cli.base import CommandContext
+from app.cli.commands import ArchiveCommand, BundleCommand
+from app.common.exceptions import DataValidationError
+from app.storage.archive import (
+    MANIFEST_NAME,
+    checksum_path,
+    create_archive,
+    extract_archive,
+    verify_archive,
+)
+from app.storage.bundle import export_bundle, import_bundle
+
+
+class _Passthrough(io.RawIOBase):
+    """Stream wrapper standing in for zstd compression in tests."""
+
+    def __init__(self, raw):
+        super().__init__()
+        self.raw = raw
+
+    def writable(self):
+        return True
+
+    def readable(self):
+        return True
+
+    def write(self, data):
+        return self.raw.write(data)
+
+    def read(self, size=-1):
+        return self.raw.read(size)
+
+    def close(self):
+        self.raw.close()
+        super().close()
+
+
+@pytest.fixture(autouse=True)
+def zstandard(monkeypatch):
+    """Install a stand-in for the zstandard bindings."""
+    module = types.ModuleType("zstandard")
+    module.ZstdCompressor = lambda level=3: types.SimpleNamespace(stream_writer=_Passthrough)
+    module.ZstdDecompressor = lambda: types.SimpleNamespace(stream_reader=_Passthrough)
+    monkeypatch.setitem(sys.modules, "zstandard", module)
+    return module
+
+
+@pytest.fixture
+def run(tmp_path):
+    """A run with data files and a report."""
+    data_dir = tmp_path / "data"
+    output_dir = tmp_path / "output"
+    data_dir.mkdir()
+    (output_dir / "charts").mkdir(parents=True)
+    (data_dir / "collected.json").write_text('{"iam_policies": []}', encoding="utf-8")
+    (data_dir / "explained.json").write_text("[]", encoding="utf-8")
+    (output_dir / "audit.md").write_text("# Audit\n", encoding="utf-8")
+    (output_dir / "charts" / "severity.svg").write_text("<svg/>", encoding="utf-8")
+    return data_dir, output_dir
+
+
+def test_create_and_extract(run, tmp_path):
+    """Test that extraction restores every artifact after verification."""
+    archive = tmp_path / "evidence" / "run.tar.zst"
+
+    manifest = create_archive(archive, *run)
+
+    assert [entry.name for entry in manifest.files] == [
+        "collected.json",
+        "explained.json",
+        "reports/audit.md",
+        "reports/charts/severity.svg",
+    ]
+    assert checksum_path(archive).read_text(encoding="utf-8").endswith("  run.tar.zst\n")
+    dest = tmp_path / "restored"
+    extract_archive(archive, dest)
+    assert (dest / "collected.json").read_text(encoding="utf-8") == '{"iam_policies": []}'
+    assert (dest / "reports" / "charts" / "severity.svg").read_text(encoding="utf-8") == "<svg/>"
+
+
+def test_verify_detects_tampering(run, tmp_path):
+    """Test that a changed archive fails the .sha256 check."""
+    archive = tmp_path / "run.tar.zst"
+    create_archive(archive, *run)
+    with open(archive, "ab") as f:
+        f.write(b"\0" * 512)
+
+    with pytest.raises(DataValidationError) as exc:
+        verify_archive(archive)
+    assert "run.tar.zst.sha256" in exc.value.details["errors"][0]
+
+
+def test_verify_manifest_mismatch(run, tmp_path):
+    """Test that members are checked against the manifest when there is no .sha256 file."""
+    archive = tmp_path / "run.tar.zst"
+    with tarfile.open(archive, "w") as tar:
+        manifest = b'{"created_at": "", "files": [{"name": "explained.json", "size": 2, '
+        manifest += b'"sha256": "0000"}, {"name": "collected.json", "size": 1, "sha256": "00"}]}'
+        for name, data in [(MANIFEST_NAME, manifest), ("explained.json", b"[]")]:
+            info = tarfile.TarInfo(name)
+            info.size = len(data)
+            tar.addfile(info, io.BytesIO(data))
+
+    with pytest.raises(DataValidationError) as exc:
+        extract_archive(archive, tmp_path / "restored")
+    assert exc.value.details["errors"] == [
+        "explained.json: checksum mismatch",
+        "collected.json: missing",
+    ]
+    assert not (tmp_path / "restored").exists()
+
+
+def test_command(run, tmp_path, monkeypatch, capsys):
+    """Test archive create and extract through the command."""
+    data_dir, output_dir = run
+    monkeypatch.chdir(tmp_path)
+
+    ArchiveCommand().execute(
+        CommandContext(action="create", target="run.tar.zst", output_dir=str(output_dir))
+    )
+    ArchiveCommand().execute(CommandContext(action="extract", target="run.tar.zst", output_dir=""))
+
+    assert "Archived 4 artifact(s)" in capsys.readouterr().out
+    assert (tmp_path / "run" / "reports" / "audit.md").exists()
+
+
+def test_bundle_holds_collected_data_only(run, tmp_path):
+    """Test that a bundle carries collected.json and is refused by import if it is an archive."""
+    data_dir, output_dir = run
+    bundle = tmp_path / "transfer.tar.zst"
+
+    manifest = export_bundle(bundle, data_dir)
+
+    assert (manifest.kind, [entry.name for entry in manifest.files]) == (
+        "bundle",
+        ["collected.json"],
+    )
+    archive = tmp_path / "run.tar.zst"
+    create_archive(archive, data_dir, output_dir)
+    with pytest.raises(DataValidationError):
+        import_bundle(archive, tmp_path / "analysis")
+    with pytest.raises(FileNotFoundError):
+        export_bundle(tmp_path / "empty.tar.zst", tmp_path / "nothing")
+
+
+@patch("app.cli.commands.ReportCommand.execute")
+@patch("app.cli.commands.ExplainCommand.execute")
+def test_bundle_command(explain, report, run, tmp_path, monkeypatch):
+    """Test that bundle import unpacks into data/ and then analyzes and reports."""
+    data_dir, _ = run
+    export_bundle(tmp_path / "transfer.tar.zst", data_dir)
+    analysis = tmp_path / "analysis"
+    analysis.mkdir()
+    monkeypatch.chdir(analysis)
+
+    BundleCommand().execute(CommandContext(action="import", target="../transfer.tar.zst"))
+
+    assert (analysis / "data" / "collected.json").read_text(encoding="utf-8") == (
+        '{"iam_policies": []}'
+    )
+    explain.assert_called_once()
+    report.assert_called_once()
+
+    BundleCommand().execute(
+        CommandContext(action="import", target="../transfer.tar.zst", skip_run=True)
+    )
+    explain.assert_called_once()
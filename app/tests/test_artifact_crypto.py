@@ -0,0 +1,108 @@
+"""Tests for age encryption of artifacts at rest."""
+
+import json
+import sys
+import types
+
+import pytest
+
+from app.common.artifact_crypto import (
+    AGE_HEADER,
+    AGE_IDENTITY_ENV,
+    AgeCipher,
+    is_age_encrypted,
+    load_artifact,
+    save_artifact,
+)
+from app.common.exceptions import ConfigurationError
+from app.config.paddi_config import PaddiConfig
+from app.findings.listing import load_findings
+
+RECIPIENT = "age1recipient"
+IDENTITY = "AGE-SECRET-KEY-1IDENTITY"
+
+
+@pytest.fixture
+def pyrage(monkeypatch):
+    """Install a stand-in for the pyrage bindings; IDENTITY decrypts for RECIPIENT."""
+    module = types.ModuleType("pyrage")
+    module.x25519 = types.SimpleNamespace(
+        Recipient=types.SimpleNamespace(from_str=lambda key: key),
+        Identity=types.SimpleNamespace(from_str=lambda key: key),
+    )
+
+    def encrypt(data, recipients):
+        return AGE_HEADER + b"\n" + ",".join(recipients).encode() + b"\n" + data[::-1]
+
+    def decrypt(data, identities):
+        _, recipients, body = data.split(b"\n", 2)
+        if RECIPIENT.encode() not in recipients.split(b",") or IDENTITY not in identities:
+            raise ValueError("no matching keys")
+        return body[::-1]
+
+    module.encrypt = encrypt
+    module.decrypt = decrypt
+    monkeypatch.setitem(sys.modules, "pyrage", module)
+    monkeypatch.delenv(AGE_IDENTITY_ENV, raising=False)
+    return module
+
+
+def test_round_trip(pyrage, tmp_path):
+    """Test that an encrypted artifact is unreadable as JSON and loads transparently."""
+    path = tmp_path / "explained.json"
+    findings = [{"title": "Public bucket", "severity": "HIGH"}]
+
+    save_artifact(path, findings, AgeCipher([RECIPIENT]), indent=2)
+
+    assert is_age_encrypted(path.read_bytes())
+    assert load_artifact(path, AgeCipher(identities=[IDENTITY])) == findings
+
+
+def test_plaintext_without_recipients(tmp_path):
+    """Test that artifacts stay plain JSON when no recipients are configured."""
+    path = tmp_path / "collected.json"
+    save_artifact(path, {"iam_policies": []}, AgeCipher())
+    assert json.loads(path.read_text(encoding="utf-8")) == {"iam_policies": []}
+    assert load_artifact(path, AgeCipher()) == {"iam_policies": []}
+
+
+def test_from_config_identity_file(pyrage, tmp_path, monkeypatch):
+    """Test that readers such as load_findings decrypt with the configured identity."""
+    identity_file = tmp_path / "age.key"
+    identity_file.write_text(f"# created: today\n{IDENTITY}\n", encoding="utf-8")
+    config = PaddiConfig(
+        {
+            "security": {
+                "age_recipients": [RECIPIENT],
+                "age_identity_file": str(identity_file),
+            }
+        }
+    )
+    monkeypatch.setattr(PaddiConfig, "load", classmethod(lambda cls, *a, **k: config))
+    path = tmp_path / "explained.json"
+
+    save_artifact(path, [{"title": "t"}])
+
+    assert is_age_encrypted(path.read_bytes())
+    assert load_findings(str(path)) == [{"title": "t"}]
+
+
+def test_identity_from_env(pyrage, monkeypatch):
+    """Test that PADDI_AGE_IDENTITY takes precedence over the identity file."""
+    monkeypatch.setenv(AGE_IDENTITY_ENV, IDENTITY)
+    cipher = AgeCipher.from_config(PaddiConfig({"security": {"age_identity_file": "/missing"}}))
+    assert cipher.identities == [IDENTITY]
+
+
+def test_decrypt_errors(pyrage, tmp_path):
+    """Test missing and wrong identities."""
+    path = tmp_path / "explained.json"
+    save_artifact(path, [], AgeCipher([RECIPIENT]))
+
+    with pytest.raises(ConfigurationError) as exc:
+        load_artifact(path, AgeCipher())
+    assert AGE_IDENTITY_ENV in exc.value.details["error"]
+
+    with pytest.raises(ConfigurationError) as exc:
+        load_artifact(path, AgeCipher(identities=["AGE-SECRET-KEY-1OTHER"]))
+    assert "cannot decrypt" in exc.value.details["error"]
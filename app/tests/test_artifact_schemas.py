@@ -0,0 +1,139 @@
+"""Tests for the JSON Schemas of pipeline artifacts and their validation."""
+
+import json
+from pathlib import Path
+
+import jsonschema
+import pytest
+
+from app.cli.base import CommandContext
+from app.cli.commands import ReportCommand, SchemaCommand, ValidateDataCommand
+from app.common.artifact_schemas import (
+    artifact_schema,
+    collected_schema,
+    explained_schema,
+    export_schemas,
+    validate_artifact,
+    validate_file,
+)
+from app.common.exceptions import DataValidationError
+from app.common.models import SecurityFinding
+
+SAMPLE_COLLECTED = Path(__file__).resolve().parents[2] / "data" / "sample_collected.json"
+
+
+class TestArtifactSchemas:
+    """Tests for schema generation."""
+
+    def test_schemas_are_valid(self):
+        """Test that both schemas are valid JSON Schema documents."""
+        jsonschema.Draft202012Validator.check_schema(collected_schema())
+        jsonschema.Draft202012Validator.check_schema(explained_schema())
+
+    def test_explained_derived_from_finding(self):
+        """Test that findings written by SecurityFinding validate, and required fields match."""
+        finding = SecurityFinding(
+            title="Public bucket",
+            severity="HIGH",
+            explanation="Anyone can read it",
+            recommendation="Remove allUsers",
+            cvss_score=7.5,
+        )
+        schema = explained_schema()
+        jsonschema.validate([finding.to_dict()], schema)
+
+        assert schema["items"]["required"] == ["title", "severity", "explanation", "recommendation"]
+        with pytest.raises(jsonschema.ValidationError):
+            jsonschema.validate([{"title": "No severity"}], schema)
+
+    def test_collected_shapes(self):
+        """Test the sample data and the multi-cloud shape."""
+        schema = collected_schema()
+        jsonschema.validate(json.loads(SAMPLE_COLLECTED.read_text(encoding="utf-8")), schema)
+        jsonschema.validate({"providers": [{"provider": "aws", "security_findings": []}]}, schema)
+
+        with pytest.raises(jsonschema.ValidationError):
+            jsonschema.validate({"metadata": {}}, schema)
+
+    def test_unknown_artifact(self):
+        """Test the error for unknown names."""
+        assert artifact_schema("explained.json")["title"] == "explained.json"
+        with pytest.raises(ValueError):
+            artifact_schema("reported")
+
+
+class TestSchemaCommand:
+    """Tests for paddi schema export."""
+
+    def test_export_to_directory(self, tmp_path):
+        """Test writing one file per artifact."""
+        SchemaCommand().execute(CommandContext(action="export", output_dir=str(tmp_path)))
+
+        assert sorted(p.name for p in tmp_path.iterdir()) == [
+            "collected.schema.json",
+            "explained.schema.json",
+        ]
+        assert export_schemas(tmp_path / "one", ["collected"]) == [
+            tmp_path / "one" / "collected.schema.json"
+        ]
+
+    def test_export_to_stdout(self, capsys):
+        """Test printing one schema."""
+        SchemaCommand().execute(CommandContext(action="export", target="explained", output_dir=""))
+
+        assert json.loads(capsys.readouterr().out)["$id"] == "urn:paddi:schema:explained"
+
+
+class TestValidateData:
+    """Tests for validate_artifact and paddi validate-data."""
+
+    @pytest.fixture
+    def data_dir(self, tmp_path, monkeypatch):
+        """Run in a directory with a data/ folder."""
+        monkeypatch.chdir(tmp_path)
+        (tmp_path / "data").mkdir()
+        return tmp_path / "data"
+
+    def test_error_paths(self):
+        """Test that errors name the JSON path of the bad value."""
+        findings = [
+            {"title": "a", "severity": "HIGH", "explanation": "e", "recommendation": "r"},
+            {"title": "b", "severity": 3, "explanation": "e"},
+        ]
+        assert validate_artifact(findings, "explained") == [
+            "$[1]: 'recommendation' is a required property",
+            "$[1].severity: 3 is not of type 'string'",
+        ]
+
+    def test_invalid_json(self, tmp_path):
+        """Test that syntax errors are reported with their line."""
+        path = tmp_path / "explained.json"
+        path.write_text('[\n  {"title": }\n]', encoding="utf-8")
+        assert validate_file(path)[0].startswith("$: invalid JSON at line 2")
+
+    def test_command(self, data_dir, capsys):
+        """Test the default files, JSON output, and the failure."""
+        (data_dir / "collected.json").write_text(SAMPLE_COLLECTED.read_text(encoding="utf-8"))
+        (data_dir / "explained.json").write_text('[{"title": "x"}]', encoding="utf-8")
+
+        with pytest.raises(DataValidationError) as exc:
+            ValidateDataCommand().execute(CommandContext(output_format="json"))
+
+        results = json.loads(capsys.readouterr().out)
+        assert [(r["schema"], r["valid"]) for r in results] == [
+            ("collected", True),
+            ("explained", False),
+        ]
+        assert exc.value.path == str(Path("data/explained.json"))
+
+    def test_report_stops_on_invalid_findings(self, data_dir, monkeypatch):
+        """Test that report checks its input before the reporter runs."""
+        (data_dir / "explained.json").write_text('{"title": "not a list"}', encoding="utf-8")
+        calls = []
+        monkeypatch.setattr("app.cli.commands.reporter_main", lambda **kw: calls.append(kw))
+
+        with pytest.raises(DataValidationError) as exc:
+            ReportCommand().execute(CommandContext())
+
+        assert exc.value.details["errors"] == ["$: {'title': 'not a list'} is not of type 'array'"]
+        assert calls == []
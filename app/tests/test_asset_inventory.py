@@ -0,0 +1,119 @@
+"""Tests for the Cloud Asset Inventory collector."""
+
+from unittest.mock import patch
+
+import pytest
+
+from app.collector.agent_collector import GCPConfigurationCollector
+from app.collector.asset_inventory import IAM_POLICY, RESOURCE, AssetInventoryCollector
+from app.common.exceptions import CollectionError
+from app.config.paddi_config import PaddiConfig
+
+PROJECT = "//cloudresourcemanager.googleapis.com/projects/314"
+BUCKET = "//storage.googleapis.com/public-data"
+
+
+class Asset(dict):
+    """An asset as the client returns it, converted with its message type's to_dict."""
+
+    @classmethod
+    def to_dict(cls, message):
+        return dict(message)
+
+
+class FakeClient:
+    """AssetServiceClient returning one page per content type."""
+
+    def __init__(self, pages):
+        self.pages = pages
+        self.requests = []
+
+    def list_assets(self, request):
+        self.requests.append(request)
+        return iter(self.pages[request["content_type"]])
+
+
+RESOURCES = [
+    Asset(
+        name=PROJECT,
+        asset_type="cloudresourcemanager.googleapis.com/Project",
+        resource={"data": {"projectId": "prod-1"}, "parent": "//x/organizations/9"},
+    ),
+    Asset(
+        name=BUCKET,
+        asset_type="storage.googleapis.com/Bucket",
+        resource={"data": {"name": "public-data"}, "location": "US", "parent": PROJECT},
+    ),
+]
+POLICIES = [
+    Asset(
+        name=PROJECT,
+        asset_type="cloudresourcemanager.googleapis.com/Project",
+        iam_policy={
+            "bindings": [{"role": "roles/owner", "members": ["user:a@example.com"]}],
+            "etag": "BwX=",
+            "version": 3,
+        },
+    ),
+    Asset(
+        name=BUCKET,
+        asset_type="storage.googleapis.com/Bucket",
+        iam_policy={"bindings": [{"role": "roles/storage.objectViewer", "members": ["allUsers"]}]},
+    ),
+]
+
+
+def test_collect_joins_resources_and_policies():
+    """Test that the project policy is picked out and policies are joined to their assets."""
+    client = FakeClient({RESOURCE: RESOURCES, IAM_POLICY: POLICIES})
+    collector = AssetInventoryCollector(
+        "prod-1", "9", scope="organization", asset_types=["a/B"], client=client
+    )
+    snapshot = collector.collect()
+
+    assert [r["parent"] for r in client.requests] == ["organizations/9", "organizations/9"]
+    assert [r["content_type"] for r in client.requests] == [RESOURCE, IAM_POLICY]
+    assert client.requests[0]["asset_types"] == ["a/B"]
+    assert snapshot["iam_policy"] == {
+        "bindings": [{"role": "roles/owner", "members": ["user:a@example.com"]}],
+        "etag": "BwX=",
+        "version": 3,
+    }
+    bucket = snapshot["assets"][1]
+    assert (bucket["location"], bucket["data"]["name"]) == ("US", "public-data")
+    assert bucket["iam_policy"]["bindings"][0]["members"] == ["allUsers"]
+
+
+def test_collect_wraps_api_errors():
+    """Test that API errors become collection errors."""
+
+    class FailingClient:
+        def list_assets(self, request):
+            raise PermissionError("cloudasset.assets.listResource denied")
+
+    with pytest.raises(CollectionError):
+        AssetInventoryCollector("prod-1", client=FailingClient()).collect()
+
+
+def test_from_config():
+    """Test that the section is opt-in and the organization scope needs an organization."""
+    assert AssetInventoryCollector.from_config("p", config=PaddiConfig({})) is None
+    section = {"collector": {"asset_inventory": {"scope": "organization"}}}
+    with pytest.raises(ValueError):
+        AssetInventoryCollector.from_config("p", config=PaddiConfig(section))
+    collector = AssetInventoryCollector.from_config("p", "9", config=PaddiConfig(section))
+    assert collector.parent == "organizations/9"
+    disabled = {"collector": {"asset_inventory": {"enabled": False}}}
+    assert AssetInventoryCollector.from_config("p", config=PaddiConfig(disabled)) is None
+
+
+def test_collect_all_uses_asset_inventory(tmp_path):
+    """Test that the collected data takes IAM from the snapshot and keeps the assets."""
+    config = PaddiConfig({"collector": {"asset_inventory": {"enabled": True}}})
+    with patch("app.collector.asset_inventory.PaddiConfig.load", return_value=config):
+        collector = GCPConfigurationCollector("prod-1", use_mock=True, output_dir=str(tmp_path))
+    data = collector.collect_all()
+
+    assert data["iam_policies"]["bindings"][0]["role"] == "roles/owner"
+    types = {asset["asset_type"] for asset in data["assets"]}
+    assert "storage.googleapis.com/Bucket" in types
@@ -0,0 +1,131 @@
+"""Tests for the Cloud Audit Logs collector."""
+
+from datetime import datetime, timezone
+
+import pytest
+
+from app.collector.audit_logs import AuditLogCollector, detect_suspicious, normalize_entry
+from app.common.exceptions import CollectionError
+from app.config.paddi_config import PaddiConfig
+from app.explainer.rule_analyzer import RuleBasedAnalyzer
+
+POLICY = {"bindings": [{"role": "roles/owner", "members": ["user:admin@example.com"]}]}
+
+
+def entry(method, principal, status=0, log="activity"):
+    """Build an audit log entry in LogEntry API form."""
+    return {
+        "logName": f"projects/p/logs/cloudaudit.googleapis.com%2F{log}",
+        "timestamp": "2026-10-15T08:00:00Z",
+        "protoPayload": {
+            "methodName": method,
+            "resourceName": "projects/p",
+            "authenticationInfo": {"principalEmail": principal},
+            "requestMetadata": {"callerIp": "203.0.113.7"},
+            "status": {"code": status} if status else {},
+        },
+    }
+
+
+class Entry:
+    """A log entry as the client returns it."""
+
+    def __init__(self, data):
+        self.data = data
+
+    def to_api_repr(self):
+        return self.data
+
+
+class FakeClient:
+    """Logging client returning fixed entries."""
+
+    def __init__(self, entries):
+        self.entries = entries
+        self.calls = []
+
+    def list_entries(self, **kwargs):
+        self.calls.append(kwargs)
+        return iter(Entry(e) for e in self.entries)
+
+
+def test_detect_suspicious_patterns():
+    """Test each pattern, known principals, and the denial threshold."""
+    entries = [
+        normalize_entry(e)
+        for e in [
+            entry("SetIamPolicy", "intruder@external.example"),
+            entry("SetIamPolicy", "admin@example.com"),
+            entry("SetIamPolicy", "terraform@ops.iam.gserviceaccount.com"),
+            entry("google.iam.admin.v1.CreateServiceAccountKey", "admin@example.com"),
+            entry("google.logging.v2.ConfigServiceV2.DeleteSink", "admin@example.com"),
+            entry("storage.objects.get", "dev@example.com", log="data_access"),
+        ]
+        + [entry("storage.objects.get", "prober@example.com", status=7)] * 3
+        + [entry("SetIamPolicy", "intruder@external.example", status=7)]
+    ]
+    known = {"admin@example.com", "terraform@ops.iam.gserviceaccount.com"}
+    activity = detect_suspicious(entries, known, denied_threshold=3)
+
+    assert [(a["pattern"], a["principal"]) for a in activity] == [
+        ("iam_policy_change_by_new_principal", "intruder@external.example"),
+        ("service_account_key_created", "admin@example.com"),
+        ("audit_logging_changed", "admin@example.com"),
+        ("repeated_permission_denied", "prober@example.com"),
+    ]
+    assert activity[-1]["count"] == 3
+    assert entries[5]["log"] == "data_access"
+
+
+def test_collect_queries_the_window():
+    """Test the query, and that policy members are known principals."""
+    client = FakeClient([entry("SetIamPolicy", "admin@example.com")])
+    collector = AuditLogCollector(
+        "p", window_hours=6, log_types=["activity"], log_filter='severity="NOTICE"', client=client
+    )
+    query = collector.build_filter(datetime(2026, 10, 15, 12, tzinfo=timezone.utc))
+    assert query == (
+        'logName=("projects/p/logs/cloudaudit.googleapis.com%2Factivity") '
+        'AND timestamp>="2026-10-15T06:00:00+00:00" AND (severity="NOTICE")'
+    )
+
+    result = collector.collect(POLICY)
+    assert (result["entries"], result["suspicious"]) == (1, [])
+    assert client.calls[0]["order_by"] == "timestamp desc"
+    assert client.calls[0]["resource_names"] == ["projects/p"]
+
+
+def test_collect_wraps_api_errors():
+    """Test that API errors become collection errors."""
+
+    class FailingClient:
+        def list_entries(self, **kwargs):
+            raise PermissionError("logging.privateLogEntries.list denied")
+
+    with pytest.raises(CollectionError):
+        AuditLogCollector("p", client=FailingClient()).collect()
+
+
+def test_from_config():
+    """Test that the section is opt-in and log types are checked."""
+    assert AuditLogCollector.from_config("p", config=PaddiConfig({})) is None
+    section = {"collector": {"audit_logs": {"window_hours": 2, "known_principals": ["a@b.c"]}}}
+    collector = AuditLogCollector.from_config("p", config=PaddiConfig(section))
+    assert (collector.window_hours, collector.log_types) == (2, ["activity", "data_access"])
+    with pytest.raises(ValueError):
+        AuditLogCollector("p", log_types=["system_event"])
+
+
+def test_activity_findings():
+    """Test that mock activity becomes findings with the audit log source."""
+    result = AuditLogCollector("p", use_mock=True).collect(POLICY)
+    findings = RuleBasedAnalyzer().activity_findings(result)
+
+    assert [f.title for f in findings] == [
+        "IAM policy changed by an unfamiliar principal",
+        "Service account key created",
+    ]
+    assert findings[0].severity == "HIGH"
+    assert findings[0].category == "SUSPICIOUS_ACTIVITY"
+    assert findings[0].source == "audit_logs"
+    assert "from 203.0.113.7" in findings[0].explanation
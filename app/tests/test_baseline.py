@@ -0,0 +1,97 @@
+"""Tests for the accepted baseline run."""
+
+import json
+
+import pytest
+
+from app.cli.base import CommandContext
+from app.cli.commands import AuditCommand, BaselineCommand
+from app.common.exceptions import NewFindingsError
+from app.history.baseline import compare_to_baseline, format_comparison
+from app.history.retention import RetentionPolicy, apply_retention
+from app.history.store import HISTORY_DB_NAME, RunStore
+
+ACCEPTED = [
+    {"title": "Public bucket", "severity": "HIGH", "resource": "bucket-a"},
+    {"title": "Owner role", "severity": "CRITICAL", "resource": "project"},
+]
+
+
+@pytest.fixture
+def store(tmp_path):
+    """A history store with an accepted run (run 1) and a later run (run 2)."""
+    store = RunStore(tmp_path / "data" / HISTORY_DB_NAME)
+    store.record_run(ACCEPTED, "p")
+    store.record_run(ACCEPTED[:1], "p")
+    return store
+
+
+def test_set_and_clear(store):
+    """Test that one run is the baseline at a time."""
+    assert store.baseline() is None
+    store.set_baseline(1)
+    store.set_baseline(2)
+    assert store.baseline().run_id == 2
+    store.clear_baseline()
+    assert store.baseline() is None
+    with pytest.raises(ValueError):
+        store.set_baseline(99)
+
+
+def test_compare_to_baseline(store):
+    """Test that only deviations from the baseline are reported."""
+    assert compare_to_baseline(store, ACCEPTED) is None
+
+    store.set_baseline(1)
+    current = ACCEPTED[1:] + [{"title": "Open firewall", "severity": "MEDIUM", "resource": "fw"}]
+    comparison = compare_to_baseline(store, current)
+
+    assert [f["title"] for f in comparison.new] == ["Open firewall"]
+    assert [f["title"] for f in comparison.resolved] == ["Public bucket"]
+    assert comparison.unchanged == 1
+    assert "1 new, 1 resolved, 1 unchanged" in format_comparison(comparison)
+
+
+def test_retention_keeps_baseline(store, tmp_path):
+    """Test that retention never deletes the baseline run."""
+    store.record_run([], "p")
+    store.set_baseline(1)
+    result = apply_retention(RetentionPolicy(max_runs=1), tmp_path / "data", tmp_path / "a")
+    assert result.deleted_runs == [2]
+    assert store.baseline().run_id == 1
+
+
+def test_baseline_command(store, tmp_path, monkeypatch, capsys):
+    """Test baseline set and compare --fail-on-new."""
+    monkeypatch.chdir(tmp_path)
+    command = BaselineCommand()
+    command.execute(CommandContext(action="set", target="1"))
+    assert store.baseline().run_id == 1
+    capsys.readouterr()
+
+    (tmp_path / "data" / "explained.json").write_text(json.dumps(ACCEPTED), encoding="utf-8")
+    command.execute(CommandContext(action="compare", fail_on_new=True, output_format="json"))
+    assert json.loads(capsys.readouterr().out)["new"] == []
+
+    findings = ACCEPTED + [{"title": "Open firewall", "severity": "MEDIUM", "resource": "fw"}]
+    (tmp_path / "data" / "explained.json").write_text(json.dumps(findings), encoding="utf-8")
+    command.execute(CommandContext(action="compare", output_format="json"))
+    with pytest.raises(NewFindingsError) as excinfo:
+        command.execute(CommandContext(action="compare", fail_on_new=True, output_format="json"))
+    assert excinfo.value.count == 1
+
+
+def test_audit_summary_shows_deviations(store, tmp_path, monkeypatch, capsys):
+    """Test that the audit summary lists only new and resolved findings."""
+    monkeypatch.chdir(tmp_path)
+    store.set_baseline(1)
+    (tmp_path / "data" / "explained.json").write_text(
+        json.dumps(ACCEPTED[:1]), encoding="utf-8"
+    )
+
+    comparison = AuditCommand()._log_risk_summary()
+
+    out = capsys.readouterr().out
+    assert "0 new, 1 resolved, 1 unchanged" in out
+    assert "Owner role" in out and "Public bucket" not in out
+    AuditCommand()._check_new_findings(comparison)
@@ -0,0 +1,75 @@
+"""Tests for pipeline benchmarks."""
+
+import json
+
+import pytest
+
+from app.benchmark.runner import Benchmark, format_results
+from app.benchmark.synthetic import synthetic_collected
+from app.cli.base import CommandContext
+from app.cli.commands import BenchmarkCommand, _sizes
+from app.common.artifact_schemas import validate_file
+from app.explainer.token_usage import TokenUsage
+
+
+def test_synthetic_data_is_sized_and_repeatable(tmp_path):
+    """Test that a size always produces the same valid dataset."""
+    data = synthetic_collected(50)
+    path = tmp_path / "collected.json"
+    path.write_text(json.dumps(data), encoding="utf-8")
+
+    assert len(data["iam_policies"]["bindings"]) == 50
+    assert len(data["scc_findings"]) == 50
+    assert synthetic_collected(50) == data
+    assert not validate_file(path, "collected")
+
+
+def test_token_usage_prefers_reported_counts():
+    """Test that reported counts are used, and missing ones estimated."""
+    usage = TokenUsage()
+
+    usage.add("x" * 400, "y" * 40, prompt_tokens=90, completion_tokens=12)
+    assert (usage.total_tokens, usage.estimated) == (102, False)
+
+    usage.add("x" * 400, "y" * 40)
+    assert (usage.prompt_tokens, usage.completion_tokens, usage.estimated) == (190, 22, True)
+
+
+def test_benchmark_measures_each_stage(tmp_path, monkeypatch):
+    """Test a mock run: every stage is measured, and findings and tokens grow with the size."""
+    monkeypatch.chdir(tmp_path)
+
+    small, large = Benchmark().run([10, 40])
+
+    assert [stage.stage for stage in small.stages] == ["collect", "explain", "report"]
+    assert all(stage.seconds >= 0 and stage.peak_memory > 0 for stage in small.stages)
+    assert large.findings > small.findings > 0
+    assert large.stages[1].tokens > small.stages[1].tokens > 0
+    assert small.tokens_estimated
+    assert not (tmp_path / "data").exists()
+    assert "explain" in format_results([small])
+
+
+def test_parse_sizes():
+    """Test that --sizes accepts a number, a list, or comma-separated numbers."""
+    assert _sizes(None) == [100, 1000, 10000]
+    assert _sizes(500) == [500]
+    assert _sizes((100, 200)) == [100, 200]
+    assert _sizes("100, 1000") == [100, 1000]
+    with pytest.raises(ValueError):
+        _sizes("0")
+    with pytest.raises(ValueError):
+        _sizes("many")
+
+
+def test_benchmark_command_saves_results(tmp_path, monkeypatch, capsys):
+    """Test that the command prints the table and saves the results as JSON."""
+    monkeypatch.chdir(tmp_path)
+    saved = tmp_path / "bench" / "results.json"
+
+    BenchmarkCommand().execute(CommandContext(sizes="5", target=str(saved)))
+
+    results = json.loads(saved.read_text(encoding="utf-8"))
+    assert results[0]["size"] == 5
+    assert [stage["stage"] for stage in results[0]["stages"]] == ["collect", "explain", "report"]
+    assert "Peak MiB" in capsys.readouterr().out
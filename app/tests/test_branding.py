@@ -0,0 +1,92 @@
+"""Tests for report branding."""
+
+from unittest.mock import MagicMock
+
+import pytest
+
+from app.common.exceptions import ConfigurationError
+from app.reporter.agent_reporter import AuditReport, HTMLGenerator
+from app.reporter.branding import Branding
+
+
+def _config(branding):
+    config = MagicMock()
+    config.section.return_value = branding
+    return config
+
+
+def _report(branding=None):
+    return AuditReport(
+        findings=[],
+        project_name="proj",
+        audit_date="2026-10-16",
+        total_findings=0,
+        severity_counts={"HIGH": 1},
+        branding=branding,
+    )
+
+
+def test_from_config(tmp_path):
+    """Test that the logo is embedded and colors and footer are read."""
+    logo = tmp_path / "logo.png"
+    logo.write_bytes(b"\x89PNG")
+
+    branding = Branding.from_config(
+        _config(
+            {
+                "company_name": "Example Corp",
+                "logo": str(logo),
+                "primary_color": "#0b3d91",
+                "severity_colors": {"high": "#000"},
+                "footer": "CONFIDENTIAL",
+            }
+        )
+    )
+
+    assert branding.logo == "data:image/png;base64,iVBORw=="
+    assert branding.severity_color("HIGH") == "#000"
+    assert branding.severity_color("CRITICAL") == "#D32F2F"
+    assert branding.footer == "CONFIDENTIAL"
+
+
+@pytest.mark.parametrize(
+    "section",
+    [
+        {"primary_color": "blue; } body { display: none"},
+        {"severity_colors": {"HIGH": "red"}},
+        {"logo": "logo.bmp"},
+        {"logo": "missing.png"},
+    ],
+)
+def test_invalid_branding(section):
+    """Test that colors must be hex colors and the logo a readable image."""
+    with pytest.raises(ConfigurationError):
+        Branding.from_config(_config(section))
+
+
+def test_html_report_is_branded():
+    """Test that the HTML report shows the company, its colors, and the escaped footer."""
+    branding = Branding(
+        company_name="Example & Co",
+        logo="data:image/png;base64,AAAA",
+        primary_color="#0b3d91",
+        severity_colors={"HIGH": "#123456"},
+        footer="Confidential <internal>",
+    )
+
+    html = HTMLGenerator().generate(_report(branding))
+
+    assert '<span class="company">Example &amp; Co</span>' in html
+    assert 'src="data:image/png;base64,AAAA"' in html
+    assert "color: #0b3d91;" in html
+    assert "color: #123456;" in html
+    assert '<footer class="confidential">Confidential &lt;internal&gt;</footer>' in html
+
+
+def test_html_report_without_branding():
+    """Test that the report keeps its default look without branding."""
+    html = HTMLGenerator().generate(_report())
+
+    assert 'class="branding"' not in html
+    assert "<footer" not in html
+    assert "color: #1a73e8;" in html
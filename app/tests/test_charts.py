@@ -0,0 +1,74 @@
+"""Tests for the inline SVG charts of the HTML report."""
+
+import json
+import xml.etree.ElementTree as ET
+
+from app.history.store import RunRecord
+from app.reporter.agent_reporter import AuditReport, HTMLGenerator, ReportService
+from app.reporter.branding import SEVERITY_COLORS
+from app.reporter.charts import TREND_RUNS, severity_chart, trend_chart
+
+SVG = "{http://www.w3.org/2000/svg}"
+
+
+def _runs(*totals):
+    return [
+        RunRecord(run_id=i, project_id="p", created_at=f"2026-10-{i:02d} 09:00:00", total=total)
+        for i, total in enumerate(totals, 1)
+    ]
+
+
+def test_severity_chart():
+    """Test one bar per severity, in severity order, sized by count."""
+    svg = ET.fromstring(severity_chart({"LOW": 1, "CRITICAL": 4}, SEVERITY_COLORS))
+
+    bars = svg.findall(f"{SVG}rect")
+    labels = [text.text for text in svg.findall(f"{SVG}text")]
+    assert labels == ["CRITICAL", "4", "LOW", "1"]
+    assert [bar.get("fill") for bar in bars] == ["#D32F2F", "#FFC107"]
+    assert int(bars[0].get("width")) == 4 * int(bars[1].get("width"))
+    assert severity_chart({}, SEVERITY_COLORS) == ""
+
+
+def test_trend_chart():
+    """Test a point per run, with the latest runs only, and no chart for a single run."""
+    svg = ET.fromstring(trend_chart(_runs(*range(TREND_RUNS + 3))))
+
+    assert len(svg.findall(f"{SVG}circle")) == TREND_RUNS
+    assert svg.find(f"{SVG}polyline") is not None
+    assert trend_chart(_runs(5)) == ""
+
+
+def test_html_report_embeds_charts():
+    """Test that the HTML report embeds the charts without any script."""
+    report = AuditReport(
+        findings=[],
+        project_name="p",
+        audit_date="2026-10-16",
+        total_findings=3,
+        severity_counts={"HIGH": 3},
+        history=_runs(5, 3),
+    )
+
+    html = HTMLGenerator().generate(report)
+    assert html.count("<svg") == 2
+    assert "<script" not in html
+    assert "<svg" not in HTMLGenerator(charts=False).generate(report)
+
+
+def test_report_trend_uses_project_history(tmp_path):
+    """Test that the trend covers the recorded runs of the reported project only."""
+    data_dir = tmp_path / "data"
+    data_dir.mkdir()
+    findings = [{"title": "t", "severity": "HIGH", "explanation": "e", "recommendation": "r"}]
+    (data_dir / "explained.json").write_text(json.dumps(findings))
+    (data_dir / "collected.json").write_text(json.dumps({"metadata": {"project_id": "p"}}))
+    service = ReportService(input_dir=data_dir, output_dir=tmp_path / "output")
+
+    service.generate_reports(["html"])
+    (data_dir / "explained.json").write_text(json.dumps(findings + [{**findings[0], "title": "u"}]))
+    service.generate_reports(["html"])
+
+    assert [run.total for run in service._run_history("p")] == [1, 2]
+    assert service._run_history("other") == []
+    assert (tmp_path / "output" / "audit.html").read_text().count("<svg") == 2
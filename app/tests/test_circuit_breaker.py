@@ -0,0 +1,167 @@
+"""Tests for the LLM circuit breaker and the rule-only fallback."""
+
+import json
+from unittest.mock import MagicMock, patch
+
+import pytest
+
+from app.common.exceptions import ConfigurationError
+from app.config.paddi_config import PaddiConfig
+from app.explainer.agent_explainer import GeminiSecurityAnalyzer, SecurityRiskExplainer
+from app.explainer.circuit_breaker import (
+    CLOSED,
+    HALF_OPEN,
+    OPEN,
+    CircuitBreaker,
+    CircuitOpenError,
+)
+from app.explainer.rule_analyzer import RuleBasedAnalyzer
+
+COLLECTED = {
+    "project_id": "prod",
+    "iam_policies": {
+        "bindings": [
+            {"role": "roles/owner", "members": ["user:admin@example.com"]},
+            {"role": "roles/storage.objectViewer", "members": ["allUsers"]},
+        ]
+    },
+    "scc_findings": [{"category": "OPEN_FIREWALL", "severity": "high"}],
+}
+
+
+class FakeClock:
+    """A clock that only moves when sleep is called."""
+
+    def __init__(self):
+        self.now = 0.0
+        self.sleeps = []
+
+    def __call__(self):
+        return self.now
+
+    def sleep(self, seconds):
+        self.sleeps.append(seconds)
+        self.now += seconds
+
+
+def test_trips_at_threshold():
+    """Test that consecutive failures trip the breaker and a success resets them."""
+    breaker = CircuitBreaker(failure_threshold=2)
+    breaker.record_failure()
+    breaker.record_success()
+    breaker.record_failure()
+    assert breaker.state == CLOSED
+
+    breaker.record_failure()
+    assert breaker.state == OPEN
+    with pytest.raises(CircuitOpenError):
+        breaker.before_call()
+
+
+def test_pause_waits_out_cooldown():
+    """Test that "pause" sleeps, lets one trial through, then gives up after max_pauses."""
+    clock = FakeClock()
+    breaker = CircuitBreaker(
+        failure_threshold=1,
+        cooldown_seconds=30,
+        on_open="pause",
+        max_pauses=1,
+        clock=clock,
+        sleep=clock.sleep,
+    )
+    breaker.record_failure()
+    clock.now = 10
+
+    breaker.before_call()
+    assert clock.sleeps == [20]
+    assert breaker.state == HALF_OPEN
+
+    # The trial call fails, so the breaker trips again and stops pausing
+    breaker.record_failure()
+    assert breaker.trips == 2
+    with pytest.raises(CircuitOpenError):
+        breaker.before_call()
+
+
+def test_from_config(tmp_path):
+    """Test reading [llm.circuit_breaker] and rejecting "fallback" without a provider."""
+    path = tmp_path / "paddi.toml"
+    path.write_text(
+        '[llm.circuit_breaker]\nfailure_threshold = 5\non_open = "fallback"\n'
+        'fallback_provider = "ollama"\n',
+        encoding="utf-8",
+    )
+    breaker = CircuitBreaker.from_config(PaddiConfig.load(path))
+    assert (breaker.failure_threshold, breaker.fallback_provider) == (5, "ollama")
+
+    path.write_text('[llm.circuit_breaker]\non_open = "fallback"\n', encoding="utf-8")
+    with pytest.raises(ConfigurationError):
+        CircuitBreaker.from_config(PaddiConfig.load(path))
+
+
+def test_retries_stop_once_open():
+    """Test that an open breaker stops the retries instead of using all of them."""
+    analyzer = GeminiSecurityAnalyzer(
+        project_id="prod", use_mock=True, circuit_breaker=CircuitBreaker(failure_threshold=2)
+    )
+    analyzer._rate_limit_delay = 0
+    analyzer._model = MagicMock()
+    analyzer._model.generate_content.side_effect = TimeoutError("deadline exceeded")
+
+    with pytest.raises(CircuitOpenError):
+        analyzer._call_llm_with_retry("prompt", max_retries=5)
+    assert analyzer._model.generate_content.call_count == 2
+
+
+def test_rule_based_analyzer():
+    """Test the rule-only findings for IAM bindings and reported findings."""
+    findings = RuleBasedAnalyzer().analyze_security_risks({"providers": [COLLECTED]})
+
+    assert [(f.category, f.severity) for f in findings] == [
+        ("OVERPRIVILEGED_ROLE", "HIGH"),
+        ("PUBLIC_ACCESS", "CRITICAL"),
+        ("OPEN_FIREWALL", "HIGH"),
+    ]
+    assert {f.source for f in findings} == {"rules"}
+
+
+def make_explainer(tmp_path, breaker):
+    """Create an explainer whose analyzer has just tripped the breaker."""
+    input_file = tmp_path / "collected.json"
+    input_file.write_text(json.dumps(COLLECTED), encoding="utf-8")
+    with patch("app.explainer.agent_explainer.CircuitBreaker.from_config", return_value=breaker):
+        explainer = SecurityRiskExplainer(
+            project_id="prod",
+            use_mock=True,
+            ai_provider="gemini",
+            input_file=str(input_file),
+            output_dir=str(tmp_path),
+        )
+    explainer.analyzer = MagicMock()
+    explainer.analyzer.analyze_security_risks.side_effect = CircuitOpenError(breaker)
+    return explainer
+
+
+def test_explainer_degrades_to_rules(tmp_path):
+    """Test that analysis finishes with the rules once the breaker trips."""
+    explainer = make_explainer(tmp_path, CircuitBreaker())
+
+    findings = explainer.analyze()
+
+    assert len(findings) == 3
+    assert {f.source for f in findings} == {"rules"}
+
+
+@patch("app.explainer.agent_explainer.get_analyzer")
+def test_explainer_uses_fallback_provider(get_analyzer, tmp_path):
+    """Test that "fallback" sends the unredacted data to the local fallback provider."""
+    explainer = make_explainer(
+        tmp_path, CircuitBreaker(on_open="fallback", fallback_provider="ollama")
+    )
+    get_analyzer.return_value.analyze_security_risks.return_value = []
+
+    assert explainer.analyze() == []
+    config = get_analyzer.call_args.args[0]
+    assert config["ai_provider"] == "ollama"
+    assert config["ollama_endpoint"]
+    get_analyzer.return_value.analyze_security_risks.assert_called_once_with(COLLECTED)
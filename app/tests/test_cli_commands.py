@@ -85,6 +85,7 @@ class TestAuditCommand:
         mock_collect_instance = Mock()
         mock_explain_instance = Mock()
         mock_report_instance = Mock()
+        mock_report_instance.generate.return_value = []
 
         mock_collect.return_value = mock_collect_instance
         mock_explain.return_value = mock_explain_instance
@@ -96,7 +97,7 @@ class TestAuditCommand:
         # Verify all commands were created and executed
         mock_collect_instance.execute.assert_called_once_with(context)
         mock_explain_instance.execute.assert_called_once_with(context)
-        mock_report_instance.execute.assert_called_once_with(context)
+        mock_report_instance.generate.assert_called_once_with(context)
 
 
 class TestCollectCommand:
@@ -194,6 +194,7 @@ class TestAuditCommandExtended:
         mock_collect = MagicMock()
         mock_explain = MagicMock()
         mock_report = MagicMock()
+        mock_report.generate.return_value = []
 
         mock_collect_class.return_value = mock_collect
         mock_explain_class.return_value = mock_explain
@@ -212,11 +213,13 @@ class TestAuditCommandExtended:
         # Verify all commands were executed with same context
         mock_collect.execute.assert_called_once_with(context)
         mock_explain.execute.assert_called_once_with(context)
-        mock_report.execute.assert_called_once_with(context)
+        mock_report.generate.assert_called_once_with(context)
 
-        # Verify completion message
+        # Verify completion message, which the run manifest path follows
         mock_logger.info.assert_called()
-        final_log_call = mock_logger.info.call_args_list[-1]
-        assert "Audit complete" in final_log_call[0][0]
+        final_log_call = next(
+            call for call in mock_logger.info.call_args_list if "Audit complete" in call[0][0]
+        )
+        assert "Run manifest" in mock_logger.info.call_args_list[-1][0][0]
         # Check if output_dir is passed as parameter
         assert final_log_call[0][1] == "test_output"
@@ -0,0 +1,192 @@
+"""Tests for the global --output and --quiet flags and the config command."""
+
+import json
+import logging
+import sys
+
+import pytest
+import yaml
+
+from app.cli.base import CommandContext
+from app.cli.commands import AuditCommand, ConfigCommand, FindingsCommand
+from app.cli.output import (
+    echo,
+    extract_output_flag,
+    extract_quiet_flag,
+    is_quiet,
+    render,
+    set_output_format,
+    set_quiet,
+)
+from app.common.exceptions import ConfigurationError
+from app.common.logging_config import LoggingConfig
+
+FINDINGS = [
+    {"title": "Public bucket", "severity": "HIGH", "category": "PUBLIC_BUCKET", "resource": "b1"},
+    {"title": "Owner role", "severity": "CRITICAL", "category": "OVERPRIVILEGED_ROLE"},
+]
+
+
+@pytest.fixture(autouse=True)
+def reset_output_format():
+    """Restore the default output format after each test."""
+    yield
+    set_output_format(None)
+
+
+class TestOutputFlag:
+    """Tests for parsing and rendering."""
+
+    def test_extract_output_flag(self):
+        """Test both flag spellings and that directories are left alone."""
+        assert extract_output_flag(["findings", "--output=json"]) == (["findings"], "json")
+        assert extract_output_flag(["--output", "yaml", "config", "show"]) == (
+            ["config", "show"],
+            "yaml",
+        )
+        assert extract_output_flag(["init", "--output=reports"]) == (
+            ["init", "--output=reports"],
+            None,
+        )
+
+    def test_unknown_format(self):
+        """Test that unknown formats are rejected."""
+        with pytest.raises(ValueError):
+            set_output_format("xml")
+
+    def test_render_yaml(self):
+        """Test YAML rendering keeps key order and non-ASCII text."""
+        assert render({"b": 1, "a": "監査"}, "yaml") == "b: 1\na: 監査"
+
+
+class TestStructuredCommands:
+    """Tests for commands that honor --output."""
+
+    def test_findings_list_yaml(self, tmp_path, capsys):
+        """Test that the global flag applies when the command has no explicit format."""
+        input_file = tmp_path / "explained.json"
+        input_file.write_text(json.dumps(FINDINGS))
+        set_output_format("yaml")
+
+        FindingsCommand().execute(CommandContext(input_file=str(input_file), output_format=None))
+
+        data = yaml.safe_load(capsys.readouterr().out)
+        assert data["total"] == 2
+        assert data["findings"][0]["severity"] == "CRITICAL"
+
+    def test_post_audit_summary_json(self, tmp_path, capsys):
+        """Test the post-audit risk summary as JSON."""
+        findings_file = tmp_path / "explained.json"
+        findings_file.write_text(json.dumps(FINDINGS))
+        set_output_format("json")
+
+        AuditCommand()._log_risk_summary(str(findings_file))
+
+        data = json.loads(capsys.readouterr().out)
+        assert data["total"] == 2
+        assert data["severity_counts"] == {"HIGH": 1, "CRITICAL": 1}
+        assert data["risk_grade"]
+
+
+class TestConfigCommand:
+    """Tests for config show and config validate."""
+
+    def test_show_table(self, tmp_path, capsys):
+        """Test the flattened key = value listing."""
+        config_file = tmp_path / "paddi.toml"
+        config_file.write_text('[storage.gcs]\nbucket = "artifacts"\n')
+
+        ConfigCommand().execute(CommandContext(action="show", config_file=str(config_file)))
+
+        assert 'storage.gcs.bucket = "artifacts"' in capsys.readouterr().out
+
+    def test_show_json(self, tmp_path, capsys):
+        """Test the configuration as JSON."""
+        config_file = tmp_path / "paddi.toml"
+        config_file.write_text('[risk]\nweights = { HIGH = 5 }\n')
+
+        ConfigCommand().execute(
+            CommandContext(action="show", config_file=str(config_file), output_format="json")
+        )
+
+        data = json.loads(capsys.readouterr().out)
+        assert data["config"] == {"risk": {"weights": {"HIGH": 5}}}
+        assert data["path"] == str(config_file)
+
+    def test_validate_reports_errors(self, tmp_path, capsys):
+        """Test that invalid sections are reported and the command fails."""
+        config_file = tmp_path / "paddi.toml"
+        config_file.write_text('[notifications.webhooks]\nbad = "ftp://example.com"\n')
+
+        with pytest.raises(ConfigurationError):
+            ConfigCommand().execute(
+                CommandContext(
+                    action="validate", config_file=str(config_file), output_format="json"
+                )
+            )
+
+        data = json.loads(capsys.readouterr().out)
+        assert data["valid"] is False
+        assert data["errors"][0].startswith("notifications.webhooks.bad:")
+
+    def test_validate_syntax_error(self, tmp_path, capsys):
+        """Test that TOML syntax errors are reported."""
+        config_file = tmp_path / "paddi.toml"
+        config_file.write_text("[risk\n")
+
+        with pytest.raises(ConfigurationError):
+            ConfigCommand().execute(CommandContext(action="validate", config_file=str(config_file)))
+
+        assert "1 error(s)" in capsys.readouterr().out
+
+    def test_validate_valid(self, tmp_path, capsys):
+        """Test a valid configuration."""
+        config_file = tmp_path / "paddi.toml"
+        config_file.write_text('[storage.s3]\nbucket = "artifacts"\n')
+
+        ConfigCommand().execute(CommandContext(action="validate", config_file=str(config_file)))
+
+        assert "is valid" in capsys.readouterr().out
+
+
+class TestQuietMode:
+    """Tests for --quiet."""
+
+    @pytest.fixture(autouse=True)
+    def reset_quiet(self, monkeypatch):
+        """Restore normal mode after each test."""
+        monkeypatch.delenv("PADDI_QUIET", raising=False)
+        yield
+        set_quiet(False)
+
+    def test_extract_quiet_flag(self):
+        """Test both flag spellings."""
+        assert extract_quiet_flag(["audit", "--quiet"]) == (["audit"], True)
+        assert extract_quiet_flag(["-q", "findings"]) == (["findings"], True)
+        assert extract_quiet_flag(["audit"]) == (["audit"], False)
+
+    def test_environment_variable(self, monkeypatch):
+        """Test that PADDI_QUIET enables quiet mode for cron jobs."""
+        assert not is_quiet()
+        monkeypatch.setenv("PADDI_QUIET", "1")
+        assert is_quiet()
+
+    def test_echo_strips_emoji(self, capsys):
+        """Test that result lines are plain text in quiet mode."""
+        set_quiet(True)
+
+        echo("✅ paddi.toml is valid")
+
+        assert capsys.readouterr().out == "paddi.toml is valid\n"
+
+    def test_logging_shows_only_plain_errors(self, monkeypatch, capsys):
+        """Test that quiet logging drops info messages and emoji."""
+        root = logging.getLogger()
+        monkeypatch.setattr(root, "handlers", [logging.StreamHandler(sys.stdout)])
+        monkeypatch.setattr(root, "level", logging.INFO)
+
+        LoggingConfig.enable_quiet_mode()
+        logging.getLogger("paddi.test").info("🔍 Analyzing security risks...")
+        logging.getLogger("paddi.test").error("❌ Findings file not found: %s", "x.json")
+
+        assert capsys.readouterr().out == "ERROR: Findings file not found: x.json\n"
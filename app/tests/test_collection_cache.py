@@ -0,0 +1,109 @@
+"""Tests for the collection cache."""
+
+import json
+import os
+import time
+from unittest.mock import patch
+
+import pytest
+
+from app.cli.base import CommandContext
+from app.cli.commands import CollectCommand
+from app.collector.cache import CollectionCache
+
+
+@pytest.fixture
+def workspace(tmp_path, monkeypatch):
+    """Run in an empty directory whose paddi.toml turns the cache on."""
+    monkeypatch.chdir(tmp_path)
+    (tmp_path / "paddi.toml").write_text("[collector.cache]\nenabled = true\n")
+    return tmp_path
+
+
+def _collect(project_id):
+    """Stand in for the collector, writing data/collected.json."""
+
+    def write(**kwargs):
+        os.makedirs("data", exist_ok=True)
+        with open("data/collected.json", "w", encoding="utf-8") as f:
+            json.dump({"metadata": {"project_id": kwargs["project_id"]}}, f)
+
+    return write
+
+
+def test_lookup_honors_ttl(tmp_path):
+    """Test that a collection is reused within the TTL, and not once expired."""
+    cache = CollectionCache(tmp_path / "cache", ttl_seconds=60)
+    source = tmp_path / "collected.json"
+    source.write_text("{}", encoding="utf-8")
+    key = cache.key({"project_id": "p"})
+
+    assert cache.lookup(key) is None
+    cache.store(key, source)
+
+    assert cache.lookup(key).age_seconds < 60
+    assert cache.lookup(key, now=time.time() + 120) is None
+    assert cache.key({"project_id": "q"}) != key
+    assert not CollectionCache(tmp_path / "cache", ttl_seconds=0).enabled
+
+
+def test_from_config(tmp_path):
+    """Test the TTL and switch in [collector.cache]."""
+    with patch("app.collector.cache.PaddiConfig") as mock_config:
+        config = mock_config.load.return_value
+        config.section.return_value = {}
+        default = CollectionCache.from_config()
+        assert (default.ttl_seconds, default.enabled) == (900, False)
+
+        config.section.return_value = {"ttl_seconds": 30, "enabled": True}
+        cache = CollectionCache.from_config(config)
+
+    assert (cache.ttl_seconds, cache.enabled) == (30, True)
+
+
+@patch("app.cli.commands.collector_main")
+def test_collect_reuses_cached_collection(mock_collector, workspace, caplog):
+    """Test that a second collect copies the cache with a warning, and --no-cache collects again."""
+    mock_collector.side_effect = _collect("p")
+    context = CommandContext(project_id="p", use_mock=True)
+
+    CollectCommand().execute(context)
+    (workspace / "data" / "collected.json").unlink()
+    with caplog.at_level("WARNING"):
+        CollectCommand().execute(context)
+
+    assert mock_collector.call_count == 1
+    assert "Using the collection cached 0 min ago" in caplog.text
+    collected = json.loads((workspace / "data" / "collected.json").read_text(encoding="utf-8"))
+    assert collected["metadata"]["project_id"] == "p"
+
+    CollectCommand().execute(CommandContext(project_id="p", use_mock=True, no_cache=True))
+    CollectCommand().execute(CommandContext(project_id="other", use_mock=True))
+    assert mock_collector.call_count == 3
+
+
+@patch("app.cli.commands.collector_main")
+def test_collect_without_cache_by_default(mock_collector, workspace):
+    """Test that collections are not cached unless paddi.toml turns the cache on."""
+    (workspace / "paddi.toml").unlink()
+    mock_collector.side_effect = _collect("p")
+
+    CollectCommand().execute(CommandContext(project_id="p", use_mock=True))
+    CollectCommand().execute(CommandContext(project_id="p", use_mock=True))
+
+    assert mock_collector.call_count == 2
+    assert not (workspace / "data" / "cache").exists()
+
+
+@patch("app.cli.commands.collector_main")
+def test_collect_does_not_cache_stale_output(mock_collector, workspace):
+    """Test that a collected.json the collector did not write is not cached."""
+    (workspace / "data").mkdir()
+    stale = workspace / "data" / "collected.json"
+    stale.write_text("{}", encoding="utf-8")
+    os.utime(stale, (0, 0))
+
+    CollectCommand().execute(CommandContext(project_id="p", use_mock=True))
+    CollectCommand().execute(CommandContext(project_id="p", use_mock=True))
+
+    assert mock_collector.call_count == 2
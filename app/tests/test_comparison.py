@@ -0,0 +1,79 @@
+"""Tests for the report comparing two recorded runs."""
+
+import pytest
+
+from app.cli.base import CommandContext
+from app.cli.commands import ReportCommand
+from app.cli.paddi_cli import join_compare_runs
+from app.history.store import HISTORY_DB_NAME, RunStore
+from app.reporter.branding import Branding
+from app.reporter.comparison import compare_runs, render_html, render_markdown
+
+BEFORE = [
+    {"title": "Public bucket", "category": "STORAGE", "resource": "gs://a", "severity": "MEDIUM"},
+    {"title": "Old key", "category": "KEY", "resource": "sa-1", "severity": "LOW"},
+    {"title": "No MFA", "category": "MFA", "resource": "org", "severity": "HIGH"},
+]
+AFTER = [
+    {"title": "Public bucket", "category": "STORAGE", "resource": "gs://a", "severity": "HIGH"},
+    {"title": "No MFA", "category": "MFA", "resource": "org", "severity": "HIGH"},
+    {"title": "Owner role", "category": "IAM", "resource": "user:x", "severity": "CRITICAL"},
+]
+
+
+@pytest.fixture
+def store(tmp_path):
+    store = RunStore(tmp_path / "data" / HISTORY_DB_NAME)
+    store.record_run(BEFORE, "p")
+    store.record_run(AFTER, "p")
+    return store
+
+
+def test_compare_runs(store):
+    """Test new, resolved, and regressed findings, matched by fingerprint."""
+    comparison = compare_runs(store, 1, 2)
+
+    assert [f["title"] for f in comparison.new] == ["Owner role"]
+    assert [f["title"] for f in comparison.resolved] == ["Old key"]
+    assert [(b["severity"], a["severity"]) for b, a in comparison.regressed] == [
+        ("MEDIUM", "HIGH")
+    ]
+    assert comparison.unchanged == 1
+
+    with pytest.raises(ValueError):
+        compare_runs(store, 1, 9)
+
+
+def test_render_comparison(store):
+    """Test that both formats show each kind of change."""
+    comparison = compare_runs(store, 1, 2)
+
+    markdown = render_markdown(comparison)
+    assert "1 new, 1 resolved, 1 regressed, 1 unchanged" in markdown
+    assert "- 🟠 `HIGH` **Public bucket** `gs://a` (was MEDIUM)" in markdown
+
+    html = render_html(comparison, Branding())
+    assert "<h2>Regressed Findings</h2>" in html
+    assert "<strong>Old key</strong> <code>sa-1</code>" in html
+
+
+def test_report_compare_command(store, tmp_path, monkeypatch):
+    """Test that report --compare writes the comparison in the selected formats."""
+    monkeypatch.chdir(tmp_path)
+
+    ReportCommand().execute(CommandContext(compare=(1, 2), formats="markdown"))
+
+    assert (tmp_path / "output" / "comparison-1-2.md").exists()
+    assert not (tmp_path / "output" / "comparison-1-2.html").exists()
+    with pytest.raises(ValueError):
+        ReportCommand().execute(CommandContext(compare="1"))
+
+
+def test_join_compare_runs():
+    """Test that the two run IDs after --compare become one Fire value."""
+    assert join_compare_runs(["report", "--compare", "3", "7", "--verbose"]) == [
+        "report",
+        "--compare=3,7",
+        "--verbose",
+    ]
+    assert join_compare_runs(["report", "--compare=3,7"]) == ["report", "--compare=3,7"]
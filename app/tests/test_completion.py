@@ -0,0 +1,69 @@
+"""Tests for shell completion."""
+
+from unittest.mock import MagicMock, patch
+
+import pytest
+
+from app.cli.base import CommandContext
+from app.cli.commands import CompletionCommand
+from app.cli.completion import completion_script, project_ids, run_ids
+from app.history.manifest import RunManifest
+from app.history.store import HISTORY_DB_NAME, RunStore
+
+
+@pytest.mark.parametrize("shell", ["bash", "zsh", "fish"])
+def test_scripts_complete_ids_dynamically(shell):
+    """Test that every script lists the commands and asks paddi for the IDs."""
+    script = completion_script(shell, ["audit", "runs"])
+
+    assert "audit runs" in script
+    assert "paddi completion --complete=" in script
+
+
+def test_unknown_shell():
+    """Test that an unsupported shell is rejected."""
+    with pytest.raises(ValueError):
+        completion_script("powershell", [])
+
+
+@patch("app.cli.completion.shutil.which", return_value="/usr/bin/gcloud")
+@patch("app.cli.completion.subprocess.run")
+def test_project_ids(mock_run, _which, tmp_path):
+    """Test that projects come from gcloud, paddi.toml, and the run history, once each."""
+    mock_run.return_value = MagicMock(returncode=0, stdout="prod\nstaging\n\n")
+    config = MagicMock()
+    config.section.return_value = {"project_id": "staging"}
+    RunStore(tmp_path / HISTORY_DB_NAME).record_run([], "dev")
+
+    assert project_ids(config, tmp_path) == ["prod", "staging", "dev"]
+
+
+@patch("app.cli.completion.shutil.which", return_value=None)
+def test_project_ids_without_gcloud(_which, tmp_path):
+    """Test that completion works without gcloud or history."""
+    config = MagicMock()
+    config.section.return_value = {}
+
+    assert project_ids(config, tmp_path) == []
+
+
+def test_run_ids(tmp_path):
+    """Test that 'runs' completes manifest IDs and 'baseline' history IDs."""
+    manifest = RunManifest.start("audit", ())
+    manifest.save(tmp_path / "runs")
+    store = RunStore(tmp_path / HISTORY_DB_NAME)
+    store.record_run([{"title": "a"}], "p")
+    store.record_run([{"title": "b"}], "p")
+
+    assert run_ids("runs", tmp_path) == [manifest.run_id]
+    assert run_ids("baseline", tmp_path) == ["2", "1"]
+
+
+def test_command_prints_candidates(tmp_path, monkeypatch, capsys):
+    """Test that --complete prints one candidate per line instead of the script."""
+    monkeypatch.chdir(tmp_path)
+    RunManifest.start("audit", ()).save()
+
+    CompletionCommand().execute(CommandContext(completion_kind="run_id", target="runs"))
+
+    assert len(capsys.readouterr().out.splitlines()) == 1
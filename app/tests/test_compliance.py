@@ -0,0 +1,201 @@
+"""Tests for compliance framework mapping and coverage."""
+
+import csv
+import io
+import json
+
+import pytest
+
+from app.common.exceptions import ConfigurationError
+from app.compliance import FRAMEWORKS
+from app.compliance.cis_gcp import CIS_GCP
+from app.compliance.framework import FAIL, PASS, coverage_to_csv, evaluate
+from app.compliance.mapping import load_frameworks
+from app.config.paddi_config import PaddiConfig
+from app.reporter.agent_reporter import MarkdownGenerator, ReportService, main
+
+FINDINGS = [
+    {
+        "title": "Public bucket",
+        "severity": "HIGH",
+        "explanation": "Bucket is public",
+        "recommendation": "Remove allUsers",
+        "category": "PUBLIC_BUCKET",
+        "resource": "//storage.googleapis.com/assets",
+        "fingerprint": "aaaaaaaaaaaaaaaa",
+    },
+    {
+        "title": "SSH open to the internet",
+        "severity": "HIGH",
+        "explanation": "0.0.0.0/0 on port 22",
+        "recommendation": "Restrict source ranges",
+        "category": "open_firewall",
+        "resource": "projects/p/global/firewalls/allow-ssh",
+    },
+]
+
+
+class TestCisMapping:
+    """Tests for the CIS GCP Benchmark mapping."""
+
+    def test_registered(self):
+        """Test that CIS is available under its format key."""
+        assert FRAMEWORKS["cis"] is CIS_GCP
+
+    def test_controls_for_category(self):
+        """Test looking up controls for a category."""
+        assert [c.control_id for c in CIS_GCP.controls_for("open_firewall")] == ["3.6", "3.7"]
+        assert CIS_GCP.controls_for("UNKNOWN") == []
+
+    def test_evaluate(self):
+        """Test per-control pass/fail coverage."""
+        coverage = evaluate(CIS_GCP, FINDINGS)
+        statuses = {r.control.control_id: r.status for r in coverage.results}
+
+        assert statuses["5.1"] == FAIL
+        assert statuses["3.6"] == FAIL
+        assert statuses["2.1"] == PASS
+        assert coverage.failed == 3
+        assert coverage.passed == len(CIS_GCP.controls) - 3
+
+    def test_firewall_controls_by_port(self):
+        """Test that SSH and RDP controls fail only for findings exposing their port."""
+        rdp = dict(FINDINGS[1], ports=[3389, 5432])
+        database = dict(FINDINGS[1], ports=[5432])
+
+        unknown = FINDINGS[1]
+        for findings, failed in (([rdp], ["3.7"]), ([database], []), ([unknown], ["3.6", "3.7"])):
+            coverage = evaluate(CIS_GCP, findings)
+            assert [r.control.control_id for r in coverage.results if r.status == FAIL] == failed
+
+    def test_csv_export(self):
+        """Test CSV rows contain status, counts and fingerprints."""
+        rows = list(csv.DictReader(io.StringIO(coverage_to_csv(evaluate(CIS_GCP, FINDINGS)))))
+        by_id = {row["control_id"]: row for row in rows}
+
+        assert len(rows) == len(CIS_GCP.controls)
+        assert by_id["5.1"]["status"] == "FAIL"
+        assert by_id["5.1"]["findings"] == "1"
+        assert by_id["5.1"]["fingerprints"] == "aaaaaaaaaaaaaaaa"
+        assert by_id["1.5"]["status"] == "PASS"
+
+
+class TestComplianceReport:
+    """Tests for compliance output from the reporter."""
+
+    def test_cis_csv_format(self, tmp_path):
+        """Test that --formats=cis-csv writes the coverage CSV and the report section."""
+        input_dir = tmp_path / "data"
+        input_dir.mkdir()
+        (input_dir / "explained.json").write_text(json.dumps(FINDINGS))
+        output_dir = tmp_path / "output"
+
+        main(
+            input_dir=str(input_dir),
+            output_dir=str(output_dir),
+            template_dir=None,
+            formats="markdown,cis-csv",
+        )
+
+        assert (output_dir / "cis_coverage.csv").exists()
+        assert not (output_dir / "audit.html").exists()
+        content = (output_dir / "audit.md").read_text(encoding="utf-8")
+        assert "## CIS Google Cloud Platform Foundation Benchmark v2.0.0" in content
+        assert "| 5.1 | Ensure that Cloud Storage bucket" in content
+
+
+class TestSoc2Mapping:
+    """Tests for the SOC 2 mapping and user mapping files."""
+
+    def test_soc2_registered(self):
+        """Test that SOC 2 is available as a built-in framework."""
+        coverage = evaluate(FRAMEWORKS["soc2"], FINDINGS)
+        statuses = {r.control.control_id: r.status for r in coverage.results}
+
+        assert statuses["CC6.6"] == FAIL
+        assert statuses["CC7.2"] == PASS
+
+    def test_mapping_file_replaces_builtin(self, tmp_path):
+        """Test that a mapping file configured in paddi.toml replaces SOC 2."""
+        (tmp_path / "soc2.toml").write_text(
+            '[[controls]]\nid = "CC9.9"\ntitle = "Custom"\ncategories = ["public_bucket"]\n'
+        )
+        config = PaddiConfig(
+            {"compliance": {"mappings": {"soc2": "soc2.toml"}}}, tmp_path / "paddi.toml"
+        )
+
+        frameworks = load_frameworks(config)
+
+        soc2 = frameworks["soc2"]
+        assert soc2.name == FRAMEWORKS["soc2"].name
+        assert [(c.control_id, c.categories) for c in soc2.controls] == [
+            ("CC9.9", ["PUBLIC_BUCKET"])
+        ]
+        assert frameworks["cis"] is CIS_GCP
+
+    def test_missing_mapping_file(self, tmp_path):
+        """Test that a missing mapping file is a configuration error."""
+        config = PaddiConfig({"compliance": {"mappings": {"soc2": str(tmp_path / "none.toml")}}})
+
+        with pytest.raises(ConfigurationError):
+            load_frameworks(config)
+
+    def test_report_lists_exceptions(self, tmp_path):
+        """Test that the appendix lists failing controls with their findings."""
+        service = ReportService(input_dir=tmp_path, output_dir=tmp_path / "output")
+        report = service.create_report(FINDINGS, {"project_id": "demo"})
+
+        content = MarkdownGenerator().generate(report)
+
+        appendix = content[content.index("## SOC 2 Trust Services Criteria") :]
+        assert "### Exceptions" in appendix
+        assert "- **CC6.6** Logical access restricted from outside system boundaries" in appendix
+        assert "  - SSH open to the internet (`projects/p/global/firewalls/allow-ssh`)" in appendix
+
+
+class TestPciDss:
+    """Tests for the PCI DSS mapping and in-scope flagging."""
+
+    def test_pci_requirements(self):
+        """Test that findings are grouped under PCI requirements."""
+        coverage = evaluate(FRAMEWORKS["pci-dss"], FINDINGS)
+        failing = {r.control.control_id: len(r.findings) for r in coverage.results if r.findings}
+
+        assert failing == {"1.3": 1, "1.4": 2, "7.2": 1}
+        assert not coverage.scoped
+
+    def test_in_scope_predicate(self):
+        """Test that the scope predicate flags findings per requirement."""
+        coverage = evaluate(
+            FRAMEWORKS["pci-dss"], FINDINGS, lambda f: "firewalls" in f.get("resource", "")
+        )
+        by_id = {r.control.control_id: r for r in coverage.results}
+
+        assert coverage.scoped
+        assert [f["title"] for f in by_id["1.4"].in_scope] == ["SSH open to the internet"]
+        assert by_id["7.2"].in_scope == []
+
+    def test_report_framework_selection_and_scope(self, tmp_path, monkeypatch):
+        """Test --framework pci-dss with in-scope resources defined by config tags."""
+        monkeypatch.chdir(tmp_path)
+        (tmp_path / "paddi.toml").write_text(
+            '[tags]\ncde = ["projects/p/global/firewalls/*"]\n\n'
+            '[compliance.scope]\npci-dss = ["cde"]\n'
+        )
+        service = ReportService(input_dir=tmp_path, output_dir=tmp_path / "output")
+        report = service.create_report(FINDINGS, {"project_id": "demo"}, ["pci-dss"])
+
+        content = MarkdownGenerator().generate(report)
+
+        assert [c.framework.key for c in report.compliance] == ["pci-dss"]
+        assert "## CIS Google Cloud" not in content
+        assert "| 1.4 | Network connections" in content
+        assert "| FAIL | 2 | 1 |" in content
+        assert "(`projects/p/global/firewalls/allow-ssh`) **[in scope]**" in content
+
+    def test_unknown_framework(self, tmp_path):
+        """Test that selecting an unknown framework is an error."""
+        service = ReportService(input_dir=tmp_path, output_dir=tmp_path / "output")
+
+        with pytest.raises(ValueError):
+            service.create_report(FINDINGS, {"project_id": "demo"}, ["iso-27001"])
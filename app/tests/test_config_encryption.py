@@ -0,0 +1,108 @@
+"""Tests for encrypted secret fields in paddi.toml."""
+
+from unittest.mock import MagicMock, patch
+
+import pytest
+from cryptography.fernet import Fernet
+
+from app.cli.base import CommandContext
+from app.cli.commands import ConfigCommand
+from app.common.exceptions import ConfigurationError
+from app.config.encryption import KMSCipher, decrypt_value, encrypt_file, is_sensitive
+from app.config.paddi_config import PaddiConfig
+
+CONFIG = """# Settings
+[export.splunk_hec]
+url = "https://splunk.example.com:8088"
+token = "hec-token"
+token_env = "SPLUNK_HEC_TOKEN"
+
+[notifications.webhooks.siem]
+url = "https://siem.example.com/hook"
+secret = "hmac-secret"
+
+[notifications.datadog]
+api_key = "vault:secret/paddi#dd"
+"""
+
+
+@pytest.fixture
+def config_key(monkeypatch):
+    """Provide a Fernet key through the environment."""
+    key = Fernet.generate_key().decode("ascii")
+    monkeypatch.setenv("PADDI_CONFIG_KEY", key)
+    return key
+
+
+class TestEncryptFile:
+    """Tests for ``paddi config encrypt``."""
+
+    def test_encrypts_and_decrypts(self, tmp_path, config_key):
+        """Test that secrets are encrypted in place and decrypted on access."""
+        path = tmp_path / "paddi.toml"
+        path.write_text(CONFIG, encoding="utf-8")
+
+        assert encrypt_file(path) == [
+            "export.splunk_hec.token",
+            "notifications.webhooks.siem.secret",
+        ]
+        text = path.read_text(encoding="utf-8")
+        assert "hec-token" not in text and "hmac-secret" not in text
+        assert text.startswith("# Settings\n")
+        assert 'api_key = "vault:secret/paddi#dd"' in text
+
+        config = PaddiConfig.load(str(path))
+        assert config.data["export"]["splunk_hec"]["token"].startswith("enc:fernet:")
+        assert config.get("export.splunk_hec.token") == "hec-token"
+        assert config.get("notifications.webhooks.siem.secret") == "hmac-secret"
+        assert encrypt_file(path) == []
+
+    def test_wrong_key(self, tmp_path, config_key, monkeypatch):
+        """Test that decrypting with another key fails clearly."""
+        path = tmp_path / "paddi.toml"
+        path.write_text(CONFIG, encoding="utf-8")
+        encrypt_file(path)
+
+        monkeypatch.setenv("PADDI_CONFIG_KEY", Fernet.generate_key().decode("ascii"))
+        with pytest.raises(ConfigurationError):
+            PaddiConfig.load(str(path)).get("export.splunk_hec.token")
+
+    def test_command(self, tmp_path, config_key, capsys):
+        """Test the config encrypt command output."""
+        path = tmp_path / "paddi.toml"
+        path.write_text(CONFIG, encoding="utf-8")
+        ConfigCommand().execute(CommandContext(action="encrypt", config_file=str(path)))
+
+        out = capsys.readouterr().out
+        assert "  - export.splunk_hec.token" in out
+        assert f"Encrypted 2 field(s) in {path}" in out
+
+
+class TestKMS:
+    """Tests for Cloud KMS encryption."""
+
+    def test_round_trip(self):
+        """Test encrypting and decrypting through the KMS client."""
+        client = MagicMock()
+        client.encrypt.return_value.ciphertext = b"\x00cipher"
+        client.decrypt.return_value.plaintext = b"plain"
+        cipher = KMSCipher("projects/p/locations/global/keyRings/r/cryptoKeys/k", client)
+
+        value = cipher.encrypt("plain")
+        assert value == "enc:kms:AGNpcGhlcg=="
+        section = {"provider": "kms", "kms_key": cipher.key_name}
+        with patch("app.config.encryption.KMSCipher.client", client):
+            assert decrypt_value(value, section) == "plain"
+        assert client.decrypt.call_args[1]["request"]["ciphertext"] == b"\x00cipher"
+
+    def test_kms_key_required(self):
+        """Test that KMS values need the key name."""
+        with pytest.raises(ConfigurationError):
+            decrypt_value("enc:kms:AAAA", {})
+
+
+def test_is_sensitive():
+    """Test which field names count as secrets."""
+    assert is_sensitive("token") and is_sensitive("hec_token") and is_sensitive("api_key")
+    assert not is_sensitive("token_env") and not is_sensitive("url")
+    assert is_sensitive("client_id", ("client_id",))
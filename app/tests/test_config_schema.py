@@ -0,0 +1,138 @@
+"""Tests for strict config validation and its diagnostics."""
+
+import json
+
+import pytest
+
+from app.cli.base import CommandContext
+from app.cli.commands import ConfigCommand
+from app.common.exceptions import ConfigurationError
+from app.config.paddi_config import PaddiConfig
+from app.config.schema import check_schema
+from app.config.validation import diagnose_config, find_line, format_diagnostic
+
+CONFIG = """[general]
+project_id = "my-project"
+
+[execution]
+timeout_seconds = 0
+retyr_count = 3
+"""
+
+
+class TestCheckSchema:
+    """Tests for check_schema."""
+
+    def test_valid(self):
+        """Test that documented keys pass."""
+        data = {
+            "general": {"project_id": "p", "use_mock": True},
+            "execution": {"timeout_seconds": 300, "retry_delay": 0.5},
+            "notifications": {"webhooks": {"siem": {"url": "https://x", "retries": 2}}},
+            "agents": {"collector": {"anything": 1}},
+        }
+        assert check_schema(data) == []
+
+    def test_unknown_key_suggestion(self):
+        """Test that misspelled keys get a suggestion."""
+        diagnostics = check_schema({"execution": {"retyr_count": 3}, "genral": {}})
+        assert [str(d) for d in diagnostics] == [
+            "execution.retyr_count: unknown key (did you mean 'retry_count'?)",
+            "genral: unknown key (did you mean 'general'?)",
+        ]
+
+    def test_ranges_and_types(self):
+        """Test values out of range and of the wrong type."""
+        diagnostics = check_schema(
+            {
+                "execution": {"timeout_seconds": 0, "parallelization": "4"},
+                "secrets": {"vault": {"kv_version": 3}},
+            }
+        )
+        assert [str(d) for d in diagnostics] == [
+            "execution.timeout_seconds: must be greater than 0, got 0",
+            "execution.parallelization: expected an integer, got '4'",
+            "secrets.vault.kv_version: invalid value 3 (expected one of: 1, 2)",
+        ]
+
+    def test_booleans_are_not_numbers(self):
+        """Test that true is not accepted as an integer."""
+        diagnostics = check_schema({"export": {"splunk_hec": {"batch_size": True}}})
+        assert diagnostics[0].message == "expected an integer, got True"
+
+
+class TestDiagnostics:
+    """Tests for line numbers and rendering."""
+
+    def test_find_line(self):
+        """Test locating keys, tables, and parents of unknown keys."""
+        assert find_line(CONFIG, "execution.retyr_count") == 6
+        assert find_line(CONFIG, "execution") == 4
+        assert find_line(CONFIG, "general.nope.deeper") == 1
+        assert find_line(CONFIG, "vault:secret/x") is None
+
+    def test_diagnose_config_lines(self, tmp_path):
+        """Test that diagnostics carry the line of their key."""
+        path = tmp_path / "paddi.toml"
+        path.write_text(CONFIG, encoding="utf-8")
+
+        diagnostics = diagnose_config(PaddiConfig.load(str(path), strict=False))
+
+        assert [(d.key, d.line) for d in diagnostics] == [
+            ("execution.timeout_seconds", 5),
+            ("execution.retyr_count", 6),
+        ]
+
+    def test_format_diagnostic(self, tmp_path):
+        """Test the snippet with location and help."""
+        diagnostic = check_schema({"execution": {"retyr_count": 3}})[0]
+        diagnostic.line = 6
+
+        lines = format_diagnostic(diagnostic, "paddi.toml", CONFIG)
+
+        assert lines == [
+            "error: execution.retyr_count: unknown key",
+            " --> paddi.toml:6",
+            "  |",
+            "6 | retyr_count = 3",
+            "  |",
+            "  = help: did you mean 'retry_count'?",
+        ]
+
+
+class TestStrictLoad:
+    """Tests for strict parsing in PaddiConfig.load."""
+
+    def test_load_rejects_unknown_keys(self, tmp_path):
+        """Test that commands refuse a config with schema errors."""
+        path = tmp_path / "paddi.toml"
+        path.write_text(CONFIG, encoding="utf-8")
+
+        with pytest.raises(ConfigurationError) as exc:
+            PaddiConfig.load(str(path))
+        assert "retyr_count" in exc.value.details["error"]
+        assert PaddiConfig.load(str(path), strict=False).get("execution.retyr_count") == 3
+
+    def test_validate_command(self, tmp_path, capsys):
+        """Test the rendered and JSON output of config validate."""
+        path = tmp_path / "paddi.toml"
+        path.write_text(CONFIG, encoding="utf-8")
+
+        with pytest.raises(ConfigurationError):
+            ConfigCommand().execute(CommandContext(action="validate", config_file=str(path)))
+        out = capsys.readouterr().out
+        assert "2 error(s)" in out
+        assert f"--> {path}:6" in out
+        assert "help: did you mean 'retry_count'?" in out
+
+        with pytest.raises(ConfigurationError):
+            ConfigCommand().execute(
+                CommandContext(action="validate", config_file=str(path), output_format="json")
+            )
+        data = json.loads(capsys.readouterr().out)
+        assert data["diagnostics"][0] == {
+            "key": "execution.timeout_seconds",
+            "message": "must be greater than 0, got 0",
+            "help": None,
+            "line": 5,
+        }
@@ -0,0 +1,131 @@
+"""Tests for paddi config get / config set."""
+
+import json
+
+import pytest
+
+from app.cli.base import CommandContext
+from app.cli.commands import ConfigCommand
+from app.common.exceptions import ConfigurationError
+from app.config.paddi_config import PaddiConfig
+from app.config.writer import parse_value
+
+CONFIG = """[general]
+project_id = "old-project"
+use_mock = true
+
+[execution]
+timeout_seconds = 300
+
+[reporter]
+formats = ["markdown", "html"]
+"""
+
+
+@pytest.fixture
+def config_file(tmp_path):
+    """Write a sample paddi.toml."""
+    path = tmp_path / "paddi.toml"
+    path.write_text(CONFIG, encoding="utf-8")
+    return path
+
+
+def run(action, key, value=None, path=None, output_format="table"):
+    """Run the config command."""
+    ConfigCommand().execute(
+        CommandContext(
+            action=action,
+            target=key,
+            config_value=value,
+            config_file=str(path) if path else None,
+            output_format=output_format,
+        )
+    )
+
+
+class TestParseValue:
+    """Tests for type conversion."""
+
+    def test_inferred_types(self):
+        """Test types of new keys."""
+        assert parse_value("true") is True
+        assert parse_value("42") == 42
+        assert parse_value('["a", "b"]') == ["a", "b"]
+        assert parse_value("my-project") == "my-project"
+
+    def test_existing_type_kept(self):
+        """Test that strings stay strings and numbers are checked."""
+        assert parse_value("123", "old") == "123"
+        assert parse_value(5, "old") == "5"
+        assert parse_value("2", 1.5) == 2.0
+        with pytest.raises(ConfigurationError):
+            parse_value("yes", True)
+        with pytest.raises(ConfigurationError):
+            parse_value("soon", 300)
+
+
+class TestConfigSet:
+    """Tests for config set."""
+
+    def test_updates_value(self, config_file, capsys):
+        """Test replacing a value and keeping the rest of the file."""
+        run("set", "general.project_id", "my-proj", config_file)
+
+        config = PaddiConfig.load(str(config_file))
+        assert config.get("general.project_id") == "my-proj"
+        assert config.get("execution.timeout_seconds") == 300
+        assert 'Set general.project_id = "my-proj"' in capsys.readouterr().out
+
+    def test_new_key(self, config_file):
+        """Test adding a key to a new table."""
+        run("set", "collector.resource_types", '["iam"]', config_file)
+        assert PaddiConfig.load(str(config_file)).get("collector.resource_types") == ["iam"]
+
+    def test_unknown_key(self, config_file):
+        """Test that keys outside the schema are not written."""
+        with pytest.raises(ConfigurationError) as exc:
+            run("set", "execution.timeout_secs", "60", config_file)
+        assert "did you mean 'timeout_seconds'?" in exc.value.details["error"]
+        assert config_file.read_text(encoding="utf-8") == CONFIG
+
+    def test_type_mismatch(self, config_file):
+        """Test that the file is unchanged when the type is wrong."""
+        with pytest.raises(ConfigurationError):
+            run("set", "execution.timeout_seconds", "fast", config_file)
+        assert config_file.read_text(encoding="utf-8") == CONFIG
+
+    def test_rejected_by_loader(self, config_file, monkeypatch):
+        """Test that values the section's loader rejects are not written."""
+        monkeypatch.delenv("DD_API_KEY", raising=False)
+        with pytest.raises(ConfigurationError) as exc:
+            run("set", "notifications.datadog.site", "datadoghq.com", config_file)
+        assert "notifications.datadog" in exc.value.details["error"]
+        assert "datadog" not in config_file.read_text(encoding="utf-8")
+
+    def test_table(self, config_file):
+        """Test that whole tables cannot be overwritten."""
+        with pytest.raises(ConfigurationError):
+            run("set", "general", "x", config_file)
+
+
+class TestConfigGet:
+    """Tests for config get."""
+
+    def test_scalar(self, config_file, capsys):
+        """Test printing strings bare and other values as TOML."""
+        run("get", "general.project_id", path=config_file)
+        run("get", "reporter.formats", path=config_file)
+        assert capsys.readouterr().out == 'old-project\n["markdown", "html"]\n'
+
+    def test_table_and_json(self, config_file, capsys):
+        """Test printing a table and structured output."""
+        run("get", "general", path=config_file)
+        assert "general.use_mock = true" in capsys.readouterr().out
+
+        run("get", "general.use_mock", path=config_file, output_format="json")
+        assert json.loads(capsys.readouterr().out) == {"key": "general.use_mock", "value": True}
+
+    def test_missing(self, config_file):
+        """Test the error for unset keys."""
+        with pytest.raises(ConfigurationError):
+            run("get", "general.nope", path=config_file)
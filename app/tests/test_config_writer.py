@@ -0,0 +1,62 @@
+"""Tests for updating values in paddi.toml."""
+
+import pytest
+
+from app.common.exceptions import ConfigurationError
+from app.config.paddi_config import PaddiConfig
+from app.config.writer import set_config_value, toml_value
+
+
+class TestSetConfigValue:
+    """Tests for set_config_value."""
+
+    def test_creates_file(self, tmp_path):
+        """Test writing a value to a new file."""
+        path = tmp_path / "paddi.toml"
+        set_config_value(path, "general.project_id", "my-project")
+        assert path.read_text(encoding="utf-8") == '[general]\nproject_id = "my-project"\n'
+
+    def test_replaces_value_and_keeps_comments(self, tmp_path):
+        """Test that only the matching key in the matching table changes."""
+        path = tmp_path / "paddi.toml"
+        path.write_text(
+            "# Paddi settings\n[general]\nproject_id = \"old\"  # set by hand\nuse_mock = true\n"
+            "\n[export.scc]\nproject_id = \"other\"\n",
+            encoding="utf-8",
+        )
+        set_config_value(path, "general.project_id", "new")
+
+        text = path.read_text(encoding="utf-8")
+        assert text.startswith("# Paddi settings\n[general]\nproject_id = \"new\"\n")
+        config = PaddiConfig.load(str(path), strict=False)
+        assert config.get("general.use_mock") is True
+        assert config.get("export.scc.project_id") == "other"
+
+    def test_adds_key_to_existing_table(self, tmp_path):
+        """Test inserting a key at the end of its table, before the next one."""
+        path = tmp_path / "paddi.toml"
+        path.write_text("[general]\nuse_mock = true\n\n[risk]\nmax = 1\n", encoding="utf-8")
+        set_config_value(path, "general.project_id", "p")
+
+        assert path.read_text(encoding="utf-8") == (
+            "[general]\nuse_mock = true\nproject_id = \"p\"\n\n[risk]\nmax = 1\n"
+        )
+
+    def test_adds_table(self, tmp_path):
+        """Test appending a missing (dotted) table."""
+        path = tmp_path / "paddi.toml"
+        path.write_text("[general]\nuse_mock = true\n", encoding="utf-8")
+        set_config_value(path, "notifications.datadog.enabled", False)
+
+        assert PaddiConfig.load(str(path)).get("notifications.datadog.enabled") is False
+
+    def test_key_without_table(self, tmp_path):
+        """Test that top-level keys are rejected."""
+        with pytest.raises(ConfigurationError):
+            set_config_value(tmp_path / "paddi.toml", "project_id", "p")
+
+    def test_toml_value(self):
+        """Test formatting of supported value types."""
+        assert toml_value(True) == "true"
+        assert toml_value(3) == "3"
+        assert toml_value(["a", 'b"c']) == '["a", "b\\"c"]'
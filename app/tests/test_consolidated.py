@@ -0,0 +1,86 @@
+"""Tests for the consolidated report of multi-project audits."""
+
+import json
+from unittest.mock import patch
+
+from app.cli.base import CommandContext
+from app.cli.commands import AuditCommand
+from app.findings.risk import RiskModel
+from app.reporter.branding import Branding
+from app.reporter.consolidated import (
+    build_consolidated_report,
+    render_html,
+    render_markdown,
+    write_consolidated_report,
+)
+
+FINDINGS = {
+    "prod": [
+        {"title": "Public bucket", "severity": "CRITICAL", "resource": "gs://data"},
+        {"title": "Owner role", "severity": "HIGH"},
+    ],
+    "dev": [{"title": "Old key", "severity": "LOW"}],
+}
+
+
+def test_build_consolidated_report():
+    """Test per-project counts and grades, and top risks labeled with their project."""
+    report = build_consolidated_report(FINDINGS, RiskModel())
+
+    assert [p.project_id for p in report.projects] == ["prod", "dev"]
+    assert report.projects[0].severity_counts == {"CRITICAL": 1, "HIGH": 1}
+    assert report.projects[0].risk.score == 15.0
+    assert report.projects[1].risk.grade == "A"
+    assert report.total == 3
+    assert [(f["title"], f["project"]) for f in report.top_risks] == [
+        ("Public bucket", "prod"),
+        ("Owner role", "prod"),
+        ("Old key", "dev"),
+    ]
+
+
+def test_render_consolidated_report():
+    """Test that both formats link each project's report and list the top risks."""
+    report = build_consolidated_report(FINDINGS, RiskModel())
+
+    markdown = render_markdown(report)
+    assert "| [prod](prod/audit.md) | B | 15.0 | 2 |" in markdown
+    assert "1. 🔴 `CRITICAL` **Public bucket** (prod)" in markdown
+
+    html = render_html(report, Branding(company_name="Example & Co"))
+    assert '<a href="dev/audit.html">dev</a>' in html
+    assert '<span class="project">(prod)</span> <code>gs://data</code>' in html
+    assert "Example &amp; Co" in html
+
+
+def test_write_consolidated_report_formats(tmp_path):
+    """Test that only the selected formats are written."""
+    report = build_consolidated_report(FINDINGS, RiskModel())
+
+    written = write_consolidated_report(report, tmp_path, ["markdown", "terraform"], Branding())
+
+    assert written == [tmp_path / "consolidated.md"]
+    assert not (tmp_path / "consolidated.html").exists()
+
+
+def test_multi_project_audit_writes_consolidated_report(tmp_path, monkeypatch):
+    """Test that a multi-project audit consolidates the findings of each passed project."""
+    monkeypatch.chdir(tmp_path)
+    (tmp_path / "data").mkdir()
+
+    def explain(project_id=None, **kwargs):
+        path = tmp_path / "data" / "explained.json"
+        findings = [
+            {**f, "explanation": "e", "recommendation": "r"} for f in FINDINGS[project_id]
+        ]
+        path.write_text(json.dumps(findings))
+
+    with patch("app.cli.commands.collector_main"), patch(
+        "app.cli.commands.explainer_main", side_effect=explain
+    ), patch("app.cli.commands.reporter_main", return_value=[]):
+        AuditCommand().execute(CommandContext(projects="prod,dev", formats="markdown"))
+
+    consolidated = (tmp_path / "output" / "consolidated.md").read_text()
+    assert "[dev](dev/audit.md)" in consolidated
+    assert "**Old key** (dev)" in consolidated
+    assert not (tmp_path / "output" / "consolidated.html").exists()
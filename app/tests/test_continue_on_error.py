@@ -0,0 +1,103 @@
+"""Tests for --continue-on-error partial results."""
+
+import json
+from pathlib import Path
+from unittest.mock import patch
+
+import pytest
+
+from app.cli.base import CommandContext
+from app.cli.commands import AuditCommand
+from app.common.exceptions import PartialResultsError
+from app.common.exit_codes import ExitCode, exit_code_for
+from app.common.failures import FAILURES_FILE, FailureLog, StageFailure, format_failures
+from app.reporter.agent_reporter import ReportService
+
+FINDING = {
+    "title": "Public bucket",
+    "severity": "HIGH",
+    "explanation": "Readable by anyone",
+    "recommendation": "Remove allUsers",
+}
+
+
+def fail(*args):
+    """A step that always fails."""
+    raise RuntimeError("quota exceeded")
+
+
+def test_failure_log():
+    """Test that failures are raised by default and recorded when continuing."""
+    with pytest.raises(RuntimeError):
+        FailureLog().run("report", "html", fail)
+
+    log = FailureLog(continue_on_error=True)
+    assert log.run("report", "html", fail) is None
+    assert log.run("report", "markdown", lambda: "ok") == "ok"
+    assert log.failures == [StageFailure("report", "html", "quota exceeded")]
+    assert format_failures(log.failures).splitlines()[1].split() == [
+        "report",
+        "html",
+        "quota",
+        "exceeded",
+    ]
+
+
+def test_report_skips_failed_inputs_and_formats(tmp_path):
+    """Test a partial report without the broken input, the failed format, or the provider."""
+    data_dir = tmp_path / "data"
+    data_dir.mkdir()
+    (data_dir / "explained.json").write_text(json.dumps([FINDING]), encoding="utf-8")
+    (data_dir / "collected.json").write_text(
+        json.dumps(
+            {
+                "providers": [
+                    {"provider": "gcp", "project_id": "prod"},
+                    {"provider": "aws", "status": "failed", "error": "AccessDenied"},
+                ]
+            }
+        ),
+        encoding="utf-8",
+    )
+    broken = tmp_path / "other.json"
+    broken.write_text('{"title": "not a list"}', encoding="utf-8")
+    service = ReportService(
+        input_dir=data_dir,
+        output_dir=tmp_path / "output",
+        extra_inputs=[broken],
+        continue_on_error=True,
+    )
+
+    with patch("app.reporter.agent_reporter.write_snippets", side_effect=fail):
+        failures = service.generate_reports(["markdown", "terraform"])
+
+    assert [(f.stage, f.target) for f in failures] == [
+        ("input", str(broken)),
+        ("collect", "aws"),
+        ("report", "terraform"),
+    ]
+    markdown = (tmp_path / "output" / "audit.md").read_text(encoding="utf-8")
+    assert "## Incomplete Results" in markdown
+    assert "| collect | `aws` | AccessDenied |" in markdown
+    assert "Public bucket" in markdown
+
+
+@patch("app.cli.commands.reporter_main")
+@patch("app.cli.commands.explainer_main")
+@patch("app.cli.commands.collector_main")
+def test_audit_ends_with_failure_summary(collector, explainer, reporter, tmp_path, monkeypatch):
+    """Test that the audit finishes, writes failures.json, and exits with code 8."""
+    monkeypatch.chdir(tmp_path)
+    Path("data").mkdir()
+    reporter.return_value = [StageFailure("report", "html", "disk full")]
+
+    with pytest.raises(PartialResultsError) as exc_info:
+        AuditCommand().execute(CommandContext(continue_on_error=True))
+
+    assert exit_code_for(exc_info.value) == ExitCode.PARTIAL_RESULTS
+    assert reporter.call_args.kwargs["continue_on_error"] is True
+    written = json.loads((tmp_path / "output" / FAILURES_FILE).read_text(encoding="utf-8"))
+    assert written == [{"stage": "report", "target": "html", "error": "disk full"}]
+
+    # Without the flag, the reporter's failures do not fail the audit
+    AuditCommand().execute(CommandContext())
@@ -0,0 +1,96 @@
+"""Tests for CVSS v3.1 scoring."""
+
+import pytest
+
+from app.common.exceptions import ConfigurationError
+from app.common.models import SecurityFinding
+from app.config.paddi_config import PaddiConfig
+from app.findings.cvss import DEFAULT_VECTORS, CvssScorer, base_score, parse_vector, score_rating
+
+
+class TestBaseScore:
+    """Tests for the CVSS v3.1 base score formula."""
+
+    @pytest.mark.parametrize(
+        "vector,expected",
+        [
+            ("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H", 9.8),
+            ("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:N/A:N", 7.5),
+            ("CVSS:3.1/AV:N/AC:L/PR:N/UI:R/S:C/C:L/I:L/A:N", 6.1),
+            ("CVSS:3.1/AV:N/AC:L/PR:L/UI:N/S:C/C:H/I:H/A:H", 9.9),
+            ("CVSS:3.1/AV:L/AC:H/PR:H/UI:R/S:U/C:N/I:N/A:N", 0.0),
+        ],
+    )
+    def test_reference_scores(self, vector, expected):
+        """Test scores against published reference values."""
+        assert base_score(vector) == expected
+
+    def test_invalid_vectors(self):
+        """Test that malformed vectors are rejected."""
+        with pytest.raises(ValueError):
+            parse_vector("AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H")
+        with pytest.raises(ValueError):
+            parse_vector("CVSS:3.1/AV:X/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H")
+        with pytest.raises(ValueError):
+            parse_vector("CVSS:3.1/AV:N/AC:L")
+
+    def test_default_vectors_are_valid(self):
+        """Test that every built-in vector parses."""
+        for vector in DEFAULT_VECTORS.values():
+            parse_vector(vector)
+
+    def test_score_rating(self):
+        """Test qualitative rating boundaries."""
+        assert score_rating(0.0) == "NONE"
+        assert score_rating(3.9) == "LOW"
+        assert score_rating(4.0) == "MEDIUM"
+        assert score_rating(7.0) == "HIGH"
+        assert score_rating(9.0) == "CRITICAL"
+
+
+class TestCvssScorer:
+    """Tests for CvssScorer."""
+
+    def _finding(self, **kwargs):
+        """Build a SecurityFinding with defaults."""
+        return SecurityFinding(
+            title="Public bucket", severity="HIGH", explanation="", recommendation="", **kwargs
+        )
+
+    def test_scores_known_category(self):
+        """Test that findings with a known category are scored."""
+        finding = CvssScorer().add_scores([self._finding(category="PUBLIC_BUCKET")])[0]
+
+        assert finding.cvss_vector == DEFAULT_VECTORS["PUBLIC_BUCKET"]
+        assert finding.cvss_score == 7.5
+        assert finding.to_dict()["cvss_score"] == 7.5
+
+    def test_unknown_category_unscored(self):
+        """Test that findings without a mapping are left unscored."""
+        finding = CvssScorer().add_scores([self._finding(category="SOMETHING_ELSE")])[0]
+
+        assert finding.cvss_score is None
+        assert "cvss_score" not in finding.to_dict()
+
+    def test_existing_vector_kept(self):
+        """Test that a vector supplied with the finding takes precedence."""
+        vector = "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H"
+        finding = CvssScorer().add_scores(
+            [self._finding(category="PUBLIC_BUCKET", cvss_vector=vector)]
+        )[0]
+
+        assert finding.cvss_score == 9.8
+
+    def test_config_overrides(self):
+        """Test that paddi.toml vectors replace the defaults."""
+        vector = "CVSS:3.1/AV:N/AC:H/PR:N/UI:N/S:U/C:L/I:N/A:N"
+        scorer = CvssScorer.from_config(
+            PaddiConfig({"cvss": {"vectors": {"public_bucket": vector}}})
+        )
+
+        assert scorer.vector_for("PUBLIC_BUCKET") == vector
+
+    def test_invalid_config_vector(self):
+        """Test that an invalid configured vector raises a configuration error."""
+        with pytest.raises(ConfigurationError):
+            CvssScorer.from_config(PaddiConfig({"cvss": {"vectors": {"X": "bogus"}}}))
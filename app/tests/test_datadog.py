@@ -0,0 +1,111 @@
+"""Tests for Datadog metrics and events."""
+
+from unittest.mock import Mock, patch
+
+import pytest
+import requests
+
+from app.common.exceptions import ConfigurationError
+from app.config.paddi_config import PaddiConfig
+from app.history.store import HISTORY_DB_NAME, RunStore
+from app.notifications.datadog import DatadogNotifier, build_event, build_series
+from app.notifications.webhooks import build_payload
+
+FINDINGS = [
+    {"title": "Public bucket", "severity": "HIGH", "category": "PUBLIC_BUCKET", "resource": "b1"},
+    {"title": "Owner role", "severity": "CRITICAL", "category": "OVERPRIVILEGED_ROLE"},
+]
+
+
+def metric(series, name, tag=None):
+    """Return the value of a metric, optionally selected by tag."""
+    for entry in series:
+        if entry["metric"] == name and (tag is None or tag in entry["tags"]):
+            return entry["points"][0]["value"]
+    return None
+
+
+class TestBuildSeries:
+    """Tests for metric series."""
+
+    def test_severity_counts(self):
+        """Test per-severity gauges, including zero counts."""
+        series = build_series(build_payload(FINDINGS, project_id="prod"), 100, ["env:prod"])
+
+        assert metric(series, "paddi.findings.total") == 2
+        assert metric(series, "paddi.findings.count", "severity:critical") == 1
+        assert metric(series, "paddi.findings.count", "severity:low") == 0
+        assert metric(series, "paddi.findings.new") is None
+        assert all({"env:prod", "project:prod"} <= set(entry["tags"]) for entry in series)
+
+    def test_delta_metrics(self, tmp_path):
+        """Test new and resolved gauges when a previous run exists."""
+        previous = RunStore(tmp_path / HISTORY_DB_NAME).record_run(FINDINGS[1:], "prod")
+        payload = build_payload(FINDINGS[:1], (previous, FINDINGS[1:]), "prod")
+
+        series = build_series(payload, 100, [], prefix="sec.paddi")
+
+        assert metric(series, "sec.paddi.findings.new") == 1
+        assert metric(series, "sec.paddi.findings.resolved") == 1
+
+
+class TestBuildEvent:
+    """Tests for the run event."""
+
+    def test_alert_type_follows_severity(self):
+        """Test that critical findings raise an error event."""
+        assert build_event(build_payload(FINDINGS), [])["alert_type"] == "error"
+        assert build_event(build_payload(FINDINGS[:1]), [])["alert_type"] == "warning"
+        assert build_event(build_payload([]), [])["alert_type"] == "info"
+
+    def test_text(self):
+        """Test the event title and summary."""
+        event = build_event(build_payload(FINDINGS, project_id="prod"), ["team:sec"])
+
+        assert event["title"] == "Paddi audit completed for prod: 2 finding(s)"
+        assert "Findings: CRITICAL 1, HIGH 1" in event["text"]
+        assert event["tags"] == ["team:sec", "project:prod"]
+
+
+class TestDatadogNotifier:
+    """Tests for delivery and configuration."""
+
+    def test_notify_run(self, tmp_path):
+        """Test that metrics and the event are posted with the API key."""
+        RunStore(tmp_path / HISTORY_DB_NAME).record_run(FINDINGS, "prod")
+        notifier = DatadogNotifier("key", site="datadoghq.eu")
+
+        with patch(
+            "app.notifications.datadog.requests.post", return_value=Mock(status_code=202)
+        ) as post:
+            assert notifier.notify_run(tmp_path) == {"metrics": True, "event": True}
+
+        urls = [call.args[0] for call in post.call_args_list]
+        assert urls == [
+            "https://api.datadoghq.eu/api/v2/series",
+            "https://api.datadoghq.eu/api/v1/events",
+        ]
+        assert post.call_args.kwargs["headers"] == {"DD-API-KEY": "key"}
+
+    def test_request_failure(self):
+        """Test that failures are reported per request."""
+        notifier = DatadogNotifier("key")
+        outcomes = [requests.ConnectionError("down"), Mock(status_code=403)]
+
+        with patch("app.notifications.datadog.requests.post", side_effect=outcomes):
+            assert notifier.send(build_payload(FINDINGS), 100) == {
+                "metrics": False,
+                "event": False,
+            }
+
+    def test_from_config(self, monkeypatch):
+        """Test that Datadog is off without a section and needs an API key."""
+        assert DatadogNotifier.from_config(PaddiConfig({})) is None
+
+        monkeypatch.delenv("DD_API_KEY", raising=False)
+        config = PaddiConfig({"notifications": {"datadog": {"tags": ["env:prod"]}}})
+        with pytest.raises(ConfigurationError):
+            DatadogNotifier.from_config(config)
+
+        monkeypatch.setenv("DD_API_KEY", "key")
+        assert DatadogNotifier.from_config(config).tags == ["env:prod"]
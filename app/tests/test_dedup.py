@@ -0,0 +1,85 @@
+"""Tests for cross-run finding deduplication."""
+
+import json
+
+from app.findings.dedup import deduplicate_findings
+from app.reporter.agent_reporter import ReportService
+
+
+class TestDeduplicateFindings:
+    """Tests for deduplicate_findings."""
+
+    def test_merges_identical_findings(self, make_finding):
+        """Test that repeated findings collapse into one with a count."""
+        result = deduplicate_findings([make_finding(), make_finding(), make_finding()])
+
+        assert len(result) == 1
+        assert result[0]["occurrences"] == 3
+        assert result[0]["affected_resources"] == ["//storage.googleapis.com/prod-assets"]
+        assert result[0]["fingerprint"]
+
+    def test_keeps_distinct_findings_in_order(self, make_finding):
+        """Test that findings with different fingerprints are kept separately."""
+        first = make_finding()
+        second = make_finding(resource="//storage.googleapis.com/other")
+
+        result = deduplicate_findings([first, second, first])
+
+        assert [f["resource"] for f in result] == [first["resource"], second["resource"]]
+        assert [f["occurrences"] for f in result] == [2, 1]
+
+    def test_collects_resource_spellings_and_highest_severity(self, make_finding):
+        """Test that merged findings keep every spelling and the worst severity."""
+        result = deduplicate_findings(
+            [
+                make_finding(),
+                make_finding(
+                    resource="https://storage.googleapis.com/prod-assets/", severity="HIGH"
+                ),
+            ]
+        )
+
+        assert len(result) == 1
+        assert result[0]["severity"] == "HIGH"
+        assert result[0]["affected_resources"] == [
+            "//storage.googleapis.com/prod-assets",
+            "https://storage.googleapis.com/prod-assets/",
+        ]
+
+    def test_sums_existing_occurrence_counts(self, make_finding):
+        """Test that already-deduplicated inputs are merged again correctly."""
+        merged = deduplicate_findings([make_finding(), make_finding()])
+
+        result = deduplicate_findings(merged + [make_finding()])
+
+        assert result[0]["occurrences"] == 3
+
+    def test_does_not_modify_input(self, make_finding):
+        """Test that the input findings are left untouched."""
+        finding = make_finding()
+        deduplicate_findings([finding, make_finding()])
+
+        assert "occurrences" not in finding
+
+
+class TestReportAggregation:
+    """Tests for aggregating multiple explained.json files in the reporter."""
+
+    def test_extra_inputs_are_deduplicated(self, tmp_path, make_finding):
+        """Test that findings from several runs are merged in the report."""
+        input_dir = tmp_path / "data"
+        input_dir.mkdir()
+        (input_dir / "explained.json").write_text(json.dumps([make_finding()]))
+        other_run = tmp_path / "other.json"
+        other_run.write_text(
+            json.dumps([make_finding(), make_finding(category="OPEN_FIREWALL")])
+        )
+
+        service = ReportService(
+            input_dir=input_dir, output_dir=tmp_path / "output", extra_inputs=[other_run]
+        )
+        service.generate_reports(["markdown"])
+
+        content = (tmp_path / "output" / "audit.md").read_text(encoding="utf-8")
+        assert "**Total Findings:** 2" in content
+        assert "**Occurrences:** 2" in content
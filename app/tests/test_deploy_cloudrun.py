@@ -0,0 +1,93 @@
+"""Tests for the Cloud Run job of scheduled audits."""
+
+import pytest
+import yaml
+
+from app.cli.base import CommandContext
+from app.cli.commands import DeployCommand
+from app.deploy.cloudrun import CloudRunJobOptions, build_job, build_setup_script, render_job
+
+
+def test_job_runs_as_service_account_with_secrets():
+    """Test the job's service account, audit command, and secrets from Secret Manager."""
+    options = CloudRunJobOptions("0 3 * * *", project_id="prod-1", ai_provider="ollama")
+    job = build_job(options, ["DD_API_KEY"], with_config=True)
+    task = job["spec"]["template"]["spec"]["template"]["spec"]
+    assert task["serviceAccountName"] == "paddi-auditor@prod-1.iam.gserviceaccount.com"
+    container = task["containers"][0]
+    assert container["image"] == "gcr.io/prod-1/paddi"
+    assert container["command"][2:] == [
+        "audit",
+        "--use_mock=False",
+        "--project_id=prod-1",
+        "--ai_provider=ollama",
+    ]
+    assert container["env"][0] == {"name": "PADDI_CONFIG", "value": "/etc/paddi/paddi.toml"}
+    assert container["env"][-1]["valueFrom"]["secretKeyRef"] == {
+        "name": "paddi-dd-api-key",
+        "key": "latest",
+    }
+    assert task["volumes"][0]["secret"]["secretName"] == "paddi-config"
+
+    options = CloudRunJobOptions(
+        "0 3 * * *",
+        organization_id="123",
+        job_project="ops",
+        service_account="auditor@ops.iam.gserviceaccount.com",
+    )
+    task = build_job(options, [], with_config=False)["spec"]["template"]["spec"]["template"]
+    assert task["spec"]["serviceAccountName"] == "auditor@ops.iam.gserviceaccount.com"
+    assert "volumes" not in task["spec"]
+
+
+def test_setup_script():
+    """Test the grants, secrets, job, and schedule the script sets up."""
+    options = CloudRunJobOptions("30 2 * * 1", project_id="prod-1", organization_id="123")
+    job_yaml = render_job(build_job(options, ["GITHUB_TOKEN"], with_config=False))
+    script = build_setup_script(options, ["GITHUB_TOKEN"], None, job_yaml, gcs_bucket="audits")
+
+    assert script.startswith("#!/usr/bin/env bash\n")
+    assert "gcloud iam service-accounts create paddi-auditor" in script
+    assert "gcloud organizations add-iam-policy-binding 123" in script
+    assert "roles/aiplatform.user" in script and "gs://audits" in script
+    assert "paddi-config" not in script
+    assert 'printf %s "$GITHUB_TOKEN" | gcloud secrets create paddi-github-token' in script
+    assert "--schedule '30 2 * * 1'" in script
+    assert "/jobs/paddi:run" in script
+    embedded = script.split("<<'YAML'\n")[1].split("\nYAML\n")[0]
+    assert yaml.safe_load(embedded)["kind"] == "Job"
+
+    script = build_setup_script(
+        CloudRunJobOptions("0 3 * * *", project_id="p-1", use_mock=True), [], None
+    )
+    assert "aiplatform" not in script and 'JOB_FILE="$(dirname "$0")/job.yaml"' in script
+
+
+@pytest.mark.parametrize(
+    "options",
+    [
+        CloudRunJobOptions("@daily", project_id="p"),
+        CloudRunJobOptions("0 3 * * *"),
+        CloudRunJobOptions("0 3 * * *", project_id="p", service_account="Bad_Name"),
+    ],
+)
+def test_invalid_options_rejected(options):
+    """Test that macros, a missing project, and invalid account IDs are rejected."""
+    with pytest.raises(ValueError):
+        build_job(options, [], with_config=False)
+
+
+def test_deploy_cloudrun_writes_files(tmp_path, monkeypatch):
+    """Test that deploy cloudrun writes job.yaml and an executable deploy.sh."""
+    monkeypatch.chdir(tmp_path)
+    (tmp_path / "paddi.toml").write_text('[cli]\nlang = "en"\n')
+    out = tmp_path / "cloudrun"
+    DeployCommand().execute(
+        CommandContext(
+            action="cloudrun", schedule="0 3 * * *", project_id="prod-1", output_dir=str(out)
+        )
+    )
+    assert yaml.safe_load((out / "job.yaml").read_text())["metadata"]["name"] == "paddi"
+    script = out / "deploy.sh"
+    assert script.stat().st_mode & 0o100
+    assert f"--data-file {tmp_path / 'paddi.toml'}" in script.read_text()
@@ -0,0 +1,107 @@
+"""Tests for the Kubernetes manifests of scheduled audits."""
+
+import pytest
+import yaml
+
+from app.cli.base import CommandContext
+from app.cli.commands import DeployCommand
+from app.config.paddi_config import PaddiConfig
+from app.deploy.kubernetes import PLACEHOLDER, CronJobOptions, build_manifests, render_manifests
+from app.deploy.schedule import secret_env_vars
+
+
+def test_secret_env_vars_from_config():
+    """Test that secret variables come from *_env keys, section defaults, and enc: values."""
+    config = PaddiConfig(
+        {
+            "notifications": {
+                "datadog": {"enabled": True},
+                "webhooks": {"slack": {"url": "https://x", "secret_env": "SLACK_SECRET"}},
+            },
+            "export": {
+                "github_issues": {"token_env": "ISSUES_TOKEN"},
+                "splunk_hec": {"token": "t"},
+            },
+            "ai": {"api_key": "enc:fernet:abc"},
+        }
+    )
+    assert secret_env_vars(config) == [
+        "DD_API_KEY",
+        "ISSUES_TOKEN",
+        "PADDI_CONFIG_KEY",
+        "SLACK_SECRET",
+    ]
+    assert secret_env_vars(PaddiConfig({})) == []
+
+
+def test_manifests_for_key_and_workload_identity():
+    """Test the CronJob with a key Secret, and with a Workload Identity service account."""
+    options = CronJobOptions("0 3 * * *", namespace="security", project_id="prod")
+    manifests = build_manifests(options, '[audit]\nproject_id = "prod"\n', ["DD_API_KEY"])
+    assert [(m["kind"], m["metadata"]["name"]) for m in manifests] == [
+        ("Secret", "paddi-config"),
+        ("Secret", "paddi-env"),
+        ("Secret", "paddi-gcp-credentials"),
+        ("CronJob", "paddi"),
+    ]
+    assert all(m["metadata"]["namespace"] == "security" for m in manifests)
+    assert manifests[0]["stringData"] == {"paddi.toml": '[audit]\nproject_id = "prod"\n'}
+    assert manifests[1]["stringData"] == {"DD_API_KEY": PLACEHOLDER}
+
+    cronjob = manifests[-1]["spec"]
+    assert cronjob["schedule"] == "0 3 * * *" and cronjob["concurrencyPolicy"] == "Forbid"
+    container = cronjob["jobTemplate"]["spec"]["template"]["spec"]["containers"][0]
+    assert container["command"][2:] == ["audit", "--use_mock=False", "--project_id=prod"]
+    assert {"name": "PADDI_CONFIG", "value": "/etc/paddi/paddi.toml"} in container["env"]
+
+    rendered = render_manifests(manifests)
+    assert list(yaml.safe_load_all(rendered)) == manifests
+    assert "paddi.toml: |" in rendered
+
+    options = CronJobOptions("@daily", service_account="paddi-auditor", volume_claim="paddi")
+    manifests = build_manifests(options, "", [])
+    assert [m["kind"] for m in manifests] == ["Secret", "CronJob"]
+    pod = manifests[-1]["spec"]["jobTemplate"]["spec"]["template"]["spec"]
+    assert pod["serviceAccountName"] == "paddi-auditor"
+    assert pod["volumes"][0] == {"name": "config", "secret": {"secretName": "paddi-config"}}
+    assert pod["volumes"][1] == {"name": "data", "persistentVolumeClaim": {"claimName": "paddi"}}
+
+
+@pytest.mark.parametrize("schedule", ["daily", "0 3 * *", "0 3 * * * *", "@often"])
+def test_invalid_schedule_rejected(schedule):
+    """Test that schedules that are not cron expressions are rejected."""
+    with pytest.raises(ValueError):
+        build_manifests(CronJobOptions(schedule), "", [])
+
+
+def test_deploy_command_writes_files(tmp_path, monkeypatch, caplog):
+    """Test that deploy k8s writes paddi.toml and one file per kind, and needs a schedule."""
+    monkeypatch.chdir(tmp_path)
+    (tmp_path / "paddi.toml").write_text('[export.github_issues]\nrepo = "o/r"\n')
+    out = tmp_path / "k8s"
+    DeployCommand().execute(
+        CommandContext(action="k8s", schedule="0 3 * * 1", output_dir=str(out), use_mock=False)
+    )
+    assert sorted(p.name for p in out.iterdir()) == [
+        "config.yaml",
+        "cronjob.yaml",
+        "secrets.yaml",
+    ]
+    config = yaml.safe_load((out / "config.yaml").read_text())
+    assert config["stringData"]["paddi.toml"].startswith("[export.github_issues]")
+    assert "GITHUB_TOKEN" in (out / "secrets.yaml").read_text()
+    assert "plaintext secrets" not in caplog.text
+
+    with pytest.raises(ValueError):
+        DeployCommand().execute(CommandContext(action="k8s"))
+
+
+def test_deploy_command_warns_of_plaintext_secrets(tmp_path, monkeypatch, capsys, caplog):
+    """Test that plaintext secrets in paddi.toml are named in a warning, not on stdout."""
+    monkeypatch.chdir(tmp_path)
+    (tmp_path / "paddi.toml").write_text('[export.splunk_hec]\ntoken = "hec-token"\n')
+
+    DeployCommand().execute(CommandContext(action="k8s", schedule="@daily", use_mock=False))
+
+    assert "export.splunk_hec.token" in caplog.text
+    assert "plaintext secrets" not in capsys.readouterr().out
@@ -0,0 +1,115 @@
+"""Tests for distributed audits over a shared queue."""
+
+import threading
+from collections import defaultdict
+
+import pytest
+
+from app.cli.multi_project import FAILED, PASSED
+from app.common.exceptions import CollectionError, ConfigurationError
+from app.common.exit_codes import ExitCode
+from app.config.paddi_config import PaddiConfig
+from app.distributed.broker import RedisBroker
+from app.distributed.worker import Coordinator, Worker
+from app.history.store import HISTORY_DB_NAME, RunStore
+
+
+class FakeRedis:
+    """In-memory stand-in for the list commands the broker uses."""
+
+    def __init__(self):
+        self.lists = defaultdict(list)
+        self.expires = {}
+        self.changed = threading.Condition()
+
+    def lpush(self, key, value):
+        with self.changed:
+            self.lists[key].insert(0, value)
+            self.changed.notify_all()
+
+    def brpop(self, keys, timeout):
+        with self.changed:
+            self.changed.wait_for(lambda: self.lists[keys[0]], timeout=0.05)
+            if self.lists[keys[0]]:
+                return keys[0], self.lists[keys[0]].pop()
+            return None
+
+    def expire(self, key, seconds):
+        self.expires[key] = seconds
+
+    def delete(self, key):
+        self.lists.pop(key, None)
+
+    def lrange(self, key, start, end):
+        return list(self.lists[key])
+
+    def lrem(self, key, count, value):
+        before = len(self.lists[key])
+        self.lists[key] = [item for item in self.lists[key] if item != value]
+        return before - len(self.lists[key])
+
+
+def _audit(project_id, options):
+    if project_id == "broken":
+        raise CollectionError("gcp", {"error": "denied"})
+    return [{"title": f"Open bucket in {project_id}", "severity": "HIGH"}]
+
+
+def test_broker_from_config():
+    """Test that a broker is created only for a Redis URL."""
+    assert RedisBroker.from_config(PaddiConfig({})) is None
+    broker = RedisBroker.from_config(
+        PaddiConfig({"workers": {"broker": "redis://queue:6379/0", "prefix": "scan"}})
+    )
+    assert broker.jobs_key == "scan:jobs" and broker.results_key("b") == "scan:results:b"
+    with pytest.raises(ConfigurationError):
+        RedisBroker.from_config(PaddiConfig({"workers": {"broker": "nats://queue:4222"}}))
+
+
+def test_worker_reports_success_and_failure():
+    """Test that a worker returns findings, or the error and exit code of a failed audit."""
+    broker = RedisBroker("redis://fake", FakeRedis())
+    worker = Worker(broker, _audit, name="w1")
+    assert worker.run_once(0.1) is None
+
+    for job_id, project_id in (("j1", "a"), ("j2", "broken")):
+        broker.push_job({"job_id": job_id, "batch_id": "b", "project_id": project_id})
+    assert worker.run(max_jobs=2, poll_seconds=0.1) == 2
+
+    first, second = broker.pop_result("b", 0.1), broker.pop_result("b", 0.1)
+    assert (first["project_id"], first["status"], first["worker"]) == ("a", PASSED, "w1")
+    assert first["findings"][0]["severity"] == "HIGH" and "environment" in first
+    assert second["status"] == FAILED and second["exit_code"] != 0
+    assert "gcp" in second["error"]
+    assert broker.client.expires["paddi:results:b"] > 0
+
+
+def test_coordinator_records_results_and_times_out(tmp_path):
+    """Test that the coordinator records passed projects and fails those without a result."""
+    broker = RedisBroker("redis://fake", FakeRedis())
+    store = RunStore(tmp_path / HISTORY_DB_NAME)
+    worker = Worker(broker, _audit, name="w1")
+    thread = threading.Thread(target=worker.run, kwargs={"max_jobs": 2, "poll_seconds": 0.1})
+    thread.start()
+
+    # The worker stops after two jobs, so the third project is never audited
+    batch = Coordinator(broker, store).run(["a", "broken", "c"], {"use_mock": True}, 1.0)
+    thread.join(5)
+
+    assert [(r.project_id, r.status) for r in batch.results] == [
+        ("a", PASSED),
+        ("broken", FAILED),
+        ("c", FAILED),
+    ]
+    assert batch.results[2].exit_code == int(ExitCode.TIMEOUT)
+    assert "no result" in batch.results[2].error
+    assert list(batch.findings_by_project) == ["a"]
+    assert batch.workers == {"a": "w1", "broken": "w1"}
+    assert [run.project_id for run in store.runs()] == ["a"]
+    # The timed-out batch takes its unstarted job off the queue, and only its own
+    assert broker.client.lists[broker.jobs_key] == []
+    for job_id, batch_id in (("j1", "other"), ("j2", "mine")):
+        broker.push_job({"job_id": job_id, "batch_id": batch_id, "project_id": "x"})
+    assert broker.cancel_jobs("mine") == 1
+    (left,) = broker.client.lists[broker.jobs_key]
+    assert '"batch_id": "other"' in left
@@ -0,0 +1,154 @@
+"""Tests for drift detection between Terraform and live data."""
+
+import json
+
+import pytest
+
+from app.cli.base import CommandContext
+from app.cli.commands import DriftCommand
+from app.drift.detector import MISSING, UNMANAGED, detect_drift
+
+IAC = {
+    "metadata": {"project_id": "prod"},
+    "iam_policies": {
+        "bindings": [
+            {"role": "roles/viewer", "members": ["user:auditor@example.com"]},
+            {"role": "roles/storage.admin", "members": ["group:ops@example.com"]},
+        ]
+    },
+    "bucket_iam_bindings": [],
+    "firewall_rules": [{"name": "allow-https", "source_ranges": ["0.0.0.0/0"]}],
+}
+
+LIVE = {
+    "metadata": {"project_id": "prod"},
+    "iam_policies": {
+        "bindings": [
+            {"role": "roles/viewer", "members": ["user:auditor@example.com"]},
+            {"role": "roles/owner", "members": ["user:admin@example.com"]},
+        ]
+    },
+}
+
+
+class TestDetectDrift:
+    """Tests for detect_drift."""
+
+    def test_manual_iam_grant_is_unmanaged(self):
+        """Test that a grant made outside Terraform is reported as unmanaged."""
+        report = detect_drift(IAC, LIVE)
+
+        assert [(i.status, i.detail, i.severity) for i in report.unmanaged] == [
+            (UNMANAGED, "user:admin@example.com has roles/owner", "HIGH")
+        ]
+        assert report.unmanaged[0].resource == "projects/prod"
+
+    def test_declared_grant_not_live_is_missing(self):
+        """Test that declared grants absent from the cloud are reported as missing."""
+        report = detect_drift(IAC, LIVE)
+
+        assert [(i.status, i.detail) for i in report.missing] == [
+            (MISSING, "group:ops@example.com has roles/storage.admin")
+        ]
+
+    def test_firewall_compared_only_when_collected(self):
+        """Test that firewall rules are skipped when live data has none."""
+        assert all(item.kind == "iam" for item in detect_drift(IAC, LIVE).items)
+
+        live = dict(LIVE, firewall_rules=[{"name": "allow-ssh", "source_ranges": ["0.0.0.0/0"]}])
+        firewall = [i for i in detect_drift(IAC, live).items if i.kind == "firewall"]
+
+        assert [(i.status, i.severity) for i in firewall] == [(UNMANAGED, "HIGH"), (MISSING, "LOW")]
+        assert firewall[0].resource == "projects/prod/global/firewalls/allow-ssh"
+
+    def test_public_bucket_grant(self):
+        """Test that a public bucket grant made by hand is critical."""
+        live = dict(
+            LIVE,
+            bucket_iam_bindings=[
+                {"bucket": "assets", "role": "roles/storage.objectViewer", "members": ["allUsers"]}
+            ],
+        )
+
+        item = next(i for i in detect_drift(IAC, live).items if i.kind == "bucket_iam")
+
+        assert item.severity == "CRITICAL"
+        assert item.resource == "//storage.googleapis.com/assets"
+
+    def test_multi_cloud_live_data(self):
+        """Test that the GCP provider section is used for multi-cloud data."""
+        live = {"providers": [{"provider": "aws"}, dict(LIVE, provider="gcp")]}
+
+        assert len(detect_drift(IAC, live).unmanaged) == 1
+
+    def test_no_drift(self):
+        """Test identical data."""
+        report = detect_drift(LIVE, LIVE)
+
+        assert report.items == []
+        assert report.to_dict() == {"unmanaged": 0, "missing": 0, "items": []}
+
+
+class TestDriftCommand:
+    """Tests for the drift command."""
+
+    @pytest.fixture
+    def files(self, tmp_path):
+        """Write a Terraform state and live collected data."""
+        state = {
+            "version": 4,
+            "resources": [
+                {
+                    "mode": "managed",
+                    "type": "google_project_iam_member",
+                    "name": "viewer",
+                    "instances": [
+                        {
+                            "attributes": {
+                                "project": "prod",
+                                "role": "roles/viewer",
+                                "member": "user:auditor@example.com",
+                            }
+                        }
+                    ],
+                }
+            ],
+        }
+        terraform_file = tmp_path / "terraform.tfstate"
+        terraform_file.write_text(json.dumps(state))
+        live_file = tmp_path / "collected.json"
+        live_file.write_text(json.dumps(LIVE))
+        return terraform_file, live_file
+
+    def test_table_output(self, files, capsys):
+        """Test the table and the summary line."""
+        terraform_file, live_file = files
+
+        DriftCommand().execute(
+            CommandContext(terraform_file=str(terraform_file), input_file=str(live_file))
+        )
+
+        out = capsys.readouterr().out
+        assert "user:admin@example.com has roles/owner" in out
+        assert "1 unmanaged, 0 missing (1 security-relevant)" in out
+
+    def test_json_output(self, files, capsys):
+        """Test JSON output."""
+        terraform_file, live_file = files
+
+        DriftCommand().execute(
+            CommandContext(
+                terraform_file=str(terraform_file),
+                input_file=str(live_file),
+                output_format="json",
+            )
+        )
+
+        data = json.loads(capsys.readouterr().out)
+        assert data["unmanaged"] == 1
+        assert data["items"][0]["status"] == "unmanaged"
+
+    def test_requires_terraform(self):
+        """Test that --terraform is required."""
+        with pytest.raises(ValueError):
+            DriftCommand().execute(CommandContext())
@@ -8,6 +8,15 @@ from app.common.exceptions import (
     ConfigurationError,
     PaddiException,
 )
+from app.common.i18n import set_language
+
+
+@pytest.fixture(autouse=True)
+def japanese():
+    """Show messages in Japanese, as the assertions below expect."""
+    set_language("ja")
+    yield
+    set_language(None)
 
 
 class TestPaddiException:
@@ -0,0 +1,199 @@
+"""Tests for the exec-based plugin protocol."""
+
+import json
+import stat
+import sys
+from unittest.mock import patch
+
+import pytest
+
+from app.cli.base import CommandContext
+from app.cli.commands import PluginsCommand
+from app.common.exceptions import PluginError
+from app.common.process_limits import ProcessLimits
+from app.plugins.exec_plugins import ExecPlugin, discover_exec_plugins
+
+
+def _write_plugin(directory, name, body):
+    """Write an executable Python plugin script."""
+    path = directory / f"paddi-plugin-{name}"
+    path.write_text(f"#!{sys.executable}\n{body}", encoding="utf-8")
+    path.chmod(path.stat().st_mode | stat.S_IEXEC)
+    return path
+
+
+ECHO_PLUGIN = """
+import json, sys
+request = json.load(sys.stdin)
+print("processing", file=sys.stderr)
+json.dump({
+    "findings": [{"title": request["action"], "severity": "LOW"}],
+    "data": {"config": request["config"], "input": request["input"]},
+}, sys.stdout)
+"""
+
+
+@pytest.fixture
+def bin_dir(tmp_path):
+    """Create a PATH directory with an echo plugin."""
+    directory = tmp_path / "bin"
+    directory.mkdir()
+    _write_plugin(directory, "echo", ECHO_PLUGIN)
+    return directory
+
+
+class TestDiscovery:
+    """Tests for discover_exec_plugins."""
+
+    def test_discovers_executables(self, bin_dir, tmp_path):
+        """Test that only executable paddi-plugin-* files are discovered."""
+        (bin_dir / "paddi-plugin-noexec").write_text("", encoding="utf-8")
+        (bin_dir / "other-tool").write_text("", encoding="utf-8")
+
+        plugins = discover_exec_plugins(str(bin_dir))
+
+        assert list(plugins) == ["echo"]
+        assert plugins["echo"].path == bin_dir / "paddi-plugin-echo"
+
+    def test_earlier_path_entries_win(self, bin_dir, tmp_path):
+        """Test PATH precedence for duplicate plugin names."""
+        later = tmp_path / "later"
+        later.mkdir()
+        _write_plugin(later, "echo", ECHO_PLUGIN)
+
+        plugins = discover_exec_plugins(f"{bin_dir}:{tmp_path / 'missing'}:{later}")
+
+        assert plugins["echo"].path.parent == bin_dir
+
+    def test_unreadable_path_entries_are_skipped(self, bin_dir, tmp_path):
+        """Test that PATH entries that cannot be listed do not stop discovery."""
+        locked = tmp_path / "locked"
+        locked.mkdir()
+        listdir = type(locked).iterdir
+
+        def iterdir(path):
+            if path == locked:
+                raise PermissionError(13, "Permission denied", str(path))
+            return listdir(path)
+
+        with patch.object(type(locked), "iterdir", iterdir):
+            plugins = discover_exec_plugins(f"{locked}:{bin_dir}")
+
+        assert list(plugins) == ["echo"]
+
+
+class TestExecPlugin:
+    """Tests for ExecPlugin.run."""
+
+    def test_run_round_trip(self, bin_dir):
+        """Test JSON request/response exchange."""
+        plugin = discover_exec_plugins(str(bin_dir))["echo"]
+
+        response = plugin.run("analyze", {"iam_policies": {}}, {"level": 1})
+
+        assert response.findings == [{"title": "analyze", "severity": "LOW"}]
+        assert response.data == {"config": {"level": 1}, "input": {"iam_policies": {}}}
+
+    def test_non_zero_exit(self, tmp_path):
+        """Test that a failing plugin raises PluginError."""
+        path = _write_plugin(tmp_path, "fail", "import sys\nsys.exit(3)\n")
+
+        with pytest.raises(PluginError) as exc_info:
+            ExecPlugin("fail", path).run("analyze")
+
+        assert "status 3" in exc_info.value.details["error"]
+
+    def test_invalid_json(self, tmp_path):
+        """Test that malformed output raises PluginError."""
+        path = _write_plugin(tmp_path, "bad", "print('not json')\n")
+
+        with pytest.raises(PluginError):
+            ExecPlugin("bad", path).run("analyze")
+
+
+class TestProcessLimits:
+    """Tests for the memory and CPU limits of plugin processes."""
+
+    def test_from_config(self):
+        """Test reading the limits from [execution], unlimited by default."""
+        with patch("app.common.process_limits.PaddiConfig") as mock_config:
+            mock_config.load.return_value.section.return_value = {}
+            assert not ProcessLimits.from_config().enabled
+
+        config = mock_config.load.return_value
+        config.section.return_value = {"max_memory_mb": 256, "max_cpu_percent": 25}
+        limits = ProcessLimits.from_config(config)
+
+        assert (limits.max_memory_mb, limits.max_cpu_percent) == (256, 25.0)
+        assert limits.cpu_seconds(300) == 75
+        with pytest.raises(ValueError):
+            ProcessLimits(max_cpu_percent=150)
+
+    @pytest.mark.skipif(sys.platform == "win32", reason="rlimits are Unix only")
+    def test_memory_limit_stops_plugin(self, tmp_path):
+        """Test that a plugin allocating past max_memory_mb fails with the limit named."""
+        path = _write_plugin(tmp_path, "hog", "data = bytearray(1024 * 1024 * 1024)\n")
+
+        with pytest.raises(PluginError) as exc_info:
+            ExecPlugin("hog", path).run("analyze", limits=ProcessLimits(max_memory_mb=256))
+
+        assert "memory limit (256 MB)" in exc_info.value.details["error"]
+
+    @pytest.mark.skipif(sys.platform == "win32", reason="rlimits are Unix only")
+    def test_cpu_limit_stops_plugin(self, tmp_path):
+        """Test that a busy plugin is stopped once it uses its share of the timeout."""
+        path = _write_plugin(tmp_path, "spin", "while True:\n    pass\n")
+
+        with pytest.raises(PluginError) as exc_info:
+            ExecPlugin("spin", path).run(
+                "analyze", timeout=20, limits=ProcessLimits(max_cpu_percent=5)
+            )
+
+        assert "CPU limit (5% of its timeout)" in exc_info.value.details["error"]
+
+    def test_limits_leave_plugin_working(self, bin_dir):
+        """Test that a plugin within its limits runs as usual."""
+        plugin = discover_exec_plugins(str(bin_dir))["echo"]
+
+        response = plugin.run("analyze", limits=ProcessLimits(max_memory_mb=1024))
+
+        assert response.findings == [{"title": "analyze", "severity": "LOW"}]
+
+
+class TestPluginsCommand:
+    """Tests for PluginsCommand."""
+
+    def test_list(self, bin_dir, monkeypatch, capsys):
+        """Test listing installed plugins."""
+        monkeypatch.setenv("PATH", str(bin_dir))
+
+        PluginsCommand().execute(CommandContext(action="list"))
+
+        assert "echo" in capsys.readouterr().out
+
+    def test_run_prints_response(self, bin_dir, tmp_path, monkeypatch, capsys):
+        """Test running a plugin with an input file."""
+        monkeypatch.setenv("PATH", str(bin_dir))
+        input_file = tmp_path / "collected.json"
+        input_file.write_text(json.dumps({"project_id": "p"}), encoding="utf-8")
+
+        with patch("app.cli.commands.PaddiConfig.load") as mock_load:
+            mock_load.return_value.section.return_value = {}
+            PluginsCommand().execute(
+                CommandContext(action="run", target="echo", input_file=str(input_file))
+            )
+
+        output = json.loads(capsys.readouterr().out)
+        assert output["data"]["input"] == {"project_id": "p"}
+
+    def test_run_unknown_plugin(self, tmp_path, monkeypatch):
+        """Test running a plugin that is not installed."""
+        monkeypatch.setenv("PATH", str(tmp_path))
+
+        with pytest.raises(PluginError):
+            PluginsCommand().execute(CommandContext(action="run", target="missing"))
+
+    def test_run_requires_name(self):
+        """Test that run without a plugin name is rejected."""
+        with pytest.raises(ValueError):
+            PluginsCommand().execute(CommandContext(action="run"))
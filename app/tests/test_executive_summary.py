@@ -0,0 +1,154 @@
+"""Tests for the executive summary and run history."""
+
+import json
+
+from app.history.store import HISTORY_DB_NAME, RunStore
+from app.reporter.agent_reporter import main
+from app.reporter.executive_summary import build_executive_summary, compute_trend
+
+FINDINGS = [
+    {
+        "title": "Public bucket",
+        "severity": "CRITICAL",
+        "category": "PUBLIC_BUCKET",
+        "resource": "//storage.googleapis.com/assets",
+    },
+    {
+        "title": "Owner role",
+        "severity": "HIGH",
+        "category": "OVERPRIVILEGED_ROLE",
+        "resource": "projects/prod",
+    },
+    {
+        "title": "Editor role",
+        "severity": "MEDIUM",
+        "category": "OVERPRIVILEGED_ROLE",
+        "resource": "projects/dev",
+    },
+    {"title": "Legacy", "severity": "LOW", "category": "LEGACY"},
+]
+
+
+class TestExecutiveSummary:
+    """Tests for build_executive_summary."""
+
+    def test_counts_and_top_risks(self):
+        """Test totals and that top risks are ordered by severity."""
+        summary = build_executive_summary(FINDINGS)
+
+        assert summary.total == 4
+        assert summary.severity_counts == {"CRITICAL": 1, "HIGH": 1, "MEDIUM": 1, "LOW": 1}
+        assert [f["title"] for f in summary.top_risks] == [
+            "Public bucket",
+            "Owner role",
+            "Editor role",
+            "Legacy",
+        ]
+        assert summary.trend is None
+
+    def test_priorities_rank_categories_by_risk(self):
+        """Test that priorities group findings by category, highest risk first."""
+        summary = build_executive_summary(FINDINGS)
+
+        assert summary.priorities == [
+            "Fix 1 PUBLIC_BUCKET finding(s), up to CRITICAL, "
+            "starting with //storage.googleapis.com/assets",
+            "Fix 2 OVERPRIVILEGED_ROLE finding(s), up to HIGH, starting with projects/prod",
+            "Fix 1 LEGACY finding(s), up to LOW",
+        ]
+
+    def test_trend(self, tmp_path):
+        """Test new and resolved counts against the previous run."""
+        store = RunStore(tmp_path / HISTORY_DB_NAME)
+        previous = store.record_run(FINDINGS[1:], "demo")
+        current = FINDINGS[:2]
+
+        trend = compute_trend(current, previous, store.findings_for(previous.run_id))
+
+        assert (trend.previous_total, trend.current_total) == (3, 2)
+        assert trend.delta == -1
+        assert trend.new == 1
+        assert trend.resolved == 2
+
+        summary = build_executive_summary(current, (previous, FINDINGS[1:]))
+        assert summary.priorities[-1] == (
+            "Review 1 new finding(s) introduced since the previous run"
+        )
+
+
+class TestRunStore:
+    """Tests for the SQLite run store."""
+
+    def test_record_and_read_back(self, tmp_path):
+        """Test that runs and their findings round-trip."""
+        store = RunStore(tmp_path / HISTORY_DB_NAME)
+        first = store.record_run(FINDINGS, "demo")
+        second = store.record_run(FINDINGS[:1], "demo")
+
+        assert [run.run_id for run in store.runs()] == [second.run_id, first.run_id]
+        assert store.previous_run(second.run_id).run_id == first.run_id
+        assert store.previous_run(first.run_id) is None
+        assert first.severity_counts["CRITICAL"] == 1
+        assert [f["title"] for f in store.findings_for(first.run_id)] == [
+            f["title"] for f in FINDINGS
+        ]
+
+    def test_identical_findings_not_recorded_twice(self, tmp_path):
+        """Test that re-recording unchanged findings returns the latest run."""
+        store = RunStore(tmp_path / HISTORY_DB_NAME)
+        first = store.record_run(FINDINGS, "demo")
+        again = store.record_run(list(reversed(FINDINGS)), "demo")
+
+        assert again.run_id == first.run_id
+        assert len(store.runs()) == 1
+
+    def test_projects_are_kept_apart(self, tmp_path):
+        """Test that two projects in one store dedup and compare only with their own runs."""
+        store = RunStore(tmp_path / HISTORY_DB_NAME)
+        first_a = store.record_run([], "a")
+        first_b = store.record_run([], "b")
+        second_a = store.record_run(FINDINGS, "a")
+        second_b = store.record_run([], "b")
+
+        assert first_b.run_id != first_a.run_id and first_b.project_id == "b"
+        assert second_b.run_id == first_b.run_id
+        assert store.previous_run(second_a.run_id).run_id == first_a.run_id
+        assert store.previous_run(first_b.run_id) is None
+
+
+class TestExecutiveReport:
+    """Tests for the executive summary in generated reports."""
+
+    def test_report_shows_trend_after_second_run(self, tmp_path, monkeypatch):
+        """Test that the second report includes the trend and priorities."""
+        monkeypatch.chdir(tmp_path)
+        input_dir = tmp_path / "data"
+        input_dir.mkdir()
+        output_dir = tmp_path / "output"
+
+        def run(findings):
+            (input_dir / "explained.json").write_text(json.dumps(findings))
+            main(input_dir=str(input_dir), output_dir=str(output_dir), formats="markdown")
+            return (output_dir / "audit.md").read_text(encoding="utf-8")
+
+        first = run(FINDINGS[1:])
+        assert "### Recommended Priorities" in first
+        assert "### Trend vs. Previous Run" not in first
+
+        second = run(FINDINGS)
+        assert "### Top Risks" in second
+        assert "### Trend vs. Previous Run" in second
+        assert "- **New:** 1" in second
+        assert (input_dir / HISTORY_DB_NAME).exists()
+
+    def test_history_can_be_disabled(self, tmp_path, monkeypatch):
+        """Test that [history] enabled = false skips the run store."""
+        monkeypatch.chdir(tmp_path)
+        (tmp_path / "paddi.toml").write_text("[history]\nenabled = false\n")
+        input_dir = tmp_path / "data"
+        input_dir.mkdir()
+        (input_dir / "explained.json").write_text(json.dumps(FINDINGS))
+
+        main(input_dir=str(input_dir), output_dir=str(tmp_path / "output"), formats="markdown")
+
+        assert not (input_dir / HISTORY_DB_NAME).exists()
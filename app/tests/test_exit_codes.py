@@ -0,0 +1,106 @@
+"""Tests for exit codes per failure class."""
+
+import json
+import sys
+from pathlib import Path
+from unittest.mock import patch
+
+import pytest
+import requests
+
+from app.cli.base import CommandContext
+from app.cli.commands import AuditCommand, CollectCommand
+from app.cli.paddi_cli import PaddiCLI
+from app.common.exceptions import (
+    AgentError,
+    AuthenticationError,
+    CollectionError,
+    ConfigurationError,
+    DataValidationError,
+    FindingsThresholdError,
+    NewFindingsError,
+    QueryError,
+)
+from app.common.exit_codes import ExitCode, exit_code_for
+from app.main import main
+
+
+@pytest.mark.parametrize(
+    "error, code",
+    [
+        (ConfigurationError("paddi.toml"), 2),
+        (QueryError("severity =="), 2),
+        (CollectionError("iam"), 3),
+        (AgentError("explainer"), 3),
+        (NewFindingsError(1), 4),
+        (FindingsThresholdError(2, "HIGH"), 4),
+        (TimeoutError(), 5),
+        (requests.ReadTimeout(), 5),
+        (DataValidationError("data/collected.json"), 6),
+        (FileNotFoundError("data/explained.json"), 6),
+        (AuthenticationError(), 7),
+        (ValueError("unknown action"), 1),
+        (KeyboardInterrupt(), 130),
+    ],
+)
+def test_exit_code_for(error, code):
+    """Test the code of each failure class."""
+    assert exit_code_for(error) == code
+
+
+def test_timeout_inside_agent_error():
+    """Test that a wrapped timeout is still reported as a timeout."""
+    try:
+        try:
+            raise requests.ConnectTimeout("vertex")
+        except Exception as e:
+            raise AgentError("explainer", {"error": str(e)}) from e
+    except AgentError as wrapped:
+        assert exit_code_for(wrapped) == ExitCode.TIMEOUT
+
+
+def test_collect_wraps_unexpected_errors():
+    """Test that an unexpected collector error becomes an agent failure."""
+    with patch("app.cli.commands.collector_main", side_effect=RuntimeError("boom")):
+        with pytest.raises(AgentError, match="boom") as exc_info:
+            CollectCommand().execute(CommandContext())
+    assert isinstance(exc_info.value.__cause__, RuntimeError)
+
+
+def test_cli_exits_with_failure_class_code():
+    """Test that non-verbose commands exit with the mapped code."""
+    with patch("app.cli.commands.collector_main", side_effect=AuthenticationError()):
+        with pytest.raises(SystemExit) as exc_info:
+            PaddiCLI().collect()
+    assert exc_info.value.code == ExitCode.AUTHENTICATION_ERROR
+
+
+def test_main_exits_with_code_for_verbose_errors(capsys):
+    """Test that main prints the traceback of an uncaught error and exits with its code."""
+    with patch.object(sys, "argv", ["main.py", "collect", "--verbose"]):
+        with patch("app.main.fire.Fire", side_effect=CollectionError("iam")):
+            with pytest.raises(SystemExit) as exc_info:
+                main()
+    assert exc_info.value.code == ExitCode.AGENT_FAILURE
+    assert "Traceback" in capsys.readouterr().err
+
+
+def test_audit_severity_threshold(tmp_path, monkeypatch):
+    """Test --fail-on-severity against the reported findings."""
+    monkeypatch.chdir(tmp_path)
+    Path("data").mkdir()
+    findings = [{"title": "Public bucket", "severity": "HIGH", "resource": "bucket"}]
+    Path("data/explained.json").write_text(json.dumps(findings), encoding="utf-8")
+
+    AuditCommand()._check_severity_threshold("CRITICAL")
+    with pytest.raises(FindingsThresholdError) as exc_info:
+        AuditCommand()._check_severity_threshold("medium")
+    assert (exc_info.value.count, exc_info.value.severity) == (1, "MEDIUM")
+
+
+@patch("app.cli.commands.collector_main")
+def test_audit_rejects_unknown_severity(collector):
+    """Test that an unknown --fail-on-severity fails before the audit runs."""
+    with pytest.raises(ConfigurationError):
+        AuditCommand().execute(CommandContext(fail_on_severity="urgent"))
+    collector.assert_not_called()
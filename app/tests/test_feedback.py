@@ -0,0 +1,86 @@
+"""Tests for false-positive feedback into analysis."""
+
+import json
+
+import pytest
+
+from app.cli.base import CommandContext
+from app.cli.commands import FindingsCommand
+from app.common.models import SecurityFinding
+from app.explainer.feedback import (
+    drop_false_positives,
+    false_positive_prompt,
+    load_false_positives,
+)
+from app.findings.fingerprint import compute_fingerprint
+from app.history.store import HISTORY_DB_NAME, RunStore
+
+BUCKET = {
+    "title": "Public bucket",
+    "severity": "HIGH",
+    "category": "PUBLIC_BUCKET",
+    "resource": "storage.googleapis.com/site",
+}
+ROLE = {
+    "title": "Owner role",
+    "severity": "CRITICAL",
+    "category": "OVERPRIVILEGED_ROLE",
+    "resource": "projects/prod",
+}
+
+
+def test_mark_and_unmark(tmp_path):
+    """Test that feedback is kept once per fingerprint."""
+    store = RunStore(tmp_path / HISTORY_DB_NAME)
+    store.mark_false_positive(BUCKET, reason="first")
+    store.mark_false_positive(BUCKET, reason="serves the public site", marked_by="alice")
+
+    [entry] = store.false_positives()
+    assert entry.fingerprint == compute_fingerprint(BUCKET)
+    assert (entry.reason, entry.marked_by) == ("serves the public site", "alice")
+    assert store.unmark_false_positive(entry.fingerprint)
+    assert not store.unmark_false_positive(entry.fingerprint)
+    assert load_false_positives(tmp_path) == []
+
+
+def test_prompt_and_drop(tmp_path):
+    """Test the prompt text and the fingerprint filter."""
+    assert false_positive_prompt([]) == ""
+    store = RunStore(tmp_path / HISTORY_DB_NAME)
+    store.mark_false_positive(BUCKET, reason="serves the public site")
+    false_positives = load_false_positives(tmp_path)
+
+    prompt = false_positive_prompt(false_positives)
+    assert "PUBLIC_BUCKET on storage.googleapis.com/site (Public bucket)" in prompt
+    assert "serves the public site" in prompt
+
+    findings = [
+        SecurityFinding(**finding, explanation="", recommendation="") for finding in (BUCKET, ROLE)
+    ]
+    kept, dropped = drop_false_positives(findings, false_positives)
+    assert [f.title for f in kept] == ["Owner role"]
+    assert [f.title for f in dropped] == ["Public bucket"]
+
+
+def test_findings_mark_fp_command(tmp_path, capsys):
+    """Test paddi findings mark-fp / unmark-fp with a fingerprint prefix."""
+    explained = tmp_path / "explained.json"
+    explained.write_text(json.dumps([BUCKET, ROLE]), encoding="utf-8")
+    fingerprint = compute_fingerprint(ROLE)
+    command = FindingsCommand()
+
+    command.execute(
+        CommandContext(
+            action="mark-fp", target=fingerprint[:8], reason="lab", input_file=str(explained)
+        )
+    )
+    assert "Owner role" in capsys.readouterr().out
+    [entry] = RunStore(tmp_path / HISTORY_DB_NAME).false_positives()
+    assert (entry.fingerprint, entry.reason) == (fingerprint, "lab")
+
+    with pytest.raises(ValueError):
+        command.execute(CommandContext(action="mark-fp", target="zz", input_file=str(explained)))
+    command.execute(
+        CommandContext(action="unmark-fp", target=fingerprint, input_file=str(explained))
+    )
+    assert RunStore(tmp_path / HISTORY_DB_NAME).false_positives() == []
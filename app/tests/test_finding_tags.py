@@ -0,0 +1,109 @@
+"""Tests for tags on findings and the --tag filters."""
+
+import json
+
+import pytest
+
+from app.cli.base import CommandContext
+from app.cli.commands import FindingsCommand
+from app.config.paddi_config import PaddiConfig
+from app.findings.fingerprint import compute_fingerprint
+from app.findings.listing import filter_findings
+from app.findings.tags import TagRules, load_finding_tags, tag_findings
+from app.history.store import HISTORY_DB_NAME, RunStore
+from app.reporter.agent_reporter import ReportService
+
+CONFIG = PaddiConfig({"tags": {"prod": ["*prod*"]}})
+
+BUCKET = {
+    "title": "Public bucket",
+    "severity": "HIGH",
+    "category": "PUBLIC_BUCKET",
+    "resource": "storage.googleapis.com/site",
+    "explanation": "",
+    "recommendation": "",
+}
+ROLE = {
+    "title": "Owner role",
+    "severity": "CRITICAL",
+    "category": "OVERPRIVILEGED_ROLE",
+    "resource": "projects/prod-app",
+    "explanation": "",
+    "recommendation": "",
+}
+
+
+def test_store_tags(tmp_path):
+    """Test that stored tags are kept once per finding and can be removed."""
+    store = RunStore(tmp_path / HISTORY_DB_NAME)
+    fingerprint = compute_fingerprint(BUCKET)
+
+    store.tag_finding(fingerprint, ["pci"])
+    assert store.tag_finding(fingerprint, ["pci", "accepted-risk"], tagged_by="alice") == [
+        "pci",
+        "accepted-risk",
+    ]
+    assert store.untag_finding(fingerprint, ["pci"]) == 1
+    assert load_finding_tags(tmp_path) == {fingerprint: ["accepted-risk"]}
+    assert store.untag_finding(fingerprint) == 1
+    assert store.finding_tags() == {}
+
+
+def test_apply_rules_and_stored_tags():
+    """Test that config tags come first and stored tags are added by fingerprint."""
+    stored = {compute_fingerprint(ROLE): ["pci", "prod"]}
+
+    tagged = TagRules.from_config(CONFIG).apply([BUCKET, ROLE], stored)
+
+    assert "tags" not in tagged[0]
+    assert tagged[1]["tags"] == ["prod", "pci"]
+    assert filter_findings(tagged, tag="PCI,other") == [tagged[1]]
+    assert filter_findings(tagged, tag="none") == []
+
+
+def test_findings_tag_command(tmp_path, capsys, monkeypatch):
+    """Test paddi findings tag / untag and the list --tag filter."""
+    monkeypatch.setattr(PaddiConfig, "load", classmethod(lambda cls, *a, **k: CONFIG))
+    explained = tmp_path / "explained.json"
+    explained.write_text(json.dumps([BUCKET, ROLE]), encoding="utf-8")
+    fingerprint = compute_fingerprint(BUCKET)
+    command = FindingsCommand()
+
+    command.execute(
+        CommandContext(
+            action="tag", target=fingerprint[:8], tag="pci, web", input_file=str(explained)
+        )
+    )
+    assert "pci, web" in capsys.readouterr().out
+    assert [f["title"] for f in tag_findings([BUCKET, ROLE], tmp_path) if f.get("tags")] == [
+        "Public bucket",
+        "Owner role",
+    ]
+
+    command.execute(
+        CommandContext(action="list", tag="web", output_format="json", input_file=str(explained))
+    )
+    listed = json.loads(capsys.readouterr().out)
+    assert [f["title"] for f in listed["findings"]] == ["Public bucket"]
+
+    with pytest.raises(ValueError):
+        command.execute(CommandContext(action="tag", target=fingerprint, input_file=str(explained)))
+    command.execute(
+        CommandContext(action="untag", target=fingerprint, tag="web", input_file=str(explained))
+    )
+    assert load_finding_tags(tmp_path) == {fingerprint: ["pci"]}
+
+
+def test_report_tag_filter(tmp_path, monkeypatch):
+    """Test that a report filtered by tag only has tagged findings and is not recorded."""
+    monkeypatch.setattr(PaddiConfig, "load", classmethod(lambda cls, *a, **k: CONFIG))
+    (tmp_path / "explained.json").write_text(json.dumps([BUCKET, ROLE]), encoding="utf-8")
+    service = ReportService(input_dir=tmp_path, output_dir=tmp_path / "output")
+
+    service.generate_reports(["markdown"], tags=["prod"])
+
+    markdown = (tmp_path / "output" / "audit.md").read_text(encoding="utf-8")
+    assert "Owner role" in markdown
+    assert "**Tags:** prod" in markdown
+    assert "Public bucket" not in markdown
+    assert RunStore(tmp_path / HISTORY_DB_NAME).latest_run() is None
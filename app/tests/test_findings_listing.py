@@ -0,0 +1,209 @@
+"""Tests for findings listing and the findings command."""
+
+import json
+
+import pytest
+
+from app.cli.base import CommandContext
+from app.cli.commands import AuditCommand, FindingsCommand
+from app.findings.listing import (
+    filter_findings,
+    format_summary,
+    format_table,
+    load_findings,
+    paginate,
+    severity_counts,
+    sort_findings,
+)
+
+FINDINGS = [
+    {
+        "title": "Public bucket",
+        "severity": "MEDIUM",
+        "category": "PUBLIC_BUCKET",
+        "resource": "//storage.googleapis.com/prod-assets",
+    },
+    {
+        "title": "Owner role granted",
+        "severity": "HIGH",
+        "category": "OVERPRIVILEGED_ROLE",
+        "resource": "projects/prod-project",
+        "cvss_score": 8.8,
+    },
+    {
+        "title": "Container CVE",
+        "severity": "CRITICAL",
+        "category": "CONTAINER_VULNERABILITY",
+        "resource": "projects/dev-project/clusters/main",
+        "cvss_score": 9.8,
+    },
+    {"title": "Legacy finding", "severity": "LOW"},
+]
+
+
+@pytest.fixture
+def explained_file(tmp_path):
+    """Write sample findings to explained.json."""
+    path = tmp_path / "explained.json"
+    path.write_text(json.dumps(FINDINGS), encoding="utf-8")
+    return path
+
+
+class TestFilterFindings:
+    """Tests for filter_findings."""
+
+    def test_no_filters(self):
+        """Test that no filters keep all findings."""
+        assert filter_findings(FINDINGS) == FINDINGS
+
+    def test_severity_filter(self):
+        """Test comma-separated, case-insensitive severity filter."""
+        result = filter_findings(FINDINGS, severity="critical,HIGH")
+        assert [f["title"] for f in result] == ["Owner role granted", "Container CVE"]
+
+    def test_category_filter(self):
+        """Test category filter."""
+        result = filter_findings(FINDINGS, category="public_bucket")
+        assert [f["title"] for f in result] == ["Public bucket"]
+
+    def test_resource_filter(self):
+        """Test resource substring filter skips findings without resources."""
+        result = filter_findings(FINDINGS, resource="PROD")
+        assert [f["title"] for f in result] == ["Public bucket", "Owner role granted"]
+
+    def test_min_score_filter(self):
+        """Test that unscored findings are excluded by a score threshold."""
+        result = filter_findings(FINDINGS, min_score=9.0)
+        assert [f["title"] for f in result] == ["Container CVE"]
+
+    def test_combined_filters(self):
+        """Test that filters are combined with AND."""
+        assert filter_findings(FINDINGS, severity="HIGH", resource="dev") == []
+
+
+class TestPaging:
+    """Tests for sorting, paging, and formatting."""
+
+    def test_sort_by_severity(self):
+        """Test most severe findings come first."""
+        result = sort_findings(FINDINGS)
+        assert [f["severity"] for f in result] == ["CRITICAL", "HIGH", "MEDIUM", "LOW"]
+
+    def test_sort_by_score(self):
+        """Test highest CVSS scores come first and unscored findings last."""
+        result = sort_findings(FINDINGS, by="score")
+        assert [f["title"] for f in result][:2] == ["Container CVE", "Owner role granted"]
+
+        with pytest.raises(ValueError):
+            sort_findings(FINDINGS, by="age")
+
+    def test_paginate(self):
+        """Test page slicing and page clamping."""
+        items, pages = paginate(FINDINGS, page=2, page_size=3)
+        assert pages == 2
+        assert items == FINDINGS[3:]
+
+        items, pages = paginate(FINDINGS, page=99, page_size=3)
+        assert items == FINDINGS[3:]
+
+        items, pages = paginate([], page=1, page_size=3)
+        assert items == []
+        assert pages == 1
+
+    def test_format_table(self):
+        """Test table output includes headers and placeholders."""
+        table = format_table(FINDINGS[3:], start_index=4)
+        assert "SEVERITY" in table
+        assert "4    LOW" in table
+        assert " - " in table
+
+    def test_load_missing_file(self, tmp_path):
+        """Test loading a missing file raises FileNotFoundError."""
+        with pytest.raises(FileNotFoundError):
+            load_findings(str(tmp_path / "missing.json"))
+
+
+class TestSummary:
+    """Tests for the post-audit findings summary."""
+
+    def test_severity_counts(self):
+        """Test counts ordered from most to least severe."""
+        assert list(severity_counts(FINDINGS).items()) == [
+            ("CRITICAL", 1),
+            ("HIGH", 1),
+            ("MEDIUM", 1),
+            ("LOW", 1),
+        ]
+
+    def test_format_summary(self):
+        """Test the counts line and the severity-sorted table of top findings."""
+        summary = format_summary(FINDINGS, top=2)
+        lines = summary.splitlines()
+
+        assert lines[0] == (
+            "Findings by severity: CRITICAL 1 | HIGH 1 | MEDIUM 1 | LOW 1 | INFO 0 (total 4)"
+        )
+        assert "Container CVE" in lines[4]
+        assert "Owner role granted" in lines[5]
+        assert "Public bucket" not in summary
+        assert lines[-1] == "... and 2 more (paddi findings list)"
+
+    def test_no_findings(self):
+        """Test the summary of a clean audit."""
+        assert format_summary([]).endswith("(total 0)")
+
+    def test_printed_after_audit(self, explained_file, capsys):
+        """Test that the audit command prints the summary table."""
+        AuditCommand()._log_risk_summary(str(explained_file))
+
+        out = capsys.readouterr().out
+        assert "Findings by severity: CRITICAL 1 | HIGH 1" in out
+        assert "Container CVE" in out
+
+
+class TestFindingsCommand:
+    """Tests for FindingsCommand."""
+
+    def test_table_output(self, explained_file, capsys):
+        """Test default table output."""
+        FindingsCommand().execute(
+            CommandContext(action="list", input_file=str(explained_file), severity="HIGH")
+        )
+
+        output = capsys.readouterr().out
+        assert "Owner role granted" in output
+        assert "Public bucket" not in output
+        assert "Page 1/1 (1 findings)" in output
+
+    def test_json_output(self, explained_file, capsys):
+        """Test JSON output with paging metadata."""
+        FindingsCommand().execute(
+            CommandContext(
+                action="list",
+                input_file=str(explained_file),
+                output_format="json",
+                page=2,
+                page_size=1,
+            )
+        )
+
+        output = json.loads(capsys.readouterr().out)
+        assert output["total"] == 4
+        assert output["page"] == 2
+        assert output["pages"] == 4
+        assert output["findings"][0]["severity"] == "HIGH"
+
+    def test_no_matches(self, explained_file, capsys):
+        """Test message when filters match nothing."""
+        FindingsCommand().execute(
+            CommandContext(action="list", input_file=str(explained_file), category="NONE")
+        )
+
+        assert "No findings match" in capsys.readouterr().out
+
+    def test_missing_file(self, tmp_path):
+        """Test error when explained.json is missing."""
+        with pytest.raises(FileNotFoundError):
+            FindingsCommand().execute(
+                CommandContext(action="list", input_file=str(tmp_path / "none.json"))
+            )
@@ -0,0 +1,79 @@
+"""Tests for finding fingerprints."""
+
+from app.common.models import SecurityFinding
+from app.findings.fingerprint import add_fingerprints, compute_fingerprint, normalize_resource
+
+
+class TestNormalizeResource:
+    """Tests for normalize_resource."""
+
+    def test_strips_prefix_case_and_trailing_slash(self):
+        """Test equivalent spellings normalize to the same value."""
+        assert normalize_resource("//Storage.googleapis.com/Bucket/") == (
+            "storage.googleapis.com/bucket"
+        )
+        assert normalize_resource("https://storage.googleapis.com/bucket") == (
+            "storage.googleapis.com/bucket"
+        )
+        assert normalize_resource(None) == ""
+
+
+class TestComputeFingerprint:
+    """Tests for compute_fingerprint."""
+
+    def test_deterministic(self, make_finding):
+        """Test that identical findings share a fingerprint."""
+        assert compute_fingerprint(make_finding()) == compute_fingerprint(make_finding())
+        assert len(compute_fingerprint(make_finding())) == 16
+
+    def test_ignores_volatile_fields(self, make_finding):
+        """Test severity and wording changes keep the fingerprint stable."""
+        original = compute_fingerprint(make_finding())
+        changed = compute_fingerprint(
+            make_finding(severity="LOW", explanation="Reworded", title="Different title")
+        )
+        assert original == changed
+
+    def test_resource_normalization(self, make_finding):
+        """Test resource spelling differences do not change the fingerprint."""
+        assert compute_fingerprint(make_finding()) == compute_fingerprint(
+            make_finding(resource="storage.googleapis.com/PROD-ASSETS/")
+        )
+
+    def test_distinguishes_category_and_resource(self, make_finding):
+        """Test different categories or resources yield different fingerprints."""
+        base = compute_fingerprint(make_finding())
+        assert base != compute_fingerprint(make_finding(category="BUCKET_LOGGING_DISABLED"))
+        assert base != compute_fingerprint(make_finding(resource="//storage.googleapis.com/other"))
+
+    def test_title_fallback_without_category(self, make_finding):
+        """Test findings without a category fall back to their title."""
+        first = make_finding(category=None, title="Owner role  granted")
+        second = make_finding(category=None, title="owner role granted")
+        third = make_finding(category=None, title="Editor role granted")
+
+        assert compute_fingerprint(first) == compute_fingerprint(second)
+        assert compute_fingerprint(first) != compute_fingerprint(third)
+
+    def test_accepts_security_finding(self, make_finding):
+        """Test SecurityFinding objects and dicts hash the same."""
+        data = make_finding()
+        finding = SecurityFinding(**data)
+        assert compute_fingerprint(finding) == compute_fingerprint(data)
+
+
+class TestAddFingerprints:
+    """Tests for add_fingerprints."""
+
+    def test_sets_missing_fingerprints_only(self, make_finding):
+        """Test existing fingerprints are preserved."""
+        findings = [
+            SecurityFinding(**make_finding()),
+            SecurityFinding(**make_finding(fingerprint="keep")),
+        ]
+
+        result = add_fingerprints(findings)
+
+        assert result[0].fingerprint == compute_fingerprint(make_finding())
+        assert result[1].fingerprint == "keep"
+        assert "fingerprint" in result[0].to_dict()
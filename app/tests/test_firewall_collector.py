@@ -0,0 +1,126 @@
+"""Tests for the VPC firewall rule collector and its rules."""
+
+import pytest
+
+from app.collector.firewall_collector import FirewallCollector, exposed_ports
+from app.config.paddi_config import PaddiConfig
+from app.explainer.rule_analyzer import RuleBasedAnalyzer
+
+PORTS = (22, 3389, 5432)
+
+
+def rule(**overrides):
+    """Build an ingress rule from anywhere allowing SSH."""
+    return {
+        "name": "allow-ssh",
+        "direction": "INGRESS",
+        "disabled": False,
+        "source_ranges": ["0.0.0.0/0"],
+        "allowed": [{"protocol": "tcp", "ports": ["22"]}],
+        **overrides,
+    }
+
+
+@pytest.mark.parametrize(
+    "overrides, expected",
+    [
+        ({}, [22]),
+        ({"allowed": [{"protocol": "tcp", "ports": ["3000-6000", "80"]}]}, [3389, 5432]),
+        ({"allowed": [{"protocol": "all", "ports": []}]}, [22, 3389, 5432]),
+        ({"allowed": [{"protocol": "icmp", "ports": []}]}, []),
+        ({"source_ranges": ["::/0"]}, [22]),
+        ({"source_ranges": ["10.0.0.0/8"]}, []),
+        ({"disabled": True}, []),
+        ({"direction": "EGRESS"}, []),
+    ],
+)
+def test_exposed_ports(overrides, expected):
+    """Test sources, directions, protocols, and port ranges."""
+    assert exposed_ports(rule(**overrides), PORTS) == expected
+
+
+class Firewall(dict):
+    """A Firewall as the client returns it, converted with its message type's to_dict."""
+
+    @classmethod
+    def to_dict(cls, message):
+        return dict(message)
+
+
+class FakeClient:
+    """FirewallsClient returning fixed rules."""
+
+    def __init__(self, firewalls):
+        self.firewalls = firewalls
+
+    def list(self, project):
+        return iter(self.firewalls)
+
+
+def test_collect_converts_and_marks_rules():
+    """Test the conversion from the Compute API, and exposures marked on it."""
+    client = FakeClient(
+        [
+            Firewall(
+                name="allow-db",
+                network="https://www.googleapis.com/compute/v1/projects/p/global/networks/prod",
+                direction="INGRESS",
+                priority=900,
+                source_ranges=["0.0.0.0/0"],
+                allowed=[{"I_p_protocol": "tcp", "ports": ["5432"]}],
+            )
+        ]
+    )
+    rules = FirewallCollector("p", PORTS, client=client).collect()
+    assert rules == [
+        {
+            "name": "allow-db",
+            "project": "p",
+            "network": "prod",
+            "direction": "INGRESS",
+            "disabled": False,
+            "priority": 900,
+            "source_ranges": ["0.0.0.0/0"],
+            "target_tags": [],
+            "allowed": [{"protocol": "tcp", "ports": ["5432"]}],
+            "exposed_ports": [5432],
+        }
+    ]
+
+
+def test_collect_skips_rules_on_errors():
+    """Test that a failed listing leaves firewall rules out instead of failing."""
+
+    class FailingClient:
+        def list(self, project):
+            raise PermissionError("compute.firewalls.list denied")
+
+    assert FirewallCollector("p", client=FailingClient()).collect() is None
+
+
+def test_from_config():
+    """Test that rules are collected by default, with configurable ports."""
+    collector = FirewallCollector.from_config("p", config=PaddiConfig({}))
+    assert 22 in collector.sensitive_ports and 3389 in collector.sensitive_ports
+    config = PaddiConfig({"collector": {"firewall": {"sensitive_ports": [8080]}}})
+    assert FirewallCollector.from_config("p", config=config).sensitive_ports == [8080]
+    off = PaddiConfig({"collector": {"firewall": {"enabled": False}}})
+    assert FirewallCollector.from_config("p", config=off) is None
+
+
+def test_firewall_findings():
+    """Test findings for exposed rules, without an LLM."""
+    rules = FirewallCollector("prod", use_mock=True).collect()
+    findings = RuleBasedAnalyzer().native_findings(
+        {"metadata": {"project_id": "prod"}, "firewall_rules": rules}
+    )
+
+    assert len(findings) == 1
+    finding = findings[0]
+    assert finding.title.startswith("Firewall rule allow-ssh-from-anywhere opens")
+    assert (finding.severity, finding.category) == ("HIGH", "OPEN_FIREWALL")
+    assert finding.resource == "projects/prod/global/firewalls/allow-ssh-from-anywhere"
+    assert "port 22 (SSH)" in finding.explanation
+
+    database = dict(rules[0], exposed_ports=[5432])
+    assert RuleBasedAnalyzer().firewall_findings([database])[0].severity == "MEDIUM"
@@ -0,0 +1,59 @@
+"""Tests for GitHub Actions annotations and job summary."""
+
+import io
+
+from app.findings.risk import RiskSummary
+from app.reporter.github_annotations import (
+    annotation,
+    format_annotations,
+    job_summary,
+    write_annotations,
+)
+
+BUCKET = {
+    "title": "Public bucket",
+    "severity": "MEDIUM",
+    "resource": "storage.googleapis.com/site",
+    "explanation": "100% public,\nreadable by anyone",
+    "recommendation": "Remove allUsers",
+}
+ROLE = {"title": "Owner role: too broad", "severity": "CRITICAL", "resource": "projects/a|b"}
+NOTE = {"title": "Audit logs", "severity": "INFO"}
+
+
+def test_annotation_levels_and_escaping():
+    """Test the workflow command level and escaping of title and message."""
+    assert annotation(BUCKET) == (
+        "::warning title=MEDIUM%3A Public bucket::Resource: storage.googleapis.com/site"
+        "%0A100%25 public,%0Areadable by anyone%0ARecommendation: Remove allUsers"
+    )
+    assert annotation(ROLE).startswith("::error title=CRITICAL%3A Owner role%3A too broad::")
+    assert annotation(NOTE) == "::notice title=INFO%3A Audit logs::INFO: Audit logs"
+    assert format_annotations([NOTE, BUCKET, ROLE]).splitlines()[0].startswith("::error")
+
+
+def test_job_summary():
+    """Test the Markdown summary counts, grade, and escaped table cells."""
+    summary = job_summary([BUCKET, ROLE], "prod", RiskSummary(score=12.5, grade="B"))
+
+    assert "## Paddi Security Audit: prod" in summary
+    assert "**Risk grade:** B (score 12.5)" in summary
+    assert "| 1 | 0 | 1 | 0 | 0 |" in summary
+    assert "| CRITICAL | Owner role: too broad | projects/a\\|b |" in summary
+
+
+def test_write_annotations(tmp_path, monkeypatch):
+    """Test that annotations are printed and the summary appended to GITHUB_STEP_SUMMARY."""
+    summary_file = tmp_path / "summary.md"
+    summary_file.write_text("# Earlier step\n", encoding="utf-8")
+    monkeypatch.setenv("GITHUB_STEP_SUMMARY", str(summary_file))
+    stream = io.StringIO()
+
+    assert write_annotations([BUCKET], "prod", stream=stream) == summary_file
+
+    assert stream.getvalue().startswith("::warning ")
+    content = summary_file.read_text(encoding="utf-8")
+    assert content.startswith("# Earlier step\n## Paddi Security Audit: prod")
+
+    monkeypatch.delenv("GITHUB_STEP_SUMMARY")
+    assert write_annotations([], "prod", stream=stream) is None
@@ -0,0 +1,141 @@
+"""Tests for Check Runs created as a GitHub App."""
+
+import base64
+import json
+from unittest.mock import Mock, patch
+
+import pytest
+from cryptography.hazmat.primitives import hashes, serialization
+from cryptography.hazmat.primitives.asymmetric import padding, rsa
+
+from app.common.exceptions import ConfigurationError
+from app.config.paddi_config import PaddiConfig
+from app.integrations.github_app import (
+    CheckRunPublisher,
+    app_jwt,
+    check_annotation,
+    check_conclusion,
+    check_output,
+)
+
+ROLE = {"title": "Owner role", "severity": "CRITICAL", "resource": "projects/prod"}
+FIREWALL = {"title": "Open firewall", "severity": "MEDIUM", "resource": "firewalls/ssh"}
+CODE = {"title": "Hard-coded key", "severity": "LOW", "file": "main.tf", "line": 12}
+
+
+@pytest.fixture(scope="module")
+def key():
+    return rsa.generate_private_key(public_exponent=65537, key_size=2048)
+
+
+def _pem(key):
+    return key.private_bytes(
+        serialization.Encoding.PEM,
+        serialization.PrivateFormat.PKCS8,
+        serialization.NoEncryption(),
+    ).decode()
+
+
+def _decode(part):
+    return base64.urlsafe_b64decode(part + "=" * (-len(part) % 4))
+
+
+def response(data):
+    """Build a successful API response."""
+    return Mock(status_code=200, json=Mock(return_value=data), raise_for_status=Mock())
+
+
+def test_app_jwt_is_signed_by_the_key(key):
+    """Test the JWT claims and that its RS256 signature verifies."""
+    token = app_jwt("42", _pem(key), now=1_000_000)
+    header, claims, signature = token.split(".")
+    assert json.loads(_decode(header)) == {"alg": "RS256", "typ": "JWT"}
+    assert json.loads(_decode(claims)) == {"iat": 999_940, "exp": 1_000_540, "iss": "42"}
+    key.public_key().verify(
+        _decode(signature), f"{header}.{claims}".encode(), padding.PKCS1v15(), hashes.SHA256()
+    )
+    with pytest.raises(ConfigurationError):
+        app_jwt("42", "not a key")
+
+
+def test_annotations_summary_and_conclusion():
+    """Test annotation levels and locations, the summary, and the conclusion."""
+    assert check_annotation(ROLE, "paddi.toml") == {
+        "path": "paddi.toml",
+        "start_line": 1,
+        "end_line": 1,
+        "annotation_level": "failure",
+        "title": "CRITICAL: Owner role",
+        "message": "Resource: projects/prod",
+    }
+    annotation = check_annotation(CODE, "paddi.toml")
+    assert (annotation["path"], annotation["start_line"]) == ("main.tf", 12)
+    assert annotation["annotation_level"] == "notice"
+
+    output = check_output([FIREWALL, ROLE], "prod")
+    assert output["title"] == "1 critical, 1 medium"
+    assert output["summary"].index("Owner role") < output["summary"].index("Open firewall")
+    assert check_output([], "prod")["title"] == "No findings"
+
+    assert check_conclusion([FIREWALL, ROLE], "HIGH") == "failure"
+    assert check_conclusion([FIREWALL], "HIGH") == "neutral"
+    assert check_conclusion([], "HIGH") == "success"
+
+
+def test_from_config(key, tmp_path):
+    """Test that the section is optional, and the key, repository, and commit are required."""
+    assert CheckRunPublisher.from_config(PaddiConfig({}), env={}) is None
+    key_file = tmp_path / "app.pem"
+    key_file.write_text(_pem(key))
+    section = {"app_id": 42, "private_key_path": str(key_file), "fail_on_severity": "medium"}
+    env = {"GITHUB_REPOSITORY": "org/repo", "GITHUB_SHA": "abc1234def"}
+
+    config = PaddiConfig({"integrations": {"github_app": section}})
+    publisher = CheckRunPublisher.from_config(config, env=env)
+    assert (publisher.repository, publisher.head_sha) == ("org/repo", "abc1234def")
+    assert publisher.fail_on_severity == "MEDIUM"
+
+    disabled = PaddiConfig({"integrations": {"github_app": {**section, "enabled": False}}})
+    assert CheckRunPublisher.from_config(disabled, env=env) is None
+    with pytest.raises(ConfigurationError):
+        CheckRunPublisher.from_config(config, env={"GITHUB_SHA": "abc"})
+    no_key = PaddiConfig({"integrations": {"github_app": {"app_id": 42}}})
+    with pytest.raises(ConfigurationError):
+        CheckRunPublisher.from_config(no_key, env=env)
+
+
+def test_publish_creates_check_run_in_batches(key):
+    """Test the installation token, the Check Run, and annotations past the first 50."""
+    publisher = CheckRunPublisher("42", _pem(key), "org/repo", "abc1234def")
+    findings = [ROLE] + [dict(FIREWALL, resource=f"firewalls/{i}") for i in range(59)]
+    calls = []
+
+    def request(method, url, **kwargs):
+        calls.append((method, url, kwargs))
+        if url.endswith("/installation"):
+            return response({"id": 7})
+        if url.endswith("/access_tokens"):
+            return response({"token": "ghs_installation"})
+        return response({"id": 99, "conclusion": "failure"})
+
+    with patch("app.integrations.github_app.requests.request", side_effect=request):
+        check_run = publisher.publish(findings, "prod", details_url="https://ci/run/1")
+
+    assert check_run["id"] == 99
+    assert [(m, u.split("api.github.com")[1]) for m, u, _ in calls] == [
+        ("GET", "/repos/org/repo/installation"),
+        ("POST", "/app/installations/7/access_tokens"),
+        ("POST", "/repos/org/repo/check-runs"),
+        ("PATCH", "/repos/org/repo/check-runs/99"),
+    ]
+    assert calls[2][2]["headers"]["Authorization"] == "Bearer ghs_installation"
+    body = calls[2][2]["json"]
+    assert (body["head_sha"], body["status"], body["conclusion"]) == (
+        "abc1234def",
+        "completed",
+        "failure",
+    )
+    assert body["details_url"] == "https://ci/run/1"
+    assert len(body["output"]["annotations"]) == 50
+    assert body["output"]["annotations"][0]["title"] == "CRITICAL: Owner role"
+    assert len(calls[3][2]["json"]["output"]["annotations"]) == 10
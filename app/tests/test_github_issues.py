@@ -0,0 +1,157 @@
+"""Tests for the GitHub Issues exporter."""
+
+import json
+from unittest.mock import Mock, patch
+
+import pytest
+
+from app.cli.base import CommandContext
+from app.cli.commands import ExportCommand
+from app.common.exceptions import ConfigurationError
+from app.config.paddi_config import PaddiConfig
+from app.export.github_issues import (
+    PADDI_LABEL,
+    GitHubIssuesExporter,
+    fingerprint_of_issue,
+    issue_body,
+)
+from app.findings.fingerprint import compute_fingerprint
+
+FINDINGS = [
+    {
+        "title": "Public bucket",
+        "severity": "HIGH",
+        "category": "PUBLIC_BUCKET",
+        "resource": "//storage.googleapis.com/assets",
+        "explanation": "Anyone can read objects.",
+        "recommendation": "Remove allUsers.",
+    },
+    {
+        "title": "Legacy setting",
+        "severity": "LOW",
+        "category": "LEGACY",
+        "resource": "projects/prod",
+    },
+]
+
+
+def make_issue(finding, number=1):
+    """Create a fake open issue for a finding."""
+    issue = Mock()
+    issue.number = number
+    issue.title = finding["title"]
+    issue.body = issue_body(finding, compute_fingerprint(finding))
+    return issue
+
+
+class TestGitHubIssuesExporter:
+    """Tests for GitHubIssuesExporter.sync."""
+
+    def test_opens_issue_for_new_finding(self):
+        """Test that new findings get labeled issues with a fingerprint marker."""
+        repo = Mock()
+        repo.get_issues.return_value = []
+
+        result = GitHubIssuesExporter(repo, labels=["security"], min_severity="HIGH").sync(
+            FINDINGS
+        )
+
+        assert result.opened == ["[HIGH] Public bucket (//storage.googleapis.com/assets)"]
+        kwargs = repo.create_issue.call_args.kwargs
+        assert kwargs["labels"] == [PADDI_LABEL, "severity:high", "security"]
+        assert fingerprint_of_issue(kwargs["body"]) == compute_fingerprint(FINDINGS[0])
+        repo.get_issues.assert_called_once_with(state="open", labels=[PADDI_LABEL])
+
+    def test_existing_issue_is_unchanged(self):
+        """Test that a finding with an open issue does not get a second one."""
+        repo = Mock()
+        repo.get_issues.return_value = [make_issue(FINDINGS[0])]
+
+        result = GitHubIssuesExporter(repo).sync(FINDINGS)
+
+        assert result.unchanged == 1
+        assert len(result.opened) == 1
+        assert repo.create_issue.call_count == 1
+
+    def test_closes_issue_for_resolved_finding(self):
+        """Test that issues for findings no longer reported are closed."""
+        issue = make_issue(FINDINGS[0])
+        repo = Mock()
+        repo.get_issues.return_value = [issue]
+
+        result = GitHubIssuesExporter(repo, min_severity="HIGH").sync(FINDINGS[1:])
+
+        assert result.closed == ["Public bucket"]
+        issue.create_comment.assert_called_once()
+        issue.edit.assert_called_once_with(state="closed")
+
+    def test_below_threshold_is_not_closed(self):
+        """Test that a finding still reported below min_severity keeps its issue."""
+        issue = make_issue(FINDINGS[1])
+        repo = Mock()
+        repo.get_issues.return_value = [issue]
+
+        result = GitHubIssuesExporter(repo, min_severity="HIGH").sync(FINDINGS)
+
+        assert result.closed == []
+        issue.edit.assert_not_called()
+
+    def test_dry_run(self):
+        """Test that dry runs do not write to GitHub."""
+        issue = make_issue({"title": "Gone", "severity": "HIGH", "category": "X"})
+        repo = Mock()
+        repo.get_issues.return_value = [issue]
+
+        result = GitHubIssuesExporter(repo).sync(FINDINGS, dry_run=True)
+
+        assert len(result.opened) == 2
+        assert result.closed == ["Gone"]
+        repo.create_issue.assert_not_called()
+        issue.edit.assert_not_called()
+
+
+class TestFromConfig:
+    """Tests for exporter configuration."""
+
+    def test_requires_repo(self):
+        """Test that the repository must be configured."""
+        with pytest.raises(ConfigurationError):
+            GitHubIssuesExporter.from_config(PaddiConfig({}))
+
+    def test_requires_token(self, monkeypatch):
+        """Test that the token environment variable must be set."""
+        monkeypatch.delenv("GITHUB_TOKEN", raising=False)
+        config = PaddiConfig({"export": {"github_issues": {"repo": "org/repo"}}})
+
+        with pytest.raises(ConfigurationError):
+            GitHubIssuesExporter.from_config(config)
+
+
+class TestExportCommand:
+    """Tests for the export command."""
+
+    def test_unknown_target(self):
+        """Test that unknown targets are rejected."""
+        with pytest.raises(ValueError):
+            ExportCommand().execute(CommandContext(export_target="jira"))
+
+    def test_github_issues(self, tmp_path, capsys):
+        """Test that the command syncs findings and prints a summary."""
+        input_file = tmp_path / "explained.json"
+        input_file.write_text(json.dumps(FINDINGS))
+        repo = Mock()
+        repo.get_issues.return_value = []
+
+        with patch(
+            "app.cli.commands.GitHubIssuesExporter.from_config",
+            return_value=GitHubIssuesExporter(repo),
+        ):
+            ExportCommand().execute(
+                CommandContext(
+                    export_target="github-issues", input_file=str(input_file), dry_run=True
+                )
+            )
+
+        out = capsys.readouterr().out
+        assert "Would open: [HIGH] Public bucket" in out
+        assert "2 opened, 0 closed, 0 unchanged" in out
@@ -0,0 +1,61 @@
+"""Tests for the GitLab security report format."""
+
+import json
+from datetime import datetime, timezone
+
+from app.findings.fingerprint import compute_fingerprint
+from app.reporter.gitlab_security import (
+    GITLAB_REPORT_FILE,
+    GITLAB_SCHEMA_VERSION,
+    gitlab_report,
+    vulnerability,
+    write_gitlab_report,
+)
+
+BUCKET = {
+    "title": "Public bucket",
+    "severity": "MEDIUM",
+    "category": "PUBLIC_BUCKET",
+    "resource": "storage.googleapis.com/site",
+    "explanation": "Readable by anyone",
+    "recommendation": "Remove allUsers",
+}
+ROLE = {
+    "title": "Owner role",
+    "severity": "CRITICAL",
+    "resource": "projects/prod",
+    "cvss_vector": "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+}
+
+
+def test_vulnerability():
+    """Test the mapping of one finding, with an ID that is stable per fingerprint."""
+    entry = vulnerability(BUCKET)
+
+    assert entry["id"] == vulnerability(dict(BUCKET))["id"]
+    assert entry["id"] != vulnerability(ROLE)["id"]
+    assert (entry["name"], entry["severity"], entry["solution"]) == (
+        "Public bucket",
+        "Medium",
+        "Remove allUsers",
+    )
+    assert entry["location"] == {"file": "storage.googleapis.com/site"}
+    assert entry["identifiers"][1]["value"] == compute_fingerprint(BUCKET)
+    assert vulnerability(ROLE)["cvss_vectors"][0]["vector"] == ROLE["cvss_vector"]
+    assert vulnerability(ROLE)["identifiers"][0]["value"] == "UNCATEGORIZED"
+
+
+def test_report_and_file(tmp_path):
+    """Test the scan metadata, ordering, and the written file."""
+    end = datetime(2026, 5, 1, 12, 30, tzinfo=timezone.utc)
+    report = gitlab_report([BUCKET, ROLE], end_time=end)
+
+    assert report["version"] == GITLAB_SCHEMA_VERSION
+    assert report["scan"]["type"] == "sast"
+    assert report["scan"]["end_time"] == "2026-05-01T12:30:00"
+    assert [v["severity"] for v in report["vulnerabilities"]] == ["Critical", "Medium"]
+
+    path = write_gitlab_report([BUCKET], tmp_path / "output")
+    assert path.name == GITLAB_REPORT_FILE
+    written = json.loads(path.read_text(encoding="utf-8"))
+    assert written["vulnerabilities"][0]["name"] == "Public bucket"
@@ -0,0 +1,96 @@
+"""Tests for the GKE security posture collector and its rules."""
+
+from types import SimpleNamespace
+
+from app.collector.gke_collector import GkeCollector
+from app.config.paddi_config import PaddiConfig
+from app.explainer.rule_analyzer import RuleBasedAnalyzer
+from app.remediation.gcloud_script import build_script
+
+
+class Cluster(dict):
+    """A Cluster as the client returns it, converted with its message type's to_dict."""
+
+    @classmethod
+    def to_dict(cls, message):
+        return dict(message)
+
+
+class FakeClient:
+    """ClusterManagerClient returning fixed clusters."""
+
+    def __init__(self, clusters):
+        self.clusters = clusters
+        self.parents = []
+
+    def list_clusters(self, parent):
+        self.parents.append(parent)
+        return SimpleNamespace(clusters=self.clusters)
+
+
+def test_collect_converts_clusters():
+    """Test the settings kept from the Kubernetes Engine API."""
+    client = FakeClient(
+        [
+            Cluster(
+                name="prod",
+                location="europe-west1",
+                private_cluster_config={"enable_private_nodes": True},
+                master_authorized_networks_config={
+                    "enabled": True,
+                    "cidr_blocks": [{"display_name": "office", "cidr_block": "198.51.100.0/24"}],
+                },
+                legacy_abac={},
+                workload_identity_config={"workload_pool": "p.svc.id.goog"},
+                node_pools=[{"name": "pool-1", "management": {"auto_upgrade": True}}],
+            )
+        ]
+    )
+    [cluster] = GkeCollector("p", client=client).collect()
+
+    assert client.parents == ["projects/p/locations/-"]
+    assert cluster["public_endpoint"] is True
+    assert cluster["authorized_networks"] == ["198.51.100.0/24"]
+    assert (cluster["legacy_abac"], cluster["workload_identity_pool"]) == (False, "p.svc.id.goog")
+    assert cluster["node_pools"] == [{"name": "pool-1", "auto_upgrade": True, "auto_repair": False}]
+    assert RuleBasedAnalyzer().gke_findings([cluster]) == []
+
+
+def test_collect_skips_clusters_on_errors():
+    """Test that a failed listing leaves clusters out instead of failing."""
+
+    class FailingClient:
+        def list_clusters(self, parent):
+            raise PermissionError("Kubernetes Engine API has not been used in project p")
+
+    assert GkeCollector("p", client=FailingClient()).collect() is None
+    off = PaddiConfig({"collector": {"gke": {"enabled": False}}})
+    assert GkeCollector.from_config("p", config=off) is None
+
+
+def test_gke_findings_and_remediation():
+    """Test findings for a cluster missing every setting, and the commands fixing them."""
+    clusters = GkeCollector("prod", use_mock=True).collect()
+    findings = RuleBasedAnalyzer().native_findings({"gke_clusters": clusters})
+
+    assert [(f.category, f.severity) for f in findings] == [
+        ("GKE_PUBLIC_ENDPOINT", "HIGH"),
+        ("GKE_LEGACY_ABAC", "HIGH"),
+        ("GKE_WORKLOAD_IDENTITY_DISABLED", "MEDIUM"),
+        ("GKE_NODE_AUTO_UPGRADE_DISABLED", "MEDIUM"),
+    ]
+    assert findings[0].resource == "projects/prod/locations/us-central1-a/clusters/legacy-cluster"
+    assert findings[3].resource.endswith("/clusters/legacy-cluster/nodePools/default-pool")
+
+    open_networks = dict(clusters[1], public_endpoint=True, authorized_networks=["0.0.0.0/0"])
+    assert RuleBasedAnalyzer().gke_findings([open_networks])[0].category == "GKE_PUBLIC_ENDPOINT"
+
+    script = build_script([f.to_dict() for f in findings])
+    assert (
+        "gcloud container clusters update legacy-cluster --location=us-central1-a "
+        "--project=prod --no-enable-legacy-authorization" in script
+    )
+    assert (
+        "gcloud container node-pools update default-pool --cluster=legacy-cluster "
+        "--location=us-central1-a --project=prod --enable-autoupgrade" in script
+    )
@@ -0,0 +1,80 @@
+"""Tests for read-only SQL over the run history."""
+
+import json
+import sqlite3
+
+import pytest
+
+from app.cli.base import CommandContext
+from app.cli.commands import QueryCommand
+from app.history.sql import MAX_CELL_WIDTH, describe_tables, format_result, run_query
+from app.history.store import HISTORY_DB_NAME, RunStore
+
+FINDINGS = [
+    {"title": "Public bucket", "severity": "HIGH", "category": "PUBLIC_BUCKET", "resource": "b"},
+    {"title": "Owner role", "severity": "CRITICAL", "category": "OVERPRIVILEGED_ROLE"},
+]
+
+
+@pytest.fixture
+def history(tmp_path):
+    """Run store with two runs."""
+    store = RunStore(tmp_path / HISTORY_DB_NAME)
+    store.record_run(FINDINGS[:1], "prod")
+    store.record_run(FINDINGS, "prod")
+    return store.path
+
+
+def test_run_query(history):
+    """Test columns, rows, and JSON functions over the recorded data."""
+    result = run_query(
+        history,
+        "SELECT run_id, json_extract(data, '$.title') AS title FROM run_findings "
+        "WHERE severity = 'HIGH' ORDER BY run_id",
+    )
+
+    assert result.columns == ["run_id", "title"]
+    assert result.to_dict() == [
+        {"run_id": 1, "title": "Public bucket"},
+        {"run_id": 2, "title": "Public bucket"},
+    ]
+    assert result.to_csv().splitlines() == ["run_id,title", "1,Public bucket", "2,Public bucket"]
+
+
+def test_query_is_read_only(history):
+    """Test that writes are rejected and leave the history unchanged."""
+    for sql in ("DELETE FROM runs", "DROP TABLE runs", "SELECT 1; DELETE FROM runs"):
+        with pytest.raises(sqlite3.Error):
+            run_query(history, sql)
+
+    assert len(RunStore(history).runs()) == 2
+
+
+def test_describe_and_format(history):
+    """Test the table listing and the truncated terminal table."""
+    assert describe_tables(history)["runs"][:2] == ["id", "project_id"]
+
+    table = format_result(run_query(history, "SELECT id, data FROM runs, run_findings LIMIT 1"))
+    header, rule, row, _, count = table.splitlines()
+    assert header.split() == ["id", "data"]
+    assert len(row) == len("id  ") + MAX_CELL_WIDTH
+    assert row.endswith("…")
+    assert count == "(1 row(s))"
+
+
+def test_query_command(history, tmp_path, capsys, monkeypatch):
+    """Test paddi query with JSON output and without a statement."""
+    monkeypatch.chdir(tmp_path)
+    (tmp_path / "data").mkdir()
+    history.rename(tmp_path / "data" / HISTORY_DB_NAME)
+
+    QueryCommand().execute(
+        CommandContext(target="SELECT COUNT(*) AS n FROM runs", output_format="json")
+    )
+    assert json.loads(capsys.readouterr().out) == [{"n": 2}]
+
+    QueryCommand().execute(CommandContext())
+    assert "run_findings: run_id, fingerprint" in capsys.readouterr().out
+
+    with pytest.raises(sqlite3.Error):
+        QueryCommand().execute(CommandContext(target="SELECT * FROM nowhere"))
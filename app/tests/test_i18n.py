@@ -0,0 +1,98 @@
+"""Tests for localized CLI messages."""
+
+import json
+import re
+
+import pytest
+
+from app.cli import theme
+from app.common import i18n
+from app.common.exceptions import CollectionError
+from app.common.i18n import extract_lang_flag, get_language, set_language, tr
+
+
+@pytest.fixture(autouse=True)
+def reset_language(monkeypatch):
+    """Detect the language from a clean environment in every test."""
+    for name in (i18n.LANG_ENV, "LC_ALL", "LC_MESSAGES", "LANG"):
+        monkeypatch.delenv(name, raising=False)
+    monkeypatch.setattr(i18n, "_configured_language", lambda: None)
+    set_language(None)
+    yield
+    set_language(None)
+
+
+def test_english_by_default():
+    """Test that messages are shown as written without any setting."""
+    assert get_language() == "en"
+    assert CollectionError("SCC").message == "Collection error: could not collect SCC."
+
+
+def test_japanese_translation():
+    """Test that messages, their fields, and theme messages are translated."""
+    set_language("ja")
+
+    assert CollectionError("SCC").message == "収集エラー: SCCのデータ収集に失敗しました。"
+    assert tr("Please choose one of: {choices}", choices="a, b") == "次のいずれかを選んでください: a, b"
+    assert theme.message("error", "\nError: %s") == "\n❌ エラー: %s"
+
+
+def test_missing_translation_falls_back_to_english():
+    """Test that a message without a translation is shown in English."""
+    set_language("ja")
+
+    assert tr("Not translated {name}", name="x") == "Not translated x"
+
+
+@pytest.mark.parametrize(
+    "variables, expected",
+    [
+        ({"PADDI_LANG": "ja", "LANG": "en_US.UTF-8"}, "ja"),
+        ({"LANG": "ja_JP.UTF-8"}, "ja"),
+        ({"LC_ALL": "en_US.UTF-8", "LANG": "ja_JP.UTF-8"}, "en"),
+        ({"LANG": "fr_FR.UTF-8"}, "en"),
+        ({"LANG": "C.UTF-8"}, "en"),
+    ],
+)
+def test_language_detection(monkeypatch, variables, expected):
+    """Test that PADDI_LANG wins over the locale, and unknown locales mean English."""
+    for name, value in variables.items():
+        monkeypatch.setenv(name, value)
+
+    assert get_language() == expected
+
+
+def test_configured_language(monkeypatch):
+    """Test that [cli] lang is used when PADDI_LANG is not set."""
+    monkeypatch.setattr(i18n, "_configured_language", lambda: "ja")
+    monkeypatch.setenv("LANG", "en_US.UTF-8")
+
+    assert get_language() == "ja"
+
+
+def test_unknown_language():
+    """Test that --lang rejects languages without a catalog."""
+    with pytest.raises(ValueError, match="supported: en, ja"):
+        set_language("fr")
+
+
+@pytest.mark.parametrize(
+    "argv, expected",
+    [
+        (["audit", "--lang", "ja"], (["audit"], "ja")),
+        (["--lang=en", "audit", "--verbose"], (["audit", "--verbose"], "en")),
+        (["audit"], (["audit"], None)),
+    ],
+)
+def test_extract_lang_flag(argv, expected):
+    """Test that --lang is removed from the arguments in both forms."""
+    assert extract_lang_flag(argv) == expected
+
+
+def test_catalog_placeholders_match():
+    """Test that every translation keeps the placeholders of its English message."""
+    catalog = json.loads((i18n.LOCALES_DIR / "ja.json").read_text(encoding="utf-8"))
+    fields = re.compile(r"\{\w+\}|%[sd]")
+
+    for message, translated in catalog.items():
+        assert sorted(fields.findall(message)) == sorted(fields.findall(translated)), message
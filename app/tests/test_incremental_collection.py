@@ -0,0 +1,96 @@
+"""Tests for incremental collection."""
+
+import json
+from datetime import datetime, timedelta, timezone
+from unittest.mock import MagicMock, patch
+
+from app.collector.agent_collector import GCPConfigurationCollector
+from app.collector.incremental import merge_scc_findings, previous_snapshot
+from app.collector.scc_collector import SCCCollector
+
+NOW = datetime(2024, 6, 10, tzinfo=timezone.utc)
+
+
+def _finding(name, state="ACTIVE", days_ago=1):
+    """Build a collected SCC finding with an event time relative to NOW."""
+    return {
+        "name": name,
+        "category": "OPEN_FIREWALL",
+        "state": state,
+        "event_time": (NOW - timedelta(days=days_ago)).isoformat(),
+    }
+
+
+def _write_collected(path, timestamp, project_id="p", organization_id="o", findings=()):
+    """Write a previous collected.json."""
+    metadata = {"project_id": project_id, "organization_id": organization_id}
+    metadata["timestamp"] = timestamp.isoformat()
+    data = {"metadata": metadata, "iam_policies": {}, "scc_findings": list(findings)}
+    path.write_text(json.dumps(data), encoding="utf-8")
+    return path
+
+
+def test_merge_updates_adds_and_removes():
+    """Test that changes replace, add, and remove findings by name."""
+    previous = [_finding("a"), _finding("b"), _finding("old", days_ago=8)]
+    changed = [
+        dict(_finding("a", days_ago=0), category="UPDATED"),
+        _finding("b", state="INACTIVE"),
+        _finding("c"),
+        _finding("gone", state="INACTIVE"),
+    ]
+
+    merged, stats = merge_scc_findings(previous, changed, now=NOW)
+
+    assert [(f["name"], f["category"]) for f in merged] == [
+        ("a", "UPDATED"),
+        ("c", "OPEN_FIREWALL"),
+    ]
+    assert stats == {"changed": 4, "added": 1, "removed": 1, "expired": 1}
+
+
+def test_previous_snapshot_requires_same_target(tmp_path):
+    """Test that only a recent collection of the same project and organization is used."""
+    path = tmp_path / "collected.json"
+
+    assert previous_snapshot(path, "p", "o", now=NOW) is None
+
+    _write_collected(path, NOW - timedelta(hours=2))
+    snapshot = previous_snapshot(path, "p", "o", now=NOW)
+    assert snapshot.collected_at == NOW - timedelta(hours=2)
+    assert previous_snapshot(path, "other", "o", now=NOW) is None
+    assert previous_snapshot(path, "p", "o", now=NOW + timedelta(days=8)) is None
+
+    path.write_text(json.dumps({"providers": []}), encoding="utf-8")
+    assert previous_snapshot(path, "p", "o", now=NOW) is None
+
+
+def test_scc_lists_changes_in_any_state():
+    """Test that an incremental listing filters by the previous time, not by state."""
+    collector = SCCCollector("o")
+    collector.since = NOW
+
+    assert collector._get_time_filter() == f'event_time >= "{NOW.isoformat()}"'
+    assert collector._state_filter() == ""
+    collector.since = None
+    assert collector._state_filter() == 'state="ACTIVE" AND '
+
+
+def test_collect_all_merges_into_previous(tmp_path):
+    """Test a collection merging the changed findings into the previous snapshot."""
+    now = datetime.now(timezone.utc)
+    kept = dict(_finding("kept"), event_time=now.isoformat())
+    path = _write_collected(tmp_path / "collected.json", now - timedelta(hours=1), "p", "o", [kept])
+    snapshot = previous_snapshot(path, "p", "o")
+    with patch("app.collector.agent_collector.WasmPluginHost") as mock_host:
+        mock_host.from_config.return_value.run_collectors.return_value = {}
+        collector = GCPConfigurationCollector("p", "o", use_mock=True, output_dir=str(tmp_path))
+        collector.scc_collector = MagicMock()
+        collector.scc_collector.collect.return_value = [dict(kept, state="INACTIVE")]
+
+        data = collector.collect_all(snapshot)
+
+    collector.scc_collector.collect.assert_called_once_with(since=snapshot.collected_at)
+    assert data["scc_findings"] == []
+    assert data["metadata"]["incremental"]["removed"] == 1
+    assert data["metadata"]["incremental"]["since"] == snapshot.collected_at.isoformat()
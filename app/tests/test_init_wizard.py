@@ -0,0 +1,82 @@
+"""Tests for the interactive init wizard."""
+
+from unittest.mock import patch
+
+from app.cli.base import CommandContext
+from app.cli.commands import InitCommand
+from app.cli.init_wizard import InitWizard, WizardAnswers
+from app.config.paddi_config import PaddiConfig
+
+
+def scripted(*answers):
+    """Return an ``ask`` function that replays answers in order."""
+    replies = iter(answers)
+    return lambda _prompt: next(replies)
+
+
+class TestInitWizard:
+    """Tests for the wizard questions."""
+
+    def test_defaults(self):
+        """Test that empty answers keep the defaults."""
+        answers = InitWizard(scripted("", "", "", "", "")).run()
+        assert answers == WizardAnswers()
+
+    def test_custom_answers(self):
+        """Test choosing AWS, real mode, Ollama, and one report format."""
+        wizard = InitWizard(scripted("aws", "123456789012", "n", "ollama", "llama3", "HTML"))
+        answers = wizard.run()
+
+        assert answers.provider == "aws"
+        assert answers.project_id == "123456789012"
+        assert answers.use_mock is False
+        assert answers.ollama_model == "llama3"
+        assert answers.formats == ["html"]
+
+    def test_invalid_answers_are_asked_again(self, capsys):
+        """Test that unknown choices repeat the question."""
+        wizard = InitWizard(scripted("oracle", "gcp", "", "maybe", "y", "", "pdf", "markdown"))
+        answers = wizard.run()
+
+        assert answers.provider == "gcp"
+        assert answers.formats == ["markdown"]
+        assert "Please choose one of: gcp, aws, azure, github" in capsys.readouterr().out
+
+    def test_toml_round_trip(self, tmp_path):
+        """Test that the written file loads as configuration."""
+        path = tmp_path / "paddi.toml"
+        answers = WizardAnswers(project_id='my "quoted" project', use_mock=False)
+        assert InitWizard(scripted()).write(answers, path)
+
+        config = PaddiConfig.load(str(path))
+        assert config.get("general.project_id") == 'my "quoted" project'
+        assert config.get("general.use_mock") is False
+        assert config.get("reporter.formats") == ["markdown", "html"]
+
+    def test_existing_file_needs_confirmation(self, tmp_path):
+        """Test that an existing paddi.toml is kept unless overwriting is confirmed."""
+        path = tmp_path / "paddi.toml"
+        path.write_text("[general]\n", encoding="utf-8")
+
+        assert not InitWizard(scripted("")).write(WizardAnswers(), path)
+        assert path.read_text(encoding="utf-8") == "[general]\n"
+
+
+class TestInteractiveInit:
+    """Tests for ``paddi init --interactive``."""
+
+    def test_writes_config_and_runs_audit(self, tmp_path, monkeypatch):
+        """Test that the answers are saved and used for the first audit."""
+        monkeypatch.chdir(tmp_path)
+        answers = WizardAnswers(provider="azure", project_id="sub-1", formats=["html"])
+        context = CommandContext(interactive=True, output_dir=str(tmp_path / "output"))
+
+        with patch("app.cli.commands.InitWizard.run", return_value=answers), patch(
+            "app.cli.commands.AuditCommand.execute"
+        ) as mock_audit:
+            InitCommand().execute(context)
+
+        assert (tmp_path / "paddi.toml").exists()
+        audit_context = mock_audit.call_args[0][0]
+        assert audit_context.azure_subscription_id == "sub-1"
+        assert audit_context.formats == "html"
@@ -0,0 +1,126 @@
+"""Tests for streaming JSON parsing."""
+
+import io
+import json
+
+import pytest
+
+from app.benchmark.synthetic import synthetic_collected
+from app.common.artifact_schemas import STREAMED, validate_artifact, validate_stream
+from app.common.json_stream import iter_array, iter_events
+from app.findings.listing import iter_findings
+from app.reporter.agent_reporter import ReportService
+
+
+def test_events_stream_matching_containers():
+    """Test that only matching containers are streamed, in small chunks as well."""
+    text = '{"metadata": {"project_id": "p"}, "items": [1, {"a": [2.5, null]}], "n": 12345}'
+
+    events = list(iter_events(io.StringIO(text), [(), ("items",)], chunk_size=3))
+
+    assert events == [
+        ("start", (), {}),
+        ("value", ("metadata",), {"project_id": "p"}),
+        ("start", ("items",), []),
+        ("value", ("items", 0), 1),
+        ("value", ("items", 1), {"a": [2.5, None]}),
+        ("end", ("items",), []),
+        ("value", ("n",), 12345),
+        ("end", (), {}),
+    ]
+
+
+@pytest.mark.parametrize("text", ['{"a": [1, 2,', '[1 2]', '{"a": 1} x', '\n\n  {"a": tru}'])
+def test_errors_match_json_loads(text):
+    """Test that invalid JSON reports the same message, line, and column as json.loads."""
+    with pytest.raises(json.JSONDecodeError) as expected:
+        json.loads(text)
+    with pytest.raises(json.JSONDecodeError) as actual:
+        list(iter_events(io.StringIO(text), [(), ("a",)], chunk_size=2))
+
+    assert (actual.value.msg, actual.value.lineno, actual.value.colno) == (
+        expected.value.msg,
+        expected.value.lineno,
+        expected.value.colno,
+    )
+
+
+def test_iter_array():
+    """Test reading the elements of a top-level array, and rejecting other documents."""
+    assert list(iter_array(io.StringIO('[1, {"a": [2]}, "x"]'), chunk_size=2)) == [
+        1,
+        {"a": [2]},
+        "x",
+    ]
+    with pytest.raises(ValueError):
+        list(iter_array(io.StringIO('{"a": 1}')))
+
+
+@pytest.mark.parametrize("chunk_size", [1, 2])
+def test_numbers_cut_at_chunk_boundaries(chunk_size):
+    """Test that floats and exponents split across chunks parse whole."""
+    text = '[1.5, -7.25, 12e3, 6.02E+23, 1e-7, 0, {"cvss_score": 9.8}]'
+
+    assert list(iter_array(io.StringIO(text), chunk_size=chunk_size)) == json.loads(text)
+    assert list(iter_events(io.StringIO("3.5e2"), [()], chunk_size=chunk_size)) == [
+        ("value", (), 350.0)
+    ]
+
+
+def test_number_after_full_chunk():
+    """Test a float starting at the end of a default-size chunk."""
+    text = '["' + "x" * 65529 + '", 7.5]'
+
+    assert list(iter_array(io.StringIO(text)))[1] == 7.5
+
+
+def test_stream_validation_matches_whole_document():
+    """Test that streamed validation finds the errors that whole-document validation does."""
+    data = synthetic_collected(20)
+    data["scc_findings"][3]["severity"] = 5
+    data["metadata"]["project_id"] = 1
+    text = json.dumps(data, indent=2)
+
+    assert validate_stream(io.StringIO(text), "collected") == validate_artifact(data, "collected")
+    assert validate_stream(io.StringIO("[]"), "collected") == validate_artifact([], "collected")
+
+
+def test_stream_validation_points_into_arrays():
+    """Test that errors inside streamed arrays name the element, not the whole array."""
+    data = {"providers": [{"provider": "aws", "security_findings": [{}, 1]}, {"x": 1}]}
+
+    errors = validate_stream(io.StringIO(json.dumps(data)), "collected")
+
+    assert errors == [
+        "$.providers[0].security_findings[1]: 1 is not of type 'object'",
+        "$.providers[1]: 'provider' is a required property",
+    ]
+    assert ("providers", "*", "security_findings") in STREAMED["collected"]
+
+
+def test_report_summary_reads_providers(tmp_path):
+    """Test that the report metadata is read from a streamed multi-cloud collected.json."""
+    collected = {
+        "providers": [
+            {"provider": "gcp", "project_id": "prod", "security_findings": [{"id": 1}] * 50},
+            {"provider": "aws", "status": "failed", "error": "expired token"},
+        ]
+    }
+    (tmp_path / "collected.json").write_text(json.dumps(collected), encoding="utf-8")
+
+    service = ReportService(input_dir=tmp_path, output_dir=tmp_path / "output")
+    metadata = service.load_metadata()
+
+    assert metadata == {"project_id": "prod", "providers": ["gcp"], "multi_cloud": True}
+    assert [failure.target for failure in service.failure_log.failures] == ["aws"]
+
+
+def test_iter_findings_in_batches(tmp_path, monkeypatch):
+    """Test that explained.json is read in batches, and a missing file is reported at once."""
+    monkeypatch.setattr("app.findings.listing.FILTER_BATCH_SIZE", 2)
+    path = tmp_path / "explained.json"
+    path.write_text(json.dumps([{"title": str(n)} for n in range(5)]), encoding="utf-8")
+
+    assert [len(batch) for batch in iter_findings(str(path))] == [2, 2, 1]
+    with pytest.raises(FileNotFoundError):
+        iter_findings(str(tmp_path / "missing.json"))
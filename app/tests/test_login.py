@@ -0,0 +1,91 @@
+"""Tests for the login command and ADC helpers."""
+
+import subprocess
+from unittest.mock import MagicMock, patch
+
+import pytest
+
+from app.cli.base import CommandContext
+from app.cli.commands import LoginCommand
+from app.common.auth import run_adc_login
+from app.common.exceptions import AuthenticationError, ConfigurationError
+from app.config.paddi_config import PaddiConfig
+
+CREDENTIALS = MagicMock()
+
+
+@pytest.fixture
+def config_path(tmp_path):
+    """Path to an empty paddi.toml in a temporary directory."""
+    return tmp_path / "paddi.toml"
+
+
+class TestLoginCommand:
+    """Tests for ``paddi login``."""
+
+    @patch("app.cli.commands.validate_project_access", return_value="Production")
+    @patch("app.cli.commands.run_adc_login")
+    @patch("app.cli.commands.load_adc", return_value=(CREDENTIALS, "adc-project"))
+    def test_existing_credentials(self, mock_adc, mock_login, mock_validate, config_path):
+        """Test that existing ADC is validated and the project is saved."""
+        context = CommandContext(project_id="prod-project", config_file=str(config_path))
+        config_path.write_text("[general]\nuse_mock = true\n", encoding="utf-8")
+        LoginCommand().execute(context)
+
+        mock_login.assert_not_called()
+        mock_validate.assert_called_once_with(CREDENTIALS, "prod-project")
+        config = PaddiConfig.load(str(config_path))
+        assert config.get("general.project_id") == "prod-project"
+        assert config.get("general.use_mock") is True
+
+    @patch("app.cli.commands.validate_project_access", return_value="adc-project")
+    @patch("app.cli.commands.run_adc_login")
+    @patch("app.cli.commands.load_adc")
+    def test_launches_gcloud_when_missing(
+        self, mock_adc, mock_login, mock_validate, config_path, monkeypatch
+    ):
+        """Test that gcloud login runs when no ADC exists, then the ADC project is used."""
+        monkeypatch.chdir(config_path.parent)
+        mock_adc.side_effect = [AuthenticationError("GCP"), (CREDENTIALS, "adc-project")]
+        LoginCommand().execute(CommandContext(project_id=None))
+
+        mock_login.assert_called_once()
+        assert PaddiConfig.load(str(config_path)).get("general.project_id") == "adc-project"
+
+    @patch("app.cli.commands.load_adc", return_value=(CREDENTIALS, None))
+    def test_no_project(self, mock_adc, config_path):
+        """Test the error when no project can be determined."""
+        context = CommandContext(project_id=None, config_file=str(config_path))
+        config_path.write_text("", encoding="utf-8")
+        with pytest.raises(ConfigurationError):
+            LoginCommand().execute(context)
+
+    @patch("app.cli.commands.validate_project_access", side_effect=AuthenticationError("GCP"))
+    @patch("app.cli.commands.load_adc", return_value=(CREDENTIALS, None))
+    def test_inaccessible_project_is_not_saved(self, mock_adc, mock_validate, config_path):
+        """Test that a project the credentials cannot read is not written."""
+        context = CommandContext(project_id="typo-project", config_file=str(config_path))
+        config_path.write_text("", encoding="utf-8")
+        with pytest.raises(AuthenticationError):
+            LoginCommand().execute(context)
+        assert config_path.read_text(encoding="utf-8") == ""
+
+
+class TestRunADCLogin:
+    """Tests for launching gcloud."""
+
+    @patch("app.common.auth.subprocess.run", side_effect=FileNotFoundError)
+    def test_gcloud_missing(self, mock_run):
+        """Test the error when gcloud is not installed."""
+        with pytest.raises(AuthenticationError) as exc:
+            run_adc_login()
+        assert exc.value.details["error"] == "gcloud not found on PATH"
+
+    @patch(
+        "app.common.auth.subprocess.run",
+        side_effect=subprocess.CalledProcessError(1, "gcloud"),
+    )
+    def test_login_cancelled(self, mock_run):
+        """Test the error when the login flow fails."""
+        with pytest.raises(AuthenticationError):
+            run_adc_login()
@@ -0,0 +1,79 @@
+"""Tests for the navigation of the Markdown report."""
+
+from pathlib import Path
+
+from app.reporter.agent_reporter import MarkdownGenerator, ReportService
+from app.reporter.markdown_nav import finding_anchor, group_by_category, severity_badge, slugify
+
+FINDINGS = [
+    {"title": "Open port", "severity": "MEDIUM", "category": "NETWORK", "explanation": "e"},
+    {"title": "Owner role", "severity": "HIGH", "category": "IAM", "explanation": "e"},
+    {"title": "Firewall", "severity": "CRITICAL", "category": "NETWORK", "explanation": "e"},
+    {"title": "Other", "severity": "LOW", "explanation": "e"},
+]
+
+
+def _report():
+    findings = [{**finding, "recommendation": "r"} for finding in FINDINGS]
+    return ReportService().create_report(findings, {"project_id": "demo"}, [])
+
+
+def test_helpers():
+    """Test badges, slugs, and anchors."""
+    report = _report()
+
+    assert severity_badge("critical") == "🔴 `CRITICAL`"
+    assert slugify("Open Firewall / SSH") == "open-firewall-ssh"
+    assert finding_anchor(report.findings[0], 1) == f"finding-{report.findings[0].fingerprint}"
+    assert [c for c in group_by_category(report.findings)] == ["NETWORK", "IAM", "uncategorized"]
+
+
+def test_table_of_contents_links_every_finding():
+    """Test that the contents link each finding to its anchor."""
+    report = _report()
+    markdown = MarkdownGenerator().generate(report)
+
+    for i, finding in enumerate(report.findings, 1):
+        anchor = finding_anchor(finding, i)
+        assert f'<a id="{anchor}"></a>\n### {i}. {finding.title}' in markdown
+        assert f"[{finding.title}](#{anchor})" in markdown
+    assert "- [Executive Summary](#executive-summary)" in markdown
+
+
+def test_builtin_template_navigation():
+    """Test that the built-in template has the contents, anchors, and badges too."""
+    report = _report()
+    template = MarkdownGenerator().generate(report, Path("app/templates/report.md.j2"))
+
+    anchor = finding_anchor(report.findings[1], 2)
+    assert f"  2. 🟠 `HIGH` [Owner role](#{anchor})" in template
+    assert f'<a id="{anchor}"></a>' in template
+    assert "**Severity Level:** 🟠 `HIGH`" in template
+
+
+def test_split_by_category():
+    """Test one file per category, keeping finding numbers, with links from the index."""
+    files = MarkdownGenerator().split_by_category(_report())
+
+    assert sorted(files) == ["README.md", "iam.md", "network.md", "uncategorized.md"]
+    assert "### 1. Open port" in files["network.md"]
+    assert "### 3. Firewall" in files["network.md"]
+    assert "Owner role" not in files["network.md"]
+    assert "| [NETWORK](network.md) | 2 | 🔴 `CRITICAL` |" in files["README.md"]
+    assert "](iam.md#finding-" in files["README.md"]
+
+
+def test_markdown_split_format(tmp_path):
+    """Test that the markdown-split format writes the split files."""
+    data_dir = tmp_path / "data"
+    data_dir.mkdir()
+    (data_dir / "explained.json").write_text(
+        '[{"title": "t", "severity": "HIGH", "explanation": "e", "recommendation": "r"}]'
+    )
+    output_dir = tmp_path / "output"
+
+    ReportService(input_dir=data_dir, output_dir=output_dir).generate_reports(["markdown-split"])
+
+    assert (output_dir / "audit-by-category" / "README.md").exists()
+    assert (output_dir / "audit-by-category" / "uncategorized.md").exists()
+    assert not (output_dir / "audit.md").exists()
@@ -1,6 +1,14 @@
 """Tests for common data models."""
 
-from common.models import EnhancedRecommendation, RecommendationStep, SecurityFinding
+from common.models import (
+    CollectedData,
+    EnhancedRecommendation,
+    IamPolicy,
+    RecommendationStep,
+    SccFinding,
+    SecurityFinding,
+    severity_rank,
+)
 
 
 class TestSecurityFinding:
@@ -40,6 +48,27 @@ class TestSecurityFinding:
         assert "finding_id" not in result
         assert "source" not in result
 
+    def test_to_dict_with_category_and_resource(self):
+        """Test that category and resource are serialized when set."""
+        finding = SecurityFinding(
+            title="Public Bucket",
+            severity="HIGH",
+            explanation="Bucket is public",
+            recommendation="Remove allUsers",
+            category="PUBLIC_BUCKET",
+            resource="//storage.googleapis.com/example",
+        )
+
+        result = finding.to_dict()
+
+        assert result["category"] == "PUBLIC_BUCKET"
+        assert result["resource"] == "//storage.googleapis.com/example"
+
+    def test_severity_rank(self):
+        """Test severity ordering helper."""
+        assert severity_rank("CRITICAL") < severity_rank("high") < severity_rank("LOW")
+        assert severity_rank("UNKNOWN") > severity_rank("INFO")
+
     def test_multiple_instances(self):
         """Test that multiple instances are independent."""
         finding1 = SecurityFinding(
@@ -250,3 +279,57 @@ class TestEnhancedRecommendation:
         assert result["enhanced_recommendation"]["summary"] == "Remove excessive permissions"
         assert len(result["enhanced_recommendation"]["steps"]) == 1
         assert result["enhanced_recommendation"]["estimated_time"] == "15分"
+
+
+class TestFromDict:
+    """Test cases for loading models from artifact JSON."""
+
+    def test_security_finding_round_trip(self):
+        """Test that to_dict output loads back into an equal finding."""
+        finding = SecurityFinding(
+            title="Public bucket",
+            severity="HIGH",
+            explanation="Readable by allUsers",
+            recommendation="Remove allUsers",
+            cvss_score=7.5,
+            enhanced_recommendation=EnhancedRecommendation(
+                summary="Restrict access", steps=[RecommendationStep(order=1, action="Remove")]
+            ),
+        )
+        assert SecurityFinding.from_dict(finding.to_dict()) == finding
+
+    def test_security_finding_defaults(self):
+        """Test defaults for missing fields and ignored unknown keys."""
+        finding = SecurityFinding.from_dict({"severity": "LOW", "tags": ["pci"]})
+        assert finding.title == "Unknown Issue"
+        assert finding.explanation == "No explanation provided"
+        assert finding.occurrences == 1
+
+    def test_iam_policy_keeps_extra_keys(self):
+        """Test that provider-specific keys survive a round trip."""
+        data = {
+            "bindings": [{"role": "roles/owner", "members": ["user:a"], "source": "tf"}],
+            "etag": "BwX",
+            "users": [{"name": "alice"}],
+        }
+        policy = IamPolicy.from_dict(data)
+        assert policy.bindings[0].role == "roles/owner"
+        assert policy.to_dict() == data
+
+    def test_collected_data(self):
+        """Test typed access to collected.json."""
+        data = CollectedData.from_dict(
+            {
+                "metadata": {"project_id": "p", "timestamp": "2024-01-01T00:00:00Z"},
+                "iam_policies": {"bindings": []},
+                "scc_findings": [{"category": "PUBLIC_BUCKET", "severity": "HIGH"}],
+                "firewall_rules": [],
+            }
+        )
+        assert data.scc_findings == [SccFinding(category="PUBLIC_BUCKET", severity="HIGH")]
+        assert data.to_dict()["metadata"] == {
+            "project_id": "p",
+            "organization_id": None,
+            "timestamp": "2024-01-01T00:00:00Z",
+        }
+        assert data.to_dict()["firewall_rules"] == []
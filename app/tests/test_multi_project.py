@@ -0,0 +1,102 @@
+"""Tests for multi-project audits."""
+
+from pathlib import Path
+from unittest.mock import patch
+
+import pytest
+
+from app.cli.base import CommandContext
+from app.cli.commands import AuditCommand
+from app.cli.multi_project import (
+    FAILED,
+    PASSED,
+    SKIPPED,
+    ProjectResult,
+    aggregate_exit_code,
+    format_project_results,
+    split_projects,
+)
+from app.common.exceptions import AuthenticationError, ProjectsFailedError
+from app.common.exit_codes import ExitCode, exit_code_for
+
+
+def test_split_projects():
+    """Test comma-separated and list values, without duplicates."""
+    assert split_projects("prod, staging,,prod") == ["prod", "staging"]
+    assert split_projects(("prod", "dev")) == ["prod", "dev"]
+
+
+def test_aggregate_exit_code():
+    """Test success, a shared failure class, and mixed failure classes."""
+    passed = ProjectResult("prod", PASSED, 0)
+    findings = ProjectResult("dev", FAILED, 4, "2 findings")
+    auth = ProjectResult("staging", FAILED, 7, "no credentials")
+
+    assert aggregate_exit_code([passed, ProjectResult("ci", SKIPPED)]) == ExitCode.SUCCESS
+    assert aggregate_exit_code([passed, findings]) == ExitCode.FINDINGS_OVER_THRESHOLD
+    assert aggregate_exit_code([findings, auth]) == ExitCode.GENERAL_ERROR
+
+    table = format_project_results([passed, auth]).splitlines()
+    assert table[0].split() == ["Project", "Status", "Exit", "Error"]
+    assert table[2].split() == ["staging", "failed", "7", "no", "credentials"]
+
+
+def run_projects(context, failing):
+    """Audit with every agent mocked; projects in ``failing`` fail to authenticate.
+
+    Returns:
+        The audited projects and the report directories, in order
+    """
+    audited, output_dirs = [], []
+
+    def collect(project_id, **kwargs):
+        audited.append(project_id)
+        if project_id in failing:
+            raise AuthenticationError()
+
+    def report(output_dir, **kwargs):
+        output_dirs.append(output_dir)
+        return []
+
+    with patch("app.cli.commands.collector_main", side_effect=collect), patch(
+        "app.cli.commands.explainer_main"
+    ), patch("app.cli.commands.reporter_main", side_effect=report):
+        AuditCommand().execute(context)
+    return audited, output_dirs
+
+
+def test_keep_going_by_default(tmp_path, monkeypatch, capsys):
+    """Test that every project is audited and the exit code is aggregated."""
+    monkeypatch.chdir(tmp_path)
+    context = CommandContext(projects="prod,staging,dev")
+
+    with pytest.raises(ProjectsFailedError) as exc_info:
+        run_projects(context, {"staging"})
+
+    assert exc_info.value.failed == 1
+    assert exit_code_for(exc_info.value) == ExitCode.AUTHENTICATION_ERROR
+    statuses = [r["status"] for r in exc_info.value.details["results"]]
+    assert statuses == ["passed", "failed", "passed"]
+    assert "staging  failed   7" in capsys.readouterr().out
+
+
+def test_fail_fast(tmp_path, monkeypatch):
+    """Test that --fail-fast skips the projects after the first failure."""
+    monkeypatch.chdir(tmp_path)
+    context = CommandContext(projects="prod,staging,dev", fail_fast=True)
+
+    with pytest.raises(ProjectsFailedError) as exc_info:
+        run_projects(context, {"prod"})
+
+    statuses = [r["status"] for r in exc_info.value.details["results"]]
+    assert statuses == ["failed", "skipped", "skipped"]
+
+
+def test_all_projects_pass(tmp_path, monkeypatch):
+    """Test that the audit succeeds when every project passes."""
+    monkeypatch.chdir(tmp_path)
+
+    audited, output_dirs = run_projects(CommandContext(projects=["prod", "dev"]), set())
+
+    assert audited == ["prod", "dev"]
+    assert output_dirs == [str(Path("output") / "prod"), str(Path("output") / "dev")]
@@ -0,0 +1,82 @@
+"""Tests for the OCSF findings export."""
+
+import json
+
+from app.cli.base import CommandContext
+from app.cli.commands import ExportCommand
+from app.export.ocsf import OCSF_FILE, build_events, ocsf_event
+from app.findings.fingerprint import compute_fingerprint
+
+FINDING = {
+    "title": "Public bucket",
+    "severity": "HIGH",
+    "category": "PUBLIC_BUCKET",
+    "resource": "//storage.googleapis.com/assets",
+    "explanation": "Anyone can read objects.",
+    "recommendation": "Remove allUsers.",
+}
+
+
+class TestOcsfEvent:
+    """Tests for mapping findings to OCSF Detection Findings."""
+
+    def test_required_attributes(self):
+        """Test the class, type, severity, and metadata attributes."""
+        event = ocsf_event(FINDING, "prod", 1_700_000_000_000)
+
+        assert (event["category_uid"], event["class_uid"], event["type_uid"]) == (2, 2004, 200401)
+        assert (event["severity_id"], event["severity"]) == (4, "High")
+        assert event["time"] == 1_700_000_000_000
+        assert event["metadata"]["version"] == "1.1.0"
+        assert event["metadata"]["product"]["name"] == "Paddi"
+
+    def test_finding_details(self):
+        """Test finding info, resources, remediation, and cloud account."""
+        event = ocsf_event(FINDING, "prod", 0)
+
+        assert event["finding_info"]["uid"] == compute_fingerprint(FINDING)
+        assert event["finding_info"]["types"] == ["PUBLIC_BUCKET"]
+        assert event["resources"] == [
+            {"uid": "//storage.googleapis.com/assets", "type": "cloud_resource"}
+        ]
+        assert event["remediation"] == {"desc": "Remove allUsers."}
+        assert event["cloud"] == {
+            "provider": "GCP",
+            "account": {"uid": "prod", "type": "GCP Account"},
+        }
+
+    def test_other_providers_and_severities(self):
+        """Test AWS findings, INFO severity, and CVSS scores."""
+        finding = dict(FINDING, provider="aws", severity="INFO", cvss_score=5.3)
+
+        event = ocsf_event(finding, None, 0)
+
+        assert event["severity_id"] == 1
+        assert event["cloud"] == {"provider": "AWS"}
+        assert event["vulnerabilities"][0]["cve"]["cvss"][0]["base_score"] == 5.3
+
+    def test_shared_event_time(self):
+        """Test that one export shares a single event time."""
+        events = build_events([FINDING, dict(FINDING, title="Other")])
+
+        assert events[0]["time"] == events[1]["time"]
+
+
+class TestExportCommand:
+    """Tests for export --to ocsf."""
+
+    def test_writes_json_lines(self, tmp_path, capsys):
+        """Test that one event is written per line."""
+        input_file = tmp_path / "explained.json"
+        input_file.write_text(json.dumps([FINDING, dict(FINDING, title="Other", category="X")]))
+        output_dir = tmp_path / "output"
+
+        ExportCommand().execute(
+            CommandContext(
+                export_target="ocsf", input_file=str(input_file), output_dir=str(output_dir)
+            )
+        )
+
+        lines = (output_dir / OCSF_FILE).read_text().splitlines()
+        assert [json.loads(line)["message"] for line in lines] == ["Public bucket", "Other"]
+        assert "Wrote 2 OCSF event(s)" in capsys.readouterr().out
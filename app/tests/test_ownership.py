@@ -0,0 +1,107 @@
+"""Tests for finding owners and per-owner routing."""
+
+import json
+from unittest.mock import MagicMock, patch
+
+import pytest
+
+from app.common.exceptions import ConfigurationError
+from app.config.paddi_config import PaddiConfig
+from app.findings.ownership import UNASSIGNED, OwnershipRules
+from app.history.store import HISTORY_DB_NAME, RunStore
+from app.notifications.webhooks import Webhook, WebhookNotifier
+from app.reporter.agent_reporter import MarkdownGenerator, ReportService
+
+CONFIG = PaddiConfig(
+    {
+        "owners": {
+            "identity": {"categories": ["overprivileged_role"], "contact": "#identity"},
+            "platform": {"resources": ["//storage.googleapis.com/*", "projects/prod-*"]},
+        }
+    }
+)
+
+ROLE = {
+    "title": "Owner role",
+    "severity": "CRITICAL",
+    "category": "OVERPRIVILEGED_ROLE",
+    "resource": "projects/prod-app",
+}
+BUCKET = {
+    "title": "Public bucket",
+    "severity": "HIGH",
+    "category": "PUBLIC_BUCKET",
+    "resource": "storage.googleapis.com/site",
+}
+FIREWALL = {
+    "title": "Open firewall",
+    "severity": "MEDIUM",
+    "category": "OPEN_FIREWALL",
+    "resource": "projects/dev-app/firewalls/ssh",
+}
+
+
+def test_assign_first_matching_owner():
+    """Test that owners match by category or resource, in definition order."""
+    rules = OwnershipRules.from_config(CONFIG)
+
+    assigned = rules.assign([ROLE, BUCKET, FIREWALL, {**FIREWALL, "owner": "network"}])
+
+    assert [f.get("owner") for f in assigned] == ["identity", "platform", None, "network"]
+    assert rules.owned_by([ROLE, BUCKET, FIREWALL], ["platform", UNASSIGNED]) == [
+        BUCKET,
+        FIREWALL,
+    ]
+
+
+def test_summarize():
+    """Test per-owner counts, with unassigned findings last."""
+    summaries = OwnershipRules.from_config(CONFIG).summarize([FIREWALL, BUCKET, ROLE])
+
+    assert [(s.owner, s.contact, s.total) for s in summaries] == [
+        ("identity", "#identity", 1),
+        ("platform", None, 1),
+        (UNASSIGNED, None, 1),
+    ]
+    assert summaries[0].severity_counts == {"CRITICAL": 1}
+
+
+def test_owner_needs_a_match():
+    """Test that an owner without resources or categories is rejected."""
+    with pytest.raises(ConfigurationError):
+        OwnershipRules.from_config(PaddiConfig({"owners": {"platform": {"contact": "x"}}}))
+
+
+def test_report_has_owner_sections(monkeypatch):
+    """Test the Findings by Owner section of the report."""
+    monkeypatch.setattr(PaddiConfig, "load", classmethod(lambda cls, *a, **k: CONFIG))
+    findings = OwnershipRules.from_config(CONFIG).assign([ROLE, BUCKET])
+
+    markdown = MarkdownGenerator().generate(ReportService().create_report(findings, {}))
+
+    assert "## Findings by Owner" in markdown
+    assert "| identity | #identity | 1 | 0 | 0 | 0 | 0 | 1 |" in markdown
+    assert "### platform" in markdown
+    assert "**Owner:** identity" in markdown
+
+
+def test_webhook_routing(tmp_path, monkeypatch):
+    """Test that a webhook with owners only receives their findings."""
+    monkeypatch.setattr(PaddiConfig, "load", classmethod(lambda cls, *a, **k: CONFIG))
+    run = RunStore(tmp_path / HISTORY_DB_NAME).record_run([ROLE, BUCKET, FIREWALL], "prod")
+    notifier = WebhookNotifier(
+        [
+            Webhook("all", "https://hooks.example.com/all"),
+            Webhook("platform", "https://hooks.example.com/platform", owners=["platform"]),
+        ]
+    )
+
+    with patch("app.notifications.webhooks.requests.post") as post:
+        post.return_value = MagicMock(status_code=200)
+        assert notifier.notify_run(tmp_path, run.run_id) == {"all": True, "platform": True}
+
+    totals = {
+        call.args[0]: json.loads(call.kwargs["data"])["summary"]["total"]
+        for call in post.call_args_list
+    }
+    assert totals == {"https://hooks.example.com/all": 3, "https://hooks.example.com/platform": 1}
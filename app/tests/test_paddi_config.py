@@ -0,0 +1,54 @@
+"""Tests for the paddi.toml configuration loader."""
+
+import pytest
+
+from app.common.exceptions import ConfigurationError
+from app.config.paddi_config import PaddiConfig
+
+
+class TestPaddiConfig:
+    """Tests for PaddiConfig."""
+
+    def test_load_missing_default_returns_empty(self, tmp_path, monkeypatch):
+        """Test that a missing default config file yields empty config."""
+        monkeypatch.chdir(tmp_path)
+        monkeypatch.delenv("PADDI_CONFIG", raising=False)
+
+        config = PaddiConfig.load()
+
+        assert config.data == {}
+        assert config.path is None
+
+    def test_load_missing_explicit_path_raises(self, tmp_path):
+        """Test that a missing explicit path is an error."""
+        with pytest.raises(ConfigurationError):
+            PaddiConfig.load(str(tmp_path / "missing.toml"))
+
+    def test_load_from_env(self, tmp_path, monkeypatch):
+        """Test resolving the config file from PADDI_CONFIG."""
+        config_file = tmp_path / "custom.toml"
+        config_file.write_text('[general]\nproject_id = "env-project"\n', encoding="utf-8")
+        monkeypatch.setenv("PADDI_CONFIG", str(config_file))
+
+        config = PaddiConfig.load()
+
+        assert config.get("general.project_id") == "env-project"
+
+    def test_invalid_toml_raises(self, tmp_path):
+        """Test that malformed TOML raises ConfigurationError."""
+        config_file = tmp_path / "paddi.toml"
+        config_file.write_text("[general\n", encoding="utf-8")
+
+        with pytest.raises(ConfigurationError):
+            PaddiConfig.load(str(config_file))
+
+    def test_get_and_section(self):
+        """Test dotted key lookup and section access."""
+        config = PaddiConfig({"plugins": {"dir": "ext", "config": {"demo": {"level": 2}}}})
+
+        assert config.get("plugins.dir") == "ext"
+        assert config.get("plugins.config.demo.level") == 2
+        assert config.get("plugins.missing", "default") == "default"
+        assert config.section("plugins.config") == {"demo": {"level": 2}}
+        assert config.section("plugins.dir") == {}
+        assert config.section("absent") == {}
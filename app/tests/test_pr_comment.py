@@ -0,0 +1,128 @@
+"""Tests for the pull request comment export."""
+
+import json
+from unittest.mock import Mock, patch
+
+import pytest
+
+from app.cli.base import CommandContext
+from app.cli.commands import ExportCommand
+from app.common.exceptions import ConfigurationError
+from app.config.paddi_config import PaddiConfig
+from app.export.pr_comment import (
+    COMMENT_MARKER,
+    PRCommentExporter,
+    PullRequest,
+    comment_body,
+    detect_pull_request,
+)
+from app.history.store import HISTORY_DB_NAME, RunStore
+
+BUCKET = {"title": "Public bucket", "severity": "HIGH", "resource": "storage.googleapis.com/site"}
+ROLE = {"title": "Owner role", "severity": "CRITICAL", "resource": "projects/prod"}
+FIREWALL = {"title": "Open firewall", "severity": "MEDIUM", "resource": "firewalls/ssh"}
+
+
+def response(data):
+    """Build a successful API response."""
+    return Mock(status_code=200, json=Mock(return_value=data), raise_for_status=Mock())
+
+
+def test_detect_pull_request(tmp_path):
+    """Test the GitHub event file, the GitHub ref fallback, and GitLab variables."""
+    event = tmp_path / "event.json"
+    event.write_text(json.dumps({"pull_request": {"number": 42}}), encoding="utf-8")
+    github = {"GITHUB_ACTIONS": "true", "GITHUB_REPOSITORY": "org/repo"}
+
+    assert detect_pull_request({**github, "GITHUB_EVENT_PATH": str(event)}) == PullRequest(
+        "github", "https://api.github.com", "org/repo", 42
+    )
+    assert detect_pull_request({**github, "GITHUB_REF": "refs/pull/7/merge"}).number == 7
+    assert detect_pull_request({**github, "GITHUB_REF": "refs/heads/main"}) is None
+    gitlab = detect_pull_request(
+        {"GITLAB_CI": "true", "CI_PROJECT_ID": "12", "CI_MERGE_REQUEST_IID": "3"}
+    )
+    assert (gitlab.platform, str(gitlab)) == ("gitlab", "12!3")
+    assert detect_pull_request({}) is None
+
+
+def test_comment_body():
+    """Test the summary line, tables, and the row limit."""
+    body = comment_body([BUCKET, ROLE], [FIREWALL], 5, "baseline run #2", max_findings=1)
+
+    assert body.startswith(COMMENT_MARKER)
+    assert "Compared with baseline run #2: **2 new**, **1 resolved**, 5 total." in body
+    assert "| CRITICAL | Owner role | `projects/prod` |" in body
+    assert "Public bucket" not in body
+    assert "…and 1 more" in body
+    assert "No findings were added or resolved." in comment_body([], [], 0)
+
+
+def test_from_config_needs_pull_request_and_token():
+    """Test the errors outside a pull request and without a token."""
+    with pytest.raises(ConfigurationError):
+        PRCommentExporter.from_config(PaddiConfig({}), env={})
+    env = {"GITLAB_CI": "true", "CI_PROJECT_ID": "12", "CI_MERGE_REQUEST_IID": "3"}
+    with patch("app.export.pr_comment.resolve_secret", return_value=None):
+        with pytest.raises(ConfigurationError):
+            PRCommentExporter.from_config(PaddiConfig({}), env=env)
+
+    exporter = PRCommentExporter.from_config(
+        PaddiConfig({"export": {"pr_comment": {"token": "t", "max_findings": 5}}}), env=env
+    )
+    assert exporter.max_findings == 5
+
+
+def test_post_creates_then_updates():
+    """Test that the marked comment is updated instead of adding another one."""
+    pull_request = PullRequest("github", "https://api.github.com", "org/repo", 4)
+    exporter = PRCommentExporter(pull_request, "t")
+
+    with patch("app.export.pr_comment.requests.request") as request:
+        request.side_effect = [response([{"id": 1, "body": "LGTM"}]), response({})]
+        assert exporter.post(COMMENT_MARKER + " first") == "created"
+        assert request.call_args.args == (
+            "POST",
+            "https://api.github.com/repos/org/repo/issues/4/comments",
+        )
+
+        request.side_effect = [response([{"id": 9, "body": COMMENT_MARKER}]), response({})]
+        assert exporter.post(COMMENT_MARKER + " second") == "updated"
+        assert request.call_args.args == (
+            "PATCH",
+            "https://api.github.com/repos/org/repo/issues/comments/9",
+        )
+        assert request.call_args.kwargs["json"] == {"body": COMMENT_MARKER + " second"}
+
+
+def test_gitlab_note_update():
+    """Test the GitLab notes API and its token header."""
+    exporter = PRCommentExporter(PullRequest("gitlab", "https://gl/api/v4", "grp/app", 3), "t")
+
+    with patch("app.export.pr_comment.requests.request") as request:
+        request.side_effect = [response([{"id": 5, "body": COMMENT_MARKER}]), response({})]
+        exporter.post("body")
+
+    assert request.call_args.args == (
+        "PUT",
+        "https://gl/api/v4/projects/grp%2Fapp/merge_requests/3/notes/5",
+    )
+    assert request.call_args.kwargs["headers"] == {"PRIVATE-TOKEN": "t"}
+
+
+def test_export_dry_run_compares_with_baseline(tmp_path, capsys, monkeypatch):
+    """Test that --dry-run prints the delta against the baseline run."""
+    monkeypatch.setattr(PaddiConfig, "load", classmethod(lambda cls, *a, **k: PaddiConfig({})))
+    store = RunStore(tmp_path / HISTORY_DB_NAME)
+    baseline = store.record_run([BUCKET, FIREWALL])
+    store.set_baseline(baseline.run_id)
+    explained = tmp_path / "explained.json"
+    explained.write_text(json.dumps([BUCKET, ROLE]), encoding="utf-8")
+
+    ExportCommand().execute(
+        CommandContext(export_target="pr-comment", dry_run=True, input_file=str(explained))
+    )
+
+    body = capsys.readouterr().out
+    assert f"Compared with baseline run #{baseline.run_id}" in body
+    assert "**1 new**, **1 resolved**, 2 total." in body
@@ -0,0 +1,53 @@
+"""Tests for per-task progress bars."""
+
+import io
+from unittest.mock import patch
+
+import pytest
+
+from app.cli.base import CommandContext
+from app.cli.commands import AUDIT_STAGES, AuditCommand
+from app.cli.progress import TaskProgress, progress_enabled
+from app.common.exceptions import CollectionError, ProjectsFailedError
+
+
+def test_disabled_without_terminal(monkeypatch):
+    """Test that no bars are drawn when stderr is not a terminal."""
+    monkeypatch.setattr("sys.stderr", io.StringIO())
+    assert not progress_enabled()
+
+    with TaskProgress(AUDIT_STAGES, enabled=False) as progress:
+        progress.add("prod")
+        progress.stage("prod", "collect")
+        progress.finish("prod", "passed")
+    assert progress._progress is None
+
+
+@patch("app.cli.commands.CollectCommand")
+@patch("app.cli.commands.ExplainCommand")
+@patch("app.cli.commands.ReportCommand")
+@patch("app.cli.commands.TaskProgress")
+def test_projects_advance_their_own_bars(
+    mock_progress, mock_report, mock_explain, _collect, tmp_path, monkeypatch
+):
+    """Test that each project's bar follows its stages and ends with its status."""
+    monkeypatch.chdir(tmp_path)
+    progress = mock_progress.from_config.return_value.__enter__.return_value
+    mock_report.return_value.generate.return_value = []
+    mock_explain.return_value.execute.side_effect = [CollectionError("x"), None]
+
+    with pytest.raises(ProjectsFailedError):
+        AuditCommand().execute(CommandContext(projects="dev,prod", use_mock=True))
+
+    assert [c.args for c in progress.add.call_args_list] == [("dev",), ("prod",)]
+    assert [c.args for c in progress.stage.call_args_list] == [
+        ("dev", "collect", None),
+        ("dev", "explain", None),
+        ("prod", "collect", None),
+        ("prod", "explain", None),
+        ("prod", "report", None),
+    ]
+    assert [c.args for c in progress.finish.call_args_list] == [
+        ("dev", "failed"),
+        ("prod", "passed"),
+    ]
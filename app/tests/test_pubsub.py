@@ -0,0 +1,125 @@
+"""Tests for publishing new findings to Pub/Sub."""
+
+import json
+from unittest.mock import MagicMock, patch
+
+import pytest
+
+from app.cli.base import CommandContext
+from app.cli.commands import ExplainCommand
+from app.common.exceptions import ConfigurationError
+from app.config.paddi_config import PaddiConfig
+from app.findings.fingerprint import compute_fingerprint
+from app.history.store import HISTORY_DB_NAME, RunStore
+from app.notifications.pubsub import PubSubPublisher, message_for, topic_path
+
+FINDINGS = [
+    {"title": "Public bucket", "severity": "HIGH", "category": "PUBLIC_BUCKET", "resource": "b1"},
+    {"title": "Legacy setting", "severity": "LOW", "category": "LEGACY"},
+]
+
+TOPIC = "projects/sec/topics/findings"
+
+
+class TestMessages:
+    """Tests for topic paths and message contents."""
+
+    def test_topic_path(self):
+        """Test topic IDs and full paths."""
+        assert topic_path("findings", "prod") == "projects/prod/topics/findings"
+        assert topic_path(TOPIC, "prod") == TOPIC
+
+    def test_message_for(self):
+        """Test the message data and filterable attributes."""
+        data, attributes = message_for(FINDINGS[0], "prod")
+
+        assert json.loads(data)["fingerprint"] == compute_fingerprint(FINDINGS[0])
+        assert attributes == {
+            "event": "finding.new",
+            "fingerprint": compute_fingerprint(FINDINGS[0]),
+            "severity": "HIGH",
+            "category": "PUBLIC_BUCKET",
+            "project_id": "prod",
+        }
+
+
+class TestPubSubPublisher:
+    """Tests for PubSubPublisher."""
+
+    def test_publish_respects_min_severity(self):
+        """Test that findings below the threshold are not published."""
+        client = MagicMock()
+
+        count = PubSubPublisher(TOPIC, "MEDIUM", client).publish(FINDINGS, "prod")
+
+        assert count == 1
+        client.publish.assert_called_once()
+        assert client.publish.call_args.args[0] == TOPIC
+        client.publish.return_value.result.assert_called_once()
+
+    def test_publish_new_skips_previous_findings(self, tmp_path):
+        """Test that only findings absent from the previous run are published."""
+        RunStore(tmp_path / HISTORY_DB_NAME).record_run(FINDINGS[:1], "prod")
+        client = MagicMock()
+
+        count = PubSubPublisher(TOPIC, publisher=client).publish_new(FINDINGS, tmp_path, "prod")
+
+        assert count == 1
+        assert client.publish.call_args.kwargs["category"] == "LEGACY"
+
+    def test_publish_new_without_history(self, tmp_path):
+        """Test that every finding is new on the first run."""
+        client = MagicMock()
+
+        assert PubSubPublisher(TOPIC, publisher=client).publish_new(FINDINGS, tmp_path) == 2
+        assert not (tmp_path / HISTORY_DB_NAME).exists()
+
+
+class TestFromConfig:
+    """Tests for Pub/Sub configuration."""
+
+    def test_not_configured(self):
+        """Test that no publisher is created without a topic."""
+        assert PubSubPublisher.from_config(PaddiConfig({})) is None
+
+    def test_topic_in_audited_project(self):
+        """Test that topic IDs resolve against the audited project."""
+        config = PaddiConfig({"notifications": {"pubsub": {"topic": "findings"}}})
+
+        assert PubSubPublisher.from_config(config, "prod").topic == "projects/prod/topics/findings"
+
+    def test_invalid_severity(self):
+        """Test that unknown severities are rejected."""
+        config = PaddiConfig({"notifications": {"pubsub": {"topic": TOPIC, "min_severity": "X"}}})
+
+        with pytest.raises(ConfigurationError):
+            PubSubPublisher.from_config(config)
+
+
+class TestExplainCommand:
+    """Tests for publishing after analysis."""
+
+    def test_publishes_after_analysis(self, tmp_path):
+        """Test that the explain command publishes the new findings."""
+        (tmp_path / "explained.json").write_text(json.dumps(FINDINGS))
+        client = MagicMock()
+
+        with patch(
+            "app.cli.commands.PubSubPublisher.from_config",
+            return_value=PubSubPublisher(TOPIC, publisher=client),
+        ):
+            ExplainCommand()._publish_new_findings(CommandContext(), str(tmp_path))
+
+        assert client.publish.call_count == 2
+
+    def test_publish_failure_does_not_fail_analysis(self, tmp_path):
+        """Test that publishing errors are logged."""
+        (tmp_path / "explained.json").write_text(json.dumps(FINDINGS))
+        client = MagicMock()
+        client.publish.return_value.result.side_effect = RuntimeError("permission denied")
+
+        with patch(
+            "app.cli.commands.PubSubPublisher.from_config",
+            return_value=PubSubPublisher(TOPIC, publisher=client),
+        ):
+            ExplainCommand()._publish_new_findings(CommandContext(), str(tmp_path))
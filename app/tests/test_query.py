@@ -0,0 +1,107 @@
+"""Tests for --query filter expressions."""
+
+import json
+
+import pytest
+
+from app.cli.base import CommandContext
+from app.cli.commands import FindingsCommand
+from app.common.exceptions import QueryError
+from app.findings.query import Query, describe_error
+
+FINDINGS = [
+    {
+        "title": "Owner role",
+        "severity": "CRITICAL",
+        "category": "IAM_OVERPRIVILEGED_ROLE",
+        "resource": "projects/prod-app",
+        "cvss_score": 9.1,
+        "tags": ["pci"],
+    },
+    {
+        "title": "Public bucket",
+        "severity": "HIGH",
+        "category": "PUBLIC_BUCKET",
+        "resource": "storage.googleapis.com/prod-site",
+        "owner": "platform",
+    },
+    {
+        "title": "Open firewall",
+        "severity": "MEDIUM",
+        "category": "OPEN_FIREWALL",
+        "resource": "projects/dev-app/firewalls/ssh",
+        "cvss_score": 5.3,
+    },
+]
+
+
+def titles(query: str):
+    """Return the titles of the findings that match a query."""
+    return [f["title"] for f in Query(query).filter(FINDINGS)]
+
+
+@pytest.mark.parametrize(
+    "query,expected",
+    [
+        (
+            'severity>=HIGH and category=~"IAM" and resource contains "prod"',
+            ["Owner role"],
+        ),
+        ("severity < high", ["Open firewall"]),
+        ("severity in (critical, MEDIUM)", ["Owner role", "Open firewall"]),
+        ("cvss_score > 5.3", ["Owner role"]),
+        ("tags = PCI or owner = platform", ["Owner role", "Public bucket"]),
+        ("tags != pci and not (owner = platform)", ["Open firewall"]),
+        ("category !~ '^(public|open)_'", ["Owner role"]),
+        (
+            "resource contains prod and (severity = high or cvss_score >= 9)",
+            ["Owner role", "Public bucket"],
+        ),
+    ],
+)
+def test_query_filter(query, expected):
+    """Test operators, precedence, and list fields."""
+    assert titles(query) == expected
+
+
+@pytest.mark.parametrize(
+    "query,position",
+    [
+        ("", 0),
+        ("severity >=", 11),
+        ("cvss_score > high", 11),
+        ("category =~ '['", 9),
+        ("severity = high critical", 16),
+        ("(severity = high", 16),
+        ("severity ~ high", 9),
+    ],
+)
+def test_invalid_query(query, position):
+    """Test that invalid queries report where they fail."""
+    with pytest.raises(QueryError) as error:
+        Query(query)
+    message, caret = describe_error(error.value)
+    assert message
+    assert caret == " " * position + "^"
+
+
+def test_findings_list_query(tmp_path, capsys):
+    """Test paddi findings list --query."""
+    explained = tmp_path / "explained.json"
+    explained.write_text(json.dumps(FINDINGS), encoding="utf-8")
+
+    FindingsCommand().execute(
+        CommandContext(
+            action="list",
+            query="severity >= high and cvss_score < 9.5",
+            output_format="json",
+            input_file=str(explained),
+        )
+    )
+
+    listed = json.loads(capsys.readouterr().out)
+    assert [f["title"] for f in listed["findings"]] == ["Owner role"]
+    with pytest.raises(QueryError):
+        FindingsCommand().execute(
+            CommandContext(action="list", query="severity >", input_file=str(explained))
+        )
@@ -0,0 +1,77 @@
+"""Tests for client-side API rate limits."""
+
+import pytest
+
+from app.common.exceptions import ConfigurationError
+from app.common.rate_limit import RateLimiter, TokenBucket
+from app.common.retry import RetryPolicy
+from app.config.paddi_config import PaddiConfig
+
+
+class FakeClock:
+    """A clock that only moves when sleep is called."""
+
+    def __init__(self):
+        self.now = 0.0
+        self.sleeps = []
+
+    def __call__(self):
+        return self.now
+
+    def sleep(self, seconds):
+        self.sleeps.append(seconds)
+        self.now += seconds
+
+
+def test_token_bucket_allows_burst_then_paces():
+    """Test that a full bucket allows a burst, then requests wait for new tokens."""
+    clock = FakeClock()
+    bucket = TokenBucket(2, clock=clock, sleep=clock.sleep)
+
+    assert [bucket.acquire() for _ in range(2)] == [0, 0]
+    assert bucket.acquire() == pytest.approx(0.5)
+
+    # Idle time refills the bucket, but never beyond one second's worth
+    clock.now += 10
+    assert [bucket.acquire() for _ in range(2)] == [0, 0]
+    assert bucket.acquire() == pytest.approx(0.5)
+
+
+def test_limits_per_family():
+    """Test that families are limited separately and default covers unlisted ones."""
+    clock = FakeClock()
+    limiter = RateLimiter({"scc": 1, "default": 1}, clock=clock, sleep=clock.sleep)
+
+    for operation in ("scc.list_findings", "iam.get_iam_policy", "scc.create_finding"):
+        limiter.acquire(operation)
+
+    assert clock.sleeps == [pytest.approx(1)]
+    RateLimiter().acquire("scc.list_findings")  # unlimited without [limits]
+
+
+def test_from_config(tmp_path):
+    """Test reading [limits] and rejecting rates that are not positive."""
+    path = tmp_path / "paddi.toml"
+    path.write_text("[limits]\niam = 5\nscc = 0.5\n", encoding="utf-8")
+    assert RateLimiter.from_config(PaddiConfig.load(path)).limits == {"iam": 5.0, "scc": 0.5}
+
+    path.write_text("[limits]\niam = 0\n", encoding="utf-8")
+    with pytest.raises(ConfigurationError):
+        RateLimiter.from_config(PaddiConfig.load(path))
+
+
+def test_retries_are_rate_limited():
+    """Test that every attempt made by the retry policy takes a token."""
+    clock = FakeClock()
+    limiter = RateLimiter({"scc": 1}, clock=clock, sleep=clock.sleep)
+    policy = RetryPolicy(delay=0, limiter=limiter, sleep=clock.sleep)
+    attempts = []
+
+    def flaky():
+        attempts.append(clock())
+        if len(attempts) < 3:
+            raise ConnectionResetError()
+        return "ok"
+
+    assert policy.call("scc.list_findings", flaky) == "ok"
+    assert attempts == [0, pytest.approx(1), pytest.approx(2)]
@@ -0,0 +1,145 @@
+"""Tests for gcloud remediation script generation."""
+
+import json
+import os
+from datetime import datetime
+
+from app.cli.base import CommandContext
+from app.cli.commands import RemediateCommand
+from app.remediation.gcloud_script import SCRIPT_NAME, build_script, write_script
+from app.remediation.templates import render, resource_params
+
+FINDINGS = [
+    {
+        "title": "Public bucket",
+        "severity": "HIGH",
+        "category": "PUBLIC_BUCKET",
+        "resource": "//storage.googleapis.com/prod-assets",
+    },
+    {
+        "title": "Service account with Editor role",
+        "severity": "HIGH",
+        "category": "OVERPRIVILEGED_SERVICE_ACCOUNT",
+        "resource": "projects/prod/serviceAccounts/app@prod.iam.gserviceaccount.com",
+    },
+    {
+        "title": "Public S3 bucket",
+        "severity": "HIGH",
+        "category": "PUBLIC_BUCKET",
+        "resource": "arn:aws:s3:::example-public-bucket",
+    },
+]
+
+
+class TestTemplates:
+    """Tests for template parameters and rendering."""
+
+    def test_resource_params(self):
+        """Test that parameters are extracted from GCP resource names."""
+        params = resource_params(FINDINGS[1]["resource"])
+
+        assert params["project"] == "prod"
+        assert params["service_account"] == "app@prod.iam.gserviceaccount.com"
+        assert resource_params(FINDINGS[0]["resource"])["bucket"] == "prod-assets"
+
+    def test_render_quotes_values_and_reports_missing(self):
+        """Test shell quoting and placeholders for missing values."""
+        command, missing = render(
+            "gcloud projects remove-iam-policy-binding {project} --role={role}",
+            {"project": "my project"},
+        )
+
+        assert command == "gcloud projects remove-iam-policy-binding 'my project' --role=<ROLE>"
+        assert missing == ["role"]
+
+
+class TestBuildScript:
+    """Tests for the generated script."""
+
+    def test_per_finding_blocks(self):
+        """Test comments and commands for each GCP finding."""
+        script = build_script(FINDINGS, generated_at=datetime(2025, 1, 1))
+
+        assert script.startswith("#!/usr/bin/env bash\n")
+        assert "# Generated: 2025-01-01T00:00:00" in script
+        assert "set -euo pipefail" in script
+        assert "# [1] HIGH Public bucket" in script
+        assert "# Resource: //storage.googleapis.com/prod-assets" in script
+        assert (
+            "gcloud storage buckets update gs://prod-assets --public-access-prevention"
+            in script.splitlines()
+        )
+
+    def test_unresolved_commands_are_commented_out(self):
+        """Test that commands with placeholders never run."""
+        script = build_script(FINDINGS)
+
+        assert "# TODO: replace <ROLE>" in script
+        assert (
+            "# gcloud projects remove-iam-policy-binding prod "
+            "--member=serviceAccount:app@prod.iam.gserviceaccount.com --role=<ROLE>"
+        ) in script
+        assert not any("<" in line for line in script.splitlines() if not line.startswith("#"))
+
+    def test_manual_steps_stay_comments(self):
+        """Test that findings no command can fix get a manual step, not a command."""
+        finding = {
+            "title": "Database not encrypted at rest",
+            "severity": "HIGH",
+            "category": "UNENCRYPTED_DATABASE",
+            "resource": "projects/prod/instances/billing",
+        }
+
+        lines = build_script([finding]).splitlines()
+
+        assert any(line.startswith("# Manual step:") for line in lines)
+        assert not any(line.startswith("gcloud") for line in lines)
+
+    def test_skips_non_gcp_resources(self):
+        """Test that findings outside GCP are counted but get no commands."""
+        script = build_script(FINDINGS)
+
+        assert "example-public-bucket" not in script
+        assert "# 1 finding(s) have no gcloud remediation template" in script
+
+    def test_comment_lines_stay_single_line(self):
+        """Test that multi-line titles cannot break out of a comment."""
+        finding = dict(FINDINGS[0], title="Public\nrm -rf /")
+
+        assert "# [1] HIGH Public rm -rf /" in build_script([finding])
+
+    def test_write_script(self, tmp_path):
+        """Test that the script is written and executable."""
+        path = write_script(FINDINGS, tmp_path / "output")
+
+        assert path == tmp_path / "output" / SCRIPT_NAME
+        assert os.access(path, os.X_OK)
+
+
+class TestRemediateCommand:
+    """Tests for the remediate command."""
+
+    def test_dry_run_prints_without_writing(self, tmp_path, capsys):
+        """Test that --dry-run previews the script."""
+        input_file = tmp_path / "explained.json"
+        input_file.write_text(json.dumps(FINDINGS))
+        output_dir = tmp_path / "output"
+
+        RemediateCommand().execute(
+            CommandContext(input_file=str(input_file), output_dir=str(output_dir), dry_run=True)
+        )
+
+        assert "gs://prod-assets" in capsys.readouterr().out
+        assert not (output_dir / SCRIPT_NAME).exists()
+
+    def test_writes_script(self, tmp_path):
+        """Test that the command writes output/remediation.sh."""
+        input_file = tmp_path / "explained.json"
+        input_file.write_text(json.dumps(FINDINGS))
+        output_dir = tmp_path / "output"
+
+        RemediateCommand().execute(
+            CommandContext(input_file=str(input_file), output_dir=str(output_dir))
+        )
+
+        assert "gs://prod-assets" in (output_dir / SCRIPT_NAME).read_text(encoding="utf-8")
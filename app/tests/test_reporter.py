@@ -14,6 +14,8 @@ from reporter.agent_reporter import (
     main,
 )
 
+from app.common.exceptions import ConfigurationError
+
 
 @pytest.fixture
 def sample_finding():
@@ -103,7 +105,7 @@ class TestMarkdownGenerator:
         assert "- **CRITICAL**: 1 findings" in content
         assert "## Detailed Findings" in content
         assert "### 1. Overly Permissive IAM Role" in content
-        assert "**Severity:** HIGH" in content
+        assert "**Severity:** 🟠 `HIGH`" in content
 
     def test_generate_markdown_with_template(self, sample_report, tmp_path):
         """Test generating Markdown report with custom template."""
@@ -342,6 +344,22 @@ class TestReportService:
         md_content = (output_dir / "audit.md").read_text()
         assert md_content == "# Custom test-proj"
 
+    def test_custom_templates_override_per_format(self, tmp_path):
+        """Test that a custom template replaces only the built-in one of its format."""
+        custom_dir = tmp_path / "custom"
+        custom_dir.mkdir()
+        (custom_dir / "report.html.j2").write_text("<h1>{{ report.project_name }}</h1>")
+
+        service = ReportService(
+            output_dir=tmp_path / "output",
+            template_dir=Path("app/templates"),
+            custom_template_dir=custom_dir,
+        )
+
+        assert service.template("html") == custom_dir / "report.html.j2"
+        assert service.template("markdown") == Path("app/templates/report.md.j2")
+        assert service.template("honkit") is None
+
 
 class TestMainFunction:
     """Test the main entry point."""
@@ -358,9 +376,25 @@ class TestMainFunction:
             input_dir=Path("data"),
             output_dir=Path("output"),
             template_dir=Path("app/templates"),
+            custom_template_dir=None,
+            extra_inputs=[],
+            continue_on_error=False,
         )
         mock_instance.generate_reports.assert_called_once()
 
+    @patch("reporter.agent_reporter.PaddiConfig.load")
+    @patch("reporter.agent_reporter.ReportService")
+    def test_main_with_configured_template_dir(self, mock_service_class, mock_load, tmp_path):
+        """Test that [reporter] template_dir is used, and must be a directory."""
+        mock_load.return_value.section.return_value = {"template_dir": str(tmp_path)}
+
+        main()
+        assert mock_service_class.call_args.kwargs["custom_template_dir"] == tmp_path
+
+        mock_load.return_value.section.return_value = {"template_dir": str(tmp_path / "none")}
+        with pytest.raises(ConfigurationError):
+            main()
+
     @patch("reporter.agent_reporter.ReportService")
     def test_main_with_custom_paths(self, mock_service_class):
         """Test main function with custom paths."""
@@ -373,5 +407,19 @@ class TestMainFunction:
             input_dir=Path("custom/input"),
             output_dir=Path("custom/output"),
             template_dir=Path("custom/templates"),
+            custom_template_dir=None,
+            extra_inputs=[],
+            continue_on_error=False,
         )
         mock_instance.generate_reports.assert_called_once()
+
+    @patch("reporter.agent_reporter.ReportService")
+    def test_main_with_comma_separated_inputs(self, mock_service_class):
+        """Test that extra inputs may be given as a comma-separated string."""
+        main(inputs="runs/a/explained.json, runs/b/explained.json")
+
+        _, kwargs = mock_service_class.call_args
+        assert kwargs["extra_inputs"] == [
+            Path("runs/a/explained.json"),
+            Path("runs/b/explained.json"),
+        ]
@@ -0,0 +1,59 @@
+"""Tests for local-only (data residency) mode."""
+
+from unittest.mock import patch
+
+import pytest
+
+from app.common.exceptions import ConfigurationError
+from app.config.paddi_config import PaddiConfig
+from app.explainer.agent_explainer import get_analyzer
+from app.explainer.residency import enforce_local_only, is_local_endpoint
+
+LOCAL_ONLY = PaddiConfig({"llm": {"local_only": True, "local_hosts": ["ollama.internal"]}})
+
+
+@pytest.mark.parametrize(
+    "url,expected",
+    [
+        ("http://localhost:11434", True),
+        ("http://127.0.0.1:11434", True),
+        ("http://[::1]:11434", True),
+        ("http://10.0.0.5:11434", True),
+        ("http://ollama.internal:11434", True),
+        ("https://ollama.example.com", False),
+        ("http://8.8.8.8:11434", False),
+    ],
+)
+def test_is_local_endpoint(url, expected):
+    """Test which endpoints count as local."""
+    assert is_local_endpoint(url, ["ollama.internal"]) is expected
+
+
+def test_hosted_provider_refused():
+    """Test that a hosted provider fails closed in local-only mode."""
+    with pytest.raises(ConfigurationError) as exc:
+        enforce_local_only("gemini", config=LOCAL_ONLY)
+    assert exc.value.config_item == "llm.local_only"
+    assert "gemini" in exc.value.details["error"]
+
+
+def test_hosted_endpoint_refused():
+    """Test that a local provider pointed at a hosted endpoint fails closed."""
+    with pytest.raises(ConfigurationError):
+        enforce_local_only("ollama", "https://ollama.example.com", config=LOCAL_ONLY)
+    enforce_local_only("ollama", "http://localhost:11434", config=LOCAL_ONLY)
+
+
+def test_disabled_by_default():
+    """Test that hosted providers are allowed without local_only."""
+    enforce_local_only("gemini", config=PaddiConfig({}))
+
+
+def test_get_analyzer_enforces():
+    """Test that no analyzer is created for a hosted backend in local-only mode."""
+    with patch("app.explainer.residency.PaddiConfig.load", return_value=LOCAL_ONLY), patch(
+        "app.explainer.agent_explainer.GeminiSecurityAnalyzer"
+    ) as gemini:
+        with pytest.raises(ConfigurationError):
+            get_analyzer({"ai_provider": "gemini", "project_id": "p"})
+    gemini.assert_not_called()
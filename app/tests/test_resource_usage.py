@@ -0,0 +1,86 @@
+"""Tests for per-agent resource usage."""
+
+import sqlite3
+from unittest.mock import patch
+
+from app.cli.base import CommandContext
+from app.cli.commands import AuditCommand
+from app.common.resource_usage import AgentUsage, ResourceUsageLog, format_usage
+from app.history.store import HISTORY_DB_NAME, RunStore
+
+
+def test_measure_records_each_agent():
+    """Test that every measured block is recorded, a failing one as well."""
+    usage = ResourceUsageLog()
+
+    with usage.measure("collector"):
+        sum(range(100000))
+    try:
+        with usage.measure("explainer"):
+            raise RuntimeError("boom")
+    except RuntimeError:
+        pass
+
+    assert [agent.agent for agent in usage.agents] == ["collector", "explainer"]
+    collector = usage.agents[0]
+    assert collector.wall_seconds >= 0 and collector.cpu_seconds >= 0
+    assert collector.peak_rss is None or collector.peak_rss > 0
+    assert AgentUsage.from_dict(usage.to_list()[0]).agent == "collector"
+
+
+def test_format_usage():
+    """Test the verbose table, with a peak that was not measured."""
+    table = format_usage(
+        [
+            AgentUsage("collector", 1.5, 0.25, 64 * 1024 * 1024),
+            AgentUsage("reporter", 0.5, 0.5, None),
+        ]
+    )
+
+    lines = table.splitlines()
+    assert "Peak RSS MiB" in lines[0]
+    assert lines[1].split() == ["collector", "1.500", "0.250", "64.0"]
+    assert lines[2].split()[-1] == "-"
+
+
+def test_store_adds_column_to_old_databases(tmp_path):
+    """Test that a history database created before resource usage is upgraded."""
+    path = tmp_path / HISTORY_DB_NAME
+    conn = sqlite3.connect(path)
+    conn.execute(
+        "CREATE TABLE runs (id INTEGER PRIMARY KEY AUTOINCREMENT, project_id TEXT, "
+        "created_at TEXT NOT NULL, content_hash TEXT NOT NULL, total INTEGER NOT NULL, "
+        "severity_counts TEXT NOT NULL)"
+    )
+    conn.execute(
+        "INSERT INTO runs (project_id, created_at, content_hash, total, severity_counts) "
+        "VALUES ('p', '2024-01-01T00:00:00+00:00', 'h', 0, '{}')"
+    )
+    conn.commit()
+    conn.close()
+
+    store = RunStore(path)
+    assert store.latest_run().resource_usage == []
+
+    usage = [{"agent": "collector", "wall_seconds": 1.0, "cpu_seconds": 0.5, "peak_rss": None}]
+    store.record_resource_usage(1, usage)
+    assert store.latest_run().resource_usage == usage
+
+
+@patch("app.cli.commands.CollectCommand")
+@patch("app.cli.commands.ExplainCommand")
+@patch("app.cli.commands.ReportCommand")
+def test_audit_records_and_prints_usage(
+    mock_report, _explain, _collect, tmp_path, monkeypatch, capsys
+):
+    """Test that audit records the usage with the run and prints it when verbose."""
+    monkeypatch.chdir(tmp_path)
+    mock_report.return_value.generate.return_value = []
+    store = RunStore(tmp_path / "data" / HISTORY_DB_NAME)
+    run = store.record_run([{"title": "t", "severity": "HIGH"}], "p")
+
+    AuditCommand().execute(CommandContext(use_mock=True, verbose=True))
+
+    recorded = store.run(run.run_id).resource_usage
+    assert [agent["agent"] for agent in recorded] == ["collector", "explainer", "reporter"]
+    assert "Resource usage:" in capsys.readouterr().out
@@ -0,0 +1,112 @@
+"""Tests for the retention policy for runs and archives."""
+
+import os
+import sqlite3
+from datetime import datetime, timedelta, timezone
+
+import pytest
+
+from app.cli.base import CommandContext
+from app.cli.commands import CleanCommand
+from app.common.exceptions import ConfigurationError
+from app.config.paddi_config import PaddiConfig
+from app.history.retention import RetentionPolicy, apply_retention
+from app.history.store import HISTORY_DB_NAME, RunStore
+from app.storage.archive import checksum_path
+
+NOW = datetime(2025, 6, 1, tzinfo=timezone.utc)
+
+
+@pytest.fixture
+def history(tmp_path):
+    """A history store with four runs, 30 days apart (run 4 is the newest)."""
+    store = RunStore(tmp_path / HISTORY_DB_NAME)
+    for i in range(4):
+        store.record_run([{"title": f"finding {i}", "severity": "HIGH"}], "p")
+    with sqlite3.connect(store.path) as conn:
+        for run_id, days in [(1, 90), (2, 60), (3, 30), (4, 0)]:
+            created = (NOW - timedelta(days=days)).isoformat(timespec="seconds")
+            conn.execute("UPDATE runs SET created_at = ? WHERE id = ?", (created, run_id))
+    return store
+
+
+def _archive(directory, name, days_old, size=100):
+    """Write a fake archive and its checksum file."""
+    directory.mkdir(exist_ok=True)
+    path = directory / name
+    path.write_bytes(b"\0" * size)
+    checksum_path(path).write_text("0  x\n", encoding="utf-8")
+    mtime = (NOW - timedelta(days=days_old)).timestamp()
+    os.utime(path, (mtime, mtime))
+    return path
+
+
+def test_max_runs(history, tmp_path):
+    """Test that only the newest runs and archives are kept."""
+    archives = tmp_path / "archives"
+    old = _archive(archives, "old.tar.zst", 20)
+    new = _archive(archives, "new.tar.zst", 1)
+
+    result = apply_retention(RetentionPolicy(max_runs=1), tmp_path, archives, now=NOW)
+
+    assert result.deleted_runs == [1, 2, 3]
+    assert [run.run_id for run in history.runs()] == [4]
+    assert history.findings_for(1) == []
+    assert result.deleted_archives == [old]
+    assert not old.exists() and not checksum_path(old).exists()
+    assert new.exists()
+
+
+def test_max_age_keeps_newest(history, tmp_path):
+    """Test that old runs are deleted, but never the newest one."""
+    result = apply_retention(RetentionPolicy(max_age_days=45), tmp_path, tmp_path / "a", now=NOW)
+    assert result.deleted_runs == [1, 2]
+
+    later = NOW + timedelta(days=400)
+    result = apply_retention(RetentionPolicy(max_age_days=45), tmp_path, tmp_path / "a", now=later)
+    assert result.deleted_runs == [3]
+    assert [run.run_id for run in history.runs()] == [4]
+
+
+def test_max_disk_bytes_archives_first(history, tmp_path):
+    """Test that the disk budget deletes the oldest archives before any run."""
+    archives = tmp_path / "archives"
+    oldest = _archive(archives, "a.tar.zst", 3, size=10_000)
+    _archive(archives, "b.tar.zst", 2, size=10_000)
+    db_size = history.path.stat().st_size
+
+    result = apply_retention(
+        RetentionPolicy(max_disk_bytes=db_size + 10_010), tmp_path, archives, now=NOW, dry_run=True
+    )
+
+    assert result.deleted_archives == [oldest]
+    assert result.deleted_runs == []
+    assert oldest.exists()
+
+
+def test_from_config():
+    """Test reading and validating [retention]."""
+    policy = RetentionPolicy.from_config(PaddiConfig({"retention": {"max_runs": 10}}))
+    assert policy == RetentionPolicy(max_runs=10)
+    assert not RetentionPolicy.from_config(PaddiConfig({})).enabled
+    with pytest.raises(ConfigurationError):
+        RetentionPolicy.from_config(PaddiConfig({"retention": {"max_age_days": 0}}))
+
+
+def test_clean_command(history, tmp_path, monkeypatch, capsys):
+    """Test paddi clean --apply-retention."""
+    (tmp_path / "data").mkdir()
+    os.replace(history.path, tmp_path / "data" / HISTORY_DB_NAME)
+    monkeypatch.chdir(tmp_path)
+    monkeypatch.setattr(
+        PaddiConfig,
+        "load",
+        classmethod(lambda cls, *a, **k: PaddiConfig({"retention": {"max_runs": 2}})),
+    )
+
+    with pytest.raises(ValueError):
+        CleanCommand().execute(CommandContext())
+    CleanCommand().execute(CommandContext(apply_retention=True, output_format="table"))
+
+    assert "Deleted 2 run(s) and 0 archive(s)" in capsys.readouterr().out
+    assert [run.run_id for run in RunStore(tmp_path / "data" / HISTORY_DB_NAME).runs()] == [4, 3]
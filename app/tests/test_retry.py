@@ -0,0 +1,111 @@
+"""Tests for retrying transient cloud API errors."""
+
+from types import SimpleNamespace
+from unittest.mock import MagicMock
+
+import pytest
+import requests
+from google.api_core import exceptions as gcp_exceptions
+
+from app.collector.agent_collector import GCPConfigurationCollector
+from app.common.retry import RetryPolicy, RetryStats, is_retryable
+from app.config.paddi_config import PaddiConfig
+from app.export.scc import SCCExporter
+
+
+def flaky(errors, result="ok"):
+    """Return a function that raises the given errors in turn, then returns ``result``."""
+    errors = list(errors)
+
+    def call(*args, **kwargs):
+        if errors:
+            raise errors.pop(0)
+        return result
+
+    return call
+
+
+def make_policy(retries=3):
+    """Create a policy that records its waits instead of sleeping."""
+    waits = []
+    return RetryPolicy(retries=retries, delay=1, max_delay=4, sleep=waits.append), waits
+
+
+def test_is_retryable():
+    """Test that 429, 5xx, and dropped connections are transient, and nothing else."""
+    response = SimpleNamespace(status_code=503)
+
+    assert is_retryable(gcp_exceptions.TooManyRequests("quota"))
+    assert is_retryable(gcp_exceptions.ServiceUnavailable("down"))
+    assert is_retryable(ConnectionResetError())
+    assert is_retryable(requests.ConnectionError())
+    assert is_retryable(requests.HTTPError(response=response))
+    assert not is_retryable(gcp_exceptions.PermissionDenied("denied"))
+    assert not is_retryable(ValueError("bad filter"))
+
+
+def test_backoff_is_capped_with_jitter():
+    """Test that the delay doubles up to max_delay and jitter keeps at least half of it."""
+    low = RetryPolicy(delay=1, max_delay=4, jitter=lambda: 0.0)
+    high = RetryPolicy(delay=1, max_delay=4, jitter=lambda: 0.999)
+
+    assert [low.backoff(n) for n in range(4)] == [0.5, 1, 2, 2]
+    assert all(high.backoff(n) < cap for n, cap in enumerate([1, 2, 4, 4]))
+
+
+def test_retries_transient_errors():
+    """Test that transient errors are retried and counted per operation."""
+    policy, waits = make_policy()
+    func = flaky([gcp_exceptions.TooManyRequests("quota"), ConnectionResetError()])
+
+    assert policy.call("iam.get_iam_policy", func) == "ok"
+    assert len(waits) == 2
+    assert policy.stats == RetryStats(calls=1, retries=2, operations={"iam.get_iam_policy": 2})
+
+
+def test_permanent_errors_and_exhaustion():
+    """Test that other errors are raised at once, and transient ones after the last retry."""
+    policy, waits = make_policy(retries=1)
+
+    with pytest.raises(gcp_exceptions.PermissionDenied):
+        policy.call("scc.list_findings", flaky([gcp_exceptions.PermissionDenied("denied")]))
+    assert not waits
+
+    with pytest.raises(gcp_exceptions.InternalServerError):
+        policy.call("scc.list_findings", flaky([gcp_exceptions.InternalServerError("x")] * 2))
+    assert policy.stats.exhausted == 1
+    assert policy.stats.retries == 1
+
+
+def test_from_config(tmp_path):
+    """Test reading the retry settings from [execution]."""
+    path = tmp_path / "paddi.toml"
+    path.write_text("[execution]\nretry_count = 5\nretry_max_delay = 60\n", encoding="utf-8")
+
+    policy = RetryPolicy.from_config(PaddiConfig.load(path))
+
+    assert (policy.retries, policy.delay, policy.max_delay) == (5, 1.0, 60.0)
+
+
+def test_scc_export_reports_retries():
+    """Test that the SCC exporter retries writes and reports how many were retried."""
+    policy, _ = make_policy()
+    client = MagicMock()
+    client.list_findings.side_effect = flaky([gcp_exceptions.ServiceUnavailable("down")], [])
+    client.create_finding.side_effect = flaky([ConnectionResetError()], None)
+    exporter = SCCExporter("organizations/123/sources/456", client=client, retry=policy)
+
+    result = exporter.sync([{"title": "Public bucket", "severity": "HIGH"}])
+
+    assert result.created == ["Public bucket"]
+    assert result.retries == 2
+    assert client.create_finding.call_count == 2
+
+
+def test_collector_writes_retry_metadata(tmp_path):
+    """Test that collected.json metadata includes the retry stats."""
+    collector = GCPConfigurationCollector("prod", use_mock=True, output_dir=str(tmp_path))
+
+    data = collector.collect_all()
+
+    assert data["metadata"]["retries"] == RetryStats().to_dict()
@@ -0,0 +1,94 @@
+"""Tests for aggregated risk scoring."""
+
+import pytest
+
+from app.common.exceptions import ConfigurationError
+from app.config.paddi_config import PaddiConfig
+from app.findings.risk import RiskModel
+from app.reporter.agent_reporter import MarkdownGenerator, ReportService
+
+FINDINGS = [
+    {"title": "Owner role", "severity": "HIGH", "resource": "projects/prod", "cvss_score": 8.8},
+    {"title": "Editor SA", "severity": "MEDIUM", "resource": "projects/prod"},
+    {
+        "title": "Public bucket",
+        "severity": "CRITICAL",
+        "resource": "//storage.googleapis.com/assets",
+        "occurrences": 2,
+    },
+    {"title": "Legacy", "severity": "LOW"},
+]
+
+
+class TestRiskModel:
+    """Tests for RiskModel."""
+
+    def test_finding_score(self):
+        """Test severity weight, CVSS factor, and occurrence count."""
+        model = RiskModel()
+
+        assert model.finding_score(FINDINGS[0]) == pytest.approx(4.4)
+        assert model.finding_score(FINDINGS[1]) == 2.0
+        assert model.finding_score(FINDINGS[2]) == 20.0
+
+    def test_cvss_factor_can_be_disabled(self):
+        """Test that use_cvss=false ignores CVSS scores."""
+        assert RiskModel(use_cvss=False).finding_score(FINDINGS[0]) == 5.0
+
+    def test_summarize(self):
+        """Test per-resource roll-up and project grade."""
+        summary = RiskModel().summarize(FINDINGS)
+
+        assert summary.score == 26.9
+        assert summary.grade == "C"
+        assert [(r.resource, r.score, r.findings) for r in summary.resources] == [
+            ("//storage.googleapis.com/assets", 20.0, 1),
+            ("projects/prod", 6.4, 2),
+        ]
+        assert summary.to_dict()["grade"] == "C"
+
+    def test_grades(self):
+        """Test grade boundaries, including F above the D threshold."""
+        model = RiskModel()
+
+        assert model.grade(0) == "A"
+        assert model.grade(5) == "A"
+        assert model.grade(5.1) == "B"
+        assert model.grade(60) == "D"
+        assert model.grade(61) == "F"
+
+    def test_from_config(self):
+        """Test that weights and thresholds are read from paddi.toml."""
+        model = RiskModel.from_config(
+            PaddiConfig(
+                {
+                    "risk": {
+                        "use_cvss": False,
+                        "severity_weights": {"high": 1},
+                        "grade_thresholds": {"A": 1, "B": 2, "C": 3, "D": 4},
+                    }
+                }
+            )
+        )
+
+        assert model.finding_score(FINDINGS[0]) == 1.0
+        assert model.grade(3.5) == "D"
+
+    def test_invalid_thresholds(self):
+        """Test that thresholds must increase from A to D."""
+        with pytest.raises(ConfigurationError):
+            RiskModel.from_config(PaddiConfig({"risk": {"grade_thresholds": {"A": 100}}}))
+
+
+class TestRiskInReport:
+    """Tests for the risk section of generated reports."""
+
+    def test_executive_summary_shows_grade(self, tmp_path):
+        """Test that the Markdown report includes the grade and resource table."""
+        service = ReportService(input_dir=tmp_path, output_dir=tmp_path / "output")
+        report = service.create_report(FINDINGS, {"project_id": "demo"})
+
+        content = MarkdownGenerator().generate(report)
+
+        assert "**Overall Grade:** C (score 26.9)" in content
+        assert "| `//storage.googleapis.com/assets` | 1 | 20.0 |" in content
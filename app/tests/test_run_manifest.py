@@ -0,0 +1,109 @@
+"""Tests for run manifests and the runs command."""
+
+import json
+from unittest.mock import patch
+
+import pytest
+
+from app.cli.base import CommandContext
+from app.cli.commands import AuditCommand, RunsCommand
+from app.common.exceptions import CollectionError
+from app.history.manifest import (
+    FAILED,
+    SKIPPED,
+    SUCCEEDED,
+    RunManifest,
+    load_manifest,
+    load_manifests,
+)
+
+
+def test_stage_records_status_duration_and_artifacts(tmp_path):
+    """Test that a stage records the files it wrote, and a failing stage its error."""
+    untouched = tmp_path / "old.json"
+    untouched.write_text("{}")
+    manifest = RunManifest.start("audit", ("collect", "explain"), {"project_id": "p"})
+    output = tmp_path / "collected.json"
+
+    with manifest.stage("collect", [output, tmp_path / "missing.json"]):
+        output.write_text('{"a": 1}')
+    with pytest.raises(RuntimeError):
+        with manifest.stage("explain"):
+            raise RuntimeError("boom")
+    manifest.finish(RuntimeError("boom"), 1)
+
+    data = manifest.to_dict()
+    assert [(s["name"], s["status"]) for s in data["stages"]] == [
+        ("collect", SUCCEEDED),
+        ("explain", FAILED),
+    ]
+    assert data["stages"][1]["error"] == "boom"
+    assert [a["path"] for a in data["artifacts"]] == [output.as_posix()]
+    assert data["artifacts"][0]["size"] == 8
+    assert data["status"] == FAILED and data["exit_code"] == 1
+
+
+def test_load_manifests_newest_first_and_by_prefix(tmp_path):
+    """Test listing runs and finding one by a unique prefix of its ID."""
+    for run_id in ("20240101T000000Z-aaaaaa", "20240102T000000Z-bbbbbb"):
+        manifest = RunManifest.start("audit", ())
+        manifest.run_id = run_id
+        manifest.finish()
+        manifest.save(tmp_path)
+    (tmp_path / "broken").mkdir()
+    (tmp_path / "broken" / "run.json").write_text("{")
+
+    assert [m["run_id"] for m in load_manifests(tmp_path)] == [
+        "20240102T000000Z-bbbbbb",
+        "20240101T000000Z-aaaaaa",
+    ]
+    assert len(load_manifests(tmp_path, limit=1)) == 1
+    assert load_manifest("20240101", tmp_path)["run_id"] == "20240101T000000Z-aaaaaa"
+    with pytest.raises(FileNotFoundError):
+        load_manifest("2024", tmp_path)
+
+
+@patch("app.cli.commands.CollectCommand")
+@patch("app.cli.commands.ExplainCommand")
+@patch("app.cli.commands.ReportCommand")
+def test_audit_writes_manifest(mock_report, _explain, _collect, tmp_path, monkeypatch):
+    """Test that a successful audit writes a manifest with its inputs and stages."""
+    monkeypatch.chdir(tmp_path)
+    mock_report.return_value.generate.return_value = []
+
+    AuditCommand().execute(CommandContext(project_id="prod", use_mock=True))
+
+    [manifest] = load_manifests()
+    assert manifest["status"] == SUCCEEDED and manifest["exit_code"] == 0
+    assert manifest["inputs"]["project_id"] == "prod"
+    assert [s["status"] for s in manifest["stages"]] == [SUCCEEDED] * 3
+
+
+@patch("app.cli.commands.CollectCommand")
+@patch("app.cli.commands.ExplainCommand")
+@patch("app.cli.commands.ReportCommand")
+def test_failed_audit_still_writes_manifest(
+    _report, mock_explain, _collect, tmp_path, monkeypatch
+):
+    """Test that a failing stage is recorded and the later stages are skipped."""
+    monkeypatch.chdir(tmp_path)
+    mock_explain.return_value.execute.side_effect = CollectionError("no data")
+
+    with pytest.raises(CollectionError):
+        AuditCommand().execute(CommandContext(use_mock=True))
+
+    [manifest] = load_manifests()
+    assert manifest["status"] == FAILED and manifest["exit_code"] != 0
+    assert [s["status"] for s in manifest["stages"]] == [SUCCEEDED, FAILED, SKIPPED]
+
+
+def test_runs_show_structured(tmp_path, monkeypatch, capsys):
+    """Test that 'runs show' prints the latest manifest as JSON."""
+    monkeypatch.chdir(tmp_path)
+    manifest = RunManifest.start("audit", ("collect",))
+    manifest.finish()
+    manifest.save()
+
+    RunsCommand().execute(CommandContext(action="show", output_format="json"))
+
+    assert json.loads(capsys.readouterr().out)["run_id"] == manifest.run_id
@@ -0,0 +1,143 @@
+"""Tests for writing findings back to Security Command Center."""
+
+import json
+from types import SimpleNamespace
+from unittest.mock import MagicMock, patch
+
+import pytest
+from google.api_core import exceptions as gcp_exceptions
+
+from app.cli.base import CommandContext
+from app.cli.commands import ExportCommand
+from app.common.exceptions import ConfigurationError
+from app.config.paddi_config import PaddiConfig
+from app.export.scc import SCCExporter, finding_id, resource_name, scc_finding
+from app.findings.fingerprint import compute_fingerprint
+
+SOURCE = "organizations/123/sources/456"
+
+FINDINGS = [
+    {
+        "title": "Public bucket",
+        "severity": "HIGH",
+        "category": "PUBLIC_BUCKET",
+        "resource": "//storage.googleapis.com/assets",
+        "explanation": "Anyone can read objects.",
+    },
+    {"title": "Legacy setting", "severity": "INFO", "category": "LEGACY"},
+]
+
+
+def listed(*findings):
+    """Create list_findings results for active findings."""
+    return [
+        SimpleNamespace(
+            finding=SimpleNamespace(
+                name=f"{SOURCE}/findings/{finding_id(compute_fingerprint(f))}"
+            )
+        )
+        for f in findings
+    ]
+
+
+class TestMapping:
+    """Tests for mapping Paddi findings to SCC findings."""
+
+    def test_finding_id(self):
+        """Test that IDs are alphanumeric and within the SCC limit."""
+        scc_id = finding_id(compute_fingerprint(FINDINGS[0]))
+
+        assert scc_id.isalnum()
+        assert len(scc_id) <= 32
+
+    def test_resource_name(self):
+        """Test full resource names for SCC."""
+        assert resource_name(FINDINGS[0], "prod") == "//storage.googleapis.com/assets"
+        assert (
+            resource_name({"resource": "projects/prod"}, None)
+            == "//cloudresourcemanager.googleapis.com/projects/prod"
+        )
+        assert resource_name({}, "prod") == "//cloudresourcemanager.googleapis.com/projects/prod"
+
+    def test_severity_mapping(self):
+        """Test that INFO maps to LOW, which is the least severe SCC level."""
+        body = scc_finding(FINDINGS[1], "abc", "prod", None)
+
+        assert (body["state"], body["severity"], body["category"]) == ("ACTIVE", "LOW", "LEGACY")
+        assert body["source_properties"]["paddi_fingerprint"] == "abc"
+
+
+class TestSCCExporter:
+    """Tests for SCCExporter.sync."""
+
+    def test_creates_and_updates(self):
+        """Test that new findings are created and known ones updated."""
+        client = MagicMock()
+        client.list_findings.return_value = listed(FINDINGS[0])
+
+        result = SCCExporter(SOURCE, client).sync(FINDINGS, project_id="prod")
+
+        assert (result.created, result.updated) == (["Legacy setting"], ["Public bucket"])
+        request = client.create_finding.call_args.kwargs["request"]
+        assert request["parent"] == SOURCE
+        assert request["finding_id"] == finding_id(compute_fingerprint(FINDINGS[1]))
+        updated = client.update_finding.call_args.kwargs["request"]["finding"]
+        assert updated["name"].startswith(f"{SOURCE}/findings/paddi")
+
+    def test_deactivates_resolved_findings(self):
+        """Test that findings no longer reported are set to INACTIVE."""
+        client = MagicMock()
+        client.list_findings.return_value = listed(FINDINGS[0])
+
+        result = SCCExporter(SOURCE, client).sync(FINDINGS[1:])
+
+        assert len(result.deactivated) == 1
+        request = client.set_finding_state.call_args.kwargs["request"]
+        assert request["state"] == "INACTIVE"
+        assert request["name"].endswith(result.deactivated[0])
+
+    def test_reactivates_inactive_finding(self):
+        """Test that a finding that comes back reuses its inactive SCC finding."""
+        client = MagicMock()
+        client.list_findings.return_value = []
+        client.create_finding.side_effect = gcp_exceptions.AlreadyExists("exists")
+
+        SCCExporter(SOURCE, client).sync(FINDINGS[:1])
+
+        finding = client.update_finding.call_args.kwargs["request"]["finding"]
+        assert finding["state"] == "ACTIVE"
+        assert finding["name"].endswith(finding_id(compute_fingerprint(FINDINGS[0])))
+
+    def test_dry_run(self):
+        """Test that dry runs do not write to SCC."""
+        client = MagicMock()
+        client.list_findings.return_value = listed({"title": "Gone", "category": "X"})
+
+        result = SCCExporter(SOURCE, client).sync(FINDINGS, dry_run=True)
+
+        assert (len(result.created), len(result.deactivated)) == (2, 1)
+        client.create_finding.assert_not_called()
+        client.set_finding_state.assert_not_called()
+
+
+class TestExportCommand:
+    """Tests for export --to scc."""
+
+    def test_requires_source(self):
+        """Test that the source must be configured."""
+        with pytest.raises(ConfigurationError):
+            SCCExporter.from_config(PaddiConfig({"export": {"scc": {"source": "456"}}}))
+
+    def test_summary(self, tmp_path, capsys):
+        """Test that the command prints a summary."""
+        input_file = tmp_path / "explained.json"
+        input_file.write_text(json.dumps(FINDINGS))
+        client = MagicMock()
+        client.list_findings.return_value = []
+
+        with patch(
+            "app.cli.commands.SCCExporter.from_config", return_value=SCCExporter(SOURCE, client)
+        ):
+            ExportCommand().execute(CommandContext(export_target="scc", input_file=str(input_file)))
+
+        assert "2 created, 0 updated, 0 deactivated" in capsys.readouterr().out
@@ -0,0 +1,67 @@
+"""Tests for resolving secretmanager: references in paddi.toml."""
+
+from unittest.mock import MagicMock, patch
+
+import pytest
+
+from app.common.exceptions import ConfigurationError
+from app.config.paddi_config import PaddiConfig
+from app.config.secret_manager import SecretManagerResolver, version_name
+
+
+def secret_client(value="s3cret"):
+    """Build a fake Secret Manager client returning ``value``."""
+    client = MagicMock()
+    client.access_secret_version.return_value.payload.data = value.encode("utf-8")
+    return client
+
+
+class TestVersionName:
+    """Tests for parsing references."""
+
+    def test_latest_by_default(self):
+        """Test that references without a version read the latest one."""
+        assert version_name("secretmanager:projects/p/secrets/dd-key") == (
+            "projects/p/secrets/dd-key/versions/latest"
+        )
+        assert version_name("secretmanager:projects/p/secrets/dd-key/versions/3") == (
+            "projects/p/secrets/dd-key/versions/3"
+        )
+
+    def test_invalid_reference(self):
+        """Test that malformed references are rejected."""
+        with pytest.raises(ConfigurationError):
+            version_name("secretmanager:dd-key")
+
+
+class TestResolver:
+    """Tests for reading secrets."""
+
+    def test_reads_once(self):
+        """Test that a secret is fetched once per process."""
+        client = secret_client()
+        resolver = SecretManagerResolver(client)
+
+        assert resolver.resolve("secretmanager:projects/p/secrets/k") == "s3cret"
+        assert resolver.resolve("secretmanager:projects/p/secrets/k") == "s3cret"
+        client.access_secret_version.assert_called_once_with(
+            request={"name": "projects/p/secrets/k/versions/latest"}
+        )
+
+    def test_access_error(self):
+        """Test that API errors become configuration errors."""
+        client = MagicMock()
+        client.access_secret_version.side_effect = RuntimeError("403 Permission denied")
+        with pytest.raises(ConfigurationError) as exc:
+            SecretManagerResolver(client).resolve("secretmanager:projects/p/secrets/k")
+        assert "Permission denied" in exc.value.details["error"]
+
+    def test_resolved_through_config(self):
+        """Test that PaddiConfig resolves references on access only."""
+        config = PaddiConfig(
+            {"export": {"splunk_hec": {"token": "secretmanager:projects/p/secrets/hec"}}}
+        )
+        resolver = SecretManagerResolver(secret_client("hec-token"))
+        with patch("app.config.paddi_config.SecretManagerResolver", return_value=resolver):
+            assert config.section("export.splunk_hec") == {"token": "hec-token"}
+        assert config.data["export"]["splunk_hec"]["token"].startswith("secretmanager:")
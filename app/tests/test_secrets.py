@@ -0,0 +1,97 @@
+"""Tests for OS keyring secrets and the config set-secret command."""
+
+import io
+import sys
+import types
+from unittest.mock import patch
+
+import pytest
+
+from app.cli.base import CommandContext
+from app.cli.commands import ConfigCommand
+from app.common.exceptions import ConfigurationError
+from app.config import secrets
+
+
+class FakeKeyring(types.ModuleType):
+    """In-memory stand-in for the keyring package."""
+
+    def __init__(self):
+        super().__init__("keyring")
+        self.store = {}
+        self.errors = types.SimpleNamespace(PasswordDeleteError=KeyError)
+
+    def set_password(self, service, name, value):
+        self.store[(service, name)] = value
+
+    def get_password(self, service, name):
+        return self.store.get((service, name))
+
+    def delete_password(self, service, name):
+        del self.store[(service, name)]
+
+
+@pytest.fixture
+def keyring(monkeypatch):
+    """Install a fake keyring module."""
+    fake = FakeKeyring()
+    monkeypatch.setitem(sys.modules, "keyring", fake)
+    return fake
+
+
+class TestSecrets:
+    """Tests for storing and resolving secrets."""
+
+    def test_round_trip(self, keyring):
+        """Test storing, reading, and deleting a secret."""
+        secrets.set_secret("gemini_api_key", "s3cret")
+        assert keyring.store == {("paddi", "gemini_api_key"): "s3cret"}
+        assert secrets.get_secret("gemini_api_key") == "s3cret"
+
+        secrets.delete_secret("gemini_api_key")
+        assert secrets.get_secret("gemini_api_key") is None
+        with pytest.raises(ConfigurationError):
+            secrets.delete_secret("gemini_api_key")
+
+    def test_env_var_wins(self, keyring, monkeypatch):
+        """Test that the environment variable is checked before the keyring."""
+        secrets.set_secret("dd_api_key", "from-keyring")
+        monkeypatch.delenv("DD_API_KEY", raising=False)
+        assert secrets.resolve_secret("DD_API_KEY") == "from-keyring"
+
+        monkeypatch.setenv("DD_API_KEY", "from-env")
+        assert secrets.resolve_secret("DD_API_KEY") == "from-env"
+
+    def test_invalid_name(self, keyring):
+        """Test that names must be simple identifiers."""
+        with pytest.raises(ConfigurationError):
+            secrets.set_secret("Bad Name", "value")
+
+    def test_keyring_not_installed(self, monkeypatch):
+        """Test that lookups fall back to None without the keyring package."""
+        monkeypatch.setitem(sys.modules, "keyring", None)
+        monkeypatch.delenv("GITHUB_TOKEN", raising=False)
+        assert secrets.resolve_secret("GITHUB_TOKEN") is None
+        with pytest.raises(ConfigurationError):
+            secrets.set_secret("github_token", "value")
+
+
+class TestSetSecretCommand:
+    """Tests for ``paddi config set-secret``."""
+
+    def test_reads_value_from_stdin(self, keyring, monkeypatch, capsys):
+        """Test storing a piped value."""
+        monkeypatch.setattr(sys, "stdin", io.StringIO("token-value\n"))
+        ConfigCommand().execute(CommandContext(action="set-secret", target="github_token"))
+
+        assert keyring.store[("paddi", "github_token")] == "token-value"
+        assert "Stored github_token in the OS keyring" in capsys.readouterr().out
+
+    def test_prompts_without_echo_on_terminal(self, keyring, monkeypatch):
+        """Test that a terminal prompt does not echo the secret."""
+        monkeypatch.setattr(sys, "stdin", types.SimpleNamespace(isatty=lambda: True))
+        with patch("app.cli.commands.getpass.getpass", return_value="typed") as mock_getpass:
+            ConfigCommand().execute(CommandContext(action="set-secret", target="gemini_api_key"))
+
+        mock_getpass.assert_called_once()
+        assert keyring.store[("paddi", "gemini_api_key")] == "typed"
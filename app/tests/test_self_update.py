@@ -0,0 +1,120 @@
+"""Tests for paddi self-update."""
+
+import base64
+import hashlib
+import json
+from unittest.mock import MagicMock, patch
+
+import pytest
+
+from app.cli.base import CommandContext
+from app.cli.commands import SelfUpdateCommand
+from app.common.exceptions import SelfUpdateError
+from app.common.self_update import Release, SelfUpdater, verify_signature, version_key
+
+WHEEL = b"wheel contents"
+WHEEL_NAME = "paddi-1.3.0-py3-none-any.whl"
+
+
+def _release(tag, prerelease=False, checksum=None):
+    checksum = checksum or hashlib.sha256(WHEEL).hexdigest()
+    return {
+        "tag_name": tag,
+        "prerelease": prerelease,
+        "html_url": f"https://github.com/susumutomita/Paddi/releases/tag/{tag}",
+        "assets": [
+            {"name": WHEEL_NAME, "browser_download_url": "https://example.com/wheel"},
+            {"name": "SHA256SUMS", "browser_download_url": "https://example.com/sums"},
+        ],
+        "_sums": f"{checksum}  {WHEEL_NAME}\n".encode(),
+    }
+
+
+def _get(releases):
+    """Serve the release list and the assets of the first release."""
+
+    def get(url, **kwargs):
+        response = MagicMock()
+        response.json.return_value = releases
+        response.content = {"https://example.com/wheel": WHEEL}.get(
+            url, releases[0]["_sums"] if releases else b""
+        )
+        return response
+
+    return get
+
+
+def test_version_ordering():
+    """Test that pre-releases sort before their final release."""
+    assert version_key("v1.2.0") < version_key("1.3.0-beta.1")
+    assert version_key("1.3.0-beta.1") < version_key("1.3.0-beta.2") < version_key("1.3.0rc1")
+    assert version_key("1.3.0rc1") < version_key("v1.3")
+    assert version_key("nightly") is None
+
+
+@patch("app.common.self_update.requests.get")
+def test_channels(mock_get):
+    """Test that the stable channel ignores pre-releases and the beta channel does not."""
+    mock_get.side_effect = _get([_release("v1.3.0-beta.1", prerelease=True), _release("v1.2.0")])
+
+    assert SelfUpdater("stable").latest().tag == "v1.2.0"
+    assert SelfUpdater("beta").latest().tag == "v1.3.0-beta.1"
+    assert SelfUpdater("stable").check("1.2.0") is None
+    with pytest.raises(SelfUpdateError):
+        SelfUpdater("nightly")
+
+
+@patch("app.common.self_update.requests.get")
+def test_download_verifies_checksum(mock_get, tmp_path):
+    """Test that a wheel is kept only when its checksum matches SHA256SUMS."""
+    mock_get.side_effect = _get([_release("v1.3.0")])
+    updater = SelfUpdater()
+
+    path = updater.download(updater.latest(), tmp_path)
+    assert path.read_bytes() == WHEEL
+
+    mock_get.side_effect = _get([_release("v1.3.0", checksum="0" * 64)])
+    with pytest.raises(SelfUpdateError, match="checksum"):
+        updater.download(updater.latest(), tmp_path)
+    assert not (tmp_path / WHEEL_NAME).exists()
+
+
+def test_signature():
+    """Test Ed25519 signatures of SHA256SUMS, raw and base64-encoded."""
+    ed25519 = pytest.importorskip("cryptography.hazmat.primitives.asymmetric.ed25519")
+    from cryptography.hazmat.primitives import serialization
+
+    private_key = ed25519.Ed25519PrivateKey.generate()
+    public_key = base64.b64encode(
+        private_key.public_key().public_bytes(
+            serialization.Encoding.Raw, serialization.PublicFormat.Raw
+        )
+    ).decode()
+    signature = private_key.sign(b"sums")
+
+    verify_signature(b"sums", signature, public_key)
+    verify_signature(b"sums", base64.b64encode(signature), public_key)
+    with pytest.raises(SelfUpdateError, match="invalid"):
+        verify_signature(b"tampered", signature, public_key)
+
+
+def test_install_refuses_source_checkout():
+    """Test that a source checkout is left to git."""
+    with patch("app.common.self_update.running_from_checkout", return_value=True):
+        with pytest.raises(SelfUpdateError, match="git pull"):
+            SelfUpdater().install(Release("v1.3.0", False))
+
+
+@patch("app.cli.commands.cli_version", return_value="1.2.0")
+@patch("app.common.self_update.requests.get")
+def test_check_reports_available_release(mock_get, _version, capsys):
+    """Test that --check reports the newer release without installing it."""
+    mock_get.side_effect = _get([_release("v1.3.0")])
+
+    with patch.object(SelfUpdater, "install") as install:
+        SelfUpdateCommand().execute(CommandContext(dry_run=True, output_format="json"))
+
+    result = json.loads(capsys.readouterr().out)
+    assert result["update_available"] is True
+    assert result["release"]["version"] == "1.3.0"
+    install.assert_not_called()
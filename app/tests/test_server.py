@@ -0,0 +1,98 @@
+"""Tests for the browser UI and API of paddi serve."""
+
+import json
+import threading
+from urllib.error import HTTPError
+from urllib.request import urlopen
+
+import pytest
+
+from app.history.store import HISTORY_DB_NAME, RunStore
+from app.server.browser import ReportBrowser
+from app.server.http_server import ServerSettings, create_server
+
+FINDINGS = [
+    {"title": "Public bucket", "severity": "HIGH", "category": "STORAGE", "resource": "gs://a"},
+    {"title": "Old key", "severity": "LOW", "category": "KEY", "resource": "sa-1"},
+    {"title": "No MFA", "severity": "CRITICAL", "category": "MFA", "resource": "org"},
+]
+
+
+@pytest.fixture
+def browser(tmp_path):
+    store = RunStore(tmp_path / "data" / HISTORY_DB_NAME)
+    store.record_run(FINDINGS[:1], "p")
+    store.record_run(FINDINGS, "p")
+    output = tmp_path / "output"
+    output.mkdir()
+    (output / "audit.md").write_text("# Report")
+    (tmp_path / "secret.md").write_text("secret")
+    return ReportBrowser(tmp_path / "data", output)
+
+
+@pytest.fixture
+def server(browser):
+    server = create_server(ServerSettings(port=0), browser)
+    thread = threading.Thread(target=server.serve_forever, daemon=True)
+    thread.start()
+    yield f"http://127.0.0.1:{server.server_address[1]}"
+    server.shutdown()
+    server.server_close()
+
+
+def _get(url):
+    with urlopen(url) as response:
+        return response.status, response.headers["Content-Type"], response.read().decode()
+
+
+def test_findings_filters(browser):
+    """Test the latest run by default, filters, and query expressions."""
+    result = browser.findings()
+    assert result["run"]["run_id"] == 2
+    assert [f["title"] for f in result["findings"]] == ["No MFA", "Public bucket", "Old key"]
+
+    assert [f["title"] for f in browser.findings(severity="LOW")["findings"]] == ["Old key"]
+    assert browser.findings(run_id=1)["total"] == 1
+    assert [f["title"] for f in browser.findings(query='resource contains "gs"')["findings"]] == [
+        "Public bucket"
+    ]
+    assert browser.findings(run_id=9) is None
+
+
+def test_report_files_stay_in_output_dir(browser):
+    """Test that only report files in the output directory are served."""
+    assert [r["path"] for r in browser.reports()] == ["audit.md"]
+    assert browser.report_file("audit.md") is not None
+    assert browser.report_file("../secret.md") is None
+
+
+def test_server_routes(server):
+    """Test the UI, its assets, the API, and the reports over HTTP."""
+    status, content_type, body = _get(server + "/")
+    assert status == 200 and content_type.startswith("text/html")
+    assert '<script src="/static/app.js">' in body
+    assert _get(server + "/static/app.js")[0] == 200
+
+    runs = json.loads(_get(server + "/api/runs")[2])
+    assert [run["run_id"] for run in runs] == [2, 1]
+    findings = json.loads(_get(server + "/api/findings?severity=CRITICAL")[2])
+    assert [f["title"] for f in findings["findings"]] == ["No MFA"]
+    assert _get(server + "/reports/audit.md")[2] == "# Report"
+    assert "No MFA" in _get(server + "/runs/2/report")[2]
+
+
+@pytest.mark.parametrize(
+    "path, status",
+    [
+        ("/api/findings?query=severity%3E%3D", 400),
+        ("/api/findings?run=9", 404),
+        ("/reports/..%2F..%2Fsecret.md", 404),
+        ("/static/..%2Fhttp_server.py", 404),
+        ("/nowhere", 404),
+    ],
+)
+def test_server_errors(server, path, status):
+    """Test invalid queries, unknown runs, and paths outside the served directories."""
+    with pytest.raises(HTTPError) as exc_info:
+        urlopen(server + path)
+    assert exc_info.value.code == status
@@ -0,0 +1,198 @@
+"""Tests for the roles of paddi serve users and OIDC ID tokens."""
+
+import base64
+import http.client
+import json
+import threading
+import time
+from urllib.error import HTTPError
+from urllib.request import Request, urlopen
+
+import pytest
+from cryptography.hazmat.primitives import hashes
+from cryptography.hazmat.primitives.asymmetric import padding, rsa
+
+from app.common.exceptions import ConfigurationError
+from app.config.paddi_config import PaddiConfig
+from app.server.access import AccessPolicy, Authenticator, OidcVerifier, role_allows
+from app.server.browser import ReportBrowser
+from app.server.events import AuditJobs
+from app.server.http_server import ServerSettings, create_server
+from app.server.tokens import TOKENS_DB_NAME, TokenStore
+
+ISSUER = "https://idp.example.com"
+AUDIENCE = "paddi"
+
+
+def _b64(data: bytes) -> str:
+    return base64.urlsafe_b64encode(data).rstrip(b"=").decode()
+
+
+@pytest.fixture(scope="module")
+def key():
+    return rsa.generate_private_key(public_exponent=65537, key_size=2048)
+
+
+def _id_token(key, **claims):
+    claims = {"iss": ISSUER, "aud": AUDIENCE, "exp": time.time() + 300, **claims}
+    header = _b64(json.dumps({"alg": "RS256", "kid": "k1"}).encode())
+    payload = _b64(json.dumps(claims).encode())
+    signature = key.sign(f"{header}.{payload}".encode(), padding.PKCS1v15(), hashes.SHA256())
+    return f"{header}.{payload}.{_b64(signature)}"
+
+
+@pytest.fixture
+def verifier(key, monkeypatch):
+    numbers = key.public_key().public_numbers()
+    jwks = {
+        "keys": [
+            {
+                "kty": "RSA",
+                "kid": "k1",
+                "n": _b64(numbers.n.to_bytes(256, "big")),
+                "e": _b64(numbers.e.to_bytes(3, "big")),
+            }
+        ]
+    }
+    verifier = OidcVerifier(ISSUER, AUDIENCE, jwks_uri=f"{ISSUER}/jwks")
+    monkeypatch.setattr(verifier, "_get_json", lambda url: jwks)
+    return verifier
+
+
+def test_roles_from_config():
+    """Test role order and the roles of users, groups, and the default role."""
+    assert role_allows("admin", "operator") and not role_allows("viewer", "operator")
+    assert not role_allows(None, "viewer")
+
+    policy = AccessPolicy.from_config(
+        PaddiConfig(
+            {
+                "server": {
+                    "users": {"alice@example.com": "admin"},
+                    "oidc": {
+                        "issuer": ISSUER,
+                        "audience": AUDIENCE,
+                        "groups_claim": "groups",
+                        "group_roles": {"secops": "operator"},
+                    },
+                }
+            }
+        )
+    )
+    assert policy.oidc_principal({"email": "alice@example.com"}).role == "admin"
+    assert policy.oidc_principal({"email": "bob@example.com", "groups": ["secops"]}).role == (
+        "operator"
+    )
+    assert policy.oidc_principal({"email": "eve@example.com"}) is None
+
+    with pytest.raises(ConfigurationError):
+        AccessPolicy.from_config(PaddiConfig({"server": {"users": {"a": "root"}}}))
+    with pytest.raises(ConfigurationError):
+        AccessPolicy.from_config(PaddiConfig({"server": {"oidc": {"issuer": ISSUER}}}))
+
+
+def test_user_tokens_follow_config(tmp_path):
+    """Test that a user's token has no more than its scope, and stops with the user."""
+    store = TokenStore(tmp_path / TOKENS_DB_NAME)
+    _, secret = store.create("laptop", "audit", user="alice")
+    _, plain = store.create("ci", "read")
+
+    admin = Authenticator(store, AccessPolicy(users={"alice": "admin"}))
+    principal = admin.authenticate(secret)
+    assert (principal.name, principal.role) == ("alice", "operator")
+    assert admin.authenticate(plain).role == "viewer"
+    assert Authenticator(store, AccessPolicy(users={"bob": "admin"})).authenticate(secret) is None
+
+
+def test_oidc_tokens_are_verified(key, verifier):
+    """Test signature, issuer, audience, and expiry checks of ID tokens."""
+    assert verifier.verify(_id_token(key, email="alice"))["email"] == "alice"
+    for bad in (
+        _id_token(key, iss="https://other.example.com"),
+        _id_token(key, aud="someone-else"),
+        _id_token(key, exp=time.time() - 3600),
+        _id_token(rsa.generate_private_key(public_exponent=65537, key_size=2048)),
+        "not-a-token",
+    ):
+        with pytest.raises(ValueError):
+            verifier.verify(bad)
+
+
+def test_malformed_provider_documents_reject_tokens(key, monkeypatch):
+    """Test that discovery without jwks_uri and keys without n or e reject tokens."""
+    verifier = OidcVerifier(ISSUER, AUDIENCE)
+    monkeypatch.setattr(verifier, "_get_json", lambda url: {"issuer": ISSUER})
+    with pytest.raises(ValueError, match="no jwks_uri"):
+        verifier.verify(_id_token(key))
+
+    verifier = OidcVerifier(ISSUER, AUDIENCE, jwks_uri=f"{ISSUER}/jwks")
+    monkeypatch.setattr(verifier, "_get_json", lambda url: {"keys": [{"kty": "RSA", "kid": "k1"}]})
+    with pytest.raises(ValueError, match="malformed signing key"):
+        verifier.verify(_id_token(key))
+    assert Authenticator(policy=AccessPolicy(oidc=verifier)).authenticate(_id_token(key)) is None
+
+
+@pytest.fixture
+def server(tmp_path, verifier):
+    tokens = TokenStore(tmp_path / TOKENS_DB_NAME)
+    policy = AccessPolicy(
+        users={"viewer@example.com": "viewer", "admin@example.com": "admin"}, oidc=verifier
+    )
+    browser = ReportBrowser(tmp_path / "data", tmp_path / "output")
+    jobs = AuditJobs(lambda options, on_stage: None)
+    server = create_server(ServerSettings(port=0), browser, jobs, tokens, policy)
+    thread = threading.Thread(target=server.serve_forever, daemon=True)
+    thread.start()
+    yield f"http://127.0.0.1:{server.server_address[1]}", tokens
+    server.shutdown()
+    server.server_close()
+
+
+def _request(url, token, method="GET", data=None):
+    request = Request(url, data=data, method=method, headers={"Authorization": f"Bearer {token}"})
+    try:
+        with urlopen(request) as response:
+            return response.status, json.loads(response.read())
+    except HTTPError as e:
+        return e.code, None
+
+
+def test_roles_enforced_per_endpoint(server, key):
+    """Test that each endpoint needs its role, for OIDC users and API tokens alike."""
+    url, tokens = server
+    viewer = _id_token(key, email="viewer@example.com")
+    admin = _id_token(key, email="admin@example.com")
+    token, operator = tokens.create("ci", "audit")
+
+    assert _request(url + "/api/me", viewer) == (
+        200,
+        {"name": "viewer@example.com", "role": "viewer", "source": "oidc"},
+    )
+    assert _request(url + "/api/runs", _id_token(key, email="eve@example.com"))[0] == 401
+
+    body = json.dumps({"use_mock": True}).encode()
+    assert _request(url + "/api/audits", viewer, "POST", body)[0] == 403
+    status, job = _request(url + "/api/audits", operator, "POST", body)
+    assert status == 202 and job["started_by"] == "token:ci"
+
+    assert _request(url + "/api/tokens", operator)[0] == 403
+    status, listed = _request(url + "/api/tokens", admin)
+    assert status == 200 and [t["token_id"] for t in listed] == [token.token_id]
+    assert _request(f"{url}/api/tokens/{token.token_id}", viewer, "DELETE")[0] == 403
+    assert _request(f"{url}/api/tokens/{token.token_id}", admin, "DELETE")[0] == 200
+    assert _request(url + "/api/runs", operator)[0] == 401
+
+
+@pytest.mark.parametrize("length", ["abc", "-1"])
+def test_invalid_content_length(server, length):
+    """Test that a Content-Length that is not a size is a bad request."""
+    url, tokens = server
+    _, operator = tokens.create("ci", "audit")
+    connection = http.client.HTTPConnection(url.removeprefix("http://"))
+    connection.putrequest("POST", "/api/audits")
+    connection.putheader("Authorization", f"Bearer {operator}")
+    connection.putheader("Content-Length", length)
+    connection.endheaders()
+
+    assert connection.getresponse().status == 400
+    connection.close()
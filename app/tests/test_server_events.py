@@ -0,0 +1,153 @@
+"""Tests for audits started from paddi serve and their event stream."""
+
+import json
+import logging
+import threading
+from urllib.error import HTTPError
+from urllib.request import Request, urlopen
+
+import pytest
+
+from app.common.exceptions import AuditQueueFullError, CollectionError
+from app.server.browser import ReportBrowser
+from app.server.events import (
+    FAILED,
+    QUEUE_STATE_NAME,
+    QUEUED,
+    RUNNING,
+    SUCCEEDED,
+    AuditJobs,
+)
+from app.server.http_server import ServerSettings, create_server
+
+
+def _fake_audit(options, on_stage):
+    on_stage("collect", 2.0)
+    logging.getLogger("app.collector").info("Collecting %s", options["project_id"])
+    on_stage("report", None)
+
+
+def _wait(job):
+    while not job.finished:
+        job.events_after(len(job.events), 1.0)
+
+
+def test_job_records_stages_logs_and_status():
+    """Test the events of a successful audit, and resuming after an event ID."""
+    job = AuditJobs(_fake_audit).start({"project_id": "p", "unknown": "x"})
+    _wait(job)
+
+    assert job.status == SUCCEEDED
+    assert job.options == {"project_id": "p"}
+    assert [e.type for e in job.events] == ["status", "status", "stage", "log", "stage", "status"]
+    assert [e.data["status"] for e in job.events if e.type == "status"] == [
+        "queued",
+        "running",
+        "succeeded",
+    ]
+    assert job.events[2].data == {"stage": "collect", "expected_seconds": 2.0}
+    assert job.events[3].data["message"] == "Collecting p"
+    assert [e.id for e in job.events_after(4)] == [5, 6]
+    assert job.events[-1].to_sse().startswith('id: 6\nevent: status\ndata: {"status": "succeeded"')
+
+
+def test_failed_audit_reports_error():
+    """Test that a failing audit reports its error and exit code."""
+
+    def runner(options, on_stage):
+        raise CollectionError("gcp", {"error": "denied"})
+
+    job = AuditJobs(runner).start({})
+    _wait(job)
+    assert job.status == FAILED
+    assert job.events[-1].data["exit_code"] != 0
+
+
+def test_queue_priority_dedup_and_restore(tmp_path):
+    """Test priorities, one audit per project, collapsed requests, and restoring the queue."""
+    release = threading.Event()
+    ran = []
+
+    def runner(options, on_stage):
+        release.wait(5)
+        ran.append(options["project_id"])
+
+    state = tmp_path / QUEUE_STATE_NAME
+    jobs = AuditJobs(runner, max_concurrency=2, max_queued=2, state_path=state)
+    first = jobs.start({"project_id": "a"})
+    again = jobs.start({"project_id": "a"})
+    urgent = jobs.start({"project_id": "b", "use_mock": True}, priority=5)
+    # a is running, so its second audit waits; b runs beside it
+    assert (first.status, again.status, urgent.status) == (RUNNING, QUEUED, RUNNING)
+
+    low = jobs.start({"project_id": "c"})
+    assert jobs.start({"project_id": "c"}, priority=9) is low
+    assert (low.requests, low.priority) == (2, 9)
+    with pytest.raises(AuditQueueFullError):
+        jobs.start({"project_id": "d"})
+    assert [job.id for job in jobs.jobs()] == [first.id, urgent.id, low.id, again.id]
+
+    saved = json.loads(state.read_text())
+    assert sorted(job["options"]["project_id"] for job in saved) == ["a", "a", "b", "c"]
+    restored = AuditJobs(lambda options, on_stage: None, state_path=tmp_path / "copy.json")
+    assert restored.jobs() == []
+    (tmp_path / "copy.json").write_text(json.dumps(saved))
+    restored = AuditJobs(lambda options, on_stage: None, state_path=tmp_path / "copy.json")
+    for job in restored.jobs():
+        _wait(job)
+    assert all(job.status == SUCCEEDED for job in restored.jobs())
+    assert json.loads((tmp_path / "copy.json").read_text()) == []
+
+    release.set()
+    for job in (first, again, urgent, low):
+        _wait(job)
+    assert sorted(ran) == ["a", "a", "b", "c"]
+    assert json.loads(state.read_text()) == []
+
+
+def test_invalid_options_rejected():
+    """Test that options of the wrong type are rejected."""
+    with pytest.raises(ValueError):
+        AuditJobs(_fake_audit).start({"use_mock": "yes"})
+
+
+@pytest.fixture
+def server(tmp_path):
+    browser = ReportBrowser(tmp_path / "data", tmp_path / "output")
+    server = create_server(ServerSettings(port=0), browser, AuditJobs(_fake_audit))
+    thread = threading.Thread(target=server.serve_forever, daemon=True)
+    thread.start()
+    yield f"http://127.0.0.1:{server.server_address[1]}"
+    server.shutdown()
+    server.server_close()
+
+
+def _post(url, data):
+    request = Request(url, data=data, headers={"Content-Type": "application/json"})
+    with urlopen(request) as response:
+        return response.status, json.loads(response.read())
+
+
+def test_event_stream_over_http(server):
+    """Test starting an audit and following its progress as server-sent events."""
+    status, job = _post(server + "/api/audits", json.dumps({"project_id": "p"}).encode())
+    assert status == 202 and job["options"] == {"project_id": "p"}
+
+    with urlopen(f"{server}/api/audits/{job['id']}/events") as response:
+        assert response.headers["Content-Type"].startswith("text/event-stream")
+        stream = response.read().decode()
+    assert "event: stage" in stream and "Collecting p" in stream
+    assert stream.rstrip().endswith('"exit_code": 0}')
+
+    request = Request(f"{server}/api/audits/{job['id']}/events", headers={"Last-Event-ID": "5"})
+    with urlopen(request) as response:
+        assert response.read().decode().startswith("id: 6\n")
+    with urlopen(server + "/api/audits") as response:
+        assert [j["status"] for j in json.loads(response.read())] == ["succeeded"]
+
+    with pytest.raises(HTTPError) as error:
+        _post(server + "/api/audits", b"[1]")
+    assert error.value.code == 400
+    with pytest.raises(HTTPError) as error:
+        urlopen(server + "/api/audits/unknown")
+    assert error.value.code == 404
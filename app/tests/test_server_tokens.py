@@ -0,0 +1,99 @@
+"""Tests for the API tokens of paddi serve."""
+
+import json
+import sqlite3
+import threading
+from urllib.error import HTTPError
+from urllib.request import Request, urlopen
+
+import pytest
+
+from app.cli.base import CommandContext
+from app.cli.commands import ServeCommand
+from app.server.browser import ReportBrowser
+from app.server.events import AuditJobs
+from app.server.http_server import ServerSettings, create_server
+from app.server.tokens import TOKENS_DB_NAME, TokenStore
+
+
+@pytest.fixture
+def store(tmp_path):
+    return TokenStore(tmp_path / TOKENS_DB_NAME)
+
+
+def test_tokens_are_hashed_and_revocable(store):
+    """Test creating, checking, and revoking tokens, and that secrets are not stored."""
+    assert not store.required()
+    token, secret = store.create("ci", "audit")
+    assert secret.startswith("paddi_")
+    with sqlite3.connect(store.path) as conn:
+        assert secret not in str(conn.execute("SELECT * FROM api_tokens").fetchall())
+
+    assert store.authenticate(secret).token_id == token.token_id
+    assert store.authenticate("paddi_wrong") is None
+    assert store.tokens()[0].last_used_at is not None
+
+    assert store.revoke(token.token_id).revoked_at is not None
+    assert store.revoke(token.token_id) is None
+    assert store.authenticate(secret) is None
+    # A revoked token keeps the server protected
+    assert store.required()
+
+    with pytest.raises(ValueError):
+        store.create("bad", "root")
+
+
+@pytest.fixture
+def server(tmp_path, store):
+    browser = ReportBrowser(tmp_path / "data", tmp_path / "output")
+    jobs = AuditJobs(lambda options, on_stage: None)
+    server = create_server(ServerSettings(port=0), browser, jobs, store)
+    thread = threading.Thread(target=server.serve_forever, daemon=True)
+    thread.start()
+    yield f"http://127.0.0.1:{server.server_address[1]}"
+    server.shutdown()
+    server.server_close()
+
+
+def _status(url, token=None, data=None):
+    headers = {"Authorization": f"Bearer {token}"} if token else {}
+    try:
+        with urlopen(Request(url, data=data, headers=headers)) as response:
+            return response.status
+    except HTTPError as e:
+        return e.code
+
+
+def test_api_requires_token_with_scope(server, store):
+    """Test that the API needs a token once one exists, and audits need the audit scope."""
+    assert _status(server + "/api/runs") == 200
+
+    _, reader = store.create("dashboard")
+    _, auditor = store.create("ci", "audit")
+    assert _status(server + "/") == 200
+    assert _status(server + "/api/runs") == 401
+    assert _status(server + "/api/runs", "paddi_wrong") == 401
+    assert _status(server + "/api/runs", reader) == 200
+    assert _status(f"{server}/api/runs?access_token={reader}") == 200
+
+    body = json.dumps({"use_mock": True}).encode()
+    assert _status(server + "/api/audits", reader, body) == 403
+    assert _status(server + "/api/audits", auditor, body) == 202
+
+
+def test_token_commands(tmp_path, monkeypatch, capsys):
+    """Test serve token create, list, and revoke."""
+    monkeypatch.chdir(tmp_path)
+    command = ServeCommand()
+    command.execute(CommandContext(action="token", token_action="create", target="ci"))
+    secret = capsys.readouterr().out.strip().splitlines()[-1]
+    store = TokenStore(tmp_path / "data" / TOKENS_DB_NAME)
+    token = store.authenticate(secret)
+    assert token.name == "ci" and token.scope == "read"
+
+    command.execute(CommandContext(action="token", token_action="list"))
+    assert token.token_id in capsys.readouterr().out
+    command.execute(CommandContext(action="token", token_action="revoke", target=token.token_id))
+    assert store.authenticate(secret) is None
+    with pytest.raises(ValueError):
+        command.execute(CommandContext(action="token", token_action="revoke", target="nope"))
@@ -0,0 +1,78 @@
+"""Tests for config-driven severity overrides."""
+
+import pytest
+
+from app.common.exceptions import ConfigurationError
+from app.config.paddi_config import PaddiConfig
+from app.findings.severity_overrides import SeverityOverrides
+
+
+# Overrides for make_finding, for a finding on a dev project's SSL policy
+WEAK_SSL_POLICY = {
+    "title": "Weak SSL policy",
+    "severity": "HIGH",
+    "category": "WEAK_SSL_POLICY",
+    "resource": "//compute.googleapis.com/projects/web-dev/global/sslPolicies/default",
+}
+
+
+def _overrides(section):
+    """Build overrides from a severity_overrides section."""
+    return SeverityOverrides.from_config(PaddiConfig({"severity_overrides": section}))
+
+
+class TestSeverityOverrides:
+    """Tests for SeverityOverrides."""
+
+    def test_category_mapping(self, make_finding):
+        """Test that a plain entry remaps a category."""
+        result = _overrides({"weak_ssl_policy": "low"}).apply([make_finding(**WEAK_SSL_POLICY)])
+
+        assert result[0]["severity"] == "LOW"
+        assert result[0]["original_severity"] == "HIGH"
+
+    def test_resource_rule_takes_precedence(self, make_finding):
+        """Test that rules are checked before plain category entries."""
+        overrides = _overrides(
+            {
+                "WEAK_SSL_POLICY": "LOW",
+                "rules": [
+                    {
+                        "category": "WEAK_SSL_POLICY",
+                        "resource": "compute.googleapis.com/projects/*-dev/*",
+                        "severity": "INFO",
+                    }
+                ],
+            }
+        )
+        dev = make_finding(**WEAK_SSL_POLICY)
+        prod = dict(dev, resource="//compute.googleapis.com/projects/web-prod/global/sslPolicies/x")
+
+        dev_result, prod_result = overrides.apply([dev, prod])
+
+        assert dev_result["severity"] == "INFO"
+        assert prod_result["severity"] == "LOW"
+
+    def test_unmatched_findings_unchanged(self, make_finding):
+        """Test that findings without a matching rule are returned as-is."""
+        finding = make_finding()
+
+        result = _overrides({"WEAK_SSL_POLICY": "LOW"}).apply([finding])
+
+        assert result[0] is finding
+        assert "original_severity" not in result[0]
+
+    def test_empty_config(self, make_finding):
+        """Test that no configuration means no changes."""
+        finding = make_finding(**WEAK_SSL_POLICY)
+        assert SeverityOverrides.from_config(PaddiConfig()).apply([finding]) == [finding]
+
+    def test_invalid_severity_rejected(self):
+        """Test that unknown severities raise a configuration error."""
+        with pytest.raises(ConfigurationError):
+            _overrides({"WEAK_SSL_POLICY": "SEVERE"})
+
+    def test_rule_without_criteria_rejected(self):
+        """Test that a rule must match on category or resource."""
+        with pytest.raises(ConfigurationError):
+            _overrides({"rules": [{"severity": "LOW"}]})
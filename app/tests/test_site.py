@@ -0,0 +1,59 @@
+"""Tests for the static dashboard site."""
+
+from app.cli.base import CommandContext
+from app.cli.commands import SiteCommand
+from app.history.store import HISTORY_DB_NAME, RunStore
+from app.reporter.branding import Branding
+from app.reporter.site import build_site
+
+FINDINGS = [
+    {"title": "Public bucket", "severity": "HIGH", "explanation": "e", "recommendation": "r"},
+    {"title": "Old key", "severity": "LOW", "explanation": "e", "recommendation": "r"},
+]
+
+
+def _store(path):
+    store = RunStore(path)
+    store.record_run(FINDINGS, "prod")
+    store.record_run(FINDINGS[:1], "prod")
+    return store
+
+
+def test_build_site(tmp_path):
+    """Test an index of runs, a trends page, and a report per run, linked relatively."""
+    store = _store(tmp_path / HISTORY_DB_NAME)
+    site = tmp_path / "site"
+
+    written = build_site(store, site, branding=Branding(company_name="Example Corp"))
+
+    assert sorted(p.relative_to(site).as_posix() for p in written) == [
+        "index.html",
+        "runs/1.html",
+        "runs/2.html",
+        "trends.html",
+    ]
+    index = (site / "index.html").read_text()
+    assert index.index('href="runs/2.html"') < index.index('href="runs/1.html"')
+    assert "<td>+0 / -1</td>" in index
+    assert "Example Corp" in index
+    assert "<svg" in (site / "trends.html").read_text()
+    assert "Old key" not in (site / "runs" / "2.html").read_text()
+
+
+def test_build_site_limits_runs(tmp_path):
+    """Test that --runs keeps only the most recent runs."""
+    store = _store(tmp_path / HISTORY_DB_NAME)
+
+    build_site(store, tmp_path / "site", limit=1, branding=Branding())
+
+    assert [p.name for p in (tmp_path / "site" / "runs").iterdir()] == ["2.html"]
+
+
+def test_site_command(tmp_path, monkeypatch):
+    """Test that site build renders data/history.db into the output directory."""
+    monkeypatch.chdir(tmp_path)
+    _store(tmp_path / "data" / HISTORY_DB_NAME)
+
+    SiteCommand().execute(CommandContext(action="build", output_dir="public"))
+
+    assert (tmp_path / "public" / "index.html").exists()
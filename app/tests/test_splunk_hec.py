@@ -0,0 +1,128 @@
+"""Tests for the Splunk HEC exporter."""
+
+import json
+from unittest.mock import Mock, patch
+
+import pytest
+import requests
+
+from app.cli.base import CommandContext
+from app.cli.commands import ExportCommand
+from app.common.exceptions import ConfigurationError
+from app.config.paddi_config import PaddiConfig
+from app.export.splunk_hec import SplunkHECExporter, hec_event
+
+FINDINGS = [
+    {"title": f"Finding {i}", "severity": "HIGH", "category": "PUBLIC_BUCKET", "resource": f"b{i}"}
+    for i in range(5)
+]
+
+
+def response(status_code):
+    """Create a fake HTTP response."""
+    return Mock(status_code=status_code, text="")
+
+
+class TestSplunkHECExporter:
+    """Tests for SplunkHECExporter.send."""
+
+    def test_event_envelope(self):
+        """Test the HEC event fields."""
+        event = hec_event(FINDINGS[0], 1.5, index="security", host="prod")
+
+        assert event["time"] == 1.5
+        assert (event["index"], event["sourcetype"], event["host"]) == (
+            "security",
+            "paddi:finding",
+            "prod",
+        )
+        assert event["event"]["title"] == "Finding 0"
+        assert event["event"]["fingerprint"]
+
+    def test_batches(self):
+        """Test that events are sent in batches to the event endpoint."""
+        exporter = SplunkHECExporter("https://splunk:8088/", "tok", index="sec", batch_size=2)
+
+        with patch("app.export.splunk_hec.requests.post", return_value=response(200)) as post:
+            assert exporter.send(FINDINGS) == {"sent": 5, "failed": 0}
+
+        assert post.call_count == 3
+        assert post.call_args.args[0] == "https://splunk:8088/services/collector/event"
+        assert post.call_args.kwargs["headers"]["Authorization"] == "Splunk tok"
+        first_batch = post.call_args_list[0].kwargs["data"].decode("utf-8").split("\n")
+        assert [json.loads(line)["index"] for line in first_batch] == ["sec", "sec"]
+
+    def test_retries_transient_failures(self, monkeypatch):
+        """Test retry with backoff when the collector is busy."""
+        sleeps = []
+        monkeypatch.setattr("app.export.splunk_hec.time.sleep", sleeps.append)
+        exporter = SplunkHECExporter("https://splunk:8088", "tok", retries=3)
+        outcomes = [requests.ConnectionError("down"), response(503), response(200)]
+
+        with patch("app.export.splunk_hec.requests.post", side_effect=outcomes):
+            assert exporter.send(FINDINGS[:1]) == {"sent": 1, "failed": 0}
+
+        assert sleeps == [1, 2]
+
+    def test_rejected_batch(self):
+        """Test that 4xx responses fail the batch without retrying."""
+        exporter = SplunkHECExporter("https://splunk:8088", "tok", batch_size=3)
+        outcomes = [response(403), response(200)]
+
+        with patch("app.export.splunk_hec.requests.post", side_effect=outcomes) as post:
+            assert exporter.send(FINDINGS) == {"sent": 2, "failed": 3}
+
+        assert post.call_count == 2
+
+
+class TestFromConfig:
+    """Tests for Splunk HEC configuration."""
+
+    def test_configured(self, monkeypatch):
+        """Test the configured values and the token variable."""
+        monkeypatch.setenv("HEC", "tok")
+        config = PaddiConfig(
+            {
+                "export": {
+                    "splunk_hec": {
+                        "url": "https://splunk:8088",
+                        "token_env": "HEC",
+                        "sourcetype": "gcp:paddi",
+                        "batch_size": 50,
+                    }
+                }
+            }
+        )
+
+        exporter = SplunkHECExporter.from_config(config)
+
+        assert exporter.token == "tok"
+        assert (exporter.sourcetype, exporter.batch_size) == ("gcp:paddi", 50)
+
+    def test_requires_token(self, monkeypatch):
+        """Test that the token variable must be set."""
+        monkeypatch.delenv("SPLUNK_HEC_TOKEN", raising=False)
+        config = PaddiConfig({"export": {"splunk_hec": {"url": "https://splunk:8088"}}})
+
+        with pytest.raises(ConfigurationError):
+            SplunkHECExporter.from_config(config)
+
+
+class TestExportCommand:
+    """Tests for export --to splunk-hec."""
+
+    def test_failed_events_fail_the_command(self, tmp_path, capsys):
+        """Test that undelivered events are reported as an error."""
+        input_file = tmp_path / "explained.json"
+        input_file.write_text(json.dumps(FINDINGS))
+        exporter = SplunkHECExporter("https://splunk:8088", "tok")
+
+        with patch(
+            "app.cli.commands.SplunkHECExporter.from_config", return_value=exporter
+        ), patch("app.export.splunk_hec.requests.post", return_value=response(400)):
+            with pytest.raises(RuntimeError):
+                ExportCommand().execute(
+                    CommandContext(export_target="splunk-hec", input_file=str(input_file))
+                )
+
+        assert "0 event(s) sent, 5 failed" in capsys.readouterr().out
@@ -0,0 +1,134 @@
+"""Tests for the Cloud SQL configuration collector and its rules."""
+
+from app.collector.sql_collector import SqlCollector
+from app.config.paddi_config import PaddiConfig
+from app.explainer.rule_analyzer import RuleBasedAnalyzer
+
+
+class Request:
+    """A discovery API request returning one page."""
+
+    def __init__(self, response):
+        self.response = response
+
+    def execute(self):
+        return self.response
+
+
+class FakeInstances:
+    """The instances() resource of the Cloud SQL Admin API, returning two pages."""
+
+    def __init__(self, pages):
+        self.pages = pages
+        self.projects = []
+
+    def list(self, project):
+        self.projects.append(project)
+        return Request(self.pages[0])
+
+    def list_next(self, request, response):
+        index = self.pages.index(response) + 1
+        return Request(self.pages[index]) if index < len(self.pages) else None
+
+
+class FakeClient:
+    """Cloud SQL Admin API client."""
+
+    def __init__(self, pages):
+        self.resource = FakeInstances(pages)
+
+    def instances(self):
+        return self.resource
+
+
+MYSQL = {
+    "name": "billing",
+    "region": "asia-northeast1",
+    "databaseVersion": "MYSQL_8_0",
+    "settings": {
+        "ipConfiguration": {
+            "ipv4Enabled": True,
+            "authorizedNetworks": [{"name": "office", "value": "203.0.113.0/24"}],
+            "sslMode": "ENCRYPTED_ONLY",
+        },
+        "backupConfiguration": {"enabled": True, "binaryLogEnabled": True},
+    },
+}
+REPLICA = {
+    "name": "billing-replica",
+    "databaseVersion": "POSTGRES_15",
+    "instanceType": "READ_REPLICA_INSTANCE",
+    "settings": {"ipConfiguration": {"privateNetwork": "projects/p/global/networks/vpc"}},
+}
+
+
+def test_collect_converts_instances():
+    """Test the settings kept from every page of the Cloud SQL Admin API."""
+    client = FakeClient([{"items": [MYSQL]}, {"items": [REPLICA]}])
+    primary, replica = SqlCollector("p", client=client).collect()
+
+    assert client.resource.projects == ["p"]
+    assert primary == {
+        "name": "billing",
+        "project": "p",
+        "region": "asia-northeast1",
+        "database_version": "MYSQL_8_0",
+        "replica": False,
+        "public_ip": True,
+        "private_network": None,
+        "authorized_networks": ["203.0.113.0/24"],
+        "ssl_required": True,
+        "backups_enabled": True,
+        "point_in_time_recovery": True,
+    }
+    assert (replica["replica"], replica["public_ip"], replica["ssl_required"]) == (
+        True,
+        False,
+        False,
+    )
+
+    findings = RuleBasedAnalyzer().sql_findings([primary, replica])
+    assert [(f.category, f.resource) for f in findings] == [
+        ("SQL_PUBLIC_IP", "projects/p/instances/billing"),
+        ("SQL_SSL_NOT_REQUIRED", "projects/p/instances/billing-replica"),
+    ]
+
+
+def test_collect_skips_instances_on_errors():
+    """Test that a failed listing leaves instances out instead of failing."""
+
+    class FailingClient:
+        def instances(self):
+            raise PermissionError("Cloud SQL Admin API has not been used in project p")
+
+    assert SqlCollector("p", client=FailingClient()).collect() is None
+    off = PaddiConfig({"collector": {"sql": {"enabled": False}}})
+    assert SqlCollector.from_config("p", config=off) is None
+
+
+def test_sql_findings():
+    """Test findings for an instance missing every setting, and broad networks."""
+    instances = SqlCollector("prod", use_mock=True).collect()
+    findings = RuleBasedAnalyzer().native_findings({"sql_instances": instances})
+
+    assert [(f.category, f.severity) for f in findings] == [
+        ("SQL_PUBLIC_IP", "MEDIUM"),
+        ("SQL_BROAD_AUTHORIZED_NETWORK", "HIGH"),
+        ("SQL_SSL_NOT_REQUIRED", "MEDIUM"),
+        ("SQL_BACKUPS_DISABLED", "MEDIUM"),
+    ]
+    assert "anyone on the internet" in findings[1].explanation
+
+    broad = dict(instances[0], authorized_networks=["10.0.0.0/8", "198.51.100.7"])
+    [finding] = [
+        f
+        for f in RuleBasedAnalyzer().sql_findings([broad])
+        if f.category == "SQL_BROAD_AUTHORIZED_NETWORK"
+    ]
+    assert finding.severity == "MEDIUM" and "10.0.0.0/8" in finding.explanation
+    assert "198.51.100.7" not in finding.explanation
+
+    no_recovery = dict(instances[1], point_in_time_recovery=False)
+    assert [f.category for f in RuleBasedAnalyzer().sql_findings([no_recovery])] == [
+        "SQL_POINT_IN_TIME_RECOVERY_DISABLED"
+    ]
@@ -0,0 +1,52 @@
+"""Tests for stage duration estimates."""
+
+from datetime import datetime
+
+from app.history.estimates import StageEstimates, finish_time, format_seconds
+from app.history.store import HISTORY_DB_NAME, RunStore
+
+
+def _usage(collect, explain, report):
+    return [
+        {"agent": "collector", "wall_seconds": collect},
+        {"agent": "explainer", "wall_seconds": explain},
+        {"agent": "reporter", "wall_seconds": report},
+    ]
+
+
+def _record(store, project_id, usage, title):
+    run = store.record_run([{"title": title, "severity": "HIGH"}], project_id)
+    store.record_resource_usage(run.run_id, usage)
+
+
+def test_median_of_the_project_runs(tmp_path):
+    """Test that each stage is estimated from the median of the project's runs."""
+    store = RunStore(tmp_path / HISTORY_DB_NAME)
+    _record(store, "prod", _usage(10, 20, 1), "a")
+    _record(store, "prod", _usage(30, 20, 1), "b")
+    _record(store, "prod", _usage(600, 20, 1), "c")
+    _record(store, "dev", _usage(1, 1, 1), "d")
+
+    estimates = StageEstimates.from_store(store, "prod")
+
+    assert estimates.samples == 3
+    assert estimates.stage("collect") == 30
+    assert estimates.remaining(["collect", "explain", "report"]) == 51
+    assert StageEstimates.from_store(store, "new-project").samples == 4
+
+
+def test_no_history(tmp_path):
+    """Test that there is no estimate before the first audit."""
+    estimates = StageEstimates.load("prod", tmp_path)
+
+    assert not estimates
+    assert estimates.stage("collect") is None
+    assert estimates.remaining(["collect"]) is None
+
+
+def test_formatting():
+    """Test durations and finish times."""
+    assert format_seconds(45.4) == "45s"
+    assert format_seconds(200) == "3m 20s"
+    assert format_seconds(3900) == "1h 05m"
+    assert finish_time(90, datetime(2024, 1, 1, 12, 0, 0)) == "12:01:30"
@@ -0,0 +1,235 @@
+"""Tests for artifact storage backends."""
+
+from datetime import datetime, timezone
+from unittest.mock import MagicMock, patch
+
+import pytest
+
+from app.cli.base import CommandContext
+from app.cli.commands import AuditCommand
+from app.common.exceptions import ConfigurationError
+from app.config.paddi_config import PaddiConfig
+from app.storage.base import run_artifacts, run_prefix
+from app.storage.factory import StorageFactory
+from app.storage.gcs import GCSStorage
+from app.storage.s3 import S3Storage
+
+
+@pytest.fixture
+def run_dirs(tmp_path):
+    """Create data and output directories with run artifacts."""
+    data_dir = tmp_path / "data"
+    data_dir.mkdir()
+    (data_dir / "collected.json").write_text("{}")
+    (data_dir / "explained.json").write_text("[]")
+    (data_dir / "history.db").write_text("")
+    output_dir = tmp_path / "output"
+    (output_dir / "docs").mkdir(parents=True)
+    (output_dir / "audit.md").write_text("# Audit")
+    (output_dir / "docs" / "index.md").write_text("# Index")
+    return data_dir, output_dir
+
+
+def fake_gcs_client():
+    """Create a GCS client whose blobs record their names."""
+    client = MagicMock()
+
+    def blob(name):
+        b = MagicMock()
+        b.name = name
+        b.generate_signed_url.return_value = f"https://signed/{name}"
+        return b
+
+    client.bucket.return_value.blob.side_effect = blob
+    return client
+
+
+class TestRunLayout:
+    """Tests for the per-run object layout."""
+
+    def test_run_prefix(self):
+        """Test the per-run object prefix."""
+        when = datetime(2025, 6, 23, 10, 0, 0, tzinfo=timezone.utc)
+
+        assert run_prefix("paddi/", "prod", when) == "paddi/prod/20250623T100000Z"
+        assert run_prefix("", None, when) == "unknown-project/20250623T100000Z"
+
+    def test_run_artifacts(self, run_dirs):
+        """Test that data files and all reports are selected."""
+        names = [name for name, _ in run_artifacts(*run_dirs)]
+
+        assert names == [
+            "collected.json",
+            "explained.json",
+            "reports/audit.md",
+            "reports/docs/index.md",
+        ]
+
+
+class TestGCSStorage:
+    """Tests for GCSStorage."""
+
+    def test_upload_run(self, run_dirs):
+        """Test uploads under the run prefix without signed URLs."""
+        client = fake_gcs_client()
+        storage = GCSStorage("artifacts", client=client)
+
+        uploaded = storage.upload_run(*run_dirs, project_id="prod")
+
+        client.bucket.assert_called_once_with("artifacts")
+        assert len(uploaded) == 4
+        assert uploaded[0].uri.startswith("gs://artifacts/paddi/prod/")
+        assert uploaded[0].uri.endswith("/collected.json")
+        assert all(artifact.signed_url is None for artifact in uploaded)
+
+    def test_signed_urls(self, run_dirs):
+        """Test that signed URLs are generated when enabled."""
+        storage = GCSStorage("artifacts", client=fake_gcs_client(), signed_urls=True)
+
+        uploaded = storage.upload_run(*run_dirs, project_id="prod")
+
+        assert uploaded[2].signed_url.startswith("https://signed/paddi/prod/")
+        assert uploaded[2].signed_url.endswith("/reports/audit.md")
+
+    def test_from_config(self):
+        """Test the configured values."""
+        config = PaddiConfig(
+            {"storage": {"gcs": {"bucket": "artifacts", "prefix": "audits", "signed_urls": True}}}
+        )
+
+        storage = GCSStorage.from_config(config)
+
+        assert (storage.bucket_name, storage.prefix, storage.signed_urls) == (
+            "artifacts",
+            "audits",
+            True,
+        )
+        assert storage.signed_url_expiration == 3600
+
+    def test_invalid_expiration(self):
+        """Test that signed URLs cannot outlive the seven-day limit."""
+        config = PaddiConfig({"storage": {"gcs": {"bucket": "b", "signed_url_expiration": 0}}})
+
+        with pytest.raises(ConfigurationError):
+            GCSStorage.from_config(config)
+
+
+class TestS3Storage:
+    """Tests for S3Storage."""
+
+    def test_upload_run(self, run_dirs):
+        """Test uploads and presigned URLs."""
+        client = MagicMock()
+        client.generate_presigned_url.return_value = "https://presigned"
+        storage = S3Storage("artifacts", client=client, signed_urls=True)
+
+        uploaded = storage.upload_run(*run_dirs, project_id="prod")
+
+        path, bucket, key = client.upload_file.call_args_list[0].args
+        assert path.endswith("collected.json")
+        assert bucket == "artifacts"
+        assert uploaded[0].uri == f"s3://artifacts/{key}"
+        assert uploaded[0].signed_url == "https://presigned"
+        last_key = client.upload_file.call_args_list[-1].args[2]
+        client.generate_presigned_url.assert_called_with(
+            "get_object", Params={"Bucket": "artifacts", "Key": last_key}, ExpiresIn=3600
+        )
+
+    def test_credentials_from_env(self, monkeypatch):
+        """Test keys read from the configured environment variables."""
+        monkeypatch.setenv("MINIO_KEY", "key")
+        monkeypatch.setenv("MINIO_SECRET", "secret")
+        config = PaddiConfig(
+            {
+                "storage": {
+                    "s3": {
+                        "bucket": "artifacts",
+                        "endpoint_url": "http://localhost:9000",
+                        "access_key_env": "MINIO_KEY",
+                        "secret_key_env": "MINIO_SECRET",
+                    }
+                }
+            }
+        )
+
+        storage = S3Storage.from_config(config)
+
+        assert storage.endpoint_url == "http://localhost:9000"
+        assert storage.credentials == {
+            "aws_access_key_id": "key",
+            "aws_secret_access_key": "secret",
+        }
+
+    def test_missing_credential_variable(self, monkeypatch):
+        """Test that an unset key variable is rejected."""
+        monkeypatch.delenv("MINIO_SECRET", raising=False)
+        monkeypatch.setenv("MINIO_KEY", "key")
+        config = PaddiConfig(
+            {
+                "storage": {
+                    "s3": {
+                        "bucket": "b",
+                        "access_key_env": "MINIO_KEY",
+                        "secret_key_env": "MINIO_SECRET",
+                    }
+                }
+            }
+        )
+
+        with pytest.raises(ConfigurationError):
+            S3Storage.from_config(config)
+
+    def test_profile(self):
+        """Test that a named profile is used for the default credential chain."""
+        config = PaddiConfig({"storage": {"s3": {"bucket": "b", "profile": "audit"}}})
+
+        storage = S3Storage.from_config(config)
+
+        assert storage.profile == "audit"
+        assert storage.credentials == {}
+
+
+class TestStorageFactory:
+    """Tests for StorageFactory."""
+
+    def test_configured_backends(self):
+        """Test that only backends with a bucket are created."""
+        assert StorageFactory.from_config(PaddiConfig({})) == []
+
+        config = PaddiConfig({"storage": {"s3": {"bucket": "b"}}})
+
+        assert [s.get_name() for s in StorageFactory.from_config(config)] == ["s3"]
+
+
+class TestAuditUpload:
+    """Tests for the upload step of the audit command."""
+
+    def test_upload_failure_does_not_fail_audit(self, run_dirs):
+        """Test that one failing backend is logged and the others still upload."""
+        failing = S3Storage("broken", client=MagicMock())
+        failing.client.upload_file.side_effect = RuntimeError("forbidden")
+        working = GCSStorage("artifacts", client=fake_gcs_client())
+        data_dir, output_dir = run_dirs
+
+        with patch(
+            "app.cli.commands.StorageFactory.from_config", return_value=[failing, working]
+        ), patch.object(working, "upload_run", wraps=working.upload_run) as upload:
+            AuditCommand()._upload_artifacts(
+                CommandContext(output_dir=str(output_dir)), str(data_dir)
+            )
+
+        upload.assert_called_once()
+
+    def test_prints_uploaded_artifacts(self, run_dirs, capsys):
+        """Test that URIs and signed URLs are printed."""
+        storage = GCSStorage("artifacts", signed_urls=True, client=fake_gcs_client())
+        data_dir, output_dir = run_dirs
+
+        with patch("app.cli.commands.StorageFactory.from_config", return_value=[storage]):
+            AuditCommand()._upload_artifacts(
+                CommandContext(output_dir=str(output_dir)), str(data_dir)
+            )
+
+        out = capsys.readouterr().out
+        assert "gs://artifacts/paddi/example-project-123/" in out
+        assert "https://signed/" in out
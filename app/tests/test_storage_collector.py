@@ -0,0 +1,109 @@
+"""Tests for the Cloud Storage bucket collector and its rules."""
+
+from types import SimpleNamespace
+
+from app.collector.storage_collector import BucketCollector
+from app.config.paddi_config import PaddiConfig
+from app.explainer.rule_analyzer import RuleBasedAnalyzer
+from app.remediation.gcloud_script import build_script
+
+
+class FakeBucket:
+    """A Bucket with its IAM configuration and a fixed IAM policy."""
+
+    def __init__(self, name, prevention, uniform, bindings):
+        self.name = name
+        self.location = "ASIA-NORTHEAST1"
+        self.iam_configuration = SimpleNamespace(
+            public_access_prevention=prevention,
+            uniform_bucket_level_access_enabled=uniform,
+        )
+        self.bindings = bindings
+        self.policy_versions = []
+
+    def get_iam_policy(self, requested_policy_version):
+        self.policy_versions.append(requested_policy_version)
+        return SimpleNamespace(bindings=self.bindings)
+
+
+class FakeClient:
+    """storage.Client returning fixed buckets."""
+
+    def __init__(self, buckets):
+        self.buckets = buckets
+        self.projects = []
+
+    def list_buckets(self, project):
+        self.projects.append(project)
+        return iter(self.buckets)
+
+
+def test_collect_converts_buckets():
+    """Test the settings and IAM bindings kept from the Cloud Storage API."""
+    bucket = FakeBucket(
+        "assets",
+        "inherited",
+        False,
+        [{"role": "roles/storage.objectViewer", "members": {"allUsers", "group:web@example.com"}}],
+    )
+    client = FakeClient([bucket])
+    data = BucketCollector("p", client=client).collect()
+
+    assert client.projects == ["p"]
+    assert bucket.policy_versions == [3]
+    assert data == {
+        "buckets": [
+            {
+                "name": "assets",
+                "project": "p",
+                "location": "ASIA-NORTHEAST1",
+                "public_access_prevention": "inherited",
+                "uniform_bucket_level_access": False,
+            }
+        ],
+        "bucket_iam_bindings": [
+            {
+                "bucket": "assets",
+                "role": "roles/storage.objectViewer",
+                "members": ["allUsers", "group:web@example.com"],
+            }
+        ],
+    }
+
+
+def test_collect_skips_buckets_on_errors():
+    """Test that a failed listing leaves buckets out instead of failing."""
+
+    class FailingClient:
+        def list_buckets(self, project):
+            raise PermissionError("storage.buckets.list denied on project p")
+
+    assert BucketCollector("p", client=FailingClient()).collect() is None
+    off = PaddiConfig({"collector": {"storage": {"enabled": False}}})
+    assert BucketCollector.from_config("p", config=off) is None
+
+
+def test_bucket_findings_and_remediation():
+    """Test findings for a public bucket, and that enforced prevention silences grants."""
+    data = BucketCollector("prod", use_mock=True).collect()
+    findings = RuleBasedAnalyzer().native_findings(data)
+
+    assert [(f.category, f.severity) for f in findings] == [
+        ("PUBLIC_BUCKET", "CRITICAL"),
+        ("BUCKET_PUBLIC_ACCESS_PREVENTION_OFF", "LOW"),
+        ("BUCKET_UNIFORM_ACCESS_DISABLED", "MEDIUM"),
+    ]
+    assert {f.resource for f in findings} == {"//storage.googleapis.com/example-public-bucket"}
+    assert "allUsers" in findings[0].explanation
+
+    enforced = [dict(b, public_access_prevention="enforced") for b in data["buckets"]]
+    assert [
+        f.category
+        for f in RuleBasedAnalyzer().bucket_findings(enforced, data["bucket_iam_bindings"])
+    ] == ["BUCKET_UNIFORM_ACCESS_DISABLED"]
+
+    script = build_script([f.to_dict() for f in findings])
+    assert (
+        "gcloud storage buckets update gs://example-public-bucket --uniform-bucket-level-access"
+        in script
+    )
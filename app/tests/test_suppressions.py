@@ -0,0 +1,93 @@
+"""Tests for suppressions with owners, justifications, and expiry dates."""
+
+from datetime import date
+
+import pytest
+
+from app.common.exceptions import ConfigurationError
+from app.config.paddi_config import PaddiConfig
+from app.findings.fingerprint import compute_fingerprint
+from app.findings.suppressions import Suppressions
+from app.reporter.agent_reporter import MarkdownGenerator, ReportService
+
+TODAY = date(2025, 6, 1)
+
+BUCKET = {
+    "title": "Public bucket",
+    "severity": "HIGH",
+    "category": "PUBLIC_BUCKET",
+    "resource": "storage.googleapis.com/site",
+}
+SSL = {
+    "title": "Weak TLS",
+    "severity": "MEDIUM",
+    "category": "WEAK_SSL_POLICY",
+    "resource": "projects/web-dev/lb",
+}
+
+
+def _rule(**kwargs):
+    """A valid rule entry with the given match fields."""
+    return {"owner": "alice", "justification": "accepted", "expires": date(2025, 12, 31), **kwargs}
+
+
+def _suppressions(*rules):
+    """Load suppressions from a config with the given rules."""
+    return Suppressions.from_config(PaddiConfig({"suppressions": {"rules": list(rules)}}))
+
+
+def test_active_suppression_hides_finding():
+    """Test matching by fingerprint and by category and resource glob."""
+    suppressions = _suppressions(
+        _rule(fingerprint=compute_fingerprint(BUCKET)),
+        _rule(category="weak_ssl_policy", resource="projects/*-dev/*"),
+    )
+
+    result = suppressions.apply([BUCKET, SSL], today=TODAY)
+
+    assert result.findings == []
+    assert [f["title"] for f in result.suppressed] == ["Public bucket", "Weak TLS"]
+    assert result.suppressed[0]["suppression"]["owner"] == "alice"
+    assert result.expired == []
+
+
+def test_expired_suppression_resurfaces():
+    """Test that findings come back after the expiry date, and the rule is listed."""
+    suppressions = _suppressions(_rule(category="PUBLIC_BUCKET", expires="2025-05-31"))
+
+    assert suppressions.apply([BUCKET], today=date(2025, 5, 31)).findings == []
+    result = suppressions.apply([BUCKET, SSL], today=TODAY)
+
+    assert result.findings == [BUCKET, SSL]
+    assert len(result.expired) == 1
+    assert result.expired[0].findings == [BUCKET]
+
+
+@pytest.mark.parametrize(
+    "rule",
+    [
+        {"category": "PUBLIC_BUCKET", "owner": "alice", "justification": "accepted"},
+        {"category": "PUBLIC_BUCKET", "justification": "accepted", "expires": "2025-12-31"},
+        _rule(),
+        _rule(category="PUBLIC_BUCKET", expires="next year"),
+    ],
+)
+def test_invalid_rules(rule):
+    """Test that rules need an owner, a justification, a valid expiry, and a match."""
+    with pytest.raises(ConfigurationError):
+        _suppressions(rule)
+
+
+def test_report_lists_expired_suppressions():
+    """Test the report's Suppressions section."""
+    result = _suppressions(
+        _rule(category="WEAK_SSL_POLICY"),
+        _rule(category="PUBLIC_BUCKET", owner="bob", expires="2025-01-31"),
+    ).apply([BUCKET, SSL], today=TODAY)
+    report = ReportService().create_report(result.findings, {}, suppressions=result)
+
+    markdown = MarkdownGenerator().generate(report)
+
+    assert "1 findings are suppressed" in markdown
+    assert "### Expired Suppressions" in markdown
+    assert "| `PUBLIC_BUCKET` | bob | accepted | 2025-01-31 | 1 |" in markdown
@@ -0,0 +1,32 @@
+"""Tests for config-driven resource tags."""
+
+from app.config.paddi_config import PaddiConfig
+from app.findings.tags import TagRules
+
+
+class TestTagRules:
+    """Tests for TagRules."""
+
+    def test_tags_for_resource(self):
+        """Test glob matching against normalized resource names."""
+        rules = TagRules({"pci": ["storage.googleapis.com/cardholder-*"], "prod": ["*prod*"]})
+
+        assert rules.tags_for("//storage.googleapis.com/Cardholder-Prod") == ["pci", "prod"]
+        assert rules.tags_for("projects/dev") == []
+        assert rules.tags_for(None) == []
+
+    def test_tags_for_finding_uses_affected_resources(self):
+        """Test that deduplicated findings are tagged from every affected resource."""
+        rules = TagRules({"pci": ["projects/payments"], "prod": ["projects/prod-*"]})
+        finding = {
+            "resource": "projects/payments",
+            "affected_resources": ["projects/payments", "projects/prod-web"],
+        }
+
+        assert rules.tags_for_finding(finding) == ["pci", "prod"]
+
+    def test_from_config(self):
+        """Test loading rules from the [tags] section, accepting a single pattern."""
+        rules = TagRules.from_config(PaddiConfig({"tags": {"prod": "*prod*"}}))
+
+        assert rules.tags_for("projects/prod-1") == ["prod"]
@@ -0,0 +1,205 @@
+"""Tests for the Terraform state/plan collector."""
+
+import json
+
+import pytest
+
+from app.collector.agent_collector import main
+from app.collector.terraform_collector import TerraformCollector, iter_resources
+from app.common.exceptions import CollectionError
+
+STATE = {
+    "version": 4,
+    "resources": [
+        {
+            "mode": "managed",
+            "type": "google_project_iam_member",
+            "name": "owner",
+            "instances": [
+                {
+                    "attributes": {
+                        "project": "prod",
+                        "role": "roles/owner",
+                        "member": "user:admin@example.com",
+                    }
+                }
+            ],
+        },
+        {
+            "mode": "managed",
+            "type": "google_project_iam_binding",
+            "name": "editors",
+            "module": "module.iam",
+            "instances": [
+                {
+                    "index_key": "a",
+                    "attributes": {
+                        "project": "prod",
+                        "role": "roles/editor",
+                        "members": ["serviceAccount:app@prod.iam.gserviceaccount.com"],
+                    },
+                }
+            ],
+        },
+        {
+            "mode": "managed",
+            "type": "google_compute_firewall",
+            "name": "ssh",
+            "instances": [
+                {
+                    "attributes": {
+                        "name": "allow-ssh",
+                        "project": "prod",
+                        "network": "projects/prod/global/networks/default",
+                        "source_ranges": ["0.0.0.0/0"],
+                        "allow": [{"protocol": "tcp", "ports": ["22"]}],
+                    }
+                }
+            ],
+        },
+        {
+            "mode": "data",
+            "type": "google_project",
+            "name": "current",
+            "instances": [{"attributes": {"project_id": "prod"}}],
+        },
+    ],
+}
+
+PLAN = {
+    "format_version": "1.2",
+    "planned_values": {
+        "root_module": {
+            "resources": [
+                {
+                    "address": "google_storage_bucket_iam_member.public",
+                    "mode": "managed",
+                    "type": "google_storage_bucket_iam_member",
+                    "values": {
+                        "bucket": "b/assets",
+                        "role": "roles/storage.objectViewer",
+                        "member": "allUsers",
+                    },
+                }
+            ],
+            "child_modules": [
+                {
+                    "resources": [
+                        {
+                            "address": "module.iam.google_project_iam_policy.main",
+                            "mode": "managed",
+                            "type": "google_project_iam_policy",
+                            "values": {
+                                "project": "staging",
+                                "policy_data": json.dumps(
+                                    {
+                                        "bindings": [
+                                            {"role": "roles/owner", "members": ["user:a@x.com"]}
+                                        ]
+                                    }
+                                ),
+                            },
+                        }
+                    ]
+                }
+            ],
+        }
+    },
+}
+
+
+def write(tmp_path, document):
+    """Write a Terraform JSON document to a temporary file."""
+    path = tmp_path / "terraform.json"
+    path.write_text(json.dumps(document))
+    return path
+
+
+class TestIterResources:
+    """Tests for reading resources from Terraform documents."""
+
+    def test_state_file(self):
+        """Test addresses from a state file, skipping data sources."""
+        addresses = [address for address, _, _ in iter_resources(STATE)]
+
+        assert addresses == [
+            "google_project_iam_member.owner",
+            'module.iam.google_project_iam_binding.editors["a"]',
+            "google_compute_firewall.ssh",
+        ]
+
+    def test_plan_includes_child_modules(self):
+        """Test that show -json output is read recursively."""
+        types = [resource_type for _, resource_type, _ in iter_resources(PLAN)]
+
+        assert types == ["google_storage_bucket_iam_member", "google_project_iam_policy"]
+
+    def test_unknown_document(self):
+        """Test that other JSON documents are rejected."""
+        with pytest.raises(ValueError):
+            list(iter_resources({"foo": "bar"}))
+
+
+class TestTerraformCollector:
+    """Tests for TerraformCollector."""
+
+    def test_collect_state(self, tmp_path):
+        """Test conversion of IAM and firewall resources."""
+        data = TerraformCollector(str(write(tmp_path, STATE))).collect()
+
+        assert data["metadata"]["project_id"] == "prod"
+        assert data["metadata"]["source"] == "terraform"
+        assert data["iam_policies"]["bindings"] == [
+            {
+                "role": "roles/owner",
+                "members": ["user:admin@example.com"],
+                "terraform_addresses": ["google_project_iam_member.owner"],
+            },
+            {
+                "role": "roles/editor",
+                "members": ["serviceAccount:app@prod.iam.gserviceaccount.com"],
+                "terraform_addresses": ['module.iam.google_project_iam_binding.editors["a"]'],
+            },
+        ]
+        rule = data["firewall_rules"][0]
+        assert rule["name"] == "allow-ssh"
+        assert rule["network"] == "default"
+        assert rule["direction"] == "INGRESS"
+        assert rule["allowed"] == [{"protocol": "tcp", "ports": ["22"]}]
+        assert rule["exposed_ports"] == [22]
+
+    def test_collect_plan(self, tmp_path):
+        """Test bucket bindings and policy_data from a plan."""
+        data = TerraformCollector(str(write(tmp_path, PLAN)), project_id="override").collect()
+
+        assert data["metadata"]["project_id"] == "override"
+        assert data["bucket_iam_bindings"][0]["bucket"] == "assets"
+        assert data["bucket_iam_bindings"][0]["members"] == ["allUsers"]
+        assert data["iam_policies"]["bindings"][0]["members"] == ["user:a@x.com"]
+
+    def test_collect_plan_with_unknown_bucket(self, tmp_path):
+        """Test that a bucket name unknown until apply is left empty."""
+        plan = json.loads(json.dumps(PLAN))
+        plan["planned_values"]["root_module"]["resources"][0]["values"]["bucket"] = None
+
+        data = TerraformCollector(str(write(tmp_path, plan))).collect()
+
+        assert data["bucket_iam_bindings"][0]["bucket"] == ""
+
+    def test_invalid_file(self, tmp_path):
+        """Test that unreadable input raises CollectionError."""
+        path = tmp_path / "broken.json"
+        path.write_text("{not json")
+
+        with pytest.raises(CollectionError):
+            TerraformCollector(str(path)).collect()
+
+    def test_main_writes_collected_json(self, tmp_path):
+        """Test that collect --terraform saves collected.json."""
+        output_dir = tmp_path / "data"
+
+        main(terraform=str(write(tmp_path, STATE)), output_dir=str(output_dir))
+
+        data = json.loads((output_dir / "collected.json").read_text(encoding="utf-8"))
+        assert data["metadata"]["terraform_file"].endswith("terraform.json")
+        assert len(data["firewall_rules"]) == 1
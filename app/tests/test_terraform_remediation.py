@@ -0,0 +1,112 @@
+"""Tests for Terraform remediation snippets."""
+
+import json
+
+from app.remediation.templates import hcl_label, hcl_quote
+from app.remediation.terraform import TERRAFORM_FILE, build_snippets, snippet_for
+from app.reporter.agent_reporter import main
+
+FINDINGS = [
+    {
+        "title": "Public bucket",
+        "severity": "HIGH",
+        "category": "PUBLIC_BUCKET",
+        "resource": "//storage.googleapis.com/prod-assets",
+    },
+    {
+        "title": "Service account with Editor role",
+        "severity": "HIGH",
+        "category": "OVERPRIVILEGED_SERVICE_ACCOUNT",
+        "resource": "projects/prod/serviceAccounts/app@prod.iam.gserviceaccount.com",
+    },
+    {
+        "title": "Audit logging disabled",
+        "severity": "MEDIUM",
+        "category": "AUDIT_LOGGING_DISABLED",
+        "resource": "projects/prod",
+    },
+    {
+        "title": "Public S3 bucket",
+        "severity": "HIGH",
+        "category": "PUBLIC_BUCKET",
+        "resource": "arn:aws:s3:::example-public-bucket",
+    },
+]
+
+
+class TestHclHelpers:
+    """Tests for HCL labels and quoting."""
+
+    def test_label(self):
+        """Test that labels are valid Terraform identifiers."""
+        assert hcl_label("//storage.googleapis.com/prod-assets") == "prod_assets"
+        assert hcl_label("projects/p/serviceAccounts/app@p.iam.gserviceaccount.com") == "app"
+        assert hcl_label("projects/123") == "r_123"
+
+    def test_quote_escapes_interpolation(self):
+        """Test that values cannot inject HCL interpolation."""
+        assert hcl_quote('a"${b}') == '"a\\"$${b}"'
+
+
+class TestSnippets:
+    """Tests for snippet rendering."""
+
+    def test_bucket_snippet(self):
+        """Test a complete snippet for a public bucket."""
+        snippet = snippet_for(FINDINGS[0])
+
+        assert "# HIGH Public bucket" in snippet
+        assert 'resource "google_storage_bucket" "prod_assets" {' in snippet
+        assert '  name                        = "prod-assets"' in snippet
+        assert '  public_access_prevention    = "enforced"' in snippet
+
+    def test_incomplete_snippet_is_commented_out(self):
+        """Test that snippets with placeholders do not break the file."""
+        snippet = snippet_for(FINDINGS[1])
+
+        assert "# TODO: replace <REPLACEMENT_ROLE>" in snippet
+        assert '#   member  = "serviceAccount:app@prod.iam.gserviceaccount.com"' in snippet
+        assert all(line.startswith("#") for line in snippet.splitlines())
+
+    def test_unencrypted_database_needs_a_key(self):
+        """Test that encryption at rest is fixed with a customer-managed key to fill in."""
+        finding = {
+            "title": "Database not encrypted at rest",
+            "severity": "HIGH",
+            "category": "UNENCRYPTED_DATABASE",
+            "resource": "projects/prod/instances/billing",
+        }
+
+        snippet = snippet_for(finding)
+
+        assert "# TODO: replace <KMS_KEY>" in snippet
+        assert "#   encryption_key_name = <KMS_KEY>" in snippet
+        assert "ssl_mode" not in snippet
+
+    def test_build_snippets(self):
+        """Test the file, unique labels, and skipped findings."""
+        content = build_snippets(FINDINGS + [dict(FINDINGS[0], title="Again")])
+
+        assert content.startswith("# Paddi remediation snippets")
+        assert 'resource "google_project_iam_audit_config" "prod" {' in content
+        assert '"prod_assets_2"' in content
+        assert "example-public-bucket" not in content
+        assert "# 1 finding(s) have no Terraform remediation template" in content
+
+
+class TestTerraformFormat:
+    """Tests for the terraform report format."""
+
+    def test_report_writes_remediation_tf(self, tmp_path, monkeypatch):
+        """Test that --formats=terraform writes remediation.tf only."""
+        monkeypatch.chdir(tmp_path)
+        input_dir = tmp_path / "data"
+        input_dir.mkdir()
+        (input_dir / "explained.json").write_text(json.dumps(FINDINGS))
+        output_dir = tmp_path / "output"
+
+        main(input_dir=str(input_dir), output_dir=str(output_dir), formats="terraform")
+
+        content = (output_dir / TERRAFORM_FILE).read_text(encoding="utf-8")
+        assert 'resource "google_storage_bucket" "prod_assets" {' in content
+        assert not (output_dir / "audit.md").exists()
@@ -0,0 +1,73 @@
+"""Tests for the terminal theme: symbols, severity colors, and NO_COLOR."""
+
+import pytest
+
+from app.cli import theme
+from app.cli.output import set_quiet
+from app.findings.listing import format_table
+
+FINDINGS = [{"title": "Owner role", "severity": "CRITICAL", "category": "OVERPRIVILEGED_ROLE"}]
+
+
+@pytest.fixture(autouse=True)
+def reset_theme(monkeypatch):
+    """Start each test with color detection and normal verbosity."""
+    monkeypatch.delenv("NO_COLOR", raising=False)
+    monkeypatch.delenv("PADDI_QUIET", raising=False)
+    yield
+    theme.set_color(None)
+    set_quiet(False)
+
+
+class TestMessage:
+    """Tests for status messages."""
+
+    def test_symbol_prefix_keeps_leading_newline(self):
+        """Test that the symbol goes after leading newlines."""
+        assert theme.message("error", "\nFindings file not found: %s") == (
+            "\n❌ Findings file not found: %s"
+        )
+
+    def test_quiet_drops_symbol(self):
+        """Test that quiet mode prints the bare message."""
+        set_quiet(True)
+        assert theme.message("success", "Audit complete!") == "Audit complete!"
+
+    def test_color_only_when_requested(self):
+        """Test that colors are applied to stdout text, not log messages."""
+        theme.set_color(True)
+        assert theme.message("success", "done") == "✅ done"
+        assert theme.message("success", "done", color=True) == "\033[32m✅ done\033[0m"
+
+
+class TestColors:
+    """Tests for color detection and severity styling."""
+
+    def test_no_color_env(self, monkeypatch):
+        """Test that NO_COLOR disables colors."""
+        monkeypatch.setenv("NO_COLOR", "1")
+        assert not theme.color_enabled()
+        assert theme.severity("HIGH") == "HIGH"
+
+    def test_not_a_terminal(self, capsys):
+        """Test that piped output is never colored."""
+        assert not theme.color_enabled()
+
+    def test_severity_colors(self):
+        """Test that padded severity cells keep their width."""
+        theme.set_color(True)
+        assert theme.severity("CRITICAL ", "CRITICAL") == "\033[1;31mCRITICAL \033[0m"
+        assert theme.severity("UNKNOWN") == "UNKNOWN"
+
+    def test_colored_table(self):
+        """Test that the findings table colors only the severity column."""
+        theme.set_color(True)
+        plain = format_table(FINDINGS)
+        colored = format_table(FINDINGS, severity_style=theme.severity)
+        assert "\033[1;31mCRITICAL \033[0m" in colored
+        assert colored.replace("\033[1;31m", "").replace("\033[0m", "") == plain
+
+    def test_extract_no_color_flag(self):
+        """Test removing the global --no-color flag."""
+        assert theme.extract_no_color_flag(["audit", "--no-color"]) == (["audit"], True)
+        assert theme.extract_no_color_flag(["audit"]) == (["audit"], False)
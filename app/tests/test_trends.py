@@ -0,0 +1,80 @@
+"""Tests for trend analysis across recorded runs."""
+
+import json
+import sqlite3
+
+import pytest
+
+from app.cli.base import CommandContext
+from app.cli.commands import TrendsCommand
+from app.history.store import HISTORY_DB_NAME, RunStore
+from app.history.trends import compute_trends, format_trends, sparkline
+
+BUCKET = {"title": "Public bucket", "severity": "HIGH", "category": "PUBLIC_BUCKET"}
+OWNER = {"title": "Owner role", "severity": "CRITICAL", "category": "IAM", "resource": "u1"}
+KEY = {"title": "Old key", "severity": "MEDIUM", "category": "KEYS"}
+
+
+@pytest.fixture
+def store(tmp_path):
+    """Four runs, one day apart: the bucket is fixed on day 2 and back on day 4."""
+    store = RunStore(tmp_path / "data" / HISTORY_DB_NAME)
+    for findings in ([BUCKET, OWNER], [OWNER, KEY], [KEY], [KEY, BUCKET]):
+        store.record_run(findings, "p")
+    with sqlite3.connect(store.path) as conn:
+        for run_id in range(1, 5):
+            conn.execute(
+                "UPDATE runs SET created_at = ? WHERE id = ?",
+                (f"2025-06-0{run_id}T00:00:00+00:00", run_id),
+            )
+    return store
+
+
+def test_sparkline():
+    """Test scaling values to bar characters."""
+    assert sparkline([0, 4, 8]) == "▁▅█"
+    assert sparkline([3, 3]) == "▅▅"
+    assert sparkline([]) == ""
+
+
+def test_compute_trends(store):
+    """Test counts per run, time to remediation, and recurring categories."""
+    trends = compute_trends(store)
+
+    assert [(p.total, p.new, p.resolved) for p in trends.points] == [
+        (2, 0, 0),
+        (2, 1, 1),
+        (1, 0, 1),
+        (2, 1, 0),
+    ]
+    assert trends.mttr_days_by_severity == {"CRITICAL": 2.0, "HIGH": 1.0}
+    assert trends.mttr_days == 1.5
+    assert trends.resolved_findings == 2
+    assert trends.recurring_categories == ["PUBLIC_BUCKET"]
+
+    assert [p.run_id for p in compute_trends(store, limit=2).points] == [3, 4]
+
+
+def test_format_and_csv(store):
+    """Test the terminal and CSV output."""
+    trends = compute_trends(store)
+
+    text = format_trends(trends)
+    assert "Findings over 4 run(s) (2025-06-01 → 2025-06-04)" in text
+    assert "Mean time to remediation: 1.5 days (2 resolved finding(s))" in text
+    assert "Newly recurring categories: PUBLIC_BUCKET" in text
+
+    lines = trends.to_csv().splitlines()
+    assert lines[0] == "run_id,created_at,total,CRITICAL,HIGH,MEDIUM,LOW,INFO,new,resolved"
+    assert lines[1] == "1,2025-06-01T00:00:00+00:00,2,1,1,0,0,0,0,0"
+
+
+def test_command(store, tmp_path, monkeypatch, capsys):
+    """Test JSON output of paddi trends and the missing-history error."""
+    monkeypatch.chdir(tmp_path)
+    TrendsCommand().execute(CommandContext(output_format="json"))
+    assert json.loads(capsys.readouterr().out)["recurring_categories"] == ["PUBLIC_BUCKET"]
+
+    monkeypatch.chdir(tmp_path / "data")
+    with pytest.raises(FileNotFoundError):
+        TrendsCommand().execute(CommandContext())
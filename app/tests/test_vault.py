@@ -0,0 +1,123 @@
+"""Tests for resolving vault: references in paddi.toml."""
+
+from unittest.mock import Mock, patch
+
+import pytest
+import requests
+
+from app.common.exceptions import ConfigurationError
+from app.config.paddi_config import PaddiConfig
+from app.config.validation import validate_config
+from app.config.vault import VaultClient, parse_reference
+from app.notifications.datadog import DatadogNotifier
+
+VAULT = {"address": "https://vault.example.com", "token_env": "TEST_VAULT_TOKEN"}
+
+
+def vault_response(data, status=200):
+    """Build a fake Vault HTTP response."""
+    return Mock(status_code=status, json=Mock(return_value={"data": data}))
+
+
+@pytest.fixture(autouse=True)
+def vault_token(monkeypatch):
+    """Provide a Vault token."""
+    monkeypatch.setenv("TEST_VAULT_TOKEN", "s.token")
+
+
+class TestPaddiConfigResolution:
+    """Tests for resolving references through PaddiConfig."""
+
+    @patch("app.config.vault.requests.get")
+    def test_kv2_reference(self, mock_get):
+        """Test that section values are resolved from KV v2 on access."""
+        mock_get.return_value = vault_response({"data": {"datadog": "dd-key"}})
+        config = PaddiConfig(
+            {
+                "secrets": {"vault": VAULT},
+                "notifications": {"datadog": {"api_key": "vault:secret/paddi#datadog"}},
+            }
+        )
+
+        assert DatadogNotifier.from_config(config).api_key == "dd-key"
+        url = mock_get.call_args[0][0]
+        assert url == "https://vault.example.com/v1/secret/data/paddi"
+        assert mock_get.call_args[1]["headers"]["X-Vault-Token"] == "s.token"
+        assert config.data["notifications"]["datadog"]["api_key"].startswith("vault:")
+
+    @patch("app.config.vault.requests.get")
+    def test_secret_read_once(self, mock_get):
+        """Test that several keys of one secret cost one request."""
+        mock_get.return_value = vault_response({"data": {"a": "1", "b": "2"}})
+        config = PaddiConfig(
+            {"secrets": {"vault": VAULT}, "x": {"a": "vault:secret/p#a", "b": "vault:secret/p#b"}}
+        )
+
+        assert config.section("x") == {"a": "1", "b": "2"}
+        assert mock_get.call_count == 1
+
+    @patch("app.config.vault.requests.get")
+    def test_kv1_and_namespace(self, mock_get):
+        """Test KV v1 paths and the Enterprise namespace header."""
+        mock_get.return_value = vault_response({"gemini": "g-key"})
+        vault = dict(VAULT, kv_version=1, namespace="admin")
+        config = PaddiConfig({"secrets": {"vault": vault}, "ai": {"key": "vault:kv/paddi#gemini"}})
+
+        assert config.get("ai.key") == "g-key"
+        assert mock_get.call_args[0][0] == "https://vault.example.com/v1/kv/paddi"
+        assert mock_get.call_args[1]["headers"]["X-Vault-Namespace"] == "admin"
+
+    def test_no_vault_needed(self):
+        """Test that configs without references never contact Vault."""
+        config = PaddiConfig({"general": {"project_id": "p"}})
+        assert config.get("general.project_id") == "p"
+
+
+class TestErrors:
+    """Tests for unresolvable references."""
+
+    def test_bad_reference(self):
+        """Test that a reference needs a path and a key."""
+        with pytest.raises(ConfigurationError):
+            parse_reference("vault:secret/paddi")
+
+    def test_missing_vault_section(self, monkeypatch):
+        """Test the error when no Vault address is configured."""
+        monkeypatch.delenv("VAULT_ADDR", raising=False)
+        config = PaddiConfig({"x": {"key": "vault:secret/p#k"}})
+        with pytest.raises(ConfigurationError) as exc:
+            config.get("x.key")
+        assert exc.value.config_item == "secrets.vault.address"
+
+    @patch("app.config.vault.requests.get")
+    def test_missing_key_and_http_errors(self, mock_get):
+        """Test errors for absent keys, HTTP failures, and connection errors."""
+        client = VaultClient.from_config(VAULT)
+        mock_get.return_value = vault_response({"data": {}})
+        with pytest.raises(ConfigurationError):
+            client.resolve("vault:secret/a#missing")
+
+        mock_get.return_value = vault_response({}, status=403)
+        with pytest.raises(ConfigurationError):
+            client.resolve("vault:secret/b#key")
+
+        mock_get.side_effect = requests.ConnectionError("refused")
+        with pytest.raises(ConfigurationError):
+            client.resolve("vault:secret/c#key")
+
+
+class TestValidation:
+    """Tests for config validate with vault references."""
+
+    @patch("app.config.vault.requests.get")
+    def test_unreadable_secret_reported(self, mock_get):
+        """Test that validate reports a reference Vault refuses."""
+        mock_get.return_value = vault_response({}, status=403)
+        config = PaddiConfig(
+            {
+                "secrets": {"vault": VAULT},
+                "notifications": {"datadog": {"api_key": "vault:secret/paddi#datadog"}},
+            }
+        )
+
+        assert validate_config(config) == ["vault:secret/paddi: Vault returned HTTP 403"]
@@ -0,0 +1,124 @@
+"""Tests for the WebAssembly plugin host."""
+
+import json
+from unittest.mock import patch
+
+import pytest
+
+from app.common.exceptions import PluginError
+from app.config.paddi_config import PaddiConfig
+from app.plugins import wasm_host
+from app.plugins.wasm_host import WasmPlugin, WasmPluginHost
+
+
+def _analyzer_wat(version: int = 1) -> str:
+    """Build a WAT module that emits one finding and echoes its config."""
+    finding = json.dumps({"title": "Plugin finding", "severity": "high"})
+    escaped = finding.replace('"', '\\"')
+    return f"""
+    (module
+      (import "paddi_v1" "emit_finding" (func $emit (param i32 i32)))
+      (import "paddi_v1" "config_len" (func $config_len (result i32)))
+      (import "paddi_v1" "config_read" (func $config_read (param i32)))
+      (import "paddi_v1" "emit_data" (func $emit_data (param i32 i32)))
+      (memory (export "memory") 1)
+      (data (i32.const 0) "{escaped}")
+      (func (export "paddi_interface_version") (result i32) i32.const {version})
+      (func (export "paddi_analyze") (result i32)
+        i32.const 0
+        i32.const {len(finding)}
+        call $emit
+        i32.const 0)
+      (func (export "paddi_collect") (result i32)
+        i32.const 1024
+        call $config_read
+        i32.const 1024
+        call $config_len
+        call $emit_data
+        i32.const 0))
+    """
+
+
+@pytest.fixture
+def plugins_dir(tmp_path):
+    """Create a plugins directory containing one compiled plugin."""
+    wasmtime = pytest.importorskip("wasmtime")
+    directory = tmp_path / "plugins"
+    directory.mkdir()
+    (directory / "demo.wasm").write_bytes(wasmtime.wat2wasm(_analyzer_wat()))
+    return directory
+
+
+class TestWasmPluginHost:
+    """Tests for WasmPluginHost."""
+
+    def test_discover_missing_dir(self, tmp_path):
+        """Test discovery when the plugins directory does not exist."""
+        host = WasmPluginHost(plugins_dir=str(tmp_path / "absent"))
+        assert host.discover() == []
+        assert host.run_analyzers({}) == []
+
+    def test_discover_only_wasm_files(self, tmp_path):
+        """Test that only .wasm files are discovered."""
+        (tmp_path / "b.wasm").write_bytes(b"")
+        (tmp_path / "a.wasm").write_bytes(b"")
+        (tmp_path / "readme.txt").write_text("ignored", encoding="utf-8")
+
+        host = WasmPluginHost(plugins_dir=str(tmp_path))
+
+        assert [p.name for p in host.discover()] == ["a.wasm", "b.wasm"]
+
+    def test_from_config(self):
+        """Test building a host from the [plugins] section."""
+        config = PaddiConfig(
+            {"plugins": {"dir": "ext", "fuel": 10, "max_memory_mb": 8, "config": {"x": {}}}}
+        )
+
+        host = WasmPluginHost.from_config(config)
+
+        assert str(host.plugins_dir) == "ext"
+        assert host.fuel == 10
+        assert host.max_memory_mb == 8
+        assert host.plugin_configs == {"x": {}}
+
+    def test_skips_plugins_without_wasmtime(self, tmp_path):
+        """Test that plugins are skipped when wasmtime is unavailable."""
+        (tmp_path / "demo.wasm").write_bytes(b"")
+        host = WasmPluginHost(plugins_dir=str(tmp_path))
+
+        with patch.object(wasm_host, "wasmtime", None):
+            assert host.load_plugins() == []
+
+    def test_plugin_requires_wasmtime(self, tmp_path):
+        """Test that loading a plugin without wasmtime raises PluginError."""
+        with patch.object(wasm_host, "wasmtime", None):
+            with pytest.raises(PluginError):
+                WasmPlugin(tmp_path / "demo.wasm")
+
+    def test_run_analyzers(self, plugins_dir):
+        """Test findings emitted by an analyzer plugin."""
+        host = WasmPluginHost(plugins_dir=str(plugins_dir))
+
+        findings = host.run_analyzers({"iam_policies": {}})
+
+        assert len(findings) == 1
+        assert findings[0].title == "Plugin finding"
+        assert findings[0].severity == "HIGH"
+        assert findings[0].source == "plugin:demo"
+
+    def test_run_collectors_reads_config(self, plugins_dir):
+        """Test that collector plugins can read their config section."""
+        host = WasmPluginHost(
+            plugins_dir=str(plugins_dir), plugin_configs={"demo": {"region": "asia"}}
+        )
+
+        assert host.run_collectors() == {"demo": {"region": "asia"}}
+
+    def test_version_mismatch(self, tmp_path):
+        """Test that plugins built for another interface version are rejected."""
+        wasmtime = pytest.importorskip("wasmtime")
+        path = tmp_path / "old.wasm"
+        path.write_bytes(wasmtime.wat2wasm(_analyzer_wat(version=99)))
+
+        with pytest.raises(PluginError):
+            WasmPlugin(path).analyze({})
@@ -0,0 +1,177 @@
+"""Tests for outbound webhook notifications."""
+
+import hashlib
+import hmac
+import json
+from unittest.mock import Mock, patch
+
+import pytest
+import requests
+
+from app.cli.commands import AuditCommand
+from app.common.exceptions import ConfigurationError
+from app.common.failures import FailureLog
+from app.config.paddi_config import PaddiConfig
+from app.history.store import HISTORY_DB_NAME, RunStore
+from app.notifications.webhooks import (
+    SIGNATURE_HEADER,
+    Webhook,
+    WebhookNotifier,
+    build_payload,
+    sign,
+)
+
+FINDINGS = [
+    {"title": "Public bucket", "severity": "HIGH", "category": "PUBLIC_BUCKET", "resource": "b1"},
+    {"title": "Owner role", "severity": "CRITICAL", "category": "OVERPRIVILEGED_ROLE"},
+]
+
+
+def response(status_code):
+    """Create a fake HTTP response."""
+    return Mock(status_code=status_code)
+
+
+class TestPayload:
+    """Tests for build_payload."""
+
+    def test_summary_without_previous_run(self):
+        """Test the run summary."""
+        payload = build_payload(FINDINGS, project_id="prod")
+
+        assert payload["event"] == "audit.completed"
+        assert payload["project_id"] == "prod"
+        assert payload["summary"]["total"] == 2
+        assert payload["summary"]["severity_counts"] == {"HIGH": 1, "CRITICAL": 1}
+        assert payload["summary"]["risk_grade"] == "B"
+        assert payload["delta"] is None
+
+    def test_delta(self, tmp_path):
+        """Test new and resolved findings against the previous run."""
+        store = RunStore(tmp_path / HISTORY_DB_NAME)
+        previous = store.record_run(FINDINGS[1:], "prod")
+
+        payload = build_payload(FINDINGS[:1], (previous, FINDINGS[1:]), "prod")
+
+        assert payload["delta"]["new_count"] == 1
+        assert payload["delta"]["resolved_count"] == 1
+        assert payload["delta"]["new"][0]["title"] == "Public bucket"
+        assert payload["delta"]["resolved"][0]["severity"] == "CRITICAL"
+
+
+class TestWebhookNotifier:
+    """Tests for delivery, signing, and retries."""
+
+    def test_signed_delivery(self):
+        """Test that the body is signed with the webhook secret."""
+        notifier = WebhookNotifier([Webhook("siem", "https://siem.example.com", secret="s3cret")])
+
+        with patch("app.notifications.webhooks.requests.post", return_value=response(204)) as post:
+            assert notifier.send({"event": "audit.completed"}) == {"siem": True}
+
+        body = post.call_args.kwargs["data"]
+        expected = hmac.new(b"s3cret", body, hashlib.sha256).hexdigest()
+        assert post.call_args.kwargs["headers"][SIGNATURE_HEADER] == f"sha256={expected}"
+        assert json.loads(body) == {"event": "audit.completed"}
+
+    def test_unsigned_delivery(self):
+        """Test that webhooks without a secret have no signature header."""
+        notifier = WebhookNotifier([Webhook("chat", "https://hooks.example.com")])
+
+        with patch("app.notifications.webhooks.requests.post", return_value=response(200)) as post:
+            notifier.send({})
+
+        assert SIGNATURE_HEADER not in post.call_args.kwargs["headers"]
+
+    def test_retries_transient_failures(self, monkeypatch):
+        """Test retry with backoff on errors and 5xx responses."""
+        sleeps = []
+        monkeypatch.setattr("app.notifications.webhooks.time.sleep", sleeps.append)
+        notifier = WebhookNotifier([Webhook("siem", "https://siem.example.com", retries=3)])
+        outcomes = [requests.ConnectionError("down"), response(503), response(200)]
+
+        with patch("app.notifications.webhooks.requests.post", side_effect=outcomes) as post:
+            assert notifier.send({}) == {"siem": True}
+
+        assert post.call_count == 3
+        assert sleeps == [1, 2]
+
+    def test_gives_up_after_retries(self, monkeypatch):
+        """Test that delivery fails after the configured retries."""
+        monkeypatch.setattr("app.notifications.webhooks.time.sleep", lambda _: None)
+        notifier = WebhookNotifier([Webhook("siem", "https://siem.example.com", retries=1)])
+
+        with patch("app.notifications.webhooks.requests.post", return_value=response(500)) as post:
+            assert notifier.send({}) == {"siem": False}
+
+        assert post.call_count == 2
+
+    def test_client_error_is_not_retried(self):
+        """Test that 4xx responses other than 429 fail immediately."""
+        notifier = WebhookNotifier([Webhook("siem", "https://siem.example.com")])
+
+        with patch("app.notifications.webhooks.requests.post", return_value=response(401)) as post:
+            assert notifier.send({}) == {"siem": False}
+
+        assert post.call_count == 1
+
+    def test_notify_run_uses_history(self, tmp_path):
+        """Test that the run and its delta from the same project's previous run are sent."""
+        store = RunStore(tmp_path / HISTORY_DB_NAME)
+        store.record_run(FINDINGS[1:], "prod")
+        store.record_run([], "staging")
+        run = store.record_run(FINDINGS, "prod")
+        notifier = WebhookNotifier([Webhook("chat", "https://hooks.example.com")])
+
+        with patch("app.notifications.webhooks.requests.post", return_value=response(200)) as post:
+            notifier.notify_run(tmp_path, run.run_id)
+
+        payload = json.loads(post.call_args.kwargs["data"])
+        assert payload["project_id"] == "prod"
+        assert payload["delta"]["new_count"] == 1
+        assert payload["delta"]["resolved_count"] == 0
+
+    def test_audit_notifies_only_new_runs(self, tmp_path, monkeypatch):
+        """Test that an audit that recorded no new run does not resend the last delta."""
+        config = PaddiConfig({"notifications": {"webhooks": {"chat": "https://hooks.example.com"}}})
+        monkeypatch.setattr(PaddiConfig, "load", classmethod(lambda cls, *a, **k: config))
+        run = RunStore(tmp_path / HISTORY_DB_NAME).record_run(FINDINGS, "prod")
+
+        with patch("app.notifications.webhooks.requests.post", return_value=response(200)) as post:
+            AuditCommand()._notify(FailureLog(), None, str(tmp_path))
+            assert post.call_count == 0
+            AuditCommand()._notify(FailureLog(), run.run_id, str(tmp_path))
+            assert post.call_count == 1
+
+
+class TestFromConfig:
+    """Tests for webhook configuration."""
+
+    def test_url_and_table_entries(self, monkeypatch):
+        """Test plain URL entries and tables with a secret from the environment."""
+        monkeypatch.setenv("HOOK_SECRET", "abc")
+        config = PaddiConfig(
+            {
+                "notifications": {
+                    "webhooks": {
+                        "chat": "https://hooks.example.com/1",
+                        "siem": {"url": "https://siem.example.com", "secret_env": "HOOK_SECRET"},
+                    }
+                }
+            }
+        )
+
+        webhooks = WebhookNotifier.from_config(config).webhooks
+
+        assert [(w.name, w.secret) for w in webhooks] == [("chat", None), ("siem", "abc")]
+
+    def test_invalid_url(self):
+        """Test that non-HTTP URLs are rejected."""
+        config = PaddiConfig({"notifications": {"webhooks": {"bad": "ftp://example.com"}}})
+
+        with pytest.raises(ConfigurationError):
+            WebhookNotifier.from_config(config)
+
+    def test_signature_helper(self):
+        """Test the signature format."""
+        assert sign(b"{}", "k").startswith("sha256=")